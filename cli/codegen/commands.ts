@@ -210,6 +210,12 @@ const BUILD_OPTIONS: CommandSchema = {
       description:
         'Whether to disable the default file header for generated type def file. Only works when `typedef` feature enabled.',
     },
+    {
+      name: 'smokeTest',
+      type: 'string',
+      description:
+        'Path and filename of generated smoke test file, which calls every export with placeholder arguments. Only works with `--platform` flag. Relative to `--output-dir`.',
+    },
     {
       name: 'strip',
       type: 'boolean',
@@ -3,7 +3,7 @@ import { createHash } from 'node:crypto'
 import { existsSync, mkdirSync } from 'node:fs'
 import { createRequire } from 'node:module'
 import { tmpdir, homedir } from 'node:os'
-import { parse, join, resolve } from 'node:path'
+import { dirname, parse, join, relative, resolve } from 'node:path'
 
 import * as colors from 'colorette'
 import { include as setjmpInclude, lib as setjmpLib } from 'wasm-sjlj'
@@ -13,6 +13,7 @@ import {
   CLI_VERSION,
   copyFileAsync,
   Crate,
+  createSmokeTest,
   debugFactory,
   DEFAULT_TYPE_DEF_HEADER,
   fileExists,
@@ -710,6 +711,10 @@ class Builder {
       )
       if (jsOutput) {
         this.outputs.push(jsOutput)
+        const smokeTestOutput = await this.writeSmokeTest(jsOutput.path)
+        if (smokeTestOutput) {
+          this.outputs.push(smokeTestOutput)
+        }
       }
       if (wasmBindingsOutput) {
         this.outputs.push(...wasmBindingsOutput)
@@ -908,6 +913,38 @@ class Builder {
     }
   }
 
+  private async writeSmokeTest(jsBindingPath: string) {
+    if (!this.options.smokeTest) {
+      return
+    }
+
+    const dest = join(this.outputDir, this.options.smokeTest)
+    let bindingPath = relative(dirname(dest), jsBindingPath).replaceAll(
+      '\\',
+      '/',
+    )
+    if (!bindingPath.startsWith('.')) {
+      bindingPath = `./${bindingPath}`
+    }
+
+    try {
+      const smokeTest = await createSmokeTest(
+        this.envs.TYPE_DEF_TMP_PATH,
+        bindingPath,
+        this.options.esm ?? false,
+      )
+      debug('Writing smoke test to:')
+      debug('  %i', dest)
+      await writeFileAsync(dest, smokeTest, 'utf-8')
+      return {
+        kind: 'js',
+        path: dest,
+      } satisfies Output
+    } catch (e) {
+      throw new Error('Failed to write smoke test file', { cause: e })
+    }
+  }
+
   private async writeWasiBinding(
     wasiRegisterFunctions: string[],
     distFileName: string | undefined,
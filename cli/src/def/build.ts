@@ -85,6 +85,11 @@ export abstract class BaseBuildCommand extends Command {
       'Whether to disable the default file header for generated type def file. Only works when `typedef` feature enabled.',
   })
 
+  smokeTest?: string = Option.String('--smoke-test', {
+    description:
+      'Path and filename of generated smoke test file, which calls every export with placeholder arguments. Only works with `--platform` flag. Relative to `--output-dir`.',
+  })
+
   strip?: boolean = Option.Boolean('--strip,-s', {
     description: 'Whether strip the library to achieve the minimum file size',
   })
@@ -159,6 +164,7 @@ export abstract class BaseBuildCommand extends Command {
       dts: this.dts,
       dtsHeader: this.dtsHeader,
       noDtsHeader: this.noDtsHeader,
+      smokeTest: this.smokeTest,
       strip: this.strip,
       release: this.release,
       verbose: this.verbose,
@@ -244,6 +250,10 @@ export interface BuildOptions {
    * Whether to disable the default file header for generated type def file. Only works when `typedef` feature enabled.
    */
   noDtsHeader?: boolean
+  /**
+   * Path and filename of generated smoke test file, which calls every export with placeholder arguments. Only works with `--platform` flag. Relative to `--output-dir`.
+   */
+  smokeTest?: string
   /**
    * Whether strip the library to achieve the minimum file size
    */
@@ -0,0 +1,63 @@
+import { join } from 'path'
+import { fileURLToPath } from 'url'
+
+import test from 'ava'
+
+import {
+  createSmokeTest,
+  placeholderArgs,
+  placeholderValue,
+} from '../smoke-test.js'
+import { TypeDefKind } from '../typegen.js'
+
+test('should create placeholder values from types', (t) => {
+  t.is(placeholderValue('number'), '0')
+  t.is(placeholderValue('bigint'), '0n')
+  t.is(placeholderValue('Array<number>'), '[]')
+  t.is(placeholderValue('string[]'), '[]')
+  t.is(placeholderValue('[number, string]'), "[0, '']")
+  t.is(placeholderValue('string | null'), 'undefined')
+  t.is(placeholderValue("'a' | 'b'"), "'a'")
+  t.is(placeholderValue('(err: Error | null, value: number) => void'), 'noop')
+  t.is(placeholderValue('Promise<Buffer>'), 'Promise.resolve(Buffer.alloc(0))')
+  t.is(placeholderValue('Uint8Array'), 'new Uint8Array(0)')
+  t.is(placeholderValue('Record<string, number>'), '{}')
+})
+
+test('should create placeholder arguments from fn def', (t) => {
+  t.deepEqual(
+    placeholderArgs({
+      kind: TypeDefKind.Fn,
+      name: 'foo',
+      def: 'function foo<T>(a: number, cb: (e: Error, v: Array<T>) => void, opts?: Options): void',
+    }),
+    ['0', 'noop'],
+  )
+})
+
+test('should create smoke test for every export', async (t) => {
+  const smokeTest = await createSmokeTest(
+    join(
+      fileURLToPath(import.meta.url),
+      '../',
+      '__fixtures__',
+      'napi_type_def',
+    ),
+    './index.js',
+    false,
+  )
+
+  t.true(smokeTest.includes("const binding = require('./index.js')"))
+  t.true(
+    smokeTest.includes(`test('sumNums', async () => {
+  assert.strictEqual(typeof binding.sumNums, 'function')
+  await smoke(() => binding.sumNums([]))
+})`),
+  )
+  t.true(
+    smokeTest.includes(`test('xxh3.xxh3_64', async () => {
+  assert.strictEqual(typeof binding.xxh3?.xxh3_64, 'function')
+  await smoke(() => binding.xxh3?.xxh3_64(Buffer.alloc(0)))
+})`),
+  )
+})
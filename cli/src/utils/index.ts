@@ -6,3 +6,4 @@ export * from './metadata.js'
 export * from './config.js'
 export * from './cargo.js'
 export * from './typegen.js'
+export * from './smoke-test.js'
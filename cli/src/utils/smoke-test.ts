@@ -0,0 +1,288 @@
+import {
+  readIntermediateTypeFile,
+  TypeDefKind,
+  type TypeDefLine,
+} from './typegen.js'
+
+const SMOKE_TEST_HEADER = `/* auto-generated by NAPI-RS */
+/* eslint-disable */
+// Calls every exported function with placeholder arguments derived from the
+// type definitions. Errors thrown by the binding are expected, missing
+// exports, non-Error throws and crashes are not.
+`
+
+const TYPED_ARRAYS = [
+  'Int8Array',
+  'Uint8Array',
+  'Uint8ClampedArray',
+  'Int16Array',
+  'Uint16Array',
+  'Int32Array',
+  'Uint32Array',
+  'Float32Array',
+  'Float64Array',
+  'BigInt64Array',
+  'BigUint64Array',
+]
+
+/**
+ * Create a `node --test` file checking every export declared in the type
+ * definitions against the JS binding at `bindingPath`
+ */
+export async function createSmokeTest(
+  intermediateTypeFile: string,
+  bindingPath: string,
+  esm: boolean,
+) {
+  const defs = await readIntermediateTypeFile(intermediateTypeFile)
+
+  const imports = esm
+    ? `import assert from 'node:assert'
+import { test } from 'node:test'
+
+import * as binding from ${quote(bindingPath)}
+`
+    : `const assert = require('node:assert')
+const { test } = require('node:test')
+
+const binding = require(${quote(bindingPath)})
+`
+
+  const cases = defs
+    .map((def) => {
+      const target = def.js_mod
+        ? `binding${property(def.js_mod)}?.${property(def.name, true)}`
+        : `binding${property(def.name)}`
+      const title = quote(def.js_mod ? `${def.js_mod}.${def.name}` : def.name)
+      switch (def.kind) {
+        case TypeDefKind.Fn:
+          return `test(${title}, async () => {
+  assert.strictEqual(typeof ${target}, 'function')
+  await smoke(() => ${target}(${placeholderArgs(def).join(', ')}))
+})`
+        case TypeDefKind.Struct:
+          return `test(${title}, () => {
+  assert.strictEqual(typeof ${target}, 'function')
+})`
+        case TypeDefKind.Enum:
+        case TypeDefKind.StringEnum:
+          return `test(${title}, () => {
+  assert.strictEqual(typeof ${target}, 'object')
+})`
+        case TypeDefKind.Const:
+          return `test(${title}, () => {
+  assert.notStrictEqual(${target}, undefined)
+})`
+        default:
+          // interfaces, type aliases and class members only exist in the typings
+          return null
+      }
+    })
+    .filter((c): c is string => c !== null)
+
+  return `${SMOKE_TEST_HEADER}
+${imports}
+const noop = () => {}
+
+async function smoke(call) {
+  try {
+    const result = call()
+    if (result && typeof result.then === 'function') {
+      let timer
+      await Promise.race([
+        result,
+        new Promise((resolve) => {
+          timer = setTimeout(resolve, 1000)
+          timer.unref?.()
+        }),
+      ]).finally(() => clearTimeout(timer))
+    }
+  } catch (e) {
+    assert.ok(e instanceof Error, \`expected an Error to be thrown, got \${e}\`)
+  }
+}
+
+${cases.join('\n\n')}
+`
+}
+
+/**
+ * JS expressions for the arguments of a `function name(a: T, b?: U): R` def
+ */
+export function placeholderArgs(def: TypeDefLine): string[] {
+  const start = def.def.indexOf('(')
+  if (start === -1) {
+    return []
+  }
+  const params = splitTopLevel(
+    def.def.slice(start + 1, findClosing(def.def, start)),
+    ',',
+  )
+  const args: string[] = []
+  for (const param of params) {
+    if (param.startsWith('...')) {
+      break
+    }
+    const colon = splitTopLevel(param, ':')
+    const optional = colon[0].endsWith('?')
+    const type = colon.slice(1).join(':').trim()
+    args.push(optional || !type ? 'undefined' : placeholderValue(type))
+  }
+  // trailing `undefined` would only change `arguments.length`
+  while (args.length && args[args.length - 1] === 'undefined') {
+    args.pop()
+  }
+  return args
+}
+
+/**
+ * A JS expression producing a value of the TypeScript type `type`, or a
+ * plain object when the type can't be constructed from its name alone
+ */
+export function placeholderValue(type: string): string {
+  type = type.trim()
+  const union = splitTopLevel(type, '|')
+  if (union.length > 1) {
+    if (union.some((t) => t === 'undefined' || t === 'null')) {
+      return 'undefined'
+    }
+    return placeholderValue(union[0])
+  }
+  if (type.startsWith('(') && findClosing(type, 0) === type.length - 1) {
+    return placeholderValue(type.slice(1, -1))
+  }
+  if (/^\(.*\)\s*=>/s.test(type) || type === 'Function') {
+    return 'noop'
+  }
+  if (type.endsWith('[]')) {
+    return '[]'
+  }
+  if (type.startsWith('[')) {
+    const items = splitTopLevel(type.slice(1, -1), ',')
+    return `[${items.map((item) => placeholderValue(item)).join(', ')}]`
+  }
+  if (/^(['"`]).*\1$/s.test(type) || /^-?\d/.test(type)) {
+    // string and number literal types
+    return type
+  }
+  if (type === 'true' || type === 'false') {
+    return type
+  }
+  const generic = type.indexOf('<')
+  const name = generic === -1 ? type : type.slice(0, generic)
+  const typeArgs =
+    generic === -1 ? [] : splitTopLevel(type.slice(generic + 1, -1), ',')
+  switch (name) {
+    case 'number':
+      return '0'
+    case 'bigint':
+      return '0n'
+    case 'string':
+      return "''"
+    case 'boolean':
+      return 'false'
+    case 'symbol':
+      return 'Symbol()'
+    case 'undefined':
+    case 'void':
+    case 'null':
+    case 'unknown':
+    case 'any':
+      return 'undefined'
+    case 'Array':
+    case 'ReadonlyArray':
+      return '[]'
+    case 'Set':
+      return 'new Set()'
+    case 'Map':
+      return 'new Map()'
+    case 'Buffer':
+      return 'Buffer.alloc(0)'
+    case 'ArrayBuffer':
+      return 'new ArrayBuffer(0)'
+    case 'DataView':
+      return 'new DataView(new ArrayBuffer(0))'
+    case 'Date':
+      return 'new Date(0)'
+    case 'RegExp':
+      return 'new RegExp("")'
+    case 'Error':
+      return 'new Error()'
+    case 'Promise':
+      return `Promise.resolve(${typeArgs.length ? placeholderValue(typeArgs[0]) : ''})`
+    default:
+      if (TYPED_ARRAYS.includes(name)) {
+        return `new ${name}(0)`
+      }
+      return '{}'
+  }
+}
+
+function quote(value: string): string {
+  return `'${value.replaceAll('\\', '\\\\').replaceAll("'", "\\'")}'`
+}
+
+function property(name: string, optionalChain = false): string {
+  if (/^[A-Za-z_$][\w$]*$/.test(name)) {
+    return optionalChain ? name : `.${name}`
+  }
+  return `[${quote(name)}]`
+}
+
+/**
+ * Split `input` on `separator` outside of brackets and string literals
+ */
+function splitTopLevel(input: string, separator: string): string[] {
+  const parts: string[] = []
+  let depth = 0
+  let quote: string | null = null
+  let current = ''
+  for (let i = 0; i < input.length; i++) {
+    const char = input[i]
+    if (quote) {
+      if (char === quote && input[i - 1] !== '\\') {
+        quote = null
+      }
+    } else if (char === "'" || char === '"' || char === '`') {
+      quote = char
+    } else if ('([{<'.includes(char)) {
+      depth += 1
+    } else if (
+      ')]}'.includes(char) ||
+      (char === '>' && input[i - 1] !== '=')
+    ) {
+      depth -= 1
+    } else if (char === separator && depth === 0) {
+      parts.push(current.trim())
+      current = ''
+      continue
+    }
+    current += char
+  }
+  if (current.trim()) {
+    parts.push(current.trim())
+  }
+  return parts
+}
+
+/**
+ * Index of the bracket closing the one at `start`
+ */
+function findClosing(input: string, start: number): number {
+  let depth = 0
+  for (let i = start; i < input.length; i++) {
+    const char = input[i]
+    if ('([{<'.includes(char)) {
+      depth += 1
+    } else if (
+      ')]}'.includes(char) ||
+      (char === '>' && input[i - 1] !== '=')
+    ) {
+      depth -= 1
+      if (depth === 0) {
+        return i
+      }
+    }
+  }
+  return input.length
+}
@@ -7,7 +7,7 @@ export const DEFAULT_TYPE_DEF_HEADER = `/* auto-generated by NAPI-RS */
 /* eslint-disable */
 `
 
-enum TypeDefKind {
+export enum TypeDefKind {
   Const = 'const',
   Enum = 'enum',
   StringEnum = 'string_enum',
@@ -15,16 +15,18 @@ enum TypeDefKind {
   Type = 'type',
   Fn = 'fn',
   Struct = 'struct',
+  Error = 'error',
   Impl = 'impl',
 }
 
-interface TypeDefLine {
+export interface TypeDefLine {
   kind: TypeDefKind
   name: string
   original_name?: string
   def: string
   js_doc?: string
   js_mod?: string
+  reflect?: boolean
 }
 
 function prettyPrint(
@@ -44,16 +46,21 @@ function prettyPrint(
       break
 
     case TypeDefKind.Enum:
-      const enumName = constEnum ? 'const enum' : 'enum'
+      // the reflection is defined on the enum object, which const enums don't have
+      const enumName = constEnum && !line.reflect ? 'const enum' : 'enum'
       s += `${exportDeclare(ambient)} ${enumName} ${line.name} {\n${line.def}\n}`
+      s += enumReflection(line, ambient)
       break
 
     case TypeDefKind.StringEnum:
-      if (constEnum) {
+      if (constEnum && line.reflect) {
+        s += `${exportDeclare(ambient)} enum ${line.name} {\n${line.def}\n}`
+      } else if (constEnum) {
         s += `${exportDeclare(ambient)} const enum ${line.name} {\n${line.def}\n}`
       } else {
         s += `export type ${line.name} = ${line.def.replaceAll(/.*=/g, '').replaceAll(',', '|')};`
       }
+      s += enumReflection(line, ambient)
       break
 
     case TypeDefKind.Struct:
@@ -63,6 +70,13 @@ function prettyPrint(
       }
       break
 
+    case TypeDefKind.Error:
+      s += `${exportDeclare(ambient)} class ${line.name} extends Error {\n${line.def}\n}`
+      if (line.original_name && line.original_name !== line.name) {
+        s += `\nexport type ${line.original_name} = ${line.name}`
+      }
+      break
+
     case TypeDefKind.Fn:
       s += `${exportDeclare(ambient)} ${line.def}`
       break
@@ -74,6 +88,16 @@ function prettyPrint(
   return correctStringIdent(s, ident)
 }
 
+function enumReflection(line: TypeDefLine, ambient: boolean): string {
+  if (!line.reflect) {
+    return ''
+  }
+  return `\n${exportDeclare(ambient)} namespace ${line.name} {
+  function values(): Array<${line.name}>
+  function nameOf(value: unknown): string | undefined
+}`
+}
+
 function exportDeclare(ambient: boolean): string {
   if (ambient) {
     return 'export'
@@ -104,8 +128,12 @@ export async function processTypeDef(
                 case TypeDefKind.Enum:
                 case TypeDefKind.StringEnum:
                 case TypeDefKind.Fn:
-                case TypeDefKind.Struct: {
-                  exports.push(def.name)
+                case TypeDefKind.Struct:
+                case TypeDefKind.Error: {
+                  // the overloads of a function share its name
+                  if (!exports.includes(def.name)) {
+                    exports.push(def.name)
+                  }
                   if (def.original_name && def.original_name !== def.name) {
                     exports.push(def.original_name)
                   }
@@ -147,7 +175,7 @@ export declare class ExternalObject<T> {
   }
 }
 
-async function readIntermediateTypeFile(file: string) {
+export async function readIntermediateTypeFile(file: string) {
   const content = await readFileAsync(file, 'utf8')
   const defs = content
     .split('\n')
@@ -11,6 +11,18 @@ pub struct NapiFn {
   pub is_ret_result: bool,
   pub is_async: bool,
   pub within_async_runtime: bool,
+  /// `#[napi(thread = "blocking")]`, the body runs on the libuv thread pool
+  pub blocking: bool,
+  /// `#[napi(memoize)]`, the results are cached per env by arguments, every call with equal
+  /// arguments gets the same JS value
+  pub memoize: bool,
+  /// `#[napi(error = MyError)]`, the `MyError` of the returned `Result` is thrown as its class
+  pub error_class: Option<Ident>,
+  /// `#[napi(overload = "...")]`, exported with the other overloads of `js_name` as one function.
+  /// The index is the declaration order, which breaks the ties between the overloads.
+  pub overload: Option<usize>,
+  /// `#[napi(unstable)]`, the first call in an env emits an `ExperimentalWarning`
+  pub unstable: bool,
   pub fn_self: Option<FnSelf>,
   pub kind: FnKind,
   pub vis: syn::Visibility,
@@ -18,16 +30,20 @@ pub struct NapiFn {
   pub strict: bool,
   pub return_if_invalid: bool,
   pub js_mod: Option<String>,
+  pub module: Option<String>,
   pub ts_generic_types: Option<String>,
   pub ts_args_type: Option<String>,
   pub ts_return_type: Option<String>,
   pub skip_typescript: bool,
   pub comments: Vec<String>,
   pub parent_is_generator: bool,
+  pub parent_has_set_hook: bool,
+  pub borrow: bool,
   pub writable: bool,
   pub enumerable: bool,
   pub configurable: bool,
   pub catch_unwind: bool,
+  pub record: bool,
   pub unsafe_: bool,
   pub register_name: Ident,
 }
@@ -80,6 +96,7 @@ pub struct NapiStruct {
   pub js_name: String,
   pub comments: Vec<String>,
   pub js_mod: Option<String>,
+  pub module: Option<String>,
   pub use_nullable: bool,
   pub register_name: Ident,
   pub kind: NapiStructKind,
@@ -91,6 +108,7 @@ pub enum NapiStructKind {
   Class(NapiClass),
   Object(NapiObject),
   StructuredEnum(NapiStructuredEnum),
+  Error(NapiErrorClass),
 }
 
 #[derive(Debug, Clone)]
@@ -100,6 +118,9 @@ pub struct NapiClass {
   pub implement_iterator: bool,
   pub is_tuple: bool,
   pub use_custom_finalize: bool,
+  pub use_set_hook: bool,
+  /// `#[napi(json)]`, adds `fromJSON` and `toJSON` converting with serde
+  pub json: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -110,6 +131,13 @@ pub struct NapiObject {
   pub is_tuple: bool,
 }
 
+/// `#[napi(error)]`, thrown as an instance of a class extending `Error`
+#[derive(Debug, Clone)]
+pub struct NapiErrorClass {
+  /// Set on the error, the `message` field is its message
+  pub fields: Vec<NapiStructField>,
+}
+
 #[derive(Debug, Clone)]
 pub struct NapiStructuredEnum {
   pub variants: Vec<NapiStructuredEnumVariant>,
@@ -139,6 +167,7 @@ pub struct NapiStructField {
   pub skip_typescript: bool,
   pub ts_type: Option<String>,
   pub has_lifetime: bool,
+  pub borrow: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -162,10 +191,13 @@ pub struct NapiEnum {
   pub js_name: String,
   pub variants: Vec<NapiEnumVariant>,
   pub js_mod: Option<String>,
+  pub module: Option<String>,
   pub comments: Vec<String>,
   pub skip_typescript: bool,
   pub register_name: Ident,
   pub is_string_enum: bool,
+  /// `#[napi(reflect)]`, define `values()` and `nameOf(value)` on the enum object
+  pub reflect: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -197,6 +229,7 @@ pub struct NapiConst {
   pub type_name: Type,
   pub value: Expr,
   pub js_mod: Option<String>,
+  pub module: Option<String>,
   pub comments: Vec<String>,
   pub skip_typescript: bool,
   pub register_name: Ident,
@@ -38,3 +38,56 @@ fn js_mod_to_token_stream(js_mod: Option<&String>) -> TokenStream {
     })
     .unwrap_or_else(|| quote! { None })
 }
+
+/// The `#[cfg]` attributes of an item
+pub fn cfg_attrs(attrs: &[syn::Attribute]) -> Vec<syn::Attribute> {
+  attrs
+    .iter()
+    .filter(|attr| attr.path().is_ident("cfg"))
+    .cloned()
+    .collect()
+}
+
+/// Put the `#[cfg]`s of an item on everything generated for it, so the registration is compiled
+/// out with the item
+pub fn with_cfg(tokens: TokenStream, cfgs: &[syn::Attribute]) -> TokenStream {
+  if cfgs.is_empty() {
+    return tokens;
+  }
+  match syn::parse2::<syn::File>(tokens.clone()) {
+    Ok(file) => file
+      .items
+      .into_iter()
+      .map(|item| quote! { #(#cfgs)* #item })
+      .collect(),
+    Err(_) => tokens,
+  }
+}
+
+/// Record the Rust item of the export, to report the names exported by several items when the
+/// module is registered
+fn export_origin_to_token_stream(
+  kind: &str,
+  name: &Ident,
+  js_mod: &TokenStream,
+  js_name: &str,
+) -> TokenStream {
+  let item = format!("{} `{}` at ", kind, name);
+  let location = quote_spanned! { name.span() => concat!(file!(), ":", line!(), ":", column!()) };
+  quote! {
+    napi::bindgen_prelude::register_export_origin(#js_mod, #js_name, concat!(#item, #location));
+  }
+}
+
+/// Tag the export with the `#[napi(module)]` it belongs to, called next to its registration
+fn module_member_to_token_stream(
+  module: Option<&String>,
+  js_mod: &TokenStream,
+  js_name: &str,
+) -> TokenStream {
+  module
+    .map(|module| {
+      quote! { napi::bindgen_prelude::register_module_member(#module, #js_mod, #js_name); }
+    })
+    .unwrap_or_default()
+}
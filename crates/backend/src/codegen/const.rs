@@ -1,7 +1,10 @@
 use proc_macro2::{Ident, Literal, TokenStream};
 use quote::ToTokens;
 
-use crate::{codegen::js_mod_to_token_stream, BindgenResult, NapiConst, TryToTokens};
+use crate::{
+  codegen::{export_origin_to_token_stream, js_mod_to_token_stream, module_member_to_token_stream},
+  BindgenResult, NapiConst, TryToTokens,
+};
 
 impl TryToTokens for NapiConst {
   fn try_to_tokens(&self, tokens: &mut TokenStream) -> BindgenResult<()> {
@@ -18,7 +21,7 @@ impl TryToTokens for NapiConst {
 impl NapiConst {
   fn gen_module_register(&self) -> TokenStream {
     let name_ident = &self.name;
-    let js_name_lit = Literal::string(&format!("{}\0", self.name));
+    let js_name_lit = Literal::string(&format!("{}\0", self.js_name));
     let register_name = &self.register_name;
     let type_name = &self.type_name;
     let cb_name = Ident::new(
@@ -26,6 +29,17 @@ impl NapiConst {
       self.name.span(),
     );
     let js_mod_ident = js_mod_to_token_stream(self.js_mod.as_ref());
+    let module_member = module_member_to_token_stream(
+      self.module.as_ref(),
+      &js_mod_ident,
+      &format!("{}\0", self.js_name),
+    );
+    let export_origin = export_origin_to_token_stream(
+      "const",
+      &self.name,
+      &js_mod_ident,
+      &format!("{}\0", self.js_name),
+    );
 
     quote! {
       #[allow(non_snake_case)]
@@ -39,6 +53,8 @@ impl NapiConst {
       #[napi::bindgen_prelude::ctor]
       fn #register_name() {
         napi::bindgen_prelude::register_module_export(#js_mod_ident, #js_name_lit, #cb_name);
+        #module_member
+        #export_origin
       }
 
       #[allow(non_snake_case)]
@@ -47,6 +63,8 @@ impl NapiConst {
       #[no_mangle]
       unsafe extern "C" fn #register_name() {
         napi::bindgen_prelude::register_module_export(#js_mod_ident, #js_name_lit, #cb_name);
+        #module_member
+        #export_origin
       }
     }
   }
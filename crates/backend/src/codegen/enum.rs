@@ -1,15 +1,20 @@
 use proc_macro2::{Ident, Literal, Span, TokenStream};
 use quote::ToTokens;
 
-use crate::{codegen::js_mod_to_token_stream, BindgenResult, NapiEnum, TryToTokens};
+use crate::{
+  codegen::{export_origin_to_token_stream, js_mod_to_token_stream, module_member_to_token_stream},
+  BindgenResult, NapiEnum, NapiEnumValue, TryToTokens,
+};
 
 impl TryToTokens for NapiEnum {
   fn try_to_tokens(&self, tokens: &mut TokenStream) -> BindgenResult<()> {
     let register = self.gen_module_register();
     let napi_value_conversion = self.gen_napi_value_map_impl();
+    let reflection = self.gen_reflect_enum_impl();
 
     (quote! {
       #napi_value_conversion
+      #reflection
       #register
     })
     .to_tokens(tokens);
@@ -155,6 +160,33 @@ impl NapiEnum {
     }
   }
 
+  fn gen_reflect_enum_impl(&self) -> TokenStream {
+    if !self.reflect {
+      return quote! {};
+    }
+    let name = &self.name;
+    let variants = self.variants.iter().map(|variant| {
+      let name_lit = Literal::string(&variant.name.to_string());
+      let value = match &variant.val {
+        NapiEnumValue::Number(number) => {
+          let number = Literal::i32_unsuffixed(*number);
+          quote! { napi::bindgen_prelude::EnumVariantValue::Number(#number) }
+        }
+        NapiEnumValue::String(string) => {
+          let string = Literal::string(string);
+          quote! { napi::bindgen_prelude::EnumVariantValue::String(#string) }
+        }
+      };
+      quote! { (#name_lit, #value) }
+    });
+
+    quote! {
+      impl napi::bindgen_prelude::ReflectEnum for #name {
+        const VARIANTS: &'static [(&'static str, napi::bindgen_prelude::EnumVariantValue)] = &[#(#variants),*];
+      }
+    }
+  }
+
   fn gen_module_register(&self) -> TokenStream {
     let name_str = self.name.to_string();
     let js_name_lit = Literal::string(&format!("{}\0", &self.js_name));
@@ -182,12 +214,32 @@ impl NapiEnum {
       })
     }
 
+    let define_reflection = if self.reflect {
+      let name = &self.name;
+      quote! {
+        napi::bindgen_prelude::define_enum_reflection::<#name>(env, obj_ptr)?;
+      }
+    } else {
+      quote! {}
+    };
+
     let callback_name = Ident::new(
       &format!("__register__enum__{}_callback__", name_str),
       Span::call_site(),
     );
 
     let js_mod_ident = js_mod_to_token_stream(self.js_mod.as_ref());
+    let module_member = module_member_to_token_stream(
+      self.module.as_ref(),
+      &js_mod_ident,
+      &format!("{}\0", self.js_name),
+    );
+    let export_origin = export_origin_to_token_stream(
+      "enum",
+      &self.name,
+      &js_mod_ident,
+      &format!("{}\0", self.js_name),
+    );
 
     quote! {
       #[allow(non_snake_case)]
@@ -205,6 +257,8 @@ impl NapiEnum {
 
         #(#define_properties)*
 
+        #define_reflection
+
         Ok(obj_ptr)
       }
       #[allow(non_snake_case)]
@@ -213,6 +267,8 @@ impl NapiEnum {
       #[napi::bindgen_prelude::ctor]
       fn #register_name() {
         napi::bindgen_prelude::register_module_export(#js_mod_ident, #js_name_lit, #callback_name);
+        #module_member
+        #export_origin
       }
       #[allow(non_snake_case)]
       #[allow(clippy::all)]
@@ -220,6 +276,8 @@ impl NapiEnum {
       #[no_mangle]
       extern "C" fn #register_name() {
         napi::bindgen_prelude::register_module_export(#js_mod_ident, #js_name_lit, #callback_name);
+        #module_member
+        #export_origin
       }
     }
   }
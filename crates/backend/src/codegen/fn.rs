@@ -3,7 +3,10 @@ use quote::ToTokens;
 use syn::{spanned::Spanned, Type, TypePath};
 
 use crate::{
-  codegen::{get_intermediate_ident, js_mod_to_token_stream},
+  codegen::{
+    export_origin_to_token_stream, get_intermediate_ident, js_mod_to_token_stream,
+    module_member_to_token_stream,
+  },
   BindgenResult, CallbackArg, Diagnostic, FnKind, FnSelf, NapiFn, NapiFnArgKind, TryToTokens,
   TYPEDARRAY_SLICE_TYPES,
 };
@@ -20,6 +23,8 @@ impl TryToTokens for NapiFn {
       refs,
       mut_ref_spans,
       unsafe_,
+      recorded_args,
+      overload_checks,
     } = self.gen_arg_conversions()?;
     // The JS engine can't properly track mutability in an async context, so refuse to compile
     // code that tries to use async and mutability together without `unsafe` mark.
@@ -41,8 +46,18 @@ impl TryToTokens for NapiFn {
     let receiver = self.gen_fn_receiver();
     let receiver_ret_name = Ident::new("_ret", Span::call_site());
     let ret = self.gen_fn_return(&receiver_ret_name)?;
-    let register = self.gen_fn_register();
+    let register = match self.overload {
+      Some(index) => self.gen_overload_register(index, &overload_checks),
+      None => self.gen_fn_register(),
+    };
     let attrs = &self.attrs;
+    // `#[napi(error = MyError)]`, thrown as an instance of the `MyError` class
+    let map_error_class = match &self.error_class {
+      Some(error_class) => quote! {
+        .map_err(|err: #error_class| napi::Error::from(napi::bindgen_prelude::JsErrorValue::from(err)))
+      },
+      None => quote! {},
+    };
 
     let build_ref_container = if self.is_async {
       quote! {
@@ -90,27 +105,99 @@ impl TryToTokens for NapiFn {
     } else {
       quote! {}
     };
-    let native_call = if !self.is_async {
+    let native_call = if self.blocking {
+      let call = if self.is_ret_result {
+        quote! { #receiver(#(#arg_names),*) #map_error_class }
+      } else {
+        let ret_type = if let Some(t) = &self.ret {
+          quote! { #t }
+        } else {
+          quote! { () }
+        };
+        quote! { Ok::<#ret_type, napi::Error>(#receiver(#(#arg_names),*)) }
+      };
+      quote! {
+        napi::bindgen_prelude::execute_blocking(env, move || { #call }, move |env, #receiver_ret_name| {
+          #ret
+        })
+      }
+    } else if !self.is_async {
+      let call = if self.record {
+        let js_name = match &self.parent {
+          Some(parent) => format!("{}.{}", parent, self.js_name),
+          None => self.js_name.clone(),
+        };
+        let record = if self.is_ret_result {
+          quote! { record_result }
+        } else {
+          quote! { record_return }
+        };
+        quote! {
+          let __record_args = napi::record::capture_args(|| vec![#(napi::record::to_value(&#recorded_args)),*]);
+          let #receiver_ret_name = {
+            #receiver(#(#arg_names),*) #map_error_class
+          };
+          napi::record::#record(#js_name, __record_args, &#receiver_ret_name);
+        }
+      } else {
+        quote! {
+          let #receiver_ret_name = {
+            #receiver(#(#arg_names),*) #map_error_class
+          };
+        }
+      };
+      let call = if self.parent_has_set_hook {
+        let parent = self.parent.as_ref().expect("Parent must exist for setter");
+        let js_name = &self.js_name;
+        let succeeded = if self.is_ret_result {
+          quote! { #receiver_ret_name.is_ok() }
+        } else {
+          quote! { true }
+        };
+        quote! {
+          #call
+          if #succeeded {
+            napi::bindgen_prelude::ObjectSetHook::after_set(cb.unwrap_borrow_mut::<#parent>()?, #js_name);
+          }
+        }
+      } else {
+        call
+      };
       if self.within_async_runtime {
         quote! {
           napi::bindgen_prelude::within_runtime_if_available(move || {
-            let #receiver_ret_name = {
-              #receiver(#(#arg_names),*)
-            };
+            #call
             #ret
           })
         }
+      } else if self.memoize {
+        let memoize_name = match &self.parent {
+          Some(parent) => format!("{}::{}", parent, name_str),
+          None => name_str.clone(),
+        };
+        quote! {
+          let __memoize_name = concat!(module_path!(), "::", #memoize_name);
+          let __memoize_key = (#(#recorded_args.to_owned(),)*);
+          if let Some(memoized) = napi::bindgen_prelude::get_memoized(env, __memoize_name, &__memoize_key)? {
+            return Ok(memoized);
+          }
+          #call
+          let __memoized = { #ret }?;
+          // a null result means an error was thrown
+          if !__memoized.is_null() {
+            napi::bindgen_prelude::set_memoized(env, __memoize_name, __memoize_key, __memoized)?;
+          }
+          Ok(__memoized)
+        }
       } else {
         quote! {
-          let #receiver_ret_name = {
-            #receiver(#(#arg_names),*)
-          };
+          #call
           #ret
         }
       }
     } else {
       let call = if self.is_ret_result {
-        quote! { #receiver(#(#arg_names),*).await }
+        quote! { #receiver(#(#arg_names),*).await #map_error_class }
       } else {
         let ret_type = if let Some(t) = &self.ret {
           quote! { #t }
@@ -148,6 +235,7 @@ impl TryToTokens for NapiFn {
       && self.kind != FnKind::Constructor
       && self.kind != FnKind::Factory
       && !self.is_async
+      && !self.memoize
     {
       quote! { #native_call }
     } else if self.kind == FnKind::Constructor {
@@ -193,6 +281,22 @@ impl TryToTokens for NapiFn {
       }
     };
 
+    let warn_unstable = if self.unstable {
+      let api_name = match (&self.parent, &self.kind) {
+        (Some(parent), FnKind::Constructor) => parent.to_string(),
+        (Some(parent), _) => format!("{}.{}", parent, self.js_name),
+        (None, _) => self.js_name.clone(),
+      };
+      quote! {
+        if let Err(e) = napi::bindgen_prelude::warn_unstable(env, #api_name) {
+          napi::bindgen_prelude::JsError::from(e).throw_into(env);
+          return std::ptr::null_mut();
+        }
+      }
+    } else {
+      quote! {}
+    };
+
     (quote! {
       #(#attrs)*
       #[doc(hidden)]
@@ -203,6 +307,7 @@ impl TryToTokens for NapiFn {
         cb: napi::bindgen_prelude::sys::napi_callback_info
       ) -> napi::bindgen_prelude::sys::napi_value {
         unsafe {
+          #warn_unstable
           #function_call.unwrap_or_else(|e| {
             napi::bindgen_prelude::JsError::from(e).throw_into(env);
             std::ptr::null_mut::<napi::bindgen_prelude::sys::napi_value__>()
@@ -224,6 +329,8 @@ impl NapiFn {
     let mut args = vec![];
     let mut refs = vec![];
     let mut mut_ref_spans = vec![];
+    let mut recorded_args = vec![];
+    let mut overload_checks = vec![];
     let make_ref = |input| {
       quote! {
         _args_array[_arg_write_index] = _make_ref(
@@ -373,11 +480,16 @@ impl NapiFn {
               continue;
             }
             arg_conversions.push(arg_conversion);
+            overload_checks.push(gen_overload_check(i, &path.ty));
+            recorded_args.push(ident.clone());
             args.push(quote! { #ident });
           }
         }
         NapiFnArgKind::Callback(cb) => {
           arg_conversions.push(self.gen_cb_arg_conversion(&ident, i, cb)?);
+          overload_checks.push(quote! {
+            <napi::JsFunction as napi::bindgen_prelude::ValidateNapiValue>::validate(env, args[#i]).is_ok()
+          });
           args.push(quote! { #ident });
         }
       }
@@ -389,6 +501,8 @@ impl NapiFn {
       refs,
       mut_ref_spans,
       unsafe_: self.unsafe_,
+      recorded_args,
+      overload_checks,
     })
   }
 
@@ -511,6 +625,7 @@ impl NapiFn {
   ) -> BindgenResult<TokenStream> {
     let mut inputs = vec![];
     let mut arg_conversions = vec![];
+    let args_len = cb.args.len();
 
     for (i, ty) in cb.args.iter().enumerate() {
       let cb_arg_ident = Ident::new(&format!("callback_arg_{}", i), Span::call_site());
@@ -536,7 +651,7 @@ impl NapiFn {
     Ok(quote! {
       napi::bindgen_prelude::assert_type_of!(env, cb.get_arg(#index), napi::bindgen_prelude::ValueType::Function)?;
       let #arg_name = |#(#inputs),*| {
-        let args = vec![
+        let args: [napi::bindgen_prelude::sys::napi_value; #args_len] = [
           #(#arg_conversions),*
         ];
 
@@ -631,8 +746,24 @@ impl NapiFn {
         } else {
           Ok(quote! { cb.factory(#js_name, #ret) })
         }
+      } else if self.borrow {
+        if self.is_ret_result {
+          Ok(quote! {
+            match #ret {
+              Ok(value) => napi::bindgen_prelude::BorrowedToNapiValue::to_napi_value_borrowed(env, value),
+              Err(err) => {
+                napi::bindgen_prelude::JsError::from(err).throw_into(env);
+                Ok(std::ptr::null_mut())
+              },
+            }
+          })
+        } else {
+          Ok(quote! {
+            napi::bindgen_prelude::BorrowedToNapiValue::to_napi_value_borrowed(env, #ret)
+          })
+        }
       } else if self.is_ret_result {
-        if self.is_async {
+        if self.is_async || self.blocking {
           Ok(quote! {
             <#ty as napi::bindgen_prelude::ToNapiValue>::to_napi_value(env, #ret)
           })
@@ -675,6 +806,9 @@ impl NapiFn {
       let module_register_name = &self.register_name;
       let intermediate_ident = get_intermediate_ident(&name_str);
       let js_mod_ident = js_mod_to_token_stream(self.js_mod.as_ref());
+      let module_member =
+        module_member_to_token_stream(self.module.as_ref(), &js_mod_ident, &js_name);
+      let export_origin = export_origin_to_token_stream("fn", &self.name, &js_mod_ident, &js_name);
       let cb_name = Ident::new(&format!("{}_js_function", name_str), Span::call_site());
 
       quote! {
@@ -705,6 +839,8 @@ impl NapiFn {
         #[napi::bindgen_prelude::ctor]
         fn #module_register_name() {
           napi::bindgen_prelude::register_module_export(#js_mod_ident, #js_name, #cb_name);
+          #module_member
+          #export_origin
         }
 
         #[allow(clippy::all)]
@@ -713,10 +849,73 @@ impl NapiFn {
         #[no_mangle]
         extern "C" fn #module_register_name() {
           napi::bindgen_prelude::register_module_export(#js_mod_ident, #js_name, #cb_name);
+          #module_member
+          #export_origin
         }
       }
     }
   }
+
+  /// Register the function as one of the overloads of its `js_name`, instead of as an export
+  fn gen_overload_register(&self, index: usize, overload_checks: &[TokenStream]) -> TokenStream {
+    let name_str = self.name.to_string();
+    let js_name = format!("{}\0", &self.js_name);
+    let module_register_name = &self.register_name;
+    let intermediate_ident = get_intermediate_ident(&name_str);
+    let js_mod_ident = js_mod_to_token_stream(self.js_mod.as_ref());
+    let module_member =
+      module_member_to_token_stream(self.module.as_ref(), &js_mod_ident, &js_name);
+    let export_origin = export_origin_to_token_stream("fn", &self.name, &js_mod_ident, &js_name);
+    let matcher_name = Ident::new(&format!("{}_overload_matches", name_str), Span::call_site());
+    let arity = overload_checks.len();
+
+    quote! {
+      #[allow(non_snake_case)]
+      #[allow(unused_variables)]
+      #[allow(clippy::all)]
+      unsafe fn #matcher_name(
+        env: napi::bindgen_prelude::sys::napi_env,
+        args: &[napi::bindgen_prelude::sys::napi_value],
+      ) -> bool {
+        true #(&& #overload_checks)*
+      }
+
+      #[allow(clippy::all)]
+      #[allow(non_snake_case)]
+      #[cfg(all(not(test), not(target_family = "wasm")))]
+      #[napi::bindgen_prelude::ctor]
+      fn #module_register_name() {
+        napi::bindgen_prelude::register_overload(#js_mod_ident, #js_name, #index, #arity, #matcher_name, Some(#intermediate_ident));
+        #module_member
+        #export_origin
+      }
+
+      #[allow(clippy::all)]
+      #[allow(non_snake_case)]
+      #[cfg(all(not(test), target_family = "wasm"))]
+      #[no_mangle]
+      extern "C" fn #module_register_name() {
+        napi::bindgen_prelude::register_overload(#js_mod_ident, #js_name, #index, #arity, #matcher_name, Some(#intermediate_ident));
+        #module_member
+        #export_origin
+      }
+    }
+  }
+}
+
+/// Whether the JavaScript argument `index` of an overload can be converted to `ty`
+fn gen_overload_check(index: usize, ty: &syn::Type) -> TokenStream {
+  // the checks are out of the function, so its lifetimes are elided
+  struct ElideLifetimes;
+  impl syn::fold::Fold for ElideLifetimes {
+    fn fold_lifetime(&mut self, _: syn::Lifetime) -> syn::Lifetime {
+      syn::Lifetime::new("'_", Span::call_site())
+    }
+  }
+  let ty = syn::fold::Fold::fold_type(&mut ElideLifetimes, ty.clone());
+  quote! {
+    <#ty as napi::bindgen_prelude::ValidateNapiValue>::validate(env, args[#index]).is_ok()
+  }
 }
 
 fn hidden_ty_lifetime(ty: &mut syn::Type) -> BindgenResult<()> {
@@ -745,6 +944,10 @@ struct ArgConversions {
   pub refs: Vec<TokenStream>,
   pub mut_ref_spans: Vec<Span>,
   pub unsafe_: bool,
+  /// JavaScript arguments serialized by `#[napi(record)]`
+  pub recorded_args: Vec<Ident>,
+  /// Whether each JavaScript argument fits its parameter, for `#[napi(overload)]`
+  pub overload_checks: Vec<TokenStream>,
 }
 
 #[derive(Debug, PartialEq, Eq)]
@@ -5,10 +5,13 @@ use proc_macro2::{Ident, Literal, Span, TokenStream};
 use quote::ToTokens;
 
 use crate::{
-  codegen::{get_intermediate_ident, js_mod_to_token_stream},
+  codegen::{
+    export_origin_to_token_stream, get_intermediate_ident, js_mod_to_token_stream,
+    module_member_to_token_stream,
+  },
   BindgenResult, FnKind, NapiImpl, NapiStruct, NapiStructKind, TryToTokens,
 };
-use crate::{NapiClass, NapiObject, NapiStructuredEnum};
+use crate::{NapiClass, NapiErrorClass, NapiObject, NapiStructuredEnum};
 
 static NAPI_IMPL_ID: AtomicU32 = AtomicU32::new(0);
 const TYPED_ARRAY_TYPE: &[&str] = &[
@@ -113,6 +116,7 @@ fn gen_napi_value_map_impl(
       ) -> napi::bindgen_prelude::Result<&'static Self> {
         let mut wrapped_val: *mut std::ffi::c_void = std::ptr::null_mut();
 
+        napi::bindgen_prelude::check_class_instance::<#name>(env, napi_val)?;
         napi::bindgen_prelude::check_status!(
           napi::bindgen_prelude::sys::napi_unwrap(env, napi_val, &mut wrapped_val),
           "Failed to recover `{}` type from napi value",
@@ -131,6 +135,7 @@ fn gen_napi_value_map_impl(
       ) -> napi::bindgen_prelude::Result<&'static mut Self> {
         let mut wrapped_val: *mut std::ffi::c_void = std::ptr::null_mut();
 
+        napi::bindgen_prelude::check_class_instance::<#name>(env, napi_val)?;
         napi::bindgen_prelude::check_status!(
           napi::bindgen_prelude::sys::napi_unwrap(env, napi_val, &mut wrapped_val),
           "Failed to recover `{}` type from napi value",
@@ -159,6 +164,7 @@ fn gen_napi_value_map_impl(
       ) -> napi::bindgen_prelude::Result<Self> {
         let mut wrapped_val: *mut std::ffi::c_void = std::ptr::null_mut();
 
+        napi::bindgen_prelude::check_class_instance::<#name>(env, napi_val)?;
         napi::bindgen_prelude::check_status!(
         napi::bindgen_prelude::sys::napi_unwrap(env, napi_val, &mut wrapped_val),
           "Failed to recover `{}` type from napi value",
@@ -215,6 +221,11 @@ impl NapiStruct {
     let register = self.gen_register(class);
 
     let getters_setters_token = getters_setters.into_iter().map(|(_, token)| token);
+    let json = if class.json {
+      self.gen_json_methods()
+    } else {
+      quote! {}
+    };
 
     quote! {
       #[allow(clippy::all)]
@@ -225,11 +236,48 @@ impl NapiStruct {
 
         #ctor
         #(#getters_setters_token)*
+        #json
         #register
       }
     }
   }
 
+  fn gen_json_methods(&self) -> TokenStream {
+    let name = &self.name;
+
+    quote! {
+      extern "C" fn from_json(
+        env: napi::bindgen_prelude::sys::napi_env,
+        cb: napi::bindgen_prelude::sys::napi_callback_info
+      ) -> napi::bindgen_prelude::sys::napi_value {
+        napi::bindgen_prelude::CallbackInfo::<1>::new(env, cb, Some(1), false)
+          .and_then(|cb| {
+            let json = unsafe { <napi::bindgen_prelude::Unknown as napi::bindgen_prelude::FromNapiValue>::from_napi_value(env, cb.get_arg(0)) }?;
+            let val: #name = napi::bindgen_prelude::Env::from_raw(env).from_js_value(json)?;
+            unsafe { <#name as napi::bindgen_prelude::ToNapiValue>::to_napi_value(env, val) }
+          })
+          .unwrap_or_else(|e| {
+            unsafe { napi::bindgen_prelude::JsError::from(e).throw_into(env) };
+            std::ptr::null_mut::<napi::bindgen_prelude::sys::napi_value__>()
+          })
+      }
+
+      extern "C" fn to_json(
+        env: napi::bindgen_prelude::sys::napi_env,
+        cb: napi::bindgen_prelude::sys::napi_callback_info
+      ) -> napi::bindgen_prelude::sys::napi_value {
+        napi::bindgen_prelude::CallbackInfo::<0>::new(env, cb, Some(0), false)
+          .and_then(|mut cb| unsafe { cb.unwrap_borrow::<#name>() })
+          .and_then(|obj| napi::bindgen_prelude::Env::from_raw(env).to_js_value(obj))
+          .map(|json| unsafe { napi::NapiRaw::raw(&json) })
+          .unwrap_or_else(|e| {
+            unsafe { napi::bindgen_prelude::JsError::from(e).throw_into(env) };
+            std::ptr::null_mut::<napi::bindgen_prelude::sys::napi_value__>()
+          })
+      }
+    }
+  }
+
   fn gen_default_ctor(&self, class: &NapiClass) -> TokenStream {
     let name = &self.name;
     let js_name_str = &self.js_name;
@@ -292,6 +340,7 @@ impl NapiStruct {
       NapiStructKind::StructuredEnum(structured_enum) => {
         self.gen_to_napi_value_structured_enum_impl(structured_enum)
       }
+      NapiStructKind::Error(error) => self.gen_error_class_impl(error),
     }
   }
 
@@ -734,7 +783,29 @@ impl NapiStruct {
         Span::call_site(),
       );
 
-      if field.getter {
+      if field.getter && field.borrow {
+        getters_setters.push((
+          field.js_name.clone(),
+          quote! {
+            extern "C" fn #getter_name(
+              env: napi::bindgen_prelude::sys::napi_env,
+              cb: napi::bindgen_prelude::sys::napi_callback_info
+            ) -> napi::bindgen_prelude::sys::napi_value {
+              napi::bindgen_prelude::CallbackInfo::<0>::new(env, cb, Some(0), false)
+                .and_then(|mut cb| {
+                  let obj = cb.unwrap_borrow::<#struct_name>()?;
+                  unsafe {
+                    <#ty as napi::bindgen_prelude::BorrowedToNapiValue>::to_napi_value_borrowed(env, &obj.#field_ident)
+                  }
+                })
+                .unwrap_or_else(|e| {
+                  unsafe { napi::bindgen_prelude::JsError::from(e).throw_into(env) };
+                  std::ptr::null_mut::<napi::bindgen_prelude::sys::napi_value__>()
+                })
+            }
+          },
+        ));
+      } else if field.getter {
         let default_to_napi_value_convert = quote! {
           let val = obj.#field_ident.to_owned();
           unsafe { <#ty as napi::bindgen_prelude::ToNapiValue>::to_napi_value(env, val) }
@@ -781,6 +852,16 @@ impl NapiStruct {
       }
 
       if field.setter {
+        let assign = if class.use_set_hook {
+          let js_name = &field.js_name;
+          quote! {
+            napi::bindgen_prelude::ObjectSetHook::before_set(obj, #js_name, &val)?;
+            obj.#field_ident = val;
+            napi::bindgen_prelude::ObjectSetHook::after_set(obj, #js_name);
+          }
+        } else {
+          quote! { obj.#field_ident = val; }
+        };
         getters_setters.push((
           field.js_name.clone(),
           quote! {
@@ -794,7 +875,7 @@ impl NapiStruct {
                     .and_then(|obj| {
                       <#ty as napi::bindgen_prelude::FromNapiValue>::from_napi_value(env, cb_info.get_arg(0))
                         .and_then(move |val| {
-                          obj.#field_ident = val;
+                          #assign
                           <() as napi::bindgen_prelude::ToNapiValue>::to_napi_value(env, ())
                         })
                     })
@@ -868,7 +949,29 @@ impl NapiStruct {
 
       props.push(prop);
     }
+
+    if class.json {
+      let attribute = super::PROPERTY_ATTRIBUTE_DEFAULT
+        | super::PROPERTY_ATTRIBUTE_WRITABLE
+        | super::PROPERTY_ATTRIBUTE_CONFIGURABLE;
+      props.push(quote! {
+        napi::bindgen_prelude::Property::new("fromJSON")
+          .unwrap()
+          .with_method(from_json)
+          .with_property_attributes(napi::bindgen_prelude::PropertyAttributes::Static)
+      });
+      props.push(quote! {
+        napi::bindgen_prelude::Property::new("toJSON")
+          .unwrap()
+          .with_property_attributes(napi::bindgen_prelude::PropertyAttributes::from_bits(#attribute).unwrap())
+          .with_method(to_json)
+      });
+    }
     let js_mod_ident = js_mod_to_token_stream(self.js_mod.as_ref());
+    let module_member =
+      module_member_to_token_stream(self.module.as_ref(), &js_mod_ident, &js_name);
+    let export_origin =
+      export_origin_to_token_stream("struct", &self.name, &js_mod_ident, &js_name);
     quote! {
       #[allow(non_snake_case)]
       #[allow(clippy::all)]
@@ -876,6 +979,8 @@ impl NapiStruct {
       #[napi::bindgen_prelude::ctor]
       fn #struct_register_name() {
         napi::__private::register_class(std::any::TypeId::of::<#name>(), #js_mod_ident, #js_name, vec![#(#props),*]);
+        #module_member
+        #export_origin
       }
 
       #[allow(non_snake_case)]
@@ -884,6 +989,140 @@ impl NapiStruct {
       #[no_mangle]
       extern "C" fn #struct_register_name() {
         napi::__private::register_class(std::any::TypeId::of::<#name>(), #js_mod_ident, #js_name, vec![#(#props),*]);
+        #module_member
+        #export_origin
+      }
+    }
+  }
+
+  fn gen_error_class_impl(&self, error: &NapiErrorClass) -> TokenStream {
+    let name = &self.name;
+    let name_str = self.name.to_string();
+    let js_name = &self.js_name;
+    let js_name_lit = format!("{}\0", self.js_name);
+    let register_name = &self.register_name;
+
+    let mut field_destructions = vec![];
+    let mut field_setters = vec![];
+    // the `message` field is the message of the error, `Display` is used without it
+    let mut message_field = None;
+    for field in error.fields.iter() {
+      let syn::Member::Named(ident) = &field.name else {
+        continue;
+      };
+      let alias_ident = format_ident!("{}_", ident);
+      field_destructions.push(quote! { #ident: #alias_ident });
+      let field_js_name = &field.js_name;
+      if field_js_name == "message" {
+        message_field = Some(alias_ident);
+        continue;
+      }
+      if !field.getter {
+        continue;
+      }
+      let is_optional_field = matches!(
+        &field.ty,
+        syn::Type::Path(syn::TypePath { path: syn::Path { segments, .. }, .. })
+          if segments.last().is_some_and(|segment| segment.ident == "Option")
+      );
+      field_setters.push(if is_optional_field {
+        quote! {
+          if let Some(#alias_ident) = #alias_ident {
+            error = error.with_field(#field_js_name, #alias_ident);
+          }
+        }
+      } else {
+        quote! {
+          error = error.with_field(#field_js_name, #alias_ident);
+        }
+      });
+    }
+
+    let (display_message, field_message) = match message_field {
+      Some(message) => (quote! {}, quote! { let message = #message; }),
+      None => (
+        quote! { let message = ::std::string::ToString::to_string(&val); },
+        quote! {},
+      ),
+    };
+
+    let callback_name = Ident::new(
+      &format!("__register__error__{}_callback__", name_str),
+      Span::call_site(),
+    );
+    let js_mod_ident = js_mod_to_token_stream(self.js_mod.as_ref());
+    let module_member =
+      module_member_to_token_stream(self.module.as_ref(), &js_mod_ident, &js_name_lit);
+    let export_origin =
+      export_origin_to_token_stream("struct", &self.name, &js_mod_ident, &js_name_lit);
+
+    quote! {
+      #[automatically_derived]
+      impl napi::bindgen_prelude::ErrorClass for #name {
+        const NAME: &'static str = #js_name;
+      }
+
+      #[automatically_derived]
+      impl From<#name> for napi::bindgen_prelude::JsErrorValue {
+        #[allow(unused_variables)]
+        fn from(val: #name) -> Self {
+          #display_message
+          let #name { #(#field_destructions),* } = val;
+          #field_message
+          #[allow(unused_mut)]
+          let mut error = napi::bindgen_prelude::JsErrorValue::new::<#name>(message);
+          #(#field_setters)*
+          error
+        }
+      }
+
+      #[automatically_derived]
+      impl From<#name> for napi::bindgen_prelude::Error {
+        fn from(val: #name) -> Self {
+          napi::bindgen_prelude::JsErrorValue::from(val).into()
+        }
+      }
+
+      #[automatically_derived]
+      impl napi::bindgen_prelude::TypeName for #name {
+        fn type_name() -> &'static str {
+          #name_str
+        }
+
+        fn value_type() -> napi::ValueType {
+          napi::ValueType::Object
+        }
+      }
+
+      #[automatically_derived]
+      impl napi::bindgen_prelude::ToNapiValue for #name {
+        unsafe fn to_napi_value(env: napi::bindgen_prelude::sys::napi_env, val: #name) -> napi::bindgen_prelude::Result<napi::bindgen_prelude::sys::napi_value> {
+          napi::bindgen_prelude::JsErrorValue::from(val).into_js_error(env)
+        }
+      }
+
+      #[allow(non_snake_case)]
+      #[allow(clippy::all)]
+      unsafe fn #callback_name(env: napi::bindgen_prelude::sys::napi_env) -> napi::bindgen_prelude::Result<napi::bindgen_prelude::sys::napi_value> {
+        napi::bindgen_prelude::error_class::<#name>(env)
+      }
+      #[allow(non_snake_case)]
+      #[allow(clippy::all)]
+      #[cfg(all(not(test), not(target_family = "wasm")))]
+      #[napi::bindgen_prelude::ctor]
+      fn #register_name() {
+        napi::bindgen_prelude::register_module_export(#js_mod_ident, #js_name_lit, #callback_name);
+        #module_member
+        #export_origin
+      }
+      #[allow(non_snake_case)]
+      #[allow(clippy::all)]
+      #[cfg(all(not(test), target_family = "wasm"))]
+      #[no_mangle]
+      extern "C" fn #register_name() {
+        napi::bindgen_prelude::register_module_export(#js_mod_ident, #js_name_lit, #callback_name);
+        #module_member
+        #export_origin
       }
     }
   }
@@ -1124,7 +1363,8 @@ impl NapiImpl {
       let js_name = Literal::string(&item.js_name);
       let item_str = item.name.to_string();
       let intermediate_name = get_intermediate_ident(&item_str);
-      methods.push(item.try_to_token_stream()?);
+      let cfgs = super::cfg_attrs(&item.attrs);
+      methods.push(super::with_cfg(item.try_to_token_stream()?, &cfgs));
 
       let mut attribute = super::PROPERTY_ATTRIBUTE_DEFAULT;
       if item.writable {
@@ -1137,10 +1377,15 @@ impl NapiImpl {
         attribute |= super::PROPERTY_ATTRIBUTE_CONFIGURABLE;
       }
 
-      let prop = props.entry(&item.js_name).or_insert_with(|| {
-        quote! {
-          napi::bindgen_prelude::Property::new(#js_name).unwrap().with_property_attributes(napi::bindgen_prelude::PropertyAttributes::from_bits(#attribute).unwrap())
-        }
+      // the same property can be implemented once per target
+      let cfg_key = quote! { #(#cfgs)* }.to_string();
+      let (prop, _) = props.entry((&item.js_name, cfg_key)).or_insert_with(|| {
+        (
+          quote! {
+            napi::bindgen_prelude::Property::new(#js_name).unwrap().with_property_attributes(napi::bindgen_prelude::PropertyAttributes::from_bits(#attribute).unwrap())
+          },
+          cfgs.clone(),
+        )
       });
 
       let appendix = match item.kind {
@@ -1160,8 +1405,11 @@ impl NapiImpl {
     }
 
     let mut props: Vec<_> = props.into_iter().collect();
-    props.sort_by_key(|(_, prop)| prop.to_string());
-    let props = props.into_iter().map(|(_, prop)| prop);
+    props.sort_by_key(|(_, (prop, _))| prop.to_string());
+    let props = props
+      .into_iter()
+      .map(|(_, (prop, cfgs))| quote! { #(#cfgs)* props.push(#prop); })
+      .collect::<Vec<_>>();
     let props_wasm = props.clone();
     let js_mod_ident = js_mod_to_token_stream(self.js_mod.as_ref());
     Ok(quote! {
@@ -1174,13 +1422,17 @@ impl NapiImpl {
         #[cfg(all(not(test), not(target_family = "wasm")))]
         #[napi::bindgen_prelude::ctor]
         fn #register_name() {
-          napi::__private::register_class(std::any::TypeId::of::<#name>(), #js_mod_ident, #js_name, vec![#(#props),*]);
+          let mut props = vec![];
+          #(#props)*
+          napi::__private::register_class(std::any::TypeId::of::<#name>(), #js_mod_ident, #js_name, props);
         }
 
         #[cfg(all(not(test), target_family = "wasm"))]
         #[no_mangle]
         extern "C" fn #register_name() {
-          napi::__private::register_class(std::any::TypeId::of::<#name>(), #js_mod_ident, #js_name, vec![#(#props_wasm),*]);
+          let mut props = vec![];
+          #(#props_wasm)*
+          napi::__private::register_class(std::any::TypeId::of::<#name>(), #js_mod_ident, #js_name, props);
         }
       }
     })
@@ -93,6 +93,7 @@ pub(crate) static PRIMITIVE_TYPES: &[(&str, (&str, bool, bool))] = &[
   ("isize", ("bigint", false, false)),
   ("JsBigInt", ("bigint", false, false)),
   ("BigInt", ("bigint", false, false)),
+  ("BigUint", ("bigint", false, false)),
   ("JsBoolean", ("boolean", false, false)),
   ("bool", ("boolean", false, false)),
   ("JsString", ("string", false, false)),
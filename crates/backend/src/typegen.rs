@@ -9,6 +9,7 @@ use std::{
 mod r#const;
 mod r#enum;
 mod r#fn;
+mod snapshot;
 pub(crate) mod r#struct;
 
 use syn::{PathSegment, Type, TypePath, TypeSlice};
@@ -29,6 +30,8 @@ pub struct TypeDef {
   pub def: String,
   pub js_mod: Option<String>,
   pub js_doc: String,
+  /// `#[napi(reflect)]` enums, declared with their `values()` and `nameOf(value)`
+  pub reflect: bool,
 }
 
 thread_local! {
@@ -116,6 +119,11 @@ impl Display for TypeDef {
     } else {
       "".to_string()
     };
+    let reflect = if self.reflect {
+      ", \"reflect\": true"
+    } else {
+      ""
+    };
     // TODO: remove this in v3
     // This is a workaround for lower version of @napi-rs/cli
     // See https://github.com/napi-rs/napi-rs/pull/1531
@@ -126,7 +134,7 @@ impl Display for TypeDef {
     };
     write!(
       f,
-      r#"{}{{"kind": "{}", "name": "{}", "js_doc": "{}", "def": "{}"{}{}}}"#,
+      r#"{}{{"kind": "{}", "name": "{}", "js_doc": "{}", "def": "{}"{}{}{}}}"#,
       prefix,
       self.kind,
       self.name,
@@ -134,6 +142,7 @@ impl Display for TypeDef {
       escape_json(&self.def),
       original_name,
       js_mod,
+      reflect,
     )
   }
 }
@@ -165,19 +174,30 @@ static KNOWN_TYPES: LazyLock<HashMap<&'static str, (&'static str, bool, bool)>>
     ("Int16Array", ("Int16Array", false, false)),
     ("Uint16Array", ("Uint16Array", false, false)),
     ("Int32Array", ("Int32Array", false, false)),
+    ("CancelFlag", ("Int32Array", false, false)),
     ("Uint32Array", ("Uint32Array", false, false)),
     ("Float32Array", ("Float32Array", false, false)),
     ("Float64Array", ("Float64Array", false, false)),
     ("BigInt64Array", ("BigInt64Array", false, false)),
     ("BigUint64Array", ("BigUint64Array", false, false)),
+    ("BigInt64Slice", ("BigInt64Array", false, false)),
+    ("BigUint64Slice", ("BigUint64Array", false, false)),
     ("DataView", ("DataView", false, false)),
+    ("JsDataView", ("DataView", false, false)),
     ("DateTime", ("Date", false, false)),
     ("NaiveDateTime", ("Date", false ,false)),
     ("Date", ("Date", false, false)),
     ("JsDate", ("Date", false, false)),
+    ("RegExp", ("RegExp", false, false)),
+    ("JsRegExp", ("RegExp", false, false)),
+    ("JsMap", ("Map<unknown, unknown>", false, false)),
+    ("JsSet", ("Set<unknown>", false, false)),
     ("JsBuffer", ("Buffer", false, false)),
     ("BufferSlice", ("Buffer", false, false)),
     ("Buffer", ("Buffer", false, false)),
+    ("Blob", ("Blob", false, false)),
+    ("BlobBuilder", ("Blob", false, false)),
+    ("File", ("File", false, false)),
     ("Vec", ("Array<{}>", false, false)),
     ("Result", ("Error | {}", false, true)),
     ("Error", ("Error", false, false)),
@@ -215,9 +235,16 @@ static KNOWN_TYPES: LazyLock<HashMap<&'static str, (&'static str, bool, bool)>>
     ("external", ("object", false, false)),
     ("Promise", ("Promise<{}>", false, false)),
     ("PromiseRaw", ("Promise<{}>", false, false)),
+    ("AsyncGenerator", ("AsyncGenerator<{}, void, unknown>", false, false)),
     ("AbortSignal", ("AbortSignal", false, false)),
+    ("Request", ("Request", false, false)),
+    ("Response", ("Response", false, false)),
+    ("ResponseBuilder", ("Response", false, false)),
+    ("Transform", ("import('node:stream').Transform", false, false)),
     ("JsGlobal", ("typeof global", false, false)),
     ("External", ("ExternalObject<{}>", false, false)),
+    ("Finite", ("{}", false, false)),
+    ("VTable", ("ExternalObject<'{}'>", false, false)),
     ("unknown", ("unknown", false, false)),
     ("Unknown", ("unknown", false, false)),
     ("UnknownReturnValue", ("unknown", false, false)),
@@ -250,6 +277,29 @@ fn fill_ty(template: &str, args: Vec<String>) -> String {
   ret
 }
 
+/// Whether the crate types `External<T>` as `ExternalObject<'T'>`, see
+/// `napi_build::branded_externals`
+fn branded_externals() -> bool {
+  std::env::var("NAPI_RS_BRANDED_EXTERNALS").is_ok_and(|branded| branded == "1")
+}
+
+/// The Rust type wrapped by `External<T>`, like `Database` or `Vec<u8>`
+fn external_brand(arguments: &syn::PathArguments) -> Option<String> {
+  let syn::PathArguments::AngleBracketed(arguments) = arguments else {
+    return None;
+  };
+  arguments.args.iter().find_map(|arg| match arg {
+    syn::GenericArgument::Type(ty) => Some(
+      quote::ToTokens::to_token_stream(ty)
+        .to_string()
+        .chars()
+        .filter(|c| !c.is_whitespace() && *c != '\'')
+        .collect(),
+    ),
+    _ => None,
+  })
+}
+
 fn is_ts_union_type(rust_ty: &str) -> bool {
   KNOWN_TYPES
     .get(rust_ty)
@@ -260,6 +310,8 @@ fn is_ts_union_type(rust_ty: &str) -> bool {
 const TSFN_RUST_TY: &str = "ThreadsafeFunction";
 const FUNCTION_TY: &str = "Function";
 const FUNCTION_REF_TY: &str = "FunctionRef";
+/// Arrays up to this length are typed as tuples
+const MAX_TUPLE_ARRAY_LEN: usize = 16;
 
 fn is_generic_function_type(rust_ty: &str) -> bool {
   rust_ty == TSFN_RUST_TY || rust_ty == FUNCTION_TY || rust_ty == FUNCTION_REF_TY
@@ -384,6 +436,12 @@ pub fn ty_to_ts_type(
               true,
             )
           });
+        } else if rust_ty == "Range" || rust_ty == "RangeInclusive" {
+          let bound = args
+            .first()
+            .map(|(arg, _)| arg.clone())
+            .unwrap_or_else(|| "number".to_owned());
+          ts_ty = Some((format!("{{ start: {bound}; end: {bound} }}"), false));
         } else if rust_ty == "AsyncTask" {
           ts_ty = r#struct::TASK_STRUCTS.with(|t| {
             let (output_type, _) = args.first().unwrap().to_owned();
@@ -393,7 +451,10 @@ pub fn ty_to_ts_type(
               Some(("Promise<unknown>".to_owned(), false))
             }
           });
-        } else if rust_ty == "Reference" || rust_ty == "WeakReference" {
+        } else if rust_ty == "Reference"
+          || rust_ty == "WeakReference"
+          || rust_ty == "PooledInstance"
+        {
           ts_ty = r#struct::TASK_STRUCTS.with(|t| {
             // Reference<T> => T
             if let Some(arg) = args.first() {
@@ -408,6 +469,14 @@ pub fn ty_to_ts_type(
               Some((rust_ty, false))
             }
           });
+        } else if rust_ty == "External" && branded_externals() {
+          // branded by the Rust type, so externals of different types can't be mixed up
+          ts_ty = Some((
+            external_brand(arguments)
+              .map(|brand| format!("ExternalObject<'{brand}'>"))
+              .unwrap_or_else(|| "ExternalObject<unknown>".to_owned()),
+            false,
+          ));
         } else if let Some(&(known_ty, _, _)) = KNOWN_TYPES.get(rust_ty.as_str()) {
           if rust_ty == "()" && is_return_ty {
             ts_ty = Some(("void".to_owned(), false));
@@ -469,7 +538,21 @@ pub fn ty_to_ts_type(
     Type::Array(a) => {
       let (element_type, is_optional) =
         ty_to_ts_type(&a.elem, is_return_ty, is_struct_field, false);
-      (format!("{}[]", element_type), is_optional)
+      // `[T; 3]` is a tuple of 3 `T`, longer or generic lengths fall back to `T[]`
+      let len = match &a.len {
+        syn::Expr::Lit(syn::ExprLit {
+          lit: syn::Lit::Int(len),
+          ..
+        }) => len.base10_parse::<usize>().ok(),
+        _ => None,
+      };
+      match len {
+        Some(len) if len <= MAX_TUPLE_ARRAY_LEN => (
+          format!("[{}]", vec![element_type; len].join(", ")),
+          is_optional,
+        ),
+        _ => (format!("{}[]", element_type), is_optional),
+      }
     }
     Type::Paren(p) => {
       let (element_type, is_optional) =
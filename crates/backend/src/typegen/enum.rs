@@ -20,6 +20,7 @@ impl ToTypeDef for NapiEnum {
       def: self.gen_ts_variants(),
       js_doc: js_doc_from_comments(&self.comments),
       js_mod: self.js_mod.to_owned(),
+      reflect: self.reflect,
     })
   }
 }
@@ -1,7 +1,7 @@
 use convert_case::{Case, Casing};
 use quote::ToTokens;
 use std::fmt::{Display, Formatter};
-use syn::{Member, Pat, PathArguments, PathSegment};
+use syn::{GenericArgument, Member, Pat, PathArguments, PathSegment, Type};
 
 use super::{r#struct::CLASS_STRUCTS, ty_to_ts_type, ToTypeDef, TypeDef};
 use crate::{js_doc_from_comments, CallbackArg, FnKind, NapiFn};
@@ -98,6 +98,7 @@ impl ToTypeDef for NapiFn {
       def,
       js_mod: self.js_mod.to_owned(),
       js_doc: js_doc_from_comments(&self.comments),
+      reflect: false,
     })
   }
 }
@@ -125,6 +126,25 @@ fn gen_callback_type(callback: &CallbackArg) -> String {
   )
 }
 
+/// `Vec<i64>` and `Vec<u64>` arguments also accept the BigInt TypedArrays
+fn bigint_typed_array_of_vec(ty: &Type) -> Option<&'static str> {
+  let Type::Path(path) = ty else {
+    return None;
+  };
+  let segment = path.path.segments.last()?;
+  if segment.ident != "Vec" {
+    return None;
+  }
+  let PathArguments::AngleBracketed(args) = &segment.arguments else {
+    return None;
+  };
+  match args.args.first()? {
+    GenericArgument::Type(Type::Path(elem)) if elem.path.is_ident("i64") => Some("BigInt64Array"),
+    GenericArgument::Type(Type::Path(elem)) if elem.path.is_ident("u64") => Some("BigUint64Array"),
+    _ => None,
+  }
+}
+
 fn gen_ts_func_arg(pat: &Pat) -> String {
   match pat {
     Pat::Struct(s) => format!(
@@ -253,7 +273,10 @@ impl NapiFn {
               i.mutability = None;
             }
 
-            let (ts_type, is_optional) = ty_to_ts_type(&path.ty, false, false, false);
+            let (mut ts_type, is_optional) = ty_to_ts_type(&path.ty, false, false, false);
+            if let Some(typed_array) = bigint_typed_array_of_vec(&path.ty) {
+              ts_type = format!("{ts_type} | {typed_array}");
+            }
             let ts_type = arg.use_overridden_type_or(|| ts_type);
             let arg = gen_ts_func_arg(&path.pat);
             Some(FnArg {
@@ -320,6 +343,9 @@ impl NapiFn {
             "void".to_owned()
           } else if ts_type == "Self" {
             "this".to_owned()
+          } else if self.borrow && ts_type == "Uint8Array" {
+            // borrowed bytes are handed out as a `Buffer` copy
+            "Buffer".to_owned()
           } else {
             ts_type
           }
@@ -327,7 +353,7 @@ impl NapiFn {
           "void".to_owned()
         };
 
-        if self.is_async {
+        if self.is_async || self.blocking {
           format!(": Promise<{}>", ret)
         } else {
           format!(": {}", ret)
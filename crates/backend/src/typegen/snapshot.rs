@@ -0,0 +1,135 @@
+use proc_macro2::{Ident, TokenStream};
+
+use super::{escape_json, js_doc_from_comments, ToTypeDef, TypeDef};
+use crate::{Napi, NapiItem};
+
+impl TypeDef {
+  /// The declaration written into the `.d.ts` by the CLI, without the re-indentation
+  pub fn to_ts(&self) -> String {
+    let def = unescape_json(&escape_json(&self.def)).trim().to_owned();
+    let name = &self.name;
+    let declaration = match self.kind.as_str() {
+      "interface" => format!("export interface {name} {{\n{def}\n}}"),
+      "type" => format!("export type {name} = \n{def}"),
+      "enum" | "string_enum" if self.reflect => format!(
+        "export declare enum {name} {{\n{def}\n}}\nexport declare namespace {name} {{\n  function values(): Array<{name}>\n  function nameOf(value: unknown): string | undefined\n}}"
+      ),
+      "enum" | "string_enum" => format!("export declare const enum {name} {{\n{def}\n}}"),
+      "struct" => format!("export declare class {name} {{\n{def}\n}}"),
+      "error" => format!("export declare class {name} extends Error {{\n{def}\n}}"),
+      "fn" => format!("export declare {def}"),
+      _ => def,
+    };
+    format!("{}{}", self.js_doc, declaration)
+  }
+}
+
+impl Napi {
+  /// `#[cfg(test)]` constants holding the TypeScript declaration of the item, and of each method
+  /// for `impl` blocks, so crates can snapshot their typings in `cargo test`.
+  ///
+  /// They are read with `napi_derive::type_def!(path::to::item)`.
+  pub fn gen_type_def_consts(&self) -> TokenStream {
+    match &self.item {
+      NapiItem::Impl(napi_impl) => {
+        let consts = napi_impl
+          .items
+          .iter()
+          .filter(|f| !f.skip_typescript)
+          .filter_map(|f| {
+            let def = f.to_type_def()?;
+            let ts = format!(
+              "{}{}",
+              js_doc_from_comments(&f.comments),
+              unescape_json(&escape_json(&def.def)).trim()
+            );
+            let cfgs = crate::cfg_attrs(&f.attrs);
+            let type_def = type_def_const(&f.name, &ts, true);
+            Some(quote! { #(#cfgs)* #type_def })
+          })
+          .collect::<Vec<_>>();
+        if consts.is_empty() {
+          return TokenStream::new();
+        }
+        let name = &napi_impl.name;
+        let self_ty = if napi_impl.has_lifetime {
+          quote! { #name<'_> }
+        } else {
+          quote! { #name }
+        };
+        quote! {
+          impl #self_ty {
+            #(#consts)*
+          }
+        }
+      }
+      item => {
+        let name = match item {
+          NapiItem::Fn(f) => &f.name,
+          NapiItem::Struct(s) => &s.name,
+          NapiItem::Enum(e) => &e.name,
+          NapiItem::Const(c) => &c.name,
+          NapiItem::Impl(_) => unreachable!(),
+        };
+        self
+          .to_type_def()
+          .map(|def| type_def_const(name, &def.to_ts(), false))
+          .unwrap_or_default()
+      }
+    }
+  }
+}
+
+fn type_def_const(name: &Ident, ts: &str, associated: bool) -> TokenStream {
+  // associated consts of impls with lifetimes can't elide it
+  let ty = if associated {
+    quote! { &'static str }
+  } else {
+    quote! { &str }
+  };
+  let const_name = format_ident!(
+    "__napi_type_def_{}",
+    name.to_string().trim_start_matches("r#")
+  );
+  quote! {
+    #[cfg(test)]
+    #[doc(hidden)]
+    #[allow(dead_code, non_upper_case_globals)]
+    pub(crate) const #const_name: #ty = #ts;
+  }
+}
+
+/// Reverse the JSON escaping, the `def`s contain escape sequences meant to be decoded by the CLI
+fn unescape_json(src: &str) -> String {
+  let mut unescaped = String::with_capacity(src.len());
+  let mut chars = src.chars();
+  while let Some(c) = chars.next() {
+    if c != '\\' {
+      unescaped.push(c);
+      continue;
+    }
+    match chars.next() {
+      Some('b') => unescaped.push('\x08'),
+      Some('f') => unescaped.push('\x0c'),
+      Some('n') => unescaped.push('\n'),
+      Some('r') => unescaped.push('\r'),
+      Some('t') => unescaped.push('\t'),
+      Some('u') => {
+        let code_unit = |chars: &mut std::str::Chars| {
+          let hex = chars.by_ref().take(4).collect::<String>();
+          u16::from_str_radix(&hex, 16).unwrap_or(0xfffd)
+        };
+        let high = code_unit(&mut chars);
+        let mut units = vec![high];
+        if (0xd800..0xdc00).contains(&high) && chars.as_str().starts_with("\\u") {
+          chars.nth(1);
+          units.push(code_unit(&mut chars));
+        }
+        unescaped.extend(char::decode_utf16(units).map(|c| c.unwrap_or('\u{fffd}')));
+      }
+      Some(c) => unescaped.push(c),
+      None => unescaped.push('\\'),
+    }
+  }
+  unescaped
+}
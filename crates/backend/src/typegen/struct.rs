@@ -24,12 +24,14 @@ impl ToTypeDef for NapiStruct {
         NapiStructKind::Class(_) => "struct",
         NapiStructKind::Object(_) => "interface",
         NapiStructKind::StructuredEnum(_) => "type",
+        NapiStructKind::Error(_) => "error",
       }),
       name: self.js_name.to_owned(),
       original_name: Some(self.name.to_string()),
       def: self.gen_ts_class(),
       js_mod: self.js_mod.to_owned(),
       js_doc: js_doc_from_comments(&self.comments),
+      reflect: false,
     })
   }
 }
@@ -75,6 +77,7 @@ impl ToTypeDef for NapiImpl {
         ),
         js_mod: self.js_mod.to_owned(),
         js_doc: "".to_string(),
+        reflect: false,
       })
     } else {
       Some(TypeDef {
@@ -100,6 +103,7 @@ impl ToTypeDef for NapiImpl {
           .join("\\n"),
         js_mod: self.js_mod.to_owned(),
         js_doc: "".to_string(),
+        reflect: false,
       })
     }
   }
@@ -151,11 +155,18 @@ impl NapiStruct {
           })
           .collect::<Vec<_>>()
           .join("\\n");
-        if class.ctor {
+        let mut def = if class.ctor {
           format!("{}\\nconstructor({})", def, ctor_args.join(", "))
         } else {
           def
+        };
+        if class.json {
+          def.push_str(&format!(
+            "\\nstatic fromJSON(json: unknown): {}\\ntoJSON(): unknown",
+            self.js_name
+          ));
         }
+        def
       }
       NapiStructKind::Object(object) => object
         .fields
@@ -164,6 +175,14 @@ impl NapiStruct {
         .filter_map(|f| self.gen_field(f).map(|(field, _)| field))
         .collect::<Vec<_>>()
         .join("\\n"),
+      // `message` is inherited from `Error`
+      NapiStructKind::Error(error) => error
+        .fields
+        .iter()
+        .filter(|f| f.getter && f.js_name != "message")
+        .filter_map(|f| self.gen_field(f).map(|(field, _)| field))
+        .collect::<Vec<_>>()
+        .join("\\n"),
       NapiStructKind::StructuredEnum(structured_enum) => structured_enum
         .variants
         .iter()
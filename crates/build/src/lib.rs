@@ -10,6 +10,8 @@ pub fn setup() {
   println!("cargo:rerun-if-env-changed=TYPE_DEF_TMP_PATH");
   println!("cargo:rerun-if-env-changed=CARGO_CFG_NAPI_RS_CLI_VERSION");
 
+  forward_cfg();
+
   match std::env::var("CARGO_CFG_TARGET_OS").as_deref() {
     Ok("macos") => {
       macos::setup();
@@ -26,3 +28,67 @@ pub fn setup() {
     _ => {}
   }
 }
+
+/// Prefix the JavaScript names of the top-level exports of the crate, its functions, classes,
+/// objects, enums and consts, so the exports of several addons can be merged into one namespace.
+///
+/// The prefix follows the case of each name, `export_prefix("native")` exports `fn sum` as
+/// `nativeSum`, `struct Animal` as `NativeAnimal` and `const DEFAULT_COST` as
+/// `NATIVE_DEFAULT_COST`. The names set with `#[napi(js_name)]` are prefixed too, the methods and
+/// the fields are not.
+///
+/// ```no_run
+/// // build.rs
+/// napi_build::setup();
+/// napi_build::export_prefix("native");
+/// ```
+pub fn export_prefix(prefix: &str) {
+  println!("cargo:rustc-env=NAPI_RS_EXPORT_PREFIX={prefix}");
+}
+
+/// Suffix the JavaScript names of the top-level exports of the crate, like [`export_prefix`]
+pub fn export_suffix(suffix: &str) {
+  println!("cargo:rustc-env=NAPI_RS_EXPORT_SUFFIX={suffix}");
+}
+
+/// Type `External<T>` as `ExternalObject<'T'>` in the TypeScript definitions, branded by the name
+/// of the Rust type, so an external of one type can't be passed where another is expected. By
+/// default it's typed as `ExternalObject<T>` with the TypeScript type of `T`.
+///
+/// ```no_run
+/// // build.rs
+/// napi_build::setup();
+/// napi_build::branded_externals();
+/// ```
+pub fn branded_externals() {
+  println!("cargo:rustc-env=NAPI_RS_BRANDED_EXTERNALS=1");
+}
+
+/// Forward the target configuration and the enabled features to `#[napi]`, so the TypeScript
+/// definitions of the items under `#[cfg]` match the target being built
+fn forward_cfg() {
+  let mut cfg = vec![];
+  let mut has_features = false;
+  for (key, value) in env::vars() {
+    let Some(name) = key.strip_prefix("CARGO_CFG_") else {
+      continue;
+    };
+    let name = name.to_lowercase();
+    has_features |= name == "feature";
+    if value.is_empty() {
+      cfg.push(name);
+    } else {
+      cfg.extend(value.split(',').map(|value| format!("{name}={value}")));
+    }
+  }
+  // `CARGO_CFG_FEATURE` is only set by recent Cargo
+  if !has_features {
+    cfg.extend(env::vars().filter_map(|(key, _)| {
+      key
+        .strip_prefix("CARGO_FEATURE_")
+        .map(|feature| format!("feature={}", feature.to_lowercase()))
+    }));
+  }
+  cfg.sort();
+  println!("cargo:rustc-env=NAPI_RS_CFG={}", cfg.join(";"));
+}
@@ -7,10 +7,10 @@ use std::io::{BufWriter, Write};
 #[cfg(feature = "type-def")]
 use std::sync::atomic::{AtomicBool, Ordering};
 
-use crate::parser::{attrs::BindgenAttrs, ParseNapi};
-use napi_derive_backend::{BindgenResult, TryToTokens};
+use crate::parser::{attrs::BindgenAttrs, item_attrs, ParseNapi};
+use napi_derive_backend::{cfg_attrs, with_cfg, BindgenResult, TryToTokens};
 #[cfg(feature = "type-def")]
-use napi_derive_backend::{Napi, ToTypeDef};
+use napi_derive_backend::{Napi, NapiItem, ToTypeDef};
 use proc_macro2::TokenStream;
 use quote::ToTokens;
 use syn::{Attribute, Item};
@@ -49,13 +49,18 @@ pub fn expand(attr: TokenStream, input: TokenStream) -> BindgenResult<TokenStrea
   let opts: BindgenAttrs = syn::parse2(attr)?;
   let mut tokens = proc_macro2::TokenStream::new();
   if let Item::Mod(mut js_mod) = item {
-    let js_name = opts.js_name().map_or_else(
-      || js_mod.ident.to_string(),
-      |(js_name, _)| js_name.to_owned(),
-    );
+    // `#[napi(auto)]` converts the plain structs and enums of the mod, it's not a namespace
+    let auto = opts.auto().is_some();
+    let js_name = (!auto).then(|| {
+      opts.js_name().map_or_else(
+        || js_mod.ident.to_string(),
+        |(js_name, _)| js_name.to_owned(),
+      )
+    });
     if let Some((_, mut items)) = js_mod.content.clone() {
       for item in items.iter_mut() {
         let mut empty_attrs = vec![];
+        let is_plain_type = matches!(item, Item::Struct(_) | Item::Enum(_));
         if let Some(item_opts) = replace_napi_attr_in_mod(
           js_name.clone(),
           match item {
@@ -81,16 +86,24 @@ pub fn expand(attr: TokenStream, input: TokenStream) -> BindgenResult<TokenStrea
             }
             _ => &mut empty_attrs,
           },
-        ) {
-          let napi = item.parse_napi(&mut tokens, &item_opts)?;
+        )
+        .or_else(|| (auto && is_plain_type).then(auto_attrs))
+        {
+          let mut item_tokens = TokenStream::new();
+          let napi = item.parse_napi(&mut item_tokens, &item_opts)?;
           item_opts.check_used()?;
-          napi.try_to_tokens(&mut tokens)?;
+          napi.try_to_tokens(&mut item_tokens)?;
 
           #[cfg(feature = "type-def")]
           {
-            output_type_def(&napi);
-            output_wasi_register_def(&napi);
+            if cfg_enabled(item_attrs(item)) {
+              output_type_def(&napi);
+              output_wasi_register_def(&napi);
+            }
+            item_tokens.extend(napi.gen_type_def_consts());
           }
+          // the items of a mod are not cfg-stripped before the expansion
+          tokens.extend(with_cfg(item_tokens, &cfg_attrs(item_attrs(item))));
         } else {
           item.to_tokens(&mut tokens);
         };
@@ -117,6 +130,7 @@ pub fn expand(attr: TokenStream, input: TokenStream) -> BindgenResult<TokenStrea
     {
       output_type_def(&napi);
       output_wasi_register_def(&napi);
+      tokens.extend(napi.gen_type_def_consts());
     }
     Ok(tokens)
   }
@@ -145,7 +159,19 @@ fn output_wasi_register_def(napi: &Napi) {
 #[cfg(feature = "type-def")]
 fn output_type_def(napi: &Napi) {
   if let Ok(type_def_file) = env::var("TYPE_DEF_TMP_PATH") {
-    if let Some(type_def) = napi.to_type_def() {
+    let type_def = match &napi.item {
+      // the methods of an impl are not cfg-stripped before the expansion
+      NapiItem::Impl(napi_impl) if napi_impl.items.iter().any(|f| !cfg_enabled(&f.attrs)) => {
+        let mut napi_impl = napi_impl.clone();
+        napi_impl.items.retain(|f| cfg_enabled(&f.attrs));
+        Napi {
+          item: NapiItem::Impl(napi_impl),
+        }
+        .to_type_def()
+      }
+      _ => napi.to_type_def(),
+    };
+    if let Some(type_def) = type_def {
       fs::OpenOptions::new()
         .append(true)
         .create(true)
@@ -163,8 +189,69 @@ fn output_type_def(napi: &Napi) {
   }
 }
 
+/// Evaluate the `#[cfg]`s of an item against the target configuration forwarded by
+/// `napi_build::setup()`, everything is enabled without it.
+#[cfg(feature = "type-def")]
+fn cfg_enabled(attrs: &[Attribute]) -> bool {
+  let Ok(cfg) = env::var("NAPI_RS_CFG") else {
+    return true;
+  };
+  let cfg = cfg
+    .split(';')
+    .map(|entry| match entry.split_once('=') {
+      Some((name, value)) => (name, Some(value)),
+      None => (entry, None),
+    })
+    .collect::<Vec<_>>();
+  attrs
+    .iter()
+    .filter(|attr| attr.path().is_ident("cfg"))
+    .all(|attr| {
+      attr
+        .parse_args::<syn::Meta>()
+        .map(|predicate| eval_cfg(&predicate, &cfg))
+        .unwrap_or(true)
+    })
+}
+
+#[cfg(feature = "type-def")]
+fn eval_cfg(predicate: &syn::Meta, cfg: &[(&str, Option<&str>)]) -> bool {
+  // Cargo reports the features with `_` in place of `-` in `CARGO_FEATURE_*`
+  let normalize = |value: &str| value.replace('-', "_");
+  match predicate {
+    syn::Meta::Path(path) => cfg
+      .iter()
+      .any(|(name, value)| path.is_ident(name) && value.is_none()),
+    syn::Meta::NameValue(name_value) => match &name_value.value {
+      syn::Expr::Lit(syn::ExprLit {
+        lit: syn::Lit::Str(expected),
+        ..
+      }) => cfg.iter().any(|(name, value)| {
+        name_value.path.is_ident(name) && value.map(normalize) == Some(normalize(&expected.value()))
+      }),
+      _ => true,
+    },
+    syn::Meta::List(list) => {
+      let Ok(predicates) = list.parse_args_with(
+        syn::punctuated::Punctuated::<syn::Meta, syn::Token![,]>::parse_terminated,
+      ) else {
+        return true;
+      };
+      if list.path.is_ident("all") {
+        predicates.iter().all(|p| eval_cfg(p, cfg))
+      } else if list.path.is_ident("any") {
+        predicates.iter().any(|p| eval_cfg(p, cfg))
+      } else if list.path.is_ident("not") {
+        !predicates.iter().all(|p| eval_cfg(p, cfg))
+      } else {
+        true
+      }
+    }
+  }
+}
+
 fn replace_napi_attr_in_mod(
-  js_namespace: String,
+  js_namespace: Option<String>,
   attrs: &mut Vec<syn::Attribute>,
 ) -> Option<BindgenAttrs> {
   let napi_attr = attrs
@@ -173,6 +260,11 @@ fn replace_napi_attr_in_mod(
     .find(|(_, m)| m.path().is_ident("napi"));
 
   if let Some((index, napi_attr)) = napi_attr {
+    let Some(js_namespace) = js_namespace else {
+      let item_opts = BindgenAttrs::try_from(napi_attr).unwrap();
+      attrs.remove(index);
+      return Some(item_opts);
+    };
     // adds `namespace = #js_namespace` into `#[napi]` attribute
     let new_attr = match &napi_attr.meta {
       syn::Meta::Path(_) => {
@@ -196,6 +288,12 @@ fn replace_napi_attr_in_mod(
   }
 }
 
+/// The options of a plain struct or enum in a `#[napi(auto)]` mod
+fn auto_attrs() -> BindgenAttrs {
+  let attr: syn::Attribute = syn::parse_quote!(#[napi(auto)]);
+  BindgenAttrs::try_from(&attr).unwrap()
+}
+
 #[cfg(feature = "type-def")]
 fn prepare_type_def_file() {
   if let Ok(ref type_def_file) = env::var("TYPE_DEF_TMP_PATH") {
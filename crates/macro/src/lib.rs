@@ -13,8 +13,9 @@ extern crate quote;
 use std::env;
 
 use proc_macro::TokenStream;
+use syn::parse_macro_input;
 #[cfg(feature = "compat-mode")]
-use syn::{fold::Fold, parse_macro_input, ItemFn};
+use syn::{fold::Fold, ItemFn};
 
 /// ```ignore
 /// #[napi]
@@ -39,6 +40,38 @@ pub fn napi(attr: TokenStream, input: TokenStream) -> TokenStream {
   }
 }
 
+/// The TypeScript declaration generated for a `#[napi]` item, as a `&str`.
+///
+/// Only available in `cargo test` builds with the `type-def` feature, so changes of the public
+/// typings can be caught by snapshot tests. Methods are referred to as `Struct::method`.
+///
+/// ```ignore
+/// #[napi]
+/// pub fn sum(a: u32, b: u32) -> u32 {
+///   a + b
+/// }
+///
+/// #[test]
+/// fn sum_typings() {
+///   assert_eq!(
+///     napi_derive::type_def!(sum),
+///     "export declare function sum(a: number, b: number): number"
+///   );
+/// }
+/// ```
+#[proc_macro]
+pub fn type_def(input: TokenStream) -> TokenStream {
+  let mut path = parse_macro_input!(input as syn::Path);
+  if let Some(last) = path.segments.last_mut() {
+    last.ident = format_ident!(
+      "__napi_type_def_{}",
+      last.ident.to_string().trim_start_matches("r#"),
+      span = last.ident.span()
+    );
+  }
+  quote! { #path }.into()
+}
+
 #[cfg(feature = "compat-mode")]
 #[proc_macro_attribute]
 pub fn contextless_function(_attr: TokenStream, input: TokenStream) -> TokenStream {
@@ -120,6 +153,7 @@ pub fn js_function(attr: TokenStream, input: TokenStream) -> TokenStream {
         debug_assert!(Status::from(status) == Status::Ok, "napi_get_cb_info failed");
       }
 
+      let _call_frame = napi::__private::CallFrame::enter();
       let mut env = unsafe { Env::from_raw(raw_env) };
       #[cfg(all(target_os = "windows", target_arch = "x86"))]
       let ctx = CallContext::new(&mut env, cb_info, raw_this, raw_args.as_slice(), argc);
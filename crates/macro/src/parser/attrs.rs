@@ -9,6 +9,7 @@ use napi_derive_backend::{bail_span, BindgenResult, Diagnostic};
 use proc_macro2::{Delimiter, Ident, Span, TokenTree};
 use quote::ToTokens;
 use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
 use syn::Attribute;
 
@@ -51,13 +52,21 @@ macro_rules! attrgen {
   ($mac:ident) => {
     $mac! {
       (catch_unwind, CatchUnwind(Span)),
+      (record, Record(Span)),
       (async_runtime, AsyncRuntime(Span)),
+      (thread, Thread(Span, String, Span)),
+      (memoize, Memoize(Span)),
+      (error, Error(Span, Option<Ident>)),
+      (overload, Overload(Span, String, Span)),
+      (auto, Auto(Span)),
+      (unstable, Unstable(Span)),
       (js_name, JsName(Span, String, Span)),
       (constructor, Constructor(Span)),
       (factory, Factory(Span)),
       (getter, Getter(Span, Option<Ident>)),
       (setter, Setter(Span, Option<Ident>)),
       (readonly, Readonly(Span)),
+      (borrow, Borrow(Span)),
       (enumerable, Enumerable(Span, Option<bool>), true),
       (writable, Writable(Span, Option<bool>), true),
       (configurable, Configurable(Span, Option<bool>), true),
@@ -68,13 +77,17 @@ macro_rules! attrgen {
       (object_from_js, ObjectFromJs(Span, Option<bool>), true),
       (object_to_js, ObjectToJs(Span, Option<bool>), true),
       (custom_finalize, CustomFinalize(Span)),
+      (set_hook, SetHook(Span)),
+      (json, Json(Span)),
       (namespace, Namespace(Span, String, Span)),
+      (module, Module(Span, String, Span)),
       (iterator, Iterator(Span)),
       (ts_args_type, TsArgsType(Span, String, Span)),
       (ts_return_type, TsReturnType(Span, String, Span)),
       (ts_type, TsType(Span, String, Span)),
       (ts_generic_types, TsGenericTypes(Span, String, Span)),
       (string_enum, StringEnum(Span, Option<(String, Span)>)),
+      (reflect, Reflect(Span)),
       (use_nullable, UseNullable(Span, Option<bool>), false),
       (discriminant, Discriminant(Span, String, Span)),
 
@@ -305,6 +318,60 @@ macro_rules! gen_bindgen_attr {
 
 attrgen!(gen_bindgen_attr);
 
+/// Evaluate a string attribute written with the macros `concat!`, `stringify!` or `env!`, like
+/// `js_name = concat!(env!("CARGO_CRATE_NAME"), "Version")`.
+///
+/// `env!` reads the environment of the compiler, Cargo doesn't rebuild the crate when the variable
+/// changes unless the build script prints `cargo:rerun-if-env-changed`.
+fn eval_str_macro(mac: &syn::Macro) -> syn::Result<String> {
+  let name = mac
+    .path
+    .get_ident()
+    .map(|ident| ident.to_string())
+    .unwrap_or_default();
+  match name.as_str() {
+    "concat" => {
+      let parts = mac.parse_body_with(Punctuated::<syn::Expr, Token![,]>::parse_terminated)?;
+      parts.iter().map(eval_str_expr).collect()
+    }
+    "stringify" => Ok(mac.tokens.to_string()),
+    "env" => {
+      let var = mac.parse_body::<syn::LitStr>()?;
+      std::env::var(var.value()).map_err(|_| {
+        syn::Error::new(
+          var.span(),
+          format!("environment variable `{}` not defined", var.value()),
+        )
+      })
+    }
+    _ => Err(syn::Error::new(
+      mac.span(),
+      "only `concat!`, `stringify!` and `env!` can be evaluated in #[napi] attributes",
+    )),
+  }
+}
+
+fn eval_str_expr(expr: &syn::Expr) -> syn::Result<String> {
+  match expr {
+    syn::Expr::Lit(syn::ExprLit { lit, .. }) => match lit {
+      syn::Lit::Str(lit) => Ok(lit.value()),
+      syn::Lit::Char(lit) => Ok(lit.value().to_string()),
+      syn::Lit::Int(lit) => Ok(lit.base10_digits().to_owned()),
+      syn::Lit::Float(lit) => Ok(lit.base10_digits().to_owned()),
+      syn::Lit::Bool(lit) => Ok(lit.value.to_string()),
+      _ => Err(syn::Error::new(
+        lit.span(),
+        "unsupported literal in `concat!`",
+      )),
+    },
+    syn::Expr::Macro(expr) => eval_str_macro(&expr.mac),
+    _ => Err(syn::Error::new(
+      expr.span(),
+      "expected a literal, `concat!`, `stringify!` or `env!`",
+    )),
+  }
+}
+
 pub fn record_struct(ident: &Ident, js_name: String, opts: &BindgenAttrs) {
   let state = STRUCTS.get_or_init(StructParseState::default);
   let mut map = state.parsed.lock().unwrap();
@@ -319,6 +386,15 @@ pub fn record_struct(ident: &Ident, js_name: String, opts: &BindgenAttrs) {
   );
 }
 
+/// The JavaScript name of a struct parsed already
+pub fn recorded_struct_js_name(ident: &Ident) -> Option<String> {
+  let state = STRUCTS.get_or_init(StructParseState::default);
+  let map = state.parsed.lock().unwrap();
+  map
+    .get(&ident.to_string())
+    .map(|parsed| parsed.js_name.clone())
+}
+
 pub fn check_recorded_struct_for_impl(ident: &Ident, opts: &BindgenAttrs) -> BindgenResult<String> {
   let state = STRUCTS.get_or_init(StructParseState::default);
   let mut map = state.parsed.lock().unwrap();
@@ -413,6 +489,10 @@ impl Parse for BindgenAttr {
           input.parse::<Token![=]>()?;
           let (val, span) = match input.parse::<syn::LitStr>() {
             Ok(str) => (str.value(), str.span()),
+            Err(_) if input.peek2(Token![!]) => {
+              let mac = input.parse::<syn::Macro>()?;
+              (eval_str_macro(&mac)?, mac.span())
+            }
             Err(_) => {
               let ident = input.parse::<AnyIdent>()?.0;
               (ident.to_string(), ident.span())
@@ -10,9 +10,9 @@ use attrs::BindgenAttrs;
 use convert_case::{Case, Casing};
 use napi_derive_backend::{
   rm_raw_prefix, BindgenResult, CallbackArg, Diagnostic, FnKind, FnSelf, Napi, NapiClass,
-  NapiConst, NapiEnum, NapiEnumValue, NapiEnumVariant, NapiFn, NapiFnArg, NapiFnArgKind, NapiImpl,
-  NapiItem, NapiObject, NapiStruct, NapiStructField, NapiStructKind, NapiStructuredEnum,
-  NapiStructuredEnumVariant,
+  NapiConst, NapiEnum, NapiEnumValue, NapiEnumVariant, NapiErrorClass, NapiFn, NapiFnArg,
+  NapiFnArgKind, NapiImpl, NapiItem, NapiObject, NapiStruct, NapiStructField, NapiStructKind,
+  NapiStructuredEnum, NapiStructuredEnumVariant,
 };
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::ToTokens;
@@ -24,12 +24,73 @@ use syn::{
   PathArguments, PathSegment, Signature, Type, Visibility,
 };
 
-use crate::parser::attrs::{check_recorded_struct_for_impl, record_struct};
+use crate::parser::attrs::{
+  check_recorded_struct_for_impl, record_struct, recorded_struct_js_name,
+};
 
 static GENERATOR_STRUCT: OnceLock<Mutex<HashMap<String, bool>>> = OnceLock::new();
+static SET_HOOK_STRUCT: OnceLock<Mutex<HashMap<String, bool>>> = OnceLock::new();
 
 static REGISTER_INDEX: AtomicUsize = AtomicUsize::new(0);
 
+pub fn item_attrs(item: &syn::Item) -> &[Attribute] {
+  match item {
+    syn::Item::Fn(f) => &f.attrs,
+    syn::Item::Struct(s) => &s.attrs,
+    syn::Item::Enum(e) => &e.attrs,
+    syn::Item::Const(c) => &c.attrs,
+    syn::Item::Impl(i) => &i.attrs,
+    _ => &[],
+  }
+}
+
+/// Add the crate-level prefix and suffix set with `napi_build::export_prefix` and
+/// `napi_build::export_suffix` to the name of a top-level export, keeping its case convention:
+/// `native` prefixes `sum` as `nativeSum`, `Animal` as `NativeAnimal` and `DEFAULT_COST` as
+/// `NATIVE_DEFAULT_COST`.
+fn exported_js_name(js_name: String) -> String {
+  let prefix = std::env::var("NAPI_RS_EXPORT_PREFIX").unwrap_or_default();
+  let suffix = std::env::var("NAPI_RS_EXPORT_SUFFIX").unwrap_or_default();
+  if prefix.is_empty() && suffix.is_empty() {
+    return js_name;
+  }
+  let is_constant = js_name.chars().any(|c| c.is_ascii_uppercase())
+    && !js_name.chars().any(|c| c.is_ascii_lowercase());
+  if is_constant {
+    // `nativeAddon` as `NATIVE_ADDON`, the digits stay with their word unlike `Case::UpperSnake`
+    let screaming = |part: &str| {
+      let mut screaming = String::new();
+      let mut after_word = false;
+      for c in part.chars() {
+        if c.is_ascii_uppercase() && after_word {
+          screaming.push('_');
+        }
+        after_word = c.is_ascii_lowercase() || c.is_ascii_digit();
+        screaming.push(c.to_ascii_uppercase());
+      }
+      screaming
+    };
+    return [screaming(&prefix), js_name, screaming(&suffix)]
+      .into_iter()
+      .filter(|part| !part.is_empty())
+      .collect::<Vec<_>>()
+      .join("_");
+  }
+  let capitalize = |part: &str| {
+    let mut chars = part.chars();
+    chars
+      .next()
+      .map(|first| first.to_uppercase().chain(chars).collect::<String>())
+      .unwrap_or_default()
+  };
+  let is_pascal = js_name.starts_with(|c: char| c.is_ascii_uppercase());
+  match (prefix.is_empty(), is_pascal) {
+    (true, _) => format!("{}{}", js_name, capitalize(&suffix)),
+    (false, true) => format!("{}{}{}", capitalize(&prefix), js_name, capitalize(&suffix)),
+    (false, false) => format!("{}{}{}", prefix, capitalize(&js_name), capitalize(&suffix)),
+  }
+}
+
 fn get_register_ident(name: &str) -> Ident {
   let new_name = format!(
     "__napi_register__{}_{}",
@@ -670,10 +731,22 @@ fn napi_fn_from_decl(
     } else if opts.constructor().is_some() {
       "constructor".to_owned()
     } else {
-      opts.js_name().map_or_else(
-        || ident.to_string().to_case(Case::Camel),
-        |(js_name, _)| js_name.to_owned(),
-      )
+      let js_name = match (opts.overload(), opts.js_name()) {
+        (Some(_), Some((_, span))) => {
+          return Err(Diagnostic::span_error(
+            span,
+            "#[napi(overload = \"...\")] is the name of the exported function, it can't be renamed by `js_name`",
+          ));
+        }
+        (Some((overload, _)), None) => overload.to_owned(),
+        (None, Some((js_name, _))) => js_name.to_owned(),
+        (None, None) => ident.to_string().to_case(Case::Camel),
+      };
+      if parent.is_none() {
+        exported_js_name(js_name)
+      } else {
+        js_name
+      }
     };
 
     let namespace = opts.namespace().map(|(m, _)| m.to_owned());
@@ -691,6 +764,18 @@ fn napi_fn_from_decl(
     } else {
       false
     };
+    let parent_has_set_hook = if let Some(p) = parent {
+      let set_hook_struct = SET_HOOK_STRUCT.get_or_init(|| Mutex::new(HashMap::new()));
+      let set_hook_struct = set_hook_struct.lock().expect("Lock set hook struct failed");
+
+      let key = namespace
+        .as_ref()
+        .map(|n| format!("{}::{}", n, p))
+        .unwrap_or_else(|| p.to_string());
+      *set_hook_struct.get(&key).unwrap_or(&false)
+    } else {
+      false
+    };
 
     let kind = fn_kind(opts);
 
@@ -705,6 +790,108 @@ fn napi_fn_from_decl(
       bail_span!(sig.ident, "Constructor don't support asynchronous function");
     }
 
+    // only setters notify the hook, other methods can't be told apart from reads
+    let parent_has_set_hook = parent_has_set_hook && matches!(kind, FnKind::Setter);
+
+    if opts.record().is_some() && asyncness.is_some() {
+      bail_span!(sig.ident, "#[napi(record)] only supports sync functions");
+    }
+
+    if opts.borrow().is_some()
+      && !(matches!(kind, FnKind::Getter)
+        && matches!(fn_self, Some(FnSelf::Ref))
+        && asyncness.is_none())
+    {
+      bail_span!(
+        sig.ident,
+        "#[napi(borrow)] only supports sync getters taking `&self`"
+      );
+    }
+
+    if opts.overload().is_some() && parent.is_some() {
+      bail_span!(
+        sig.ident,
+        "#[napi(overload)] only supports functions, not methods"
+      );
+    }
+
+    if opts.module().is_some() && parent.is_some() {
+      bail_span!(
+        sig.ident,
+        "#[napi(module)] can't be applied to methods, classes belong to the module of their struct"
+      );
+    }
+
+    let blocking = match opts.thread() {
+      Some((thread, span)) if thread != "blocking" => {
+        return Err(Diagnostic::span_error(
+          span,
+          format!(
+            "Unknown thread `{}`, only `thread = \"blocking\"` is supported",
+            thread
+          ),
+        ));
+      }
+      Some(_) => true,
+      None => false,
+    };
+
+    if blocking
+      && (asyncness.is_some()
+        || fn_self.is_some()
+        || !matches!(kind, FnKind::Normal)
+        || opts.async_runtime().is_some()
+        || opts.record().is_some())
+    {
+      bail_span!(
+        sig.ident,
+        "#[napi(thread = \"blocking\")] only supports sync functions and static methods, the arguments are moved to the thread pool"
+      );
+    }
+
+    if opts.memoize().is_some() {
+      if asyncness.is_some()
+        || fn_self.is_some()
+        || !matches!(kind, FnKind::Normal)
+        || blocking
+        || opts.record().is_some()
+      {
+        bail_span!(
+          sig.ident,
+          "#[napi(memoize)] only supports sync functions and static methods"
+        );
+      }
+      for arg in &args {
+        if let NapiFnArgKind::Callback(cb) = &arg.kind {
+          bail_span!(cb.pat, "#[napi(memoize)] can't memoize calls taking callbacks");
+        }
+      }
+    }
+
+    let error_class = match opts.error() {
+      Some(Some(error)) if !is_ret_result => {
+        bail_span!(
+          sig.ident,
+          "#[napi(error = {})] requires the function to return a `Result<T, {}>`",
+          error,
+          error
+        );
+      }
+      Some(Some(error)) => Some(error.clone()),
+      Some(None) => {
+        bail_span!(
+          sig.ident,
+          "#[napi(error)] declares an error class on a struct, use #[napi(error = MyError)] on functions returning `Result<T, MyError>`"
+        );
+      }
+      None => None,
+    };
+
+    let mut comments = extract_doc_comments(&attrs);
+    if opts.unstable().is_some() {
+      comments.push(" @experimental".to_owned());
+    }
+
     Ok(NapiFn {
       name: ident.clone(),
       js_name,
@@ -713,24 +900,35 @@ fn napi_fn_from_decl(
       is_ret_result,
       is_async: asyncness.is_some(),
       within_async_runtime: opts.async_runtime().is_some(),
+      blocking,
+      memoize: opts.memoize().is_some(),
+      error_class,
+      overload: opts
+        .overload()
+        .map(|_| REGISTER_INDEX.fetch_add(1, std::sync::atomic::Ordering::Relaxed)),
+      unstable: opts.unstable().is_some(),
       vis,
       kind,
       fn_self,
       parent: parent.cloned(),
-      comments: extract_doc_comments(&attrs),
+      comments,
       attrs,
       strict: opts.strict().is_some(),
       return_if_invalid: opts.return_if_invalid().is_some(),
       js_mod: opts.namespace().map(|(m, _)| m.to_owned()),
+      module: opts.module().map(|(m, _)| m.to_owned()),
       ts_generic_types: opts.ts_generic_types().map(|(m, _)| m.to_owned()),
       ts_args_type: opts.ts_args_type().map(|(m, _)| m.to_owned()),
       ts_return_type: opts.ts_return_type().map(|(m, _)| m.to_owned()),
       skip_typescript: opts.skip_typescript().is_some(),
       parent_is_generator,
+      parent_has_set_hook,
+      borrow: opts.borrow().is_some(),
       writable: opts.writable(),
       enumerable: opts.enumerable(),
       configurable: opts.configurable(),
       catch_unwind: opts.catch_unwind().is_some(),
+      record: opts.record().is_some(),
       unsafe_: sig.unsafety.is_some(),
       register_name: get_register_ident(ident.to_string().as_str()),
     })
@@ -797,9 +995,21 @@ impl ParseNapi for syn::ItemStruct {
         "#[napi(catch_unwind)] can only be applied to a function or method."
       );
     }
+    if opts.record().is_some() {
+      bail_span!(
+        self,
+        "#[napi(record)] can only be applied to a function or method."
+      );
+    }
     if opts.object().is_some() && opts.custom_finalize().is_some() {
       bail_span!(self, "Custom finalize is not supported for #[napi(object)]");
     }
+    if opts.object().is_some() && opts.set_hook().is_some() {
+      bail_span!(
+        self,
+        "#[napi(set_hook)] is not supported for #[napi(object)]"
+      );
+    }
     let napi = self.convert_to_ast(opts);
     self.to_tokens(tokens);
 
@@ -832,6 +1042,18 @@ impl ParseNapi for syn::ItemImpl {
         "#[napi(catch_unwind)] can only be applied to a function or method."
       );
     }
+    if opts.record().is_some() {
+      bail_span!(
+        self,
+        "#[napi(record)] can only be applied to a function or method."
+      );
+    }
+    if opts.module().is_some() {
+      bail_span!(
+        self,
+        "#[napi(module)] can't be applied to impl blocks, classes belong to the module of their struct"
+      );
+    }
     // #[napi] macro will be remove from impl items after converted to ast
     let napi = self.convert_to_ast(opts);
     self.to_tokens(tokens);
@@ -864,6 +1086,12 @@ impl ParseNapi for syn::ItemEnum {
         "#[napi(catch_unwind)] can only be applied to a function or method."
       );
     }
+    if opts.record().is_some() {
+      bail_span!(
+        self,
+        "#[napi(record)] can only be applied to a function or method."
+      );
+    }
     let napi = self.convert_to_ast(opts);
     self.to_tokens(tokens);
 
@@ -894,6 +1122,12 @@ impl ParseNapi for syn::ItemConst {
         "#[napi(catch_unwind)] can only be applied to a function or method."
       );
     }
+    if opts.record().is_some() {
+      bail_span!(
+        self,
+        "#[napi(record)] can only be applied to a function or method."
+      );
+    }
     let napi = self.convert_to_ast(opts);
     self.to_tokens(tokens);
     napi
@@ -938,6 +1172,29 @@ impl ConvertToAST for syn::ItemFn {
   }
 }
 
+fn is_string_type(ty: &Type) -> bool {
+  match ty {
+    Type::Path(syn::TypePath { path, .. }) => {
+      path
+        .segments
+        .last()
+        .is_some_and(|segment| match &segment.arguments {
+          // `Arc<str>` of borrowed fields
+          PathArguments::AngleBracketed(AngleBracketedGenericArguments { args, .. })
+            if segment.ident == "Arc" =>
+          {
+            args
+              .iter()
+              .any(|arg| matches!(arg, GenericArgument::Type(ty) if is_string_type(ty)))
+          }
+          _ => segment.ident == "String" || segment.ident == "str",
+        })
+    }
+    Type::Reference(r) => is_string_type(&r.elem),
+    _ => false,
+  }
+}
+
 fn convert_fields(
   fields: &mut syn::Fields,
   check_vis: bool,
@@ -971,7 +1228,18 @@ fn convert_fields(
     let enumerable = field_opts.enumerable();
     let configurable = field_opts.configurable();
     let skip_typescript = field_opts.skip_typescript().is_some();
-    let ts_type = field_opts.ts_type().map(|e| e.0.to_string());
+    let borrow = field_opts.borrow().is_some();
+    if borrow && !readonly {
+      bail_span!(
+        field,
+        "#[napi(borrow)] fields must be #[napi(readonly)], replace the `Arc` from a method instead"
+      );
+    }
+    // borrowed bytes are handed out as a `Buffer` copy
+    let ts_type = field_opts
+      .ts_type()
+      .map(|e| e.0.to_string())
+      .or_else(|| (borrow && !is_string_type(&field.ty)).then(|| "Buffer".to_owned()));
 
     let mut ty = field.ty.clone();
 
@@ -1013,6 +1281,7 @@ fn convert_fields(
       skip_typescript,
       ts_type,
       has_lifetime,
+      borrow,
     })
   }
   Ok((napi_fields, is_tuple))
@@ -1023,13 +1292,15 @@ impl ConvertToAST for syn::ItemStruct {
     let mut errors = vec![];
 
     let struct_name = self.ident.clone();
-    let js_name = opts.js_name().map_or_else(
+    let js_name = exported_js_name(opts.js_name().map_or_else(
       || self.ident.to_string().to_case(Case::Pascal),
       |(js_name, _)| js_name.to_owned(),
-    );
+    ));
 
     let use_nullable = opts.use_nullable();
-    let (fields, is_tuple) = convert_fields(&mut self.fields, true)?;
+    // `#[napi(auto)]` converts all the fields, the conversions are in the module of the struct
+    let auto = opts.auto().is_some();
+    let (fields, is_tuple) = convert_fields(&mut self.fields, !auto)?;
 
     record_struct(&struct_name, js_name.clone(), opts);
     let namespace = opts.namespace().map(|(m, _)| m.to_owned());
@@ -1042,10 +1313,54 @@ impl ConvertToAST for syn::ItemStruct {
       .as_ref()
       .map(|n| format!("{}::{}", n, struct_name))
       .unwrap_or_else(|| struct_name.to_string());
-    generator_struct.insert(key, implement_iterator);
+    generator_struct.insert(key.clone(), implement_iterator);
     drop(generator_struct);
+    SET_HOOK_STRUCT
+      .get_or_init(|| Mutex::new(HashMap::new()))
+      .lock()
+      .expect("Lock set hook struct failed")
+      .insert(key, opts.set_hook().is_some());
+
+    if opts.object().is_some() && opts.json().is_some() {
+      errors.push(err_span!(
+        self,
+        "#[napi(json)] is for classes, #[napi(object)] is already converted from and to plain objects"
+      ));
+    }
 
-    let struct_kind = if opts.object().is_some() {
+    if opts.error().is_some()
+      && (opts.object().is_some() || opts.constructor().is_some() || opts.json().is_some())
+    {
+      errors.push(err_span!(
+        self,
+        "#[napi(error)] structs are thrown as errors, they can't be objects or have a constructor"
+      ));
+    }
+
+    if auto
+      && (opts.object().is_some()
+        || opts.constructor().is_some()
+        || opts.error().is_some()
+        || opts.json().is_some())
+    {
+      errors.push(err_span!(
+        self,
+        "#[napi(auto)] structs are converted from and to plain objects, they can't be classes, errors or be marked as #[napi(object)] too"
+      ));
+    }
+
+    let struct_kind = if let Some(error) = opts.error() {
+      if let Some(error) = error {
+        bail_span!(
+          error,
+          "#[napi(error = ...)] is for functions, use #[napi(error)] to declare an error class"
+        );
+      }
+      if is_tuple {
+        bail_span!(self, "#[napi(error)] structs must have named fields");
+      }
+      NapiStructKind::Error(NapiErrorClass { fields })
+    } else if opts.object().is_some() || auto {
       NapiStructKind::Object(NapiObject {
         fields,
         object_from_js: opts.object_from_js(),
@@ -1059,11 +1374,14 @@ impl ConvertToAST for syn::ItemStruct {
         implement_iterator,
         is_tuple,
         use_custom_finalize: opts.custom_finalize().is_some(),
+        use_set_hook: opts.set_hook().is_some(),
+        json: opts.json().is_some(),
       })
     };
 
     match &struct_kind {
       NapiStructKind::Class(class) if !class.ctor => {}
+      NapiStructKind::Object(_) if auto => {}
       _ => {
         for field in self.fields.iter() {
           if !matches!(field.vis, syn::Visibility::Public(_)) {
@@ -1098,6 +1416,7 @@ impl ConvertToAST for syn::ItemStruct {
         name: struct_name.clone(),
         kind: struct_kind,
         js_mod: namespace,
+        module: opts.module().map(|(m, _)| m.to_owned()),
         use_nullable,
         register_name: get_register_ident(format!("{struct_name}_struct").as_str()),
         comments: extract_doc_comments(&self.attrs),
@@ -1121,7 +1440,8 @@ impl ConvertToAST for syn::ItemImpl {
 
     let (struct_name, has_lifetime) = extract_path_ident(struct_name)?;
 
-    let mut struct_js_name = struct_name.to_string().to_case(Case::UpperCamel);
+    let mut struct_js_name = recorded_struct_js_name(&struct_name)
+      .unwrap_or_else(|| exported_js_name(struct_name.to_string().to_case(Case::UpperCamel)));
     let mut items = vec![];
     let mut task_output_type = None;
     let mut iterator_yield_type = None;
@@ -1213,10 +1533,15 @@ impl ConvertToAST for syn::ItemEnum {
       _ => bail_span!(self, "only public enum allowed"),
     }
 
-    let js_name = opts
-      .js_name()
-      .map_or_else(|| self.ident.to_string(), |(s, _)| s.to_string());
-    let is_string_enum = opts.string_enum().is_some();
+    let js_name = exported_js_name(
+      opts
+        .js_name()
+        .map_or_else(|| self.ident.to_string(), |(s, _)| s.to_string()),
+    );
+    // `#[napi(auto)]` enums without data are string enums of the variant names
+    let auto = opts.auto().is_some();
+    let string_enum = opts.string_enum().or(auto.then_some(None));
+    let is_string_enum = string_enum.is_some();
 
     if self
       .variants
@@ -1251,6 +1576,7 @@ impl ConvertToAST for syn::ItemEnum {
           js_name,
           comments: extract_doc_comments(&self.attrs),
           js_mod: opts.namespace().map(|(m, _)| m.to_owned()),
+          module: opts.module().map(|(m, _)| m.to_owned()),
           use_nullable: opts.use_nullable(),
           register_name: get_register_ident(format!("{struct_name}_struct").as_str()),
           kind: NapiStructKind::StructuredEnum(NapiStructuredEnum {
@@ -1264,7 +1590,7 @@ impl ConvertToAST for syn::ItemEnum {
       });
     }
 
-    let variants = match opts.string_enum() {
+    let variants = match string_enum {
       Some(case) => {
         let case = case.map(|c| Ok::<Case, Diagnostic>(match c.0.as_str() {
           "lowercase" => Case::Flat,
@@ -1376,10 +1702,12 @@ impl ConvertToAST for syn::ItemEnum {
         js_name,
         variants,
         js_mod: opts.namespace().map(|(m, _)| m.to_owned()),
+        module: opts.module().map(|(m, _)| m.to_owned()),
         comments: extract_doc_comments(&self.attrs),
         skip_typescript: opts.skip_typescript().is_some(),
         register_name: get_register_ident(self.ident.to_string().as_str()),
         is_string_enum,
+        reflect: opts.reflect().is_some(),
       }),
     })
   }
@@ -1391,12 +1719,15 @@ impl ConvertToAST for syn::ItemConst {
       Visibility::Public(_) => Ok(Napi {
         item: NapiItem::Const(NapiConst {
           name: self.ident.clone(),
-          js_name: opts
-            .js_name()
-            .map_or_else(|| self.ident.to_string(), |(s, _)| s.to_string()),
+          js_name: exported_js_name(
+            opts
+              .js_name()
+              .map_or_else(|| self.ident.to_string(), |(s, _)| s.to_string()),
+          ),
           type_name: *self.ty.clone(),
           value: *self.expr.clone(),
           js_mod: opts.namespace().map(|(m, _)| m.to_owned()),
+          module: opts.module().map(|(m, _)| m.to_owned()),
           comments: extract_doc_comments(&self.attrs),
           skip_typescript: opts.skip_typescript().is_some(),
           register_name: get_register_ident(self.ident.to_string().as_str()),
@@ -1,4 +1,10 @@
 fn main() {
+  println!("cargo:rerun-if-env-changed=NAPI_RS_INIT_SYMBOL");
+  let init_symbol = std::env::var("NAPI_RS_INIT_SYMBOL")
+    .ok()
+    .filter(|symbol| !symbol.is_empty())
+    .unwrap_or_else(|| "napi_register_module_v1".to_owned());
+  println!("cargo:rustc-env=NAPI_RS_INIT_SYMBOL={init_symbol}");
   let target_os = std::env::var("CARGO_CFG_TARGET_OS").unwrap();
   let target_env = std::env::var("CARGO_CFG_TARGET_ENV").unwrap();
   if target_os == "windows" && target_env == "gnu" {
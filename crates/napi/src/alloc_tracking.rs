@@ -0,0 +1,340 @@
+//! A global allocator wrapper attributing the native allocations of the addon to categories, to
+//! tell whether RSS growth comes from the native side or from the V8 heap.
+//!
+//! Install it as the global allocator of the addon, and enter a category around the code to
+//! attribute. The memory is attributed to the category entered on the thread when it's allocated,
+//! until it's freed, whatever thread frees it:
+//!
+//! ```rust,ignore
+//! use napi::alloc_tracking::{AllocCategory, TrackingAllocator};
+//!
+//! #[global_allocator]
+//! static GLOBAL: TrackingAllocator = TrackingAllocator::new();
+//!
+//! static PARSER: AllocCategory = AllocCategory::new("parser");
+//!
+//! #[napi]
+//! fn parse(input: String) -> Ast {
+//!   let _category = PARSER.enter();
+//!   Ast::parse(&input)
+//! }
+//! ```
+//!
+//! With the `alloc_tracking` feature enabled, JS reads the totals with `allocTracking.snapshot()`
+//! on the addon:
+//!
+//! ```js
+//! const { allocTracking } = require('./index.node')
+//! allocTracking.snapshot()
+//! // { uncategorized: { allocated: 24576, allocations: 31, totalAllocated: 1048576 },
+//! //   parser: { allocated: 4096, allocations: 2, totalAllocated: 65536 } }
+//! ```
+//!
+//! Every allocation carries a small header with its category, the overhead is about one word per
+//! allocation plus a few atomic additions.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::mem;
+use std::ptr;
+use std::sync::atomic::{AtomicBool, AtomicPtr, AtomicU64, AtomicUsize, Ordering};
+
+use crate::{
+  bindgen_prelude::{register_module_export, CallbackInfo, ToNapiValue},
+  sys, Env, JsObject, Result,
+};
+
+/// The category of the allocations made outside of any entered category
+pub static UNCATEGORIZED: AllocCategory = AllocCategory::new("uncategorized");
+
+/// The head of the list of the categories which have allocated memory
+static CATEGORIES: AtomicPtr<AllocCategory> = AtomicPtr::new(ptr::null_mut());
+
+thread_local! {
+  static CURRENT_CATEGORY: Cell<*const AllocCategory> = const { Cell::new(ptr::null()) };
+}
+
+/// A category of native allocations, declared as a `static`
+pub struct AllocCategory {
+  name: &'static str,
+  allocated: AtomicUsize,
+  allocations: AtomicUsize,
+  total_allocated: AtomicU64,
+  registered: AtomicBool,
+  next: AtomicPtr<AllocCategory>,
+}
+
+/// The allocations of an [`AllocCategory`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AllocStats {
+  /// The bytes allocated and not freed yet
+  pub allocated: usize,
+  /// The number of allocations not freed yet
+  pub allocations: usize,
+  /// The bytes allocated since the start, including the freed ones
+  pub total_allocated: u64,
+}
+
+impl AllocCategory {
+  pub const fn new(name: &'static str) -> Self {
+    Self {
+      name,
+      allocated: AtomicUsize::new(0),
+      allocations: AtomicUsize::new(0),
+      total_allocated: AtomicU64::new(0),
+      registered: AtomicBool::new(false),
+      next: AtomicPtr::new(ptr::null_mut()),
+    }
+  }
+
+  pub fn name(&self) -> &'static str {
+    self.name
+  }
+
+  /// Attribute the allocations of the current thread to this category until the guard is dropped
+  pub fn enter(&'static self) -> AllocCategoryGuard {
+    let previous = CURRENT_CATEGORY.with(|current| current.replace(self));
+    AllocCategoryGuard {
+      previous,
+      _not_send: std::marker::PhantomData,
+    }
+  }
+
+  /// Run `f` with its allocations attributed to this category
+  pub fn scope<T>(&'static self, f: impl FnOnce() -> T) -> T {
+    let _guard = self.enter();
+    f()
+  }
+
+  pub fn stats(&self) -> AllocStats {
+    AllocStats {
+      allocated: self.allocated.load(Ordering::Relaxed),
+      allocations: self.allocations.load(Ordering::Relaxed),
+      total_allocated: self.total_allocated.load(Ordering::Relaxed),
+    }
+  }
+
+  fn on_alloc(&'static self, size: usize) {
+    self.allocated.fetch_add(size, Ordering::Relaxed);
+    self.allocations.fetch_add(1, Ordering::Relaxed);
+    self
+      .total_allocated
+      .fetch_add(size as u64, Ordering::Relaxed);
+    if !self.registered.load(Ordering::Relaxed) && !self.registered.swap(true, Ordering::AcqRel) {
+      let this = self as *const Self as *mut Self;
+      let mut head = CATEGORIES.load(Ordering::Acquire);
+      loop {
+        self.next.store(head, Ordering::Relaxed);
+        match CATEGORIES.compare_exchange_weak(head, this, Ordering::AcqRel, Ordering::Acquire) {
+          Ok(_) => break,
+          Err(current) => head = current,
+        }
+      }
+    }
+  }
+
+  fn on_dealloc(&self, size: usize) {
+    self.allocated.fetch_sub(size, Ordering::Relaxed);
+    self.allocations.fetch_sub(1, Ordering::Relaxed);
+  }
+
+  fn on_realloc(&self, old_size: usize, new_size: usize) {
+    if new_size >= old_size {
+      let grown = new_size - old_size;
+      self.allocated.fetch_add(grown, Ordering::Relaxed);
+      self
+        .total_allocated
+        .fetch_add(grown as u64, Ordering::Relaxed);
+    } else {
+      self
+        .allocated
+        .fetch_sub(old_size - new_size, Ordering::Relaxed);
+    }
+  }
+}
+
+/// Restores the category entered before [`AllocCategory::enter`] when dropped
+pub struct AllocCategoryGuard {
+  previous: *const AllocCategory,
+  _not_send: std::marker::PhantomData<*const ()>,
+}
+
+impl Drop for AllocCategoryGuard {
+  fn drop(&mut self) {
+    let _ = CURRENT_CATEGORY.try_with(|current| current.set(self.previous));
+  }
+}
+
+/// The stats of every category which has allocated memory through the [`TrackingAllocator`]
+pub fn snapshot() -> Vec<(&'static str, AllocStats)> {
+  let mut categories = Vec::new();
+  let mut category = CATEGORIES.load(Ordering::Acquire);
+  while let Some(current) = unsafe { category.as_ref() } {
+    categories.push((current.name, current.stats()));
+    category = current.next.load(Ordering::Acquire);
+  }
+  categories.reverse();
+  categories
+}
+
+/// A [`GlobalAlloc`] wrapping `A`, the system allocator by default, and attributing the
+/// allocations to the [`AllocCategory`] entered on the current thread
+pub struct TrackingAllocator<A = System> {
+  inner: A,
+}
+
+impl TrackingAllocator {
+  pub const fn new() -> Self {
+    Self { inner: System }
+  }
+}
+
+impl Default for TrackingAllocator {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl<A: GlobalAlloc> TrackingAllocator<A> {
+  pub const fn with_allocator(inner: A) -> Self {
+    Self { inner }
+  }
+}
+
+/// The size of the header holding the category in front of an allocation of `layout`, a
+/// multiple of its alignment so the allocation stays aligned
+fn header_size(layout: Layout) -> usize {
+  layout.align().max(mem::size_of::<*const AllocCategory>())
+}
+
+fn with_header(layout: Layout) -> Option<Layout> {
+  Layout::from_size_align(
+    layout.size().checked_add(header_size(layout))?,
+    layout.align(),
+  )
+  .ok()
+}
+
+fn current_category() -> &'static AllocCategory {
+  let current = CURRENT_CATEGORY.try_with(Cell::get).unwrap_or(ptr::null());
+  unsafe { current.as_ref() }.unwrap_or(&UNCATEGORIZED)
+}
+
+impl<A: GlobalAlloc> TrackingAllocator<A> {
+  unsafe fn alloc_with(
+    &self,
+    layout: Layout,
+    alloc: impl FnOnce(&A, Layout) -> *mut u8,
+  ) -> *mut u8 {
+    let Some(full_layout) = with_header(layout) else {
+      return ptr::null_mut();
+    };
+    let base = alloc(&self.inner, full_layout);
+    if base.is_null() {
+      return base;
+    }
+    let category = current_category();
+    category.on_alloc(layout.size());
+    let user = unsafe { base.add(header_size(layout)) };
+    unsafe { write_category(user, category) };
+    user
+  }
+}
+
+unsafe fn write_category(user: *mut u8, category: &'static AllocCategory) {
+  unsafe {
+    user
+      .sub(mem::size_of::<*const AllocCategory>())
+      .cast::<*const AllocCategory>()
+      .write_unaligned(category)
+  };
+}
+
+unsafe fn read_category(user: *mut u8) -> &'static AllocCategory {
+  unsafe {
+    &*user
+      .sub(mem::size_of::<*const AllocCategory>())
+      .cast::<*const AllocCategory>()
+      .read_unaligned()
+  }
+}
+
+unsafe impl<A: GlobalAlloc> GlobalAlloc for TrackingAllocator<A> {
+  unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+    unsafe { self.alloc_with(layout, |inner, layout| inner.alloc(layout)) }
+  }
+
+  unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+    unsafe { self.alloc_with(layout, |inner, layout| inner.alloc_zeroed(layout)) }
+  }
+
+  unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+    unsafe { read_category(ptr) }.on_dealloc(layout.size());
+    let header = header_size(layout);
+    let full_layout =
+      unsafe { Layout::from_size_align_unchecked(layout.size() + header, layout.align()) };
+    unsafe { self.inner.dealloc(ptr.sub(header), full_layout) };
+  }
+
+  unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+    let header = header_size(layout);
+    let Some(new_full_size) = new_size.checked_add(header) else {
+      return ptr::null_mut();
+    };
+    let full_layout =
+      unsafe { Layout::from_size_align_unchecked(layout.size() + header, layout.align()) };
+    let base = unsafe {
+      self
+        .inner
+        .realloc(ptr.sub(header), full_layout, new_full_size)
+    };
+    if base.is_null() {
+      return base;
+    }
+    let user = unsafe { base.add(header) };
+    // the memory stays attributed to the category it was allocated in
+    unsafe { read_category(user) }.on_realloc(layout.size(), new_size);
+    user
+  }
+}
+
+unsafe fn snapshot_js(env: sys::napi_env, cb: sys::napi_callback_info) -> Result<sys::napi_value> {
+  CallbackInfo::<0>::new(env, cb, None, false)?;
+  let env = Env::from_raw(env);
+  let mut categories = env.create_object()?;
+  // categories sharing a name are merged
+  let mut merged: Vec<(&'static str, AllocStats)> = Vec::new();
+  for (name, stats) in snapshot() {
+    match merged
+      .iter_mut()
+      .find(|(merged_name, _)| *merged_name == name)
+    {
+      Some((_, merged_stats)) => {
+        merged_stats.allocated += stats.allocated;
+        merged_stats.allocations += stats.allocations;
+        merged_stats.total_allocated += stats.total_allocated;
+      }
+      None => merged.push((name, stats)),
+    }
+  }
+  for (name, stats) in merged {
+    let mut category = env.create_object()?;
+    category.set("allocated", stats.allocated as f64)?;
+    category.set("allocations", stats.allocations as f64)?;
+    category.set("totalAllocated", stats.total_allocated as f64)?;
+    categories.set(name, category)?;
+  }
+  unsafe { JsObject::to_napi_value(env.raw(), categories) }
+}
+
+js_function!(
+  "snapshot",
+  snapshot_js,
+  snapshot_callback,
+  register_snapshot
+);
+
+/// Export `snapshot` on the `allocTracking` object of the addon
+pub(crate) fn register_exports() {
+  register_module_export(Some("allocTracking\0"), "snapshot\0", register_snapshot);
+}
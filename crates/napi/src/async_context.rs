@@ -0,0 +1,33 @@
+use crate::{sys, Status};
+
+/// The async context of a native resource, created by [`Env::async_init`](crate::Env::async_init).
+///
+/// Calling into JavaScript through [`Env::make_callback`](crate::Env::make_callback) or
+/// [`Env::run_in_callback_scope`](crate::Env::run_in_callback_scope) with it restores the async
+/// context the resource was created in, so `async_hooks`, `AsyncLocalStorage` and domains see the
+/// callbacks of native event sources like the ones of any other Node.js resource.
+///
+/// The context is destroyed when `AsyncContext` is dropped, which must happen on the JavaScript
+/// thread before the env is torn down.
+pub struct AsyncContext {
+  pub(crate) env: sys::napi_env,
+  pub(crate) context: sys::napi_async_context,
+  pub(crate) resource: sys::napi_ref,
+}
+
+impl Drop for AsyncContext {
+  fn drop(&mut self) {
+    let status = unsafe { sys::napi_async_destroy(self.env, self.context) };
+    debug_assert!(
+      status == sys::Status::napi_ok,
+      "Destroy async context failed: {}",
+      Status::from(status)
+    );
+    let status = unsafe { sys::napi_delete_reference(self.env, self.resource) };
+    debug_assert!(
+      status == sys::Status::napi_ok,
+      "Delete async resource reference failed: {}",
+      Status::from(status)
+    );
+  }
+}
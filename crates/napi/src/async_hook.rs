@@ -0,0 +1,199 @@
+use std::any::type_name;
+use std::cell::{Cell, RefCell};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use crate::{Env, Error, Result, Status};
+
+static NEXT_WORK_ID: AtomicU64 = AtomicU64::new(1);
+
+/// Observes the lifecycle of the async works of an env, see [`Env::add_async_hook`].
+///
+/// The callbacks are called on the thread where each step happens: `on_queue` on the JavaScript
+/// thread, `on_start` on the thread running the work, `on_complete` and `on_error` on the
+/// JavaScript thread for a `Task` and on the thread driving the future for a future. They must be
+/// quick and must not panic.
+pub trait AsyncHook: Send + Sync {
+  /// The work is queued, but not started yet
+  fn on_queue(&self, _work: &AsyncWorkInfo) {}
+
+  /// The work is started after waiting `queued` in the queue
+  fn on_start(&self, _work: &AsyncWorkInfo, _queued: Duration) {}
+
+  /// The work is done and its value converted
+  fn on_complete(&self, _work: &AsyncWorkInfo, _timings: &AsyncWorkTimings) {}
+
+  /// The work failed, was cancelled, or was dropped before completing like after a panic
+  fn on_error(&self, _work: &AsyncWorkInfo, _error: &Error, _timings: &AsyncWorkTimings) {}
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AsyncWorkKind {
+  /// A [`Task`](crate::Task) run on the libuv thread pool
+  Task,
+  /// A future run on the tokio runtime, like an `async fn` or `Env::spawn_future`
+  Future,
+}
+
+#[derive(Debug, Clone)]
+pub struct AsyncWorkInfo {
+  /// Unique in the process
+  pub id: u64,
+  pub kind: AsyncWorkKind,
+  /// The type name of the `Task` or of the future, an `async fn` is named after its function
+  pub type_name: &'static str,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AsyncWorkTimings {
+  /// From queueing the work to starting it, zero if it was never started
+  pub queued: Duration,
+  /// From starting the work to its outcome
+  pub running: Duration,
+}
+
+/// Created by `Env::add_async_hook`
+/// And used by `Env::remove_async_hook`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AsyncHookId(u64);
+
+#[derive(Default)]
+struct AsyncHooks {
+  next_id: Cell<u64>,
+  hooks: RefCell<Vec<(u64, Arc<dyn AsyncHook>)>>,
+}
+
+impl Env {
+  /// Observe the `Task`s and tokio futures queued by this crate in this env from now on, like the
+  /// `AsyncWork`s of `#[napi]` functions returning an `AsyncTask`, `async fn`s and
+  /// `Env::spawn_future`.
+  ///
+  /// ```rust,ignore
+  /// struct SlowWorkLogger;
+  ///
+  /// impl AsyncHook for SlowWorkLogger {
+  ///   fn on_complete(&self, work: &AsyncWorkInfo, timings: &AsyncWorkTimings) {
+  ///     if timings.queued + timings.running > Duration::from_secs(1) {
+  ///       eprintln!("{} took {:?}", work.type_name, timings.queued + timings.running);
+  ///     }
+  ///   }
+  /// }
+  ///
+  /// env.add_async_hook(SlowWorkLogger)?;
+  /// ```
+  ///
+  /// The works already queued keep the hooks they were queued with. The hooks are dropped when the
+  /// env is torn down.
+  pub fn add_async_hook<H: 'static + AsyncHook>(&self, hook: H) -> Result<AsyncHookId> {
+    let hooks = self.get_or_init_instance_data_typed(|| Ok(AsyncHooks::default()))?;
+    let id = hooks.next_id.get();
+    hooks.next_id.set(id + 1);
+    hooks.hooks.borrow_mut().push((id, Arc::new(hook)));
+    Ok(AsyncHookId(id))
+  }
+
+  /// Stop observing the works queued from now on with the hook of `id`, `false` if it was removed
+  /// already
+  pub fn remove_async_hook(&self, id: AsyncHookId) -> bool {
+    let Some(hooks) = self.get_instance_data_typed::<AsyncHooks>() else {
+      return false;
+    };
+    let mut hooks = hooks.hooks.borrow_mut();
+    let count = hooks.len();
+    hooks.retain(|(hook_id, _)| *hook_id != id.0);
+    hooks.len() != count
+  }
+}
+
+/// Reports the lifecycle of a work to the hooks of its env.
+///
+/// Dropped without an outcome, like when a future panics or the runtime is shut down, it reports
+/// an error.
+pub(crate) struct AsyncWorkTracker {
+  hooks: Vec<Arc<dyn AsyncHook>>,
+  info: AsyncWorkInfo,
+  queued_at: Instant,
+  started_at: Option<Instant>,
+  reported: bool,
+}
+
+impl AsyncWorkTracker {
+  /// Report the work `W` as queued, `None` if there are no hooks in `env`
+  pub(crate) fn queue<W>(env: &Env, kind: AsyncWorkKind) -> Option<Self> {
+    let hooks = env.get_instance_data_typed::<AsyncHooks>()?;
+    let hooks = hooks
+      .hooks
+      .borrow()
+      .iter()
+      .map(|(_, hook)| hook.clone())
+      .collect::<Vec<_>>();
+    if hooks.is_empty() {
+      return None;
+    }
+    let tracker = Self {
+      hooks,
+      info: AsyncWorkInfo {
+        id: NEXT_WORK_ID.fetch_add(1, Ordering::Relaxed),
+        kind,
+        type_name: type_name::<W>(),
+      },
+      queued_at: Instant::now(),
+      started_at: None,
+      reported: false,
+    };
+    tracker
+      .hooks
+      .iter()
+      .for_each(|hook| hook.on_queue(&tracker.info));
+    Some(tracker)
+  }
+
+  pub(crate) fn start(&mut self) {
+    let started_at = Instant::now();
+    self.started_at = Some(started_at);
+    let queued = started_at - self.queued_at;
+    self
+      .hooks
+      .iter()
+      .for_each(|hook| hook.on_start(&self.info, queued));
+  }
+
+  /// Report the outcome of the work
+  pub(crate) fn finish<T>(&mut self, outcome: std::result::Result<T, &Error>) {
+    self.reported = true;
+    let timings = self.timings();
+    match outcome {
+      Ok(_) => self
+        .hooks
+        .iter()
+        .for_each(|hook| hook.on_complete(&self.info, &timings)),
+      Err(error) => self
+        .hooks
+        .iter()
+        .for_each(|hook| hook.on_error(&self.info, error, &timings)),
+    }
+  }
+
+  fn timings(&self) -> AsyncWorkTimings {
+    match self.started_at {
+      Some(started_at) => AsyncWorkTimings {
+        queued: started_at - self.queued_at,
+        running: started_at.elapsed(),
+      },
+      None => AsyncWorkTimings::default(),
+    }
+  }
+}
+
+impl Drop for AsyncWorkTracker {
+  fn drop(&mut self) {
+    if !self.reported {
+      let error = Error::new(
+        Status::Cancelled,
+        "The async work was dropped before completing",
+      );
+      self.finish::<()>(Err(&error));
+    }
+  }
+}
@@ -0,0 +1,296 @@
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::task::{Context, Poll, Waker};
+
+use crate::{Error, Result, Status};
+
+/// Tracks the async work started on behalf of a JavaScript object, and cancels it when the object
+/// loses interest in it.
+///
+/// Futures run in the scope end with a `Cancelled` error and are dropped as soon as it's cancelled,
+/// other work like threadsafe function calls or `Task`s holds a [`ScopeGuard`] and checks it. The
+/// scope is cancelled by [`AsyncScope::cancel`], by [`AsyncScope::close`] which also waits for the
+/// pending work to be dropped, or when it's dropped itself. Kept in a `#[napi]` class, it's
+/// dropped when the JavaScript object is garbage collected:
+///
+/// ```rust,ignore
+/// #[napi]
+/// pub struct Watcher {
+///   scope: AsyncScope,
+/// }
+///
+/// #[napi]
+/// impl Watcher {
+///   #[napi]
+///   pub fn next_event(&self, env: Env) -> Result<PromiseRaw<String>> {
+///     self.scope.spawn(&env, async move { wait_for_event().await })
+///   }
+///
+///   #[napi]
+///   pub fn close(&self, env: Env) -> Result<PromiseRaw<()>> {
+///     self.scope.close(&env)
+///   }
+/// }
+/// ```
+pub struct AsyncScope {
+  inner: Arc<ScopeInner>,
+}
+
+struct ScopeInner {
+  cancelled: AtomicBool,
+  pending: AtomicUsize,
+  wakers: Mutex<ScopeWakers>,
+}
+
+#[derive(Default)]
+struct ScopeWakers {
+  next_id: u64,
+  /// The futures of the scope, woken when it's cancelled
+  running: HashMap<u64, Waker>,
+  /// Woken when there is no pending work anymore
+  closing: Vec<Waker>,
+}
+
+impl ScopeInner {
+  fn wakers(&self) -> MutexGuard<'_, ScopeWakers> {
+    self.wakers.lock().unwrap_or_else(|e| e.into_inner())
+  }
+
+  fn cancel(&self) {
+    if self.cancelled.swap(true, Ordering::AcqRel) {
+      return;
+    }
+    let running = std::mem::take(&mut self.wakers().running);
+    running.into_values().for_each(Waker::wake);
+  }
+
+  fn cancelled_error() -> Error {
+    Error::new(Status::Cancelled, "The async scope is closed")
+  }
+}
+
+impl Default for AsyncScope {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl AsyncScope {
+  pub fn new() -> Self {
+    Self {
+      inner: Arc::new(ScopeInner {
+        cancelled: AtomicBool::new(false),
+        pending: AtomicUsize::new(0),
+        wakers: Mutex::new(ScopeWakers::default()),
+      }),
+    }
+  }
+
+  /// Cancel the pending work and refuse the new one, the futures of the scope are woken to end
+  /// with a `Cancelled` error
+  pub fn cancel(&self) {
+    self.inner.cancel();
+  }
+
+  pub fn is_cancelled(&self) -> bool {
+    self.inner.cancelled.load(Ordering::Acquire)
+  }
+
+  /// The number of futures and [`ScopeGuard`]s of the scope which are not dropped yet
+  pub fn pending(&self) -> usize {
+    self.inner.pending.load(Ordering::Acquire)
+  }
+
+  /// Count some work as pending in the scope until the guard is dropped, a `Cancelled` error if the
+  /// scope is cancelled already
+  pub fn guard(&self) -> Result<ScopeGuard> {
+    if self.is_cancelled() {
+      return Err(ScopeInner::cancelled_error());
+    }
+    self.inner.pending.fetch_add(1, Ordering::AcqRel);
+    Ok(ScopeGuard {
+      inner: self.inner.clone(),
+    })
+  }
+
+  /// Run `future` in the scope, it ends with a `Cancelled` error and is dropped once the scope is
+  /// cancelled
+  pub fn run<F, T>(&self, future: F) -> Scoped<F>
+  where
+    F: Future<Output = Result<T>>,
+  {
+    Scoped {
+      guard: self.guard().ok(),
+      id: None,
+      future: Some(Box::pin(future)),
+    }
+  }
+
+  /// [`Env::spawn_future`](crate::Env::spawn_future) in the scope
+  #[cfg(all(feature = "tokio_rt", feature = "napi4"))]
+  pub fn spawn<F, T>(
+    &self,
+    env: &crate::Env,
+    future: F,
+  ) -> Result<crate::bindgen_prelude::PromiseRaw<T>>
+  where
+    T: 'static + Send + crate::bindgen_prelude::ToNapiValue,
+    F: 'static + Send + Future<Output = Result<T>>,
+  {
+    env.spawn_future(self.run(future))
+  }
+
+  /// [`Env::spawn_local`](crate::Env::spawn_local) in the scope
+  #[cfg(all(feature = "napi3", not(target_family = "wasm")))]
+  pub fn spawn_local<F, T>(
+    &self,
+    env: &crate::Env,
+    future: F,
+  ) -> Result<crate::bindgen_prelude::PromiseRaw<T>>
+  where
+    T: 'static + crate::bindgen_prelude::ToNapiValue,
+    F: 'static + Future<Output = Result<T>>,
+  {
+    env.spawn_local(self.run(future))
+  }
+
+  /// Cancel the scope, the returned Promise is resolved once all its pending work is dropped
+  #[cfg(all(feature = "napi3", not(target_family = "wasm")))]
+  pub fn close(&self, env: &crate::Env) -> Result<crate::bindgen_prelude::PromiseRaw<()>> {
+    self.cancel();
+    env.spawn_local(Closed {
+      inner: self.inner.clone(),
+    })
+  }
+}
+
+impl Drop for AsyncScope {
+  fn drop(&mut self) {
+    self.inner.cancel();
+  }
+}
+
+/// Some work counted as pending in an [`AsyncScope`], see [`AsyncScope::guard`].
+///
+/// It can be sent to other threads, like in the closure of a threadsafe function, to check whether
+/// the work is still wanted.
+pub struct ScopeGuard {
+  inner: Arc<ScopeInner>,
+}
+
+impl ScopeGuard {
+  pub fn is_cancelled(&self) -> bool {
+    self.inner.cancelled.load(Ordering::Acquire)
+  }
+
+  /// A `Cancelled` error if the scope is cancelled
+  pub fn check(&self) -> Result<()> {
+    if self.is_cancelled() {
+      return Err(ScopeInner::cancelled_error());
+    }
+    Ok(())
+  }
+}
+
+impl Drop for ScopeGuard {
+  fn drop(&mut self) {
+    if self.inner.pending.fetch_sub(1, Ordering::AcqRel) == 1 {
+      let closing = std::mem::take(&mut self.inner.wakers().closing);
+      closing.into_iter().for_each(Waker::wake);
+    }
+  }
+}
+
+/// A future run in an [`AsyncScope`], see [`AsyncScope::run`]
+pub struct Scoped<F> {
+  /// `None` if the scope was cancelled before the future was run
+  guard: Option<ScopeGuard>,
+  /// The slot of the waker in the scope
+  id: Option<u64>,
+  future: Option<Pin<Box<F>>>,
+}
+
+impl<F> Scoped<F> {
+  fn unregister(&mut self) {
+    if let (Some(guard), Some(id)) = (&self.guard, self.id.take()) {
+      guard.inner.wakers().running.remove(&id);
+    }
+  }
+}
+
+impl<F, T> Future for Scoped<F>
+where
+  F: Future<Output = Result<T>>,
+{
+  type Output = Result<T>;
+
+  fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+    let this = &mut *self;
+    let Some(guard) = this.guard.as_ref().filter(|guard| !guard.is_cancelled()) else {
+      this.future = None;
+      this.unregister();
+      this.guard = None;
+      return Poll::Ready(Err(ScopeInner::cancelled_error()));
+    };
+    {
+      let mut wakers = guard.inner.wakers();
+      let id = *this.id.get_or_insert_with(|| {
+        wakers.next_id += 1;
+        wakers.next_id
+      });
+      wakers.running.insert(id, cx.waker().clone());
+    }
+    // cancelled while registering, `cancel` took the wakers before this one was inserted
+    if guard.is_cancelled() {
+      cx.waker().wake_by_ref();
+      return Poll::Pending;
+    }
+    let Some(future) = this.future.as_mut() else {
+      return Poll::Ready(Err(Error::new(
+        Status::GenericFailure,
+        "Scoped future polled after completion",
+      )));
+    };
+    let output = future.as_mut().poll(cx);
+    if output.is_ready() {
+      this.future = None;
+      this.unregister();
+      this.guard = None;
+    }
+    output
+  }
+}
+
+impl<F> Drop for Scoped<F> {
+  fn drop(&mut self) {
+    // the future may hold resources of the scope, drop it first
+    self.future = None;
+    self.unregister();
+  }
+}
+
+/// Resolved once there is no pending work in the scope
+#[cfg(all(feature = "napi3", not(target_family = "wasm")))]
+struct Closed {
+  inner: Arc<ScopeInner>,
+}
+
+#[cfg(all(feature = "napi3", not(target_family = "wasm")))]
+impl Future for Closed {
+  type Output = Result<()>;
+
+  fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+    if self.inner.pending.load(Ordering::Acquire) == 0 {
+      return Poll::Ready(Ok(()));
+    }
+    self.inner.wakers().closing.push(cx.waker().clone());
+    // the last guard may be dropped before the waker was pushed
+    if self.inner.pending.load(Ordering::Acquire) == 0 {
+      return Poll::Ready(Ok(()));
+    }
+    Poll::Pending
+  }
+}
@@ -5,8 +5,11 @@ use std::ptr;
 use std::rc::Rc;
 use std::sync::atomic::{AtomicU8, Ordering};
 
+use crate::async_hook::{AsyncWorkKind, AsyncWorkTracker};
 use crate::bindgen_runtime::PromiseRaw;
-use crate::{bindgen_runtime::ToNapiValue, check_status, sys, Env, JsError, Result, Task};
+use crate::{
+  bindgen_runtime::ToNapiValue, check_status, sys, Env, Error, JsError, Result, Status, Task,
+};
 
 struct AsyncWork<T: Task> {
   inner_task: T,
@@ -14,6 +17,7 @@ struct AsyncWork<T: Task> {
   value: Result<mem::MaybeUninit<T::Output>>,
   napi_async_work: sys::napi_async_work,
   status: Rc<AtomicU8>,
+  tracker: Option<AsyncWorkTracker>,
 }
 
 pub struct AsyncWorkPromise<T> {
@@ -58,6 +62,7 @@ pub fn run<T: Task>(
     value: Ok(mem::MaybeUninit::zeroed()),
     napi_async_work: ptr::null_mut(),
     status: task_status.clone(),
+    tracker: AsyncWorkTracker::queue::<T>(&Env::from_raw(env), AsyncWorkKind::Task),
   }));
   check_status!(unsafe {
     sys::napi_create_async_work(
@@ -88,6 +93,9 @@ unsafe impl<T: Task + Sync> Sync for AsyncWork<T> {}
 /// So it actually could do nothing here, because `execute` function is called in the other thread mostly.
 unsafe extern "C" fn execute<T: Task>(_env: sys::napi_env, data: *mut c_void) {
   let mut work = unsafe { Box::from_raw(data as *mut AsyncWork<T>) };
+  if let Some(tracker) = work.tracker.as_mut() {
+    tracker.start();
+  }
   let _ = mem::replace(
     &mut work.value,
     work.inner_task.compute().map(mem::MaybeUninit::new),
@@ -117,6 +125,9 @@ unsafe extern "C" fn complete<T: Task>(
       .and_then(|v| unsafe { ToNapiValue::to_napi_value(env, v) })
     {
       Ok(v) => {
+        if let Some(tracker) = work.tracker.as_mut() {
+          tracker.finish(Ok(()));
+        }
         let status = unsafe { sys::napi_resolve_deferred(env, deferred, v) };
         debug_assert!(
           status == sys::Status::napi_ok,
@@ -125,6 +136,9 @@ unsafe extern "C" fn complete<T: Task>(
         );
       }
       Err(e) => {
+        if let Some(tracker) = work.tracker.as_mut() {
+          tracker.finish::<()>(Err(&e));
+        }
         let status =
           unsafe { sys::napi_reject_deferred(env, deferred, JsError::from(e).into_value(env)) };
         debug_assert!(
@@ -134,6 +148,11 @@ unsafe extern "C" fn complete<T: Task>(
         );
       }
     };
+  } else if let Some(tracker) = work.tracker.as_mut() {
+    tracker.finish::<()>(Err(&Error::new(
+      Status::Cancelled,
+      "The async work was cancelled",
+    )));
   }
   if let Err(e) = work.inner_task.finally(Env::from_raw(env)) {
     debug_assert!(false, "Panic in Task finally fn: {:?}", e);
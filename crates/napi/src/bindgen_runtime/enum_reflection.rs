@@ -0,0 +1,132 @@
+use std::ptr;
+
+use super::{FromNapiValue, ToNapiValue};
+use crate::{check_status, sys, JsError, Result, ValueType};
+
+#[doc(hidden)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum EnumVariantValue {
+  Number(i32),
+  String(&'static str),
+}
+
+impl ToNapiValue for EnumVariantValue {
+  unsafe fn to_napi_value(env: sys::napi_env, val: Self) -> Result<sys::napi_value> {
+    match val {
+      EnumVariantValue::Number(number) => unsafe { i32::to_napi_value(env, number) },
+      EnumVariantValue::String(string) => unsafe { <&str>::to_napi_value(env, string) },
+    }
+  }
+}
+
+#[doc(hidden)]
+/// The variants of a `#[napi(reflect)]` enum, in declaration order
+pub trait ReflectEnum: 'static {
+  const VARIANTS: &'static [(&'static str, EnumVariantValue)];
+}
+
+const VALUES: &str = "values\0";
+const NAME_OF: &str = "nameOf\0";
+
+#[doc(hidden)]
+/// Define the non-enumerable `values()` and `nameOf(value)` of a `#[napi(reflect)]` enum on its
+/// object
+///
+/// # Safety
+///
+/// `object` must be a valid object of `env`
+pub unsafe fn define_enum_reflection<E: ReflectEnum>(
+  env: sys::napi_env,
+  object: sys::napi_value,
+) -> Result<()> {
+  let method = |name: &'static str, method: sys::napi_callback| sys::napi_property_descriptor {
+    utf8name: name.as_ptr().cast(),
+    name: ptr::null_mut(),
+    method,
+    getter: None,
+    setter: None,
+    value: ptr::null_mut(),
+    attributes: sys::PropertyAttributes::default,
+    data: ptr::null_mut(),
+  };
+  let properties = [
+    method(VALUES, Some(enum_values::<E>)),
+    method(NAME_OF, Some(enum_name_of::<E>)),
+  ];
+  check_status!(
+    unsafe { sys::napi_define_properties(env, object, properties.len(), properties.as_ptr()) },
+    "Failed to define the reflection of enum `{}`",
+    std::any::type_name::<E>()
+  )
+}
+
+unsafe extern "C" fn enum_values<E: ReflectEnum>(
+  env: sys::napi_env,
+  _cb_info: sys::napi_callback_info,
+) -> sys::napi_value {
+  let values = E::VARIANTS
+    .iter()
+    .map(|(_, value)| *value)
+    .collect::<Vec<_>>();
+  unsafe { Vec::to_napi_value(env, values) }.unwrap_or_else(|err| {
+    unsafe { JsError::from(err).throw_into(env) };
+    ptr::null_mut()
+  })
+}
+
+unsafe extern "C" fn enum_name_of<E: ReflectEnum>(
+  env: sys::napi_env,
+  cb_info: sys::napi_callback_info,
+) -> sys::napi_value {
+  unsafe { name_of::<E>(env, cb_info) }.unwrap_or_else(|err| {
+    unsafe { JsError::from(err).throw_into(env) };
+    ptr::null_mut()
+  })
+}
+
+/// The name of the variant of `E` with the value of the first argument, `undefined` if there is none
+unsafe fn name_of<E: ReflectEnum>(
+  env: sys::napi_env,
+  cb_info: sys::napi_callback_info,
+) -> Result<sys::napi_value> {
+  let mut argc = 1;
+  let mut value = ptr::null_mut();
+  check_status!(
+    unsafe {
+      sys::napi_get_cb_info(
+        env,
+        cb_info,
+        &mut argc,
+        &mut value,
+        ptr::null_mut(),
+        ptr::null_mut(),
+      )
+    },
+    "Failed to get the arguments of `nameOf`"
+  )?;
+  let mut value_type = 0;
+  check_status!(unsafe { sys::napi_typeof(env, value, &mut value_type) })?;
+  let name = match ValueType::from(value_type) {
+    ValueType::Number => {
+      let number = unsafe { f64::from_napi_value(env, value)? };
+      E::VARIANTS
+        .iter()
+        .find(|(_, value)| matches!(value, EnumVariantValue::Number(n) if f64::from(*n) == number))
+    }
+    ValueType::String => {
+      let string = unsafe { String::from_napi_value(env, value)? };
+      E::VARIANTS
+        .iter()
+        .find(|(_, value)| matches!(value, EnumVariantValue::String(s) if *s == string))
+    }
+    _ => None,
+  };
+  match name {
+    Some((name, _)) => unsafe { <&str>::to_napi_value(env, name) },
+    None => {
+      let mut undefined = ptr::null_mut();
+      check_status!(unsafe { sys::napi_get_undefined(env, &mut undefined) })?;
+      Ok(undefined)
+    }
+  }
+}
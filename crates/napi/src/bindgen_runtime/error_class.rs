@@ -0,0 +1,292 @@
+use std::any::TypeId;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt;
+use std::ptr;
+use std::sync::Arc;
+
+use super::ToNapiValue;
+use crate::{check_status, sys, Env, Error, JsError, Result, Status};
+
+#[doc(hidden)]
+/// A `#[napi(error)]` struct, thrown as an instance of its own subclass of `Error`
+pub trait ErrorClass: 'static {
+  /// The name of the class in JavaScript
+  const NAME: &'static str;
+}
+
+type ErrorField = Arc<dyn Fn(sys::napi_env) -> Result<sys::napi_value> + Send + Sync>;
+
+/// An error to throw or reject with as an instance of a `#[napi(error)]` class, with its fields
+/// set on it.
+///
+/// It's usually created from the `#[napi(error)]` struct itself, returning `Err(MyError { .. })`
+/// from a `#[napi(error = MyError)]` function, so `e instanceof MyError` is `true` on the JS side.
+#[derive(Clone)]
+pub struct JsErrorValue {
+  class_name: &'static str,
+  class: unsafe fn(sys::napi_env) -> Result<sys::napi_value>,
+  message: String,
+  fields: Vec<(&'static str, ErrorField)>,
+}
+
+impl JsErrorValue {
+  pub fn new<E: ErrorClass>(message: impl Into<String>) -> Self {
+    Self {
+      class_name: E::NAME,
+      class: error_class::<E>,
+      message: message.into(),
+      fields: Vec::new(),
+    }
+  }
+
+  /// Set `name` to `value` on the error when it's thrown
+  pub fn with_field<V>(mut self, name: &'static str, value: V) -> Self
+  where
+    V: ToNapiValue + Clone + Send + Sync + 'static,
+  {
+    self.fields.push((
+      name,
+      Arc::new(move |env| unsafe { V::to_napi_value(env, value.clone()) }),
+    ));
+    self
+  }
+
+  pub fn message(&self) -> &str {
+    &self.message
+  }
+
+  /// Create the JS error
+  ///
+  /// # Safety
+  ///
+  /// `env` must be a valid env, on its own thread
+  pub unsafe fn into_js_error(self, env: sys::napi_env) -> Result<sys::napi_value> {
+    let message = unsafe { <&str>::to_napi_value(env, &self.message)? };
+    let mut error = ptr::null_mut();
+    check_status!(
+      unsafe { sys::napi_create_error(env, ptr::null_mut(), message, &mut error) },
+      "Failed to create the `{}` error",
+      self.class_name
+    )?;
+    unsafe { self.init_js_error(env, error)? };
+    Ok(error)
+  }
+
+  /// Make the existing `error` an instance of the class and set the fields on it, its message is
+  /// left as is
+  pub(crate) unsafe fn init_js_error(
+    self,
+    env: sys::napi_env,
+    error: sys::napi_value,
+  ) -> Result<()> {
+    let class = unsafe { (self.class)(env)? };
+    let prototype = unsafe { get_property(env, class, c"prototype")? };
+    unsafe { set_prototype_of(env, error, prototype)? };
+    for (name, field) in self.fields {
+      let key = unsafe { <&str>::to_napi_value(env, name)? };
+      let value = field(env)?;
+      check_status!(
+        unsafe { sys::napi_set_property(env, error, key, value) },
+        "Failed to set the `{}` field of the `{}` error",
+        name,
+        self.class_name
+      )?;
+    }
+    Ok(())
+  }
+}
+
+impl fmt::Debug for JsErrorValue {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("JsErrorValue")
+      .field("class", &self.class_name)
+      .field("message", &self.message)
+      .field(
+        "fields",
+        &self.fields.iter().map(|(name, _)| name).collect::<Vec<_>>(),
+      )
+      .finish()
+  }
+}
+
+impl From<JsErrorValue> for Error {
+  fn from(value: JsErrorValue) -> Self {
+    let mut error = Error::new(Status::GenericFailure, value.message.clone());
+    error.js_error_value = Some(Box::new(value));
+    error
+  }
+}
+
+/// The classes created in an env, they are created once per env on first use
+struct ErrorClasses(RefCell<HashMap<TypeId, sys::napi_ref>>);
+
+#[doc(hidden)]
+/// The class of `E` in `env`, created on first use
+///
+/// # Safety
+///
+/// `env` must be a valid env, on its own thread
+pub unsafe fn error_class<E: ErrorClass>(env: sys::napi_env) -> Result<sys::napi_value> {
+  let classes = Env::from_raw(env)
+    .get_or_init_instance_data_typed(|| Ok(ErrorClasses(RefCell::new(HashMap::new()))))?;
+  if let Some(reference) = classes.0.borrow().get(&TypeId::of::<E>()) {
+    let mut class = ptr::null_mut();
+    check_status!(
+      unsafe { sys::napi_get_reference_value(env, *reference, &mut class) },
+      "Failed to get the class of `{}`",
+      E::NAME
+    )?;
+    return Ok(class);
+  }
+  let class = unsafe { create_error_class(env, E::NAME)? };
+  let mut reference = ptr::null_mut();
+  check_status!(
+    unsafe { sys::napi_create_reference(env, class, 1, &mut reference) },
+    "Failed to create a reference to the class of `{}`",
+    E::NAME
+  )?;
+  classes.0.borrow_mut().insert(TypeId::of::<E>(), reference);
+  Ok(class)
+}
+
+/// Create a class extending `Error`, whose instances are real errors with a stack
+unsafe fn create_error_class(env: sys::napi_env, name: &str) -> Result<sys::napi_value> {
+  let mut class = ptr::null_mut();
+  check_status!(
+    unsafe {
+      sys::napi_define_class(
+        env,
+        name.as_ptr().cast(),
+        name.len(),
+        Some(construct_error),
+        ptr::null_mut(),
+        0,
+        ptr::null(),
+        &mut class,
+      )
+    },
+    "Failed to define the `{}` class",
+    name
+  )?;
+  let mut global = ptr::null_mut();
+  check_status!(unsafe { sys::napi_get_global(env, &mut global) })?;
+  let error = unsafe { get_property(env, global, c"Error")? };
+  let error_prototype = unsafe { get_property(env, error, c"prototype")? };
+  let prototype = unsafe { get_property(env, class, c"prototype")? };
+  unsafe { set_prototype_of(env, prototype, error_prototype)? };
+  unsafe { set_prototype_of(env, class, error)? };
+  // non-enumerable like `Error.prototype.name`
+  let name_property = sys::napi_property_descriptor {
+    utf8name: c"name".as_ptr(),
+    name: ptr::null_mut(),
+    method: None,
+    getter: None,
+    setter: None,
+    value: unsafe { <&str>::to_napi_value(env, name)? },
+    attributes: sys::PropertyAttributes::writable | sys::PropertyAttributes::configurable,
+    data: ptr::null_mut(),
+  };
+  check_status!(
+    unsafe { sys::napi_define_properties(env, prototype, 1, &name_property) },
+    "Failed to define the name of the `{}` class",
+    name
+  )?;
+  Ok(class)
+}
+
+/// `new MyError(message)` from JavaScript, creating a real error with the prototype of the class
+unsafe extern "C" fn construct_error(
+  env: sys::napi_env,
+  cb_info: sys::napi_callback_info,
+) -> sys::napi_value {
+  unsafe { construct(env, cb_info) }.unwrap_or_else(|err| {
+    unsafe { JsError::from(err).throw_into(env) };
+    ptr::null_mut()
+  })
+}
+
+unsafe fn construct(
+  env: sys::napi_env,
+  cb_info: sys::napi_callback_info,
+) -> Result<sys::napi_value> {
+  let mut argc = 1;
+  let mut message = ptr::null_mut();
+  check_status!(
+    unsafe {
+      sys::napi_get_cb_info(
+        env,
+        cb_info,
+        &mut argc,
+        &mut message,
+        ptr::null_mut(),
+        ptr::null_mut(),
+      )
+    },
+    "Failed to get the arguments of the error constructor"
+  )?;
+  let mut new_target = ptr::null_mut();
+  check_status!(unsafe { sys::napi_get_new_target(env, cb_info, &mut new_target) })?;
+  if new_target.is_null() {
+    return Err(Error::new(
+      Status::InvalidArg,
+      "The error class constructor cannot be invoked without 'new'",
+    ));
+  }
+  let mut message_type = sys::ValueType::napi_undefined;
+  if argc > 0 {
+    check_status!(unsafe { sys::napi_typeof(env, message, &mut message_type) })?;
+  }
+  let message = if message_type == sys::ValueType::napi_undefined {
+    unsafe { <&str>::to_napi_value(env, "")? }
+  } else {
+    let mut string = ptr::null_mut();
+    check_status!(unsafe { sys::napi_coerce_to_string(env, message, &mut string) })?;
+    string
+  };
+  let mut error = ptr::null_mut();
+  check_status!(unsafe { sys::napi_create_error(env, ptr::null_mut(), message, &mut error) })?;
+  let prototype = unsafe { get_property(env, new_target, c"prototype")? };
+  unsafe { set_prototype_of(env, error, prototype)? };
+  Ok(error)
+}
+
+unsafe fn get_property(
+  env: sys::napi_env,
+  object: sys::napi_value,
+  name: &std::ffi::CStr,
+) -> Result<sys::napi_value> {
+  let mut value = ptr::null_mut();
+  check_status!(
+    unsafe { sys::napi_get_named_property(env, object, name.as_ptr(), &mut value) },
+    "Failed to get `{}`",
+    name.to_string_lossy()
+  )?;
+  Ok(value)
+}
+
+/// `Object.setPrototypeOf(object, prototype)`
+unsafe fn set_prototype_of(
+  env: sys::napi_env,
+  object: sys::napi_value,
+  prototype: sys::napi_value,
+) -> Result<()> {
+  let mut global = ptr::null_mut();
+  check_status!(unsafe { sys::napi_get_global(env, &mut global) })?;
+  let object_class = unsafe { get_property(env, global, c"Object")? };
+  let set_prototype_of = unsafe { get_property(env, object_class, c"setPrototypeOf")? };
+  let args = [object, prototype];
+  check_status!(
+    unsafe {
+      sys::napi_call_function(
+        env,
+        object_class,
+        set_prototype_of,
+        args.len(),
+        args.as_ptr(),
+        ptr::null_mut(),
+      )
+    },
+    "Failed to set the prototype of the error"
+  )
+}
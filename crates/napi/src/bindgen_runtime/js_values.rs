@@ -8,51 +8,86 @@ use crate::{check_status, sys, Error, JsUnknown, NapiRaw, NapiValue, Result, Sta
 
 mod array;
 mod arraybuffer;
+#[cfg(feature = "async_generator")]
+mod async_generator;
 #[cfg(feature = "napi6")]
 mod bigint;
+mod blob;
 mod boolean;
+mod borrowed;
 mod buffer;
+mod cancel_flag;
 mod class;
+mod class_pool;
 #[cfg(all(feature = "chrono_date", feature = "napi5"))]
 mod date;
 mod either;
 mod external;
+mod fetch;
 mod function;
+mod intl;
 mod map;
 mod nil;
 mod number;
 mod object;
+#[cfg(feature = "pod")]
+mod pod;
 #[cfg(all(feature = "tokio_rt", feature = "napi4"))]
 mod promise;
+mod promise_combinators;
+mod promise_future;
 mod promise_raw;
+mod range;
 #[cfg(feature = "serde-json")]
 mod serde;
 mod string;
 mod symbol;
 mod task;
+#[cfg(feature = "transform_stream")]
+mod transform;
 mod value_ref;
+#[cfg(feature = "napi8")]
+mod vtable;
 
 pub use crate::js_values::JsUnknown as Unknown;
 #[cfg(feature = "napi5")]
 pub use crate::JsDate as Date;
+pub use crate::{JsRegExp as RegExp, RegExpMatch};
 pub use array::*;
 pub use arraybuffer::*;
+#[cfg(feature = "async_generator")]
+pub use async_generator::*;
 #[cfg(feature = "napi6")]
 pub use bigint::*;
+pub use blob::*;
+pub use borrowed::*;
 pub use buffer::*;
+pub use cancel_flag::*;
 pub use class::*;
+pub use class_pool::*;
 pub use either::*;
 pub use external::*;
+pub use fetch::*;
 pub use function::*;
+pub use intl::*;
 pub use nil::*;
+pub use number::*;
 pub use object::*;
+#[cfg(feature = "pod")]
+pub use pod::*;
 #[cfg(all(feature = "tokio_rt", feature = "napi4"))]
 pub use promise::*;
+pub use promise_future::*;
 pub use promise_raw::*;
+pub use range::*;
 pub use string::*;
 pub use symbol::*;
 pub use task::*;
+#[cfg(feature = "transform_stream")]
+pub use transform::*;
 pub use value_ref::*;
+#[cfg(feature = "napi8")]
+pub use vtable::*;
 
 pub trait TypeName {
   fn type_name() -> &'static str;
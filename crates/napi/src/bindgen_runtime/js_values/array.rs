@@ -177,6 +177,16 @@ impl<T> TypeName for Vec<T> {
   }
 }
 
+impl<T, const N: usize> TypeName for [T; N] {
+  fn type_name() -> &'static str {
+    "[T; N]"
+  }
+
+  fn value_type() -> ValueType {
+    ValueType::Object
+  }
+}
+
 impl<T, const N: usize> ToNapiValue for [T; N]
 where
   T: ToNapiValue + Copy,
@@ -250,6 +260,10 @@ where
   T: FromNapiValue,
 {
   unsafe fn from_napi_value(env: sys::napi_env, napi_val: sys::napi_value) -> Result<Self> {
+    #[cfg(feature = "napi6")]
+    if let Some(vec) = unsafe { vec_from_typed_array_as::<i64, T>(env, napi_val)? } {
+      return Ok(vec);
+    }
     let arr = unsafe { Array::from_napi_value(env, napi_val)? };
     let mut vec = vec![];
 
@@ -268,6 +282,49 @@ where
   }
 }
 
+impl<T, const N: usize> FromNapiValue for [T; N]
+where
+  T: FromNapiValue,
+{
+  unsafe fn from_napi_value(env: sys::napi_env, napi_val: sys::napi_value) -> Result<Self> {
+    let arr = unsafe { Array::from_napi_value(env, napi_val)? };
+    if arr.len() as usize != N {
+      return Err(Error::new(
+        Status::InvalidArg,
+        format!("Expected an array of length {}, got {}", N, arr.len()),
+      ));
+    }
+    let vec = unsafe { Vec::<T>::from_napi_value(env, napi_val)? };
+    vec.try_into().map_err(|vec: Vec<T>| {
+      Error::new(
+        Status::InvalidArg,
+        format!("Expected an array of length {}, got {}", N, vec.len()),
+      )
+    })
+  }
+}
+
+impl<T, const N: usize> ValidateNapiValue for [T; N]
+where
+  T: FromNapiValue,
+{
+  unsafe fn validate(env: sys::napi_env, napi_val: sys::napi_value) -> Result<sys::napi_value> {
+    unsafe { Vec::<T>::validate(env, napi_val)? };
+    let mut length = 0;
+    check_status!(
+      unsafe { sys::napi_get_array_length(env, napi_val, &mut length) },
+      "Failed to get the length of the array"
+    )?;
+    if length as usize != N {
+      return Err(Error::new(
+        Status::InvalidArg,
+        format!("Expected an array of length {}, got {}", N, length),
+      ));
+    }
+    Ok(ptr::null_mut())
+  }
+}
+
 impl<T> ValidateNapiValue for Vec<T>
 where
   T: FromNapiValue,
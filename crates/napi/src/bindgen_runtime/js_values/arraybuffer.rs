@@ -1,4 +1,8 @@
+#[cfg(feature = "napi6")]
+use std::any::TypeId;
 use std::ffi::c_void;
+#[cfg(feature = "napi6")]
+use std::marker::PhantomData;
 use std::mem;
 use std::ops::{Deref, DerefMut};
 use std::ptr;
@@ -216,6 +220,16 @@ macro_rules! impl_typed_array {
         ret
       }
 
+      /// The elements, shared with the JavaScript `TypedArray` without copying
+      pub fn as_slice(&self) -> &[$rust_type] {
+        self.as_ref()
+      }
+
+      /// The elements, the changes are visible to JavaScript without copying
+      pub fn as_mut_slice(&mut self) -> &mut [$rust_type] {
+        self.as_mut()
+      }
+
       /// # Safety
       ///
       /// The caller will be notified when the data is deallocated by vm
@@ -659,6 +673,150 @@ impl_typed_array!(BigUint64Array, u64, TypedArrayType::BigUint64);
 #[cfg(feature = "napi6")]
 impl_from_slice!(BigUint64Array, u64, TypedArrayType::BigUint64);
 
+/// The elements of the TypedArrays a `Vec` is copied from, besides the `Array`s
+#[cfg(feature = "napi6")]
+pub(crate) trait TypedArrayElement: Copy + 'static {
+  const TYPED_ARRAY_TYPE: TypedArrayType;
+}
+
+#[cfg(feature = "napi6")]
+impl TypedArrayElement for i64 {
+  const TYPED_ARRAY_TYPE: TypedArrayType = TypedArrayType::BigInt64;
+}
+
+#[cfg(feature = "napi6")]
+impl TypedArrayElement for u64 {
+  const TYPED_ARRAY_TYPE: TypedArrayType = TypedArrayType::BigUint64;
+}
+
+/// Copy the elements of a TypedArray of `T`, `None` if `napi_val` is not a TypedArray
+#[cfg(feature = "napi6")]
+pub(crate) unsafe fn vec_from_typed_array<T: TypedArrayElement>(
+  env: sys::napi_env,
+  napi_val: sys::napi_value,
+) -> Result<Option<Vec<T>>> {
+  let expected_type = T::TYPED_ARRAY_TYPE;
+  let mut is_typed_array = false;
+  check_status!(
+    unsafe { sys::napi_is_typedarray(env, napi_val, &mut is_typed_array) },
+    "Failed to check the value is a TypedArray"
+  )?;
+  if !is_typed_array {
+    return Ok(None);
+  }
+  let mut typed_array_type = 0;
+  let mut length = 0;
+  let mut data = ptr::null_mut();
+  check_status!(
+    unsafe {
+      sys::napi_get_typedarray_info(
+        env,
+        napi_val,
+        &mut typed_array_type,
+        &mut length,
+        &mut data,
+        ptr::null_mut(),
+        ptr::null_mut(),
+      )
+    },
+    "Get TypedArray info failed"
+  )?;
+  if typed_array_type != expected_type as i32 {
+    return Err(Error::new(
+      Status::InvalidArg,
+      format!(
+        "Expected an Array or a {}Array, got {}Array",
+        expected_type.as_ref(),
+        TypedArrayType::from(typed_array_type).as_ref()
+      ),
+    ));
+  }
+  Ok(Some(if length == 0 {
+    vec![]
+  } else {
+    unsafe { std::slice::from_raw_parts(data.cast::<T>(), length) }.to_vec()
+  }))
+}
+
+/// [`vec_from_typed_array`] for the `Vec<T>` of any `FromNapiValue`, `None` if `T` is not `E`
+#[cfg(feature = "napi6")]
+pub(crate) unsafe fn vec_from_typed_array_as<E: TypedArrayElement, T>(
+  env: sys::napi_env,
+  napi_val: sys::napi_value,
+) -> Result<Option<Vec<T>>> {
+  if non_static_type_id::<T>() != TypeId::of::<E>() {
+    return Ok(None);
+  }
+  Ok(
+    unsafe { vec_from_typed_array::<E>(env, napi_val)? }.map(|vec| {
+      let mut vec = mem::ManuallyDrop::new(vec);
+      // `T` is `E`
+      unsafe { Vec::from_raw_parts(vec.as_mut_ptr().cast::<T>(), vec.len(), vec.capacity()) }
+    }),
+  )
+}
+
+/// The `TypeId` of `T` without requiring `T: 'static`, its lifetimes are erased.
+///
+/// It's only compared with the ids of types without lifetimes, which are then the same type.
+#[cfg(feature = "napi6")]
+fn non_static_type_id<T: ?Sized>() -> TypeId {
+  trait NonStaticAny {
+    fn get_type_id(&self) -> TypeId
+    where
+      Self: 'static;
+  }
+
+  impl<T: ?Sized> NonStaticAny for PhantomData<T> {
+    fn get_type_id(&self) -> TypeId
+    where
+      Self: 'static,
+    {
+      TypeId::of::<T>()
+    }
+  }
+
+  let phantom_data = PhantomData::<T>;
+  NonStaticAny::get_type_id(unsafe {
+    mem::transmute::<&dyn NonStaticAny, &(dyn NonStaticAny + 'static)>(&phantom_data)
+  })
+}
+
+/// `u64` is not converted from a `number`, only from the elements of a `BigUint64Array` or from
+/// the `bigint`s of an `Array` when it's lossless
+#[cfg(feature = "napi6")]
+impl FromNapiValue for Vec<u64> {
+  unsafe fn from_napi_value(env: sys::napi_env, napi_val: sys::napi_value) -> Result<Self> {
+    if let Some(vec) = unsafe { vec_from_typed_array(env, napi_val)? } {
+      return Ok(vec);
+    }
+    let arr = unsafe { super::Array::from_napi_value(env, napi_val)? };
+    (0..arr.len())
+      .map(|i| {
+        let value = arr
+          .get::<super::Unknown>(i)?
+          .ok_or_else(|| Error::new(Status::InvalidArg, format!("Missing element {i}")))?;
+        let mut result = 0;
+        let mut lossless = false;
+        check_status!(
+          unsafe {
+            sys::napi_get_value_bigint_uint64(env, value.0.value, &mut result, &mut lossless)
+          },
+          "Failed to convert the element {} into u64",
+          i
+        )?;
+        if !lossless {
+          return Err(Error::new(
+            Status::InvalidArg,
+            format!("The element {i} doesn't fit in u64"),
+          ));
+        }
+        Ok(result)
+      })
+      .collect()
+  }
+}
+
 impl Uint8Array {
   /// Create a new JavaScript `Uint8Array` from a Rust `String` without copying the underlying data.
   pub fn from_string(mut s: String) -> Self {
@@ -779,6 +937,196 @@ impl<'scope> DerefMut for Uint8ClampedSlice<'scope> {
   }
 }
 
+#[cfg(feature = "napi6")]
+macro_rules! impl_typed_array_slice {
+  ($name:ident, $rust_type:ident, $typed_array_type:expr, $js_name:literal) => {
+    #[doc = concat!("Zero copy `", $js_name, "` view shared between Rust and Node.js.")]
+    ///
+    /// It can only be used in non-async context and the lifetime is bound to the fn closure.
+    #[doc = concat!("Use `", $js_name, "` in async context or to extend the lifetime.")]
+    pub struct $name<'scope> {
+      pub(crate) inner: &'scope mut [$rust_type],
+      raw_value: sys::napi_value,
+    }
+
+    impl<'scope> $name<'scope> {
+      #[doc = concat!("Create a new `", $js_name, "` with a copy of `data`")]
+      pub fn copy_from<D: AsRef<[$rust_type]>>(env: &crate::Env, data: D) -> Result<Self> {
+        let data = data.as_ref();
+        let mut array_buffer_data = ptr::null_mut();
+        let mut array_buffer = ptr::null_mut();
+        check_status!(
+          unsafe {
+            sys::napi_create_arraybuffer(
+              env.0,
+              mem::size_of_val(data),
+              &mut array_buffer_data,
+              &mut array_buffer,
+            )
+          },
+          "Failed to create the ArrayBuffer of a {}",
+          $js_name
+        )?;
+        let mut raw_value = ptr::null_mut();
+        check_status!(
+          unsafe {
+            sys::napi_create_typedarray(
+              env.0,
+              $typed_array_type as i32,
+              data.len(),
+              array_buffer,
+              0,
+              &mut raw_value,
+            )
+          },
+          "Failed to create a {}",
+          $js_name
+        )?;
+        let inner = if data.is_empty() {
+          &mut []
+        } else {
+          let inner =
+            unsafe { std::slice::from_raw_parts_mut(array_buffer_data.cast(), data.len()) };
+          inner.copy_from_slice(data);
+          inner
+        };
+        Ok(Self { inner, raw_value })
+      }
+    }
+
+    impl crate::NapiRaw for $name<'_> {
+      unsafe fn raw(&self) -> sys::napi_value {
+        self.raw_value
+      }
+    }
+
+    impl FromNapiValue for $name<'_> {
+      unsafe fn from_napi_value(env: sys::napi_env, napi_val: sys::napi_value) -> Result<Self> {
+        let mut typed_array_type = 0;
+        let mut length = 0;
+        let mut data = ptr::null_mut();
+        check_status!(
+          unsafe {
+            sys::napi_get_typedarray_info(
+              env,
+              napi_val,
+              &mut typed_array_type,
+              &mut length,
+              &mut data,
+              ptr::null_mut(),
+              ptr::null_mut(),
+            )
+          },
+          "Get TypedArray info failed"
+        )?;
+        if typed_array_type != $typed_array_type as i32 {
+          return Err(Error::new(
+            Status::InvalidArg,
+            format!(
+              "Expected {}, got {}Array",
+              $js_name,
+              TypedArrayType::from(typed_array_type).as_ref()
+            ),
+          ));
+        }
+        Ok(Self {
+          inner: if length == 0 {
+            &mut []
+          } else {
+            unsafe { std::slice::from_raw_parts_mut(data.cast(), length) }
+          },
+          raw_value: napi_val,
+        })
+      }
+    }
+
+    impl TypeName for $name<'_> {
+      fn type_name() -> &'static str {
+        $js_name
+      }
+
+      fn value_type() -> ValueType {
+        ValueType::Object
+      }
+    }
+
+    impl ValidateNapiValue for $name<'_> {
+      unsafe fn validate(env: sys::napi_env, napi_val: sys::napi_value) -> Result<sys::napi_value> {
+        let mut is_typedarray = false;
+        check_status!(
+          unsafe { sys::napi_is_typedarray(env, napi_val, &mut is_typedarray) },
+          "Failed to validate typed buffer"
+        )?;
+        let mut typed_array_type = 0;
+        if is_typedarray {
+          check_status!(
+            unsafe {
+              sys::napi_get_typedarray_info(
+                env,
+                napi_val,
+                &mut typed_array_type,
+                ptr::null_mut(),
+                ptr::null_mut(),
+                ptr::null_mut(),
+                ptr::null_mut(),
+              )
+            },
+            "Get TypedArray info failed"
+          )?;
+        }
+        if !is_typedarray || typed_array_type != $typed_array_type as i32 {
+          return Err(Error::new(
+            Status::InvalidArg,
+            concat!("Expected a ", $js_name, " value").to_owned(),
+          ));
+        }
+        Ok(ptr::null_mut())
+      }
+    }
+
+    impl AsRef<[$rust_type]> for $name<'_> {
+      fn as_ref(&self) -> &[$rust_type] {
+        self.inner
+      }
+    }
+
+    impl AsMut<[$rust_type]> for $name<'_> {
+      fn as_mut(&mut self) -> &mut [$rust_type] {
+        self.inner
+      }
+    }
+
+    impl Deref for $name<'_> {
+      type Target = [$rust_type];
+
+      fn deref(&self) -> &Self::Target {
+        self.inner
+      }
+    }
+
+    impl DerefMut for $name<'_> {
+      fn deref_mut(&mut self) -> &mut Self::Target {
+        self.inner
+      }
+    }
+  };
+}
+
+#[cfg(feature = "napi6")]
+impl_typed_array_slice!(
+  BigInt64Slice,
+  i64,
+  TypedArrayType::BigInt64,
+  "BigInt64Array"
+);
+#[cfg(feature = "napi6")]
+impl_typed_array_slice!(
+  BigUint64Slice,
+  u64,
+  TypedArrayType::BigUint64,
+  "BigUint64Array"
+);
+
 impl<T: Into<Vec<u8>>> From<T> for Uint8Array {
   fn from(data: T) -> Self {
     Uint8Array::new(data.into())
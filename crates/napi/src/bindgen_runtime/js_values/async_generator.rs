@@ -0,0 +1,147 @@
+use std::future::poll_fn;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use futures_core::Stream;
+use tokio::sync::Mutex;
+
+use super::{ToNapiValue, TypeName, Unknown};
+use crate::{
+  sys, tokio_runtime::execute_tokio_future, Env, Error, JsObject, NapiRaw, NapiValue, Result,
+  ValueType,
+};
+
+type BoxedStream<T> = Pin<Box<dyn Stream<Item = Result<T>> + Send>>;
+
+/// A [`Stream`] exposed to JavaScript as an async generator, to be consumed with `for await`.
+///
+/// Items are polled in the tokio runtime, one `next()` at a time and in order. An `Err` item
+/// rejects the pending `next()` and ends the generator, like a throwing `async function*`.
+///
+/// ```rust
+/// #[napi]
+/// pub fn count_to(n: u32) -> AsyncGenerator<u32> {
+///   AsyncGenerator::new(futures::stream::iter((1..=n).map(Ok)))
+/// }
+/// ```
+///
+/// ```js
+/// for await (const n of countTo(3)) {
+///   console.log(n) // 1, 2, 3
+/// }
+/// ```
+pub struct AsyncGenerator<T> {
+  stream: BoxedStream<T>,
+}
+
+impl<T: ToNapiValue + Send + 'static> AsyncGenerator<T> {
+  /// Wrap a stream, from `async-stream`, `futures::stream` or a struct implementing `poll_next`
+  pub fn new<S>(stream: S) -> Self
+  where
+    S: Stream<Item = Result<T>> + Send + 'static,
+  {
+    Self {
+      stream: Box::pin(stream),
+    }
+  }
+}
+
+impl<T> TypeName for AsyncGenerator<T> {
+  fn type_name() -> &'static str {
+    "AsyncGenerator"
+  }
+
+  fn value_type() -> ValueType {
+    ValueType::Object
+  }
+}
+
+impl<T: ToNapiValue + Send + 'static> ToNapiValue for AsyncGenerator<T> {
+  unsafe fn to_napi_value(env: sys::napi_env, val: Self) -> Result<sys::napi_value> {
+    let env = Env::from_raw(env);
+    // `None` once the generator is done
+    let stream = Arc::new(Mutex::new(Some(val.stream)));
+    let mut generator = env.create_object()?;
+
+    let next_stream = stream.clone();
+    let next = env.create_function_from_closure::<Unknown, Unknown, _>("next", move |ctx| {
+      let stream = next_stream.clone();
+      let promise = execute_tokio_future(
+        ctx.env.raw(),
+        async move {
+          let mut stream = stream.lock().await;
+          let item = match stream.as_mut() {
+            Some(inner) => poll_fn(|cx| inner.as_mut().poll_next(cx)).await,
+            None => None,
+          };
+          if !matches!(item, Some(Ok(_))) {
+            *stream = None;
+          }
+          item.transpose()
+        },
+        |env, item| unsafe { iterator_result(env, item) },
+      )?;
+      Ok(unsafe { Unknown::from_raw_unchecked(ctx.env.raw(), promise) })
+    })?;
+
+    let return_stream = stream.clone();
+    let complete =
+      env.create_function_from_closure::<Unknown, Unknown, _>("return", move |ctx| {
+        let stream = return_stream.clone();
+        let promise = execute_tokio_future(
+          ctx.env.raw(),
+          async move {
+            stream.lock().await.take();
+            Ok::<_, Error>(())
+          },
+          |env, _| unsafe { iterator_result::<T>(env, None) },
+        )?;
+        Ok(unsafe { Unknown::from_raw_unchecked(ctx.env.raw(), promise) })
+      })?;
+
+    let throw_stream = stream;
+    let throw = env.create_function_from_closure::<Unknown, Unknown, _>("throw", move |ctx| {
+      let stream = throw_stream.clone();
+      let reason = Error::from(ctx.first_arg::<Unknown>()?);
+      let promise = execute_tokio_future(
+        ctx.env.raw(),
+        async move {
+          stream.lock().await.take();
+          Err::<(), _>(reason)
+        },
+        |env, _| unsafe { iterator_result::<T>(env, None) },
+      )?;
+      Ok(unsafe { Unknown::from_raw_unchecked(ctx.env.raw(), promise) })
+    })?;
+
+    let this = env
+      .create_function_from_closure::<(), Unknown, _>("[Symbol.asyncIterator]", |ctx| {
+        ctx.this::<Unknown>()
+      })?;
+
+    generator.set_named_property("next", next)?;
+    generator.set_named_property("return", complete)?;
+    generator.set_named_property("throw", throw)?;
+    // `Symbol` is a function, read it without the object type check
+    let symbol: JsObject = env.get_global()?.get_named_property_unchecked("Symbol")?;
+    let async_iterator: Unknown = symbol.get_named_property("asyncIterator")?;
+    generator.set_property(async_iterator, this)?;
+    Ok(unsafe { generator.raw() })
+  }
+}
+
+/// `{ value, done }` of `next()`
+unsafe fn iterator_result<T: ToNapiValue>(
+  env: sys::napi_env,
+  item: Option<T>,
+) -> Result<sys::napi_value> {
+  let env = Env::from_raw(env);
+  let mut result = env.create_object()?;
+  let done = item.is_none();
+  match item {
+    Some(value) => result.set_named_property("value", value)?,
+    None => result.set_named_property("value", ())?,
+  }
+  result.set_named_property("done", done)?;
+  Ok(unsafe { result.raw() })
+}
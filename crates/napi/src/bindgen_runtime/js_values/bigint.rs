@@ -128,8 +128,75 @@ impl BigInt {
       (self.sign_bit, val, len > 2)
     }
   }
+
+  /// The value as an `i64`, an `InvalidArg` error if it doesn't fit
+  pub fn try_into_i64(&self) -> crate::Result<i64> {
+    i64::try_from(self.try_into_i128()?).map_err(|_| self.out_of_range("i64"))
+  }
+
+  /// The value as a `u64`, an `InvalidArg` error if it's negative or doesn't fit
+  pub fn try_into_u64(&self) -> crate::Result<u64> {
+    u64::try_from(self.try_into_u128()?).map_err(|_| self.out_of_range("u64"))
+  }
+
+  /// The value as an `i128`, an `InvalidArg` error if it doesn't fit
+  pub fn try_into_i128(&self) -> crate::Result<i128> {
+    let magnitude = self.magnitude().ok_or_else(|| self.out_of_range("i128"))?;
+    if self.sign_bit {
+      // `i128::MIN` has no positive counterpart
+      if magnitude > i128::MIN.unsigned_abs() {
+        return Err(self.out_of_range("i128"));
+      }
+      Ok((magnitude as i128).wrapping_neg())
+    } else {
+      i128::try_from(magnitude).map_err(|_| self.out_of_range("i128"))
+    }
+  }
+
+  /// The value as a `u128`, an `InvalidArg` error if it's negative or doesn't fit
+  pub fn try_into_u128(&self) -> crate::Result<u128> {
+    match self.magnitude() {
+      Some(0) => Ok(0),
+      Some(magnitude) if !self.sign_bit => Ok(magnitude),
+      _ => Err(self.out_of_range("u128")),
+    }
+  }
+
+  /// The absolute value, `None` if it's over `u128::MAX`
+  fn magnitude(&self) -> Option<u128> {
+    if self.words.iter().skip(2).any(|word| *word != 0) {
+      return None;
+    }
+    let low = self.words.first().copied().unwrap_or(0);
+    let high = self.words.get(1).copied().unwrap_or(0);
+    Some(u128::from(low) | (u128::from(high) << 64))
+  }
+
+  fn out_of_range(&self, target: &str) -> crate::Error {
+    crate::Error::new(
+      crate::Status::InvalidArg,
+      format!("The BigInt doesn't fit in {target}"),
+    )
+  }
 }
 
+macro_rules! impl_try_from_bigint {
+  ($($ty:ident => $method:ident),*) => {
+    $(
+      /// Fails if the value doesn't fit, unlike the `get_*` methods which truncate it
+      impl TryFrom<BigInt> for $ty {
+        type Error = crate::Error;
+
+        fn try_from(value: BigInt) -> crate::Result<$ty> {
+          value.$method()
+        }
+      }
+    )*
+  };
+}
+
+impl_try_from_bigint!(i64 => try_into_i64, u64 => try_into_u64, i128 => try_into_i128, u128 => try_into_u128);
+
 impl ToNapiValue for BigInt {
   unsafe fn to_napi_value(env: sys::napi_env, val: Self) -> crate::Result<sys::napi_value> {
     let mut raw_value = ptr::null_mut();
@@ -256,3 +323,118 @@ impl From<u128> for BigInt {
     }
   }
 }
+
+#[cfg(feature = "num-bigint")]
+impl From<num_bigint::BigInt> for BigInt {
+  fn from(val: num_bigint::BigInt) -> Self {
+    BigInt {
+      sign_bit: val.sign() == num_bigint::Sign::Minus,
+      words: val.magnitude().to_u64_digits(),
+    }
+  }
+}
+
+#[cfg(feature = "num-bigint")]
+impl From<num_bigint::BigUint> for BigInt {
+  fn from(val: num_bigint::BigUint) -> Self {
+    BigInt {
+      sign_bit: false,
+      words: val.to_u64_digits(),
+    }
+  }
+}
+
+#[cfg(feature = "num-bigint")]
+impl From<BigInt> for num_bigint::BigInt {
+  fn from(val: BigInt) -> Self {
+    let sign = if val.sign_bit {
+      num_bigint::Sign::Minus
+    } else {
+      num_bigint::Sign::Plus
+    };
+    num_bigint::BigInt::from_biguint(sign, val.into_biguint())
+  }
+}
+
+#[cfg(feature = "num-bigint")]
+impl BigInt {
+  /// The absolute value
+  fn into_biguint(self) -> num_bigint::BigUint {
+    let bytes = self
+      .words
+      .iter()
+      .flat_map(|word| word.to_le_bytes())
+      .collect::<Vec<_>>();
+    num_bigint::BigUint::from_bytes_le(&bytes)
+  }
+}
+
+#[cfg(feature = "num-bigint")]
+impl TryFrom<BigInt> for num_bigint::BigUint {
+  type Error = crate::Error;
+
+  /// Fails if the value is negative
+  fn try_from(val: BigInt) -> crate::Result<Self> {
+    if val.sign_bit && val.words.iter().any(|word| *word != 0) {
+      return Err(val.out_of_range("BigUint"));
+    }
+    Ok(val.into_biguint())
+  }
+}
+
+#[cfg(feature = "num-bigint")]
+impl TypeName for num_bigint::BigInt {
+  fn type_name() -> &'static str {
+    "BigInt"
+  }
+
+  fn value_type() -> crate::ValueType {
+    crate::ValueType::BigInt
+  }
+}
+
+#[cfg(feature = "num-bigint")]
+impl ValidateNapiValue for num_bigint::BigInt {}
+
+#[cfg(feature = "num-bigint")]
+impl FromNapiValue for num_bigint::BigInt {
+  unsafe fn from_napi_value(env: sys::napi_env, napi_val: sys::napi_value) -> crate::Result<Self> {
+    Ok(unsafe { BigInt::from_napi_value(env, napi_val) }?.into())
+  }
+}
+
+#[cfg(feature = "num-bigint")]
+impl ToNapiValue for num_bigint::BigInt {
+  unsafe fn to_napi_value(env: sys::napi_env, val: Self) -> crate::Result<sys::napi_value> {
+    unsafe { BigInt::to_napi_value(env, val.into()) }
+  }
+}
+
+#[cfg(feature = "num-bigint")]
+impl TypeName for num_bigint::BigUint {
+  fn type_name() -> &'static str {
+    "BigUint"
+  }
+
+  fn value_type() -> crate::ValueType {
+    crate::ValueType::BigInt
+  }
+}
+
+#[cfg(feature = "num-bigint")]
+impl ValidateNapiValue for num_bigint::BigUint {}
+
+#[cfg(feature = "num-bigint")]
+impl FromNapiValue for num_bigint::BigUint {
+  /// Fails with `InvalidArg` on negative values
+  unsafe fn from_napi_value(env: sys::napi_env, napi_val: sys::napi_value) -> crate::Result<Self> {
+    unsafe { BigInt::from_napi_value(env, napi_val) }?.try_into()
+  }
+}
+
+#[cfg(feature = "num-bigint")]
+impl ToNapiValue for num_bigint::BigUint {
+  unsafe fn to_napi_value(env: sys::napi_env, val: Self) -> crate::Result<sys::napi_value> {
+    unsafe { BigInt::to_napi_value(env, val.into()) }
+  }
+}
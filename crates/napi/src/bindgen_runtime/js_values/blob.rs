@@ -0,0 +1,439 @@
+use std::io;
+use std::ops::Deref;
+use std::ptr;
+
+#[cfg(all(feature = "tokio_rt", feature = "napi4"))]
+use super::Promise;
+use super::{
+  FromNapiValue, Function, PromiseRaw, ToNapiValue, TypeName, Uint8Array, Unknown,
+  ValidateNapiValue,
+};
+#[cfg(all(feature = "tokio_rt", feature = "napi4"))]
+use crate::threadsafe_function::ThreadsafeFunction;
+use crate::{
+  check_status, sys, Env, Error, JsArrayBuffer, JsObject, NapiRaw, NapiValue, Result, Status,
+  TypedArrayType, ValueType,
+};
+
+/// Represents a WHATWG [`Blob`](https://developer.mozilla.org/en-US/docs/Web/API/Blob) object.
+///
+/// It can be received from JavaScript as a function argument, or created in Rust via [`BlobBuilder`].
+pub struct Blob {
+  pub(crate) env: sys::napi_env,
+  pub(crate) inner: JsObject,
+}
+
+impl Blob {
+  /// Create a `Blob` containing a copy of `data`
+  pub fn from_data<D: AsRef<[u8]>>(env: &Env, data: D, mime_type: Option<&str>) -> Result<Self> {
+    let mut builder = BlobBuilder::new();
+    if let Some(mime_type) = mime_type {
+      builder = builder.with_type(mime_type);
+    }
+    builder.append(data);
+    builder.build(env)
+  }
+
+  /// The size of the `Blob` in bytes
+  pub fn size(&self) -> Result<usize> {
+    let size: f64 = self.inner.get_named_property_unchecked("size")?;
+    Ok(size as usize)
+  }
+
+  /// The MIME type of the `Blob`, or an empty string if it's unknown
+  pub fn mime_type(&self) -> Result<String> {
+    self.inner.get_named_property_unchecked("type")
+  }
+
+  /// Create a new `Blob` containing the data in the specified range of bytes
+  pub fn slice(
+    &self,
+    start: usize,
+    end: Option<usize>,
+    content_type: Option<&str>,
+  ) -> Result<Blob> {
+    let end = match end {
+      Some(end) => end,
+      None => self.size()?,
+    };
+    let slice: Function<(f64, f64, &str), JsObject> =
+      self.inner.get_named_property_unchecked("slice")?;
+    let inner = slice.apply(
+      &self.inner,
+      (start as f64, end as f64, content_type.unwrap_or_default()),
+    )?;
+    Ok(Blob {
+      env: self.env,
+      inner,
+    })
+  }
+
+  /// Read the whole content of the `Blob` as UTF-8 text
+  pub fn text(&self) -> Result<PromiseRaw<String>> {
+    read_text(self.env, &self.inner)
+  }
+
+  /// Read the whole content of the `Blob` into a `Uint8Array`
+  pub fn bytes(&self) -> Result<PromiseRaw<Uint8Array>> {
+    read_array_buffer(self.env, &self.inner)
+  }
+
+  #[cfg(all(feature = "tokio_rt", feature = "napi4"))]
+  /// Consume the `Blob` as an async stream of chunks, each of them at most `chunk_size` bytes long.
+  ///
+  /// Each chunk is read on the JavaScript thread when the previous one is consumed, the returned
+  /// [`BlobChunks`] is `Send` and can be polled from the async runtime. It keeps the event loop
+  /// alive until it's dropped.
+  pub fn chunks(&self, chunk_size: usize) -> Result<BlobChunks> {
+    if chunk_size == 0 {
+      return Err(Error::new(
+        Status::InvalidArg,
+        "chunk_size of Blob::chunks must be greater than 0",
+      ));
+    }
+    let size = self.size()?;
+    let env = Env::from_raw(self.env);
+    let read_chunk = env.create_function_from_closure::<(f64, f64), PromiseRaw<Uint8Array>, _>(
+      "readBlobChunk",
+      |ctx| {
+        let blob: Blob = ctx.this()?;
+        let (start, end): (f64, f64) = ctx.args()?;
+        blob
+          .slice(start as usize, Some(end as usize), None)?
+          .bytes()
+      },
+    )?;
+    // the chunks are read from the blob bound as `this`
+    let read_chunk = unsafe { JsObject::from_raw_unchecked(self.env, read_chunk.raw()) };
+    let bind: Function<JsObject, Function<(f64, f64), Promise<Uint8Array>>> =
+      read_chunk.get_named_property_unchecked("bind")?;
+    let read_chunk = bind.apply(&read_chunk, unsafe {
+      JsObject::from_raw_unchecked(self.env, self.inner.raw())
+    })?;
+    Ok(BlobChunks {
+      read_chunk: read_chunk.build_threadsafe_function().build()?,
+      start: 0,
+      size,
+      chunk_size,
+    })
+  }
+}
+
+impl TypeName for Blob {
+  fn type_name() -> &'static str {
+    "Blob"
+  }
+
+  fn value_type() -> ValueType {
+    ValueType::Object
+  }
+}
+
+impl ValidateNapiValue for Blob {
+  unsafe fn validate(env: sys::napi_env, napi_val: sys::napi_value) -> Result<sys::napi_value> {
+    validate_instance_of(env, napi_val, "Blob")
+  }
+}
+
+impl FromNapiValue for Blob {
+  unsafe fn from_napi_value(env: sys::napi_env, napi_val: sys::napi_value) -> Result<Self> {
+    Ok(Blob {
+      env,
+      inner: unsafe { JsObject::from_raw_unchecked(env, napi_val) },
+    })
+  }
+}
+
+impl NapiRaw for Blob {
+  unsafe fn raw(&self) -> sys::napi_value {
+    unsafe { self.inner.raw() }
+  }
+}
+
+/// Represents a WHATWG [`File`](https://developer.mozilla.org/en-US/docs/Web/API/File) object.
+///
+/// `File` derefs to [`Blob`], so all the `Blob` methods are available on it.
+pub struct File {
+  blob: Blob,
+}
+
+impl File {
+  /// The name of the file
+  pub fn name(&self) -> Result<String> {
+    self.blob.inner.get_named_property_unchecked("name")
+  }
+
+  /// The last modified time of the file, in milliseconds since the UNIX epoch
+  pub fn last_modified(&self) -> Result<i64> {
+    let last_modified: f64 = self
+      .blob
+      .inner
+      .get_named_property_unchecked("lastModified")?;
+    Ok(last_modified as i64)
+  }
+
+  pub fn into_blob(self) -> Blob {
+    self.blob
+  }
+}
+
+impl Deref for File {
+  type Target = Blob;
+
+  fn deref(&self) -> &Self::Target {
+    &self.blob
+  }
+}
+
+impl TypeName for File {
+  fn type_name() -> &'static str {
+    "File"
+  }
+
+  fn value_type() -> ValueType {
+    ValueType::Object
+  }
+}
+
+impl ValidateNapiValue for File {
+  unsafe fn validate(env: sys::napi_env, napi_val: sys::napi_value) -> Result<sys::napi_value> {
+    validate_instance_of(env, napi_val, "File")
+  }
+}
+
+impl FromNapiValue for File {
+  unsafe fn from_napi_value(env: sys::napi_env, napi_val: sys::napi_value) -> Result<Self> {
+    Ok(File {
+      blob: unsafe { Blob::from_napi_value(env, napi_val)? },
+    })
+  }
+}
+
+impl NapiRaw for File {
+  unsafe fn raw(&self) -> sys::napi_value {
+    unsafe { self.blob.raw() }
+  }
+}
+
+/// Build a [`Blob`] or a [`File`] from bytes produced in Rust.
+///
+/// The data can be appended chunk by chunk, or streamed into it via [`std::io::Write`]:
+///
+/// ```no_run
+/// #[napi]
+/// pub fn read_to_blob(path: String) -> Result<BlobBuilder> {
+///   let mut file = std::fs::File::open(path)?;
+///   let mut builder = BlobBuilder::new().with_type("application/octet-stream");
+///   std::io::copy(&mut file, &mut builder)?;
+///   Ok(builder)
+/// }
+/// ```
+///
+/// `BlobBuilder` is `Send`, so it can be filled in an async fn or a `Task` and returned to JavaScript.
+#[derive(Debug, Default, Clone)]
+pub struct BlobBuilder {
+  data: Vec<u8>,
+  mime_type: Option<String>,
+}
+
+impl BlobBuilder {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Create a `BlobBuilder` with preallocated space for `capacity` bytes
+  pub fn with_capacity(capacity: usize) -> Self {
+    Self {
+      data: Vec::with_capacity(capacity),
+      mime_type: None,
+    }
+  }
+
+  /// Set the MIME type of the `Blob`
+  pub fn with_type<S: Into<String>>(mut self, mime_type: S) -> Self {
+    self.mime_type = Some(mime_type.into());
+    self
+  }
+
+  /// Append a chunk of bytes to the end of the `Blob`
+  pub fn append<D: AsRef<[u8]>>(&mut self, chunk: D) -> &mut Self {
+    self.data.extend_from_slice(chunk.as_ref());
+    self
+  }
+
+  /// Number of bytes appended so far
+  pub fn len(&self) -> usize {
+    self.data.len()
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.data.is_empty()
+  }
+
+  pub fn build(self, env: &Env) -> Result<Blob> {
+    let ctor = get_global_constructor::<(Vec<Uint8Array>, JsObject)>(env.raw(), "Blob")?;
+    let options = self.create_options(env, None)?;
+    let inner = ctor.new_instance((vec![Uint8Array::new(self.data)], options))?;
+    Ok(Blob {
+      env: env.raw(),
+      inner: unsafe { JsObject::from_raw_unchecked(env.raw(), inner.raw()) },
+    })
+  }
+
+  /// Build a `File` with the given `name`.
+  ///
+  /// `last_modified` is in milliseconds since the UNIX epoch, the current time is used if it's `None`.
+  pub fn build_file(self, env: &Env, name: &str, last_modified: Option<i64>) -> Result<File> {
+    let ctor = get_global_constructor::<(Vec<Uint8Array>, &str, JsObject)>(env.raw(), "File")?;
+    let options = self.create_options(env, last_modified)?;
+    let inner = ctor.new_instance((vec![Uint8Array::new(self.data)], name, options))?;
+    Ok(File {
+      blob: Blob {
+        env: env.raw(),
+        inner: unsafe { JsObject::from_raw_unchecked(env.raw(), inner.raw()) },
+      },
+    })
+  }
+
+  fn create_options(&self, env: &Env, last_modified: Option<i64>) -> Result<JsObject> {
+    let mut options = env.create_object()?;
+    if let Some(mime_type) = &self.mime_type {
+      options.set_named_property("type", mime_type.as_str())?;
+    }
+    if let Some(last_modified) = last_modified {
+      options.set_named_property("lastModified", last_modified as f64)?;
+    }
+    Ok(options)
+  }
+}
+
+impl io::Write for BlobBuilder {
+  fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+    self.data.extend_from_slice(buf);
+    Ok(buf.len())
+  }
+
+  fn flush(&mut self) -> io::Result<()> {
+    Ok(())
+  }
+}
+
+impl<D: AsRef<[u8]>> Extend<D> for BlobBuilder {
+  fn extend<I: IntoIterator<Item = D>>(&mut self, iter: I) {
+    for chunk in iter {
+      self.append(chunk);
+    }
+  }
+}
+
+impl TypeName for BlobBuilder {
+  fn type_name() -> &'static str {
+    "Blob"
+  }
+
+  fn value_type() -> ValueType {
+    ValueType::Object
+  }
+}
+
+impl ToNapiValue for BlobBuilder {
+  unsafe fn to_napi_value(env: sys::napi_env, val: Self) -> Result<sys::napi_value> {
+    Ok(unsafe { val.build(&Env::from_raw(env))?.raw() })
+  }
+}
+
+#[cfg(all(feature = "tokio_rt", feature = "napi4"))]
+/// Async stream of the chunks of a [`Blob`], created by [`Blob::chunks`]
+pub struct BlobChunks {
+  read_chunk: ThreadsafeFunction<(f64, f64), Promise<Uint8Array>, (f64, f64), false>,
+  start: usize,
+  size: usize,
+  chunk_size: usize,
+}
+
+#[cfg(all(feature = "tokio_rt", feature = "napi4"))]
+impl BlobChunks {
+  /// Read the next chunk, returns `None` if all the chunks have been consumed
+  pub async fn next_chunk(&mut self) -> Option<Result<Uint8Array>> {
+    if self.start >= self.size {
+      return None;
+    }
+    let end = (self.start + self.chunk_size).min(self.size);
+    let chunk = match self
+      .read_chunk
+      .call_async((self.start as f64, end as f64))
+      .await
+    {
+      Ok(bytes) => bytes.await,
+      Err(err) => Err(err),
+    };
+    self.start = end;
+    Some(chunk)
+  }
+
+  /// Number of chunks not consumed yet
+  pub fn remaining(&self) -> usize {
+    (self.size - self.start).div_ceil(self.chunk_size)
+  }
+}
+
+/// Call `text()` on `Blob`, `Request` or `Response`
+pub(crate) fn read_text(env: sys::napi_env, object: &JsObject) -> Result<PromiseRaw<String>> {
+  let text: Function<(), Unknown> = object.get_named_property_unchecked("text")?;
+  let promise = text.apply(object, ())?;
+  Ok(PromiseRaw::new(env, unsafe { promise.raw() }))
+}
+
+/// Call `arrayBuffer()` on `Blob`, `Request` or `Response` and view the result as `Uint8Array`
+pub(crate) fn read_array_buffer(
+  env: sys::napi_env,
+  object: &JsObject,
+) -> Result<PromiseRaw<Uint8Array>> {
+  let array_buffer: Function<(), Unknown> = object.get_named_property_unchecked("arrayBuffer")?;
+  let mut promise =
+    PromiseRaw::<JsArrayBuffer>::new(env, unsafe { array_buffer.apply(object, ())?.raw() });
+  let bytes = promise.then(|ctx| {
+    let array_buffer = ctx.value.into_value()?;
+    let length = array_buffer.len();
+    array_buffer
+      .into_raw()
+      .into_typedarray(TypedArrayType::Uint8, length, 0)
+  })?;
+  Ok(PromiseRaw::new(env, bytes.inner))
+}
+
+pub(crate) fn get_global_constructor<'scope, Args: super::JsValuesTupleIntoVec>(
+  env: sys::napi_env,
+  name: &str,
+) -> Result<Function<'scope, Args, Unknown>> {
+  let global = Env::from_raw(env).get_global()?;
+  let ctor: Unknown = global.get_named_property_unchecked(name)?;
+  if ctor.get_type()? != ValueType::Function {
+    return Err(Error::new(
+      Status::GenericFailure,
+      format!("`{name}` is not available in the current JavaScript runtime"),
+    ));
+  }
+  unsafe { Function::from_napi_value(env, ctor.raw()) }
+}
+
+pub(crate) fn validate_instance_of(
+  env: sys::napi_env,
+  napi_val: sys::napi_value,
+  class_name: &str,
+) -> Result<sys::napi_value> {
+  let ctor = get_global_constructor::<()>(env, class_name)?;
+  let mut is_instance = false;
+  check_status!(
+    unsafe { sys::napi_instanceof(env, napi_val, ctor.raw(), &mut is_instance) },
+    "Failed to check if value is an instance of {}",
+    class_name
+  )?;
+  if !is_instance {
+    return Err(Error::new(
+      Status::InvalidArg,
+      format!("Expect value to be {class_name}"),
+    ));
+  }
+  Ok(ptr::null_mut())
+}
@@ -0,0 +1,105 @@
+#[cfg(feature = "experimental")]
+use std::ffi::c_void;
+use std::ptr;
+use std::sync::Arc;
+
+use crate::{check_status, sys, Result};
+
+/// Convert data shared with a class instance to JavaScript without cloning the field, used by the
+/// getters marked with `#[napi(borrow)]`.
+///
+/// ```rust
+/// #[napi]
+/// pub struct Document {
+///   #[napi(readonly, borrow)]
+///   pub content: Arc<[u8]>,
+/// }
+///
+/// #[napi]
+/// impl Document {
+///   #[napi(getter, borrow)]
+///   pub fn shared_content(&self) -> &Arc<[u8]> {
+///     &self.content
+///   }
+/// }
+/// ```
+///
+/// Only reference counted data can be borrowed: an external JavaScript value holds its own clone
+/// of the `Arc`, so the memory stays alive until the value is collected, even if the field is
+/// replaced or the instance finalized in the meantime.
+///
+/// Strings are returned as external strings with the `experimental` feature when they are ASCII,
+/// other strings are copied. Bytes are always copied into a new `Buffer`: JavaScript can write
+/// into any `Buffer`, and the data behind an `Arc` must not change.
+#[diagnostic::on_unimplemented(
+  message = "`{Self}` can't be borrowed by JavaScript",
+  note = "only `Arc<[u8]>` and `Arc<str>` can be borrowed, the JavaScript value keeps its own clone"
+)]
+pub trait BorrowedToNapiValue {
+  /// # Safety
+  ///
+  /// `env` must be the current env, like for [`ToNapiValue`](super::ToNapiValue)
+  unsafe fn to_napi_value_borrowed(env: sys::napi_env, val: &Self) -> Result<sys::napi_value>;
+}
+
+impl BorrowedToNapiValue for Arc<[u8]> {
+  unsafe fn to_napi_value_borrowed(env: sys::napi_env, val: &Self) -> Result<sys::napi_value> {
+    let mut buf = ptr::null_mut();
+    check_status!(
+      unsafe {
+        sys::napi_create_buffer_copy(
+          env,
+          val.len(),
+          val.as_ptr().cast(),
+          ptr::null_mut(),
+          &mut buf,
+        )
+      },
+      "Failed to create buffer"
+    )?;
+    Ok(buf)
+  }
+}
+
+impl BorrowedToNapiValue for Arc<str> {
+  unsafe fn to_napi_value_borrowed(env: sys::napi_env, val: &Self) -> Result<sys::napi_value> {
+    let mut result = ptr::null_mut();
+    // latin1 and utf-8 only agree on ASCII
+    #[cfg(feature = "experimental")]
+    if !val.is_empty() && val.is_ascii() {
+      let hint = Box::into_raw(Box::new(val.clone()));
+      let mut copied = false;
+      let status = unsafe {
+        sys::node_api_create_external_string_latin1(
+          env,
+          val.as_ptr().cast(),
+          val.len(),
+          Some(release_borrowed_string),
+          hint.cast(),
+          &mut result,
+          &mut copied,
+        )
+      };
+      // a copied string has already been finalized
+      if status != sys::Status::napi_ok {
+        drop(unsafe { Box::from_raw(hint) });
+      }
+      check_status!(status, "Failed to create the borrowed string")?;
+      return Ok(result);
+    }
+    check_status!(
+      unsafe { sys::napi_create_string_utf8(env, val.as_ptr().cast(), val.len(), &mut result) },
+      "Failed to create string"
+    )?;
+    Ok(result)
+  }
+}
+
+#[cfg(feature = "experimental")]
+unsafe extern "C" fn release_borrowed_string(
+  _env: sys::napi_env,
+  _data: *mut c_void,
+  hint: *mut c_void,
+) {
+  drop(unsafe { Box::from_raw(hint.cast::<Arc<str>>()) });
+}
@@ -292,6 +292,8 @@ pub struct Buffer {
   pub(crate) capacity: usize,
   raw: Option<(sys::napi_ref, sys::napi_env)>,
   pub(crate) ref_count: Arc<()>,
+  /// Borrowed from static data by `Buffer::from_static`, never freed
+  is_static: bool,
 }
 
 impl Drop for Buffer {
@@ -337,7 +339,7 @@ impl Drop for Buffer {
           unsafe { sys::napi_delete_reference(env, ref_) },
           "Failed to delete Buffer reference in drop"
         );
-      } else {
+      } else if !self.is_static {
         unsafe { Vec::from_raw_parts(self.inner.as_ptr(), self.len, self.capacity) };
       }
     }
@@ -356,6 +358,7 @@ impl Clone for Buffer {
       capacity: self.capacity,
       raw: self.raw,
       ref_count: self.ref_count.clone(),
+      is_static: self.is_static,
     }
   }
 }
@@ -391,6 +394,25 @@ impl From<Vec<u8>> for Buffer {
       capacity,
       raw: None,
       ref_count: Arc::new(()),
+      is_static: false,
+    }
+  }
+}
+
+impl Buffer {
+  /// Zero copy `Buffer` of static data, like the assets embedded by `include_bytes!`.
+  ///
+  /// The data is neither copied nor freed, the JavaScript `Buffer` has no finalizer. Mutating the
+  /// `Buffer` in Rust copies the data first, but JavaScript must not write to it: the static data
+  /// is usually in read-only memory.
+  pub fn from_static(data: &'static [u8]) -> Self {
+    Buffer {
+      inner: NonNull::from(data).cast(),
+      len: data.len(),
+      capacity: data.len(),
+      raw: None,
+      ref_count: Arc::new(()),
+      is_static: true,
     }
   }
 }
@@ -422,6 +444,9 @@ impl AsRef<[u8]> for Buffer {
 
 impl AsMut<[u8]> for Buffer {
   fn as_mut(&mut self) -> &mut [u8] {
+    if self.is_static {
+      *self = Buffer::from(self.as_ref().to_vec());
+    }
     // SAFETY: This is literally undefined behavior. `Buffer::clone` allows you to create shared
     // access to the underlying data, but `as_mut` and `deref_mut` allow unsynchronized mutation of
     // that data (not to speak of the JS side having write access as well, at the same time).
@@ -486,6 +511,7 @@ impl FromNapiValue for Buffer {
       capacity: len,
       raw: Some((ref_, env)),
       ref_count: Arc::new(()),
+      is_static: false,
     })
   }
 }
@@ -517,6 +543,9 @@ impl ToNapiValue for Buffer {
         // but NAPI/V8 only allows multiple buffers to have
         // the same data pointer if it's 0x0.
         unsafe { sys::napi_create_buffer(env, len, ptr::null_mut(), &mut ret) }
+      } else if val.is_static {
+        let data = unsafe { slice::from_raw_parts(val.inner.as_ptr(), len) };
+        unsafe { create_static_buffer(env, data, &mut ret) }
       } else {
         let value_ptr = val.inner.as_ptr();
         let val_box_ptr = Box::into_raw(Box::new(val));
@@ -581,3 +610,34 @@ impl ValidateNapiValue for Buffer {
     Ok(ptr::null_mut())
   }
 }
+
+/// Create a Buffer of `data` without a finalizer, or a copy of it if the runtime doesn't allow
+/// external buffers
+pub(crate) unsafe fn create_static_buffer(
+  env: sys::napi_env,
+  data: &'static [u8],
+  result: &mut sys::napi_value,
+) -> sys::napi_status {
+  let status = unsafe {
+    sys::napi_create_external_buffer(
+      env,
+      data.len(),
+      data.as_ptr() as *mut c_void,
+      None,
+      ptr::null_mut(),
+      result,
+    )
+  };
+  if status == sys::Status::napi_no_external_buffers_allowed {
+    return unsafe {
+      sys::napi_create_buffer_copy(
+        env,
+        data.len(),
+        data.as_ptr().cast(),
+        ptr::null_mut(),
+        result,
+      )
+    };
+  }
+  status
+}
@@ -0,0 +1,80 @@
+use std::marker::PhantomData;
+use std::ptr;
+use std::sync::atomic::{AtomicI32, Ordering};
+
+use super::{FromNapiValue, TypeName, TypedArrayType, ValidateNapiValue};
+use crate::{check_status, sys, Error, Result, Status, ValueType};
+
+/// A cancellation flag set from JavaScript while a synchronous call is running, see
+/// [`Env::yield_every`](crate::Env::yield_every).
+///
+/// It's the first element of an `Int32Array` on a `SharedArrayBuffer`, the JavaScript thread is
+/// busy with the call so the flag is set from a worker holding the same buffer:
+///
+/// ```js
+/// const flag = new Int32Array(new SharedArrayBuffer(4))
+/// worker.postMessage(flag) // the worker calls `Atomics.store(flag, 0, 1)` to cancel
+/// addon.longComputation(input, flag)
+/// ```
+///
+/// It's only valid during the call it's an argument of.
+pub struct CancelFlag {
+  flag: *const AtomicI32,
+  _not_send: PhantomData<*const ()>,
+}
+
+impl CancelFlag {
+  /// Whether the flag is set to a non-zero value
+  pub fn is_cancelled(&self) -> bool {
+    unsafe { &*self.flag }.load(Ordering::Acquire) != 0
+  }
+}
+
+impl TypeName for CancelFlag {
+  fn type_name() -> &'static str {
+    "CancelFlag"
+  }
+
+  fn value_type() -> ValueType {
+    ValueType::Object
+  }
+}
+
+impl ValidateNapiValue for CancelFlag {}
+
+impl FromNapiValue for CancelFlag {
+  unsafe fn from_napi_value(env: sys::napi_env, napi_val: sys::napi_value) -> Result<Self> {
+    let mut typed_array_type = 0;
+    let mut length = 0;
+    let mut data = ptr::null_mut();
+    let mut array_buffer = ptr::null_mut();
+    let mut byte_offset = 0;
+    check_status!(
+      unsafe {
+        sys::napi_get_typedarray_info(
+          env,
+          napi_val,
+          &mut typed_array_type,
+          &mut length,
+          &mut data,
+          &mut array_buffer,
+          &mut byte_offset,
+        )
+      },
+      "Expected an Int32Array on a SharedArrayBuffer as the cancel flag"
+    )?;
+    // `napi_is_arraybuffer` is false for a `SharedArrayBuffer`
+    let mut is_array_buffer = false;
+    check_status!(unsafe { sys::napi_is_arraybuffer(env, array_buffer, &mut is_array_buffer) })?;
+    if typed_array_type != TypedArrayType::Int32 as i32 || length == 0 || is_array_buffer {
+      return Err(Error::new(
+        Status::InvalidArg,
+        "Expected an Int32Array on a SharedArrayBuffer as the cancel flag",
+      ));
+    }
+    Ok(Self {
+      flag: data.cast(),
+      _not_send: PhantomData,
+    })
+  }
+}
@@ -7,8 +7,8 @@ use std::ptr;
 use super::Object;
 use crate::{
   bindgen_runtime::{
-    raw_finalize_unchecked, FromNapiValue, ObjectFinalize, Reference, Result, TypeName,
-    ValidateNapiValue,
+    check_class_instance, raw_finalize_unchecked, tag_class_instance, FromNapiValue,
+    ObjectFinalize, Reference, Result, TypeName, ValidateNapiValue,
   },
   check_status, sys, Env, NapiRaw, NapiValue, ValueType,
 };
@@ -189,6 +189,7 @@ where
 impl<'env, T: 'env> FromNapiValue for ClassInstance<'env, T> {
   unsafe fn from_napi_value(env: sys::napi_env, napi_val: sys::napi_value) -> crate::Result<Self> {
     let mut value = ptr::null_mut();
+    unsafe { check_class_instance::<T>(env, napi_val) }?;
     check_status!(
       unsafe { sys::napi_unwrap(env, napi_val, &mut value) },
       "Unwrap value [{}] from class failed",
@@ -273,6 +274,7 @@ pub unsafe fn new_instance<T: 'static + ObjectFinalize>(
     "Failed to wrap native object of class `{}`",
     type_name::<T>(),
   )?;
+  tag_class_instance::<T>(env, result)?;
   Reference::<T>::add_ref(
     env,
     wrapped_value,
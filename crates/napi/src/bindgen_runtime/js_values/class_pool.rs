@@ -0,0 +1,141 @@
+use std::any::type_name;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::mem;
+use std::rc::Rc;
+
+use super::{JavaScriptClassExt, Reference, ToNapiValue};
+use crate::{sys, Env, Error, Result, Status};
+
+/// The generation of each pooled instance, keyed by the address of its native value
+type Generations = Rc<RefCell<HashMap<usize, u32>>>;
+
+/// A pool of the JavaScript instances of the class `T`, for classes created at high rates like
+/// row objects.
+///
+/// [`ClassInstancePool::acquire`] reuses a released instance with its native value replaced,
+/// instead of constructing a new one, and [`ClassInstancePool::release`] returns an instance to
+/// the pool. JavaScript must not use an instance once it's released.
+///
+/// Every release bumps the generation of the instance, the [`PooledInstance`] handles acquired
+/// before fail instead of reaching the value of the next owner.
+///
+/// The pool must be used and dropped on the JavaScript thread.
+pub struct ClassInstancePool<T: 'static> {
+  free: Vec<Reference<T>>,
+  max_size: usize,
+  generations: Generations,
+}
+
+impl<T: 'static + JavaScriptClassExt> ClassInstancePool<T> {
+  /// Create a pool keeping up to `max_size` released instances
+  pub fn new(max_size: usize) -> Self {
+    Self {
+      free: Vec::with_capacity(max_size),
+      max_size,
+      generations: Default::default(),
+    }
+  }
+
+  /// Get an instance of `T` holding `value`, recycling a released instance if there is one
+  pub fn acquire(&mut self, env: Env, value: T) -> Result<PooledInstance<T>> {
+    let reference = match self.free.pop() {
+      Some(mut reference) => {
+        drop(mem::replace(&mut *reference, value));
+        reference
+      }
+      None => value.into_reference(env)?,
+    };
+    let generation = *self
+      .generations
+      .borrow_mut()
+      .entry(address(&reference))
+      .or_default();
+    Ok(PooledInstance {
+      reference,
+      generation,
+      generations: self.generations.clone(),
+    })
+  }
+
+  /// Return an instance to the pool, the handles of its current generation become stale.
+  ///
+  /// The instance is left to the GC if the pool is full.
+  pub fn release(&mut self, instance: Reference<T>) -> Result<()> {
+    let key = address(&instance);
+    if self.free.iter().any(|free| address(free) == key) {
+      return Err(Error::new(
+        Status::InvalidArg,
+        format!("The instance of `{}` is already released", type_name::<T>()),
+      ));
+    }
+    let mut generations = self.generations.borrow_mut();
+    let generation = generations.entry(key).or_default();
+    *generation = generation.wrapping_add(1);
+    if self.free.len() < self.max_size {
+      self.free.push(instance);
+    } else {
+      generations.remove(&key);
+    }
+    Ok(())
+  }
+
+  /// The number of released instances waiting in the pool
+  pub fn len(&self) -> usize {
+    self.free.len()
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.free.is_empty()
+  }
+}
+
+/// An instance acquired from a [`ClassInstancePool`], valid until it's released
+pub struct PooledInstance<T: 'static> {
+  reference: Reference<T>,
+  generation: u32,
+  generations: Generations,
+}
+
+impl<T: 'static> PooledInstance<T> {
+  /// Whether the instance is not released since it was acquired
+  pub fn is_current(&self) -> bool {
+    self
+      .generations
+      .borrow()
+      .get(&address(&self.reference))
+      .is_some_and(|generation| *generation == self.generation)
+  }
+
+  pub fn get(&self) -> Result<&T> {
+    self.check_generation()?;
+    Ok(&self.reference)
+  }
+
+  pub fn get_mut(&mut self) -> Result<&mut T> {
+    self.check_generation()?;
+    Ok(&mut self.reference)
+  }
+
+  fn check_generation(&self) -> Result<()> {
+    if self.is_current() {
+      Ok(())
+    } else {
+      Err(Error::new(
+        Status::InvalidArg,
+        format!("The pooled instance of `{}` is released", type_name::<T>()),
+      ))
+    }
+  }
+}
+
+impl<T: 'static> ToNapiValue for PooledInstance<T> {
+  unsafe fn to_napi_value(env: sys::napi_env, val: Self) -> Result<sys::napi_value> {
+    val.check_generation()?;
+    unsafe { Reference::to_napi_value(env, val.reference) }
+  }
+}
+
+fn address<T: 'static>(reference: &Reference<T>) -> usize {
+  &**reference as *const T as usize
+}
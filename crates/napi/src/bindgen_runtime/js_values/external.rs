@@ -4,6 +4,7 @@ use std::{
 };
 
 use super::{FromNapiMutRef, FromNapiRef, FromNapiValue, ToNapiValue, TypeName, ValidateNapiValue};
+use crate::bindgen_runtime::{check_class_instance, tag_class_instance};
 use crate::{check_status, sys, Error, Status};
 
 #[repr(C)]
@@ -71,8 +72,11 @@ impl<T: 'static> FromNapiMutRef for External<T> {
       "Failed to get external value"
     )?;
 
-    let type_id = unknown_tagged_object as *const TypeId;
-    if unsafe { *type_id } == TypeId::of::<T>() {
+    // The type tag tells the externals created by other addons apart, their data may not start
+    // with a `TypeId`. The check passes when the runtime doesn't support type tags.
+    let is_same_type = unsafe { check_class_instance::<External<T>>(env, napi_val) }.is_ok()
+      && unsafe { *(unknown_tagged_object as *const TypeId) } == TypeId::of::<T>();
+    if is_same_type {
       let tagged_object = unknown_tagged_object as *mut External<T>;
       Ok(Box::leak(unsafe { Box::from_raw(tagged_object) }))
     } else {
@@ -152,26 +156,12 @@ impl<T: 'static> ToNapiValue for External<T> {
       },
       "Create external value failed"
     )?;
+    unsafe { tag_class_instance::<External<T>>(env, napi_value) }?;
 
-    #[cfg(not(target_family = "wasm"))]
-    {
-      let mut adjusted_external_memory_size = std::mem::MaybeUninit::new(0);
-
-      if size_hint != 0 {
-        check_status!(
-          unsafe {
-            sys::napi_adjust_external_memory(
-              env,
-              size_hint,
-              adjusted_external_memory_size.as_mut_ptr(),
-            )
-          },
-          "Adjust external memory failed"
-        )?;
-      };
-
-      (Box::leak(unsafe { Box::from_raw(obj_ptr) })).adjusted_size =
-        unsafe { adjusted_external_memory_size.assume_init() };
+    if size_hint != 0 {
+      let adjusted_external_memory_size =
+        unsafe { crate::memory::adjust_external_memory(env, size_hint)? };
+      (Box::leak(unsafe { Box::from_raw(obj_ptr) })).adjusted_size = adjusted_external_memory_size;
     }
 
     Ok(napi_value)
@@ -0,0 +1,373 @@
+#[cfg(all(feature = "tokio_rt", feature = "napi5"))]
+use std::{cell::Cell, rc::Rc, sync::Arc};
+
+#[cfg(all(feature = "tokio_rt", feature = "napi5"))]
+use super::CallbackContext;
+use super::{
+  get_global_constructor, read_array_buffer, read_text, validate_instance_of, FromNapiValue,
+  Function, PromiseRaw, ToNapiValue, TypeName, Uint8Array, Unknown, ValidateNapiValue,
+};
+#[cfg(all(feature = "tokio_rt", feature = "napi5"))]
+use crate::Ref;
+use crate::{sys, Env, JsObject, NapiRaw, NapiValue, Result, ValueType};
+
+/// Represents a WHATWG [`Request`](https://developer.mozilla.org/en-US/docs/Web/API/Request) object,
+/// for example the one passed to a fetch-style handler.
+pub struct Request {
+  pub(crate) env: sys::napi_env,
+  pub(crate) inner: JsObject,
+}
+
+impl Request {
+  /// The request method, e.g. `GET`
+  pub fn method(&self) -> Result<String> {
+    self.inner.get_named_property_unchecked("method")
+  }
+
+  pub fn url(&self) -> Result<String> {
+    self.inner.get_named_property_unchecked("url")
+  }
+
+  /// All the headers of the request, the names are lowercased by the `Headers` object
+  pub fn headers(&self) -> Result<Vec<(String, String)>> {
+    read_headers(self.env, &self.inner)
+  }
+
+  /// Get the value of the header `name`, or `None` if it's not present
+  pub fn header(&self, name: &str) -> Result<Option<String>> {
+    read_header(&self.inner, name)
+  }
+
+  /// Read the request body as UTF-8 text
+  pub fn text(&self) -> Result<PromiseRaw<String>> {
+    read_text(self.env, &self.inner)
+  }
+
+  /// Read the request body into a `Uint8Array`
+  pub fn bytes(&self) -> Result<PromiseRaw<Uint8Array>> {
+    read_array_buffer(self.env, &self.inner)
+  }
+}
+
+/// Represents a WHATWG [`Response`](https://developer.mozilla.org/en-US/docs/Web/API/Response) object.
+///
+/// Use [`ResponseBuilder`] to create a `Response` from Rust.
+pub struct Response {
+  pub(crate) env: sys::napi_env,
+  pub(crate) inner: JsObject,
+}
+
+impl Response {
+  pub fn status(&self) -> Result<u16> {
+    let status: u32 = self.inner.get_named_property_unchecked("status")?;
+    Ok(status as u16)
+  }
+
+  pub fn status_text(&self) -> Result<String> {
+    self.inner.get_named_property_unchecked("statusText")
+  }
+
+  /// Whether the status is in the range 200-299
+  pub fn ok(&self) -> Result<bool> {
+    self.inner.get_named_property_unchecked("ok")
+  }
+
+  /// All the headers of the response, the names are lowercased by the `Headers` object
+  pub fn headers(&self) -> Result<Vec<(String, String)>> {
+    read_headers(self.env, &self.inner)
+  }
+
+  /// Get the value of the header `name`, or `None` if it's not present
+  pub fn header(&self, name: &str) -> Result<Option<String>> {
+    read_header(&self.inner, name)
+  }
+
+  /// Read the response body as UTF-8 text
+  pub fn text(&self) -> Result<PromiseRaw<String>> {
+    read_text(self.env, &self.inner)
+  }
+
+  /// Read the response body into a `Uint8Array`
+  pub fn bytes(&self) -> Result<PromiseRaw<Uint8Array>> {
+    read_array_buffer(self.env, &self.inner)
+  }
+}
+
+macro_rules! impl_fetch_object {
+  ($name:ident, $class_name:literal) => {
+    impl TypeName for $name {
+      fn type_name() -> &'static str {
+        $class_name
+      }
+
+      fn value_type() -> ValueType {
+        ValueType::Object
+      }
+    }
+
+    impl ValidateNapiValue for $name {
+      unsafe fn validate(env: sys::napi_env, napi_val: sys::napi_value) -> Result<sys::napi_value> {
+        validate_instance_of(env, napi_val, $class_name)
+      }
+    }
+
+    impl FromNapiValue for $name {
+      unsafe fn from_napi_value(env: sys::napi_env, napi_val: sys::napi_value) -> Result<Self> {
+        Ok($name {
+          env,
+          inner: unsafe { JsObject::from_raw_unchecked(env, napi_val) },
+        })
+      }
+    }
+
+    impl NapiRaw for $name {
+      unsafe fn raw(&self) -> sys::napi_value {
+        unsafe { self.inner.raw() }
+      }
+    }
+  };
+}
+
+impl_fetch_object!(Request, "Request");
+impl_fetch_object!(Response, "Response");
+
+/// Body of a [`ResponseBuilder`]
+pub enum ResponseBody {
+  Empty,
+  Bytes(Vec<u8>),
+  Text(String),
+  #[cfg(all(feature = "tokio_rt", feature = "napi5"))]
+  /// Stream the chunks received from the channel as a `ReadableStream`.
+  ///
+  /// A chunk is only requested from the channel when the JavaScript side pulls it,
+  /// so a bounded channel applies backpressure to the producer.
+  /// The stream is closed once all the senders are dropped, and errored if an `Err` is received.
+  Stream(tokio::sync::mpsc::Receiver<Result<Vec<u8>>>),
+}
+
+impl From<Vec<u8>> for ResponseBody {
+  fn from(value: Vec<u8>) -> Self {
+    ResponseBody::Bytes(value)
+  }
+}
+
+impl From<&[u8]> for ResponseBody {
+  fn from(value: &[u8]) -> Self {
+    ResponseBody::Bytes(value.to_vec())
+  }
+}
+
+impl From<String> for ResponseBody {
+  fn from(value: String) -> Self {
+    ResponseBody::Text(value)
+  }
+}
+
+impl From<&str> for ResponseBody {
+  fn from(value: &str) -> Self {
+    ResponseBody::Text(value.to_owned())
+  }
+}
+
+#[cfg(all(feature = "tokio_rt", feature = "napi5"))]
+impl From<tokio::sync::mpsc::Receiver<Result<Vec<u8>>>> for ResponseBody {
+  fn from(value: tokio::sync::mpsc::Receiver<Result<Vec<u8>>>) -> Self {
+    ResponseBody::Stream(value)
+  }
+}
+
+/// Build a WHATWG `Response` object from Rust.
+///
+/// ```no_run
+/// #[napi]
+/// pub fn handle(request: Request) -> Result<ResponseBuilder> {
+///   Ok(
+///     ResponseBuilder::new()
+///       .status(200)
+///       .header("content-type", "text/plain")
+///       .body(format!("{} {}", request.method()?, request.url()?)),
+///   )
+/// }
+/// ```
+pub struct ResponseBuilder {
+  status: u16,
+  status_text: Option<String>,
+  headers: Vec<(String, String)>,
+  body: ResponseBody,
+}
+
+impl Default for ResponseBuilder {
+  fn default() -> Self {
+    Self {
+      status: 200,
+      status_text: None,
+      headers: Vec::new(),
+      body: ResponseBody::Empty,
+    }
+  }
+}
+
+impl ResponseBuilder {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  pub fn status(mut self, status: u16) -> Self {
+    self.status = status;
+    self
+  }
+
+  pub fn status_text<S: Into<String>>(mut self, status_text: S) -> Self {
+    self.status_text = Some(status_text.into());
+    self
+  }
+
+  /// Append a header, a header name can be appended multiple times
+  pub fn header<K: Into<String>, V: Into<String>>(mut self, name: K, value: V) -> Self {
+    self.headers.push((name.into(), value.into()));
+    self
+  }
+
+  pub fn body<B: Into<ResponseBody>>(mut self, body: B) -> Self {
+    self.body = body.into();
+    self
+  }
+
+  pub fn build(self, env: &Env) -> Result<Response> {
+    let ctor = get_global_constructor::<(Unknown, JsObject)>(env.raw(), "Response")?;
+    let body = match self.body {
+      ResponseBody::Empty => env.get_null()?.into_unknown(),
+      ResponseBody::Bytes(bytes) => unsafe {
+        Unknown::from_napi_value(
+          env.raw(),
+          Uint8Array::to_napi_value(env.raw(), Uint8Array::new(bytes))?,
+        )?
+      },
+      ResponseBody::Text(text) => env.create_string_from_std(text)?.into_unknown(),
+      #[cfg(all(feature = "tokio_rt", feature = "napi5"))]
+      ResponseBody::Stream(receiver) => create_readable_stream(env, receiver)?,
+    };
+    let mut init = env.create_object()?;
+    init.set_named_property("status", self.status as u32)?;
+    if let Some(status_text) = self.status_text {
+      init.set_named_property("statusText", status_text)?;
+    }
+    let headers = self
+      .headers
+      .into_iter()
+      .map(|(name, value)| vec![name, value])
+      .collect::<Vec<_>>();
+    init.set_named_property("headers", headers)?;
+    let inner = ctor.new_instance((body, init))?;
+    Ok(Response {
+      env: env.raw(),
+      inner: unsafe { JsObject::from_raw_unchecked(env.raw(), inner.raw()) },
+    })
+  }
+}
+
+impl TypeName for ResponseBuilder {
+  fn type_name() -> &'static str {
+    "Response"
+  }
+
+  fn value_type() -> ValueType {
+    ValueType::Object
+  }
+}
+
+impl ToNapiValue for ResponseBuilder {
+  unsafe fn to_napi_value(env: sys::napi_env, val: Self) -> Result<sys::napi_value> {
+    Ok(unsafe { val.build(&Env::from_raw(env))?.raw() })
+  }
+}
+
+fn read_headers(env: sys::napi_env, object: &JsObject) -> Result<Vec<(String, String)>> {
+  let headers: JsObject = object.get_named_property_unchecked("headers")?;
+  let array: JsObject = Env::from_raw(env)
+    .get_global()?
+    .get_named_property_unchecked("Array")?;
+  let from: Function<JsObject, Vec<Vec<String>>> = array.get_named_property_unchecked("from")?;
+  Ok(
+    from
+      .apply(&array, headers)?
+      .into_iter()
+      .filter_map(|mut entry| {
+        let value = entry.pop()?;
+        let name = entry.pop()?;
+        Some((name, value))
+      })
+      .collect(),
+  )
+}
+
+fn read_header(object: &JsObject, name: &str) -> Result<Option<String>> {
+  let headers: JsObject = object.get_named_property_unchecked("headers")?;
+  let get: Function<&str, Option<String>> = headers.get_named_property_unchecked("get")?;
+  get.apply(&headers, name)
+}
+
+#[cfg(all(feature = "tokio_rt", feature = "napi5"))]
+fn create_readable_stream(
+  env: &Env,
+  receiver: tokio::sync::mpsc::Receiver<Result<Vec<u8>>>,
+) -> Result<Unknown> {
+  let receiver = Arc::new(tokio::sync::Mutex::new(receiver));
+  let receiver_to_cancel = receiver.clone();
+  let pull =
+    env.create_function_from_closure::<JsObject, PromiseRaw<()>, _>("pull", move |ctx| {
+      let controller: JsObject = ctx.first_arg()?;
+      let controller = Rc::new(Cell::new(Some(Ref::new(ctx.env, &controller)?)));
+      let controller_in_catch = controller.clone();
+      let receiver = receiver.clone();
+      ctx
+        .env
+        .spawn_future(async move {
+          let chunk = receiver.lock().await.recv().await.transpose()?;
+          Ok(chunk.map(Uint8Array::new))
+        })?
+        .then(move |ctx| {
+          let Some(mut controller) = controller.take() else {
+            return Ok(());
+          };
+          let ret = enqueue_chunk(&ctx.env, &controller, ctx.value);
+          controller.unref(&ctx.env)?;
+          ret
+        })?
+        .catch(move |ctx: CallbackContext<Unknown>| {
+          if let Some(mut controller) = controller_in_catch.take() {
+            controller.unref(&ctx.env)?;
+          }
+          Err::<(), _>(ctx.value.into())
+        })
+    })?;
+  let cancel = env.create_function_from_closure::<(), (), _>("cancel", move |_| {
+    // if a chunk is being pulled, the receiver will be dropped along with the stream
+    if let Ok(mut receiver) = receiver_to_cancel.try_lock() {
+      receiver.close();
+    }
+    Ok(())
+  })?;
+  let mut source = env.create_object()?;
+  source.set_named_property("pull", pull)?;
+  source.set_named_property("cancel", cancel)?;
+  let ctor = get_global_constructor::<JsObject>(env.raw(), "ReadableStream")?;
+  ctor.new_instance(source)
+}
+
+#[cfg(all(feature = "tokio_rt", feature = "napi5"))]
+fn enqueue_chunk(env: &Env, controller: &Ref<JsObject>, chunk: Option<Uint8Array>) -> Result<()> {
+  let controller = controller.get_value(env)?;
+  match chunk {
+    Some(chunk) => {
+      let enqueue: Function<Uint8Array, Unknown> =
+        controller.get_named_property_unchecked("enqueue")?;
+      enqueue.apply(&controller, chunk)?;
+    }
+    None => {
+      let close: Function<(), Unknown> = controller.get_named_property_unchecked("close")?;
+      close.apply(&controller, ())?;
+    }
+  }
+  Ok(())
+}
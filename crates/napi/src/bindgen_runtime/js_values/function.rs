@@ -11,8 +11,106 @@ use crate::{check_pending_exception, check_status, sys, Env, NapiRaw, Result, Va
 
 impl ValidateNapiValue for JsFunction {}
 
+/// The number of arguments [`ArgsBuffer`] keeps on the stack
+const STACK_ARGS: usize = 8;
+
+#[doc(hidden)]
+/// The arguments of a call, kept on the stack unless there are more than 8 of them, so calling
+/// a function doesn't allocate
+pub struct ArgsBuffer {
+  len: usize,
+  stack: [sys::napi_value; STACK_ARGS],
+  heap: Vec<sys::napi_value>,
+}
+
+impl Default for ArgsBuffer {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl ArgsBuffer {
+  pub fn new() -> Self {
+    Self {
+      len: 0,
+      stack: [ptr::null_mut(); STACK_ARGS],
+      heap: Vec::new(),
+    }
+  }
+
+  /// `len` null arguments, to be filled by `napi_get_cb_info`
+  pub fn with_len(len: usize) -> Self {
+    let mut args = Self::new();
+    if len > STACK_ARGS {
+      args.heap = vec![ptr::null_mut(); len];
+    }
+    args.len = len;
+    args
+  }
+
+  pub fn push(&mut self, value: sys::napi_value) {
+    if self.len < STACK_ARGS {
+      self.stack[self.len] = value;
+    } else {
+      if self.len == STACK_ARGS {
+        self.heap.reserve(STACK_ARGS * 2);
+        self.heap.extend_from_slice(&self.stack);
+      }
+      self.heap.push(value);
+    }
+    self.len += 1;
+  }
+
+  pub fn as_slice(&self) -> &[sys::napi_value] {
+    if self.len <= STACK_ARGS {
+      &self.stack[..self.len]
+    } else {
+      &self.heap
+    }
+  }
+
+  pub fn as_mut_slice(&mut self) -> &mut [sys::napi_value] {
+    if self.len <= STACK_ARGS {
+      &mut self.stack[..self.len]
+    } else {
+      &mut self.heap
+    }
+  }
+}
+
+impl std::ops::Deref for ArgsBuffer {
+  type Target = [sys::napi_value];
+
+  fn deref(&self) -> &Self::Target {
+    self.as_slice()
+  }
+}
+
+impl FromIterator<sys::napi_value> for ArgsBuffer {
+  fn from_iter<I: IntoIterator<Item = sys::napi_value>>(iter: I) -> Self {
+    let mut args = Self::new();
+    for value in iter {
+      args.push(value);
+    }
+    args
+  }
+}
+
 pub trait JsValuesTupleIntoVec {
   fn into_vec(self, env: sys::napi_env) -> Result<Vec<sys::napi_value>>;
+
+  #[doc(hidden)]
+  /// Push the converted values into `args`, the calls use this instead of `into_vec` to avoid
+  /// allocating
+  fn push_into(self, env: sys::napi_env, args: &mut ArgsBuffer) -> Result<()>
+  where
+    Self: Sized,
+  {
+    for value in self.into_vec(env)? {
+      args.push(value);
+    }
+    Ok(())
+  }
 }
 
 impl<T: ToNapiValue> JsValuesTupleIntoVec for T {
@@ -22,6 +120,12 @@ impl<T: ToNapiValue> JsValuesTupleIntoVec for T {
       <T as ToNapiValue>::to_napi_value(env, self)?
     }])
   }
+
+  #[allow(clippy::not_unsafe_ptr_arg_deref)]
+  fn push_into(self, env: sys::napi_env, args: &mut ArgsBuffer) -> Result<()> {
+    args.push(unsafe { <T as ToNapiValue>::to_napi_value(env, self)? });
+    Ok(())
+  }
 }
 
 pub trait TupleFromSliceValues {
@@ -40,6 +144,14 @@ macro_rules! impl_tuple_conversion {
         let ($($ident,)*) = self;
         Ok(vec![$(unsafe { <$ident as ToNapiValue>::to_napi_value(env, $ident)? }),*])
       }
+
+      #[allow(clippy::not_unsafe_ptr_arg_deref)]
+      fn push_into(self, env: sys::napi_env, args: &mut ArgsBuffer) -> Result<()> {
+        #[allow(non_snake_case)]
+        let ($($ident,)*) = self;
+        $(args.push(unsafe { <$ident as ToNapiValue>::to_napi_value(env, $ident)? });)*
+        Ok(())
+      }
     }
 
     impl<$($ident: FromNapiValue),*> TupleFromSliceValues for ($($ident,)*) {
@@ -162,14 +274,15 @@ impl<'scope, Args: JsValuesTupleIntoVec, Return> Function<'scope, Args, Return>
   /// Create a new instance of the JavaScript Class.
   pub fn new_instance(&self, args: Args) -> Result<Unknown> {
     let mut raw_instance = ptr::null_mut();
-    let mut args = args.into_vec(self.env)?;
+    let mut args_ptr = ArgsBuffer::new();
+    args.push_into(self.env, &mut args_ptr)?;
     check_status!(
       unsafe {
         sys::napi_new_instance(
           self.env,
           self.value,
-          args.len(),
-          args.as_mut_ptr().cast(),
+          args_ptr.len(),
+          args_ptr.as_ptr(),
           &mut raw_instance,
         )
       },
@@ -178,6 +291,57 @@ impl<'scope, Args: JsValuesTupleIntoVec, Return> Function<'scope, Args, Return>
     unsafe { Unknown::from_napi_value(self.env, raw_instance) }
   }
 
+  /// Prepare repeated calls of the JavaScript function with `this` as the receiver and
+  /// `constants` as the leading arguments, see [`PreparedCall`].
+  pub fn prepare_call<Context, Constants, CallArgs>(
+    &self,
+    this: Context,
+    constants: Constants,
+  ) -> Result<PreparedCall<CallArgs, Return>>
+  where
+    Context: ToNapiValue,
+    Constants: JsValuesTupleIntoVec,
+    CallArgs: JsValuesTupleIntoVec,
+  {
+    let raw_this = unsafe { Context::to_napi_value(self.env, this) }?;
+    let mut constant_values = ArgsBuffer::new();
+    constants.push_into(self.env, &mut constant_values)?;
+    let mut constants_array = ptr::null_mut();
+    check_status!(
+      unsafe { sys::napi_create_array_with_length(self.env, 0, &mut constants_array) },
+      "Create the constant arguments array failed"
+    )?;
+    for (index, value) in constant_values.iter().enumerate() {
+      check_status!(
+        unsafe { sys::napi_set_element(self.env, constants_array, index as u32, *value) },
+        "Set the constant argument {} failed",
+        index
+      )?;
+    }
+    let mut call = PreparedCall {
+      env: self.env,
+      func: ptr::null_mut(),
+      constants: ptr::null_mut(),
+      constants_len: constant_values.len() as u32,
+      _args: std::marker::PhantomData,
+      _return: std::marker::PhantomData,
+    };
+    // The receiver may be a primitive, it's kept in the constants array to be referenced
+    check_status!(
+      unsafe { sys::napi_set_element(self.env, constants_array, call.constants_len, raw_this) },
+      "Set the receiver failed"
+    )?;
+    check_status!(
+      unsafe { sys::napi_create_reference(self.env, self.value, 1, &mut call.func) },
+      "Create reference failed"
+    )?;
+    check_status!(
+      unsafe { sys::napi_create_reference(self.env, constants_array, 1, &mut call.constants) },
+      "Create reference failed"
+    )?;
+    Ok(call)
+  }
+
   #[cfg(feature = "napi4")]
   /// Create a threadsafe function from the JavaScript function.
   pub fn build_threadsafe_function<T: 'static>(
@@ -202,7 +366,8 @@ impl<'scope, Args: JsValuesTupleIntoVec, Return: FromNapiValue> Function<'scope,
       unsafe { sys::napi_get_undefined(self.env, &mut raw_this) },
       "Get undefined value failed"
     )?;
-    let args_ptr = args.into_vec(self.env)?;
+    let mut args_ptr = ArgsBuffer::new();
+    args.push_into(self.env, &mut args_ptr)?;
     let mut raw_return = ptr::null_mut();
     check_pending_exception!(
       self.env,
@@ -225,7 +390,8 @@ impl<'scope, Args: JsValuesTupleIntoVec, Return: FromNapiValue> Function<'scope,
   /// `this` in the JavaScript function will be the provided `this`.
   pub fn apply<Context: ToNapiValue>(&self, this: Context, args: Args) -> Result<Return> {
     let raw_this = unsafe { Context::to_napi_value(self.env, this) }?;
-    let args_ptr = args.into_vec(self.env)?;
+    let mut args_ptr = ArgsBuffer::new();
+    args.push_into(self.env, &mut args_ptr)?;
     let mut raw_return = ptr::null_mut();
     check_status!(
       unsafe {
@@ -403,6 +569,115 @@ impl<Args: JsValuesTupleIntoVec, Return: FromNapiValue> ValidateNapiValue
 {
 }
 
+/// A call of a JavaScript function prepared for hot loops, created by [`Function::prepare_call`].
+///
+/// The function, the receiver and the constant leading arguments are converted once and kept
+/// behind references, so each call only converts `CallArgs`. It outlives the scope of the
+/// function, but must be used and dropped on the JavaScript thread.
+///
+/// ```rust
+/// #[napi]
+/// pub fn render_rows(template: Object, render: Function<(String, u32), String>, rows: u32) -> Result<Vec<String>> {
+///   let call: PreparedCall<u32, String> = render.prepare_call(template, "row".to_owned())?;
+///   (0..rows).map(|row| call.call(row)).collect()
+/// }
+/// ```
+pub struct PreparedCall<CallArgs: JsValuesTupleIntoVec, Return> {
+  env: sys::napi_env,
+  func: sys::napi_ref,
+  /// The constant arguments followed by the receiver
+  constants: sys::napi_ref,
+  constants_len: u32,
+  _args: std::marker::PhantomData<CallArgs>,
+  _return: std::marker::PhantomData<Return>,
+}
+
+impl<CallArgs: JsValuesTupleIntoVec, Return: FromNapiValue> PreparedCall<CallArgs, Return> {
+  /// Call the JavaScript function with the constant arguments followed by `args`.
+  pub fn call(&self, args: CallArgs) -> Result<Return> {
+    let (func, this, args_ptr) = self.resolve(args)?;
+    let mut raw_return = ptr::null_mut();
+    check_pending_exception!(
+      self.env,
+      unsafe {
+        sys::napi_call_function(
+          self.env,
+          this,
+          func,
+          args_ptr.len(),
+          args_ptr.as_ptr(),
+          &mut raw_return,
+        )
+      },
+      "Call Function failed"
+    )?;
+    unsafe { Return::from_napi_value(self.env, raw_return) }
+  }
+
+  /// Call the JavaScript function without checking whether it threw.
+  ///
+  /// # Safety
+  ///
+  /// The function must not throw: the exception would be left pending and the return value
+  /// converted from an invalid value.
+  pub unsafe fn call_unchecked(&self, args: CallArgs) -> Result<Return> {
+    let (func, this, args_ptr) = self.resolve(args)?;
+    let mut raw_return = ptr::null_mut();
+    unsafe {
+      sys::napi_call_function(
+        self.env,
+        this,
+        func,
+        args_ptr.len(),
+        args_ptr.as_ptr(),
+        &mut raw_return,
+      )
+    };
+    unsafe { Return::from_napi_value(self.env, raw_return) }
+  }
+
+  fn resolve(&self, args: CallArgs) -> Result<(sys::napi_value, sys::napi_value, ArgsBuffer)> {
+    let mut func = ptr::null_mut();
+    check_status!(
+      unsafe { sys::napi_get_reference_value(self.env, self.func, &mut func) },
+      "Get reference value failed"
+    )?;
+    let mut constants = ptr::null_mut();
+    check_status!(
+      unsafe { sys::napi_get_reference_value(self.env, self.constants, &mut constants) },
+      "Get reference value failed"
+    )?;
+    let mut args_ptr = ArgsBuffer::new();
+    for index in 0..self.constants_len {
+      let mut constant = ptr::null_mut();
+      check_status!(
+        unsafe { sys::napi_get_element(self.env, constants, index, &mut constant) },
+        "Get the constant argument {} failed",
+        index
+      )?;
+      args_ptr.push(constant);
+    }
+    let mut this = ptr::null_mut();
+    check_status!(
+      unsafe { sys::napi_get_element(self.env, constants, self.constants_len, &mut this) },
+      "Get the receiver failed"
+    )?;
+    args.push_into(self.env, &mut args_ptr)?;
+    Ok((func, this, args_ptr))
+  }
+}
+
+impl<CallArgs: JsValuesTupleIntoVec, Return> Drop for PreparedCall<CallArgs, Return> {
+  fn drop(&mut self) {
+    for reference in [self.func, self.constants] {
+      if !reference.is_null() {
+        let status = unsafe { sys::napi_delete_reference(self.env, reference) };
+        debug_assert_eq!(status, sys::Status::napi_ok, "Drop PreparedCall failed");
+      }
+    }
+  }
+}
+
 pub struct FunctionCallContext<'scope> {
   pub(crate) args: &'scope [sys::napi_value],
   pub(crate) this: sys::napi_value,
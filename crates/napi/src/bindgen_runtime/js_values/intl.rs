@@ -0,0 +1,222 @@
+use std::cmp::Ordering;
+
+use super::{Function, FunctionRef, Unknown};
+use crate::{Env, Error, JsObject, JsString, NapiRaw, NapiValue, Result, Status, ValueType};
+
+/// Which differences between strings lead to non-zero results,
+/// see [`Intl.Collator` options](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/Collator/Collator#sensitivity)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CollatorSensitivity {
+  /// `a ≠ b`, `a = á`, `a = A`
+  Base,
+  /// `a ≠ b`, `a ≠ á`, `a = A`
+  Accent,
+  /// `a ≠ b`, `a = á`, `a ≠ A`
+  Case,
+  /// `a ≠ b`, `a ≠ á`, `a ≠ A`
+  Variant,
+}
+
+impl CollatorSensitivity {
+  fn as_str(&self) -> &'static str {
+    match self {
+      Self::Base => "base",
+      Self::Accent => "accent",
+      Self::Case => "case",
+      Self::Variant => "variant",
+    }
+  }
+}
+
+/// Whether upper case or lower case sorts first
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CollatorCaseFirst {
+  Upper,
+  Lower,
+  /// Use the default of the locale
+  False,
+}
+
+impl CollatorCaseFirst {
+  fn as_str(&self) -> &'static str {
+    match self {
+      Self::Upper => "upper",
+      Self::Lower => "lower",
+      Self::False => "false",
+    }
+  }
+}
+
+/// Options of [`Collator::new`], `None` fields use the default of the locale
+#[derive(Debug, Clone, Default)]
+pub struct CollatorOptions {
+  pub sensitivity: Option<CollatorSensitivity>,
+  pub case_first: Option<CollatorCaseFirst>,
+  /// Compare numeric substrings by their value, so that `"2" < "10"`
+  pub numeric: Option<bool>,
+  pub ignore_punctuation: Option<bool>,
+}
+
+/// A locale-aware string comparator backed by the `Intl.Collator` of the JavaScript engine,
+/// so sorting in native code matches `localeCompare` and `Intl.Collator` on the JavaScript side.
+///
+/// The bound `compare` function is kept in a reference, a `Collator` can be created once and
+/// reused across calls on the same thread.
+pub struct Collator {
+  compare: FunctionRef<(JsString, JsString), f64>,
+  locale: String,
+}
+
+impl Collator {
+  /// Create a collator for the first supported locale in `locales`, or the default locale
+  /// of the runtime if `locales` is empty or none of them is supported.
+  pub fn new(env: &Env, locales: &[&str], options: CollatorOptions) -> Result<Self> {
+    let intl = get_intl(env)?;
+    let ctor: Function<(Vec<&str>, JsObject), JsObject> =
+      intl.get_named_property_unchecked("Collator")?;
+    let mut js_options = env.create_object()?;
+    if let Some(sensitivity) = options.sensitivity {
+      js_options.set("sensitivity", sensitivity.as_str())?;
+    }
+    if let Some(case_first) = options.case_first {
+      js_options.set("caseFirst", case_first.as_str())?;
+    }
+    if let Some(numeric) = options.numeric {
+      js_options.set("numeric", numeric)?;
+    }
+    if let Some(ignore_punctuation) = options.ignore_punctuation {
+      js_options.set("ignorePunctuation", ignore_punctuation)?;
+    }
+    let collator = ctor.new_instance((locales.to_vec(), js_options))?;
+    let collator = unsafe { JsObject::from_raw_unchecked(env.raw(), collator.raw()) };
+    let resolved_options: Function<(), JsObject> =
+      collator.get_named_property_unchecked("resolvedOptions")?;
+    let locale = resolved_options
+      .apply(&collator, ())?
+      .get_named_property_unchecked("locale")?;
+    // `compare` is a getter returning a function bound to the collator
+    let compare: Function<(JsString, JsString), f64> =
+      collator.get_named_property_unchecked("compare")?;
+    Ok(Self {
+      compare: compare.create_ref()?,
+      locale,
+    })
+  }
+
+  /// The locale actually used, e.g. `"de"` for `["de-XX"]`
+  pub fn locale(&self) -> &str {
+    &self.locale
+  }
+
+  pub fn compare(&self, env: &Env, a: &str, b: &str) -> Result<Ordering> {
+    self.compare_js(env, env.create_string(a)?, env.create_string(b)?)
+  }
+
+  /// Sort `items` with the collator, the sort is stable.
+  ///
+  /// The JavaScript strings are only created once for each item, not for each comparison.
+  pub fn sort<S: AsRef<str>>(&self, env: &Env, items: Vec<S>) -> Result<Vec<S>> {
+    let keys = items
+      .iter()
+      .map(|item| env.create_string(item.as_ref()))
+      .collect::<Result<Vec<_>>>()?;
+    let mut indices = (0..items.len()).collect::<Vec<_>>();
+    merge_sort(&mut indices, &mut |a, b| {
+      self.compare_js(env, keys[a], keys[b])
+    })?;
+    let mut items = items.into_iter().map(Some).collect::<Vec<_>>();
+    Ok(
+      indices
+        .into_iter()
+        .filter_map(|index| items[index].take())
+        .collect(),
+    )
+  }
+
+  fn compare_js(&self, env: &Env, a: JsString, b: JsString) -> Result<Ordering> {
+    let result = self.compare.borrow_back(env)?.call((a, b))?;
+    Ok(result.partial_cmp(&0.0).unwrap_or(Ordering::Equal))
+  }
+}
+
+/// Locale-aware case mapping with `String.prototype.toLocaleUpperCase` and
+/// `String.prototype.toLocaleLowerCase`, e.g. `i` maps to `İ` in Turkish.
+///
+/// Like [`Collator`], the functions are kept in references and the mapper can be reused.
+pub struct LocaleCaseMapper {
+  upper: FunctionRef<Option<String>, String>,
+  lower: FunctionRef<Option<String>, String>,
+  locale: Option<String>,
+}
+
+impl LocaleCaseMapper {
+  /// Map case for `locale`, or for the default locale of the runtime if it's `None`
+  pub fn new(env: &Env, locale: Option<&str>) -> Result<Self> {
+    let global = env.get_global()?;
+    let string: JsObject = global.get_named_property_unchecked("String")?;
+    let prototype: JsObject = string.get_named_property_unchecked("prototype")?;
+    let upper: Function<Option<String>, String> =
+      prototype.get_named_property_unchecked("toLocaleUpperCase")?;
+    let lower: Function<Option<String>, String> =
+      prototype.get_named_property_unchecked("toLocaleLowerCase")?;
+    Ok(Self {
+      upper: upper.create_ref()?,
+      lower: lower.create_ref()?,
+      locale: locale.map(ToOwned::to_owned),
+    })
+  }
+
+  pub fn to_upper_case(&self, env: &Env, input: &str) -> Result<String> {
+    self
+      .upper
+      .borrow_back(env)?
+      .apply(input, self.locale.clone())
+  }
+
+  pub fn to_lower_case(&self, env: &Env, input: &str) -> Result<String> {
+    self
+      .lower
+      .borrow_back(env)?
+      .apply(input, self.locale.clone())
+  }
+}
+
+fn get_intl(env: &Env) -> Result<JsObject> {
+  let intl: Unknown = env.get_global()?.get_named_property_unchecked("Intl")?;
+  if intl.get_type()? != ValueType::Object {
+    return Err(Error::new(
+      Status::GenericFailure,
+      "`Intl` is not available in the current JavaScript runtime",
+    ));
+  }
+  Ok(unsafe { JsObject::from_raw_unchecked(env.raw(), intl.raw()) })
+}
+
+/// Stable merge sort with a fallible comparator, `slice::sort_by` can't stop on errors
+fn merge_sort<F>(items: &mut [usize], compare: &mut F) -> Result<()>
+where
+  F: FnMut(usize, usize) -> Result<Ordering>,
+{
+  if items.len() <= 1 {
+    return Ok(());
+  }
+  let middle = items.len() / 2;
+  merge_sort(&mut items[..middle], compare)?;
+  merge_sort(&mut items[middle..], compare)?;
+  let (left, right) = items.split_at(middle);
+  let mut merged = Vec::with_capacity(items.len());
+  let (mut i, mut j) = (0, 0);
+  while i < left.len() && j < right.len() {
+    if compare(right[j], left[i])? == Ordering::Less {
+      merged.push(right[j]);
+      j += 1;
+    } else {
+      merged.push(left[i]);
+      i += 1;
+    }
+  }
+  merged.extend_from_slice(&left[i..]);
+  merged.extend_from_slice(&right[j..]);
+  items.copy_from_slice(&merged);
+  Ok(())
+}
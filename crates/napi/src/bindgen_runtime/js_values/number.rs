@@ -1,5 +1,7 @@
-use super::{check_status, sys};
-use crate::{bindgen_prelude::ToNapiValue, type_of, Error, Result};
+use std::ops::Deref;
+
+use super::{check_status, sys, FromNapiValue, TypeName, ValidateNapiValue};
+use crate::{bindgen_prelude::ToNapiValue, type_of, Error, Result, Status, ValueType};
 
 macro_rules! impl_number_conversions {
   ( $( ($name:literal, $t:ty as $st:ty, $get:ident, $create:ident) ,)* ) => {
@@ -72,3 +74,73 @@ impl ToNapiValue for f32 {
     Ok(ptr)
   }
 }
+
+/// A `number` which must be finite, `NaN` and `±Infinity` are rejected instead of being converted.
+///
+/// Without it, the numbers are converted like this:
+///
+/// - `f64` keeps every value, including `-0`, `NaN` and `±Infinity`. The payload of `NaN` may be
+///   canonicalized by the JavaScript engine, don't store data in it.
+/// - the integers truncate the fractional part and `-0` becomes `0`. `NaN` and `±Infinity` become
+///   `0` too, which `Finite` prevents.
+///
+/// ```rust,ignore
+/// #[napi]
+/// fn scale(factor: Finite<f64>, value: Finite<i32>) -> f64 {
+///   *factor * f64::from(*value)
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Finite<T>(pub T);
+
+impl<T> Finite<T> {
+  pub fn into_inner(self) -> T {
+    self.0
+  }
+}
+
+impl<T> Deref for Finite<T> {
+  type Target = T;
+
+  fn deref(&self) -> &T {
+    &self.0
+  }
+}
+
+impl<T: TypeName> TypeName for Finite<T> {
+  fn type_name() -> &'static str {
+    T::type_name()
+  }
+
+  fn value_type() -> ValueType {
+    ValueType::Number
+  }
+}
+
+impl<T: TypeName> ValidateNapiValue for Finite<T> {}
+
+impl<T: FromNapiValue> FromNapiValue for Finite<T> {
+  unsafe fn from_napi_value(env: sys::napi_env, napi_val: sys::napi_value) -> Result<Self> {
+    let value = unsafe { f64::from_napi_value(env, napi_val)? };
+    if !value.is_finite() {
+      let value = if value.is_nan() {
+        "NaN"
+      } else if value > 0.0 {
+        "Infinity"
+      } else {
+        "-Infinity"
+      };
+      return Err(Error::new(
+        Status::InvalidArg,
+        format!("Expected a finite number, got {value}"),
+      ));
+    }
+    unsafe { T::from_napi_value(env, napi_val) }.map(Finite)
+  }
+}
+
+impl<T: ToNapiValue> ToNapiValue for Finite<T> {
+  unsafe fn to_napi_value(env: sys::napi_env, val: Self) -> Result<sys::napi_value> {
+    unsafe { T::to_napi_value(env, val.0) }
+  }
+}
@@ -67,20 +67,102 @@ impl Object {
     }
   }
 
-  pub fn keys(obj: &Object) -> Result<Vec<String>> {
+  /// The enumerable string keys of the object, including the inherited ones like `for...in`
+  pub fn keys(&self) -> Result<Vec<String>> {
+    let names = self.property_names()?;
+    let mut ret = Vec::with_capacity(names.len() as usize);
+
+    for i in 0..names.len() {
+      ret.push(names.get::<String>(i)?.unwrap());
+    }
+
+    Ok(ret)
+  }
+
+  /// The values of [`Object::keys`], converted to `T`
+  pub fn values<T: FromNapiValue>(&self) -> Result<Vec<T>> {
+    Ok(
+      self
+        .properties::<T>(false)?
+        .into_iter()
+        .map(|(_, value)| value)
+        .collect(),
+    )
+  }
+
+  /// The keys and values of [`Object::keys`], with the values converted to `T`
+  pub fn entries<T: FromNapiValue>(&self) -> Result<Vec<(String, T)>> {
+    self.properties(true)
+  }
+
+  fn property_names(&self) -> Result<Array> {
     let mut names = ptr::null_mut();
     unsafe {
       check_status!(
-        sys::napi_get_property_names(obj.0.env, obj.0.value, &mut names),
+        sys::napi_get_property_names(self.0.env, self.0.value, &mut names),
         "Failed to get property names of given object"
       )?;
     }
 
-    let names = unsafe { Array::from_napi_value(obj.0.env, names)? };
-    let mut ret = vec![];
+    unsafe { Array::from_napi_value(self.0.env, names) }
+  }
 
-    for i in 0..names.len() {
-      ret.push(names.get::<String>(i)?.unwrap());
+  // Read each property in its own scope, only the value escapes it
+  fn properties<T: FromNapiValue>(&self, with_keys: bool) -> Result<Vec<(String, T)>> {
+    let env = self.0.env;
+    let names = self.property_names()?;
+    let len = names.len();
+    let names = unsafe { Array::to_napi_value(env, names)? };
+    let mut ret = Vec::with_capacity(len as usize);
+
+    for i in 0..len {
+      let mut scope = ptr::null_mut();
+      check_status!(
+        unsafe { sys::napi_open_escapable_handle_scope(env, &mut scope) },
+        "Failed to open the handle scope of property {}",
+        i
+      )?;
+      let property = (|| -> Result<(String, sys::napi_value)> {
+        let mut key = ptr::null_mut();
+        check_status!(
+          unsafe { sys::napi_get_element(env, names, i, &mut key) },
+          "Failed to get the key of property {}",
+          i
+        )?;
+        let mut value = ptr::null_mut();
+        check_status!(
+          unsafe { sys::napi_get_property(env, self.0.value, key, &mut value) },
+          "Failed to get the value of property {}",
+          i
+        )?;
+        let key = if with_keys {
+          unsafe { String::from_napi_value(env, key)? }
+        } else {
+          String::new()
+        };
+        let mut escaped = ptr::null_mut();
+        check_status!(
+          unsafe { sys::napi_escape_handle(env, scope, value, &mut escaped) },
+          "Failed to escape the value of property {}",
+          i
+        )?;
+        Ok((key, escaped))
+      })();
+      check_status!(
+        unsafe { sys::napi_close_escapable_handle_scope(env, scope) },
+        "Failed to close the handle scope of property {}",
+        i
+      )?;
+      let (key, value) = property?;
+      let value = unsafe { T::from_napi_value(env, value) }.map_err(|mut err| {
+        err.reason = if with_keys {
+          format!("Invalid value of property `{}`: {}", key, err.reason)
+        } else {
+          format!("Invalid value of property {}: {}", i, err.reason)
+        };
+        err
+      })?;
+      ret.push((key, value));
     }
 
     Ok(ret)
@@ -0,0 +1,115 @@
+use std::any::type_name;
+use std::mem;
+use std::slice;
+
+use super::{Buffer, BufferSlice};
+use crate::{Error, Result, Status};
+
+/// Plain old data, the types which can be read from and written to any bytes.
+///
+/// # Safety
+///
+/// The type must be `Copy`, have no padding bytes, and every bit pattern must be a valid value of
+/// it. For structs this means `#[repr(C)]` or `#[repr(transparent)]` with `Pod` fields only and no
+/// padding between them, like:
+///
+/// ```rust
+/// #[repr(C)]
+/// #[derive(Clone, Copy)]
+/// struct Vertex {
+///   position: [f32; 3],
+///   uv: [f32; 2],
+/// }
+///
+/// unsafe impl Pod for Vertex {}
+/// ```
+pub unsafe trait Pod: Copy + 'static {}
+
+macro_rules! impl_pod {
+  ($($ty:ty),*) => {
+    $(unsafe impl Pod for $ty {})*
+  };
+}
+
+impl_pod!(u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize, f32, f64);
+
+unsafe impl<T: Pod, const N: usize> Pod for [T; N] {}
+
+/// View `bytes` as a slice of `T`, `bytes` must be aligned for `T` and its length a multiple of
+/// the size of `T`
+pub fn cast_slice<T: Pod>(bytes: &[u8]) -> Result<&[T]> {
+  match check_cast::<T>(bytes)? {
+    // the dangling pointer of empty slices may not be aligned for `T`
+    0 => Ok(&[]),
+    len => Ok(unsafe { slice::from_raw_parts(bytes.as_ptr().cast(), len) }),
+  }
+}
+
+/// View `bytes` as a mutable slice of `T`, with the same requirements as [`cast_slice`]
+pub fn cast_slice_mut<T: Pod>(bytes: &mut [u8]) -> Result<&mut [T]> {
+  match check_cast::<T>(bytes)? {
+    0 => Ok(&mut []),
+    len => Ok(unsafe { slice::from_raw_parts_mut(bytes.as_mut_ptr().cast(), len) }),
+  }
+}
+
+/// View the elements of `data` as bytes
+pub fn bytes_of<T: Pod>(data: &[T]) -> &[u8] {
+  unsafe { slice::from_raw_parts(data.as_ptr().cast(), mem::size_of_val(data)) }
+}
+
+/// The number of `T` in `bytes`
+fn check_cast<T: Pod>(bytes: &[u8]) -> Result<usize> {
+  let size = mem::size_of::<T>();
+  if size == 0 {
+    return Err(Error::new(
+      Status::InvalidArg,
+      format!("Can't view bytes as the zero sized `{}`", type_name::<T>()),
+    ));
+  }
+  if bytes.len() % size != 0 {
+    return Err(Error::new(
+      Status::InvalidArg,
+      format!(
+        "The byte length {} is not a multiple of the size of `{}` ({size})",
+        bytes.len(),
+        type_name::<T>()
+      ),
+    ));
+  }
+  if !bytes.is_empty() && bytes.as_ptr().align_offset(mem::align_of::<T>()) != 0 {
+    return Err(Error::new(
+      Status::InvalidArg,
+      format!(
+        "The bytes are not aligned to {} for `{}`",
+        mem::align_of::<T>(),
+        type_name::<T>()
+      ),
+    ));
+  }
+  Ok(bytes.len() / size)
+}
+
+impl BufferSlice<'_> {
+  /// View the buffer as a slice of `T`, see [`cast_slice`]
+  pub fn as_slice_of<T: Pod>(&self) -> Result<&[T]> {
+    cast_slice(self)
+  }
+
+  /// View the buffer as a mutable slice of `T`, see [`cast_slice`]
+  pub fn as_mut_slice_of<T: Pod>(&mut self) -> Result<&mut [T]> {
+    cast_slice_mut(self)
+  }
+}
+
+impl Buffer {
+  /// View the buffer as a slice of `T`, see [`cast_slice`]
+  pub fn as_slice_of<T: Pod>(&self) -> Result<&[T]> {
+    cast_slice(self)
+  }
+
+  /// View the buffer as a mutable slice of `T`, see [`cast_slice`]
+  pub fn as_mut_slice_of<T: Pod>(&mut self) -> Result<&mut [T]> {
+    cast_slice_mut(self)
+  }
+}
@@ -7,7 +7,9 @@ use std::task::{Context, Poll};
 
 use tokio::sync::oneshot::{channel, Receiver};
 
-use crate::{sys, Error, Result, Status};
+use crate::{
+  check_status, sys, Env, Error, JsObject, NapiRaw, NapiValue, Result, Status, ValueType,
+};
 
 use super::{CallbackContext, FromNapiValue, PromiseRaw, TypeName, Unknown, ValidateNapiValue};
 
@@ -20,13 +22,17 @@ use super::{CallbackContext, FromNapiValue, PromiseRaw, TypeName, Unknown, Valid
 ///
 /// ```no_run
 /// #[napi]
-/// pub fn await_promise_in_rust(promise: Promise<u32>) {
-///   let value = promise.await.unwrap();
+/// pub async fn await_promise_in_rust(promise: Promise<u32>) -> Result<u32> {
+///   let value = promise.await?;
 ///
-///   println!("{value}");
+///   Ok(value + 1)
 /// }
 /// ```
 ///
+/// Like `await`, it accepts any thenable too. A rejection is returned as an `Error` rethrowing the
+/// rejection reason once it's thrown back to JavaScript, a reason which isn't an object is thrown
+/// as an `Error` with the reason as message.
+///
 /// But this `Promise<T>` can not be pass back to `JavaScript`.
 /// If you want to use raw JavaScript `Promise` API, you can use the [`PromiseRaw`](./PromiseRaw) instead.
 pub struct Promise<T: 'static + FromNapiValue> {
@@ -59,7 +65,8 @@ unsafe impl<T: FromNapiValue + Send> Send for Promise<T> {}
 impl<T: FromNapiValue> FromNapiValue for Promise<T> {
   unsafe fn from_napi_value(env: sys::napi_env, napi_val: sys::napi_value) -> crate::Result<Self> {
     let (tx, rx) = channel();
-    let mut promise_object = unsafe { PromiseRaw::<T>::from_napi_value(env, napi_val)? };
+    let promise = unsafe { resolve_thenable(env, napi_val)? };
+    let mut promise_object = unsafe { PromiseRaw::<T>::from_napi_value(env, promise)? };
     let tx_box = Arc::new(Cell::new(Some(tx)));
     let tx_in_catch = tx_box.clone();
     promise_object
@@ -84,6 +91,31 @@ impl<T: FromNapiValue> FromNapiValue for Promise<T> {
   }
 }
 
+/// `Promise.resolve(value)` for a thenable, the Promise itself for a Promise
+unsafe fn resolve_thenable(env: sys::napi_env, value: sys::napi_value) -> Result<sys::napi_value> {
+  let mut is_promise = false;
+  check_status!(unsafe { sys::napi_is_promise(env, value, &mut is_promise) })?;
+  if is_promise {
+    return Ok(value);
+  }
+  let value = unsafe { Unknown::from_raw_unchecked(env, value) };
+  let value_type = value.get_type()?;
+  let is_thenable = matches!(value_type, ValueType::Object | ValueType::Function)
+    && unsafe { JsObject::from_raw_unchecked(env, value.raw()) }
+      .get_named_property_unchecked::<Unknown>("then")?
+      .get_type()?
+      == ValueType::Function;
+  if !is_thenable {
+    return Err(Error::new(
+      Status::InvalidArg,
+      format!("Expected a Promise or a thenable, got {value_type}"),
+    ));
+  }
+  Env::from_raw(env)
+    .builtins()?
+    .promise_resolve_raw(value.raw())
+}
+
 impl<T: FromNapiValue> future::Future for Promise<T> {
   type Output = Result<T>;
 
@@ -0,0 +1,168 @@
+use std::cell::Cell;
+use std::ptr;
+use std::rc::Rc;
+#[cfg(all(feature = "napi3", not(target_family = "wasm")))]
+use std::time::Duration;
+
+use crate::{check_status, sys, Env, NapiRaw, Result};
+
+use super::{on_settled, FromNapiValue, PromiseRaw};
+
+/// The deferred of a Promise settled by the first of several callbacks
+struct Settler {
+  env: sys::napi_env,
+  deferred: Cell<Option<sys::napi_deferred>>,
+}
+
+impl Settler {
+  fn new(env: sys::napi_env) -> Result<(Rc<Self>, sys::napi_value)> {
+    let mut deferred = ptr::null_mut();
+    let mut promise = ptr::null_mut();
+    check_status!(
+      unsafe { sys::napi_create_promise(env, &mut deferred, &mut promise) },
+      "Failed to create the combined Promise"
+    )?;
+    let settler = Self {
+      env,
+      deferred: Cell::new(Some(deferred)),
+    };
+    Ok((Rc::new(settler), promise))
+  }
+
+  fn is_settled(&self) -> bool {
+    self.deferred.get().is_none()
+  }
+
+  /// Settle the Promise, unless it's settled already
+  fn settle(&self, fulfilled: bool, value: sys::napi_value) -> Result<()> {
+    let Some(deferred) = self.deferred.take() else {
+      return Ok(());
+    };
+    if fulfilled {
+      check_status!(
+        unsafe { sys::napi_resolve_deferred(self.env, deferred, value) },
+        "Failed to resolve the combined Promise"
+      )
+    } else {
+      check_status!(
+        unsafe { sys::napi_reject_deferred(self.env, deferred, value) },
+        "Failed to reject the combined Promise"
+      )
+    }
+  }
+}
+
+impl<T: FromNapiValue> PromiseRaw<T> {
+  /// A Promise settled like this one, or rejected with a `Cancelled` error if this one is still
+  /// pending after `duration`.
+  ///
+  /// The timer runs on the libuv loop and keeps the process alive until either happens, like a
+  /// `setTimeout`. This Promise itself keeps running, it can't be aborted.
+  #[cfg(all(feature = "napi3", not(target_family = "wasm")))]
+  pub fn timeout(self, duration: Duration) -> Result<PromiseRaw<T>> {
+    let env = Env::from_raw(self.env);
+    let (settler, promise) = Settler::new(self.env)?;
+    let on_timeout = settler.clone();
+    let timer = Rc::new(crate::uv::uv_timer_once(&env, duration, move |env| {
+      let error = crate::Error::new(
+        crate::Status::Cancelled,
+        format!("The Promise timed out after {}ms", duration.as_millis()),
+      );
+      on_timeout.settle(false, unsafe {
+        crate::JsError::from(error).into_value(env.raw())
+      })
+    })?);
+    let on_settled_timer = timer.clone();
+    let on_value = move |_: Env, fulfilled, value| {
+      on_settled_timer.cancel();
+      settler.settle(fulfilled, value)
+    };
+    if let Err(err) = unsafe { on_settled(self.env, self.inner, on_value) } {
+      timer.cancel();
+      return Err(err);
+    }
+    Ok(PromiseRaw::new(self.env, promise))
+  }
+
+  /// Like `Promise.race`, a Promise settled like the first of `promises` to settle.
+  ///
+  /// The `promises` can be any JavaScript Promises or thenables, it's forever pending if there are
+  /// none.
+  pub fn race<I>(env: &Env, promises: I) -> Result<PromiseRaw<T>>
+  where
+    I: IntoIterator<Item = PromiseRaw<T>>,
+  {
+    let (settler, promise) = Settler::new(env.raw())?;
+    for input in promises {
+      if settler.is_settled() {
+        break;
+      }
+      let settler = settler.clone();
+      let on_value = move |_: Env, fulfilled, value| settler.settle(fulfilled, value);
+      unsafe { on_settled(env.raw(), input.raw(), on_value)? };
+    }
+    Ok(PromiseRaw::new(env.raw(), promise))
+  }
+
+  /// Like `Promise.all`, a Promise fulfilled with the values of all `promises` in order once they
+  /// are fulfilled, or rejected like the first of them to be rejected.
+  ///
+  /// The `promises` can be any JavaScript Promises or thenables, it's fulfilled with an empty array
+  /// if there are none.
+  pub fn all<I>(env: &Env, promises: I) -> Result<PromiseRaw<Vec<T>>>
+  where
+    I: IntoIterator<Item = PromiseRaw<T>>,
+  {
+    let promises = promises.into_iter().collect::<Vec<_>>();
+    let (settler, promise) = Settler::new(env.raw())?;
+    let mut values = ptr::null_mut();
+    check_status!(unsafe {
+      sys::napi_create_array_with_length(env.raw(), promises.len(), &mut values)
+    })?;
+    if promises.is_empty() {
+      settler.settle(true, values)?;
+      return Ok(PromiseRaw::new(env.raw(), promise));
+    }
+    let mut values_ref = ptr::null_mut();
+    check_status!(unsafe { sys::napi_create_reference(env.raw(), values, 1, &mut values_ref) })?;
+    let values = Rc::new(AllValues {
+      env: env.raw(),
+      values: values_ref,
+      remaining: Cell::new(promises.len()),
+    });
+    for (index, input) in promises.into_iter().enumerate() {
+      let settler = settler.clone();
+      let values = values.clone();
+      let on_value = move |env: Env, fulfilled: bool, value| {
+        if !fulfilled || settler.is_settled() {
+          return settler.settle(fulfilled, value);
+        }
+        let mut array = ptr::null_mut();
+        check_status!(unsafe {
+          sys::napi_get_reference_value(env.raw(), values.values, &mut array)
+        })?;
+        check_status!(unsafe { sys::napi_set_element(env.raw(), array, index as u32, value) })?;
+        values.remaining.set(values.remaining.get() - 1);
+        if values.remaining.get() == 0 {
+          settler.settle(true, array)?;
+        }
+        Ok(())
+      };
+      unsafe { on_settled(env.raw(), input.raw(), on_value)? };
+    }
+    Ok(PromiseRaw::new(env.raw(), promise))
+  }
+}
+
+/// The values of [`PromiseRaw::all`], referenced until the last callback is dropped
+struct AllValues {
+  env: sys::napi_env,
+  values: sys::napi_ref,
+  remaining: Cell<usize>,
+}
+
+impl Drop for AllValues {
+  fn drop(&mut self) {
+    unsafe { sys::napi_delete_reference(self.env, self.values) };
+  }
+}
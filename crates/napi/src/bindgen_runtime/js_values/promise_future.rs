@@ -0,0 +1,265 @@
+use std::cell::RefCell;
+use std::future::{Future, IntoFuture};
+use std::marker::PhantomData;
+use std::mem;
+use std::pin::Pin;
+use std::ptr;
+use std::rc::{Rc, Weak};
+use std::task::{Context, Poll, Waker};
+
+use crate::{check_status, sys, Env, Error, JsUnknown, NapiRaw, NapiValue, Result, Status};
+
+use super::{FromNapiValue, FunctionCallContext, PromiseRaw, TypeName, ValidateNapiValue};
+
+/// A JavaScript Promise, or any thenable, awaited on the JavaScript thread without a threadsafe
+/// function, like in the futures of [`Env::spawn_local`](crate::Env::spawn_local).
+///
+/// The callbacks passed to its `then` wake the task awaiting it. The settled value is converted to
+/// `T` when the future is polled, so a JavaScript value like an `Object` is valid in the handle
+/// scope of the poll resuming the future:
+///
+/// ```rust,ignore
+/// #[napi]
+/// fn fetch_all(env: Env, fetch: FunctionRef<String, PromiseRaw<String>>, urls: Vec<String>) -> Result<PromiseRaw<Vec<String>>> {
+///   env.spawn_local(async move {
+///     let mut bodies = Vec::with_capacity(urls.len());
+///     for url in urls {
+///       bodies.push(fetch.borrow_back(&env)?.call(url)?.await?);
+///     }
+///     Ok(bodies)
+///   })
+/// }
+/// ```
+///
+/// Unlike [`Promise`](super::Promise) it's neither `Send` nor needs the `tokio_rt` feature, it must
+/// be polled on the JavaScript thread.
+pub struct JsPromiseFuture<T> {
+  env: sys::napi_env,
+  state: FutureState,
+  _phantom: PhantomData<fn() -> T>,
+}
+
+enum FutureState {
+  Waiting(Rc<RefCell<Settlement>>),
+  /// Registering the callbacks failed, the error is returned on the first poll
+  Failed(Error),
+  Done,
+}
+
+/// Shared with the `then` callbacks, which only hold a weak reference to it so it's dropped with
+/// the future
+struct Settlement {
+  env: sys::napi_env,
+  /// An array holding the settled value, primitives can't be referenced directly
+  holder: sys::napi_ref,
+  outcome: Outcome,
+  waker: Option<Waker>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Outcome {
+  Pending,
+  Fulfilled,
+  Rejected,
+}
+
+impl Drop for Settlement {
+  fn drop(&mut self) {
+    unsafe { sys::napi_delete_reference(self.env, self.holder) };
+  }
+}
+
+impl<T: FromNapiValue> JsPromiseFuture<T> {
+  /// Await `value`, adopted like `Promise.resolve(value)` does: a thenable is followed, any other
+  /// value is the output of the future
+  pub fn new<V: NapiRaw>(env: &Env, value: V) -> Result<Self> {
+    unsafe { Self::from_napi_value(env.raw(), value.raw()) }
+  }
+}
+
+impl<T: FromNapiValue> FromNapiValue for JsPromiseFuture<T> {
+  unsafe fn from_napi_value(env: sys::napi_env, napi_val: sys::napi_value) -> Result<Self> {
+    let settlement = unsafe { listen(env, napi_val)? };
+    Ok(Self {
+      env,
+      state: FutureState::Waiting(settlement),
+      _phantom: PhantomData,
+    })
+  }
+}
+
+impl<T: FromNapiValue> TypeName for JsPromiseFuture<T> {
+  fn type_name() -> &'static str {
+    "Promise"
+  }
+
+  fn value_type() -> crate::ValueType {
+    crate::ValueType::Object
+  }
+}
+
+impl<T: FromNapiValue> ValidateNapiValue for JsPromiseFuture<T> {
+  unsafe fn validate(env: sys::napi_env, napi_val: sys::napi_value) -> Result<sys::napi_value> {
+    super::validate_promise(env, napi_val)
+  }
+}
+
+impl<T: FromNapiValue> IntoFuture for PromiseRaw<T> {
+  type Output = Result<T>;
+  type IntoFuture = JsPromiseFuture<T>;
+
+  /// Await the Promise on the JavaScript thread, see [`JsPromiseFuture`]
+  fn into_future(self) -> Self::IntoFuture {
+    let env = Env::from_raw(self.env);
+    JsPromiseFuture::new(&env, self).unwrap_or_else(|err| JsPromiseFuture {
+      env: env.raw(),
+      state: FutureState::Failed(err),
+      _phantom: PhantomData,
+    })
+  }
+}
+
+impl<T: FromNapiValue> Future for JsPromiseFuture<T> {
+  type Output = Result<T>;
+
+  fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+    let settlement = match mem::replace(&mut self.state, FutureState::Done) {
+      FutureState::Waiting(settlement) => settlement,
+      FutureState::Failed(err) => return Poll::Ready(Err(err)),
+      FutureState::Done => {
+        return Poll::Ready(Err(Error::new(
+          Status::GenericFailure,
+          "JsPromiseFuture polled after completion",
+        )))
+      }
+    };
+    let outcome = settlement.borrow().outcome;
+    if outcome == Outcome::Pending {
+      settlement.borrow_mut().waker = Some(cx.waker().clone());
+      self.state = FutureState::Waiting(settlement);
+      return Poll::Pending;
+    }
+    let env = self.env;
+    let value = unsafe { settled_value(env, &settlement.borrow()) };
+    Poll::Ready(value.and_then(|value| match outcome {
+      Outcome::Fulfilled => unsafe { T::from_napi_value(env, value) },
+      _ => Err(unsafe { JsUnknown::from_raw_unchecked(env, value) }.into()),
+    }))
+  }
+}
+
+unsafe fn settled_value(env: sys::napi_env, settlement: &Settlement) -> Result<sys::napi_value> {
+  let mut holder = ptr::null_mut();
+  check_status!(
+    unsafe { sys::napi_get_reference_value(env, settlement.holder, &mut holder) },
+    "Failed to get the settled value of the Promise"
+  )?;
+  let mut value = ptr::null_mut();
+  check_status!(unsafe { sys::napi_get_element(env, holder, 0, &mut value) })?;
+  Ok(value)
+}
+
+/// Listen to `value` with callbacks settling the returned state
+unsafe fn listen(env: sys::napi_env, value: sys::napi_value) -> Result<Rc<RefCell<Settlement>>> {
+  let mut holder = ptr::null_mut();
+  check_status!(unsafe { sys::napi_create_array_with_length(env, 1, &mut holder) })?;
+  let mut holder_ref = ptr::null_mut();
+  check_status!(unsafe { sys::napi_create_reference(env, holder, 1, &mut holder_ref) })?;
+  let settlement = Rc::new(RefCell::new(Settlement {
+    env,
+    holder: holder_ref,
+    outcome: Outcome::Pending,
+    waker: None,
+  }));
+  let weak: Weak<RefCell<Settlement>> = Rc::downgrade(&settlement);
+  let on_value = move |env: Env, fulfilled, value| {
+    // the future is dropped
+    let Some(settlement) = weak.upgrade() else {
+      return Ok(());
+    };
+    let mut settlement = settlement.borrow_mut();
+    let mut holder = ptr::null_mut();
+    check_status!(unsafe {
+      sys::napi_get_reference_value(env.raw(), settlement.holder, &mut holder)
+    })?;
+    check_status!(unsafe { sys::napi_set_element(env.raw(), holder, 0, value) })?;
+    settlement.outcome = if fulfilled {
+      Outcome::Fulfilled
+    } else {
+      Outcome::Rejected
+    };
+    let waker = settlement.waker.take();
+    drop(settlement);
+    if let Some(waker) = waker {
+      waker.wake();
+    }
+    Ok(())
+  };
+  unsafe { on_settled(env, value, on_value)? };
+  Ok(settlement)
+}
+
+/// Call `Promise.resolve(value).then(onFulfilled, onRejected)`, `callback` is called on the
+/// JavaScript thread with whether the value is fulfilled and the value or the rejection reason.
+///
+/// An error returned by `callback` rejects the Promise returned by `then`, which nothing listens to
+pub(crate) unsafe fn on_settled<F>(
+  env: sys::napi_env,
+  value: sys::napi_value,
+  callback: F,
+) -> Result<()>
+where
+  F: 'static + Fn(Env, bool, sys::napi_value) -> Result<()>,
+{
+  let promise = Env::from_raw(env).builtins()?.promise_resolve_raw(value)?;
+  let then = unsafe { get_named(env, promise, c"then")? };
+  let callback = Rc::new(callback);
+  let env_ref = Env::from_raw(env);
+  let mut callbacks = [ptr::null_mut(); 2];
+  for (fulfilled, raw) in [true, false].into_iter().zip(callbacks.iter_mut()) {
+    let callback = callback.clone();
+    let function = env_ref.create_function_from_closure::<JsUnknown, (), _>(
+      if fulfilled {
+        "onFulfilled"
+      } else {
+        "onRejected"
+      },
+      move |ctx: FunctionCallContext| {
+        let value = match ctx.args.first() {
+          Some(value) => *value,
+          None => ctx.env.get_undefined()?.raw(),
+        };
+        callback(*ctx.env, fulfilled, value)
+      },
+    )?;
+    *raw = unsafe { function.raw() };
+  }
+  let mut chained = ptr::null_mut();
+  check_status!(
+    unsafe {
+      sys::napi_call_function(
+        env,
+        promise,
+        then,
+        callbacks.len(),
+        callbacks.as_ptr(),
+        &mut chained,
+      )
+    },
+    "Failed to call `then` of the awaited Promise"
+  )
+}
+
+unsafe fn get_named(
+  env: sys::napi_env,
+  object: sys::napi_value,
+  name: &std::ffi::CStr,
+) -> Result<sys::napi_value> {
+  let mut value = ptr::null_mut();
+  check_status!(
+    unsafe { sys::napi_get_named_property(env, object, name.as_ptr(), &mut value) },
+    "Failed to get `{}`",
+    name.to_string_lossy()
+  )?;
+  Ok(value)
+}
@@ -11,7 +11,7 @@ use crate::{Env, Error, NapiRaw, NapiValue, Status};
 
 pub struct PromiseRaw<T> {
   pub(crate) inner: sys::napi_value,
-  env: sys::napi_env,
+  pub(crate) env: sys::napi_env,
   _phantom: PhantomData<T>,
 }
 
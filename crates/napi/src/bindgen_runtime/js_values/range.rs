@@ -0,0 +1,122 @@
+use std::ops::{Range, RangeInclusive};
+
+use super::{FromNapiValue, Object, ToNapiValue, TypeName, ValidateNapiValue};
+use crate::{sys, Env, Error, Result, Status, ValueType};
+
+impl<T> TypeName for Range<T> {
+  fn type_name() -> &'static str {
+    "Range"
+  }
+
+  fn value_type() -> ValueType {
+    ValueType::Object
+  }
+}
+
+impl<T> TypeName for RangeInclusive<T> {
+  fn type_name() -> &'static str {
+    "RangeInclusive"
+  }
+
+  fn value_type() -> ValueType {
+    ValueType::Object
+  }
+}
+
+impl<T: FromNapiValue + PartialOrd> ValidateNapiValue for Range<T> {
+  unsafe fn validate(env: sys::napi_env, napi_val: sys::napi_value) -> Result<sys::napi_value> {
+    unsafe { Object::validate(env, napi_val) }
+  }
+}
+
+impl<T: FromNapiValue + PartialOrd> ValidateNapiValue for RangeInclusive<T> {
+  unsafe fn validate(env: sys::napi_env, napi_val: sys::napi_value) -> Result<sys::napi_value> {
+    unsafe { Object::validate(env, napi_val) }
+  }
+}
+
+/// `{ start, end }` to a `Range`, `end` is exclusive
+impl<T: FromNapiValue + PartialOrd> FromNapiValue for Range<T> {
+  unsafe fn from_napi_value(env: sys::napi_env, napi_val: sys::napi_value) -> Result<Self> {
+    let (start, end) = unsafe { range_bounds(env, napi_val)? };
+    Ok(start..end)
+  }
+}
+
+/// `{ start, end }` to a `RangeInclusive`, `end` is inclusive
+impl<T: FromNapiValue + PartialOrd> FromNapiValue for RangeInclusive<T> {
+  unsafe fn from_napi_value(env: sys::napi_env, napi_val: sys::napi_value) -> Result<Self> {
+    let (start, end) = unsafe { range_bounds(env, napi_val)? };
+    Ok(start..=end)
+  }
+}
+
+impl<T: ToNapiValue> ToNapiValue for Range<T> {
+  unsafe fn to_napi_value(env: sys::napi_env, val: Self) -> Result<sys::napi_value> {
+    unsafe { range_to_object(env, val.start, val.end) }
+  }
+}
+
+impl<T: ToNapiValue> ToNapiValue for RangeInclusive<T> {
+  unsafe fn to_napi_value(env: sys::napi_env, val: Self) -> Result<sys::napi_value> {
+    let (start, end) = val.into_inner();
+    unsafe { range_to_object(env, start, end) }
+  }
+}
+
+unsafe fn range_bounds<T: FromNapiValue + PartialOrd>(
+  env: sys::napi_env,
+  napi_val: sys::napi_value,
+) -> Result<(T, T)> {
+  unsafe { Object::validate(env, napi_val)? };
+  let obj = unsafe { Object::from_napi_value(env, napi_val)? };
+  let start = obj
+    .get::<T>("start")?
+    .ok_or_else(|| Error::new(Status::InvalidArg, "Missing `start` of the range"))?;
+  let end = obj
+    .get::<T>("end")?
+    .ok_or_else(|| Error::new(Status::InvalidArg, "Missing `end` of the range"))?;
+  if start > end {
+    return Err(Error::new(
+      Status::InvalidArg,
+      "Invalid range, `start` is greater than `end`",
+    ));
+  }
+  Ok((start, end))
+}
+
+unsafe fn range_to_object<T: ToNapiValue>(
+  env: sys::napi_env,
+  start: T,
+  end: T,
+) -> Result<sys::napi_value> {
+  let mut obj = Env::from_raw(env).create_object()?;
+  obj.set("start", start)?;
+  obj.set("end", end)?;
+  unsafe { Object::to_napi_value(env, obj) }
+}
+
+/// Resolve the `start` and `end` arguments of a slice-style API, like `Array.prototype.slice`, to
+/// a range of a sequence of `len` items.
+///
+/// Missing `start` is 0 and missing `end` is `len`, negative values count back from `len`, and
+/// both are clamped to `0..=len`. An `end` before `start` is an empty range.
+///
+/// ```rust
+/// #[napi]
+/// pub fn slice_bytes(bytes: Buffer, start: Option<i64>, end: Option<i64>) -> Buffer {
+///   bytes[slice_range(bytes.len(), start, end)].to_vec().into()
+/// }
+/// ```
+pub fn slice_range(len: usize, start: Option<i64>, end: Option<i64>) -> Range<usize> {
+  let resolve = |index: i64| {
+    if index < 0 {
+      len.saturating_sub(index.unsigned_abs() as usize)
+    } else {
+      (index as u64).min(len as u64) as usize
+    }
+  };
+  let start = start.map_or(0, resolve);
+  let end = end.map_or(len, resolve);
+  start..end.max(start)
+}
@@ -147,9 +147,9 @@ impl ToNapiValue for Number {
 impl FromNapiValue for Number {
   unsafe fn from_napi_value(env: sys::napi_env, napi_val: sys::napi_value) -> Result<Self> {
     let n = unsafe { f64::from_napi_value(env, napi_val)? };
-    // Try to auto-convert to integers
+    // Try to auto-convert to integers, `-0` must stay a float to keep its sign
     let n = if n.trunc() == n {
-      if n >= 0.0f64 && n <= u32::MAX as f64 {
+      if n.is_sign_positive() && n <= u32::MAX as f64 {
         // This can be represented as u32
         Some(Number::from(n as u32))
       } else if n < 0.0f64 && n >= i32::MIN as f64 {
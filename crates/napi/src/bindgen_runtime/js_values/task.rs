@@ -1,4 +1,5 @@
 use std::ffi::c_void;
+use std::marker::PhantomData;
 use std::ptr;
 use std::rc::Rc;
 use std::sync::atomic::{AtomicPtr, AtomicU8, Ordering};
@@ -164,3 +165,63 @@ unsafe extern "C" fn async_task_abort_controller_finalize(
 ) {
   drop(unsafe { Box::from_raw(finalize_data as *mut AbortSignal) });
 }
+
+/// The `Task` running the body of a `#[napi(thread = "blocking")]` function
+struct BlockingCall<Data, Call, Resolver> {
+  call: Option<Call>,
+  resolver: Option<Resolver>,
+  _data: PhantomData<Data>,
+}
+
+// `resolver` is only taken in `resolve`, which is always called on the JavaScript thread,
+// the libuv thread only runs `call`
+unsafe impl<Data, Call: Send, Resolver> Send for BlockingCall<Data, Call, Resolver> {}
+
+impl<Data, Call, Resolver> Task for BlockingCall<Data, Call, Resolver>
+where
+  Data: 'static + Send,
+  Call: 'static + Send + FnOnce() -> crate::Result<Data>,
+  Resolver: 'static + FnOnce(sys::napi_env, Data) -> crate::Result<sys::napi_value>,
+{
+  type Output = Data;
+  type JsValue = Unknown;
+
+  fn compute(&mut self) -> crate::Result<Self::Output> {
+    let call = self
+      .call
+      .take()
+      .ok_or_else(|| Error::new(Status::GenericFailure, "Blocking call already run"))?;
+    call()
+  }
+
+  fn resolve(&mut self, env: Env, output: Self::Output) -> crate::Result<Self::JsValue> {
+    let resolver = self
+      .resolver
+      .take()
+      .ok_or_else(|| Error::new(Status::GenericFailure, "Blocking call already resolved"))?;
+    let value = resolver(env.raw(), output)?;
+    Ok(unsafe { Unknown::from_raw_unchecked(env.raw(), value) })
+  }
+}
+
+#[doc(hidden)]
+/// Run `call` on the libuv thread pool and return a promise of its result converted by
+/// `resolver`, used by the functions marked with `#[napi(thread = "blocking")]`
+pub fn execute_blocking<Data, Call, Resolver>(
+  env: sys::napi_env,
+  call: Call,
+  resolver: Resolver,
+) -> crate::Result<sys::napi_value>
+where
+  Data: 'static + Send,
+  Call: 'static + Send + FnOnce() -> crate::Result<Data>,
+  Resolver: 'static + FnOnce(sys::napi_env, Data) -> crate::Result<sys::napi_value>,
+{
+  let task = BlockingCall {
+    call: Some(call),
+    resolver: Some(resolver),
+    _data: PhantomData,
+  };
+  let async_promise = async_work::run(env, task, None)?;
+  Ok(async_promise.promise_object().inner)
+}
@@ -0,0 +1,199 @@
+use std::cell::Cell;
+use std::rc::Rc;
+use std::sync::{Arc, Mutex};
+
+use super::{Buffer, CallbackContext, FromNapiValue, Function, FunctionRef, TypeName, Unknown};
+use crate::{sys, Env, Error, JsObject, NapiRaw, Result, Status, Task, ValueType};
+
+#[cfg(any(
+  feature = "transform_zlib",
+  feature = "transform_brotli",
+  feature = "transform_zstd"
+))]
+mod codecs;
+#[cfg(any(
+  feature = "transform_zlib",
+  feature = "transform_brotli",
+  feature = "transform_zstd"
+))]
+pub use codecs::CompressionCodec;
+
+/// A stateful byte transformer, e.g. a compressor or a decompressor, that can be driven by a
+/// Node.js [`Transform`] stream.
+///
+/// ```rust
+/// struct Gzip(flate2::write::GzEncoder<Vec<u8>>);
+///
+/// impl StreamCodec for Gzip {
+///   fn transform(&mut self, input: &[u8], output: &mut Vec<u8>) -> Result<()> {
+///     self.0.write_all(input)?;
+///     output.append(self.0.get_mut());
+///     Ok(())
+///   }
+///
+///   fn flush(&mut self, output: &mut Vec<u8>) -> Result<()> {
+///     self.0.try_finish()?;
+///     output.append(self.0.get_mut());
+///     Ok(())
+///   }
+/// }
+/// ```
+pub trait StreamCodec: Send + 'static {
+  /// Process a chunk written to the stream, appending the produced bytes to `output`.
+  ///
+  /// Called in the libuv thread pool, chunks are processed one at a time and in order.
+  fn transform(&mut self, input: &[u8], output: &mut Vec<u8>) -> Result<()>;
+
+  /// Called once after the writable side of the stream ended, append the remaining bytes
+  /// (e.g. the compression trailer) to `output`.
+  fn flush(&mut self, output: &mut Vec<u8>) -> Result<()>;
+}
+
+/// A Node.js [`stream.Transform`](https://nodejs.org/api/stream.html#class-streamtransform) backed
+/// by a [`StreamCodec`].
+///
+/// The gzip, deflate, brotli and zstd codecs are available as [`CompressionCodec`] with the
+/// `transform_zlib`, `transform_brotli` and `transform_zstd` features.
+///
+/// The codec runs in the libuv thread pool and the `transform` callback of the stream is only
+/// called back once the chunk has been processed, so the usual backpressure of Node.js streams
+/// applies. `flush` is forwarded when the writable side ends.
+///
+/// Requires `process.getBuiltinModule`, available since Node.js 20.16 and 22.3.
+pub struct Transform {
+  pub(crate) inner: JsObject,
+}
+
+type TransformCallback = FunctionRef<(Option<Error>, Option<Buffer>), Unknown>;
+
+impl Transform {
+  pub fn new<C: StreamCodec>(env: &Env, codec: C) -> Result<Self> {
+    let codec = Arc::new(Mutex::new(codec));
+    let codec_to_flush = codec.clone();
+    let transform = env.create_function_from_closure::<(Buffer, Unknown, Unknown), (), _>(
+      "transform",
+      move |ctx| {
+        let (chunk, _encoding, callback) = ctx.args::<(
+          Buffer,
+          Unknown,
+          Function<(Option<Error>, Option<Buffer>), Unknown>,
+        )>()?;
+        let task = CodecTask {
+          codec: codec.clone(),
+          chunk: Some(chunk.to_vec()),
+        };
+        run_codec_task(ctx.env, task, callback.create_ref()?)
+      },
+    )?;
+    let flush = env.create_function_from_closure::<Unknown, (), _>("flush", move |ctx| {
+      let callback: Function<(Option<Error>, Option<Buffer>), Unknown> = ctx.first_arg()?;
+      let task = CodecTask {
+        codec: codec_to_flush.clone(),
+        chunk: None,
+      };
+      run_codec_task(ctx.env, task, callback.create_ref()?)
+    })?;
+    let mut options = env.create_object()?;
+    options.set_named_property("transform", transform)?;
+    options.set_named_property("flush", flush)?;
+    let ctor = get_stream_constructor(env, "Transform")?;
+    let inner = ctor.new_instance(options)?;
+    Ok(Self {
+      inner: unsafe { JsObject::from_napi_value(env.raw(), inner.raw())? },
+    })
+  }
+}
+
+impl TypeName for Transform {
+  fn type_name() -> &'static str {
+    "Transform"
+  }
+
+  fn value_type() -> ValueType {
+    ValueType::Object
+  }
+}
+
+impl NapiRaw for Transform {
+  unsafe fn raw(&self) -> sys::napi_value {
+    unsafe { self.inner.raw() }
+  }
+}
+
+struct CodecTask<C: StreamCodec> {
+  codec: Arc<Mutex<C>>,
+  // `None` means flush
+  chunk: Option<Vec<u8>>,
+}
+
+impl<C: StreamCodec> Task for CodecTask<C> {
+  type Output = Vec<u8>;
+  type JsValue = Buffer;
+
+  fn compute(&mut self) -> Result<Self::Output> {
+    let mut codec = self
+      .codec
+      .lock()
+      .map_err(|_| Error::new(Status::GenericFailure, "StreamCodec mutex is poisoned"))?;
+    let mut output = Vec::new();
+    match self.chunk.take() {
+      Some(chunk) => codec.transform(&chunk, &mut output)?,
+      None => codec.flush(&mut output)?,
+    }
+    Ok(output)
+  }
+
+  fn resolve(&mut self, _env: Env, output: Self::Output) -> Result<Self::JsValue> {
+    Ok(output.into())
+  }
+}
+
+fn run_codec_task<C: StreamCodec>(
+  env: &Env,
+  task: CodecTask<C>,
+  callback: TransformCallback,
+) -> Result<()> {
+  let callback = Rc::new(Cell::new(Some(callback)));
+  let callback_in_catch = callback.clone();
+  env
+    .spawn(task)?
+    .promise_object()
+    .then(move |ctx| {
+      if let Some(callback) = callback.take() {
+        let output = if ctx.value.is_empty() {
+          None
+        } else {
+          Some(ctx.value)
+        };
+        callback.borrow_back(&ctx.env)?.call((None, output))?;
+      }
+      Ok(())
+    })?
+    .catch(move |ctx: CallbackContext<Unknown>| {
+      if let Some(callback) = callback_in_catch.take() {
+        callback
+          .borrow_back(&ctx.env)?
+          .call((Some(ctx.value.into()), None))?;
+      }
+      Ok(())
+    })?;
+  Ok(())
+}
+
+fn get_stream_constructor<'scope>(
+  env: &Env,
+  name: &str,
+) -> Result<Function<'scope, JsObject, Unknown>> {
+  let process: JsObject = env.get_global()?.get_named_property_unchecked("process")?;
+  let get_builtin_module: Unknown = process.get_named_property_unchecked("getBuiltinModule")?;
+  if get_builtin_module.get_type()? != ValueType::Function {
+    return Err(Error::new(
+      Status::GenericFailure,
+      "`process.getBuiltinModule` is not available, it's required to create Node.js streams",
+    ));
+  }
+  let get_builtin_module: Function<&str, JsObject> =
+    unsafe { Function::from_napi_value(env.raw(), get_builtin_module.raw())? };
+  let stream = get_builtin_module.apply(&process, "node:stream")?;
+  stream.get_named_property(name)
+}
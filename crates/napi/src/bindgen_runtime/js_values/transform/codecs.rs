@@ -0,0 +1,310 @@
+use std::io::{self, Write};
+
+use super::StreamCodec;
+use crate::{Error, Result, Status};
+
+/// A streaming compressor or decompressor writing into a `Vec<u8>`
+trait CodecWriter: Write + Send {
+  /// The bytes produced so far
+  fn output(&mut self) -> &mut Vec<u8>;
+
+  /// Write the end of the stream, e.g. the compression trailer, and return the remaining bytes.
+  ///
+  /// Decompressors fail if the input ended in the middle of the compressed data.
+  fn finish(self: Box<Self>) -> io::Result<Vec<u8>>;
+}
+
+#[cfg(feature = "transform_zlib")]
+macro_rules! impl_flate2_writer {
+  ($($writer:ident),*) => {
+    $(
+      impl CodecWriter for flate2::write::$writer<Vec<u8>> {
+        fn output(&mut self) -> &mut Vec<u8> {
+          self.get_mut()
+        }
+
+        fn finish(self: Box<Self>) -> io::Result<Vec<u8>> {
+          flate2::write::$writer::finish(*self)
+        }
+      }
+    )*
+  };
+}
+
+#[cfg(feature = "transform_zlib")]
+impl_flate2_writer!(GzEncoder, GzDecoder, ZlibEncoder, DeflateEncoder);
+
+#[cfg(feature = "transform_zlib")]
+/// The deflate decoders of flate2 accept truncated streams, the end of the stream is tracked here
+struct Inflater {
+  decompress: flate2::Decompress,
+  output: Vec<u8>,
+  ended: bool,
+}
+
+#[cfg(feature = "transform_zlib")]
+impl Write for Inflater {
+  fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+    let mut input = buf;
+    // the bytes after the end of the stream are ignored, like by `node:zlib`
+    while !input.is_empty() && !self.ended {
+      self.output.reserve(32 * 1024);
+      let total_in = self.decompress.total_in();
+      let output_len = self.output.len();
+      let status =
+        self
+          .decompress
+          .decompress_vec(input, &mut self.output, flate2::FlushDecompress::None)?;
+      let consumed = (self.decompress.total_in() - total_in) as usize;
+      if consumed == 0 && self.output.len() == output_len {
+        return Err(io::Error::new(
+          io::ErrorKind::InvalidData,
+          "the deflate stream is corrupted",
+        ));
+      }
+      input = &input[consumed..];
+      self.ended = status == flate2::Status::StreamEnd;
+    }
+    Ok(buf.len())
+  }
+
+  fn flush(&mut self) -> io::Result<()> {
+    Ok(())
+  }
+}
+
+#[cfg(feature = "transform_zlib")]
+impl CodecWriter for Inflater {
+  fn output(&mut self) -> &mut Vec<u8> {
+    &mut self.output
+  }
+
+  fn finish(self: Box<Self>) -> io::Result<Vec<u8>> {
+    if !self.ended {
+      return Err(io::Error::new(
+        io::ErrorKind::UnexpectedEof,
+        "the deflate stream is incomplete",
+      ));
+    }
+    Ok(self.output)
+  }
+}
+
+#[cfg(feature = "transform_brotli")]
+impl CodecWriter for brotli::CompressorWriter<Vec<u8>> {
+  fn output(&mut self) -> &mut Vec<u8> {
+    self.get_mut()
+  }
+
+  fn finish(self: Box<Self>) -> io::Result<Vec<u8>> {
+    Ok(self.into_inner())
+  }
+}
+
+#[cfg(feature = "transform_brotli")]
+impl CodecWriter for brotli::DecompressorWriter<Vec<u8>> {
+  fn output(&mut self) -> &mut Vec<u8> {
+    self.get_mut()
+  }
+
+  fn finish(mut self: Box<Self>) -> io::Result<Vec<u8>> {
+    self.close()?;
+    self.into_inner().map_err(|_| {
+      io::Error::new(
+        io::ErrorKind::UnexpectedEof,
+        "the brotli stream is incomplete",
+      )
+    })
+  }
+}
+
+#[cfg(feature = "transform_zstd")]
+impl<D: zstd::stream::raw::Operation + Send> CodecWriter for zstd::stream::zio::Writer<Vec<u8>, D> {
+  fn output(&mut self) -> &mut Vec<u8> {
+    self.writer_mut()
+  }
+
+  fn finish(mut self: Box<Self>) -> io::Result<Vec<u8>> {
+    zstd::stream::zio::Writer::finish(&mut self)?;
+    Ok(self.into_inner().0)
+  }
+}
+
+/// The compressors and decompressors bundled with napi, named like the `node:zlib` streams they
+/// replace.
+///
+/// Each format is behind its own feature: `transform_zlib` for gzip and deflate, `transform_brotli`
+/// and `transform_zstd`.
+///
+/// ```rust
+/// #[napi]
+/// pub fn create_gzip(env: Env, level: Option<u32>) -> Result<Transform> {
+///   Transform::new(&env, CompressionCodec::gzip(level.unwrap_or(6))?)
+/// }
+/// ```
+pub struct CompressionCodec {
+  /// `None` once the stream is flushed
+  writer: Option<Box<dyn CodecWriter>>,
+  name: &'static str,
+}
+
+impl CompressionCodec {
+  fn new<W: CodecWriter + 'static>(name: &'static str, writer: W) -> Self {
+    Self {
+      writer: Some(Box::new(writer)),
+      name,
+    }
+  }
+
+  #[cfg(feature = "transform_zlib")]
+  fn compression_level(level: u32) -> Result<flate2::Compression> {
+    if level > 9 {
+      return Err(Error::new(
+        Status::InvalidArg,
+        format!("The compression level must be between 0 and 9, got {level}"),
+      ));
+    }
+    Ok(flate2::Compression::new(level))
+  }
+
+  #[cfg(feature = "transform_zlib")]
+  /// gzip compression, `level` is between 0 (no compression) and 9 (smallest output)
+  pub fn gzip(level: u32) -> Result<Self> {
+    let level = Self::compression_level(level)?;
+    Ok(Self::new(
+      "gzip",
+      flate2::write::GzEncoder::new(Vec::new(), level),
+    ))
+  }
+
+  #[cfg(feature = "transform_zlib")]
+  /// gzip decompression
+  pub fn gunzip() -> Self {
+    Self::new("gunzip", flate2::write::GzDecoder::new(Vec::new()))
+  }
+
+  #[cfg(feature = "transform_zlib")]
+  /// deflate compression with the zlib header, `level` is between 0 and 9
+  pub fn deflate(level: u32) -> Result<Self> {
+    let level = Self::compression_level(level)?;
+    Ok(Self::new(
+      "deflate",
+      flate2::write::ZlibEncoder::new(Vec::new(), level),
+    ))
+  }
+
+  #[cfg(feature = "transform_zlib")]
+  /// deflate decompression with the zlib header
+  pub fn inflate() -> Self {
+    Self::new(
+      "inflate",
+      Inflater {
+        decompress: flate2::Decompress::new(true),
+        output: Vec::new(),
+        ended: false,
+      },
+    )
+  }
+
+  #[cfg(feature = "transform_zlib")]
+  /// raw deflate compression, without header, `level` is between 0 and 9
+  pub fn deflate_raw(level: u32) -> Result<Self> {
+    let level = Self::compression_level(level)?;
+    Ok(Self::new(
+      "deflateRaw",
+      flate2::write::DeflateEncoder::new(Vec::new(), level),
+    ))
+  }
+
+  #[cfg(feature = "transform_zlib")]
+  /// raw deflate decompression
+  pub fn inflate_raw() -> Self {
+    Self::new(
+      "inflateRaw",
+      Inflater {
+        decompress: flate2::Decompress::new(false),
+        output: Vec::new(),
+        ended: false,
+      },
+    )
+  }
+
+  #[cfg(feature = "transform_brotli")]
+  /// brotli compression, `quality` is between 0 and 11
+  pub fn brotli_compress(quality: u32) -> Result<Self> {
+    if quality > 11 {
+      return Err(Error::new(
+        Status::InvalidArg,
+        format!("The brotli quality must be between 0 and 11, got {quality}"),
+      ));
+    }
+    Ok(Self::new(
+      "brotliCompress",
+      brotli::CompressorWriter::new(Vec::new(), 4096, quality, 22),
+    ))
+  }
+
+  #[cfg(feature = "transform_brotli")]
+  /// brotli decompression
+  pub fn brotli_decompress() -> Self {
+    Self::new(
+      "brotliDecompress",
+      brotli::DecompressorWriter::new(Vec::new(), 4096),
+    )
+  }
+
+  #[cfg(feature = "transform_zstd")]
+  /// zstd compression, `level` is between 1 and 22, 0 is the default level of zstd
+  pub fn zstd_compress(level: i32) -> Result<Self> {
+    let encoder = zstd::stream::raw::Encoder::new(level)?;
+    Ok(Self::new(
+      "zstdCompress",
+      zstd::stream::zio::Writer::new(Vec::new(), encoder),
+    ))
+  }
+
+  #[cfg(feature = "transform_zstd")]
+  /// zstd decompression
+  pub fn zstd_decompress() -> Result<Self> {
+    let decoder = zstd::stream::raw::Decoder::new()?;
+    Ok(Self::new(
+      "zstdDecompress",
+      zstd::stream::zio::Writer::new(Vec::new(), decoder),
+    ))
+  }
+
+  fn flushed_error(&self) -> Error {
+    Error::new(
+      Status::GenericFailure,
+      format!("The {} stream is already flushed", self.name),
+    )
+  }
+
+  fn codec_error(&self, err: io::Error) -> Error {
+    Error::new(
+      Status::GenericFailure,
+      format!("{} failed: {err}", self.name),
+    )
+  }
+}
+
+impl StreamCodec for CompressionCodec {
+  fn transform(&mut self, input: &[u8], output: &mut Vec<u8>) -> Result<()> {
+    let Some(writer) = self.writer.as_mut() else {
+      return Err(self.flushed_error());
+    };
+    let written = writer.write_all(input);
+    output.append(writer.output());
+    written.map_err(|err| self.codec_error(err))
+  }
+
+  fn flush(&mut self, output: &mut Vec<u8>) -> Result<()> {
+    let Some(mut writer) = self.writer.take() else {
+      return Err(self.flushed_error());
+    };
+    output.append(writer.output());
+    let mut rest = writer.finish().map_err(|err| self.codec_error(err))?;
+    output.append(&mut rest);
+    Ok(())
+  }
+}
@@ -114,6 +114,7 @@ impl<T: 'static> FromNapiValue for Reference<T> {
     napi_val: crate::sys::napi_value,
   ) -> Result<Self> {
     let mut value = ptr::null_mut();
+    unsafe { crate::bindgen_prelude::check_class_instance::<T>(env, napi_val) }?;
     check_status!(
       unsafe { crate::sys::napi_unwrap(env, napi_val, &mut value) },
       "Unwrap value [{}] from class Reference failed",
@@ -0,0 +1,145 @@
+//! Share the native state of a host addon with plugin addons.
+//!
+//! The host exports a `#[repr(C)]` table of function pointers as a type tagged `External`, and the
+//! plugins, which are separate cdylibs with their own copy of napi-rs, receive it from JavaScript
+//! and call into the host through it:
+//!
+//! ```rust,ignore
+//! // in a crate shared by the host and the plugins
+//! #[repr(C)]
+//! pub struct CounterVTable {
+//!   pub add: extern "C" fn(by: u32) -> u32,
+//! }
+//!
+//! unsafe impl PluginVTable for CounterVTable {
+//!   const TYPE_TAG: u128 = 0x8f6c_1d02_4b7e_4a39_b3c5_90e1_27d4_6a18;
+//! }
+//!
+//! // in the host
+//! #[napi]
+//! fn counter_vtable() -> VTable<CounterVTable> {
+//!   VTable::new(&COUNTER_VTABLE)
+//! }
+//!
+//! // in a plugin
+//! #[napi]
+//! fn init(host: VTable<CounterVTable>) {
+//!   (host.add)(1);
+//! }
+//! ```
+
+use std::any::type_name;
+use std::ops::Deref;
+use std::ptr;
+
+use super::{FromNapiValue, ToNapiValue, TypeName, ValidateNapiValue};
+use crate::{check_status, sys, Error, Result, Status, ValueType};
+
+/// A table of function pointers exported by a host addon to its plugins.
+///
+/// # Safety
+///
+/// The type must be `#[repr(C)]` with only FFI-safe fields, and have the same layout in the host
+/// and in every plugin using the same `TYPE_TAG`.
+pub unsafe trait PluginVTable: Sync + 'static {
+  /// Identifies the vtable and its layout across addons, generate it once, like a UUID, and change
+  /// it whenever the layout changes.
+  const TYPE_TAG: u128;
+}
+
+fn type_tag<V: PluginVTable>() -> sys::napi_type_tag {
+  sys::napi_type_tag {
+    lower: V::TYPE_TAG as u64,
+    upper: (V::TYPE_TAG >> 64) as u64,
+  }
+}
+
+/// A [`PluginVTable`] passed to JavaScript as an `External`
+pub struct VTable<V: PluginVTable> {
+  inner: &'static V,
+}
+
+impl<V: PluginVTable> VTable<V> {
+  pub fn new(vtable: &'static V) -> Self {
+    Self { inner: vtable }
+  }
+}
+
+impl<V: PluginVTable> Clone for VTable<V> {
+  fn clone(&self) -> Self {
+    *self
+  }
+}
+
+impl<V: PluginVTable> Copy for VTable<V> {}
+
+impl<V: PluginVTable> Deref for VTable<V> {
+  type Target = V;
+
+  fn deref(&self) -> &V {
+    self.inner
+  }
+}
+
+impl<V: PluginVTable> TypeName for VTable<V> {
+  fn type_name() -> &'static str {
+    "VTable"
+  }
+
+  fn value_type() -> ValueType {
+    ValueType::External
+  }
+}
+
+impl<V: PluginVTable> ValidateNapiValue for VTable<V> {}
+
+impl<V: PluginVTable> ToNapiValue for VTable<V> {
+  unsafe fn to_napi_value(env: sys::napi_env, val: Self) -> Result<sys::napi_value> {
+    let mut external = ptr::null_mut();
+    check_status!(
+      unsafe {
+        sys::napi_create_external(
+          env,
+          (val.inner as *const V).cast_mut().cast(),
+          None,
+          ptr::null_mut(),
+          &mut external,
+        )
+      },
+      "Failed to create the External of `{}`",
+      type_name::<V>(),
+    )?;
+    check_status!(
+      unsafe { sys::napi_type_tag_object(env, external, &type_tag::<V>()) },
+      "Failed to tag the External of `{}`",
+      type_name::<V>(),
+    )?;
+    Ok(external)
+  }
+}
+
+impl<V: PluginVTable> FromNapiValue for VTable<V> {
+  unsafe fn from_napi_value(env: sys::napi_env, napi_val: sys::napi_value) -> Result<Self> {
+    let mut is_vtable = false;
+    check_status!(
+      unsafe { sys::napi_check_object_type_tag(env, napi_val, &type_tag::<V>(), &mut is_vtable) },
+      "Failed to check the type tag of `{}`",
+      type_name::<V>(),
+    )?;
+    if !is_vtable {
+      return Err(Error::new(
+        Status::InvalidArg,
+        format!("The value is not a `{}` vtable", type_name::<V>()),
+      ));
+    }
+    let mut data = ptr::null_mut();
+    check_status!(
+      unsafe { sys::napi_get_value_external(env, napi_val, &mut data) },
+      "Failed to get the vtable of `{}`",
+      type_name::<V>(),
+    )?;
+    Ok(Self {
+      inner: unsafe { &*data.cast::<V>() },
+    })
+  }
+}
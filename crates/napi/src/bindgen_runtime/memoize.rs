@@ -0,0 +1,129 @@
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::ptr;
+
+use crate::{check_status, sys, Env, Result};
+
+/// The number of results kept for each `#[napi(memoize)]` function in an env, the least recently
+/// used one is released beyond it
+const MEMOIZE_CAPACITY: usize = 256;
+
+/// The results of the `#[napi(memoize)]` functions taking arguments of type `K`, keyed by function
+struct MemoizedResults<K> {
+  env: sys::napi_env,
+  results: RefCell<HashMap<&'static str, HashMap<K, MemoizedResult>>>,
+  last_use: Cell<u64>,
+}
+
+struct MemoizedResult {
+  value: sys::napi_ref,
+  last_use: u64,
+}
+
+impl<K> MemoizedResults<K> {
+  fn next_use(&self) -> u64 {
+    let last_use = self.last_use.get() + 1;
+    self.last_use.set(last_use);
+    last_use
+  }
+}
+
+impl<K> Drop for MemoizedResults<K> {
+  fn drop(&mut self) {
+    // Without cleanup hooks the results are only dropped when the thread exits, with the env gone
+    #[cfg(feature = "napi3")]
+    for result in self.results.get_mut().values().flat_map(HashMap::values) {
+      unsafe { sys::napi_delete_reference(self.env, result.value) };
+    }
+  }
+}
+
+#[doc(hidden)]
+/// The result memoized for the arguments `key` by the function `name` in `env`
+///
+/// # Safety
+///
+/// `env` must be the env of the current call
+pub unsafe fn get_memoized<K: Hash + Eq + 'static>(
+  env: sys::napi_env,
+  name: &'static str,
+  key: &K,
+) -> Result<Option<sys::napi_value>> {
+  let Some(memoized) = Env::from_raw(env).get_instance_data_typed::<MemoizedResults<K>>() else {
+    return Ok(None);
+  };
+  let last_use = memoized.next_use();
+  let Some(result_ref) = memoized
+    .results
+    .borrow_mut()
+    .get_mut(name)
+    .and_then(|results| results.get_mut(key))
+    .map(|result| {
+      result.last_use = last_use;
+      result.value
+    })
+  else {
+    return Ok(None);
+  };
+  let mut result = ptr::null_mut();
+  check_status!(
+    unsafe { sys::napi_get_reference_value(env, result_ref, &mut result) },
+    "Failed to get the memoized result of `{}`",
+    name
+  )?;
+  Ok(Some(result))
+}
+
+#[doc(hidden)]
+/// Keep `value` as the result of the function `name` for the arguments `key`, until `env` is torn
+/// down or it's the least recently used of `MEMOIZE_CAPACITY` results.
+///
+/// Every call with equal arguments gets the same `value`, objects mutated by one caller are seen
+/// mutated by the next ones.
+///
+/// # Safety
+///
+/// `env` must be the env of the current call, and `value` a valid value of it
+pub unsafe fn set_memoized<K: Hash + Eq + 'static>(
+  env: sys::napi_env,
+  name: &'static str,
+  key: K,
+  value: sys::napi_value,
+) -> Result<()> {
+  let memoized = Env::from_raw(env).get_or_init_instance_data_typed(|| {
+    Ok(MemoizedResults::<K> {
+      env,
+      results: Default::default(),
+      last_use: Cell::new(0),
+    })
+  })?;
+  let mut result_ref = ptr::null_mut();
+  check_status!(
+    unsafe { sys::napi_create_reference(env, value, 1, &mut result_ref) },
+    "Failed to memoize the result of `{}`",
+    name
+  )?;
+  let result = MemoizedResult {
+    value: result_ref,
+    last_use: memoized.next_use(),
+  };
+  let mut results = memoized.results.borrow_mut();
+  let results = results.entry(name).or_default();
+  if let Some(previous) = results.insert(key, result) {
+    unsafe { sys::napi_delete_reference(env, previous.value) };
+  }
+  if results.len() > MEMOIZE_CAPACITY {
+    // the uses are unique, this releases one result
+    if let Some(least_recent_use) = results.values().map(|result| result.last_use).min() {
+      results.retain(|_, result| {
+        if result.last_use != least_recent_use {
+          return true;
+        }
+        unsafe { sys::napi_delete_reference(env, result.value) };
+        false
+      });
+    }
+  }
+  Ok(())
+}
@@ -3,20 +3,30 @@ use std::rc::Rc;
 
 pub use callback_info::*;
 pub use ctor::ctor;
+pub use enum_reflection::*;
 pub use env::*;
+pub use error_class::*;
 pub use iterator::Generator;
 pub use js_values::*;
+pub use memoize::*;
 pub use module_register::*;
+pub use type_tag::*;
+pub use unstable::*;
 
 use super::sys;
 use crate::{JsError, Result, Status};
 
 mod callback_info;
+mod enum_reflection;
 mod env;
 mod error;
+mod error_class;
 pub mod iterator;
 mod js_values;
+mod memoize;
 mod module_register;
+mod type_tag;
+mod unstable;
 
 pub trait ObjectFinalize: Sized {
   #[allow(unused)]
@@ -25,6 +35,43 @@ pub trait ObjectFinalize: Sized {
   }
 }
 
+/// Observe the properties JavaScript sets on a class marked with `#[napi(set_hook)]`, for
+/// validation or dirty tracking without writing every setter by hand.
+///
+/// ```rust
+/// #[napi(set_hook)]
+/// pub struct Model {
+///   pub name: String,
+///   pub age: u32,
+///   dirty: Vec<&'static str>,
+/// }
+///
+/// impl ObjectSetHook for Model {
+///   fn before_set(&mut self, property: &'static str, value: &dyn Any) -> Result<()> {
+///     match value.downcast_ref::<u32>() {
+///       Some(age) if property == "age" && *age > 150 => Err(Error::from_reason("invalid age")),
+///       _ => Ok(()),
+///     }
+///   }
+///
+///   fn after_set(&mut self, property: &'static str) {
+///     self.dirty.push(property);
+///   }
+/// }
+/// ```
+pub trait ObjectSetHook {
+  /// Called with the converted value before the generated setter of a pub field assigns it,
+  /// returning an error keeps the previous value and throws in JavaScript.
+  #[allow(unused)]
+  fn before_set(&mut self, property: &'static str, value: &dyn std::any::Any) -> Result<()> {
+    Ok(())
+  }
+
+  /// Called after a pub field or a `#[napi(setter)]` method was set from JavaScript.
+  #[allow(unused)]
+  fn after_set(&mut self, property: &'static str) {}
+}
+
 /// # Safety
 ///
 /// called when node wrapper objects destroyed
@@ -1,8 +1,12 @@
 #[cfg(not(feature = "noop"))]
+use std::any::Any;
+#[cfg(not(feature = "noop"))]
 use std::collections::HashSet;
 #[cfg(not(feature = "noop"))]
 use std::ffi::CStr;
 #[cfg(not(feature = "noop"))]
+use std::panic::{self, AssertUnwindSafe};
+#[cfg(not(feature = "noop"))]
 use std::ptr;
 #[cfg(all(
   not(any(target_os = "macos", target_family = "wasm")),
@@ -11,18 +15,27 @@ use std::ptr;
 ))]
 use std::sync::atomic::AtomicUsize;
 #[cfg(not(feature = "noop"))]
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::atomic::Ordering;
+#[cfg(not(feature = "noop"))]
+use std::sync::{Condvar, Mutex, MutexGuard, Once, OnceLock};
 use std::sync::{LazyLock, RwLock};
 use std::thread::ThreadId;
+#[cfg(not(feature = "noop"))]
+use std::time::Duration;
 use std::{any::TypeId, collections::HashMap};
 
 #[cfg(not(feature = "noop"))]
-use crate::{check_status, check_status_or_throw, JsError};
+use super::ArgsBuffer;
+#[cfg(not(feature = "noop"))]
+use crate::{check_status, Error, JsError, JsTypeError, Status};
 use crate::{sys, Property, Result};
 
 pub type ExportRegisterCallback = unsafe fn(sys::napi_env) -> Result<sys::napi_value>;
 pub type ModuleExportsCallback =
   unsafe fn(env: sys::napi_env, exports: sys::napi_value) -> Result<()>;
+/// Whether the arguments of a call match the parameters of an overload, the arguments missing from
+/// the call are `undefined`
+pub type OverloadMatcher = unsafe fn(env: sys::napi_env, args: &[sys::napi_value]) -> bool;
 
 #[repr(transparent)]
 pub(crate) struct PersistedPerInstanceHashMap<K, V>(RwLock<HashMap<K, V>>);
@@ -61,20 +74,87 @@ unsafe impl<K, V> Sync for PersistedPerInstanceHashMap<K, V> {}
 type FnRegisterMap =
   PersistedPerInstanceHashMap<ExportRegisterCallback, (sys::napi_callback, &'static str)>;
 type RegisteredClassesMap = PersistedPerInstanceHashMap<ThreadId, RegisteredClasses>;
+type ModuleMembers = RwLock<HashMap<(Option<&'static str>, &'static str), &'static str>>;
+type ModuleOverloads = RwLock<Vec<(Option<&'static str>, &'static str, Overload)>>;
+type ExportOrigins = RwLock<Vec<(Option<&'static str>, &'static str, &'static str)>>;
+
+#[derive(Clone, Copy)]
+#[cfg_attr(feature = "noop", allow(dead_code))]
+struct Overload {
+  /// The declaration order, the registration order depends on the platform
+  index: usize,
+  arity: usize,
+  matcher: OverloadMatcher,
+  callback: sys::napi_callback,
+}
+
+/// The registration of the first module, the registrations on other threads wait for it
+#[cfg(not(feature = "noop"))]
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum FirstModule {
+  Unclaimed,
+  Registering,
+  Registered,
+}
+
+/// The claim of the registration of the first module, given up when dropped before
+/// [`FirstModuleClaim::registered`], so the next registration sets up the first module again
+#[cfg(not(feature = "noop"))]
+struct FirstModuleClaim;
+
+#[cfg(not(feature = "noop"))]
+impl FirstModuleClaim {
+  fn registered(self) {
+    std::mem::forget(self);
+    set_first_module(FirstModule::Registered);
+  }
+}
+
+#[cfg(not(feature = "noop"))]
+impl Drop for FirstModuleClaim {
+  fn drop(&mut self) {
+    set_first_module(FirstModule::Unclaimed);
+  }
+}
+
+/// The overloads exported as one function, its callback data
+#[cfg(not(feature = "noop"))]
+struct OverloadSet {
+  js_mod: Option<&'static str>,
+  name: &'static str,
+  arity: usize,
+  overloads: Vec<Overload>,
+}
 
 static MODULE_REGISTER_CALLBACK: LazyLock<ModuleRegisterCallback> = LazyLock::new(Default::default);
 static MODULE_CLASS_PROPERTIES: LazyLock<ModuleClassProperty> = LazyLock::new(Default::default);
 #[cfg(not(feature = "noop"))]
-static IS_FIRST_MODULE: AtomicBool = AtomicBool::new(true);
+static FIRST_MODULE: Mutex<FirstModule> = Mutex::new(FirstModule::Unclaimed);
+#[cfg(not(feature = "noop"))]
+static FIRST_MODULE_CHANGED: Condvar = Condvar::new();
+// the exports of the features and the settings read from the environment, once per process
+#[cfg(not(feature = "noop"))]
+static FIRST_MODULE_SETUP: Once = Once::new();
+// how long the registrations on other threads wait for the first one, like in the Workers
 #[cfg(not(feature = "noop"))]
-static FIRST_MODULE_REGISTERED: AtomicBool = AtomicBool::new(false);
+const FIRST_MODULE_TIMEOUT: Duration = Duration::from_secs(30);
 static REGISTERED_CLASSES: LazyLock<RegisteredClassesMap> = LazyLock::new(Default::default);
 static FN_REGISTER_MAP: LazyLock<FnRegisterMap> = LazyLock::new(Default::default);
+// `#[napi(module)]` of the exports, keyed by namespace and name
+static MODULE_MEMBERS: LazyLock<ModuleMembers> = LazyLock::new(Default::default);
+// `#[napi(overload = "...")]` functions in the order they were registered
+static MODULE_OVERLOADS: LazyLock<ModuleOverloads> = LazyLock::new(Default::default);
+/// The `#[napi]` items exported under each name, to report the names exported more than once
+static EXPORT_ORIGINS: LazyLock<ExportOrigins> = LazyLock::new(Default::default);
+// grouped once all of them are registered, so the exported functions can point to them
+#[cfg(not(feature = "noop"))]
+static OVERLOAD_SETS: OnceLock<Vec<OverloadSet>> = OnceLock::new();
 #[cfg(all(feature = "napi4", not(feature = "noop"), not(target_family = "wasm")))]
 pub(crate) static CUSTOM_GC_TSFN: std::sync::atomic::AtomicPtr<sys::napi_threadsafe_function__> =
   std::sync::atomic::AtomicPtr::new(ptr::null_mut());
 #[cfg(all(feature = "napi4", not(feature = "noop"), not(target_family = "wasm")))]
-pub(crate) static CUSTOM_GC_TSFN_DESTROYED: AtomicBool = AtomicBool::new(false);
+pub(crate) static CUSTOM_GC_TSFN_DESTROYED: std::sync::atomic::AtomicBool =
+  std::sync::atomic::AtomicBool::new(false);
 #[cfg(all(feature = "napi4", not(feature = "noop"), not(target_family = "wasm")))]
 // Store thread id of the thread that created the CustomGC ThreadsafeFunction.
 pub(crate) static THREADS_CAN_ACCESS_ENV: LazyLock<PersistedPerInstanceHashMap<ThreadId, bool>> =
@@ -89,11 +169,12 @@ static MODULE_EXPORTS: LazyLock<RwLock<Vec<ModuleExportsCallback>>> =
   LazyLock::new(Default::default);
 
 #[cfg(not(feature = "noop"))]
-#[inline]
-fn wait_first_thread_registered() {
-  while !FIRST_MODULE_REGISTERED.load(Ordering::SeqCst) {
-    std::hint::spin_loop();
-  }
+fn panic_message(panic: &(dyn Any + Send)) -> &str {
+  panic
+    .downcast_ref::<String>()
+    .map(String::as_str)
+    .or_else(|| panic.downcast_ref::<&str>().copied())
+    .unwrap_or("unknown panic payload")
 }
 
 #[doc(hidden)]
@@ -118,6 +199,31 @@ pub fn register_module_export(
     .push((js_mod, (name, cb)));
 }
 
+#[doc(hidden)]
+pub fn register_module_member(
+  module: &'static str,
+  js_mod: Option<&'static str>,
+  name: &'static str,
+) {
+  MODULE_MEMBERS
+    .write()
+    .expect("Register module member failed")
+    .insert((js_mod, name), module);
+}
+
+#[doc(hidden)]
+/// Record the Rust item exported as `name`, like ``fn `parse` at src/lib.rs:12:8``
+pub fn register_export_origin(
+  js_mod: Option<&'static str>,
+  name: &'static str,
+  origin: &'static str,
+) {
+  EXPORT_ORIGINS
+    .write()
+    .expect("Register export origin failed")
+    .push((js_mod, name, origin));
+}
+
 #[doc(hidden)]
 pub fn register_js_function(
   name: &'static str,
@@ -129,6 +235,128 @@ pub fn register_js_function(
   });
 }
 
+#[doc(hidden)]
+/// Add a function taking `arity` JavaScript arguments to the overloads exported as `name`, `index`
+/// is its declaration order
+pub fn register_overload(
+  js_mod: Option<&'static str>,
+  name: &'static str,
+  index: usize,
+  arity: usize,
+  matcher: OverloadMatcher,
+  callback: sys::napi_callback,
+) {
+  MODULE_OVERLOADS
+    .write()
+    .expect("Register overload failed")
+    .push((
+      js_mod,
+      name,
+      Overload {
+        index,
+        arity,
+        matcher,
+        callback,
+      },
+    ));
+}
+
+#[cfg(not(feature = "noop"))]
+fn group_overloads() -> Vec<OverloadSet> {
+  let mut sets: Vec<OverloadSet> = Vec::new();
+  for (js_mod, name, overload) in MODULE_OVERLOADS
+    .read()
+    .expect("Read MODULE_OVERLOADS failed")
+    .iter()
+  {
+    match sets
+      .iter_mut()
+      .find(|set| set.js_mod == *js_mod && set.name == *name)
+    {
+      Some(set) => {
+        set.arity = set.arity.max(overload.arity);
+        set.overloads.push(*overload);
+      }
+      None => sets.push(OverloadSet {
+        js_mod: *js_mod,
+        name,
+        arity: overload.arity,
+        overloads: vec![*overload],
+      }),
+    }
+  }
+  // the overload with the fewest parameters wins, then the first declared one
+  for set in sets.iter_mut() {
+    set
+      .overloads
+      .sort_by_key(|overload| (overload.arity, overload.index));
+  }
+  sets
+}
+
+#[cfg(not(feature = "noop"))]
+/// The exported function of an [`OverloadSet`], calls the first overload matching the arguments
+unsafe extern "C" fn call_overload(
+  env: sys::napi_env,
+  cb_info: sys::napi_callback_info,
+) -> sys::napi_value {
+  let mut argc = 0;
+  let mut data = ptr::null_mut();
+  let status = unsafe {
+    sys::napi_get_cb_info(
+      env,
+      cb_info,
+      &mut argc,
+      ptr::null_mut(),
+      ptr::null_mut(),
+      &mut data,
+    )
+  };
+  if status != sys::Status::napi_ok || data.is_null() {
+    return ptr::null_mut();
+  }
+  let set = unsafe { &*(data as *const OverloadSet) };
+  // the missing arguments are filled with `undefined`
+  let mut args = ArgsBuffer::with_len(set.arity.max(argc));
+  let mut len = args.len();
+  let status = unsafe {
+    sys::napi_get_cb_info(
+      env,
+      cb_info,
+      &mut len,
+      args.as_mut_slice().as_mut_ptr(),
+      ptr::null_mut(),
+      ptr::null_mut(),
+    )
+  };
+  if status != sys::Status::napi_ok {
+    return ptr::null_mut();
+  }
+  for overload in &set.overloads {
+    if argc <= overload.arity && unsafe { (overload.matcher)(env, &args) } {
+      return match overload.callback {
+        Some(callback) => unsafe { callback(env, cb_info) },
+        None => ptr::null_mut(),
+      };
+    }
+  }
+  let received = args[..argc]
+    .iter()
+    .map(|arg| {
+      let mut value_type = -1;
+      unsafe { sys::napi_typeof(env, *arg, &mut value_type) };
+      crate::ValueType::from(value_type).to_string()
+    })
+    .collect::<Vec<_>>()
+    .join(", ");
+  let reason = format!(
+    "No overload of `{}` matches the arguments ({received})",
+    set.name.trim_end_matches('\0')
+  );
+  unsafe { JsTypeError::from(Error::new(Status::InvalidArg, reason)).throw_into(env) };
+  ptr::null_mut()
+}
+
 #[doc(hidden)]
 pub fn get_class_constructor(js_name: &'static str) -> Option<sys::napi_ref> {
   let current_id = std::thread::current().id();
@@ -197,9 +425,16 @@ unsafe extern "C" fn napi_register_wasm_v1(
 }
 
 #[cfg(not(feature = "noop"))]
-#[no_mangle]
+#[export_name = env!("NAPI_RS_INIT_SYMBOL")]
 /// Register the n-api module exports.
 ///
+/// The symbol is `napi_register_module_v1` unless the `NAPI_RS_INIT_SYMBOL` environment variable
+/// was set while building, see [`module!`](crate::module) to export it under more names.
+///
+/// An error or a panic while registering, like in a `#[module_exports]` function, is thrown to
+/// JavaScript by `require` with the export it happened in. The setup of the first module is done
+/// again by the next registration then, so the addon can be required again once the cause is gone.
+///
 /// # Safety
 /// This method is meant to be called by Node.js while importing the n-api module.
 /// Only call this method if the current module is **not** imported by a node-like runtime.
@@ -208,148 +443,357 @@ unsafe extern "C" fn napi_register_wasm_v1(
 pub unsafe extern "C" fn napi_register_module_v1(
   env: sys::napi_env,
   exports: sys::napi_value,
+) -> sys::napi_value {
+  unsafe { register_module(env, exports) }
+}
+
+#[cfg(not(feature = "noop"))]
+/// Register the exports of the `#[napi]` items on `exports`, the body of every init symbol.
+///
+/// # Safety
+/// Same as [`napi_register_module_v1`].
+pub unsafe fn register_module(env: sys::napi_env, exports: sys::napi_value) -> sys::napi_value {
+  unsafe { register_module_items(env, exports, None) }
+}
+
+#[cfg(not(feature = "noop"))]
+/// Register only the exports of the items marked with `#[napi(module = "...")]`, so one library
+/// can provide several modules, each with its own init symbol, see [`module!`](crate::module).
+///
+/// # Safety
+/// Same as [`napi_register_module_v1`].
+pub unsafe fn register_named_module(
+  env: sys::napi_env,
+  exports: sys::napi_value,
+  module: &str,
+) -> sys::napi_value {
+  unsafe { register_module_items(env, exports, Some(module)) }
+}
+
+#[cfg(not(feature = "noop"))]
+unsafe fn register_module_items(
+  env: sys::napi_env,
+  exports: sys::napi_value,
+  module: Option<&str>,
 ) -> sys::napi_value {
   #[cfg(all(
-    any(target_env = "msvc", feature = "dyn-symbols"),
+    any(target_env = "msvc", target_os = "android", feature = "dyn-symbols"),
     not(feature = "noop")
   ))]
   unsafe {
     sys::setup();
   }
-  if IS_FIRST_MODULE.load(Ordering::SeqCst) {
-    IS_FIRST_MODULE.store(false, Ordering::SeqCst);
+  // a panic can't unwind into Node.js, it would abort the process
+  let registered = panic::catch_unwind(AssertUnwindSafe(|| unsafe {
+    try_register_module_items(env, exports, module)
+  }))
+  .unwrap_or_else(|panic| {
+    Err(Error::new(
+      Status::GenericFailure,
+      format!("panicked: {}", panic_message(&*panic)),
+    ))
+  });
+  match registered {
+    Ok(errors) => {
+      // the other exports are registered, the failures are thrown together
+      if let Some(e) = aggregate_errors(errors) {
+        let reason = format!("Failed to initialize the module: {}", e.reason);
+        unsafe { JsError::from(Error::new(e.status, reason)).throw_into(env) };
+      }
+    }
+    Err(e) => {
+      let reason = format!("Failed to initialize the module: {}", e.reason);
+      unsafe { JsError::from(Error::new(e.status, reason)).throw_into(env) };
+    }
+  }
+  exports
+}
+
+#[cfg(not(feature = "noop"))]
+/// One error for the exports which failed to register
+fn aggregate_errors(mut errors: Vec<Error>) -> Option<Error> {
+  match errors.len() {
+    0 => None,
+    1 => errors.pop(),
+    count => Some(Error::new(
+      Status::GenericFailure,
+      format!(
+        "{count} exports failed: {}",
+        errors
+          .iter()
+          .map(|e| e.reason.as_str())
+          .collect::<Vec<_>>()
+          .join("; ")
+      ),
+    )),
+  }
+}
+
+#[cfg(not(feature = "noop"))]
+/// The errors for the names exported by more than one item, the item registered last replaces the
+/// others
+fn duplicate_export_errors(
+  in_module: impl Fn(Option<&'static str>, &'static str) -> bool,
+) -> Vec<Error> {
+  let mut counts = HashMap::<(Option<&'static str>, &'static str), usize>::new();
+  let mut count = |js_mod: Option<&'static str>, name: &'static str| {
+    if in_module(js_mod, name) {
+      *counts.entry((js_mod, name)).or_default() += 1;
+    }
+  };
+  for (js_mod, (name, _)) in MODULE_REGISTER_CALLBACK
+    .read()
+    .expect("Read MODULE_REGISTER_CALLBACK failed")
+    .iter()
+  {
+    count(*js_mod, name);
+  }
+  for set in OVERLOAD_SETS.get_or_init(group_overloads) {
+    count(set.js_mod, set.name);
+  }
+  MODULE_CLASS_PROPERTIES.borrow_mut(|inner| {
+    for (js_mod, (js_name, _)) in inner.values().flatten() {
+      count(*js_mod, js_name);
+    }
+  });
+
+  let origins = EXPORT_ORIGINS.read().expect("Read EXPORT_ORIGINS failed");
+  let mut duplicates = counts
+    .into_iter()
+    .filter(|(_, count)| *count > 1)
+    .collect::<Vec<_>>();
+  duplicates.sort();
+  duplicates
+    .into_iter()
+    .map(|((js_mod, name), count)| {
+      let items = origins
+        .iter()
+        .filter(|(origin_mod, origin_name, _)| *origin_mod == js_mod && *origin_name == name)
+        .map(|(_, _, origin)| *origin)
+        .collect::<Vec<_>>();
+      let name = name.trim_end_matches('\0');
+      let js_name = match js_mod {
+        Some(js_mod) => format!("{}.{}", js_mod.trim_end_matches('\0'), name),
+        None => name.to_owned(),
+      };
+      let by = match items.split_last() {
+        Some((last, rest)) if !rest.is_empty() => format!(" by {} and {}", rest.join(", "), last),
+        _ => String::new(),
+      };
+      Error::new(
+        Status::GenericFailure,
+        format!("`{js_name}` is exported {count} times{by}"),
+      )
+    })
+    .collect()
+}
+
+#[cfg(not(feature = "noop"))]
+fn first_module() -> MutexGuard<'static, FirstModule> {
+  FIRST_MODULE
+    .lock()
+    .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+#[cfg(not(feature = "noop"))]
+fn set_first_module(state: FirstModule) {
+  *first_module() = state;
+  FIRST_MODULE_CHANGED.notify_all();
+}
+
+#[cfg(not(feature = "noop"))]
+/// Claim the setup of the first module, or wait for the thread which claimed it to finish it
+fn claim_first_module() -> Result<Option<FirstModuleClaim>> {
+  let (mut state, _) = FIRST_MODULE_CHANGED
+    .wait_timeout_while(first_module(), FIRST_MODULE_TIMEOUT, |state| {
+      *state == FirstModule::Registering
+    })
+    .unwrap_or_else(|poisoned| poisoned.into_inner());
+  match *state {
+    FirstModule::Registered => Ok(None),
+    FirstModule::Unclaimed => {
+      *state = FirstModule::Registering;
+      Ok(Some(FirstModuleClaim))
+    }
+    FirstModule::Registering => Err(Error::new(
+      Status::GenericFailure,
+      format!(
+        "timed out after {FIRST_MODULE_TIMEOUT:?} waiting for the module to be initialized on another thread"
+      ),
+    )),
+  }
+}
+
+#[cfg(not(feature = "noop"))]
+/// Get the object of the namespace `js_mod` on `exports`, creating it the first time
+unsafe fn get_or_create_js_mod(
+  env: sys::napi_env,
+  exports: sys::napi_value,
+  js_mod: &'static str,
+  exports_objects: &mut HashSet<String>,
+) -> Result<sys::napi_value> {
+  let mod_name_c_str = unsafe { CStr::from_bytes_with_nul_unchecked(js_mod.as_bytes()) };
+  let mut exports_js_mod = ptr::null_mut();
+  if exports_objects.contains(js_mod) {
+    check_status!(
+      unsafe {
+        sys::napi_get_named_property(env, exports, mod_name_c_str.as_ptr(), &mut exports_js_mod)
+      },
+      "Get mod {} from exports failed",
+      js_mod,
+    )?;
   } else {
-    wait_first_thread_registered();
+    check_status!(
+      unsafe { sys::napi_create_object(env, &mut exports_js_mod) },
+      "Create export JavaScript Object [{}] failed",
+      js_mod
+    )?;
+    check_status!(
+      unsafe {
+        sys::napi_set_named_property(env, exports, mod_name_c_str.as_ptr(), exports_js_mod)
+      },
+      "Set exports Object [{}] into exports object failed",
+      js_mod
+    )?;
+    exports_objects.insert(js_mod.to_string());
+  }
+  Ok(exports_js_mod)
+}
+
+#[cfg(not(feature = "noop"))]
+/// Register the items of `module`, or of the whole addon, on `exports`.
+///
+/// The failures of the exports don't stop the registration of the others, they are returned
+/// instead. The `Err` is a failure to set up the module itself.
+unsafe fn try_register_module_items(
+  env: sys::napi_env,
+  exports: sys::napi_value,
+  module: Option<&str>,
+) -> Result<Vec<Error>> {
+  let first_module_claim = claim_first_module()?;
+  if first_module_claim.is_some() {
+    FIRST_MODULE_SETUP.call_once(|| {
+      #[cfg(feature = "alloc_tracking")]
+      crate::alloc_tracking::register_exports();
+      #[cfg(feature = "formatting")]
+      crate::formatting::register_exports();
+      #[cfg(feature = "http_codecs")]
+      crate::http_codecs::register_exports();
+      #[cfg(feature = "text_encoding")]
+      crate::text_encoding::register_exports();
+      #[cfg(feature = "record")]
+      crate::record::start_recording_from_env();
+      crate::trace::enable_from_env();
+    });
   }
   let mut exports_objects: HashSet<String> = HashSet::default();
+  let module_members = MODULE_MEMBERS
+    .read()
+    .expect("Read MODULE_MEMBERS in napi_register_module_v1 failed");
+  let in_module = |js_mod: Option<&'static str>, name: &'static str| {
+    module.map_or(true, |module| {
+      module_members.get(&(js_mod, name)).copied() == Some(module)
+    })
+  };
+  let mut errors = duplicate_export_errors(in_module);
 
   {
-    let mut register_callback = MODULE_REGISTER_CALLBACK
-      .write()
-      .expect("Write MODULE_REGISTER_CALLBACK in napi_register_module_v1 failed");
-    register_callback
-      .iter_mut()
-      .fold(
-        HashMap::<Option<&'static str>, Vec<(&'static str, ExportRegisterCallback)>>::new(),
-        |mut acc, (js_mod, item)| {
-          if let Some(k) = acc.get_mut(js_mod) {
-            k.push(*item);
-          } else {
-            acc.insert(*js_mod, vec![*item]);
+    let register_callback = MODULE_REGISTER_CALLBACK
+      .read()
+      .expect("Read MODULE_REGISTER_CALLBACK in napi_register_module_v1 failed");
+    let mut grouped_items =
+      HashMap::<Option<&'static str>, Vec<(&'static str, ExportRegisterCallback)>>::new();
+    for (js_mod, item) in register_callback.iter() {
+      if in_module(*js_mod, item.0) {
+        grouped_items.entry(*js_mod).or_default().push(*item);
+      }
+    }
+    for (js_mod, items) in grouped_items {
+      let exported_object = match js_mod {
+        Some(js_mod_str) => {
+          match unsafe { get_or_create_js_mod(env, exports, js_mod_str, &mut exports_objects) } {
+            Ok(exported_object) => exported_object,
+            Err(e) => {
+              errors.push(e);
+              continue;
+            }
           }
-          acc
-        },
-      )
-      .iter()
-      .for_each(|(js_mod, items)| {
-        let mut exports_js_mod = ptr::null_mut();
-        if let Some(js_mod_str) = js_mod {
-          let mod_name_c_str =
-            unsafe { CStr::from_bytes_with_nul_unchecked(js_mod_str.as_bytes()) };
-          if exports_objects.contains(*js_mod_str) {
-            check_status_or_throw!(
-              env,
-              unsafe {
-                sys::napi_get_named_property(
-                  env,
-                  exports,
-                  mod_name_c_str.as_ptr(),
-                  &mut exports_js_mod,
-                )
-              },
-              "Get mod {} from exports failed",
-              js_mod_str,
-            );
-          } else {
-            check_status_or_throw!(
-              env,
-              unsafe { sys::napi_create_object(env, &mut exports_js_mod) },
-              "Create export JavaScript Object [{}] failed",
-              js_mod_str
-            );
-            check_status_or_throw!(
-              env,
+        }
+        None => exports,
+      };
+      for (name, callback) in items {
+        let export_name = name.trim_end_matches('\0');
+        // the panics are caught here too, to tell which export failed
+        let registered = panic::catch_unwind(|| unsafe { callback(env) })
+          .unwrap_or_else(|panic| {
+            Err(Error::new(
+              Status::GenericFailure,
+              format!("panicked: {}", panic_message(&*panic)),
+            ))
+          })
+          .map_err(|e| {
+            let reason = format!("Failed to create export `{export_name}`: {}", e.reason);
+            Error::new(e.status, reason)
+          })
+          .and_then(|value| {
+            let js_name = unsafe { CStr::from_bytes_with_nul_unchecked(name.as_bytes()) };
+            check_status!(
               unsafe {
-                sys::napi_set_named_property(env, exports, mod_name_c_str.as_ptr(), exports_js_mod)
+                sys::napi_set_named_property(env, exported_object, js_name.as_ptr(), value)
               },
-              "Set exports Object [{}] into exports object failed",
-              js_mod_str
-            );
-            exports_objects.insert(js_mod_str.to_string());
-          }
+              "Failed to register export `{}`",
+              export_name,
+            )
+          });
+        if let Err(e) = registered {
+          errors.push(e);
         }
-        for (name, callback) in items {
-          unsafe {
-            let js_name = CStr::from_bytes_with_nul_unchecked(name.as_bytes());
-            if let Err(e) = callback(env).and_then(|v| {
-              let exported_object = if exports_js_mod.is_null() {
-                exports
-              } else {
-                exports_js_mod
-              };
-              check_status!(
-                sys::napi_set_named_property(env, exported_object, js_name.as_ptr(), v),
-                "Failed to register export `{}`",
-                name,
-              )
-            }) {
-              JsError::from(e).throw_into(env)
-            }
-          }
-        }
-      });
+      }
+    }
+  }
+
+  for set in OVERLOAD_SETS.get_or_init(group_overloads) {
+    if !in_module(set.js_mod, set.name) {
+      continue;
+    }
+    if let Err(e) = unsafe { register_overload_set(env, exports, set, &mut exports_objects) } {
+      errors.push(e);
+    }
   }
 
   let mut registered_classes = HashMap::new();
 
   MODULE_CLASS_PROPERTIES.borrow_mut(|inner| {
-    inner.iter().for_each(|(_, js_mods)| {
+    for js_mods in inner.values() {
       for (js_mod, (js_name, props)) in js_mods {
-        let mut exports_js_mod = ptr::null_mut();
-        unsafe {
-          if let Some(js_mod_str) = js_mod {
-            let mod_name_c_str = CStr::from_bytes_with_nul_unchecked(js_mod_str.as_bytes());
-            if exports_objects.contains(*js_mod_str) {
-              check_status_or_throw!(
-                env,
-                sys::napi_get_named_property(
-                  env,
-                  exports,
-                  mod_name_c_str.as_ptr(),
-                  &mut exports_js_mod,
-                ),
-                "Get mod {} from exports failed",
-                js_mod_str,
-              );
-            } else {
-              check_status_or_throw!(
-                env,
-                sys::napi_create_object(env, &mut exports_js_mod),
-                "Create export JavaScript Object [{}] failed",
-                js_mod_str
-              );
-              check_status_or_throw!(
-                env,
-                sys::napi_set_named_property(env, exports, mod_name_c_str.as_ptr(), exports_js_mod),
-                "Set exports Object [{}] into exports object failed",
-                js_mod_str
-              );
-              exports_objects.insert(js_mod_str.to_string());
+        if !in_module(*js_mod, js_name) {
+          continue;
+        }
+        let exported_object = match js_mod {
+          Some(js_mod_str) => {
+            match unsafe { get_or_create_js_mod(env, exports, js_mod_str, &mut exports_objects) } {
+              Ok(exported_object) => exported_object,
+              Err(e) => {
+                errors.push(e);
+                continue;
+              }
             }
           }
-          let (ctor, props): (Vec<_>, Vec<_>) = props.iter().partition(|prop| prop.is_ctor);
+          None => exports,
+        };
+        let (ctor, props): (Vec<_>, Vec<_>) = props.iter().partition(|prop| prop.is_ctor);
 
-          let ctor = ctor
-            .first()
-            .map(|c| c.raw().method.unwrap())
-            .unwrap_or(noop);
-          let raw_props: Vec<_> = props.iter().map(|prop| prop.raw()).collect();
+        let ctor = ctor
+          .first()
+          .map(|c| c.raw().method.unwrap())
+          .unwrap_or(noop);
+        let raw_props: Vec<_> = props.iter().map(|prop| prop.raw()).collect();
 
-          let js_class_name = CStr::from_bytes_with_nul_unchecked(js_name.as_bytes());
-          let mut class_ptr = ptr::null_mut();
+        let js_class_name = unsafe { CStr::from_bytes_with_nul_unchecked(js_name.as_bytes()) };
+        let mut class_ptr = ptr::null_mut();
 
-          check_status_or_throw!(
-            env,
+        if let Err(e) = check_status!(
+          unsafe {
             sys::napi_define_class(
               env,
               js_class_name.as_ptr(),
@@ -359,51 +803,63 @@ pub unsafe extern "C" fn napi_register_module_v1(
               raw_props.len(),
               raw_props.as_ptr(),
               &mut class_ptr,
-            ),
-            "Failed to register class `{}`",
-            &js_name,
-          );
+            )
+          },
+          "Failed to register class `{}`",
+          js_name.trim_end_matches('\0'),
+        ) {
+          errors.push(e);
+          continue;
+        }
 
-          let mut ctor_ref = ptr::null_mut();
-          sys::napi_create_reference(env, class_ptr, 1, &mut ctor_ref);
+        let mut ctor_ref = ptr::null_mut();
+        unsafe { sys::napi_create_reference(env, class_ptr, 1, &mut ctor_ref) };
 
-          registered_classes.insert(js_name.to_string(), ctor_ref);
+        registered_classes.insert(js_name.to_string(), ctor_ref);
 
-          check_status_or_throw!(
-            env,
-            sys::napi_set_named_property(
-              env,
-              if exports_js_mod.is_null() {
-                exports
-              } else {
-                exports_js_mod
-              },
-              js_class_name.as_ptr(),
-              class_ptr
-            ),
-            "Failed to register class `{}`",
-            &js_name,
-          );
+        if let Err(e) = check_status!(
+          unsafe {
+            sys::napi_set_named_property(env, exported_object, js_class_name.as_ptr(), class_ptr)
+          },
+          "Failed to register class `{}`",
+          js_name.trim_end_matches('\0'),
+        ) {
+          errors.push(e);
         }
       }
-    });
+    }
 
-    REGISTERED_CLASSES.borrow_mut(|map| {
-      map.insert(
-        std::thread::current().id(),
-        PersistedPerInstanceHashMap::from_hashmap(registered_classes),
-      )
+    REGISTERED_CLASSES.borrow_mut(|map| match map.get(&std::thread::current().id()) {
+      // the classes of the other modules registered in this env stay available
+      Some(classes) if module.is_some() => {
+        classes.borrow_mut(|classes| classes.extend(registered_classes))
+      }
+      _ => {
+        map.insert(
+          std::thread::current().id(),
+          PersistedPerInstanceHashMap::from_hashmap(registered_classes),
+        );
+      }
     });
-  });
+    Ok::<_, Error>(())
+  })?;
+  drop(module_members);
 
   #[cfg(feature = "compat-mode")]
-  {
+  if module.is_none() {
     let module_exports = MODULE_EXPORTS.read().expect("Read MODULE_EXPORTS failed");
-    module_exports.iter().for_each(|callback| unsafe {
-      if let Err(e) = callback(env, exports) {
-        JsError::from(e).throw_into(env);
+    for callback in module_exports.iter() {
+      let registered =
+        panic::catch_unwind(|| unsafe { callback(env, exports) }).unwrap_or_else(|panic| {
+          Err(Error::new(
+            Status::GenericFailure,
+            format!("panicked: {}", panic_message(&*panic)),
+          ))
+        });
+      if let Err(e) = registered {
+        errors.push(e);
       }
-    })
+    }
   }
 
   #[cfg(all(
@@ -427,9 +883,75 @@ pub unsafe extern "C" fn napi_register_module_v1(
     };
   }
   #[cfg(all(feature = "napi4", not(target_family = "wasm")))]
-  create_custom_gc(env);
-  FIRST_MODULE_REGISTERED.store(true, Ordering::SeqCst);
-  exports
+  create_custom_gc(env)?;
+  if let Some(claim) = first_module_claim {
+    claim.registered();
+  }
+  Ok(errors)
+}
+
+#[cfg(not(feature = "noop"))]
+/// Export the function dispatching the calls of `set` to its overloads
+unsafe fn register_overload_set(
+  env: sys::napi_env,
+  exports: sys::napi_value,
+  set: &'static OverloadSet,
+  exports_objects: &mut HashSet<String>,
+) -> Result<()> {
+  let exported_object = match set.js_mod {
+    Some(js_mod_str) => unsafe { get_or_create_js_mod(env, exports, js_mod_str, exports_objects)? },
+    None => exports,
+  };
+  let js_name = unsafe { CStr::from_bytes_with_nul_unchecked(set.name.as_bytes()) };
+  let export_name = set.name.trim_end_matches('\0');
+  let mut function = ptr::null_mut();
+  check_status!(
+    unsafe {
+      sys::napi_create_function(
+        env,
+        js_name.as_ptr(),
+        export_name.len(),
+        Some(call_overload),
+        set as *const OverloadSet as *mut std::ffi::c_void,
+        &mut function,
+      )
+    },
+    "Failed to create the overloads of `{}`",
+    export_name,
+  )?;
+  check_status!(
+    unsafe { sys::napi_set_named_property(env, exported_object, js_name.as_ptr(), function) },
+    "Failed to register export `{}`",
+    export_name,
+  )
+}
+
+#[doc(hidden)]
+#[cfg(not(feature = "noop"))]
+/// Called at load time by the `linked` form of [`module!`](crate::module).
+///
+/// Before Node.js is initialized, which is the case for addons linked statically into a custom
+/// Node.js build, the module is added to the linked bindings and can be loaded with
+/// `process._linkedBinding(name)`. When the addon is loaded by `require` it is registered like
+/// with the init symbol.
+///
+/// # Safety
+/// `name` must be nul-terminated.
+pub unsafe fn register_linked_module(name: &'static str, init: sys::napi_addon_register_func) {
+  #[cfg(any(target_env = "msvc", target_os = "android", feature = "dyn-symbols"))]
+  unsafe {
+    sys::setup();
+  }
+  let module = Box::leak(Box::new(sys::napi_module {
+    nm_version: 1,
+    nm_flags: 0,
+    nm_filename: name.as_ptr().cast(),
+    nm_register_func: init,
+    nm_modname: name.as_ptr().cast(),
+    nm_priv: ptr::null_mut(),
+    reserved: [ptr::null_mut(); 4],
+  }));
+  unsafe { sys::napi_module_register(module) };
 }
 
 #[cfg(not(feature = "noop"))]
@@ -451,11 +973,12 @@ pub(crate) unsafe extern "C" fn noop(
 }
 
 #[cfg(all(feature = "napi4", not(target_family = "wasm"), not(feature = "noop")))]
-fn create_custom_gc(env: sys::napi_env) {
-  if !FIRST_MODULE_REGISTERED.load(Ordering::SeqCst) {
+fn create_custom_gc(env: sys::napi_env) -> Result<()> {
+  // the first module is set up again when its registration failed, the function is only created
+  // once
+  if *first_module() != FirstModule::Registered && CUSTOM_GC_TSFN.load(Ordering::SeqCst).is_null() {
     let mut custom_gc_fn = ptr::null_mut();
-    check_status_or_throw!(
-      env,
+    check_status!(
       unsafe {
         sys::napi_create_function(
           env,
@@ -467,18 +990,16 @@ fn create_custom_gc(env: sys::napi_env) {
         )
       },
       "Create Custom GC Function in napi_register_module_v1 failed"
-    );
+    )?;
     let mut async_resource_name = ptr::null_mut();
-    check_status_or_throw!(
-      env,
+    check_status!(
       unsafe {
         sys::napi_create_string_utf8(env, "CustomGC".as_ptr().cast(), 8, &mut async_resource_name)
       },
       "Create async resource string in napi_register_module_v1"
-    );
+    )?;
     let mut custom_gc_tsfn = ptr::null_mut();
-    check_status_or_throw!(
-      env,
+    check_status!(
       unsafe {
         sys::napi_create_threadsafe_function(
           env,
@@ -495,19 +1016,17 @@ fn create_custom_gc(env: sys::napi_env) {
         )
       },
       "Create Custom GC ThreadsafeFunction in napi_register_module_v1 failed"
-    );
-    check_status_or_throw!(
-      env,
+    )?;
+    check_status!(
       unsafe { sys::napi_unref_threadsafe_function(env, custom_gc_tsfn) },
       "Unref Custom GC ThreadsafeFunction in napi_register_module_v1 failed"
-    );
+    )?;
     CUSTOM_GC_TSFN.store(custom_gc_tsfn, Ordering::Relaxed);
   }
 
   let current_thread_id = std::thread::current().id();
   THREADS_CAN_ACCESS_ENV.borrow_mut(|m| m.insert(current_thread_id, true));
-  check_status_or_throw!(
-    env,
+  check_status!(
     unsafe {
       sys::napi_add_env_cleanup_hook(
         env,
@@ -516,7 +1035,8 @@ fn create_custom_gc(env: sys::napi_env) {
       )
     },
     "Failed to add remove thread id cleanup hook"
-  );
+  )?;
+  Ok(())
 }
 
 #[cfg(all(feature = "napi4", not(target_family = "wasm"), not(feature = "noop")))]
@@ -556,7 +1076,7 @@ extern "C" fn custom_gc(
     return;
   }
   let mut ref_count = 0;
-  check_status_or_throw!(
+  crate::check_status_or_throw!(
     env,
     unsafe { sys::napi_reference_unref(env, data.cast(), &mut ref_count) },
     "Failed to unref Buffer reference in Custom GC"
@@ -565,7 +1085,7 @@ extern "C" fn custom_gc(
     ref_count == 0,
     "Buffer reference count in Custom GC is not 0"
   );
-  check_status_or_throw!(
+  crate::check_status_or_throw!(
     env,
     unsafe { sys::napi_delete_reference(env, data.cast()) },
     "Failed to delete Buffer reference in Custom GC"
@@ -0,0 +1,96 @@
+#[cfg(feature = "napi8")]
+use std::any::type_name;
+#[cfg(feature = "napi8")]
+use std::collections::hash_map::{DefaultHasher, RandomState};
+#[cfg(feature = "napi8")]
+use std::hash::{BuildHasher, Hash, Hasher};
+#[cfg(feature = "napi8")]
+use std::sync::OnceLock;
+
+#[cfg(feature = "napi8")]
+use crate::{check_status, Error, Status};
+use crate::{sys, Result};
+
+/// The tag of the objects wrapping a `T`, unique to this copy of the addon so the instances of
+/// another addon, or of another copy of this one, are never unwrapped as a `T`.
+#[cfg(feature = "napi8")]
+pub(crate) fn class_type_tag<T: ?Sized>() -> sys::napi_type_tag {
+  static SEED: OnceLock<u64> = OnceLock::new();
+  let seed = *SEED.get_or_init(|| RandomState::new().hash_one(0u8));
+  let mut hasher = DefaultHasher::new();
+  seed.hash(&mut hasher);
+  // `TypeId` needs `'static`, the classes can have lifetimes
+  type_name::<T>().hash(&mut hasher);
+  sys::napi_type_tag {
+    lower: hasher.finish(),
+    upper: seed,
+  }
+}
+
+/// Runtimes implementing a subset of Node-API may not have the type tag functions
+#[cfg(feature = "napi8")]
+fn type_tags_available() -> bool {
+  static AVAILABLE: OnceLock<bool> = OnceLock::new();
+  *AVAILABLE.get_or_init(|| {
+    sys::is_available("napi_type_tag_object") && sys::is_available("napi_check_object_type_tag")
+  })
+}
+
+#[doc(hidden)]
+/// Tag a new class instance wrapping a `T`, does nothing without the `napi8` feature
+///
+/// # Safety
+///
+/// `object` must be a valid object of `env`
+pub unsafe fn tag_class_instance<T: ?Sized>(
+  env: sys::napi_env,
+  object: sys::napi_value,
+) -> Result<()> {
+  #[cfg(feature = "napi8")]
+  if type_tags_available() {
+    check_status!(
+      unsafe { sys::napi_type_tag_object(env, object, &class_type_tag::<T>()) },
+      "Failed to tag the instance of `{}`",
+      type_name::<T>(),
+    )?;
+  }
+  #[cfg(not(feature = "napi8"))]
+  let _ = (env, object);
+  Ok(())
+}
+
+#[doc(hidden)]
+/// Check a value wraps a `T` of this addon before unwrapping it, does nothing without the `napi8`
+/// feature
+///
+/// # Safety
+///
+/// `value` must be a valid value of `env`
+pub unsafe fn check_class_instance<T: ?Sized>(
+  env: sys::napi_env,
+  value: sys::napi_value,
+) -> Result<()> {
+  #[cfg(feature = "napi8")]
+  if type_tags_available() {
+    let mut is_tagged = false;
+    check_status!(
+      unsafe {
+        sys::napi_check_object_type_tag(env, value, &class_type_tag::<T>(), &mut is_tagged)
+      },
+      "Failed to check the type tag of `{}`",
+      type_name::<T>(),
+    )?;
+    if !is_tagged {
+      return Err(Error::new(
+        Status::InvalidArg,
+        format!(
+          "Value is not an instance of `{}` created by this addon",
+          type_name::<T>()
+        ),
+      ));
+    }
+  }
+  #[cfg(not(feature = "napi8"))]
+  let _ = (env, value);
+  Ok(())
+}
@@ -0,0 +1,64 @@
+use std::cell::RefCell;
+use std::collections::HashSet;
+use std::ptr;
+
+use crate::{
+  bindgen_runtime::{ToNapiValue, Unknown},
+  check_status, sys, Env, JsObject, NapiRaw, NapiValue, Result, ValueType,
+};
+
+/// The code of the warnings emitted by the `#[napi(unstable)]` exports
+pub const UNSTABLE_WARNING_CODE: &str = "NAPI_RS_UNSTABLE";
+
+/// The `#[napi(unstable)]` exports already used in an env
+#[derive(Default)]
+struct UnstableWarnings(RefCell<HashSet<&'static str>>);
+
+#[doc(hidden)]
+/// Emit an `ExperimentalWarning` for `name` the first time it's used in `env`.
+///
+/// Nothing is emitted in the runtimes without `process.emitWarning`.
+///
+/// # Safety
+///
+/// `env` must be a valid env, on its own thread
+pub unsafe fn warn_unstable(env: sys::napi_env, name: &'static str) -> Result<()> {
+  let env = Env::from_raw(env);
+  let warnings = env.get_or_init_instance_data_typed(|| Ok(UnstableWarnings::default()))?;
+  if !warnings.0.borrow_mut().insert(name) {
+    return Ok(());
+  }
+  let global = env.get_global()?;
+  let process: Unknown = global.get_named_property_unchecked("process")?;
+  if process.get_type()? != ValueType::Object {
+    return Ok(());
+  }
+  let process = unsafe { JsObject::from_raw_unchecked(env.0, process.raw()) };
+  let emit_warning: Unknown = process.get_named_property_unchecked("emitWarning")?;
+  if emit_warning.get_type()? != ValueType::Function {
+    return Ok(());
+  }
+  let message = format!("`{name}` is an experimental API and might change at any time");
+  let mut options = env.create_object()?;
+  options.set_named_property("type", "ExperimentalWarning")?;
+  options.set_named_property("code", UNSTABLE_WARNING_CODE)?;
+  let args = [
+    unsafe { ToNapiValue::to_napi_value(env.0, message)? },
+    unsafe { options.raw() },
+  ];
+  let mut result = ptr::null_mut();
+  check_status!(
+    unsafe {
+      sys::napi_call_function(
+        env.0,
+        process.raw(),
+        emit_warning.raw(),
+        args.len(),
+        args.as_ptr(),
+        &mut result,
+      )
+    },
+    "Failed to emit the warning of `{}`",
+    name
+  )
+}
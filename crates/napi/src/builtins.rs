@@ -0,0 +1,179 @@
+use std::cell::Cell;
+use std::ptr;
+use std::rc::Rc;
+
+use crate::{
+  bindgen_runtime::{Buffer, FromNapiValue, PromiseRaw, ToNapiValue, Unknown},
+  check_status, sys, Env, Error, JsObject, NapiRaw, NapiValue, Result, Status, ValueType,
+};
+
+#[derive(Clone, Copy)]
+enum Builtin {
+  ArrayIsArray,
+  ObjectAssign,
+  PromiseResolve,
+  BufferFrom,
+}
+
+impl Builtin {
+  const COUNT: usize = 4;
+
+  /// The global holding the function, and the name of the function
+  fn path(self) -> (&'static str, &'static str) {
+    match self {
+      Builtin::ArrayIsArray => ("Array", "isArray"),
+      Builtin::ObjectAssign => ("Object", "assign"),
+      Builtin::PromiseResolve => ("Promise", "resolve"),
+      Builtin::BufferFrom => ("Buffer", "from"),
+    }
+  }
+}
+
+/// The references to the holder and to the function of each builtin, looked up on first use
+#[derive(Default)]
+struct BuiltinRefs([Cell<Option<(sys::napi_ref, sys::napi_ref)>>; Builtin::COUNT]);
+
+/// The functions of the JavaScript built-ins used on hot paths, looked up once per env, see
+/// [`Env::builtins`].
+///
+/// The functions are the ones found on first use in the env, replacing them from JavaScript later,
+/// like with a polyfill, has no effect.
+#[derive(Clone)]
+pub struct Builtins {
+  env: sys::napi_env,
+  refs: Rc<BuiltinRefs>,
+}
+
+impl Env {
+  /// The cached handles of `Array.isArray`, `Object.assign`, `Promise.resolve` and
+  /// `Buffer.from`, instead of looking them up from the global object by name on every call.
+  ///
+  /// ```rust,ignore
+  /// let builtins = env.builtins()?;
+  /// if builtins.is_array(&value)? {
+  ///   // ...
+  /// }
+  /// ```
+  pub fn builtins(&self) -> Result<Builtins> {
+    Ok(Builtins {
+      env: self.0,
+      refs: self.get_or_init_instance_data_typed(|| Ok(BuiltinRefs::default()))?,
+    })
+  }
+}
+
+impl Builtins {
+  /// `Array.isArray(value)`
+  pub fn is_array<V: NapiRaw>(&self, value: &V) -> Result<bool> {
+    let result = self.call(Builtin::ArrayIsArray, &[unsafe { value.raw() }])?;
+    unsafe { bool::from_napi_value(self.env, result) }
+  }
+
+  /// `Object.assign(target, source)`, copy the own enumerable properties of `source` to `target`
+  pub fn object_assign<T: NapiRaw, S: ToNapiValue>(&self, target: &T, source: S) -> Result<()> {
+    let source = unsafe { S::to_napi_value(self.env, source)? };
+    self.call(Builtin::ObjectAssign, &[unsafe { target.raw() }, source])?;
+    Ok(())
+  }
+
+  /// `Promise.resolve(value)`, `value` itself if it's a native Promise already, or a Promise
+  /// following it if it's a thenable
+  pub fn promise_resolve<T: FromNapiValue, V: ToNapiValue>(
+    &self,
+    value: V,
+  ) -> Result<PromiseRaw<T>> {
+    let value = unsafe { V::to_napi_value(self.env, value)? };
+    let promise = self.call(Builtin::PromiseResolve, &[value])?;
+    Ok(PromiseRaw::new(self.env, promise))
+  }
+
+  /// `Buffer.from(value)`, like from a string in UTF-8, an array of bytes or an `ArrayBuffer`.
+  ///
+  /// The global `Buffer` of Node.js is needed, a `GenericFailure` is returned in the runtimes
+  /// without it.
+  pub fn buffer_from<V: ToNapiValue>(&self, value: V) -> Result<Buffer> {
+    let value = unsafe { V::to_napi_value(self.env, value)? };
+    let buffer = self.call(Builtin::BufferFrom, &[value])?;
+    unsafe { Buffer::from_napi_value(self.env, buffer) }
+  }
+
+  /// `Promise.resolve(value)` for the internals, without converting the value
+  pub(crate) fn promise_resolve_raw(&self, value: sys::napi_value) -> Result<sys::napi_value> {
+    self.call(Builtin::PromiseResolve, &[value])
+  }
+
+  fn call(&self, builtin: Builtin, args: &[sys::napi_value]) -> Result<sys::napi_value> {
+    let (holder, function) = self.get(builtin)?;
+    let mut result = ptr::null_mut();
+    check_status!(
+      unsafe {
+        sys::napi_call_function(
+          self.env,
+          holder,
+          function,
+          args.len(),
+          args.as_ptr(),
+          &mut result,
+        )
+      },
+      "Failed to call `{}.{}`",
+      builtin.path().0,
+      builtin.path().1
+    )?;
+    Ok(result)
+  }
+
+  fn get(&self, builtin: Builtin) -> Result<(sys::napi_value, sys::napi_value)> {
+    let slot = &self.refs.0[builtin as usize];
+    let (holder_ref, function_ref) = match slot.get() {
+      Some(refs) => refs,
+      None => {
+        let refs = self.lookup(builtin)?;
+        slot.set(Some(refs));
+        refs
+      }
+    };
+    let mut holder = ptr::null_mut();
+    let mut function = ptr::null_mut();
+    check_status!(unsafe { sys::napi_get_reference_value(self.env, holder_ref, &mut holder) })?;
+    check_status!(unsafe { sys::napi_get_reference_value(self.env, function_ref, &mut function) })?;
+    Ok((holder, function))
+  }
+
+  fn lookup(&self, builtin: Builtin) -> Result<(sys::napi_ref, sys::napi_ref)> {
+    let (holder_name, function_name) = builtin.path();
+    let env = Env::from_raw(self.env);
+    let global = env.get_global()?;
+    let holder: Unknown = global.get_named_property_unchecked(holder_name)?;
+    let function = match holder.get_type()? {
+      ValueType::Object | ValueType::Function => {
+        let holder = unsafe { JsObject::from_raw_unchecked(self.env, holder.raw()) };
+        Some(holder.get_named_property_unchecked::<Unknown>(function_name)?)
+      }
+      _ => None,
+    };
+    let function = match function {
+      Some(function) if function.get_type()? == ValueType::Function => function,
+      _ => {
+        return Err(Error::new(
+          Status::GenericFailure,
+          format!("`{holder_name}.{function_name}` is not available"),
+        ))
+      }
+    };
+    let mut holder_ref = ptr::null_mut();
+    let mut function_ref = ptr::null_mut();
+    check_status!(
+      unsafe { sys::napi_create_reference(self.env, holder.raw(), 1, &mut holder_ref) },
+      "Failed to create a reference to `{}`",
+      holder_name
+    )?;
+    check_status!(
+      unsafe { sys::napi_create_reference(self.env, function.raw(), 1, &mut function_ref) },
+      "Failed to create a reference to `{}.{}`",
+      holder_name,
+      function_name
+    )?;
+    Ok((holder_ref, function_ref))
+  }
+}
@@ -1,8 +1,28 @@
+use std::any::{Any, TypeId};
+use std::cell::{Cell, OnceCell, RefCell};
+use std::collections::HashMap;
+use std::marker::PhantomData;
 use std::ptr;
+use std::rc::Rc;
 
 use crate::bindgen_runtime::{FromNapiValue, TypeName};
 use crate::check_status;
-use crate::{sys, Either, Env, Error, JsUndefined, NapiValue, Result, Status};
+use crate::{sys, Either, Env, Error, JsUndefined, NapiRaw, NapiValue, Result, Status};
+
+// The conversions only get the env, so the storages are found from there through the thread. A call
+// only bumps the depth, its storage is registered when it's first used.
+thread_local! {
+  /// The depth of the `#[js_function]` calls running on this thread
+  static CALL_DEPTH: Cell<usize> = const { Cell::new(0) };
+  /// The storages of the calls running on this thread which use one, innermost last
+  static CALL_STORAGES: RefCell<Vec<StorageFrame>> = const { RefCell::new(Vec::new()) };
+}
+
+struct StorageFrame {
+  env: usize,
+  depth: usize,
+  storage: Rc<CallStorage>,
+}
 
 /// Function call context
 pub struct CallContext<'env> {
@@ -10,8 +30,9 @@ pub struct CallContext<'env> {
   raw_this: sys::napi_value,
   callback_info: sys::napi_callback_info,
   args: &'env [sys::napi_value],
-  /// arguments.length
+  /// arguments.length, see [`CallContext::arguments`] for the arguments over the declared arity
   pub length: usize,
+  storage: OnceCell<StorageGuard>,
 }
 
 impl<'env> CallContext<'env> {
@@ -43,9 +64,18 @@ impl<'env> CallContext<'env> {
       callback_info,
       args,
       length,
+      storage: OnceCell::new(),
     }
   }
 
+  /// The values shared by everything running for this call, see [`CallStorage`]
+  pub fn storage(&self) -> &CallStorage {
+    &self
+      .storage
+      .get_or_init(|| StorageGuard::enter(self.env.0))
+      .0
+  }
+
   pub fn get<ArgType: FromNapiValue>(&self, index: usize) -> Result<ArgType> {
     if index >= self.arg_len() {
       Err(Error::new(
@@ -82,6 +112,70 @@ impl<'env> CallContext<'env> {
       .collect()
   }
 
+  /// The number of arguments declared by `#[js_function(arg_len)]`, [`CallContext::length`] is
+  /// the number of arguments of this call, which can be more or less.
+  ///
+  /// Node-API has no way to get the function being called, a wrapper which needs it should keep
+  /// it itself, like in a property of `this` or in a closure.
+  pub fn arity(&self) -> usize {
+    self.arg_len()
+  }
+
+  /// All the arguments of this call, including the ones over the declared `arg_len`
+  pub fn arguments(&self) -> Result<Vec<crate::JsUnknown>> {
+    Ok(
+      self
+        .raw_arguments()?
+        .into_iter()
+        .map(|raw| unsafe { crate::JsUnknown::from_raw_unchecked(self.env.0, raw) })
+        .collect(),
+    )
+  }
+
+  /// Call `function` with the `this` and all the arguments of this call, like
+  /// `function.apply(this, arguments)`, for wrappers passing the calls through.
+  pub fn forward_call<F: NapiRaw>(&self, function: &F) -> Result<crate::JsUnknown> {
+    let args = self.raw_arguments()?;
+    let mut result = ptr::null_mut();
+    check_status!(
+      unsafe {
+        sys::napi_call_function(
+          self.env.0,
+          self.raw_this,
+          function.raw(),
+          args.len(),
+          args.as_ptr(),
+          &mut result,
+        )
+      },
+      "Failed to forward the call"
+    )?;
+    Ok(unsafe { crate::JsUnknown::from_raw_unchecked(self.env.0, result) })
+  }
+
+  fn raw_arguments(&self) -> Result<Vec<sys::napi_value>> {
+    if self.length <= self.arg_len() {
+      return Ok(self.args[..self.length].to_vec());
+    }
+    let mut argc = self.length;
+    let mut args = vec![ptr::null_mut(); argc];
+    check_status!(
+      unsafe {
+        sys::napi_get_cb_info(
+          self.env.0,
+          self.callback_info,
+          &mut argc,
+          args.as_mut_ptr(),
+          ptr::null_mut(),
+          ptr::null_mut(),
+        )
+      },
+      "Failed to get the arguments"
+    )?;
+    args.truncate(argc);
+    Ok(args)
+  }
+
   pub fn get_new_target<V>(&self) -> Result<V>
   where
     V: NapiValue,
@@ -99,3 +193,157 @@ impl<'env> CallContext<'env> {
     unsafe { T::from_raw_unchecked(self.env.0, self.raw_this) }
   }
 }
+
+/// Typed values shared by the conversions and callbacks running for one call of a
+/// `#[js_function]`, one value per type like [`Env::set_instance_data_typed`].
+///
+/// Set it up front with [`CallContext::storage`], and read it from deeper in the call with
+/// [`Env::call_storage`], which only needs the env a `FromNapiValue` conversion or a callback
+/// gets:
+///
+/// ```rust,ignore
+/// struct ParseOptions { strict: bool }
+///
+/// impl FromNapiValue for Node {
+///   unsafe fn from_napi_value(env: sys::napi_env, value: sys::napi_value) -> Result<Self> {
+///     let strict = Env::from_raw(env)
+///       .call_storage()
+///       .and_then(|storage| storage.get::<ParseOptions>())
+///       .is_some_and(|options| options.strict);
+///     // ...
+///   }
+/// }
+///
+/// #[js_function(2)]
+/// fn parse(ctx: CallContext) -> Result<JsUnknown> {
+///   ctx.storage().insert(ParseOptions { strict: ctx.get(1)? });
+///   let node: Node = ctx.get(0)?;
+///   // ...
+/// }
+/// ```
+///
+/// The values are dropped when the call returns. A `#[js_function]` called from JavaScript
+/// during the call, through a callback for example, has its own storage.
+#[derive(Default)]
+pub struct CallStorage {
+  values: RefCell<HashMap<TypeId, Rc<dyn Any>>>,
+}
+
+impl CallStorage {
+  /// Store `value` in the slot of `T`, returns the previous one
+  pub fn insert<T: 'static>(&self, value: T) -> Option<Rc<T>> {
+    self
+      .values
+      .borrow_mut()
+      .insert(TypeId::of::<T>(), Rc::new(value))
+      .map(downcast)
+  }
+
+  pub fn get<T: 'static>(&self) -> Option<Rc<T>> {
+    self
+      .values
+      .borrow()
+      .get(&TypeId::of::<T>())
+      .cloned()
+      .map(downcast)
+  }
+
+  /// The value of `T`, or the one returned by `init` if there is none yet
+  pub fn get_or_insert_with<T: 'static>(&self, init: impl FnOnce() -> T) -> Rc<T> {
+    if let Some(value) = self.get::<T>() {
+      return value;
+    }
+    // `init` may use the storage too, don't hold it while calling it
+    let value = Rc::new(init());
+    self
+      .values
+      .borrow_mut()
+      .insert(TypeId::of::<T>(), value.clone());
+    value
+  }
+
+  pub fn remove<T: 'static>(&self) -> Option<Rc<T>> {
+    self
+      .values
+      .borrow_mut()
+      .remove(&TypeId::of::<T>())
+      .map(downcast)
+  }
+}
+
+fn downcast<T: 'static>(value: Rc<dyn Any>) -> Rc<T> {
+  value
+    .downcast()
+    .unwrap_or_else(|_| unreachable!("The call storage is keyed by TypeId"))
+}
+
+impl Env {
+  /// The storage of the innermost `#[js_function]` call running on this env, `None` if the call
+  /// didn't set it up with [`CallContext::storage`], see [`CallStorage`]
+  pub fn call_storage(&self) -> Option<Rc<CallStorage>> {
+    let env = self.0 as usize;
+    let depth = CALL_DEPTH.with(Cell::get);
+    CALL_STORAGES.with(|storages| {
+      storages
+        .borrow()
+        .iter()
+        .rev()
+        .find(|frame| frame.env == env && frame.depth == depth)
+        .map(|frame| frame.storage.clone())
+    })
+  }
+}
+
+/// The storage of a call, registered until the call returns
+struct StorageGuard(Rc<CallStorage>);
+
+impl StorageGuard {
+  fn enter(env: sys::napi_env) -> Self {
+    let storage = Rc::new(CallStorage::default());
+    CALL_STORAGES.with(|storages| {
+      storages.borrow_mut().push(StorageFrame {
+        env: env as usize,
+        depth: CALL_DEPTH.with(Cell::get),
+        storage: storage.clone(),
+      })
+    });
+    Self(storage)
+  }
+}
+
+impl Drop for StorageGuard {
+  fn drop(&mut self) {
+    let frame = CALL_STORAGES.try_with(|storages| {
+      let mut storages = storages.borrow_mut();
+      let index = storages
+        .iter()
+        .rposition(|frame| Rc::ptr_eq(&frame.storage, &self.0))?;
+      Some(storages.remove(index))
+    });
+    // the values may use the storage when they are dropped
+    drop(frame);
+  }
+}
+
+/// Counts the `#[js_function]` calls for the duration of the call, so a call nested in another one
+/// doesn't see its storage
+#[doc(hidden)]
+pub struct CallFrame {
+  _not_send: PhantomData<*const ()>,
+}
+
+impl CallFrame {
+  #[doc(hidden)]
+  pub fn enter() -> Self {
+    CALL_DEPTH.with(|depth| depth.set(depth.get() + 1));
+    Self {
+      _not_send: PhantomData,
+    }
+  }
+}
+
+impl Drop for CallFrame {
+  fn drop(&mut self) {
+    let _ = CALL_DEPTH.try_with(|depth| depth.set(depth.get() - 1));
+  }
+}
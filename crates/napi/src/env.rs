@@ -1,13 +1,19 @@
 #![allow(deprecated)]
 
+use std::alloc::{self, Layout};
 use std::any::{type_name, TypeId};
+use std::cell::{Cell, RefCell};
+use std::collections::HashSet;
 use std::convert::TryInto;
-use std::ffi::CString;
+use std::ffi::{CStr, CString};
 #[cfg(all(feature = "tokio_rt", feature = "napi4"))]
 use std::future::Future;
 use std::mem;
+use std::ops::Deref;
 use std::os::raw::{c_char, c_void};
 use std::ptr;
+#[cfg(feature = "napi5")]
+use std::time::SystemTime;
 
 #[cfg(feature = "serde-json")]
 use serde::de::DeserializeOwned;
@@ -16,27 +22,32 @@ use serde::Serialize;
 
 #[cfg(feature = "napi8")]
 use crate::async_cleanup_hook::AsyncCleanupHook;
-#[cfg(feature = "napi5")]
-use crate::bindgen_runtime::FunctionCallContext;
 #[cfg(all(feature = "tokio_rt", feature = "napi4"))]
 use crate::bindgen_runtime::PromiseRaw;
-#[cfg(feature = "napi4")]
-use crate::bindgen_runtime::ToNapiValue;
-use crate::bindgen_runtime::{FromNapiValue, Function, JsValuesTupleIntoVec, Unknown};
+use crate::bindgen_runtime::{
+  create_static_buffer, get_global_constructor, ArgsBuffer, FromNapiValue, Function,
+  FunctionCallContext, JsValuesTupleIntoVec, ToNapiValue, TypeName, Unknown,
+};
+#[cfg(feature = "pod")]
+use crate::bindgen_runtime::{BufferSlice, Pod};
 #[cfg(feature = "napi3")]
 use crate::cleanup_env::{CleanupEnvHook, CleanupEnvHookData};
+#[cfg(feature = "napi6")]
+use crate::instance_data::{InstanceData, UntypedInstanceData};
 #[cfg(feature = "serde-json")]
 use crate::js_values::{De, Ser};
 #[cfg(feature = "napi4")]
 use crate::threadsafe_function::{ThreadsafeCallContext, ThreadsafeFunction};
-#[cfg(feature = "napi3")]
 use crate::JsError;
+#[cfg(feature = "napi8")]
+use crate::TypeTag;
 use crate::{
+  async_context::AsyncContext,
   async_work::{self, AsyncWorkPromise},
-  check_status,
+  check_pending_exception, check_status,
   js_values::*,
   sys,
-  task::Task,
+  task::{BlockingTask, Task},
   Error, ExtendedErrorInfo, NodeVersion, Result, Status, ValueType,
 };
 
@@ -56,6 +67,11 @@ pub(crate) static EMPTY_VEC: Vec<u8> = vec![];
 /// Notification of this event is delivered through the callbacks given to `Env::add_env_cleanup_hook` and `Env::set_instance_data`.
 pub struct Env(pub(crate) sys::napi_env);
 
+thread_local! {
+  /// The `napi_get_version` of the env running on this thread
+  static NAPI_VERSION: Cell<Option<(sys::napi_env, u32)>> = const { Cell::new(None) };
+}
+
 impl From<sys::napi_env> for Env {
   fn from(env: sys::napi_env) -> Self {
     Env(env)
@@ -237,6 +253,65 @@ impl Env {
     Ok(unsafe { JsSymbol::from_raw_unchecked(self.0, result) })
   }
 
+  /// Get a symbol built in JavaScript, like `Symbol.toStringTag`, to read or define the protocols
+  /// of objects
+  pub fn get_well_known_symbol(&self, symbol: WellKnownSymbol) -> Result<JsSymbol> {
+    let global = self.get_global()?;
+    let symbol_constructor: JsObject = global.get_named_property_unchecked("Symbol")?;
+    symbol_constructor.get_named_property(symbol.name())
+  }
+
+  /// Get the symbol registered for `description` in the global symbol registry, like
+  /// `Symbol.for(description)` in JavaScript.
+  ///
+  /// Uses `node_api_symbol_for` with the `napi9` feature when the runtime has it, and calls
+  /// `Symbol.for` otherwise.
+  pub fn create_symbol_for(&self, description: &str) -> Result<JsSymbol> {
+    let mut result = ptr::null_mut();
+    #[cfg(feature = "napi9")]
+    {
+      if sys::is_available("node_api_symbol_for") {
+        check_status!(
+          unsafe {
+            sys::node_api_symbol_for(
+              self.0,
+              description.as_ptr().cast(),
+              description.len(),
+              &mut result,
+            )
+          },
+          "Failed to call node_api_symbol_for"
+        )?;
+        return Ok(unsafe { JsSymbol::from_raw_unchecked(self.0, result) });
+      }
+    }
+    let mut global = ptr::null_mut();
+    check_status!(unsafe { sys::napi_get_global(self.0, &mut global) })?;
+    let mut symbol = ptr::null_mut();
+    check_status!(unsafe {
+      sys::napi_get_named_property(self.0, global, c"Symbol".as_ptr(), &mut symbol)
+    })?;
+    let mut symbol_for = ptr::null_mut();
+    check_status!(unsafe {
+      sys::napi_get_named_property(self.0, symbol, c"for".as_ptr(), &mut symbol_for)
+    })?;
+    let description = self.create_string(description)?;
+    check_status!(
+      unsafe {
+        sys::napi_call_function(
+          self.0,
+          symbol,
+          symbol_for,
+          1,
+          &description.0.value,
+          &mut result,
+        )
+      },
+      "Failed to call Symbol.for"
+    )?;
+    Ok(unsafe { JsSymbol::from_raw_unchecked(self.0, result) })
+  }
+
   pub fn create_object(&self) -> Result<JsObject> {
     let mut raw_value = ptr::null_mut();
     check_status!(unsafe { sys::napi_create_object(self.0, &mut raw_value) })?;
@@ -396,22 +471,42 @@ impl Env {
     ))
   }
 
-  #[cfg(not(target_family = "wasm"))]
+  /// Create a `Buffer` of static data, like the assets embedded by `include_bytes!`, without
+  /// copying it and without a finalizer.
+  ///
+  /// The data is copied if the JavaScript runtime doesn't support external buffers.
+  ///
+  /// # Safety
+  ///
+  /// JavaScript must never write to the returned buffer, the static data is usually in read-only
+  /// memory and writing to it crashes the process. Don't hand the buffer to code which may mutate
+  /// it, use [`Env::create_buffer_copy`] instead.
+  pub unsafe fn create_buffer_with_static_data(&self, data: &'static [u8]) -> Result<JsBuffer> {
+    let mut raw_value = ptr::null_mut();
+    check_status!(
+      if data.is_empty() {
+        unsafe { sys::napi_create_buffer(self.0, 0, ptr::null_mut(), &mut raw_value) }
+      } else {
+        unsafe { create_static_buffer(self.0, data, &mut raw_value) }
+      },
+      "Failed to create a Buffer of static data"
+    )?;
+    Ok(JsBuffer(Value {
+      env: self.0,
+      value: raw_value,
+      value_type: ValueType::Object,
+    }))
+  }
+
   /// This function gives V8 an indication of the amount of externally allocated memory that is kept alive by JavaScript objects (i.e. a JavaScript object that points to its own memory allocated by a native module).
   ///
   /// Registering externally allocated memory will trigger global garbage collections more often than it would otherwise.
   ///
+  /// The change is counted in the [`MemoryUsage::addon_external`](crate::MemoryUsage::addon_external) of [`Env::memory_usage`]. It's a no-op returning `0` on WebAssembly.
+  ///
   /// ***ATTENTION ⚠️***, do not use this with `create_buffer_with_data/create_arraybuffer_with_data`, since these two functions already called the `adjust_external_memory` internal.
   pub fn adjust_external_memory(&self, size: i64) -> Result<i64> {
-    let mut changed = 0i64;
-    check_status!(unsafe { sys::napi_adjust_external_memory(self.0, size, &mut changed) })?;
-    Ok(changed)
-  }
-
-  #[cfg(target_family = "wasm")]
-  #[allow(unused_variables)]
-  pub fn adjust_external_memory(&self, size: i64) -> Result<i64> {
-    Ok(0)
+    unsafe { crate::memory::adjust_external_memory(self.0, size) }
   }
 
   #[deprecated(since = "3.0.0", note = "Use `BufferSlice::copy_from` instead")]
@@ -459,6 +554,60 @@ impl Env {
     ))
   }
 
+  /// Create a zeroed `ArrayBuffer` of `length` bytes whose data is aligned to `align`, e.g. for SIMD
+  /// loads, `align` must be a power of two.
+  ///
+  /// The data is allocated by Rust and freed when the `ArrayBuffer` is garbage collected, so it
+  /// fails in the runtimes which don't allow external buffers.
+  pub fn create_arraybuffer_aligned(
+    &self,
+    length: usize,
+    align: usize,
+  ) -> Result<JsArrayBufferValue> {
+    let layout = Layout::from_size_align(length, align).map_err(|err| {
+      Error::new(
+        Status::InvalidArg,
+        format!("Invalid ArrayBuffer layout of {length} bytes aligned to {align}: {err}"),
+      )
+    })?;
+    if length == 0 {
+      return self.create_arraybuffer(0);
+    }
+    let data_ptr = unsafe { alloc::alloc_zeroed(layout) };
+    if data_ptr.is_null() {
+      alloc::handle_alloc_error(layout);
+    }
+    let hint_ptr = Box::into_raw(Box::new(layout));
+    let mut raw_value = ptr::null_mut();
+    let status = unsafe {
+      sys::napi_create_external_arraybuffer(
+        self.0,
+        data_ptr.cast(),
+        length,
+        Some(drop_aligned_buffer),
+        hint_ptr.cast(),
+        &mut raw_value,
+      )
+    };
+    if status != sys::Status::napi_ok {
+      unsafe {
+        drop(Box::from_raw(hint_ptr));
+        alloc::dealloc(data_ptr, layout);
+      }
+    }
+    check_status!(
+      status,
+      "Failed to create the ArrayBuffer of {} bytes aligned to {}",
+      length,
+      align
+    )?;
+    Ok(JsArrayBufferValue::new(
+      unsafe { JsArrayBuffer::from_raw_unchecked(self.0, raw_value) },
+      data_ptr.cast(),
+      length,
+    ))
+  }
+
   pub fn create_arraybuffer_with_data(&self, mut data: Vec<u8>) -> Result<JsArrayBufferValue> {
     let length = data.len();
     let mut raw_value = ptr::null_mut();
@@ -577,6 +726,261 @@ impl Env {
     ))
   }
 
+  /// Create an `ArrayBuffer` over the bytes owned by `guard` without copying them, like a
+  /// memory-mapped file behind an `Arc`.
+  ///
+  /// The guard is kept alive by the finalizer of the `ArrayBuffer` and dropped when it's garbage
+  /// collected, so the bytes stay valid exactly as long as JavaScript can reach them. Other clones
+  /// of an `Arc` guard can keep reading them meanwhile.
+  ///
+  /// The bytes are copied and the guard dropped right away if the JavaScript runtime doesn't
+  /// support external buffers.
+  ///
+  /// # Notes
+  ///
+  /// JavaScript can write to the returned `ArrayBuffer`, it must not if the bytes are read-only,
+  /// like a read-only mapping, or read by Rust at the same time.
+  pub fn create_arraybuffer_with_guard<G>(&self, guard: G) -> Result<JsArrayBufferValue>
+  where
+    G: 'static + Deref,
+    G::Target: AsRef<[u8]>,
+  {
+    // boxed first, so the bytes of a guard owning them inline don't move after this
+    let guard = Box::new(guard);
+    let bytes = (**guard).as_ref();
+    let (data_ptr, length) = (bytes.as_ptr().cast_mut(), bytes.len());
+    let mut raw_value = ptr::null_mut();
+    if length == 0 {
+      // the data pointer of an empty slice is dangling, and may be shared by other buffers
+      check_status!(unsafe {
+        sys::napi_create_arraybuffer(self.0, 0, ptr::null_mut(), &mut raw_value)
+      })?;
+      return Ok(JsArrayBufferValue::new(
+        unsafe { JsArrayBuffer::from_raw_unchecked(self.0, raw_value) },
+        ptr::null_mut(),
+        0,
+      ));
+    }
+    let guard = Box::into_raw(guard);
+    let status = unsafe {
+      sys::napi_create_external_arraybuffer(
+        self.0,
+        data_ptr.cast(),
+        length,
+        Some(drop_guard::<G>),
+        guard.cast(),
+        &mut raw_value,
+      )
+    };
+    let data_ptr = if status == sys::Status::napi_no_external_buffers_allowed {
+      let guard = unsafe { Box::from_raw(guard) };
+      let mut underlying_data = ptr::null_mut();
+      check_status!(
+        unsafe {
+          sys::napi_create_arraybuffer(self.0, length, &mut underlying_data, &mut raw_value)
+        },
+        "Failed to create the ArrayBuffer of {} bytes",
+        length
+      )?;
+      unsafe { ptr::copy_nonoverlapping(data_ptr, underlying_data.cast(), length) };
+      drop(guard);
+      underlying_data
+    } else {
+      if status != sys::Status::napi_ok {
+        drop(unsafe { Box::from_raw(guard) });
+      }
+      check_status!(
+        status,
+        "Failed to create the external ArrayBuffer of {} bytes",
+        length
+      )?;
+      data_ptr.cast()
+    };
+    Ok(JsArrayBufferValue::new(
+      unsafe { JsArrayBuffer::from_raw_unchecked(self.0, raw_value) },
+      data_ptr,
+      length,
+    ))
+  }
+
+  /// Create a `DataView` over the `byte_length` bytes of `arraybuffer` from `byte_offset`, sharing
+  /// them with the `ArrayBuffer`
+  pub fn create_dataview(
+    &self,
+    arraybuffer: &JsArrayBuffer,
+    byte_offset: usize,
+    byte_length: usize,
+  ) -> Result<JsDataView> {
+    let mut arraybuffer_length = 0;
+    check_status!(unsafe {
+      sys::napi_get_arraybuffer_info(
+        self.0,
+        arraybuffer.0.value,
+        ptr::null_mut(),
+        &mut arraybuffer_length,
+      )
+    })?;
+    // checked here, Node.js would throw a RangeError
+    if byte_offset
+      .checked_add(byte_length)
+      .map_or(true, |end| end > arraybuffer_length)
+    {
+      return Err(Error::new(
+        Status::InvalidArg,
+        format!(
+          "The range {byte_offset}..{} is outside the ArrayBuffer of {arraybuffer_length} bytes",
+          byte_offset.saturating_add(byte_length)
+        ),
+      ));
+    }
+    let mut raw_value = ptr::null_mut();
+    check_status!(
+      unsafe {
+        sys::napi_create_dataview(
+          self.0,
+          byte_length,
+          arraybuffer.0.value,
+          byte_offset,
+          &mut raw_value,
+        )
+      },
+      "Failed to create the DataView"
+    )?;
+    Ok(unsafe { JsDataView::from_raw_unchecked(self.0, raw_value) })
+  }
+
+  /// Create a `TypedArray` of the kind matching `T`, like a `Float32Array` for `Vec<f32>`, over an
+  /// external `ArrayBuffer` taking the ownership of `data` without copying it.
+  ///
+  /// `data` is dropped when the `ArrayBuffer` is garbage collected. It's copied in the runtimes not
+  /// supporting external buffers.
+  pub fn create_external_typed_array<T: TypedArrayElement>(
+    &self,
+    mut data: Vec<T>,
+  ) -> Result<JsTypedArray> {
+    let length = data.len();
+    let byte_length = mem::size_of_val(data.as_slice());
+    let data_ptr = data.as_mut_ptr();
+    let mut arraybuffer = ptr::null_mut();
+    check_status!(
+      unsafe {
+        if byte_length == 0 {
+          // Rust uses a dangling pointer for empty `Vec`s,
+          // but NAPI/V8 only allows multiple buffers to have
+          // the same data pointer if it's 0x0.
+          sys::napi_create_arraybuffer(self.0, 0, ptr::null_mut(), &mut arraybuffer)
+        } else {
+          let hint_ptr = Box::into_raw(Box::new((length, data.capacity())));
+          let status = sys::napi_create_external_arraybuffer(
+            self.0,
+            data_ptr.cast(),
+            byte_length,
+            Some(drop_typed_array_data::<T>),
+            hint_ptr.cast(),
+            &mut arraybuffer,
+          );
+          if status == sys::Status::napi_no_external_buffers_allowed {
+            drop(Box::from_raw(hint_ptr));
+            let mut underlying_data = ptr::null_mut();
+            let status = sys::napi_create_arraybuffer(
+              self.0,
+              byte_length,
+              &mut underlying_data,
+              &mut arraybuffer,
+            );
+            if status == sys::Status::napi_ok {
+              ptr::copy_nonoverlapping(data_ptr.cast::<u8>(), underlying_data.cast(), byte_length);
+            }
+            status
+          } else {
+            if status == sys::Status::napi_ok {
+              mem::forget(data);
+            } else {
+              drop(Box::from_raw(hint_ptr));
+            }
+            status
+          }
+        }
+      },
+      "Failed to create the ArrayBuffer of the external TypedArray"
+    )?;
+    let mut typed_array = ptr::null_mut();
+    check_status!(
+      unsafe {
+        sys::napi_create_typedarray(
+          self.0,
+          T::TYPED_ARRAY_TYPE.into(),
+          length,
+          arraybuffer,
+          0,
+          &mut typed_array,
+        )
+      },
+      "Failed to create the external {}Array",
+      T::TYPED_ARRAY_TYPE.as_ref()
+    )?;
+    Ok(unsafe { JsTypedArray::from_raw_unchecked(self.0, typed_array) })
+  }
+
+  /// Create a `Buffer` over the bytes of `data` without copying them, for the slices of vertices,
+  /// samples or any other [`Pod`] type.
+  ///
+  /// `data` is dropped once the `Buffer` is garbage collected, or right away if the runtime
+  /// doesn't support external buffers and the bytes are copied.
+  ///
+  /// Use [`create_external_typed_array`](Env::create_external_typed_array) to get a `TypedArray`
+  /// of the elements instead, and [`BufferSlice::as_slice_of`] to read a `Buffer` as a `&[T]`.
+  #[cfg(feature = "pod")]
+  pub fn create_buffer_slice_of<T: Pod>(&self, data: Vec<T>) -> Result<BufferSlice<'_>> {
+    let byte_length = mem::size_of_val(data.as_slice());
+    if byte_length == 0 {
+      // `Vec`s without bytes have dangling pointers, see `create_buffer_with_data`
+      return BufferSlice::copy_from(self, []);
+    }
+    let mut data = mem::ManuallyDrop::new(data);
+    let data_ptr = data.as_mut_ptr().cast::<u8>();
+    let hint_ptr = Box::into_raw(Box::new((data.len(), data.capacity())));
+    let mut buf = ptr::null_mut();
+    let mut buf_data = data_ptr;
+    let status = unsafe {
+      sys::napi_create_external_buffer(
+        self.0,
+        byte_length,
+        data_ptr.cast(),
+        Some(drop_typed_array_data::<T>),
+        hint_ptr.cast(),
+        &mut buf,
+      )
+    };
+    let status = if status == sys::Status::napi_ok {
+      status
+    } else {
+      drop(unsafe { Box::from_raw(hint_ptr) });
+      let status = if status == sys::Status::napi_no_external_buffers_allowed {
+        let mut copied = ptr::null_mut();
+        let status = unsafe {
+          sys::napi_create_buffer_copy(self.0, byte_length, data_ptr.cast(), &mut copied, &mut buf)
+        };
+        buf_data = copied.cast();
+        status
+      } else {
+        status
+      };
+      drop(mem::ManuallyDrop::into_inner(data));
+      status
+    };
+    check_status!(
+      status,
+      "Failed to create a Buffer of `{}` elements",
+      type_name::<T>()
+    )?;
+    Ok(BufferSlice {
+      inner: unsafe { std::slice::from_raw_parts_mut(buf_data, byte_length) },
+      raw_value: buf,
+      env: self.0,
+    })
+  }
+
   /// This API allows an add-on author to create a function object in native code.
   ///
   /// This is the primary mechanism to allow calling into the add-on's native code from JavaScript.
@@ -605,7 +1009,11 @@ impl Env {
     unsafe { Function::<Args, Return>::from_napi_value(self.0, raw_result) }
   }
 
-  #[cfg(feature = "napi5")]
+  /// Create a function calling `callback`, which can capture state unlike the `extern "C"`
+  /// callback of [`Env::create_function`].
+  ///
+  /// `callback` gets the arguments and `this` from the [`FunctionCallContext`], it's dropped when
+  /// the function is garbage collected.
   pub fn create_function_from_closure<Args: JsValuesTupleIntoVec, Return, F>(
     &self,
     name: &str,
@@ -619,7 +1027,7 @@ impl Env {
 
     let mut raw_result = ptr::null_mut();
     let len = name.len();
-    check_status!(unsafe {
+    let status = unsafe {
       sys::napi_create_function(
         self.0,
         name.as_ptr().cast(),
@@ -628,7 +1036,11 @@ impl Env {
         closure_data_ptr.cast(), // We let it borrow the data here
         &mut raw_result,
       )
-    })?;
+    };
+    if status != sys::Status::napi_ok {
+      drop(unsafe { Box::from_raw(closure_data_ptr) });
+      check_status!(status, "Failed to create function `{}`", name)?;
+    }
 
     // Note: based on N-API docs, at this point, we have created an effective
     // `&'static dyn Fn…` in Rust parlance, in that thanks to `Box::into_raw()`
@@ -638,6 +1050,7 @@ impl Env {
     // running the `drop(Box::from_raw(…))` cleanup code.
     //
     // To solve that, according to the docs, we need to attach a finalizer:
+    #[cfg(feature = "napi5")]
     check_status!(unsafe {
       sys::napi_add_finalizer(
         self.0,
@@ -648,6 +1061,19 @@ impl Env {
         ptr::null_mut(),
       )
     })?;
+    // `napi_add_finalizer` is not available before N-API 5, wrapping the function works the same as
+    // nothing else wraps it
+    #[cfg(not(feature = "napi5"))]
+    check_status!(unsafe {
+      sys::napi_wrap(
+        self.0,
+        raw_result,
+        closure_data_ptr.cast(),
+        Some(finalize_box_trampoline::<F>),
+        ptr::null_mut(),
+        ptr::null_mut(),
+      )
+    })?;
 
     unsafe { Function::from_napi_value(self.0, raw_result) }
   }
@@ -665,85 +1091,117 @@ impl Env {
     unsafe { ptr::read(raw_extended_error) }.try_into()
   }
 
-  /// Throw any JavaScript value
-  pub fn throw<T: NapiRaw>(&self, value: T) -> Result<()> {
-    check_status!(unsafe { sys::napi_throw(self.0, value.raw()) })
+  /// Whether a JavaScript exception is pending, like after a call into JavaScript failed with
+  /// `Status::PendingException`.
+  ///
+  /// This API can be called even if there is a pending JavaScript exception.
+  pub fn is_exception_pending(&self) -> Result<bool> {
+    let mut is_pending = false;
+    check_status!(unsafe { sys::napi_is_exception_pending(self.0, &mut is_pending) })?;
+    Ok(is_pending)
+  }
+
+  /// Get and clear the pending JavaScript exception, `None` if there is none.
+  ///
+  /// Once cleared, the exception is not thrown to the caller of the native function, so the
+  /// native code can recover from it and keep calling into JavaScript.
+  ///
+  /// This API can be called even if there is a pending JavaScript exception.
+  pub fn get_and_clear_last_exception(&self) -> Result<Option<JsUnknown>> {
+    if !self.is_exception_pending()? {
+      return Ok(None);
+    }
+    let mut exception = ptr::null_mut();
+    check_status!(unsafe { sys::napi_get_and_clear_last_exception(self.0, &mut exception) })?;
+    Ok(Some(unsafe {
+      JsUnknown::from_raw_unchecked(self.0, exception)
+    }))
+  }
+
+  /// Throw any JavaScript value, like an instance of a custom error class.
+  ///
+  /// Return `Status::PendingException` from the native function after that, the thrown value is
+  /// kept instead of being replaced by the returned error:
+  ///
+  /// ```rust
+  /// #[napi]
+  /// pub fn validate(env: Env, validation_error: Function<String, Unknown>) -> Result<()> {
+  ///   env.throw(validation_error.new_instance("Invalid input".to_owned())?)?;
+  ///   Err(Error::from_status(Status::PendingException))
+  /// }
+  /// ```
+  pub fn throw<T: ToNapiValue>(&self, value: T) -> Result<()> {
+    let value = unsafe { T::to_napi_value(self.0, value)? };
+    check_status!(unsafe { sys::napi_throw(self.0, value) })
   }
 
   /// This API throws a JavaScript Error with the text provided.
+  ///
+  /// The NUL characters in `msg` and `code` are replaced by U+FFFD, they can't be passed to
+  /// Node-API.
   pub fn throw_error(&self, msg: &str, code: Option<&str>) -> Result<()> {
-    let code = code.and_then(|s| CString::new(s).ok());
-    let msg = CString::new(msg)?;
-    check_status!(unsafe {
-      sys::napi_throw_error(
-        self.0,
-        code.as_ref().map(|s| s.as_ptr()).unwrap_or(ptr::null_mut()),
-        msg.as_ptr(),
-      )
+    self.throw_error_with(msg, code, |code, msg| unsafe {
+      sys::napi_throw_error(self.0, code, msg)
     })
   }
 
   /// This API throws a JavaScript RangeError with the text provided.
+  ///
+  /// The NUL characters are replaced like in [`throw_error`](Env::throw_error).
   pub fn throw_range_error(&self, msg: &str, code: Option<&str>) -> Result<()> {
-    let code = code.and_then(|s| CString::new(s).ok());
-    let msg = CString::new(msg)?;
-    check_status!(unsafe {
-      sys::napi_throw_range_error(
-        self.0,
-        code.as_ref().map(|s| s.as_ptr()).unwrap_or(ptr::null_mut()),
-        msg.as_ptr(),
-      )
+    self.throw_error_with(msg, code, |code, msg| unsafe {
+      sys::napi_throw_range_error(self.0, code, msg)
     })
   }
 
   /// This API throws a JavaScript TypeError with the text provided.
+  ///
+  /// The NUL characters are replaced like in [`throw_error`](Env::throw_error).
   pub fn throw_type_error(&self, msg: &str, code: Option<&str>) -> Result<()> {
-    let code = code.and_then(|s| CString::new(s).ok());
-    let msg = CString::new(msg)?;
-    check_status!(unsafe {
-      sys::napi_throw_type_error(
-        self.0,
-        code.as_ref().map(|s| s.as_ptr()).unwrap_or(ptr::null_mut()),
-        msg.as_ptr(),
-      )
+    self.throw_error_with(msg, code, |code, msg| unsafe {
+      sys::napi_throw_type_error(self.0, code, msg)
     })
   }
 
   /// This API throws a JavaScript SyntaxError with the text provided.
+  ///
+  /// The NUL characters are replaced like in [`throw_error`](Env::throw_error).
   #[cfg(feature = "napi9")]
   pub fn throw_syntax_error<S: AsRef<str>, C: AsRef<str>>(&self, msg: S, code: Option<C>) {
-    use crate::check_status_or_throw;
-
-    let code = code.as_ref().map(|c| c.as_ref()).unwrap_or("");
-    let c_code = CString::new(code).expect("code must be a valid utf-8 string");
-    let code_ptr = c_code.as_ptr();
-    let msg: CString = CString::new(msg.as_ref()).expect("msg must be a valid utf-8 string");
-    let msg_ptr = msg.as_ptr();
-    check_status_or_throw!(
-      self.0,
-      unsafe { sys::node_api_throw_syntax_error(self.0, code_ptr, msg_ptr,) },
-      "Throw syntax error failed"
-    );
+    let code = code.as_ref().map(|c| c.as_ref());
+    if let Err(e) = self.throw_error_with(msg.as_ref(), code, |code, msg| unsafe {
+      sys::node_api_throw_syntax_error(self.0, code, msg)
+    }) {
+      unsafe { JsError::from(e).throw_into(self.0) };
+    }
+  }
+
+  /// The C strings live until `throw` returns
+  fn throw_error_with<F>(&self, msg: &str, code: Option<&str>, throw: F) -> Result<()>
+  where
+    F: FnOnce(*const c_char, *const c_char) -> sys::napi_status,
+  {
+    let code = code.map(c_string_lossy);
+    let msg = c_string_lossy(msg);
+    check_status!(throw(
+      code.as_deref().map_or(ptr::null(), CStr::as_ptr),
+      msg.as_ptr(),
+    ))
   }
 
-  #[allow(clippy::expect_fun_call)]
   /// In the event of an unrecoverable error in a native module
   ///
   /// A fatal error can be thrown to immediately terminate the process.
   pub fn fatal_error(self, location: &str, message: &str) {
-    let location_len = location.len();
-    let message_len = message.len();
-    let location =
-      CString::new(location).expect(format!("Convert [{}] to CString failed", location).as_str());
-    let message =
-      CString::new(message).expect(format!("Convert [{}] to CString failed", message).as_str());
+    let location = c_string_lossy(location);
+    let message = c_string_lossy(message);
 
     unsafe {
       sys::napi_fatal_error(
         location.as_ptr(),
-        location_len,
+        location.as_bytes().len(),
         message.as_ptr(),
-        message_len,
+        message.as_bytes().len(),
       )
     }
   }
@@ -795,70 +1253,132 @@ impl Env {
     native_object: T,
     size_hint: Option<usize>,
   ) -> Result<()> {
-    check_status!(unsafe {
+    let wrapped_objects = self.get_or_init_instance_data_typed(|| Ok(WrappedObjects::default()))?;
+    let tagged_object = Box::into_raw(Box::new(TaggedObject::new(native_object)));
+    let size_hint = Box::into_raw(Box::new(size_hint.unwrap_or(0) as i64));
+    let status = unsafe {
       sys::napi_wrap(
         self.0,
         js_object.0.value,
-        Box::into_raw(Box::new(TaggedObject::new(native_object))).cast(),
-        Some(raw_finalize::<TaggedObject<T>>),
-        Box::into_raw(Box::new(size_hint.unwrap_or(0) as i64)).cast(),
+        tagged_object.cast(),
+        Some(finalize_wrapped::<T>),
+        size_hint.cast(),
         ptr::null_mut(),
       )
-    })
+    };
+    if status != sys::Status::napi_ok {
+      drop(unsafe { Box::from_raw(tagged_object) });
+      drop(unsafe { Box::from_raw(size_hint) });
+      return check_status!(status);
+    }
+    wrapped_objects
+      .0
+      .borrow_mut()
+      .insert(tagged_object as usize);
+    Ok(())
   }
 
   pub fn unwrap<T: 'static>(&self, js_object: &JsObject) -> Result<&mut T> {
-    unsafe {
-      let mut unknown_tagged_object: *mut c_void = ptr::null_mut();
-      check_status!(sys::napi_unwrap(
-        self.0,
-        js_object.0.value,
-        &mut unknown_tagged_object,
-      ))?;
-
-      let type_id = unknown_tagged_object as *const TypeId;
-      if *type_id == TypeId::of::<T>() {
-        let tagged_object = unknown_tagged_object as *mut TaggedObject<T>;
-        (*tagged_object).object.as_mut().ok_or_else(|| {
-          Error::new(
-            Status::InvalidArg,
-            "Invalid argument, nothing attach to js_object".to_owned(),
-          )
-        })
-      } else {
-        Err(Error::new(
-          Status::InvalidArg,
-          format!(
-            "Invalid argument, {} on unwrap is not the type of wrapped object",
-            type_name::<T>()
-          ),
-        ))
-      }
-    }
+    let tagged_object = self.wrapped_object::<T>(js_object)?;
+    unsafe { (*tagged_object).object.as_mut() }.ok_or_else(|| {
+      Error::new(
+        Status::InvalidArg,
+        "Invalid argument, nothing attach to js_object".to_owned(),
+      )
+    })
   }
 
   pub fn drop_wrapped<T: 'static>(&self, js_object: &JsObject) -> Result<()> {
-    unsafe {
-      let mut unknown_tagged_object = ptr::null_mut();
-      check_status!(sys::napi_remove_wrap(
-        self.0,
-        js_object.0.value,
-        &mut unknown_tagged_object,
-      ))?;
-      let type_id = unknown_tagged_object as *const TypeId;
-      if *type_id == TypeId::of::<T>() {
-        drop(Box::from_raw(unknown_tagged_object as *mut TaggedObject<T>));
-        Ok(())
-      } else {
-        Err(Error::new(
-          Status::InvalidArg,
-          format!(
-            "Invalid argument, {} on unwrap is not the type of wrapped object",
-            type_name::<T>()
-          ),
-        ))
-      }
+    self.remove_wrap::<T>(js_object).map(drop)
+  }
+
+  /// Remove the native value wrapped by [`wrap`](Env::wrap) from `js_object` and return it, its
+  /// finalizer will not be called.
+  ///
+  /// The object can then be wrapped again, with a value of any type.
+  pub fn remove_wrap<T: 'static>(&self, js_object: &JsObject) -> Result<T> {
+    // Check the type first, the object keeps its value if it's not a `T`
+    self.wrapped_object::<T>(js_object)?;
+    let mut tagged_object = ptr::null_mut();
+    check_status!(unsafe { sys::napi_remove_wrap(self.0, js_object.0.value, &mut tagged_object) })?;
+    WrappedObjects::remove(self.0, tagged_object);
+    let tagged_object = unsafe { Box::from_raw(tagged_object.cast::<TaggedObject<T>>()) };
+    tagged_object.object.ok_or_else(|| {
+      Error::new(
+        Status::InvalidArg,
+        "Invalid argument, nothing attach to js_object".to_owned(),
+      )
+    })
+  }
+
+  /// The `TaggedObject<T>` wrapped by `js_object`. Objects wrapped by another addon, or with
+  /// `napi_wrap` directly, can't be read as a `TaggedObject`.
+  fn wrapped_object<T: 'static>(&self, js_object: &JsObject) -> Result<*mut TaggedObject<T>> {
+    let mut native_object = ptr::null_mut();
+    check_status!(unsafe { sys::napi_unwrap(self.0, js_object.0.value, &mut native_object) })?;
+    let is_wrapped = self
+      .get_instance_data_typed::<WrappedObjects>()
+      .is_some_and(|wrapped_objects| {
+        wrapped_objects
+          .0
+          .borrow()
+          .contains(&(native_object as usize))
+      });
+    if is_wrapped && unsafe { *native_object.cast::<TypeId>() } == TypeId::of::<T>() {
+      return Ok(native_object.cast());
     }
+    Err(Error::new(
+      Status::InvalidArg,
+      format!(
+        "Invalid argument, {} on unwrap is not the type of wrapped object",
+        type_name::<T>()
+      ),
+    ))
+  }
+
+  /// Mark `object` with `type_tag`, so it can later be recognized with
+  /// [`check_object_type_tag`](Env::check_object_type_tag), for example before unwrapping an
+  /// object that may come from another addon.
+  ///
+  /// An object can only be tagged once, and the instances of `#[napi]` classes are already tagged
+  /// by napi-rs.
+  #[cfg(feature = "napi8")]
+  pub fn type_tag_object<T: NapiRaw>(&self, object: &T, type_tag: &TypeTag) -> Result<()> {
+    check_status!(
+      unsafe { sys::napi_type_tag_object(self.0, object.raw(), type_tag) },
+      "Failed to tag object"
+    )
+  }
+
+  /// Whether `object` was tagged with `type_tag` by [`type_tag_object`](Env::type_tag_object)
+  #[cfg(feature = "napi8")]
+  pub fn check_object_type_tag<T: NapiRaw>(&self, object: &T, type_tag: &TypeTag) -> Result<bool> {
+    let mut result = false;
+    check_status!(
+      unsafe { sys::napi_check_object_type_tag(self.0, object.raw(), type_tag, &mut result) },
+      "Failed to check the type tag of object"
+    )?;
+    Ok(result)
+  }
+
+  /// Freeze `object`, like `Object.freeze()` in JavaScript, properties can no longer be added,
+  /// removed or changed
+  #[cfg(feature = "napi8")]
+  pub fn object_freeze(&self, object: &JsObject) -> Result<()> {
+    check_status!(
+      unsafe { sys::napi_object_freeze(self.0, object.0.value) },
+      "Failed to freeze object"
+    )
+  }
+
+  /// Seal `object`, like `Object.seal()` in JavaScript, properties can no longer be added or
+  /// removed but the writable ones can still be changed
+  #[cfg(feature = "napi8")]
+  pub fn object_seal(&self, object: &JsObject) -> Result<()> {
+    check_status!(
+      unsafe { sys::napi_object_seal(self.0, object.0.value) },
+      "Failed to seal object"
+    )
   }
 
   /// This API create a new reference with the initial 1 ref count to the Object passed in.
@@ -866,15 +1386,20 @@ impl Env {
   where
     T: NapiRaw,
   {
-    let mut raw_ref = ptr::null_mut();
-    let initial_ref_count = 1;
-    let raw_value = unsafe { value.raw() };
-    check_status!(unsafe {
-      sys::napi_create_reference(self.0, raw_value, initial_ref_count, &mut raw_ref)
-    })?;
     Ref::new(self, value)
   }
 
+  /// Create a reference with the initial 0 ref count, which doesn't keep the value alive.
+  ///
+  /// Use [`Ref::upgrade`] to get the value while it's not garbage collected, for caches holding
+  /// objects as long as JavaScript uses them.
+  pub fn create_weak_reference<T>(&self, value: &T) -> Result<Ref<T>>
+  where
+    T: NapiRaw,
+  {
+    Ref::new_weak(self, value)
+  }
+
   /// Get reference value from `Ref` with type check
   pub fn get_reference_value<T>(&self, reference: &Ref<T>) -> Result<T>
   where
@@ -884,6 +1409,12 @@ impl Env {
     check_status!(unsafe {
       sys::napi_get_reference_value(self.0, reference.raw_ref, &mut js_value)
     })?;
+    if js_value.is_null() {
+      return Err(Error::new(
+        Status::InvalidArg,
+        "Ref value has been garbage collected".to_owned(),
+      ));
+    }
     Ok(unsafe { T::from_raw_unchecked(self.0, js_value) })
   }
 
@@ -900,6 +1431,12 @@ impl Env {
     check_status!(unsafe {
       sys::napi_get_reference_value(self.0, reference.raw_ref, &mut js_value)
     })?;
+    if js_value.is_null() {
+      return Err(Error::new(
+        Status::InvalidArg,
+        "Ref value has been garbage collected".to_owned(),
+      ));
+    }
     Ok(unsafe { T::from_raw_unchecked(self.0, js_value) })
   }
 
@@ -926,10 +1463,7 @@ impl Env {
     })?;
     if let Some(changed) = size_hint {
       if changed != 0 {
-        let mut adjusted_value = 0i64;
-        check_status!(unsafe {
-          sys::napi_adjust_external_memory(self.0, changed, &mut adjusted_value)
-        })?;
+        unsafe { crate::memory::adjust_external_memory(self.0, changed)? };
       }
     };
     Ok(unsafe { JsExternal::from_raw_unchecked(self.0, object_value) })
@@ -973,11 +1507,83 @@ impl Env {
     Ok(unsafe { JsObject::from_raw_unchecked(self.0, result) })
   }
 
+  /// This API creates a JavaScript TypeError with the text provided, and the `code` property set
+  /// if `code` is provided.
+  pub fn create_type_error(&self, msg: &str, code: Option<&str>) -> Result<JsObject> {
+    self.create_error_with(msg, code, |code, msg, result| unsafe {
+      sys::napi_create_type_error(self.0, code, msg, result)
+    })
+  }
+
+  /// This API creates a JavaScript RangeError with the text provided, and the `code` property set
+  /// if `code` is provided.
+  pub fn create_range_error(&self, msg: &str, code: Option<&str>) -> Result<JsObject> {
+    self.create_error_with(msg, code, |code, msg, result| unsafe {
+      sys::napi_create_range_error(self.0, code, msg, result)
+    })
+  }
+
+  #[cfg(feature = "napi9")]
+  /// This API creates a JavaScript SyntaxError with the text provided, and the `code` property set
+  /// if `code` is provided.
+  pub fn create_syntax_error(&self, msg: &str, code: Option<&str>) -> Result<JsObject> {
+    self.create_error_with(msg, code, |code, msg, result| unsafe {
+      sys::node_api_create_syntax_error(self.0, code, msg, result)
+    })
+  }
+
+  fn create_error_with<F>(&self, msg: &str, code: Option<&str>, create: F) -> Result<JsObject>
+  where
+    F: FnOnce(sys::napi_value, sys::napi_value, &mut sys::napi_value) -> sys::napi_status,
+  {
+    let msg = self.create_string(msg)?;
+    let code = match code {
+      Some(code) => self.create_string(code)?.0.value,
+      None => ptr::null_mut(),
+    };
+    let mut result = ptr::null_mut();
+    check_status!(create(code, msg.0.value, &mut result))?;
+    Ok(unsafe { JsObject::from_raw_unchecked(self.0, result) })
+  }
+
   /// Run [Task](./trait.Task.html) in libuv thread pool, return [AsyncWorkPromise](./struct.AsyncWorkPromise.html)
   pub fn spawn<T: 'static + Task>(&self, task: T) -> Result<AsyncWorkPromise<T::JsValue>> {
     async_work::run(self.0, task, None)
   }
 
+  /// Run `compute` in the libuv thread pool, then `resolver` on the JavaScript thread with its
+  /// output, without implementing [`Task`]:
+  ///
+  /// ```rust,ignore
+  /// #[napi]
+  /// fn hash_file(env: Env, path: String) -> Result<PromiseRaw<String>> {
+  ///   let work = env.spawn_blocking(
+  ///     move || Ok(sha256(&std::fs::read(path)?)),
+  ///     |_env, hash| Ok(hex::encode(hash)),
+  ///   )?;
+  ///   Ok(work.promise_object())
+  /// }
+  /// ```
+  ///
+  /// The promise is rejected with the error returned by either of them.
+  pub fn spawn_blocking<T, V, F, R>(&self, compute: F, resolver: R) -> Result<AsyncWorkPromise<V>>
+  where
+    T: 'static + Send,
+    V: ToNapiValue + TypeName,
+    F: 'static + Send + FnOnce() -> Result<T>,
+    R: 'static + Send + FnOnce(Env, T) -> Result<V>,
+  {
+    self.spawn(BlockingTask {
+      compute: Some(compute),
+      resolver: Some(resolver),
+    })
+  }
+
+  /// Run `executor` in a new handle scope, the JavaScript values created by it are released when it
+  /// returns.
+  ///
+  /// The values must not be returned, use [`run_in_escapable_scope`](Env::run_in_escapable_scope)
+  /// to return one.
   pub fn run_in_scope<T, F>(&self, executor: F) -> Result<T>
   where
     F: FnOnce() -> Result<T>,
@@ -991,6 +1597,141 @@ impl Env {
     result
   }
 
+  /// Run `executor` in a new handle scope like [`run_in_scope`](Env::run_in_scope), except the
+  /// value it returns is escaped to the outer scope and stays valid.
+  ///
+  /// ```rust
+  /// let list = env.run_in_escapable_scope(|| {
+  ///   let mut list = env.create_array_with_length(items.len())?;
+  ///   for (i, item) in items.iter().enumerate() {
+  ///     // the strings are released with the scope, the array is kept alive by its caller
+  ///     list.set_element(i as u32, env.create_string(item)?)?;
+  ///   }
+  ///   Ok(list)
+  /// })?;
+  /// ```
+  pub fn run_in_escapable_scope<T, F>(&self, executor: F) -> Result<T>
+  where
+    T: NapiValue,
+    F: FnOnce() -> Result<T>,
+  {
+    let mut handle_scope = ptr::null_mut();
+    check_status!(unsafe { sys::napi_open_escapable_handle_scope(self.0, &mut handle_scope) })?;
+
+    let result = executor().and_then(|value| {
+      let mut escaped = ptr::null_mut();
+      check_status!(unsafe {
+        sys::napi_escape_handle(self.0, handle_scope, value.raw(), &mut escaped)
+      })?;
+      Ok(unsafe { T::from_raw_unchecked(self.0, escaped) })
+    });
+
+    check_status!(unsafe { sys::napi_close_escapable_handle_scope(self.0, handle_scope) })?;
+    result
+  }
+
+  /// Create the async context of a native resource, like an `AsyncResource` in JavaScript.
+  ///
+  /// `resource` is the object passed to the `async_hooks` init hooks, an empty object is created if
+  /// it's `None`. `name` is the type of the resource reported to the hooks.
+  pub fn async_init(&self, resource: Option<JsObject>, name: &str) -> Result<AsyncContext> {
+    let resource = match resource {
+      Some(resource) => resource,
+      None => self.create_object()?,
+    };
+    let name = self.create_string(name)?;
+    let mut context = ptr::null_mut();
+    check_status!(
+      unsafe { sys::napi_async_init(self.0, resource.0.value, name.0.value, &mut context) },
+      "Failed to create the async context"
+    )?;
+    let mut resource_ref = ptr::null_mut();
+    let status =
+      unsafe { sys::napi_create_reference(self.0, resource.0.value, 1, &mut resource_ref) };
+    if status != sys::Status::napi_ok {
+      unsafe { sys::napi_async_destroy(self.0, context) };
+      return Err(Error::new(
+        Status::from(status),
+        "Failed to reference the async resource",
+      ));
+    }
+    Ok(AsyncContext {
+      env: self.0,
+      context,
+      resource: resource_ref,
+    })
+  }
+
+  /// Call `func` from a native event, in the async context of `context` with its resource as
+  /// `this`.
+  ///
+  /// Unlike [`Function::call`], the async context is restored for `async_hooks` and the
+  /// `process.nextTick` queue and microtasks are drained after the call if there is no JavaScript
+  /// on the stack.
+  pub fn make_callback<Args: JsValuesTupleIntoVec, Return: FromNapiValue>(
+    &self,
+    context: &AsyncContext,
+    func: &Function<Args, Return>,
+    args: Args,
+  ) -> Result<Return> {
+    let resource = self.async_resource(context)?;
+    let mut args_ptr = ArgsBuffer::new();
+    args.push_into(self.0, &mut args_ptr)?;
+    let mut raw_return = ptr::null_mut();
+    check_pending_exception!(
+      self.0,
+      unsafe {
+        sys::napi_make_callback(
+          self.0,
+          context.context,
+          resource,
+          func.value,
+          args_ptr.len(),
+          args_ptr.as_ptr(),
+          &mut raw_return,
+        )
+      },
+      "Make callback failed"
+    )?;
+    unsafe { Return::from_napi_value(self.0, raw_return) }
+  }
+
+  #[cfg(feature = "napi3")]
+  /// Run `executor` in a callback scope of `context`, for native code calling several JavaScript
+  /// functions from one event.
+  ///
+  /// The `process.nextTick` queue and microtasks are drained when the scope is closed.
+  pub fn run_in_callback_scope<T, F>(&self, context: &AsyncContext, executor: F) -> Result<T>
+  where
+    F: FnOnce() -> Result<T>,
+  {
+    let resource = self.async_resource(context)?;
+    let mut callback_scope = ptr::null_mut();
+    check_status!(
+      unsafe {
+        sys::napi_open_callback_scope(self.0, resource, context.context, &mut callback_scope)
+      },
+      "Failed to open the callback scope"
+    )?;
+
+    let result = executor();
+
+    check_status!(
+      unsafe { sys::napi_close_callback_scope(self.0, callback_scope) },
+      "Failed to close the callback scope"
+    )?;
+    result
+  }
+
+  fn async_resource(&self, context: &AsyncContext) -> Result<sys::napi_value> {
+    let mut resource = ptr::null_mut();
+    check_status!(
+      unsafe { sys::napi_get_reference_value(self.0, context.resource, &mut resource) },
+      "Failed to get the async resource"
+    )?;
+    Ok(resource)
+  }
+
   /// Node-API provides an API for executing a string containing JavaScript using the underlying JavaScript engine.
   /// This function executes a string of JavaScript code and returns its result with the following caveats:
   /// - Unlike `eval`, this function does not allow the script to access the current lexical scope, and therefore also does not allow to access the [module scope](https://nodejs.org/api/modules.html#the-module-scope), meaning that pseudo-globals such as require will not be available.
@@ -1003,17 +1744,19 @@ impl Env {
     unsafe { V::from_napi_value(self.0, raw_value) }
   }
 
-  /// `process.versions.napi`
+  /// The highest Node-API version supported by the runtime, the same as `process.versions.napi`.
+  ///
+  /// It's read with `napi_get_version` once and then cached for the env.
   pub fn get_napi_version(&self) -> Result<u32> {
-    let global = self.get_global()?;
-    let process: JsObject = global.get_named_property("process")?;
-    let versions: JsObject = process.get_named_property("versions")?;
-    let napi_version: JsString = versions.get_named_property("napi")?;
-    napi_version
-      .into_utf8()?
-      .as_str()?
-      .parse()
-      .map_err(|e| Error::new(Status::InvalidArg, format!("{}", e)))
+    if let Some((env, version)) = NAPI_VERSION.get() {
+      if env == self.0 {
+        return Ok(version);
+      }
+    }
+    let mut version = 0;
+    check_status!(unsafe { sys::napi_get_version(self.0, &mut version) })?;
+    NAPI_VERSION.set(Some((self.0, version)));
+    Ok(version)
   }
 
   #[cfg(all(feature = "napi2", not(target_family = "wasm")))]
@@ -1139,6 +1882,9 @@ impl Env {
   }
 
   /// Creates a deferred promise, which can be resolved or rejected from a background thread.
+  ///
+  /// Returns the [`JsDeferred`] settling the promise and the promise itself, without the need of
+  /// an async runtime.
   #[cfg(feature = "napi4")]
   pub fn create_deferred<Data: ToNapiValue, Resolver: FnOnce(Env) -> Result<Data>>(
     &self,
@@ -1158,6 +1904,49 @@ impl Env {
     Ok(unsafe { JsDate::from_raw_unchecked(self.0, js_value) })
   }
 
+  /// Create a `Date` of `time`, rounded down to the millisecond.
+  ///
+  /// Returns an error instead of an Invalid Date if `time` is out of the range of `Date`, more than
+  /// 100,000,000 days away from the epoch.
+  #[cfg(feature = "napi5")]
+  pub fn create_date_from_system_time(&self, time: SystemTime) -> Result<JsDate> {
+    let millis = system_time_to_millis(time).ok_or_else(|| {
+      Error::new(
+        Status::InvalidArg,
+        "The SystemTime is out of the range of Date".to_owned(),
+      )
+    })?;
+    self.create_date(millis as f64)
+  }
+
+  /// Create a `Date` of `time`, rounded down to the millisecond.
+  ///
+  /// `From<DateTime<Tz>>` can't be implemented for [`JsDate`] as it needs an env, a `DateTime` can
+  /// also be returned from a `#[napi]` function directly.
+  #[cfg(feature = "chrono_date")]
+  pub fn create_date_from_chrono<Tz: chrono::TimeZone>(
+    &self,
+    time: &chrono::DateTime<Tz>,
+  ) -> Result<JsDate> {
+    let millis = time.timestamp_millis();
+    if millis.abs() > MAX_DATE_MILLIS {
+      return Err(Error::new(
+        Status::InvalidArg,
+        format!("{} is out of the range of Date", time.to_utc()),
+      ));
+    }
+    self.create_date(millis as f64)
+  }
+
+  /// Create a `RegExp` from a pattern and flags, same as `new RegExp(pattern, flags)` in JavaScript.
+  ///
+  /// An invalid pattern or invalid flags throw a `SyntaxError`, which is left pending.
+  pub fn create_regexp(&self, pattern: &str, flags: &str) -> Result<JsRegExp> {
+    let ctor = get_global_constructor::<(&str, &str)>(self.0, "RegExp")?;
+    let regexp = ctor.new_instance((pattern, flags))?;
+    Ok(unsafe { JsRegExp::from_raw_unchecked(self.0, regexp.raw()) })
+  }
+
   #[cfg(feature = "napi6")]
 
   /// This API associates data with the currently running Agent. data can later be retrieved using `Env::get_instance_data()`.
@@ -1165,28 +1954,22 @@ impl Env {
   /// Any existing data associated with the currently running Agent which was set by means of a previous call to `Env::set_instance_data()` will be overwritten.
   ///
   /// If a `finalize_cb` was provided by the previous call, it will not be called.
+  ///
+  /// Use [`Env::set_instance_data_typed`] to keep several values, one per type.
   pub fn set_instance_data<T, Hint, F>(&self, native: T, hint: Hint, finalize_cb: F) -> Result<()>
   where
     T: 'static,
     Hint: 'static,
     F: FnOnce(FinalizeContext<T, Hint>),
   {
-    check_status!(unsafe {
-      sys::napi_set_instance_data(
-        self.0,
-        Box::leak(Box::new((TaggedObject::new(native), finalize_cb))) as *mut (TaggedObject<T>, F)
-          as *mut c_void,
-        Some(
-          set_instance_finalize_callback::<T, Hint, F>
-            as unsafe extern "C" fn(
-              env: sys::napi_env,
-              finalize_data: *mut c_void,
-              finalize_hint: *mut c_void,
-            ),
-        ),
-        Box::leak(Box::new(hint)) as *mut Hint as *mut c_void,
-      )
-    })
+    // shares the instance data with the typed slots
+    let instance_data = InstanceData::get_or_init(self.0)?;
+    instance_data.set_untyped(UntypedInstanceData {
+      data: Box::into_raw(Box::new((TaggedObject::new(native), finalize_cb))).cast(),
+      hint: Box::into_raw(Box::new(hint)).cast(),
+      finalize: set_instance_finalize_callback::<T, Hint, F>,
+    });
+    Ok(())
   }
 
   /// This API retrieves data that was previously associated with the currently running Agent via `Env::set_instance_data()`.
@@ -1197,12 +1980,10 @@ impl Env {
   where
     T: 'static,
   {
-    let mut unknown_tagged_object: *mut c_void = ptr::null_mut();
+    let unknown_tagged_object = InstanceData::get(self.0)?
+      .and_then(|instance_data| instance_data.untyped())
+      .map_or(ptr::null_mut(), |untyped| untyped.data);
     unsafe {
-      check_status!(sys::napi_get_instance_data(
-        self.0,
-        &mut unknown_tagged_object
-      ))?;
       let type_id = unknown_tagged_object as *const TypeId;
       if unknown_tagged_object.is_null() {
         return Ok(None);
@@ -1291,25 +2072,40 @@ impl Env {
     Ok(unsafe { JsSymbol::from_raw_unchecked(self.0, result) })
   }
 
-  #[cfg(feature = "napi9")]
   /// This API retrieves the file path of the currently running JS module as a URL. For a file on
   /// the local file system it will start with `file://`.
   ///
+  /// Uses `node_api_get_module_file_name` with the `napi9` feature when the runtime has it. On
+  /// older runtimes, or when it's empty, the path of the loaded addon binary is read from the OS
+  /// instead, which is the same file.
+  ///
   /// # Errors
   ///
-  /// The retrieved string may be empty if the add-on loading process fails to establish the
-  /// add-on's file name.
+  /// Fails if neither knows the file of the add-on, on WebAssembly for example.
   pub fn get_module_file_name(&self) -> Result<String> {
-    let mut char_ptr = ptr::null();
-    check_status!(
-      unsafe { sys::node_api_get_module_file_name(self.0, &mut char_ptr) },
-      "call node_api_get_module_file_name failed"
-    )?;
-    // SAFETY: This is safe because `char_ptr` is guaranteed to not be `null`, and point to
-    // null-terminated string data.
-    let module_filename = unsafe { std::ffi::CStr::from_ptr(char_ptr) };
-
-    Ok(module_filename.to_string_lossy().into_owned())
+    #[cfg(feature = "napi9")]
+    {
+      if sys::is_available("node_api_get_module_file_name") {
+        let mut char_ptr = ptr::null();
+        check_status!(
+          unsafe { sys::node_api_get_module_file_name(self.0, &mut char_ptr) },
+          "call node_api_get_module_file_name failed"
+        )?;
+        if !char_ptr.is_null() {
+          // SAFETY: `char_ptr` points to null-terminated string data owned by the runtime.
+          let module_filename = unsafe { std::ffi::CStr::from_ptr(char_ptr) };
+          if !module_filename.is_empty() {
+            return Ok(module_filename.to_string_lossy().into_owned());
+          }
+        }
+      }
+    }
+    crate::module_file::addon_file_url().ok_or_else(|| {
+      Error::new(
+        Status::GenericFailure,
+        "Failed to get the file name of the module",
+      )
+    })
   }
 
   /// ### Serialize `Rust Struct` into `JavaScript Value`
@@ -1376,6 +2172,43 @@ impl Env {
     Ok(result)
   }
 
+  /// Convert `value` to a boolean like `Boolean(value)` in JavaScript, it never throws.
+  pub fn coerce_to_bool<T: NapiRaw>(&self, value: &T) -> Result<JsBoolean> {
+    let mut result = ptr::null_mut();
+    check_status!(unsafe { sys::napi_coerce_to_bool(self.0, value.raw(), &mut result) })?;
+    Ok(unsafe { JsBoolean::from_raw_unchecked(self.0, result) })
+  }
+
+  /// Convert `value` to a number like `Number(value)` in JavaScript, `"123"` becomes `123` and
+  /// `"abc"` becomes `NaN`.
+  ///
+  /// It throws for a `Symbol` or a `BigInt`, or if `valueOf` of an object throws, the exception
+  /// is left pending.
+  pub fn coerce_to_number<T: NapiRaw>(&self, value: &T) -> Result<JsNumber> {
+    let mut result = ptr::null_mut();
+    check_status!(unsafe { sys::napi_coerce_to_number(self.0, value.raw(), &mut result) })?;
+    Ok(unsafe { JsNumber::from_raw_unchecked(self.0, result) })
+  }
+
+  /// Convert `value` to an object like `Object(value)` in JavaScript, primitives are wrapped.
+  ///
+  /// It throws for `null` and `undefined`, the exception is left pending.
+  pub fn coerce_to_object<T: NapiRaw>(&self, value: &T) -> Result<JsObject> {
+    let mut result = ptr::null_mut();
+    check_status!(unsafe { sys::napi_coerce_to_object(self.0, value.raw(), &mut result) })?;
+    Ok(unsafe { JsObject::from_raw_unchecked(self.0, result) })
+  }
+
+  /// Convert `value` to a string like `` `${value}` `` in JavaScript.
+  ///
+  /// It throws for a `Symbol`, or if `toString` of an object throws, the exception is left
+  /// pending.
+  pub fn coerce_to_string<T: NapiRaw>(&self, value: &T) -> Result<JsString> {
+    let mut result = ptr::null_mut();
+    check_status!(unsafe { sys::napi_coerce_to_string(self.0, value.raw(), &mut result) })?;
+    Ok(unsafe { JsString::from_raw_unchecked(self.0, result) })
+  }
+
   pub fn get_node_version(&self) -> Result<NodeVersion> {
     let mut result = ptr::null();
     check_status!(unsafe { sys::napi_get_node_version(self.0, &mut result) })?;
@@ -1402,6 +2235,67 @@ unsafe extern "C" fn drop_buffer(
   mem::drop(unsafe { Vec::from_raw_parts(finalize_data as *mut u8, length, cap) });
 }
 
+unsafe extern "C" fn drop_aligned_buffer(
+  _env: sys::napi_env,
+  finalize_data: *mut c_void,
+  hint: *mut c_void,
+) {
+  let layout = unsafe { *Box::from_raw(hint as *mut Layout) };
+  unsafe { alloc::dealloc(finalize_data.cast(), layout) };
+}
+
+unsafe extern "C" fn drop_guard<G>(
+  _env: sys::napi_env,
+  _finalize_data: *mut c_void,
+  hint: *mut c_void,
+) {
+  drop(unsafe { Box::from_raw(hint.cast::<G>()) });
+}
+
+unsafe extern "C" fn drop_typed_array_data<T>(
+  _env: sys::napi_env,
+  finalize_data: *mut c_void,
+  hint: *mut c_void,
+) {
+  let (length, cap) = unsafe { *Box::from_raw(hint as *mut (usize, usize)) };
+  mem::drop(unsafe { Vec::from_raw_parts(finalize_data as *mut T, length, cap) });
+}
+
+/// `s` as a C string, with its NUL characters replaced by U+FFFD instead of failing
+fn c_string_lossy(s: &str) -> CString {
+  CString::new(s)
+    .or_else(|_| CString::new(s.replace('\0', "\u{FFFD}")))
+    .unwrap_or_default()
+}
+
+/// The `TaggedObject`s wrapped by [`Env::wrap`] in an env and not finalized yet.
+///
+/// They are tracked here rather than with a type tag: an object has a single type tag, which belongs
+/// to the users of [`Env::type_tag_object`]. An address is removed before its `TaggedObject` is
+/// freed, so it can't match a later allocation.
+#[derive(Default)]
+struct WrappedObjects(RefCell<HashSet<usize>>);
+
+impl WrappedObjects {
+  fn remove(env: sys::napi_env, tagged_object: *mut c_void) {
+    if let Some(wrapped_objects) = Env::from_raw(env).get_instance_data_typed::<WrappedObjects>() {
+      wrapped_objects
+        .0
+        .borrow_mut()
+        .remove(&(tagged_object as usize));
+    }
+  }
+}
+
+unsafe extern "C" fn finalize_wrapped<T: 'static>(
+  env: sys::napi_env,
+  finalize_data: *mut c_void,
+  finalize_hint: *mut c_void,
+) {
+  WrappedObjects::remove(env, finalize_data);
+  unsafe { raw_finalize::<TaggedObject<T>>(env, finalize_data, finalize_hint) };
+}
+
 pub(crate) unsafe extern "C" fn raw_finalize<T>(
   env: sys::napi_env,
   finalize_data: *mut c_void,
@@ -1409,14 +2303,12 @@ pub(crate) unsafe extern "C" fn raw_finalize<T>(
 ) {
   let tagged_object = finalize_data as *mut T;
   drop(unsafe { Box::from_raw(tagged_object) });
-  #[cfg(not(target_family = "wasm"))]
   if !finalize_hint.is_null() {
     let size_hint = unsafe { *Box::from_raw(finalize_hint as *mut i64) };
     if size_hint != 0 {
-      let mut adjusted = 0i64;
-      let status = unsafe { sys::napi_adjust_external_memory(env, -size_hint, &mut adjusted) };
+      let adjusted = unsafe { crate::memory::adjust_external_memory(env, -size_hint) };
       debug_assert!(
-        status == sys::Status::napi_ok,
+        adjusted.is_ok(),
         "Calling napi_adjust_external_memory failed"
       );
     }
@@ -1479,7 +2371,6 @@ unsafe extern "C" fn async_finalize<Arg, F>(
   }
 }
 
-#[cfg(feature = "napi5")]
 pub(crate) unsafe extern "C" fn trampoline<
   Return: ToNapiValue,
   F: Fn(FunctionCallContext) -> Result<Return>,
@@ -1487,9 +2378,11 @@ pub(crate) unsafe extern "C" fn trampoline<
   raw_env: sys::napi_env,
   cb_info: sys::napi_callback_info,
 ) -> sys::napi_value {
-  // Fast path for 4 arguments or less.
-  let mut argc = 4;
-  let mut raw_args = Vec::with_capacity(4);
+  // Fast path for 8 arguments or less, they stay on the stack.
+  const STACK_ARGC: usize = 8;
+  let mut argc = STACK_ARGC;
+  let mut stack_args = [ptr::null_mut(); STACK_ARGC];
+  let mut heap_args = Vec::new();
   let mut raw_this = ptr::null_mut();
   let mut closure_data_ptr = ptr::null_mut();
 
@@ -1499,7 +2392,7 @@ pub(crate) unsafe extern "C" fn trampoline<
         raw_env,
         cb_info,
         &mut argc,
-        raw_args.as_mut_ptr(),
+        stack_args.as_mut_ptr(),
         &mut raw_this,
         &mut closure_data_ptr,
       )
@@ -1507,34 +2400,34 @@ pub(crate) unsafe extern "C" fn trampoline<
     "napi_get_cb_info failed"
   )
   .and_then(|_| {
-    // Arguments length greater than 4, resize the vector.
-    if argc > 4 {
-      raw_args = vec![ptr::null_mut(); argc];
+    // Arguments length greater than 8, fetch them again into the heap.
+    if argc > STACK_ARGC {
+      heap_args = vec![ptr::null_mut(); argc];
       check_status!(
         unsafe {
           sys::napi_get_cb_info(
             raw_env,
             cb_info,
             &mut argc,
-            raw_args.as_mut_ptr(),
+            heap_args.as_mut_ptr(),
             &mut raw_this,
             &mut closure_data_ptr,
           )
         },
         "napi_get_cb_info failed"
       )?;
-    } else {
-      unsafe { raw_args.set_len(argc) };
     }
-    Ok((raw_this, raw_args, closure_data_ptr, argc))
-  })
-  .and_then(|(raw_this, raw_args, closure_data_ptr, _argc)| {
+    let raw_args = if argc > STACK_ARGC {
+      heap_args.as_slice()
+    } else {
+      &stack_args[..argc]
+    };
     let closure: &F = Box::leak(unsafe { Box::from_raw(closure_data_ptr.cast()) });
     let mut env = Env::from_raw(raw_env);
     closure(FunctionCallContext {
       env: &mut env,
       this: raw_this,
-      args: raw_args.as_slice(),
+      args: raw_args,
     })
   })
   .and_then(|ret| unsafe { <Return as ToNapiValue>::to_napi_value(raw_env, ret) })
@@ -1554,9 +2447,9 @@ pub(crate) unsafe extern "C" fn trampoline_setter<
 ) -> sys::napi_value {
   use crate::bindgen_runtime::This;
 
-  let (raw_args, raw_this, closure_data_ptr) = {
+  let (raw_args, argc, raw_this, closure_data_ptr) = {
     let mut argc = 1;
-    let mut raw_args = vec![ptr::null_mut(); 1];
+    let mut raw_args = [ptr::null_mut(); 1];
     let mut raw_this = ptr::null_mut();
     let mut data_ptr = ptr::null_mut();
 
@@ -1570,19 +2463,18 @@ pub(crate) unsafe extern "C" fn trampoline_setter<
         &mut data_ptr,
       )
     };
-    unsafe { raw_args.set_len(argc) };
     debug_assert!(
       Status::from(status) == Status::Ok,
       "napi_get_cb_info failed"
     );
 
     let closure_data_ptr = unsafe { *(data_ptr as *mut PropertyClosures) }.setter_closure;
-    (raw_args, raw_this, closure_data_ptr)
+    (raw_args, argc, raw_this, closure_data_ptr)
   };
 
   let closure: &F = Box::leak(unsafe { Box::from_raw(closure_data_ptr.cast()) });
   let env = Env::from_raw(raw_env);
-  raw_args
+  raw_args[..argc.min(1)]
     .first()
     .ok_or_else(|| Error::new(Status::InvalidArg, "Missing argument in property setter"))
     .and_then(|value| unsafe { V::from_napi_value(raw_env, *value) })
@@ -1643,7 +2535,6 @@ pub(crate) unsafe extern "C" fn trampoline_getter<
   })
 }
 
-#[cfg(feature = "napi5")]
 pub(crate) unsafe extern "C" fn finalize_box_trampoline<F>(
   _raw_env: sys::napi_env,
   closure_data_ptr: *mut c_void,
@@ -1,18 +1,22 @@
+#[cfg(feature = "error-backtrace")]
+use std::backtrace::{Backtrace, BacktraceStatus};
 use std::convert::{From, TryFrom};
 use std::error;
-use std::ffi::CString;
+use std::ffi::{CStr, CString};
 use std::fmt;
 #[cfg(feature = "serde-json")]
 use std::fmt::Display;
 use std::os::raw::{c_char, c_void};
 use std::ptr;
+#[cfg(feature = "error-backtrace")]
+use std::sync::Arc;
 
 #[cfg(feature = "serde-json")]
 use serde::{de, ser};
 #[cfg(feature = "serde-json")]
 use serde_json::Error as SerdeJSONError;
 
-use crate::bindgen_runtime::ToNapiValue;
+use crate::bindgen_runtime::{JsErrorValue, ToNapiValue};
 use crate::{check_status, sys, Env, JsUnknown, NapiValue, Status};
 
 pub type Result<T, S = Status> = std::result::Result<T, Error<S>>;
@@ -26,16 +30,69 @@ pub struct Error<S: AsRef<str> = Status> {
   pub reason: String,
   // Convert raw `JsError` into Error
   pub(crate) maybe_raw: sys::napi_ref,
+  pub(crate) napi_call: Option<Box<NapiCallInfo>>,
+  // Thrown as an instance of its `#[napi(error)]` class
+  pub(crate) js_error_value: Option<Box<JsErrorValue>>,
+  // Captured when `RUST_BACKTRACE` or `RUST_LIB_BACKTRACE` is set
+  #[cfg(feature = "error-backtrace")]
+  pub(crate) backtrace: Arc<Backtrace>,
+}
+
+/// The Node-API call an [`Error`] is returned from
+#[derive(Clone, Debug)]
+pub struct NapiCallInfo {
+  /// The name of the `napi_*` function
+  pub function: &'static str,
+  /// The message of the extended error info of the env, if the env is known
+  pub message: Option<String>,
+}
+
+impl NapiCallInfo {
+  /// Read the extended error info right after the failed call, it's reset by the next call
+  #[doc(hidden)]
+  pub fn new(code: &'static str, env: Option<sys::napi_env>) -> Option<Self> {
+    let function = crate::trace::sys_call(code)?;
+    let message = env.and_then(|env| {
+      let mut info = ptr::null();
+      let status = unsafe { sys::napi_get_last_error_info(env, &mut info) };
+      if status != sys::Status::napi_ok || info.is_null() {
+        return None;
+      }
+      let message = unsafe { (*info).error_message };
+      if message.is_null() {
+        return None;
+      }
+      Some(
+        unsafe { CStr::from_ptr(message) }
+          .to_string_lossy()
+          .into_owned(),
+      )
+    });
+    Some(Self { function, message })
+  }
+}
+
+impl fmt::Display for NapiCallInfo {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match &self.message {
+      Some(message) => write!(f, "`{}` failed: {}", self.function, message),
+      None => write!(f, "`{}` failed", self.function),
+    }
+  }
 }
 
 impl<S: AsRef<str>> std::fmt::Debug for Error<S> {
   fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
     write!(
       f,
-      "Error {{ status: {:?}, reason: {:?} }}",
+      "Error {{ status: {:?}, reason: {:?}",
       self.status.as_ref(),
       self.reason
-    )
+    )?;
+    if let Some(call) = &self.napi_call {
+      write!(f, ", napi_call: {:?}", call)?;
+    }
+    write!(f, " }}")
   }
 }
 
@@ -96,10 +153,9 @@ impl From<JsUnknown> for Error {
     let mut result = std::ptr::null_mut();
     let status = unsafe { sys::napi_create_reference(value.0.env, value.0.value, 1, &mut result) };
     if status != sys::Status::napi_ok {
-      return Error::new(
-        Status::from(status),
-        "Create Error reference failed".to_owned(),
-      );
+      // the runtimes without references to primitives, like a rejection with a number, get an
+      // `Error` with the value as message instead
+      result = std::ptr::null_mut();
     }
 
     let maybe_error_message = value
@@ -110,6 +166,10 @@ impl From<JsUnknown> for Error {
         status: Status::GenericFailure,
         reason: error_message,
         maybe_raw: result,
+        napi_call: None,
+        js_error_value: None,
+        #[cfg(feature = "error-backtrace")]
+        backtrace: Arc::new(Backtrace::capture()),
       };
     }
 
@@ -117,6 +177,10 @@ impl From<JsUnknown> for Error {
       status: Status::GenericFailure,
       reason: "".to_string(),
       maybe_raw: result,
+      napi_call: None,
+      js_error_value: None,
+      #[cfg(feature = "error-backtrace")]
+      backtrace: Arc::new(Backtrace::capture()),
     }
   }
 }
@@ -131,10 +195,19 @@ impl From<anyhow::Error> for Error {
 impl<S: AsRef<str> + std::fmt::Debug> fmt::Display for Error<S> {
   fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
     if !self.reason.is_empty() {
-      write!(f, "{:?}, {}", self.status, self.reason)
+      write!(f, "{:?}, {}", self.status, self.reason)?;
     } else {
-      write!(f, "{:?}", self.status)
+      write!(f, "{:?}", self.status)?;
+    }
+    match &self.napi_call {
+      Some(call) if !self.reason.starts_with(&call.to_string()) => write!(f, " ({})", call)?,
+      _ => {}
     }
+    #[cfg(feature = "error-backtrace")]
+    if let Some(backtrace) = self.backtrace() {
+      write!(f, "\n\nStack backtrace:\n{}", backtrace)?;
+    }
+    Ok(())
   }
 }
 
@@ -144,6 +217,10 @@ impl<S: AsRef<str>> Error<S> {
       status,
       reason: reason.to_string(),
       maybe_raw: ptr::null_mut(),
+      napi_call: None,
+      js_error_value: None,
+      #[cfg(feature = "error-backtrace")]
+      backtrace: Arc::new(Backtrace::capture()),
     }
   }
 
@@ -152,8 +229,38 @@ impl<S: AsRef<str>> Error<S> {
       status,
       reason: "".to_owned(),
       maybe_raw: ptr::null_mut(),
+      napi_call: None,
+      js_error_value: None,
+      #[cfg(feature = "error-backtrace")]
+      backtrace: Arc::new(Backtrace::capture()),
     }
   }
+
+  /// Where the error is created, if `RUST_BACKTRACE` or `RUST_LIB_BACKTRACE` is set
+  #[cfg(feature = "error-backtrace")]
+  pub fn backtrace(&self) -> Option<&Backtrace> {
+    match self.backtrace.status() {
+      BacktraceStatus::Captured => Some(&self.backtrace),
+      _ => None,
+    }
+  }
+
+  /// The Node-API call the error is returned from, if it's checked by `check_status!`
+  pub fn napi_call(&self) -> Option<&NapiCallInfo> {
+    self.napi_call.as_deref()
+  }
+
+  /// Attach the failed Node-API call, it becomes the reason if there is none
+  #[doc(hidden)]
+  pub fn with_napi_call(mut self, call: Option<NapiCallInfo>) -> Self {
+    if let Some(call) = call {
+      if self.reason.is_empty() {
+        self.reason = call.to_string();
+      }
+      self.napi_call = Some(Box::new(call));
+    }
+    self
+  }
 }
 
 impl Error {
@@ -162,6 +269,10 @@ impl Error {
       status: Status::GenericFailure,
       reason: reason.into(),
       maybe_raw: ptr::null_mut(),
+      napi_call: None,
+      js_error_value: None,
+      #[cfg(feature = "error-backtrace")]
+      backtrace: Arc::new(Backtrace::capture()),
     }
   }
 }
@@ -172,6 +283,10 @@ impl From<std::ffi::NulError> for Error {
       status: Status::GenericFailure,
       reason: format!("{}", error),
       maybe_raw: ptr::null_mut(),
+      napi_call: None,
+      js_error_value: None,
+      #[cfg(feature = "error-backtrace")]
+      backtrace: Arc::new(Backtrace::capture()),
     }
   }
 }
@@ -182,6 +297,10 @@ impl From<std::io::Error> for Error {
       status: Status::GenericFailure,
       reason: format!("{}", error),
       maybe_raw: ptr::null_mut(),
+      napi_call: None,
+      js_error_value: None,
+      #[cfg(feature = "error-backtrace")]
+      backtrace: Arc::new(Backtrace::capture()),
     }
   }
 }
@@ -233,7 +352,7 @@ macro_rules! impl_object_methods {
       /// # Safety
       ///
       /// This function is safety if env is not null ptr.
-      pub unsafe fn into_value(self, env: sys::napi_env) -> sys::napi_value {
+      pub unsafe fn into_value(mut self, env: sys::napi_env) -> sys::napi_value {
         if !self.0.maybe_raw.is_null() {
           let mut err = ptr::null_mut();
           let get_err_status =
@@ -258,6 +377,12 @@ macro_rules! impl_object_methods {
             return err;
           }
         }
+        if let Some(js_error_value) = self.0.js_error_value.take() {
+          // fall back to a plain error if the class can't be created
+          if let Ok(err) = unsafe { js_error_value.into_js_error(env) } {
+            return err;
+          }
+        }
 
         let error_status = self.0.status.as_ref();
         let status_len = error_status.len();
@@ -365,34 +490,111 @@ macro_rules! error {
   };
 }
 
+/// Check the status returned by a Node-API call, the `Error` tells which `napi_*` function failed.
+///
+/// If the call is written as `sys::napi_*(env, ..)`, optionally in an `unsafe` block, the error also
+/// has the extended error info of the env.
 #[doc(hidden)]
 #[macro_export]
 macro_rules! check_status {
-  ($code:expr) => {{
-    let c = $code;
-    match c {
+  (unsafe { $($func:ident)::+ ( $env:expr $(, $arg:expr)* $(,)? ) } $(, $($msg:tt)*)?) => {{
+    #[allow(unused_unsafe)]
+    let env = unsafe { $env };
+    let last_error_env = $crate::last_error_env!(env);
+    let c = unsafe { $($func)::+ (env $(, $arg)*) };
+    $crate::check_status!(@check c, stringify!($($func)::+ ($env)), last_error_env $(, $($msg)*)?)
+  }};
+
+  ($($func:ident)::+ ( $env:expr $(, $arg:expr)* $(,)? ) $(, $($msg:tt)*)?) => {{
+    let env = $env;
+    let last_error_env = $crate::last_error_env!(env);
+    let c = $($func)::+ (env $(, $arg)*);
+    $crate::check_status!(@check c, stringify!($($func)::+ ($env)), last_error_env $(, $($msg)*)?)
+  }};
+
+  (@check $c:ident, $code:expr, $last_error_env:expr) => {
+    match $c {
       $crate::sys::Status::napi_ok => Ok(()),
-      _ => Err($crate::Error::new($crate::Status::from(c), "".to_owned())),
+      _ => {
+        let call = $crate::NapiCallInfo::new($code, $last_error_env);
+        $crate::trace_status!($c, $code);
+        Err($crate::Error::new($crate::Status::from($c), "".to_owned()).with_napi_call(call))
+      }
     }
-  }};
+  };
 
-  ($code:expr, $($msg:tt)*) => {{
-    let c = $code;
-    match c {
+  (@check $c:ident, $code:expr, $last_error_env:expr, $($msg:tt)*) => {
+    match $c {
       $crate::sys::Status::napi_ok => Ok(()),
-      _ => Err($crate::Error::new($crate::Status::from(c), format!($($msg)*))),
+      _ => {
+        let call = $crate::NapiCallInfo::new($code, $last_error_env);
+        $crate::trace_status!($c, $code);
+        Err($crate::Error::new($crate::Status::from($c), format!($($msg)*)).with_napi_call(call))
+      }
     }
+  };
+
+  ($code:expr) => {{
+    let c = $code;
+    $crate::check_status!(@check c, stringify!($code), None)
   }};
 
-  ($code:expr, $msg:expr, $env:expr, $val:expr) => {{
+  ($code:expr, $($msg:tt)*) => {{
     let c = $code;
-    match c {
-      $crate::sys::Status::napi_ok => Ok(()),
-      _ => Err($crate::Error::new($crate::Status::from(c), format!($msg, $crate::type_of!($env, $val)?))),
-    }
+    $crate::check_status!(@check c, stringify!($code), None, $($msg)*)
+  }};
+}
+
+/// The env of a Node-API call if its first argument is a `napi_env`, `None` otherwise
+#[doc(hidden)]
+#[macro_export]
+macro_rules! last_error_env {
+  ($env:ident) => {{
+    #[allow(unused_imports)]
+    use $crate::{LastErrorEnv as _, NotLastErrorEnv as _};
+    (&$crate::LastErrorEnvProbe(&$env)).last_error_env()
+  }};
+}
+
+/// Log the failed call to the trace, with the function it's called from, see [`trace`](crate::trace)
+#[doc(hidden)]
+#[macro_export]
+macro_rules! trace_status {
+  ($status:expr, $code:expr) => {{
+    fn __napi_trace_fn() {}
+    $crate::trace::status_failure(
+      $status,
+      $code,
+      ::std::any::type_name_of_val(&__napi_trace_fn),
+      file!(),
+      line!(),
+    );
   }};
 }
 
+#[doc(hidden)]
+pub struct LastErrorEnvProbe<'a, T>(pub &'a T);
+
+#[doc(hidden)]
+pub trait LastErrorEnv {
+  fn last_error_env(&self) -> Option<sys::napi_env>;
+}
+
+impl LastErrorEnv for LastErrorEnvProbe<'_, sys::napi_env> {
+  fn last_error_env(&self) -> Option<sys::napi_env> {
+    Some(*self.0)
+  }
+}
+
+#[doc(hidden)]
+pub trait NotLastErrorEnv {
+  fn last_error_env(&self) -> Option<sys::napi_env> {
+    None
+  }
+}
+
+impl<T> NotLastErrorEnv for &LastErrorEnvProbe<'_, T> {}
+
 #[doc(hidden)]
 #[macro_export]
 macro_rules! check_status_and_type {
@@ -0,0 +1,189 @@
+use std::cell::RefCell;
+use std::ffi::c_void;
+use std::ptr;
+use std::rc::Rc;
+
+use crate::{check_status, sys, Env, Error, JsArrayBuffer, NapiValue, Result, Status};
+
+/// Memory allocated outside of the JavaScript heap, like a mapped GPU buffer or pinned host
+/// memory, which [`MappedArrayBuffer`] exposes to JavaScript without copying it.
+///
+/// ```rust,ignore
+/// struct PinnedHost {
+///   ptr: *mut u8,
+///   len: usize,
+/// }
+///
+/// unsafe impl ExternalMemory for PinnedHost {
+///   fn as_mut_ptr(&mut self) -> *mut u8 {
+///     self.ptr
+///   }
+///
+///   fn len(&self) -> usize {
+///     self.len
+///   }
+///
+///   fn release(self, _env: Env) {
+///     unsafe { cuda_free_host(self.ptr) };
+///   }
+/// }
+/// ```
+///
+/// # Safety
+///
+/// The `len` bytes at `as_mut_ptr` must be valid for reads and writes from the JavaScript thread,
+/// and must stay at the same address until the memory is released or dropped.
+pub unsafe trait ExternalMemory: 'static {
+  fn as_mut_ptr(&mut self) -> *mut u8;
+
+  fn len(&self) -> usize;
+
+  fn is_empty(&self) -> bool {
+    self.len() == 0
+  }
+
+  /// Called on the JavaScript thread once JavaScript can't reach the memory anymore, like to
+  /// unmap it or to hand it back to a pool. Drops the memory by default.
+  fn release(self, _env: Env)
+  where
+    Self: Sized,
+  {
+  }
+}
+
+/// The memory of a [`MappedArrayBuffer`], `None` once it's released
+struct Mapping<M> {
+  memory: RefCell<Option<M>>,
+}
+
+/// An `ArrayBuffer` over [`ExternalMemory`], and the handle to take the memory back from
+/// JavaScript.
+///
+/// The memory is released when the `ArrayBuffer` is garbage collected, or when it's taken back by
+/// [`MappedArrayBuffer::unmap`], which detaches the `ArrayBuffer` first: JavaScript sees an empty
+/// `ArrayBuffer` from then on instead of reading unmapped memory.
+///
+/// ```rust,ignore
+/// let mapped = MappedArrayBuffer::new(&env, PinnedHost::alloc(size)?)?;
+/// let array_buffer = mapped.array_buffer()?;
+/// // ... before the memory is written by the device again
+/// if let Some(memory) = mapped.unmap()? {
+///   memory.release(env);
+/// }
+/// ```
+///
+/// Dropping the handle leaves the memory to the garbage collector. The runtimes not allowing
+/// external ArrayBuffers, like Electron, are not supported, the memory is never copied.
+pub struct MappedArrayBuffer<M: ExternalMemory> {
+  env: sys::napi_env,
+  mapping: Rc<Mapping<M>>,
+  /// Weak, it doesn't keep the `ArrayBuffer` alive
+  array_buffer: sys::napi_ref,
+}
+
+impl<M: ExternalMemory> MappedArrayBuffer<M> {
+  pub fn new(env: &Env, mut memory: M) -> Result<Self> {
+    let length = memory.len();
+    let data = if length == 0 {
+      // the address of empty memory may be shared by other buffers
+      ptr::null_mut()
+    } else {
+      memory.as_mut_ptr()
+    };
+    let mapping = Rc::new(Mapping {
+      memory: RefCell::new(Some(memory)),
+    });
+    let hint = Rc::into_raw(mapping.clone());
+    let mut array_buffer = ptr::null_mut();
+    let status = unsafe {
+      sys::napi_create_external_arraybuffer(
+        env.0,
+        data.cast(),
+        length,
+        Some(release_mapping::<M>),
+        hint.cast_mut().cast(),
+        &mut array_buffer,
+      )
+    };
+    if status != sys::Status::napi_ok {
+      drop(unsafe { Rc::from_raw(hint) });
+      if let Some(memory) = mapping.memory.borrow_mut().take() {
+        memory.release(*env);
+      }
+      if status == sys::Status::napi_no_external_buffers_allowed {
+        return Err(Error::new(
+          Status::GenericFailure,
+          "External ArrayBuffers are not allowed in this runtime, the memory can't be mapped",
+        ));
+      }
+      check_status!(
+        status,
+        "Failed to create the ArrayBuffer of the external memory"
+      )?;
+    }
+    let mut reference = ptr::null_mut();
+    check_status!(
+      unsafe { sys::napi_create_reference(env.0, array_buffer, 0, &mut reference) },
+      "Failed to create a reference to the ArrayBuffer of the external memory"
+    )?;
+    Ok(Self {
+      env: env.0,
+      mapping,
+      array_buffer: reference,
+    })
+  }
+
+  /// The `ArrayBuffer` over the memory, `None` once it's unmapped or garbage collected
+  pub fn array_buffer(&self) -> Result<Option<JsArrayBuffer>> {
+    if !self.is_mapped() {
+      return Ok(None);
+    }
+    let mut array_buffer = ptr::null_mut();
+    check_status!(unsafe {
+      sys::napi_get_reference_value(self.env, self.array_buffer, &mut array_buffer)
+    })?;
+    if array_buffer.is_null() {
+      return Ok(None);
+    }
+    Ok(Some(unsafe {
+      JsArrayBuffer::from_raw_unchecked(self.env, array_buffer)
+    }))
+  }
+
+  /// Whether JavaScript can still reach the memory
+  pub fn is_mapped(&self) -> bool {
+    self.mapping.memory.borrow().is_some()
+  }
+
+  /// Detach the `ArrayBuffer` and take the memory back, without releasing it.
+  ///
+  /// `None` if the memory is released already. The memory stays mapped and an error is returned
+  /// if the `ArrayBuffer` can't be detached.
+  pub fn unmap(&self) -> Result<Option<M>> {
+    if let Some(array_buffer) = self.array_buffer()? {
+      check_status!(
+        unsafe { sys::napi_detach_arraybuffer(self.env, array_buffer.0.value) },
+        "Failed to detach the ArrayBuffer of the external memory, it's still mapped"
+      )?;
+    }
+    Ok(self.mapping.memory.borrow_mut().take())
+  }
+}
+
+impl<M: ExternalMemory> Drop for MappedArrayBuffer<M> {
+  fn drop(&mut self) {
+    unsafe { sys::napi_delete_reference(self.env, self.array_buffer) };
+  }
+}
+
+unsafe extern "C" fn release_mapping<M: ExternalMemory>(
+  env: sys::napi_env,
+  _finalize_data: *mut c_void,
+  finalize_hint: *mut c_void,
+) {
+  let mapping = unsafe { Rc::<Mapping<M>>::from_raw(finalize_hint.cast()) };
+  let memory = mapping.memory.borrow_mut().take();
+  if let Some(memory) = memory {
+    memory.release(Env::from_raw(env));
+  }
+}
@@ -0,0 +1,342 @@
+//! Deterministic number and date formatting, independent of the ICU variant Node.js is built
+//! with (`small-icu`, `full-icu` or none) and of the locale of the machine.
+//!
+//! The separators are either given explicitly, or taken from the CLDR data of a locale bundled
+//! with the addon by [icu4x](https://github.com/unicode-org/icu4x) instead of the ICU data of
+//! Node.js, so the output of a given call is the same everywhere.
+//!
+//! With the `formatting` feature enabled, the addon exposes the same formatting to JS on its
+//! `formatting` object:
+//!
+//! ```js
+//! const { formatting } = require('./index.node')
+//! formatting.toFixed(1.005, 2) // '1.00', same as `(1.005).toFixed(2)`
+//! formatting.formatNumber(1234567.891, { fractionDigits: 2 }) // '1,234,567.89'
+//! formatting.formatNumber(1234567.891, { groupSeparator: '.', decimalSeparator: ',' }) // '1.234.568'
+//! formatting.formatNumber(1234567.891, { locale: 'de', fractionDigits: 2 }) // '1.234.567,89'
+//! formatting.formatRfc3339(new Date(0)) // '1970-01-01T00:00:00.000Z'
+//! ```
+
+use crate::{
+  bindgen_prelude::{register_module_export, CallbackInfo, FromNapiValue, Object, ToNapiValue},
+  check_status, sys, Error, Result, Status,
+};
+
+/// The largest number of fraction digits accepted by `Number.prototype.toFixed`
+pub const MAX_FRACTION_DIGITS: usize = 100;
+
+/// Format `value` with exactly `digits` fraction digits, same as `Number.prototype.toFixed`.
+///
+/// The value is rounded from its exact binary value, a tie is rounded away from zero. Like
+/// `toFixed`, values of `1e21` or more are written in exponent notation and `-0` is written `0`.
+pub fn to_fixed(value: f64, digits: usize) -> Result<String> {
+  check_fraction_digits(digits)?;
+  if !value.is_finite() {
+    return Ok(non_finite(value).to_owned());
+  }
+  if value.abs() >= 1e21 {
+    return Ok(exponent_notation(value));
+  }
+  let (integer, fraction) = round_decimal(value.abs(), digits);
+  let mut output = String::with_capacity(integer.len() + fraction.len() + 2);
+  if value < 0.0 {
+    output.push('-');
+  }
+  output.push_str(&integer);
+  if !fraction.is_empty() {
+    output.push('.');
+    output.push_str(&fraction);
+  }
+  Ok(output)
+}
+
+/// How [`NumberFormat::format`] writes a number
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NumberFormat {
+  /// The exact number of fraction digits, rounded like [`to_fixed`]
+  pub fraction_digits: usize,
+  /// Written between the groups of three integer digits, `None` to not group them
+  pub group_separator: Option<String>,
+  pub decimal_separator: String,
+}
+
+impl Default for NumberFormat {
+  fn default() -> Self {
+    Self {
+      fraction_digits: 0,
+      group_separator: Some(",".to_owned()),
+      decimal_separator: ".".to_owned(),
+    }
+  }
+}
+
+impl NumberFormat {
+  /// Format `value`, `NaN`, `Infinity` and `-Infinity` are written as is, and `-0` as `0`.
+  ///
+  /// Unlike [`to_fixed`], large values are written with all their integer digits.
+  pub fn format(&self, value: f64) -> Result<String> {
+    check_fraction_digits(self.fraction_digits)?;
+    if !value.is_finite() {
+      return Ok(non_finite(value).to_owned());
+    }
+    let (integer, fraction) = round_decimal(value.abs(), self.fraction_digits);
+    let mut output = String::with_capacity(integer.len() * 2 + fraction.len() + 2);
+    if value < 0.0 {
+      output.push('-');
+    }
+    match &self.group_separator {
+      Some(separator) => {
+        for (i, digit) in integer.chars().enumerate() {
+          if i > 0 && (integer.len() - i) % 3 == 0 {
+            output.push_str(separator);
+          }
+          output.push(digit);
+        }
+      }
+      None => output.push_str(&integer),
+    }
+    if !fraction.is_empty() {
+      output.push_str(&self.decimal_separator);
+      output.push_str(&fraction);
+    }
+    Ok(output)
+  }
+
+  /// Format `value` with the separators and grouping of `locale`, a BCP 47 locale like `"de-CH"`,
+  /// instead of `group_separator` and `decimal_separator`. The digits are grouped unless
+  /// `group_separator` is `None`.
+  ///
+  /// The numbers are rounded like [`NumberFormat::format`], the locale data is the CLDR data
+  /// compiled into icu4x, the locales it doesn't know fall back to the root locale.
+  pub fn format_in_locale(&self, value: f64, locale: &str) -> Result<String> {
+    check_fraction_digits(self.fraction_digits)?;
+    if !value.is_finite() {
+      return Ok(non_finite(value).to_owned());
+    }
+    let locale = locale.parse::<icu_locid::Locale>().map_err(|err| {
+      Error::new(
+        Status::InvalidArg,
+        format!("Invalid locale `{locale}`: {err}"),
+      )
+    })?;
+    let mut options = icu_decimal::options::FixedDecimalFormatterOptions::default();
+    if self.group_separator.is_none() {
+      options.grouping_strategy = icu_decimal::options::GroupingStrategy::Never;
+    }
+    let formatter = icu_decimal::FixedDecimalFormatter::try_new(&(&locale).into(), options)
+      .map_err(|err| {
+        Error::new(
+          Status::GenericFailure,
+          format!("No number format for `{locale}`: {err}"),
+        )
+      })?;
+    let (integer, fraction) = round_decimal(value.abs(), self.fraction_digits);
+    let mut decimal = if fraction.is_empty() {
+      integer.parse::<fixed_decimal::FixedDecimal>()
+    } else {
+      format!("{integer}.{fraction}").parse()
+    }
+    .map_err(|err| Error::new(Status::GenericFailure, err.to_string()))?;
+    if value < 0.0 {
+      decimal.set_sign(fixed_decimal::Sign::Negative);
+    }
+    Ok(formatter.format_to_string(&decimal))
+  }
+}
+
+/// Format a time as RFC 3339 in UTC with milliseconds, same as `Date.prototype.toISOString`.
+///
+/// Like `toISOString`, the years before 0 or after 9999 are written with a sign and six digits,
+/// which is not valid RFC 3339.
+pub fn format_rfc3339(millis_since_epoch: i64) -> String {
+  let days = millis_since_epoch.div_euclid(86_400_000);
+  let millis_of_day = millis_since_epoch.rem_euclid(86_400_000);
+  let (year, month, day) = civil_from_days(days);
+  let year = if (0..=9999).contains(&year) {
+    format!("{year:04}")
+  } else {
+    format!("{}{:06}", if year < 0 { '-' } else { '+' }, year.abs())
+  };
+  format!(
+    "{year}-{month:02}-{day:02}T{:02}:{:02}:{:02}.{:03}Z",
+    millis_of_day / 3_600_000,
+    millis_of_day / 60_000 % 60,
+    millis_of_day / 1000 % 60,
+    millis_of_day % 1000,
+  )
+}
+
+fn check_fraction_digits(digits: usize) -> Result<()> {
+  if digits > MAX_FRACTION_DIGITS {
+    return Err(Error::new(
+      Status::InvalidArg,
+      format!("The fraction digits must be between 0 and {MAX_FRACTION_DIGITS}, got {digits}"),
+    ));
+  }
+  Ok(())
+}
+
+fn non_finite(value: f64) -> &'static str {
+  if value.is_nan() {
+    "NaN"
+  } else if value > 0.0 {
+    "Infinity"
+  } else {
+    "-Infinity"
+  }
+}
+
+/// The shortest round-tripping digits in exponent notation, like `Number.prototype.toString`
+fn exponent_notation(value: f64) -> String {
+  let formatted = format!("{value:e}");
+  match formatted.split_once('e') {
+    Some((mantissa, exponent)) if !exponent.starts_with('-') => format!("{mantissa}e+{exponent}"),
+    _ => formatted,
+  }
+}
+
+/// The integer and fraction digits of the non-negative `value` rounded to `digits` fraction
+/// digits, a tie is rounded up
+fn round_decimal(value: f64, digits: usize) -> (String, String) {
+  // written with all the fraction digits of its binary value, the formatting is exact
+  let exact = format!("{value:.*}", exact_fraction_digits(value).max(digits));
+  let (integer, fraction) = exact.split_once('.').unwrap_or((&exact, ""));
+  let round_up = fraction.as_bytes().get(digits).is_some_and(|d| *d >= b'5');
+  let mut rounded = format!("{integer}{}", &fraction[..digits]).into_bytes();
+  if round_up {
+    match rounded.iter().rposition(|d| *d != b'9') {
+      Some(last) => {
+        rounded[last] += 1;
+        rounded[last + 1..].fill(b'0');
+      }
+      None => {
+        rounded.fill(b'0');
+        rounded.insert(0, b'1');
+      }
+    }
+  }
+  let fraction = rounded.split_off(rounded.len() - digits);
+  (
+    String::from_utf8(rounded).expect("The digits are ASCII"),
+    String::from_utf8(fraction).expect("The digits are ASCII"),
+  )
+}
+
+/// The number of digits after the decimal point in the exact decimal value of `value`, the same
+/// as the number of fraction bits in its binary value
+fn exact_fraction_digits(value: f64) -> usize {
+  if value == 0.0 {
+    return 0;
+  }
+  let bits = value.to_bits();
+  let biased_exponent = ((bits >> 52) & 0x7ff) as i64;
+  let mantissa = bits & ((1 << 52) - 1);
+  let (mantissa, exponent) = if biased_exponent == 0 {
+    (mantissa, -1074)
+  } else {
+    (mantissa | (1 << 52), biased_exponent - 1075)
+  };
+  let exponent = exponent + i64::from(mantissa.trailing_zeros());
+  (-exponent).max(0) as usize
+}
+
+/// The proleptic Gregorian `(year, month, day)` of the days since 1970-01-01
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+  // http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+  let days = days + 719_468;
+  let era = days.div_euclid(146_097);
+  let day_of_era = days.rem_euclid(146_097);
+  let year_of_era =
+    (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+  let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+  let shifted_month = (5 * day_of_year + 2) / 153;
+  let day = (day_of_year - (153 * shifted_month + 2) / 5 + 1) as u32;
+  let month = if shifted_month < 10 {
+    shifted_month + 3
+  } else {
+    shifted_month - 9
+  } as u32;
+  let year = year_of_era + era * 400 + i64::from(month <= 2);
+  (year, month, day)
+}
+
+fn fraction_digits_arg(digits: Option<f64>) -> Result<usize> {
+  let digits = digits.unwrap_or(0.0);
+  if digits.fract() != 0.0 || !(0.0..=MAX_FRACTION_DIGITS as f64).contains(&digits) {
+    return Err(Error::new(
+      Status::InvalidArg,
+      format!("The fraction digits must be between 0 and {MAX_FRACTION_DIGITS}, got {digits}"),
+    ));
+  }
+  Ok(digits as usize)
+}
+
+unsafe fn to_fixed_js(env: sys::napi_env, cb: sys::napi_callback_info) -> Result<sys::napi_value> {
+  let cb = CallbackInfo::<2>::new(env, cb, Some(1), false)?;
+  let value = unsafe { f64::from_napi_value(env, cb.get_arg(0))? };
+  let digits = fraction_digits_arg(unsafe { Option::<f64>::from_napi_value(env, cb.get_arg(1))? })?;
+  unsafe { String::to_napi_value(env, to_fixed(value, digits)?) }
+}
+
+unsafe fn format_number_js(
+  env: sys::napi_env,
+  cb: sys::napi_callback_info,
+) -> Result<sys::napi_value> {
+  let cb = CallbackInfo::<2>::new(env, cb, Some(1), false)?;
+  let value = unsafe { f64::from_napi_value(env, cb.get_arg(0))? };
+  let options = unsafe { Option::<Object>::from_napi_value(env, cb.get_arg(1))? };
+  let mut format = NumberFormat::default();
+  if let Some(options) = options {
+    format.fraction_digits = fraction_digits_arg(options.get::<f64>("fractionDigits")?)?;
+    if let Some(separator) = options.get::<String>("groupSeparator")? {
+      format.group_separator = (!separator.is_empty()).then_some(separator);
+    }
+    if let Some(separator) = options.get::<String>("decimalSeparator")? {
+      format.decimal_separator = separator;
+    }
+    if let Some(locale) = options.get::<String>("locale")? {
+      return unsafe { String::to_napi_value(env, format.format_in_locale(value, &locale)?) };
+    }
+  }
+  unsafe { String::to_napi_value(env, format.format(value)?) }
+}
+
+unsafe fn format_rfc3339_js(
+  env: sys::napi_env,
+  cb: sys::napi_callback_info,
+) -> Result<sys::napi_value> {
+  let cb = CallbackInfo::<1>::new(env, cb, Some(1), false)?;
+  // a `Date` is coerced to its time value, like `Number(date)`
+  let mut time = std::ptr::null_mut();
+  check_status!(
+    unsafe { sys::napi_coerce_to_number(env, cb.get_arg(0), &mut time) },
+    "Failed to coerce the time to a number"
+  )?;
+  let millis = unsafe { f64::from_napi_value(env, time)? };
+  if !millis.is_finite() {
+    return Err(Error::new(Status::InvalidArg, "Invalid time value"));
+  }
+  unsafe { String::to_napi_value(env, format_rfc3339(millis.trunc() as i64)) }
+}
+
+js_function!("toFixed", to_fixed_js, to_fixed_callback, register_to_fixed);
+js_function!(
+  "formatNumber",
+  format_number_js,
+  format_number_callback,
+  register_format_number
+);
+js_function!(
+  "formatRfc3339",
+  format_rfc3339_js,
+  format_rfc3339_callback,
+  register_format_rfc3339
+);
+
+/// Export `toFixed`, `formatNumber` and `formatRfc3339` on the `formatting` object of the addon
+pub(crate) fn register_exports() {
+  const JS_MOD: Option<&str> = Some("formatting\0");
+  register_module_export(JS_MOD, "toFixed\0", register_to_fixed);
+  register_module_export(JS_MOD, "formatNumber\0", register_format_number);
+  register_module_export(JS_MOD, "formatRfc3339\0", register_format_rfc3339);
+}
@@ -0,0 +1,401 @@
+use std::collections::VecDeque;
+use std::ops::{Deref, DerefMut};
+#[cfg(all(feature = "napi3", not(target_family = "wasm")))]
+use std::ptr;
+use std::sync::{Arc, Condvar, Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+use crate::ring_buffer::{out_of_range, slot_buffer, SlotMemory};
+#[cfg(all(feature = "napi3", not(target_family = "wasm")))]
+use crate::{check_status, UvAsyncSender};
+use crate::{sys, Env, Error, JsArrayBuffer, Result, Status};
+
+/// What a [`FrameSender`] does when all the slots of its [`FrameQueue`] are taken
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OverflowPolicy {
+  /// Reuse the slot of the oldest frame JavaScript has not received yet, like for live capture
+  /// where a late frame is worthless. The frames held by JavaScript are never reused.
+  DropOldest,
+  /// Wait for JavaScript to release a slot, like for codecs which must not skip a frame
+  Backpressure,
+}
+
+/// A frame queued by [`FrameSlot::commit`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QueuedFrame {
+  /// The slot of the frame, its data is at the start of `queue.buffer(index)`
+  pub index: usize,
+  /// The number of bytes written by the producer
+  pub len: usize,
+  /// The timestamp given by the producer, in its own unit, like microseconds
+  pub timestamp: i64,
+}
+
+struct QueueState {
+  /// The slots waiting for the producer
+  free: Vec<usize>,
+  /// The committed frames JavaScript has not received yet, oldest first
+  ready: VecDeque<QueuedFrame>,
+  held: Vec<bool>,
+  dropped: u64,
+  sender_closed: bool,
+  /// The queue and its subscription, the queue is closed when both are dropped
+  receivers: usize,
+}
+
+struct QueueShared {
+  memory: Arc<SlotMemory>,
+  policy: OverflowPolicy,
+  state: Mutex<QueueState>,
+  /// Notified when a slot is freed, or when the receiver is dropped
+  released: Condvar,
+  #[cfg(all(feature = "napi3", not(target_family = "wasm")))]
+  waker: Mutex<Option<UvAsyncSender>>,
+}
+
+impl QueueShared {
+  fn state(&self) -> MutexGuard<'_, QueueState> {
+    self.state.lock().unwrap_or_else(|e| e.into_inner())
+  }
+
+  fn wake(&self) {
+    #[cfg(all(feature = "napi3", not(target_family = "wasm")))]
+    if let Some(waker) = self
+      .waker
+      .lock()
+      .unwrap_or_else(|e| e.into_inner())
+      .as_ref()
+    {
+      // only fails once the env is torn down, when nobody listens anymore
+      let _ = waker.send();
+    }
+  }
+}
+
+/// A queue of timestamped frames from a producer thread to JavaScript, over a fixed set of
+/// ArrayBuffers, for capture and codec addons.
+///
+/// JavaScript receives the frames in the order they are committed, either by polling with
+/// [`FrameQueue::poll`] and handing them back with [`FrameQueue::release`], or by subscribing with
+/// [`FrameQueue::on_frame`]. When JavaScript falls behind, the [`OverflowPolicy`] decides whether
+/// the producer drops the oldest queued frames or waits.
+///
+/// ```rust,ignore
+/// let (queue, mut sender) = FrameQueue::new(&env, 4, 640 * 480 * 4, OverflowPolicy::DropOldest)?;
+/// std::thread::spawn(move || {
+///   while let Some(mut slot) = sender.acquire(Duration::from_secs(1)) {
+///     let (len, timestamp) = camera.read_frame(&mut slot);
+///     slot.commit(len, timestamp);
+///   }
+/// });
+/// queue.on_frame(|env, frame, buffer| {
+///   // `buffer` holds the frame until the closure returns
+///   Ok(())
+/// })?;
+/// ```
+///
+/// The queue lives on the JavaScript thread and must be dropped before its env is torn down, like
+/// by keeping it in a `#[napi]` class. The ArrayBuffers are external, runtimes not allowing
+/// external ArrayBuffers, like Electron, are not supported.
+pub struct FrameQueue {
+  env: sys::napi_env,
+  shared: Arc<QueueShared>,
+  buffers: Vec<sys::napi_ref>,
+}
+
+/// The producer side of a [`FrameQueue`], it can be sent to another thread.
+///
+/// Dropping it closes the queue, JavaScript still receives the frames queued before.
+pub struct FrameSender {
+  shared: Arc<QueueShared>,
+}
+
+impl FrameQueue {
+  /// Create a queue of `capacity` ArrayBuffers of `frame_size` bytes, and the sender filling them
+  pub fn new(
+    env: &Env,
+    capacity: usize,
+    frame_size: usize,
+    policy: OverflowPolicy,
+  ) -> Result<(FrameQueue, FrameSender)> {
+    let memory = SlotMemory::new(capacity, frame_size)?;
+    let buffers = memory.create_buffers(env.0, capacity)?;
+    let shared = Arc::new(QueueShared {
+      memory,
+      policy,
+      state: Mutex::new(QueueState {
+        free: (0..capacity).rev().collect(),
+        ready: VecDeque::with_capacity(capacity),
+        held: vec![false; capacity],
+        dropped: 0,
+        sender_closed: false,
+        receivers: 1,
+      }),
+      released: Condvar::new(),
+      #[cfg(all(feature = "napi3", not(target_family = "wasm")))]
+      waker: Mutex::new(None),
+    });
+    Ok((
+      FrameQueue {
+        env: env.0,
+        shared: shared.clone(),
+        buffers,
+      },
+      FrameSender { shared },
+    ))
+  }
+
+  pub fn capacity(&self) -> usize {
+    self.buffers.len()
+  }
+
+  pub fn frame_size(&self) -> usize {
+    self.shared.memory.slot_size()
+  }
+
+  pub fn policy(&self) -> OverflowPolicy {
+    self.shared.policy
+  }
+
+  /// The ArrayBuffer of the slot `index`, the same one on every call
+  pub fn buffer(&self, index: usize) -> Result<JsArrayBuffer> {
+    slot_buffer(self.env, &self.buffers, index)
+  }
+
+  /// Receive the oldest queued frame, `None` if there is none. The slot is held by JavaScript
+  /// until [`FrameQueue::release`].
+  pub fn poll(&self) -> Option<QueuedFrame> {
+    let mut state = self.shared.state();
+    let frame = state.ready.pop_front()?;
+    state.held[frame.index] = true;
+    Some(frame)
+  }
+
+  /// Hand the slot `index` received by [`FrameQueue::poll`] back to the producer, an `InvalidArg`
+  /// error if it's not held by JavaScript
+  pub fn release(&self, index: usize) -> Result<()> {
+    let mut state = self.shared.state();
+    match state.held.get_mut(index) {
+      Some(held) if *held => *held = false,
+      Some(_) => {
+        return Err(Error::new(
+          Status::InvalidArg,
+          format!("The slot {index} is not held by JavaScript"),
+        ))
+      }
+      None => return Err(out_of_range(index)),
+    }
+    state.free.push(index);
+    self.shared.released.notify_one();
+    Ok(())
+  }
+
+  /// The number of frames queued and not received yet
+  pub fn pending(&self) -> usize {
+    self.shared.state().ready.len()
+  }
+
+  /// The number of frames dropped by [`OverflowPolicy::DropOldest`] so far
+  pub fn dropped(&self) -> u64 {
+    self.shared.state().dropped
+  }
+
+  /// Whether the sender is dropped and all its frames are received
+  pub fn is_finished(&self) -> bool {
+    let state = self.shared.state();
+    state.sender_closed && state.ready.is_empty()
+  }
+
+  /// Call `callback` on the JavaScript thread with each frame as soon as it's queued, instead of
+  /// polling. The slot is released when `callback` returns, so `buffer` must not be used after
+  /// that.
+  ///
+  /// Replaces the previous subscription. An error returned by `callback` is thrown as an uncaught
+  /// exception. The subscription keeps the process alive until the sender is dropped.
+  #[cfg(all(feature = "napi3", not(target_family = "wasm")))]
+  pub fn on_frame<F>(&self, mut callback: F) -> Result<()>
+  where
+    F: 'static + FnMut(Env, QueuedFrame, JsArrayBuffer) -> Result<()>,
+  {
+    let env = Env::from_raw(self.env);
+    let shared = self.shared.clone();
+    let buffers = self
+      .buffers
+      .iter()
+      .map(|reference| {
+        let mut buffer = ptr::null_mut();
+        check_status!(unsafe { sys::napi_get_reference_value(self.env, *reference, &mut buffer) })?;
+        let mut held = ptr::null_mut();
+        check_status!(unsafe { sys::napi_create_reference(self.env, buffer, 1, &mut held) })?;
+        Ok(held)
+      })
+      .collect::<Result<Vec<_>>>()?;
+    shared.state().receivers += 1;
+    let receiver = FrameQueue {
+      env: self.env,
+      shared: shared.clone(),
+      buffers,
+    };
+    let waker = env.uv_async(move |env| {
+      while let Some(frame) = receiver.poll() {
+        let result = receiver
+          .buffer(frame.index)
+          .and_then(|buffer| callback(env, frame, buffer));
+        receiver.release(frame.index)?;
+        result?;
+      }
+      Ok(())
+    })?;
+    let mut current = shared.waker.lock().unwrap_or_else(|e| e.into_inner());
+    if shared.state().sender_closed {
+      // nothing more is coming, deliver what's queued and let the handle close
+      let _ = waker.send();
+      *current = None;
+    } else {
+      let _ = waker.send();
+      *current = Some(waker);
+    }
+    Ok(())
+  }
+}
+
+impl Drop for FrameQueue {
+  fn drop(&mut self) {
+    for reference in self.buffers.drain(..) {
+      unsafe { sys::napi_delete_reference(self.env, reference) };
+    }
+    self.shared.state().receivers -= 1;
+    self.shared.released.notify_all();
+  }
+}
+
+impl FrameSender {
+  /// A free slot to fill, `None` if there is none.
+  ///
+  /// With [`OverflowPolicy::DropOldest`], the oldest frame not received by JavaScript yet is
+  /// dropped to make room, `None` is only returned when JavaScript holds all the slots.
+  pub fn try_acquire(&mut self) -> Option<FrameSlot<'_>> {
+    let mut state = self.shared.state();
+    let index = self.take_slot(&mut state)?;
+    drop(state);
+    Some(FrameSlot {
+      sender: self,
+      index,
+    })
+  }
+
+  /// A free slot to fill, waiting up to `timeout` for JavaScript to release one.
+  ///
+  /// `None` if the timeout expires, or if the [`FrameQueue`] is dropped.
+  pub fn acquire(&mut self, timeout: Duration) -> Option<FrameSlot<'_>> {
+    let deadline = Instant::now() + timeout;
+    let mut state = self.shared.state();
+    let index = loop {
+      if state.receivers == 0 {
+        return None;
+      }
+      if let Some(index) = self.take_slot(&mut state) {
+        break index;
+      }
+      let now = Instant::now();
+      if now >= deadline {
+        return None;
+      }
+      state = self
+        .shared
+        .released
+        .wait_timeout(state, deadline - now)
+        .unwrap_or_else(|e| e.into_inner())
+        .0;
+    };
+    drop(state);
+    Some(FrameSlot {
+      sender: self,
+      index,
+    })
+  }
+
+  fn take_slot(&self, state: &mut QueueState) -> Option<usize> {
+    if let Some(index) = state.free.pop() {
+      return Some(index);
+    }
+    match self.shared.policy {
+      OverflowPolicy::DropOldest => {
+        let frame = state.ready.pop_front()?;
+        state.dropped += 1;
+        Some(frame.index)
+      }
+      OverflowPolicy::Backpressure => None,
+    }
+  }
+
+  /// Whether the [`FrameQueue`] is dropped, nothing is received anymore
+  pub fn is_closed(&self) -> bool {
+    self.shared.state().receivers == 0
+  }
+
+  pub fn frame_size(&self) -> usize {
+    self.shared.memory.slot_size()
+  }
+}
+
+impl Drop for FrameSender {
+  fn drop(&mut self) {
+    self.shared.state().sender_closed = true;
+    self.shared.wake();
+    #[cfg(all(feature = "napi3", not(target_family = "wasm")))]
+    self
+      .shared
+      .waker
+      .lock()
+      .unwrap_or_else(|e| e.into_inner())
+      .take();
+  }
+}
+
+/// A slot being filled, see [`FrameSender::try_acquire`].
+///
+/// Dropped without [`FrameSlot::commit`], the slot is free again.
+pub struct FrameSlot<'a> {
+  sender: &'a mut FrameSender,
+  index: usize,
+}
+
+impl FrameSlot<'_> {
+  pub fn index(&self) -> usize {
+    self.index
+  }
+
+  /// Queue the first `len` bytes of the slot as the frame at `timestamp`
+  pub fn commit(self, len: usize, timestamp: i64) {
+    let shared = &self.sender.shared;
+    shared.state().ready.push_back(QueuedFrame {
+      index: self.index,
+      len: len.min(shared.memory.slot_size()),
+      timestamp,
+    });
+    shared.wake();
+    // the slot is queued already
+    std::mem::forget(self);
+  }
+}
+
+impl Deref for FrameSlot<'_> {
+  type Target = [u8];
+
+  fn deref(&self) -> &[u8] {
+    unsafe { self.sender.shared.memory.slot(self.index) }
+  }
+}
+
+impl DerefMut for FrameSlot<'_> {
+  fn deref_mut(&mut self) -> &mut [u8] {
+    unsafe { self.sender.shared.memory.slot_mut(self.index) }
+  }
+}
+
+impl Drop for FrameSlot<'_> {
+  fn drop(&mut self) {
+    self.sender.shared.state().free.push(self.index);
+  }
+}
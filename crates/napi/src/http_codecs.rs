@@ -0,0 +1,368 @@
+//! Native codecs for the HTTP-adjacent conversions addons keep reimplementing:
+//! header lists <-> JS objects, URL query strings and `Cookie` headers.
+//!
+//! The parsers borrow from their input and only allocate when a component
+//! actually needs percent/plus decoding.
+//!
+//! With the `http_codecs` feature enabled, the JS facing helpers are exported
+//! automatically under the `httpCodecs` namespace of the addon. They are
+//! registered at module init rather than by `#[napi]`, so they are not part of
+//! the generated `.d.ts` and JS binding:
+//!
+//! ```js
+//! const { httpCodecs } = require('./index.node')
+//! httpCodecs.parseQueryString('a=1&a=2&b=%20') // { a: ['1', '2'], b: ' ' }
+//! httpCodecs.stringifyQueryString({ a: ['1', '2'], b: ' ' }) // 'a=1&a=2&b=%20'
+//! httpCodecs.parseCookie('foo=bar; baz="qux"') // { foo: 'bar', baz: 'qux' }
+//! httpCodecs.parseRawHeaders(['Accept', 'a', 'accept', 'b']) // { accept: 'a, b' }
+//! ```
+
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+  bindgen_prelude::{register_module_export, CallbackInfo, FromNapiValue, Object, ToNapiValue},
+  sys, Env, Error, JsObject, JsUnknown, Result, Status, ValueType,
+};
+
+/// Parse a URL query string into `(key, value)` pairs, in order.
+///
+/// A leading `?` is ignored, `+` is decoded as a space and malformed percent
+/// escapes are kept as-is.
+pub fn parse_query_string(input: &str) -> Vec<(Cow<'_, str>, Cow<'_, str>)> {
+  let input = input.strip_prefix('?').unwrap_or(input);
+  input
+    .split('&')
+    .filter(|pair| !pair.is_empty())
+    .map(|pair| {
+      let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+      (percent_decode(key, true), percent_decode(value, true))
+    })
+    .collect()
+}
+
+/// Serialize `(key, value)` pairs into a URL query string, escaping the same
+/// characters as Node.js `querystring.escape`.
+pub fn stringify_query_string<K, V, I>(pairs: I) -> String
+where
+  K: AsRef<str>,
+  V: AsRef<str>,
+  I: IntoIterator<Item = (K, V)>,
+{
+  let mut output = String::new();
+  for (key, value) in pairs {
+    if !output.is_empty() {
+      output.push('&');
+    }
+    percent_encode_into(key.as_ref(), &mut output);
+    output.push('=');
+    percent_encode_into(value.as_ref(), &mut output);
+  }
+  output
+}
+
+/// Parse a `Cookie` request header into `(name, value)` pairs, in order.
+///
+/// Surrounding double quotes are stripped from values and the values are
+/// percent decoded. Pairs without a `=` are skipped.
+pub fn parse_cookie(input: &str) -> Vec<(&str, Cow<'_, str>)> {
+  input
+    .split(';')
+    .filter_map(|pair| {
+      let (name, value) = pair.split_once('=')?;
+      let name = name.trim();
+      if name.is_empty() {
+        return None;
+      }
+      let value = value.trim();
+      let value = value
+        .strip_prefix('"')
+        .and_then(|v| v.strip_suffix('"'))
+        .unwrap_or(value);
+      Some((name, percent_decode(value, false)))
+    })
+    .collect()
+}
+
+/// Convert a header list into a JS object shaped like Node.js
+/// `IncomingMessage.headers`.
+///
+/// Names are lower-cased; repeated headers are joined with `, ` (`; ` for
+/// `cookie`), except `set-cookie` which is always an array.
+pub fn headers_to_object<K, V, I>(env: &Env, headers: I) -> Result<JsObject>
+where
+  K: AsRef<str>,
+  V: AsRef<str>,
+  I: IntoIterator<Item = (K, V)>,
+{
+  let mut grouped: Vec<(String, Vec<V>)> = Vec::new();
+  let mut index: HashMap<String, usize> = HashMap::new();
+  for (name, value) in headers {
+    let name = name.as_ref().to_ascii_lowercase();
+    match index.get(&name) {
+      Some(&i) => grouped[i].1.push(value),
+      None => {
+        index.insert(name.clone(), grouped.len());
+        grouped.push((name, vec![value]));
+      }
+    }
+  }
+
+  let mut object = env.create_object()?;
+  for (name, values) in grouped {
+    if is_forbidden_key(&name) {
+      continue;
+    }
+    let values = values.iter().map(|v| v.as_ref()).collect::<Vec<_>>();
+    if name == "set-cookie" {
+      object.set(name, values)?;
+    } else {
+      let separator = if name == "cookie" { "; " } else { ", " };
+      object.set(name, values.join(separator))?;
+    }
+  }
+  Ok(object)
+}
+
+/// Read a header list back from a JS object.
+///
+/// Array values produce one entry per element, other values are coerced to
+/// strings. `undefined` and `null` values are skipped.
+pub fn headers_from_object(object: &JsObject) -> Result<Vec<(String, String)>> {
+  let mut headers = Vec::new();
+  for name in Object::keys(object)? {
+    let value: JsUnknown = object.get_named_property_unchecked(&name)?;
+    for value in value_to_strings(value)? {
+      headers.push((name.clone(), value));
+    }
+  }
+  Ok(headers)
+}
+
+fn percent_decode(input: &str, plus_as_space: bool) -> Cow<'_, str> {
+  if !input.contains(|c| c == '%' || (plus_as_space && c == '+')) {
+    return Cow::Borrowed(input);
+  }
+  let bytes = input.as_bytes();
+  let mut decoded = Vec::with_capacity(bytes.len());
+  let mut i = 0;
+  while i < bytes.len() {
+    match bytes[i] {
+      b'+' if plus_as_space => decoded.push(b' '),
+      b'%' if i + 2 < bytes.len() => match (hex_value(bytes[i + 1]), hex_value(bytes[i + 2])) {
+        (Some(high), Some(low)) => {
+          decoded.push(high << 4 | low);
+          i += 2;
+        }
+        _ => decoded.push(b'%'),
+      },
+      byte => decoded.push(byte),
+    }
+    i += 1;
+  }
+  Cow::Owned(match String::from_utf8(decoded) {
+    Ok(decoded) => decoded,
+    Err(e) => String::from_utf8_lossy(e.as_bytes()).into_owned(),
+  })
+}
+
+fn hex_value(byte: u8) -> Option<u8> {
+  match byte {
+    b'0'..=b'9' => Some(byte - b'0'),
+    b'a'..=b'f' => Some(byte - b'a' + 10),
+    b'A'..=b'F' => Some(byte - b'A' + 10),
+    _ => None,
+  }
+}
+
+fn percent_encode_into(input: &str, output: &mut String) {
+  const HEX: &[u8; 16] = b"0123456789ABCDEF";
+  for &byte in input.as_bytes() {
+    match byte {
+      b'A'..=b'Z'
+      | b'a'..=b'z'
+      | b'0'..=b'9'
+      | b'-'
+      | b'_'
+      | b'.'
+      | b'!'
+      | b'~'
+      | b'*'
+      | b'\''
+      | b'('
+      | b')' => output.push(byte as char),
+      _ => {
+        output.push('%');
+        output.push(HEX[(byte >> 4) as usize] as char);
+        output.push(HEX[(byte & 0xf) as usize] as char);
+      }
+    }
+  }
+}
+
+// Setting `__proto__` through `napi_set_named_property` would replace the
+// prototype of the result object instead of creating an own property.
+fn is_forbidden_key(key: &str) -> bool {
+  key == "__proto__"
+}
+
+fn value_to_strings(value: JsUnknown) -> Result<Vec<String>> {
+  match value.get_type()? {
+    ValueType::Undefined | ValueType::Null => Ok(vec![]),
+    ValueType::Object if value.is_array()? => {
+      let array: JsObject = unsafe { value.cast() };
+      let len = array.get_array_length_unchecked()?;
+      let mut values = Vec::with_capacity(len as usize);
+      for i in 0..len {
+        let element: JsUnknown = array.get_element_unchecked(i)?;
+        values.extend(value_to_strings(element)?);
+      }
+      Ok(values)
+    }
+    _ => Ok(vec![value.coerce_to_string()?.into_utf8()?.into_owned()?]),
+  }
+}
+
+fn group_pairs<'a>(
+  pairs: Vec<(Cow<'a, str>, Cow<'a, str>)>,
+) -> Vec<(Cow<'a, str>, Vec<Cow<'a, str>>)> {
+  let mut grouped: Vec<(Cow<'a, str>, Vec<Cow<'a, str>>)> = Vec::with_capacity(pairs.len());
+  let mut index: HashMap<Cow<'a, str>, usize> = HashMap::with_capacity(pairs.len());
+  for (key, value) in pairs {
+    match index.get(&key) {
+      Some(&i) => grouped[i].1.push(value),
+      None => {
+        index.insert(key.clone(), grouped.len());
+        grouped.push((key, vec![value]));
+      }
+    }
+  }
+  grouped
+}
+
+unsafe fn parse_query_string_js(
+  env: sys::napi_env,
+  cb: sys::napi_callback_info,
+) -> Result<sys::napi_value> {
+  let cb = CallbackInfo::<1>::new(env, cb, Some(1), false)?;
+  let input = unsafe { String::from_napi_value(env, cb.get_arg(0))? };
+  let mut object = Env::from_raw(env).create_object()?;
+  for (key, values) in group_pairs(parse_query_string(&input)) {
+    if is_forbidden_key(&key) {
+      continue;
+    }
+    if values.len() == 1 {
+      object.set(&key, values[0].as_ref())?;
+    } else {
+      object.set(&key, values.iter().map(|v| v.as_ref()).collect::<Vec<_>>())?;
+    }
+  }
+  unsafe { JsObject::to_napi_value(env, object) }
+}
+
+unsafe fn stringify_query_string_js(
+  env: sys::napi_env,
+  cb: sys::napi_callback_info,
+) -> Result<sys::napi_value> {
+  let cb = CallbackInfo::<1>::new(env, cb, Some(1), false)?;
+  let input = unsafe { JsObject::from_napi_value(env, cb.get_arg(0))? };
+  let mut pairs = Vec::new();
+  for key in Object::keys(&input)? {
+    let value: JsUnknown = input.get_named_property_unchecked(&key)?;
+    // mirrors Node.js `querystring.stringify`: primitives are stringified,
+    // arrays are expanded and everything else becomes an empty string
+    let values = match value.get_type()? {
+      ValueType::Object if value.is_array()? => value_to_strings(value)?,
+      ValueType::Undefined
+      | ValueType::Null
+      | ValueType::Symbol
+      | ValueType::Object
+      | ValueType::Function
+      | ValueType::External
+      | ValueType::Unknown => vec![String::new()],
+      _ => vec![value.coerce_to_string()?.into_utf8()?.into_owned()?],
+    };
+    for value in values {
+      pairs.push((key.clone(), value));
+    }
+  }
+  unsafe { String::to_napi_value(env, stringify_query_string(pairs)) }
+}
+
+unsafe fn parse_cookie_js(
+  env: sys::napi_env,
+  cb: sys::napi_callback_info,
+) -> Result<sys::napi_value> {
+  let cb = CallbackInfo::<1>::new(env, cb, Some(1), false)?;
+  let header = unsafe { String::from_napi_value(env, cb.get_arg(0))? };
+  let mut object = Env::from_raw(env).create_object()?;
+  let mut seen = HashSet::new();
+  for (name, value) in parse_cookie(&header) {
+    // the first occurrence wins, same as the `cookie` package on npm
+    if is_forbidden_key(name) || !seen.insert(name) {
+      continue;
+    }
+    object.set(name, value.as_ref())?;
+  }
+  unsafe { JsObject::to_napi_value(env, object) }
+}
+
+unsafe fn parse_raw_headers_js(
+  env: sys::napi_env,
+  cb: sys::napi_callback_info,
+) -> Result<sys::napi_value> {
+  let cb = CallbackInfo::<1>::new(env, cb, Some(1), false)?;
+  let raw_headers = unsafe { Vec::<String>::from_napi_value(env, cb.get_arg(0))? };
+  if raw_headers.len() % 2 != 0 {
+    return Err(Error::new(
+      Status::InvalidArg,
+      format!(
+        "The raw headers must be name and value pairs, got {} elements",
+        raw_headers.len()
+      ),
+    ));
+  }
+  let headers = raw_headers
+    .chunks_exact(2)
+    .map(|pair| (pair[0].as_str(), pair[1].as_str()));
+  let object = headers_to_object(&Env::from_raw(env), headers)?;
+  unsafe { JsObject::to_napi_value(env, object) }
+}
+
+js_function!(
+  "parseQueryString",
+  parse_query_string_js,
+  parse_query_string_callback,
+  register_parse_query_string
+);
+js_function!(
+  "stringifyQueryString",
+  stringify_query_string_js,
+  stringify_query_string_callback,
+  register_stringify_query_string
+);
+js_function!(
+  "parseCookie",
+  parse_cookie_js,
+  parse_cookie_callback,
+  register_parse_cookie
+);
+js_function!(
+  "parseRawHeaders",
+  parse_raw_headers_js,
+  parse_raw_headers_callback,
+  register_parse_raw_headers
+);
+
+/// Export the codecs on the `httpCodecs` object of the addon
+pub(crate) fn register_exports() {
+  const JS_MOD: Option<&str> = Some("httpCodecs\0");
+  register_module_export(JS_MOD, "parseQueryString\0", register_parse_query_string);
+  register_module_export(
+    JS_MOD,
+    "stringifyQueryString\0",
+    register_stringify_query_string,
+  );
+  register_module_export(JS_MOD, "parseCookie\0", register_parse_cookie);
+  register_module_export(JS_MOD, "parseRawHeaders\0", register_parse_raw_headers);
+}
@@ -0,0 +1,220 @@
+use std::any::{Any, TypeId};
+#[cfg(feature = "napi6")]
+use std::cell::Cell;
+use std::cell::RefCell;
+use std::collections::HashMap;
+#[cfg(any(feature = "napi3", feature = "napi6"))]
+use std::ffi::c_void;
+#[cfg(feature = "napi6")]
+use std::ptr;
+use std::rc::Rc;
+
+#[cfg(any(feature = "napi3", feature = "napi6"))]
+use crate::check_status;
+use crate::{sys, Env, Result};
+
+type TypedData = HashMap<TypeId, Rc<dyn Any>>;
+
+/// The instance data of the addon in one env, set with `napi_set_instance_data`.
+///
+/// It holds the typed slots and the data of [`Env::set_instance_data`], which would otherwise
+/// replace each other.
+#[cfg(feature = "napi6")]
+#[derive(Default)]
+pub(crate) struct InstanceData {
+  typed: RefCell<TypedData>,
+  untyped: Cell<Option<UntypedInstanceData>>,
+}
+
+/// The data and finalizer passed to [`Env::set_instance_data`]
+#[cfg(feature = "napi6")]
+#[derive(Clone, Copy)]
+pub(crate) struct UntypedInstanceData {
+  pub(crate) data: *mut c_void,
+  pub(crate) hint: *mut c_void,
+  pub(crate) finalize: unsafe extern "C" fn(sys::napi_env, *mut c_void, *mut c_void),
+}
+
+#[cfg(feature = "napi6")]
+impl InstanceData {
+  /// The instance data of `env`, `None` if nothing was set yet
+  pub(crate) fn get(env: sys::napi_env) -> Result<Option<&'static InstanceData>> {
+    let mut data = ptr::null_mut();
+    check_status!(
+      unsafe { sys::napi_get_instance_data(env, &mut data) },
+      "Failed to get the instance data"
+    )?;
+    // it's only dropped when the env is torn down
+    Ok(unsafe { data.cast::<InstanceData>().as_ref() })
+  }
+
+  pub(crate) fn get_or_init(env: sys::napi_env) -> Result<&'static InstanceData> {
+    if let Some(instance_data) = Self::get(env)? {
+      return Ok(instance_data);
+    }
+    let data = Box::into_raw(Box::<InstanceData>::default());
+    if let Err(err) = check_status!(
+      unsafe {
+        sys::napi_set_instance_data(
+          env,
+          data.cast(),
+          Some(finalize_instance_data),
+          ptr::null_mut(),
+        )
+      },
+      "Failed to set the instance data"
+    ) {
+      drop(unsafe { Box::from_raw(data) });
+      return Err(err);
+    }
+    Ok(unsafe { &*data })
+  }
+
+  /// Replace the data of [`Env::set_instance_data`], the previous one is not finalized
+  pub(crate) fn set_untyped(&self, data: UntypedInstanceData) {
+    self.untyped.set(Some(data));
+  }
+
+  pub(crate) fn untyped(&self) -> Option<UntypedInstanceData> {
+    self.untyped.get()
+  }
+}
+
+#[cfg(not(feature = "napi6"))]
+thread_local! {
+  /// The typed instance data, keyed by env.
+  ///
+  /// Before `napi6` there is no instance data, the data stays on the thread of the env instead.
+  /// The cleanup hook drops it when the env is torn down, without `napi3` it is only dropped when
+  /// the thread exits.
+  static INSTANCE_DATA: RefCell<HashMap<usize, TypedData>> = Default::default();
+}
+
+/// Call `f` with the typed slots of `env`, creating them if `init` is set
+fn with_typed_data<R>(
+  env: sys::napi_env,
+  init: bool,
+  f: impl FnOnce(Option<&mut TypedData>) -> R,
+) -> Result<R> {
+  #[cfg(feature = "napi6")]
+  {
+    let instance_data = if init {
+      Some(InstanceData::get_or_init(env)?)
+    } else {
+      InstanceData::get(env)?
+    };
+    Ok(f(instance_data
+      .map(|data| data.typed.borrow_mut())
+      .as_deref_mut()))
+  }
+  #[cfg(not(feature = "napi6"))]
+  {
+    let key = env as usize;
+    #[cfg(feature = "napi3")]
+    if init && !INSTANCE_DATA.with(|data_map| data_map.borrow().contains_key(&key)) {
+      check_status!(
+        unsafe { sys::napi_add_env_cleanup_hook(env, Some(release_instance_data), env.cast()) },
+        "Failed to add the cleanup hook of the typed instance data"
+      )?;
+    }
+    Ok(INSTANCE_DATA.with(|data_map| {
+      let mut data_map = data_map.borrow_mut();
+      if init {
+        f(Some(data_map.entry(key).or_default()))
+      } else {
+        f(data_map.get_mut(&key))
+      }
+    }))
+  }
+}
+
+impl Env {
+  /// Associate `data` with this env in the slot of `T`, replacing the previous `T` only.
+  ///
+  /// Unlike [`Env::set_instance_data`], each type has its own slot, so independent parts of an
+  /// addon can keep their state side by side. The data is dropped when the env is torn down.
+  ///
+  /// The slots are kept in the Node-API instance data with `napi6`, next to the data of
+  /// [`Env::set_instance_data`]. Setting the instance data with `napi_set_instance_data` directly
+  /// drops them. Before `napi6` they are kept on the thread of the env, and without `napi3` only
+  /// dropped when that thread exits.
+  pub fn set_instance_data_typed<T: 'static>(&self, data: T) -> Result<Option<Rc<T>>> {
+    let data: Rc<dyn Any> = Rc::new(data);
+    let previous = with_typed_data(self.0, true, |typed| {
+      typed.and_then(|typed| typed.insert(TypeId::of::<T>(), data))
+    })?;
+    Ok(previous.map(downcast))
+  }
+
+  /// The data set by [`Env::set_instance_data_typed`] for `T`
+  pub fn get_instance_data_typed<T: 'static>(&self) -> Option<Rc<T>> {
+    with_typed_data(self.0, false, |typed| {
+      typed.and_then(|typed| typed.get(&TypeId::of::<T>()).cloned())
+    })
+    .ok()
+    .flatten()
+    .map(downcast)
+  }
+
+  /// The data set for `T`, or the one returned by `init` if there is none yet
+  pub fn get_or_init_instance_data_typed<T: 'static>(
+    &self,
+    init: impl FnOnce() -> Result<T>,
+  ) -> Result<Rc<T>> {
+    if let Some(data) = self.get_instance_data_typed::<T>() {
+      return Ok(data);
+    }
+    // `init` may set other slots, don't hold the map while calling it
+    let data = init()?;
+    self.set_instance_data_typed(data)?;
+    Ok(
+      self
+        .get_instance_data_typed::<T>()
+        .expect("The typed instance data was just set"),
+    )
+  }
+
+  /// Remove the data of `T` from this env
+  pub fn remove_instance_data_typed<T: 'static>(&self) -> Option<Rc<T>> {
+    with_typed_data(self.0, false, |typed| {
+      typed.and_then(|typed| typed.remove(&TypeId::of::<T>()))
+    })
+    .ok()
+    .flatten()
+    .map(downcast)
+  }
+}
+
+fn downcast<T: 'static>(data: Rc<dyn Any>) -> Rc<T> {
+  data
+    .downcast()
+    .unwrap_or_else(|_| unreachable!("The typed instance data is keyed by its TypeId"))
+}
+
+#[cfg(feature = "napi6")]
+unsafe extern "C" fn finalize_instance_data(
+  env: sys::napi_env,
+  data: *mut c_void,
+  _hint: *mut c_void,
+) {
+  let instance_data = data.cast::<InstanceData>();
+  // the `Drop` of the data may use the instance data too, keep it alive until every slot is gone
+  loop {
+    let released = unsafe { (*instance_data).typed.take() };
+    if released.is_empty() {
+      break;
+    }
+    drop(released);
+  }
+  if let Some(untyped) = unsafe { (*instance_data).untyped.take() } {
+    unsafe { (untyped.finalize)(env, untyped.data, untyped.hint) };
+  }
+  drop(unsafe { Box::from_raw(instance_data) });
+}
+
+#[cfg(all(feature = "napi3", not(feature = "napi6")))]
+unsafe extern "C" fn release_instance_data(env: *mut c_void) {
+  let released = INSTANCE_DATA.with(|data_map| data_map.borrow_mut().remove(&(env as usize)));
+  // the `Drop` of the data may use the instance data too
+  drop(released);
+}
@@ -72,6 +72,20 @@ impl TypeName for JsDataView {
   }
 }
 
+impl ValidateNapiValue for JsDataView {
+  unsafe fn validate(env: sys::napi_env, napi_val: sys::napi_value) -> Result<sys::napi_value> {
+    let mut is_dataview = false;
+    check_status!(unsafe { sys::napi_is_dataview(env, napi_val, &mut is_dataview) })?;
+    if !is_dataview {
+      return Err(Error::new(
+        Status::InvalidArg,
+        "Value is not a DataView".to_owned(),
+      ));
+    }
+    Ok(ptr::null_mut())
+  }
+}
+
 pub struct JsDataViewValue {
   pub arraybuffer: JsArrayBuffer,
   _data: *mut c_void,
@@ -149,6 +163,32 @@ impl From<TypedArrayType> for sys::napi_typedarray_type {
   }
 }
 
+/// The element types of the `TypedArray`s, see [`Env::create_external_typed_array`](crate::Env::create_external_typed_array)
+pub trait TypedArrayElement: Copy + 'static {
+  const TYPED_ARRAY_TYPE: TypedArrayType;
+}
+
+macro_rules! impl_typed_array_element {
+  ($rust_type:ty, $typed_array_type:expr) => {
+    impl TypedArrayElement for $rust_type {
+      const TYPED_ARRAY_TYPE: TypedArrayType = $typed_array_type;
+    }
+  };
+}
+
+impl_typed_array_element!(i8, TypedArrayType::Int8);
+impl_typed_array_element!(u8, TypedArrayType::Uint8);
+impl_typed_array_element!(i16, TypedArrayType::Int16);
+impl_typed_array_element!(u16, TypedArrayType::Uint16);
+impl_typed_array_element!(i32, TypedArrayType::Int32);
+impl_typed_array_element!(u32, TypedArrayType::Uint32);
+impl_typed_array_element!(f32, TypedArrayType::Float32);
+impl_typed_array_element!(f64, TypedArrayType::Float64);
+#[cfg(feature = "napi6")]
+impl_typed_array_element!(i64, TypedArrayType::BigInt64);
+#[cfg(feature = "napi6")]
+impl_typed_array_element!(u64, TypedArrayType::BigUint64);
+
 impl JsArrayBuffer {
   #[cfg(feature = "napi7")]
   pub fn detach(self) -> Result<()> {
@@ -356,7 +396,129 @@ impl_as_ref!(i64, TypedArrayType::BigInt64);
 #[cfg(feature = "napi6")]
 impl_as_ref!(u64, TypedArrayType::BigUint64);
 
+macro_rules! impl_dataview_accessors {
+  ($($ty:ident, $get:ident, $set:ident, $js_type:literal);* $(;)?) => {
+    $(
+      #[doc = concat!("Read the `", stringify!($ty), "` at `byte_offset`, like `DataView.prototype.get", $js_type, "`")]
+      pub fn $get(&self, byte_offset: usize, little_endian: bool) -> Result<$ty> {
+        let mut bytes = [0; std::mem::size_of::<$ty>()];
+        self.read(byte_offset, &mut bytes)?;
+        Ok(if little_endian {
+          $ty::from_le_bytes(bytes)
+        } else {
+          $ty::from_be_bytes(bytes)
+        })
+      }
+
+      #[doc = concat!("Write `value` at `byte_offset`, like `DataView.prototype.set", $js_type, "`")]
+      pub fn $set(&mut self, byte_offset: usize, value: $ty, little_endian: bool) -> Result<()> {
+        let bytes = if little_endian {
+          value.to_le_bytes()
+        } else {
+          value.to_be_bytes()
+        };
+        self.write(byte_offset, &bytes)
+      }
+    )*
+  };
+}
+
 impl JsDataView {
+  /// The number of bytes of the view
+  pub fn byte_length(&self) -> Result<usize> {
+    Ok(self.bytes()?.1)
+  }
+
+  /// The offset of the view in its `ArrayBuffer`
+  pub fn byte_offset(&self) -> Result<usize> {
+    let mut byte_offset = 0;
+    check_status!(unsafe {
+      sys::napi_get_dataview_info(
+        self.0.env,
+        self.0.value,
+        ptr::null_mut(),
+        ptr::null_mut(),
+        ptr::null_mut(),
+        &mut byte_offset,
+      )
+    })?;
+    Ok(byte_offset)
+  }
+
+  /// Read the `u8` at `byte_offset`, like `DataView.prototype.getUint8`
+  pub fn get_u8(&self, byte_offset: usize) -> Result<u8> {
+    let mut bytes = [0; 1];
+    self.read(byte_offset, &mut bytes)?;
+    Ok(bytes[0])
+  }
+
+  /// Write `value` at `byte_offset`, like `DataView.prototype.setUint8`
+  pub fn set_u8(&mut self, byte_offset: usize, value: u8) -> Result<()> {
+    self.write(byte_offset, &[value])
+  }
+
+  /// Read the `i8` at `byte_offset`, like `DataView.prototype.getInt8`
+  pub fn get_i8(&self, byte_offset: usize) -> Result<i8> {
+    self.get_u8(byte_offset).map(|value| value as i8)
+  }
+
+  /// Write `value` at `byte_offset`, like `DataView.prototype.setInt8`
+  pub fn set_i8(&mut self, byte_offset: usize, value: i8) -> Result<()> {
+    self.set_u8(byte_offset, value as u8)
+  }
+
+  impl_dataview_accessors!(
+    u16, get_u16, set_u16, "Uint16";
+    i16, get_i16, set_i16, "Int16";
+    u32, get_u32, set_u32, "Uint32";
+    i32, get_i32, set_i32, "Int32";
+    u64, get_u64, set_u64, "BigUint64";
+    i64, get_i64, set_i64, "BigInt64";
+    f32, get_f32, set_f32, "Float32";
+    f64, get_f64, set_f64, "Float64";
+  );
+
+  /// The data and the length of the view, `(null, 0)` once its `ArrayBuffer` is detached
+  fn bytes(&self) -> Result<(*mut u8, usize)> {
+    let mut length = 0;
+    let mut data = ptr::null_mut();
+    check_status!(unsafe {
+      sys::napi_get_dataview_info(
+        self.0.env,
+        self.0.value,
+        &mut length,
+        &mut data,
+        ptr::null_mut(),
+        ptr::null_mut(),
+      )
+    })?;
+    Ok((data.cast(), length))
+  }
+
+  /// The data at `byte_offset..byte_offset + size`, checked like the `DataView` methods
+  fn range(&self, byte_offset: usize, size: usize) -> Result<*mut u8> {
+    let (data, length) = self.bytes()?;
+    match byte_offset.checked_add(size) {
+      Some(end) if end <= length && !data.is_null() => Ok(unsafe { data.add(byte_offset) }),
+      _ => Err(Error::new(
+        Status::InvalidArg,
+        format!("Offset {byte_offset} is outside the bounds of the DataView of {length} bytes"),
+      )),
+    }
+  }
+
+  fn read(&self, byte_offset: usize, bytes: &mut [u8]) -> Result<()> {
+    let data = self.range(byte_offset, bytes.len())?;
+    unsafe { ptr::copy_nonoverlapping(data, bytes.as_mut_ptr(), bytes.len()) };
+    Ok(())
+  }
+
+  fn write(&mut self, byte_offset: usize, bytes: &[u8]) -> Result<()> {
+    let data = self.range(byte_offset, bytes.len())?;
+    unsafe { ptr::copy_nonoverlapping(bytes.as_ptr(), data, bytes.len()) };
+    Ok(())
+  }
+
   pub fn into_value(self) -> Result<JsDataViewValue> {
     let mut length = 0u64;
     let mut byte_offset = 0u64;
@@ -2,7 +2,10 @@ use std::convert::TryFrom;
 use std::ptr;
 
 use super::*;
-use crate::{bindgen_runtime::TypeName, check_status, sys, Result};
+use crate::{
+  bindgen_runtime::{BigInt, TypeName},
+  check_status, sys, Result,
+};
 
 #[derive(Clone, Copy)]
 pub struct JsBigInt {
@@ -183,7 +186,8 @@ impl NapiValue for JsBigInt {
   }
 }
 
-/// The BigInt will be converted losslessly when the value is over what an int64 could hold.
+/// The BigInt is truncated when the value is over what an int64 could hold, see
+/// [`JsBigInt::try_into_i64`] to fail instead.
 impl TryFrom<JsBigInt> for i64 {
   type Error = Error;
 
@@ -192,7 +196,8 @@ impl TryFrom<JsBigInt> for i64 {
   }
 }
 
-/// The BigInt will be converted losslessly when the value is over what an uint64 could hold.
+/// The BigInt is truncated when the value is over what an uint64 could hold, see
+/// [`JsBigInt::try_into_u64`] to fail instead.
 impl TryFrom<JsBigInt> for u64 {
   type Error = Error;
 
@@ -203,27 +208,56 @@ impl TryFrom<JsBigInt> for u64 {
 
 impl JsBigInt {
   /// <https://nodejs.org/api/n-api.html#n_api_napi_get_value_bigint_words>
-  pub fn get_words(&mut self) -> Result<(bool, Vec<u64>)> {
+  ///
+  /// The sign, `true` for negative values, and the 64-bit words of the absolute value from the
+  /// least significant one.
+  pub fn get_words(&self) -> Result<(bool, Vec<u64>)> {
     let mut words: Vec<u64> = Vec::with_capacity(self.word_count);
-    let word_count = &mut self.word_count;
+    let mut word_count = self.word_count;
     let mut sign_bit = 0;
     check_status!(unsafe {
       sys::napi_get_value_bigint_words(
         self.raw.env,
         self.raw.value,
         &mut sign_bit,
-        word_count,
+        &mut word_count,
         words.as_mut_ptr(),
       )
     })?;
 
     unsafe {
-      words.set_len(self.word_count);
+      words.set_len(word_count);
     };
 
     Ok((sign_bit == 1, words))
   }
 
+  /// The value as an `i64`, an `InvalidArg` error if it doesn't fit instead of the `lossless` flag
+  /// of [`JsBigInt::get_i64`]
+  pub fn try_into_i64(&self) -> Result<i64> {
+    self.read_bigint()?.try_into_i64()
+  }
+
+  /// The value as a `u64`, an `InvalidArg` error if it's negative or doesn't fit
+  pub fn try_into_u64(&self) -> Result<u64> {
+    self.read_bigint()?.try_into_u64()
+  }
+
+  /// The value as an `i128`, an `InvalidArg` error if it doesn't fit
+  pub fn try_into_i128(&self) -> Result<i128> {
+    self.read_bigint()?.try_into_i128()
+  }
+
+  /// The value as a `u128`, an `InvalidArg` error if it's negative or doesn't fit
+  pub fn try_into_u128(&self) -> Result<u128> {
+    self.read_bigint()?.try_into_u128()
+  }
+
+  fn read_bigint(&self) -> Result<BigInt> {
+    let (sign_bit, words) = self.get_words()?;
+    Ok(BigInt { sign_bit, words })
+  }
+
   pub fn get_u64(&self) -> Result<(u64, bool)> {
     let mut val: u64 = 0;
     let mut lossless = false;
@@ -243,7 +277,7 @@ impl JsBigInt {
     Ok((val, lossless))
   }
 
-  pub fn get_i128(&mut self) -> Result<(i128, bool)> {
+  pub fn get_i128(&self) -> Result<(i128, bool)> {
     let (signed, words) = self.get_words()?;
 
     let low_part = words.first().copied().unwrap_or(0).to_ne_bytes();
@@ -277,7 +311,7 @@ impl JsBigInt {
     Ok((val, loss))
   }
 
-  pub fn get_u128(&mut self) -> Result<(bool, u128, bool)> {
+  pub fn get_u128(&self) -> Result<(bool, u128, bool)> {
     let (signed, words) = self.get_words()?;
 
     let low_part = words.first().copied().unwrap_or(0).to_ne_bytes();
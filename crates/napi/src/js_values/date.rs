@@ -1,4 +1,5 @@
 use std::ptr;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 use super::check_status;
 use crate::{
@@ -39,4 +40,61 @@ impl JsDate {
     check_status!(unsafe { sys::napi_get_date_value(self.0.env, self.0.value, &mut timestamp) })?;
     Ok(timestamp)
   }
+
+  /// The time of this `Date`, an error if it's an invalid date
+  pub fn to_system_time(&self) -> Result<SystemTime> {
+    let millis = self.valid_millis()?;
+    let since_epoch = Duration::from_millis(millis.unsigned_abs());
+    Ok(if millis < 0 {
+      UNIX_EPOCH - since_epoch
+    } else {
+      UNIX_EPOCH + since_epoch
+    })
+  }
+
+  /// The time of this `Date` in UTC, an error if it's an invalid date
+  #[cfg(feature = "chrono_date")]
+  pub fn to_chrono(&self) -> Result<chrono::DateTime<chrono::Utc>> {
+    let millis = self.valid_millis()?;
+    chrono::DateTime::from_timestamp_millis(millis).ok_or_else(|| {
+      Error::new(
+        Status::InvalidArg,
+        format!("The Date {millis} is out of the range of `DateTime`"),
+      )
+    })
+  }
+
+  /// The milliseconds since the epoch, a valid `Date` always holds an integral number of them
+  fn valid_millis(&self) -> Result<i64> {
+    let millis = self.value_of()?;
+    if millis.is_nan() {
+      return Err(Error::new(
+        Status::InvalidArg,
+        "Expected a valid Date, got Invalid Date".to_owned(),
+      ));
+    }
+    Ok(millis as i64)
+  }
+}
+
+/// The largest number of milliseconds from the epoch a `Date` can hold, in both directions
+pub(crate) const MAX_DATE_MILLIS: i64 = 8_640_000_000_000_000;
+
+/// The milliseconds since the epoch of `time`, rounded down like `Date` does, `None` if it's out of
+/// the range of `Date`
+pub(crate) fn system_time_to_millis(time: SystemTime) -> Option<i64> {
+  let millis = match time.duration_since(UNIX_EPOCH) {
+    Ok(after) => i64::try_from(after.as_millis()).ok()?,
+    Err(before) => {
+      let before = before.duration();
+      let whole = i64::try_from(before.as_millis()).ok()?;
+      // rounding down a time before the epoch moves it further from the epoch
+      if before.subsec_nanos() % 1_000_000 == 0 {
+        -whole
+      } else {
+        -whole - 1
+      }
+    }
+  };
+  (millis.abs() <= MAX_DATE_MILLIS).then_some(millis)
 }
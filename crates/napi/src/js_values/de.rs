@@ -36,7 +36,11 @@ impl<'x, 'de, 'env> serde::de::Deserializer<'x> for &'de mut De<'env> {
       ValueType::Number => {
         let js_number: f64 =
           unsafe { JsNumber::from_raw_unchecked(self.0.env, self.0.value).try_into()? };
-        if (js_number.trunc() - js_number).abs() < f64::EPSILON {
+        // `-0` and the integers out of the `i64` range stay `f64`, `as` would lose them
+        if js_number.fract() == 0.0
+          && !(js_number == 0.0 && js_number.is_sign_negative())
+          && (i64::MIN as f64..i64::MAX as f64).contains(&js_number)
+        {
           visitor.visit_i64(js_number as i64)
         } else {
           visitor.visit_f64(js_number)
@@ -72,7 +76,7 @@ impl<'x, 'de, 'env> serde::de::Deserializer<'x> for &'de mut De<'env> {
       }
       #[cfg(feature = "napi6")]
       ValueType::BigInt => {
-        let mut js_bigint = unsafe { JsBigInt::from_raw(self.0.env, self.0.value)? };
+        let js_bigint = unsafe { JsBigInt::from_raw(self.0.env, self.0.value)? };
 
         let (signed, words) = js_bigint.get_words()?;
         let word_sized = words.len() < 2;
@@ -71,6 +71,11 @@ impl DeferredTrace {
         "Delete error reference in `to_napi_value` failed"
       )?;
       err_value
+    } else if let Some(js_error_value) = err.js_error_value {
+      // keep the stack of the call, as an instance of the `#[napi(error)]` class
+      obj.set_named_property("message", js_error_value.message())?;
+      unsafe { js_error_value.init_js_error(raw_env, raw)? };
+      Ok(raw)
     } else {
       obj.set_named_property("message", &err.reason)?;
       obj.set_named_property(
@@ -93,7 +98,25 @@ struct DeferredData<Data: ToNapiValue, Resolver: FnOnce(Env) -> Result<Data>> {
   trace: DeferredTrace,
 }
 
-pub struct JsDeferred<Data: ToNapiValue, Resolver: FnOnce(Env) -> Result<Data>> {
+/// The resolver of a [`JsDeferred`] when its type isn't named, so a `JsDeferred<T>` can be stored
+/// in a struct or sent to another thread while it's waiting for the value
+pub type DeferredResolver<Data> = Box<dyn FnOnce(Env) -> Result<Data> + Send>;
+
+/// The resolving side of a promise created by [`Env::create_deferred`], it can be moved to any
+/// thread and settles the promise on the JavaScript thread.
+///
+/// ```rust
+/// #[napi(ts_return_type = "Promise<number>")]
+/// pub fn answer(env: Env) -> Result<JsObject> {
+///   let (deferred, promise): (JsDeferred<u32>, _) = env.create_deferred()?;
+///   std::thread::spawn(move || deferred.resolve_value(42));
+///   Ok(promise)
+/// }
+/// ```
+pub struct JsDeferred<
+  Data: ToNapiValue,
+  Resolver: FnOnce(Env) -> Result<Data> = DeferredResolver<Data>,
+> {
   pub(crate) tsfn: sys::napi_threadsafe_function,
   #[cfg(feature = "deferred_trace")]
   trace: DeferredTrace,
@@ -178,6 +201,13 @@ impl<Data: ToNapiValue, Resolver: FnOnce(Env) -> Result<Data>> JsDeferred<Data,
   }
 }
 
+impl<Data: 'static + Send + ToNapiValue> JsDeferred<Data> {
+  /// Consumes the deferred, and resolves the promise with `value`.
+  pub fn resolve_value(self, value: Data) {
+    self.resolve(Box::new(move |_| Ok(value)))
+  }
+}
+
 fn js_deferred_new_raw(
   env: sys::napi_env,
   resolve_deferred: sys::napi_threadsafe_function_call_js,
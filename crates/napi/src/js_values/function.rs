@@ -6,7 +6,10 @@ use crate::{
   bindgen_runtime::JsValuesTupleIntoVec,
   threadsafe_function::{ThreadsafeCallContext, ThreadsafeFunction},
 };
-use crate::{bindgen_runtime::TypeName, JsString};
+use crate::{
+  bindgen_runtime::{ArgsBuffer, TypeName},
+  JsString,
+};
 use crate::{check_pending_exception, ValueType};
 use crate::{sys, Env, Error, JsObject, JsUnknown, NapiRaw, NapiValue, Result, Status};
 
@@ -55,7 +58,7 @@ impl JsFunction {
     let raw_args = args
       .iter()
       .map(|arg| unsafe { arg.raw() })
-      .collect::<Vec<sys::napi_value>>();
+      .collect::<ArgsBuffer>();
     let mut return_value = ptr::null_mut();
     check_pending_exception!(self.0.env, unsafe {
       sys::napi_call_function(
@@ -110,7 +113,7 @@ impl JsFunction {
     let raw_args = args
       .iter()
       .map(|arg| unsafe { arg.raw() })
-      .collect::<Vec<sys::napi_value>>();
+      .collect::<ArgsBuffer>();
     check_pending_exception!(self.0.env, unsafe {
       sys::napi_new_instance(
         self.0.env,
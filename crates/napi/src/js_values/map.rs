@@ -0,0 +1,193 @@
+use std::collections::HashMap;
+use std::hash::{BuildHasher, Hash};
+use std::marker::PhantomData;
+use std::ptr;
+
+use crate::{
+  bindgen_runtime::{
+    get_global_constructor, validate_instance_of, FromNapiValue, Function, ToNapiValue, TypeName,
+    Unknown, ValidateNapiValue,
+  },
+  check_status, sys, Env, JsObject, NapiRaw, NapiValue, Result, Value, ValueType,
+};
+
+/// Represents a JavaScript [`Map`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Map) object.
+///
+/// Create it with `Env::create_map` or `Env::create_map_from`, or receive it as a function
+/// argument. Unlike a `HashMap`, which is converted from and to a plain object, its keys can be any
+/// JavaScript value and keep their insertion order.
+pub struct JsMap(pub(crate) Value);
+
+impl TypeName for JsMap {
+  fn type_name() -> &'static str {
+    "Map"
+  }
+
+  fn value_type() -> ValueType {
+    ValueType::Object
+  }
+}
+
+impl ValidateNapiValue for JsMap {
+  unsafe fn validate(env: sys::napi_env, napi_val: sys::napi_value) -> Result<sys::napi_value> {
+    validate_instance_of(env, napi_val, "Map")
+  }
+}
+
+impl JsMap {
+  /// The value of `key`, `None` if there is none or if it's `undefined`
+  pub fn get<K: ToNapiValue, V: FromNapiValue>(&self, key: K) -> Result<Option<V>> {
+    let get: Function<K, Unknown> = self.get_named_property_unchecked("get")?;
+    let value = get.apply(self, key)?;
+    if value.get_type()? == ValueType::Undefined {
+      return Ok(None);
+    }
+    unsafe { V::from_napi_value(self.0.env, value.raw()) }.map(Some)
+  }
+
+  /// Set the value of `key`, replacing the previous one
+  pub fn set<K: ToNapiValue, V: ToNapiValue>(&mut self, key: K, value: V) -> Result<()> {
+    let set: Function<(K, V), Unknown> = self.get_named_property_unchecked("set")?;
+    set.apply(&*self, (key, value))?;
+    Ok(())
+  }
+
+  pub fn has<K: ToNapiValue>(&self, key: K) -> Result<bool> {
+    let has: Function<K, bool> = self.get_named_property_unchecked("has")?;
+    has.apply(self, key)
+  }
+
+  /// Remove `key`, `false` if there was no such key
+  pub fn delete<K: ToNapiValue>(&mut self, key: K) -> Result<bool> {
+    let delete: Function<K, bool> = self.get_named_property_unchecked("delete")?;
+    delete.apply(&*self, key)
+  }
+
+  pub fn clear(&mut self) -> Result<()> {
+    let clear: Function<(), Unknown> = self.get_named_property_unchecked("clear")?;
+    clear.apply(&*self, ())?;
+    Ok(())
+  }
+
+  /// The number of entries
+  pub fn size(&self) -> Result<u32> {
+    self.get_named_property_unchecked("size")
+  }
+
+  /// Iterate over the entries in insertion order, converting each of them to `(K, V)`.
+  ///
+  /// Like `Map.prototype.entries`, the entries added during the iteration are visited too.
+  pub fn entries<K: FromNapiValue, V: FromNapiValue>(&self) -> Result<JsMapEntries<K, V>> {
+    let entries: Function<(), JsObject> = self.get_named_property_unchecked("entries")?;
+    let iterator = entries.apply(self, ())?;
+    let next: Unknown = iterator.get_named_property_unchecked("next")?;
+    Ok(JsMapEntries {
+      env: self.0.env,
+      iterator: unsafe { iterator.raw() },
+      next: unsafe { next.raw() },
+      done: false,
+      _phantom: PhantomData,
+    })
+  }
+
+  /// Collect the entries into a `HashMap`, the later of the keys converted to the same `K` wins
+  pub fn to_hash_map<K, V, S>(&self) -> Result<HashMap<K, V, S>>
+  where
+    K: FromNapiValue + Eq + Hash,
+    V: FromNapiValue,
+    S: Default + BuildHasher,
+  {
+    self.entries()?.collect()
+  }
+}
+
+impl<K, V, S> TryFrom<JsMap> for HashMap<K, V, S>
+where
+  K: FromNapiValue + Eq + Hash,
+  V: FromNapiValue,
+  S: Default + BuildHasher,
+{
+  type Error = crate::Error;
+
+  fn try_from(map: JsMap) -> Result<Self> {
+    map.to_hash_map()
+  }
+}
+
+/// The entries of a [`JsMap`], see [`JsMap::entries`]
+pub struct JsMapEntries<K, V> {
+  env: sys::napi_env,
+  iterator: sys::napi_value,
+  next: sys::napi_value,
+  done: bool,
+  _phantom: PhantomData<(K, V)>,
+}
+
+impl<K: FromNapiValue, V: FromNapiValue> JsMapEntries<K, V> {
+  fn next_entry(&mut self) -> Result<Option<(K, V)>> {
+    let mut result = ptr::null_mut();
+    check_status!(
+      unsafe {
+        sys::napi_call_function(
+          self.env,
+          self.iterator,
+          self.next,
+          0,
+          ptr::null(),
+          &mut result,
+        )
+      },
+      "Failed to get the next entry of the Map"
+    )?;
+    let result = unsafe { JsObject::from_raw_unchecked(self.env, result) };
+    if result.get_named_property_unchecked::<bool>("done")? {
+      return Ok(None);
+    }
+    let entry: JsObject = result.get_named_property_unchecked("value")?;
+    let key = entry.get_element::<Unknown>(0)?;
+    let value = entry.get_element::<Unknown>(1)?;
+    unsafe {
+      Ok(Some((
+        K::from_napi_value(self.env, key.raw())?,
+        V::from_napi_value(self.env, value.raw())?,
+      )))
+    }
+  }
+}
+
+impl<K: FromNapiValue, V: FromNapiValue> Iterator for JsMapEntries<K, V> {
+  type Item = Result<(K, V)>;
+
+  fn next(&mut self) -> Option<Self::Item> {
+    if self.done {
+      return None;
+    }
+    let entry = self.next_entry();
+    // stop after the last entry or the first error
+    self.done = !matches!(entry, Ok(Some(_)));
+    entry.transpose()
+  }
+}
+
+impl Env {
+  /// Create an empty `Map`, same as `new Map()` in JavaScript
+  pub fn create_map(&self) -> Result<JsMap> {
+    let ctor = get_global_constructor::<()>(self.0, "Map")?;
+    let map = ctor.new_instance(())?;
+    Ok(unsafe { JsMap::from_raw_unchecked(self.0, map.raw()) })
+  }
+
+  /// Create a `Map` with `entries` in order, like from a `HashMap` or a `BTreeMap`
+  pub fn create_map_from<K, V, I>(&self, entries: I) -> Result<JsMap>
+  where
+    K: ToNapiValue,
+    V: ToNapiValue,
+    I: IntoIterator<Item = (K, V)>,
+  {
+    let mut map = self.create_map()?;
+    for (key, value) in entries {
+      map.set(key, value)?;
+    }
+    Ok(map)
+  }
+}
@@ -7,8 +7,10 @@ use std::ffi::CString;
 use std::ptr;
 
 use crate::{
-  bindgen_runtime::{FromNapiValue, ToNapiValue, TypeName, ValidateNapiValue},
-  check_status, sys, type_of, Callback, Error, Result, Status, ValueType,
+  bindgen_runtime::{
+    get_global_constructor, FromNapiValue, Function, ToNapiValue, TypeName, ValidateNapiValue,
+  },
+  check_status, sys, type_of, Callback, Env, Error, Result, Status, ValueType,
 };
 
 #[cfg(feature = "serde-json")]
@@ -29,9 +31,12 @@ mod either;
 mod escapable_handle_scope;
 mod function;
 mod global;
+mod map;
 mod number;
 mod object;
 mod object_property;
+mod regexp;
+mod set;
 mod string;
 mod tagged_object;
 mod undefined;
@@ -53,11 +58,14 @@ pub use either::Either;
 pub use escapable_handle_scope::EscapableHandleScope;
 pub use function::JsFunction;
 pub use global::*;
+pub use map::*;
 pub use number::JsNumber;
 pub use object::*;
 pub use object_property::*;
+pub use regexp::*;
 #[cfg(feature = "serde-json")]
 pub use ser::Ser;
+pub use set::*;
 pub use string::*;
 pub(crate) use tagged_object::TaggedObject;
 pub use undefined::JsUndefined;
@@ -98,6 +106,45 @@ impl TypeName for JsSymbol {
 
 impl ValidateNapiValue for JsSymbol {}
 
+/// The symbols built in JavaScript, see [`Env::get_well_known_symbol`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WellKnownSymbol {
+  AsyncIterator,
+  HasInstance,
+  IsConcatSpreadable,
+  Iterator,
+  Match,
+  MatchAll,
+  Replace,
+  Search,
+  Species,
+  Split,
+  ToPrimitive,
+  ToStringTag,
+  Unscopables,
+}
+
+impl WellKnownSymbol {
+  /// The name of the symbol on the `Symbol` global, like `toStringTag` for `Symbol.toStringTag`
+  pub fn name(self) -> &'static str {
+    match self {
+      WellKnownSymbol::AsyncIterator => "asyncIterator",
+      WellKnownSymbol::HasInstance => "hasInstance",
+      WellKnownSymbol::IsConcatSpreadable => "isConcatSpreadable",
+      WellKnownSymbol::Iterator => "iterator",
+      WellKnownSymbol::Match => "match",
+      WellKnownSymbol::MatchAll => "matchAll",
+      WellKnownSymbol::Replace => "replace",
+      WellKnownSymbol::Search => "search",
+      WellKnownSymbol::Species => "species",
+      WellKnownSymbol::Split => "split",
+      WellKnownSymbol::ToPrimitive => "toPrimitive",
+      WellKnownSymbol::ToStringTag => "toStringTag",
+      WellKnownSymbol::Unscopables => "unscopables",
+    }
+  }
+}
+
 #[deprecated(since = "3.0.0", note = "Please use `External` instead")]
 pub struct JsExternal(pub(crate) Value);
 
@@ -268,6 +315,13 @@ macro_rules! impl_js_value_methods {
         Ok(is_buffer)
       }
 
+      /// `value instanceof RegExp`, Node-API has no dedicated check for it.
+      ///
+      /// So a `RegExp` created in another realm, like with `vm.runInNewContext`, is not detected.
+      pub fn is_regexp(&self) -> Result<bool> {
+        is_instance_of_global(self.0.env, self.0.value, "RegExp")
+      }
+
       pub fn instanceof<Constructor>(&self, constructor: Constructor) -> Result<bool>
       where
         Constructor: NapiRaw,
@@ -377,6 +431,43 @@ macro_rules! impl_object_methods {
         unsafe { <T as FromNapiValue>::from_napi_value(self.0.env, raw_value) }
       }
 
+      /// The property keyed by the symbol `key`, like `obj[Symbol.toStringTag]`
+      pub fn get_symbol_property<T>(&self, key: &JsSymbol) -> Result<T>
+      where
+        T: FromNapiValue + ValidateNapiValue,
+      {
+        let mut raw_value = ptr::null_mut();
+        check_status!(
+          unsafe { sys::napi_get_property(self.0.env, self.0.value, key.0.value, &mut raw_value) },
+          "get_symbol_property error"
+        )?;
+        unsafe { <T as ValidateNapiValue>::validate(self.0.env, raw_value) }.map_err(
+          |mut err| {
+            err.reason = format!("Symbol-keyed property type mismatch. {}", err.reason);
+            err
+          },
+        )?;
+        unsafe { <T as FromNapiValue>::from_napi_value(self.0.env, raw_value) }
+      }
+
+      pub fn set_symbol_property<V>(&mut self, key: &JsSymbol, value: V) -> Result<()>
+      where
+        V: ToNapiValue,
+      {
+        let raw_value = unsafe { V::to_napi_value(self.0.env, value)? };
+        check_status!(unsafe {
+          sys::napi_set_property(self.0.env, self.0.value, key.0.value, raw_value)
+        })
+      }
+
+      pub fn has_own_symbol_property(&self, key: &JsSymbol) -> Result<bool> {
+        let mut result = false;
+        check_status!(unsafe {
+          sys::napi_has_own_property(self.0.env, self.0.value, key.0.value, &mut result)
+        })?;
+        Ok(result)
+      }
+
       pub fn get_named_property_unchecked<T>(&self, name: &str) -> Result<T>
       where
         T: FromNapiValue,
@@ -504,6 +595,53 @@ macro_rules! impl_object_methods {
         Ok(unsafe { JsObject::from_raw_unchecked(self.0.env, properties_value) })
       }
 
+      /// The keys of [`get_all_property_names`](Self::get_all_property_names), symbols included
+      /// unless filtered out by `KeyFilter::SkipSymbols`.
+      ///
+      /// ```rust,ignore
+      /// // the own enumerable string keys, like `Object.keys`
+      /// let keys = obj.get_property_keys(
+      ///   KeyCollectionMode::OwnOnly,
+      ///   KeyFilter::Enumerable | KeyFilter::SkipSymbols,
+      ///   KeyConversion::NumbersToStrings,
+      /// )?;
+      /// ```
+      #[cfg(feature = "napi6")]
+      pub fn get_property_keys(
+        &self,
+        mode: KeyCollectionMode,
+        filter: KeyFilter,
+        conversion: KeyConversion,
+      ) -> Result<Vec<PropertyKey>> {
+        let names = self.get_all_property_names(mode, filter, conversion)?;
+        let len = names.get_array_length_unchecked()?;
+        let mut keys = Vec::with_capacity(len as usize);
+        for index in 0..len {
+          let mut key = ptr::null_mut();
+          check_status!(unsafe {
+            sys::napi_get_element(self.0.env, names.0.value, index, &mut key)
+          })?;
+          keys.push(match type_of!(self.0.env, key)? {
+            ValueType::String => {
+              PropertyKey::String(unsafe { JsString::from_raw_unchecked(self.0.env, key) })
+            }
+            ValueType::Symbol => {
+              PropertyKey::Symbol(unsafe { JsSymbol::from_raw_unchecked(self.0.env, key) })
+            }
+            ValueType::Number => {
+              PropertyKey::Number(unsafe { u32::from_napi_value(self.0.env, key)? })
+            }
+            value_type => {
+              return Err(Error::new(
+                Status::GenericFailure,
+                format!("Unexpected property key of type {}", value_type),
+              ))
+            }
+          });
+        }
+        Ok(keys)
+      }
+
       /// This returns the equivalent of `Object.getPrototypeOf` (which is not the same as the function's prototype property).
       pub fn get_prototype<T>(&self) -> Result<T>
       where
@@ -605,6 +743,12 @@ macro_rules! impl_object_methods {
         })
       }
 
+      /// Define the accessor property `name`, implemented by the Rust closures of `accessor`
+      #[cfg(feature = "napi5")]
+      pub fn define_property(&mut self, name: &str, accessor: Accessor) -> Result<()> {
+        unsafe { accessor.define(self.0.env, self.0.value, name) }
+      }
+
       /// Perform `is_array` check before get the length
       /// if `Object` is not array, `ArrayExpected` error returned
       pub fn get_array_length(&self) -> Result<u32> {
@@ -635,10 +779,41 @@ macro_rules! impl_object_methods {
       pub fn seal(&mut self) -> Result<()> {
         check_status!(unsafe { sys::napi_object_seal(self.0.env, self.0.value) })
       }
+
+      /// `Object.isFrozen(object)`
+      pub fn is_frozen(&self) -> Result<bool> {
+        object_integrity_check(self.0.env, self.0.value, "isFrozen")
+      }
+
+      /// `Object.isSealed(object)`
+      pub fn is_sealed(&self) -> Result<bool> {
+        object_integrity_check(self.0.env, self.0.value, "isSealed")
+      }
     }
   };
 }
 
+/// `value instanceof globalThis[class]`
+fn is_instance_of_global(env: sys::napi_env, value: sys::napi_value, class: &str) -> Result<bool> {
+  let ctor = get_global_constructor::<()>(env, class)?;
+  let mut result = false;
+  check_status!(unsafe { sys::napi_instanceof(env, value, ctor.raw(), &mut result) })?;
+  Ok(result)
+}
+
+/// Node-API has no way to query the integrity level of an object, so ask the `Object` global
+fn object_integrity_check(
+  env: sys::napi_env,
+  value: sys::napi_value,
+  method: &str,
+) -> Result<bool> {
+  let object_ctor: JsObject = Env::from_raw(env)
+    .get_global()?
+    .get_named_property_unchecked("Object")?;
+  let check: Function<JsUnknown, bool> = object_ctor.get_named_property_unchecked(method)?;
+  check.call(unsafe { JsUnknown::from_raw_unchecked(env, value) })
+}
+
 pub trait NapiRaw {
   #[allow(clippy::missing_safety_doc)]
   unsafe fn raw(&self) -> sys::napi_value;
@@ -664,6 +839,9 @@ impl_js_value_methods!(JsNumber);
 impl_js_value_methods!(JsString);
 impl_js_value_methods!(JsObject);
 impl_js_value_methods!(JsGlobal);
+impl_js_value_methods!(JsRegExp);
+impl_js_value_methods!(JsMap);
+impl_js_value_methods!(JsSet);
 #[cfg(feature = "napi5")]
 impl_js_value_methods!(JsDate);
 impl_js_value_methods!(JsFunction);
@@ -678,6 +856,9 @@ impl_object_methods!(JsArrayBuffer);
 impl_object_methods!(JsTypedArray);
 impl_object_methods!(JsDataView);
 impl_object_methods!(JsGlobal);
+impl_object_methods!(JsRegExp);
+impl_object_methods!(JsMap);
+impl_object_methods!(JsSet);
 impl_object_methods!(JSON);
 
 use ValueType::*;
@@ -693,6 +874,9 @@ impl_napi_value_trait!(JsNumber, Number);
 impl_napi_value_trait!(JsString, String);
 impl_napi_value_trait!(JsObject, Object);
 impl_napi_value_trait!(JsGlobal, Object);
+impl_napi_value_trait!(JsRegExp, Object);
+impl_napi_value_trait!(JsMap, Object);
+impl_napi_value_trait!(JsSet, Object);
 #[cfg(feature = "napi5")]
 impl_napi_value_trait!(JsDate, Object);
 impl_napi_value_trait!(JsTimeout, Object);
@@ -1,5 +1,8 @@
 #[cfg(feature = "napi6")]
 use std::convert::TryFrom;
+
+#[cfg(feature = "napi6")]
+use bitflags::bitflags;
 #[cfg(feature = "napi5")]
 use std::ffi::c_void;
 #[cfg(feature = "napi5")]
@@ -16,6 +19,8 @@ use crate::Env;
 use crate::Error;
 #[cfg(feature = "napi5")]
 use crate::Result;
+#[cfg(feature = "napi6")]
+use crate::{JsString, JsSymbol};
 
 pub struct JsObject(pub(crate) Value);
 impl From<Value> for JsObject {
@@ -125,13 +130,18 @@ impl From<KeyCollectionMode> for sys::napi_key_collection_mode {
 }
 
 #[cfg(feature = "napi6")]
-pub enum KeyFilter {
-  AllProperties,
-  Writable,
-  Enumerable,
-  Configurable,
-  SkipStrings,
-  SkipSymbols,
+bitflags! {
+  /// The keys skipped by [`JsObject::get_all_property_names`], combined with `|`, like
+  /// `KeyFilter::Enumerable | KeyFilter::SkipSymbols` for the enumerable string keys only
+  #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+  pub struct KeyFilter: i32 {
+    const AllProperties = sys::KeyFilter::all_properties;
+    const Writable = sys::KeyFilter::writable;
+    const Enumerable = sys::KeyFilter::enumerable;
+    const Configurable = sys::KeyFilter::configurable;
+    const SkipStrings = sys::KeyFilter::skip_strings;
+    const SkipSymbols = sys::KeyFilter::skip_symbols;
+  }
 }
 
 #[cfg(feature = "napi6")]
@@ -139,32 +149,19 @@ impl TryFrom<sys::napi_key_filter> for KeyFilter {
   type Error = Error;
 
   fn try_from(value: sys::napi_key_filter) -> Result<Self> {
-    match value {
-      sys::KeyFilter::all_properties => Ok(Self::AllProperties),
-      sys::KeyFilter::writable => Ok(Self::Writable),
-      sys::KeyFilter::enumerable => Ok(Self::Enumerable),
-      sys::KeyFilter::configurable => Ok(Self::Configurable),
-      sys::KeyFilter::skip_strings => Ok(Self::SkipStrings),
-      sys::KeyFilter::skip_symbols => Ok(Self::SkipSymbols),
-      _ => Err(Error::new(
+    Self::from_bits(value).ok_or_else(|| {
+      Error::new(
         crate::Status::InvalidArg,
         format!("Invalid key filter [{}]", value),
-      )),
-    }
+      )
+    })
   }
 }
 
 #[cfg(feature = "napi6")]
 impl From<KeyFilter> for sys::napi_key_filter {
   fn from(value: KeyFilter) -> Self {
-    match value {
-      KeyFilter::AllProperties => sys::KeyFilter::all_properties,
-      KeyFilter::Writable => sys::KeyFilter::writable,
-      KeyFilter::Enumerable => sys::KeyFilter::enumerable,
-      KeyFilter::Configurable => sys::KeyFilter::configurable,
-      KeyFilter::SkipStrings => sys::KeyFilter::skip_strings,
-      KeyFilter::SkipSymbols => sys::KeyFilter::skip_symbols,
-    }
+    value.bits()
   }
 }
 
@@ -199,3 +196,12 @@ impl From<KeyConversion> for sys::napi_key_conversion {
     }
   }
 }
+
+/// A key returned by [`JsObject::get_property_keys`]
+#[cfg(feature = "napi6")]
+pub enum PropertyKey {
+  String(JsString),
+  /// An array index, with [`KeyConversion::KeepNumbers`]
+  Number(u32),
+  Symbol(JsSymbol),
+}
@@ -9,6 +9,8 @@ use bitflags::bitflags;
 #[cfg(feature = "napi5")]
 use crate::bindgen_runtime::{FromNapiValue, This};
 use crate::{bindgen_runtime::ToNapiValue, sys, Callback, Env, NapiRaw, Result};
+#[cfg(feature = "napi5")]
+use crate::{check_status, Error, JsError, NapiValue, Status};
 
 #[cfg(feature = "napi5")]
 #[derive(Copy, Clone)]
@@ -176,3 +178,189 @@ impl Property {
     self
   }
 }
+
+#[cfg(feature = "napi5")]
+type AccessorGetter = Box<dyn Fn(Env, This) -> Result<sys::napi_value>>;
+#[cfg(feature = "napi5")]
+type AccessorSetter = Box<dyn Fn(Env, This, sys::napi_value) -> Result<()>>;
+
+/// An accessor property implemented by Rust closures, see `JsObject::define_property`.
+///
+/// ```rust,ignore
+/// obj.define_property(
+///   "x",
+///   Accessor::new()
+///     .with_getter(|_env, this| this.object.get_named_property::<u32>("_x"))
+///     .with_setter(|_env, mut this, x: u32| this.object.set_named_property("_x", x)),
+/// )?;
+/// ```
+///
+/// The closures are dropped when the object is garbage collected.
+#[cfg(feature = "napi5")]
+pub struct Accessor {
+  getter: Option<AccessorGetter>,
+  setter: Option<AccessorSetter>,
+  attrs: PropertyAttributes,
+}
+
+#[cfg(feature = "napi5")]
+impl Default for Accessor {
+  fn default() -> Self {
+    Self {
+      getter: None,
+      setter: None,
+      attrs: PropertyAttributes::Configurable | PropertyAttributes::Enumerable,
+    }
+  }
+}
+
+#[cfg(feature = "napi5")]
+impl Accessor {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  pub fn with_getter<R, F>(mut self, getter: F) -> Self
+  where
+    F: 'static + Fn(Env, This) -> Result<R>,
+    R: ToNapiValue,
+  {
+    self.getter = Some(Box::new(move |env, this| {
+      getter(env, this).and_then(|value| unsafe { R::to_napi_value(env.0, value) })
+    }));
+    self
+  }
+
+  pub fn with_setter<V, F>(mut self, setter: F) -> Self
+  where
+    F: 'static + Fn(Env, This, V) -> Result<()>,
+    V: FromNapiValue,
+  {
+    self.setter = Some(Box::new(move |env, this, value| {
+      setter(env, this, unsafe { V::from_napi_value(env.0, value)? })
+    }));
+    self
+  }
+
+  /// `Configurable | Enumerable` by default, `Writable` has no effect on accessors
+  pub fn with_property_attributes(mut self, attributes: PropertyAttributes) -> Self {
+    self.attrs = attributes;
+    self
+  }
+
+  /// # Safety
+  ///
+  /// `object` must be an object of `env`, on its own thread
+  pub(crate) unsafe fn define(
+    self,
+    env: sys::napi_env,
+    object: sys::napi_value,
+    name: &str,
+  ) -> Result<()> {
+    let name = CString::new(name)?;
+    let getter: sys::napi_callback = self.getter.as_ref().map(|_| accessor_getter as _);
+    let setter: sys::napi_callback = self.setter.as_ref().map(|_| accessor_setter as _);
+    let attributes = self.attrs.into();
+    let data = Box::into_raw(Box::new(self));
+    // tied to the object, not to the property, which may be deleted while a getter is running
+    let status = unsafe {
+      sys::napi_add_finalizer(
+        env,
+        object,
+        data.cast(),
+        Some(finalize_accessor),
+        ptr::null_mut(),
+        ptr::null_mut(),
+      )
+    };
+    if status != sys::Status::napi_ok {
+      drop(unsafe { Box::from_raw(data) });
+      check_status!(
+        status,
+        "Failed to add the finalizer of the accessor `{}`",
+        name.to_string_lossy()
+      )?;
+    }
+    let descriptor = sys::napi_property_descriptor {
+      utf8name: name.as_ptr(),
+      name: ptr::null_mut(),
+      method: None,
+      getter,
+      setter,
+      value: ptr::null_mut(),
+      attributes,
+      data: data.cast(),
+    };
+    check_status!(
+      unsafe { sys::napi_define_properties(env, object, 1, &descriptor) },
+      "Failed to define the accessor `{}`",
+      name.to_string_lossy()
+    )
+  }
+}
+
+/// The `this` and the first argument of an accessor call, and its [`Accessor`]
+#[cfg(feature = "napi5")]
+unsafe fn accessor_call_info<'a>(
+  env: sys::napi_env,
+  cb_info: sys::napi_callback_info,
+) -> (sys::napi_value, Option<sys::napi_value>, &'a Accessor) {
+  let mut argc = 1;
+  let mut arg = ptr::null_mut();
+  let mut this = ptr::null_mut();
+  let mut data = ptr::null_mut();
+  let status =
+    unsafe { sys::napi_get_cb_info(env, cb_info, &mut argc, &mut arg, &mut this, &mut data) };
+  debug_assert!(status == sys::Status::napi_ok, "napi_get_cb_info failed");
+  let arg = (argc > 0).then_some(arg);
+  (this, arg, unsafe { &*data.cast::<Accessor>() })
+}
+
+#[cfg(feature = "napi5")]
+unsafe extern "C" fn accessor_getter(
+  raw_env: sys::napi_env,
+  cb_info: sys::napi_callback_info,
+) -> sys::napi_value {
+  let (this, _, accessor) = unsafe { accessor_call_info(raw_env, cb_info) };
+  let getter = accessor.getter.as_ref().expect("Accessor without getter");
+  getter(Env::from_raw(raw_env), unsafe {
+    This::from_raw_unchecked(raw_env, this)
+  })
+  .unwrap_or_else(|e| {
+    unsafe { JsError::from(e).throw_into(raw_env) };
+    ptr::null_mut()
+  })
+}
+
+#[cfg(feature = "napi5")]
+unsafe extern "C" fn accessor_setter(
+  raw_env: sys::napi_env,
+  cb_info: sys::napi_callback_info,
+) -> sys::napi_value {
+  let (this, value, accessor) = unsafe { accessor_call_info(raw_env, cb_info) };
+  let setter = accessor.setter.as_ref().expect("Accessor without setter");
+  let result = match value {
+    Some(value) => setter(
+      Env::from_raw(raw_env),
+      unsafe { This::from_raw_unchecked(raw_env, this) },
+      value,
+    ),
+    None => Err(Error::new(
+      Status::InvalidArg,
+      "Missing argument in property setter",
+    )),
+  };
+  if let Err(e) = result {
+    unsafe { JsError::from(e).throw_into(raw_env) };
+  }
+  ptr::null_mut()
+}
+
+#[cfg(feature = "napi5")]
+unsafe extern "C" fn finalize_accessor(
+  _env: sys::napi_env,
+  finalize_data: *mut c_void,
+  _finalize_hint: *mut c_void,
+) {
+  drop(unsafe { Box::<Accessor>::from_raw(finalize_data.cast()) });
+}
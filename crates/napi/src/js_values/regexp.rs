@@ -0,0 +1,120 @@
+use std::collections::HashMap;
+
+use crate::{
+  bindgen_runtime::{validate_instance_of, FromNapiValue, Function, TypeName, ValidateNapiValue},
+  sys, Env, JsObject, NapiRaw, Result, Value, ValueType,
+};
+
+/// Represents a JavaScript [`RegExp`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp) object.
+///
+/// Create it with `Env::create_regexp`, or receive it as a function argument.
+pub struct JsRegExp(pub(crate) Value);
+
+/// The result of a successful `RegExp.prototype.exec`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RegExpMatch {
+  /// The matched substring
+  pub matched: String,
+  /// The position of the match in the input, in UTF-16 code units like `match.index` in JavaScript
+  pub index: u32,
+  /// The capture groups in order, `None` for groups that did not participate in the match
+  pub captures: Vec<Option<String>>,
+  /// The named capture groups, empty if the pattern has none
+  pub groups: HashMap<String, Option<String>>,
+}
+
+impl TypeName for JsRegExp {
+  fn type_name() -> &'static str {
+    "RegExp"
+  }
+
+  fn value_type() -> ValueType {
+    ValueType::Object
+  }
+}
+
+impl ValidateNapiValue for JsRegExp {
+  unsafe fn validate(env: sys::napi_env, napi_val: sys::napi_value) -> Result<sys::napi_value> {
+    validate_instance_of(env, napi_val, "RegExp")
+  }
+}
+
+impl JsRegExp {
+  /// The text of the pattern, without the slashes and flags
+  pub fn source(&self) -> Result<String> {
+    self.get_named_property_unchecked("source")
+  }
+
+  /// The flags of the `RegExp`, e.g. `"gi"`
+  pub fn flags(&self) -> Result<String> {
+    self.get_named_property_unchecked("flags")
+  }
+
+  /// The index at which the next match starts, only used by `global` and `sticky` RegExps
+  pub fn last_index(&self) -> Result<u32> {
+    self.get_named_property_unchecked("lastIndex")
+  }
+
+  pub fn set_last_index(&mut self, last_index: u32) -> Result<()> {
+    self.set_named_property("lastIndex", last_index)
+  }
+
+  /// Same as `RegExp.prototype.test`, advances `lastIndex` for `global` and `sticky` RegExps
+  pub fn test(&self, input: &str) -> Result<bool> {
+    let test: Function<&str, bool> = self.get_named_property_unchecked("test")?;
+    test.apply(self, input)
+  }
+
+  /// Same as `RegExp.prototype.exec`, advances `lastIndex` for `global` and `sticky` RegExps
+  pub fn exec(&self, input: &str) -> Result<Option<RegExpMatch>> {
+    let exec: Function<&str, Option<JsObject>> = self.get_named_property_unchecked("exec")?;
+    exec
+      .apply(self, input)?
+      .map(RegExpMatch::from_exec_result)
+      .transpose()
+  }
+
+  /// Same as `String.prototype.matchAll`, so the `RegExp` must have the `g` flag
+  pub fn match_all(&self, input: &str) -> Result<Vec<RegExpMatch>> {
+    let global = Env::from_raw(self.0.env).get_global()?;
+    let string: JsObject = global.get_named_property_unchecked("String")?;
+    let prototype: JsObject = string.get_named_property_unchecked("prototype")?;
+    let match_all: Function<&JsRegExp, JsObject> =
+      prototype.get_named_property_unchecked("matchAll")?;
+    let iterator = match_all.apply(input, self)?;
+    let next: Function<(), JsObject> = iterator.get_named_property_unchecked("next")?;
+    let mut matches = Vec::new();
+    loop {
+      let result = next.apply(&iterator, ())?;
+      if result.get_named_property_unchecked::<bool>("done")? {
+        break;
+      }
+      matches.push(RegExpMatch::from_exec_result(
+        result.get_named_property_unchecked("value")?,
+      )?);
+    }
+    Ok(matches)
+  }
+}
+
+impl RegExpMatch {
+  fn from_exec_result(result: JsObject) -> Result<Self> {
+    let mut elements =
+      unsafe { Vec::<Option<String>>::from_napi_value(result.0.env, result.raw())? };
+    let matched = elements.remove(0).unwrap_or_default();
+    let mut groups = HashMap::new();
+    if let Some(named) = result.get_named_property_unchecked::<Option<JsObject>>("groups")? {
+      let names = named.get_property_names()?;
+      for name in unsafe { Vec::<String>::from_napi_value(names.0.env, names.raw())? } {
+        let value = named.get_named_property_unchecked(&name)?;
+        groups.insert(name, value);
+      }
+    }
+    Ok(Self {
+      matched,
+      index: result.get_named_property_unchecked("index")?,
+      captures: elements,
+      groups,
+    })
+  }
+}
@@ -0,0 +1,185 @@
+use std::collections::HashSet;
+use std::hash::{BuildHasher, Hash};
+use std::marker::PhantomData;
+use std::ptr;
+
+use crate::{
+  bindgen_runtime::{
+    get_global_constructor, validate_instance_of, FromNapiValue, Function, ToNapiValue, TypeName,
+    Unknown, ValidateNapiValue,
+  },
+  check_status, sys, Env, JsObject, NapiRaw, NapiValue, Result, Value, ValueType,
+};
+
+/// Represents a JavaScript [`Set`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set) object.
+///
+/// Create it with `Env::create_set` or `Env::create_set_from`, or receive it as a function
+/// argument. Its values can be any JavaScript value and keep their insertion order.
+pub struct JsSet(pub(crate) Value);
+
+impl TypeName for JsSet {
+  fn type_name() -> &'static str {
+    "Set"
+  }
+
+  fn value_type() -> ValueType {
+    ValueType::Object
+  }
+}
+
+impl ValidateNapiValue for JsSet {
+  unsafe fn validate(env: sys::napi_env, napi_val: sys::napi_value) -> Result<sys::napi_value> {
+    validate_instance_of(env, napi_val, "Set")
+  }
+}
+
+impl JsSet {
+  pub fn has<V: ToNapiValue>(&self, value: V) -> Result<bool> {
+    let has: Function<V, bool> = self.get_named_property_unchecked("has")?;
+    has.apply(self, value)
+  }
+
+  /// Add `value`, nothing changes if it's in the set already
+  pub fn add<V: ToNapiValue>(&mut self, value: V) -> Result<()> {
+    let add: Function<V, Unknown> = self.get_named_property_unchecked("add")?;
+    add.apply(&*self, value)?;
+    Ok(())
+  }
+
+  /// Remove `value`, `false` if it wasn't in the set
+  pub fn delete<V: ToNapiValue>(&mut self, value: V) -> Result<bool> {
+    let delete: Function<V, bool> = self.get_named_property_unchecked("delete")?;
+    delete.apply(&*self, value)
+  }
+
+  pub fn clear(&mut self) -> Result<()> {
+    let clear: Function<(), Unknown> = self.get_named_property_unchecked("clear")?;
+    clear.apply(&*self, ())?;
+    Ok(())
+  }
+
+  /// The number of values
+  pub fn size(&self) -> Result<u32> {
+    self.get_named_property_unchecked("size")
+  }
+
+  /// Iterate over the values in insertion order, converting each of them to `V`.
+  ///
+  /// Like `Set.prototype.values`, the values added during the iteration are visited too.
+  pub fn values<V: FromNapiValue>(&self) -> Result<JsSetValues<V>> {
+    let values: Function<(), JsObject> = self.get_named_property_unchecked("values")?;
+    let iterator = values.apply(self, ())?;
+    let next: Unknown = iterator.get_named_property_unchecked("next")?;
+    Ok(JsSetValues {
+      env: self.0.env,
+      iterator: unsafe { iterator.raw() },
+      next: unsafe { next.raw() },
+      done: false,
+      _phantom: PhantomData,
+    })
+  }
+
+  /// Collect the values in insertion order
+  pub fn to_vec<V: FromNapiValue>(&self) -> Result<Vec<V>> {
+    self.values()?.collect()
+  }
+
+  /// Collect the values into a `HashSet`, the values converted to the same `V` are merged
+  pub fn to_hash_set<V, S>(&self) -> Result<HashSet<V, S>>
+  where
+    V: FromNapiValue + Eq + Hash,
+    S: Default + BuildHasher,
+  {
+    self.values()?.collect()
+  }
+}
+
+impl<V, S> TryFrom<JsSet> for HashSet<V, S>
+where
+  V: FromNapiValue + Eq + Hash,
+  S: Default + BuildHasher,
+{
+  type Error = crate::Error;
+
+  fn try_from(set: JsSet) -> Result<Self> {
+    set.to_hash_set()
+  }
+}
+
+impl<V: FromNapiValue> TryFrom<JsSet> for Vec<V> {
+  type Error = crate::Error;
+
+  fn try_from(set: JsSet) -> Result<Self> {
+    set.to_vec()
+  }
+}
+
+/// The values of a [`JsSet`], see [`JsSet::values`]
+pub struct JsSetValues<V> {
+  env: sys::napi_env,
+  iterator: sys::napi_value,
+  next: sys::napi_value,
+  done: bool,
+  _phantom: PhantomData<V>,
+}
+
+impl<V: FromNapiValue> JsSetValues<V> {
+  fn next_value(&mut self) -> Result<Option<V>> {
+    let mut result = ptr::null_mut();
+    check_status!(
+      unsafe {
+        sys::napi_call_function(
+          self.env,
+          self.iterator,
+          self.next,
+          0,
+          ptr::null(),
+          &mut result,
+        )
+      },
+      "Failed to get the next value of the Set"
+    )?;
+    let result = unsafe { JsObject::from_raw_unchecked(self.env, result) };
+    if result.get_named_property_unchecked::<bool>("done")? {
+      return Ok(None);
+    }
+    let value: Unknown = result.get_named_property_unchecked("value")?;
+    unsafe { V::from_napi_value(self.env, value.raw()) }.map(Some)
+  }
+}
+
+impl<V: FromNapiValue> Iterator for JsSetValues<V> {
+  type Item = Result<V>;
+
+  fn next(&mut self) -> Option<Self::Item> {
+    if self.done {
+      return None;
+    }
+    let value = self.next_value();
+    // stop after the last value or the first error
+    self.done = !matches!(value, Ok(Some(_)));
+    value.transpose()
+  }
+}
+
+impl Env {
+  /// Create an empty `Set`, same as `new Set()` in JavaScript
+  pub fn create_set(&self) -> Result<JsSet> {
+    let ctor = get_global_constructor::<()>(self.0, "Set")?;
+    let set = ctor.new_instance(())?;
+    Ok(unsafe { JsSet::from_raw_unchecked(self.0, set.raw()) })
+  }
+
+  /// Create a `Set` with `values` in order, like from a `HashSet`, a `Vec` or any iterator
+  pub fn create_set_from<V, I>(&self, values: I) -> Result<JsSet>
+  where
+    V: ToNapiValue,
+    I: IntoIterator<Item = V>,
+  {
+    let mut set = self.create_set()?;
+    for value in values {
+      set.add(value)?;
+    }
+    Ok(set)
+  }
+}
@@ -1,5 +1,8 @@
+use std::cell::RefCell;
+use std::char::DecodeUtf16;
 use std::mem;
 use std::ptr;
+use std::vec;
 
 use crate::bindgen_runtime::TypeName;
 use crate::bindgen_runtime::ValidateNapiValue;
@@ -14,6 +17,11 @@ mod latin1;
 mod utf16;
 mod utf8;
 
+thread_local! {
+  /// The buffer reused by [`JsString::with_utf16`]
+  static UTF16_BUFFER: RefCell<Vec<u16>> = const { RefCell::new(Vec::new()) };
+}
+
 #[derive(Clone, Copy)]
 pub struct JsString(pub(crate) Value);
 
@@ -30,6 +38,7 @@ impl TypeName for JsString {
 impl ValidateNapiValue for JsString {}
 
 impl JsString {
+  /// The length in bytes of the string encoded in UTF-8, without copying it
   pub fn utf8_len(&self) -> Result<usize> {
     let mut length = 0;
     check_status!(unsafe {
@@ -38,6 +47,7 @@ impl JsString {
     Ok(length)
   }
 
+  /// The length in UTF-16 code units, like `.length` in JavaScript, without copying the string
   pub fn utf16_len(&self) -> Result<usize> {
     let mut length = 0;
     check_status!(unsafe {
@@ -54,6 +64,66 @@ impl JsString {
     Ok(length)
   }
 
+  /// Call `f` with the UTF-16 code units of the string.
+  ///
+  /// Node-API can't expose the memory of a string, so it's copied, but to a buffer reused by the
+  /// calls on the same thread instead of a new allocation per string. The slice is only valid in
+  /// `f`, and may have lone surrogates.
+  pub fn with_utf16<R>(&self, f: impl FnOnce(&[u16]) -> R) -> Result<R> {
+    // a nested call gets a new buffer
+    let mut buf = UTF16_BUFFER.with(|buf| buf.try_borrow_mut().map(|mut buf| mem::take(&mut *buf)));
+    let buf = match &mut buf {
+      Ok(buf) => buf,
+      Err(_) => return Ok(f(&self.utf16_units()?)),
+    };
+    let result = self.write_utf16(buf).map(|len| f(&buf[..len]));
+    let buf = mem::take(buf);
+    UTF16_BUFFER.with(|reused| {
+      if let Ok(mut reused) = reused.try_borrow_mut() {
+        if reused.capacity() < buf.capacity() {
+          *reused = buf;
+        }
+      }
+    });
+    result
+  }
+
+  /// Iterate over the code points of the string, the lone surrogates are replaced with
+  /// `U+FFFD REPLACEMENT CHARACTER` like `String::from_utf16_lossy`.
+  ///
+  /// The string is copied once, the characters are decoded lazily.
+  pub fn chars(&self) -> Result<JsStringChars> {
+    Ok(JsStringChars {
+      inner: char::decode_utf16(self.utf16_units()?),
+    })
+  }
+
+  fn utf16_units(&self) -> Result<Vec<u16>> {
+    let mut buf = Vec::new();
+    let len = self.write_utf16(&mut buf)?;
+    buf.truncate(len);
+    Ok(buf)
+  }
+
+  /// Copy the UTF-16 code units to `buf`, the number of them written
+  fn write_utf16(&self, buf: &mut Vec<u16>) -> Result<usize> {
+    let len = self.utf16_len()?;
+    // room for the '\0' written by Node-API
+    buf.clear();
+    buf.resize(len + 1, 0);
+    let mut written_char_count = 0usize;
+    check_status!(unsafe {
+      sys::napi_get_value_string_utf16(
+        self.0.env,
+        self.0.value,
+        buf.as_mut_ptr(),
+        buf.len(),
+        &mut written_char_count,
+      )
+    })?;
+    Ok(written_char_count)
+  }
+
   pub fn into_utf8(self) -> Result<JsStringUtf8> {
     let mut written_char_count = 0;
     let len = self.utf8_len()? + 1;
@@ -125,3 +195,23 @@ impl JsString {
     })
   }
 }
+
+/// The code points of a [`JsString`], see [`JsString::chars`]
+pub struct JsStringChars {
+  inner: DecodeUtf16<vec::IntoIter<u16>>,
+}
+
+impl Iterator for JsStringChars {
+  type Item = char;
+
+  fn next(&mut self) -> Option<char> {
+    self
+      .inner
+      .next()
+      .map(|c| c.unwrap_or(char::REPLACEMENT_CHARACTER))
+  }
+
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    self.inner.size_hint()
+  }
+}
@@ -18,8 +18,20 @@ unsafe impl<T> Sync for Ref<T> {}
 
 impl<T: NapiRaw> Ref<T> {
   pub fn new(env: &Env, value: &T) -> Result<Ref<T>> {
+    Self::with_ref_count(env, value, 1)
+  }
+
+  /// Create a reference which doesn't keep `value` alive, [`upgrade`](Ref::upgrade) returns
+  /// `None` once it's garbage collected
+  pub fn new_weak(env: &Env, value: &T) -> Result<Ref<T>> {
+    Self::with_ref_count(env, value, 0)
+  }
+
+  fn with_ref_count(env: &Env, value: &T, initial_ref_count: u32) -> Result<Ref<T>> {
     let mut raw_ref = ptr::null_mut();
-    check_status!(unsafe { sys::napi_create_reference(env.0, value.raw(), 1, &mut raw_ref) })?;
+    check_status!(unsafe {
+      sys::napi_create_reference(env.0, value.raw(), initial_ref_count, &mut raw_ref)
+    })?;
     Ok(Ref {
       raw_ref,
       taken: false,
@@ -28,8 +40,7 @@ impl<T: NapiRaw> Ref<T> {
   }
 
   pub fn unref(&mut self, env: &Env) -> Result<()> {
-    check_status!(unsafe { sys::napi_reference_unref(env.0, self.raw_ref, &mut 0) })?;
-
+    // deleting releases the value of strong references too, and weak ones can't be unref'ed
     check_status!(unsafe { sys::napi_delete_reference(env.0, self.raw_ref) })?;
     self.taken = true;
     Ok(())
@@ -39,6 +50,17 @@ impl<T: NapiRaw> Ref<T> {
 impl<T: FromNapiValue> Ref<T> {
   /// Get the value from the reference
   pub fn get_value(&self, env: &Env) -> Result<T> {
+    self.upgrade(env)?.ok_or_else(|| {
+      crate::Error::new(
+        crate::Status::InvalidArg,
+        "Ref value has been garbage collected",
+      )
+    })
+  }
+
+  /// Get the value from the reference, or `None` if the value of a weak reference has been
+  /// garbage collected
+  pub fn upgrade(&self, env: &Env) -> Result<Option<T>> {
     if self.taken {
       return Err(crate::Error::new(
         crate::Status::InvalidArg,
@@ -47,7 +69,10 @@ impl<T: FromNapiValue> Ref<T> {
     }
     let mut result = ptr::null_mut();
     check_status!(unsafe { sys::napi_get_reference_value(env.0, self.raw_ref, &mut result) })?;
-    unsafe { T::from_napi_value(env.0, result) }
+    if result.is_null() {
+      return Ok(None);
+    }
+    unsafe { T::from_napi_value(env.0, result) }.map(Some)
   }
 }
 
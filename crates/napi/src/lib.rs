@@ -33,12 +33,79 @@
 //! }
 //! ```
 //!
+//! ### async_generator
+//!
+//! Return a [`Stream`](https://docs.rs/futures-core/latest/futures_core/stream/trait.Stream.html)
+//! from a `#[napi]` function as an async generator with `bindgen_prelude::AsyncGenerator`, it's
+//! polled in the tokio runtime and consumed with `for await` in JavaScript.
+//!
 //! ### latin1
 //!
 //! Decode latin1 string from JavaScript using [encoding_rs](https://docs.rs/encoding_rs).
 //!
 //! With this feature, you can use `JsString.as_latin1_string` function
 //!
+//! ### http_codecs
+//!
+//! Native helpers for header lists, URL query strings and `Cookie` headers, see [`http_codecs`].
+//!
+//! The JS side helpers are exported automatically under the `httpCodecs` namespace.
+//!
+//! ### alloc_tracking
+//!
+//! A global allocator wrapper attributing the native allocations of the addon to categories, see
+//! [`alloc_tracking`].
+//!
+//! The JS side totals are exported automatically under the `allocTracking` namespace.
+//!
+//! ### formatting
+//!
+//! Locale independent number and date formatting, with the same output whatever ICU variant
+//! Node.js is built with, see [`formatting`].
+//!
+//! The JS side helpers are exported automatically under the `formatting` namespace.
+//!
+//! ### text_encoding
+//!
+//! Encode and decode legacy encodings such as Shift_JIS, GBK or windows-1251 using
+//! [encoding_rs](https://docs.rs/encoding_rs), see [`text_encoding`].
+//!
+//! The JS side helpers are exported automatically under the `textEncoding` namespace.
+//!
+//! ### dyn-symbols
+//!
+//! Resolve the Node-API functions at runtime instead of linking against them, so one binary can
+//! be loaded by runtimes that export different sets of functions, like Bun or older Electron
+//! versions. Functions the runtime doesn't have are listed by `napi::sys::missing_symbols()`,
+//! calling one of them panics with its name, `napi::sys::is_available(name)` tells if one can be
+//! called on any target. It also helps with Node.js builds exporting fewer functions, like some
+//! musl builds for Alpine.
+//!
+//! On Windows with MSVC and on Android the functions are always resolved at runtime, the latter
+//! because the NDK refuses to link a shared library with undefined symbols. The module exporting
+//! them is searched in the current executable then in `libnode.dll`/`node.exe`/`electron.exe` on
+//! Windows, and in the symbols of the process then in an already loaded `libnode.so`, like the one
+//! of nodejs-mobile, on unix. Hosts with other names can be added with the `NAPI_RS_HOST_MODULE`
+//! environment variable or `napi::sys::set_host_modules` from a `#[napi::module_init]` function.
+//!
+//! ### error-backtrace
+//!
+//! Capture a Rust backtrace when an [`Error`] is created, to find where the conversion failures
+//! surfacing far from their origin come from. The backtrace is captured only when `RUST_BACKTRACE`
+//! or `RUST_LIB_BACKTRACE` is set, it's returned by [`Error::backtrace`] and printed by the
+//! `Display` of the error.
+//!
+//! ### record
+//!
+//! Record the arguments and return values of functions marked with `#[napi(record)]` as JSON lines,
+//! and replay them in Rust tests, see [`record`].
+//!
+//! ### pod
+//!
+//! Share slices of plain old data like vertices or samples with JavaScript without copying, with
+//! `Env::create_buffer_slice_of`, and read a `Buffer` as a `&[T]` with `BufferSlice::as_slice_of`.
+//! Implement the unsafe [`bindgen_prelude::Pod`] trait for the `#[repr(C)]` structs to share.
+//!
 //! ### serde-json
 //!
 //! Enable Serialize/Deserialize data cross `JavaScript Object` and `Rust struct`.
@@ -65,23 +132,107 @@
 //! ```
 //!
 
+/// Defines the callback and the export register function of a JS function implemented by the
+/// crate itself, see `http_codecs` for example.
+///
+/// The feature gated modules pass the register functions to `register_module_export` in their
+/// `register_exports`, called once from `napi_register_module_v1`. They are not registered from a
+/// `ctor` like the `#[napi]` generated exports, because that would force the linker to keep them
+/// (and the N-API symbols they need) in `cargo test` binaries of every crate enabling the feature.
+/// Not being `#[napi]` items, they are not part of the generated `.d.ts` and JS binding either.
+#[cfg(any(
+  feature = "alloc_tracking",
+  feature = "formatting",
+  feature = "http_codecs",
+  feature = "text_encoding"
+))]
+macro_rules! js_function {
+  ($js_name:literal, $implementation:ident, $callback:ident, $register:ident) => {
+    unsafe extern "C" fn $callback(
+      env: $crate::sys::napi_env,
+      cb: $crate::sys::napi_callback_info,
+    ) -> $crate::sys::napi_value {
+      unsafe { $implementation(env, cb) }.unwrap_or_else(|e| {
+        unsafe { $crate::JsError::from(e).throw_into(env) };
+        std::ptr::null_mut()
+      })
+    }
+
+    unsafe fn $register(env: $crate::sys::napi_env) -> $crate::Result<$crate::sys::napi_value> {
+      let mut fn_ptr = std::ptr::null_mut();
+      $crate::check_status!(
+        unsafe {
+          $crate::sys::napi_create_function(
+            env,
+            concat!($js_name, "\0").as_ptr().cast(),
+            $js_name.len(),
+            Some($callback),
+            std::ptr::null_mut(),
+            &mut fn_ptr,
+          )
+        },
+        "Failed to register function `{}`",
+        $js_name,
+      )?;
+      $crate::bindgen_prelude::register_js_function(
+        concat!($js_name, "\0"),
+        $register,
+        Some($callback),
+      );
+      Ok(fn_ptr)
+    }
+  };
+}
+
+#[cfg(feature = "alloc_tracking")]
+pub mod alloc_tracking;
 #[cfg(feature = "napi8")]
 mod async_cleanup_hook;
 #[cfg(feature = "napi8")]
 pub use async_cleanup_hook::AsyncCleanupHook;
+mod async_context;
+mod async_hook;
+mod async_scope;
 mod async_work;
 mod bindgen_runtime;
+mod builtins;
 mod call_context;
 #[cfg(feature = "napi3")]
 mod cleanup_env;
 mod env;
 mod error;
+#[cfg(feature = "napi7")]
+mod external_memory;
+#[cfg(feature = "formatting")]
+pub mod formatting;
+mod frame_queue;
+#[cfg(feature = "http_codecs")]
+pub mod http_codecs;
+mod instance_data;
 mod js_values;
+#[cfg(all(feature = "napi3", not(target_family = "wasm")))]
+mod local_task;
+mod memory;
+mod module_file;
+#[cfg(all(feature = "tokio_rt", feature = "napi4"))]
+mod pool;
+mod random;
+#[cfg(feature = "record")]
+pub mod record;
+mod ring_buffer;
+mod schedule;
 mod status;
+mod structured_clone;
 mod task;
+#[cfg(feature = "text_encoding")]
+pub mod text_encoding;
 #[cfg(all(feature = "tokio_rt", feature = "napi4"))]
 mod tokio_runtime;
+pub mod trace;
+#[cfg(all(feature = "napi3", not(target_family = "wasm")))]
+mod uv;
 mod value_type;
+mod yielder;
 #[cfg(feature = "napi3")]
 pub use cleanup_env::CleanupEnvHook;
 #[cfg(feature = "napi4")]
@@ -91,17 +242,37 @@ mod version;
 
 pub use napi_sys as sys;
 
+pub use async_context::AsyncContext;
+pub use async_hook::{AsyncHook, AsyncHookId, AsyncWorkInfo, AsyncWorkKind, AsyncWorkTimings};
+pub use async_scope::{AsyncScope, ScopeGuard, Scoped};
 pub use async_work::AsyncWorkPromise;
-pub use call_context::CallContext;
+pub use builtins::Builtins;
+pub use call_context::{CallContext, CallStorage};
 
 pub use bindgen_runtime::iterator;
 pub use env::*;
 pub use error::*;
+#[cfg(feature = "napi7")]
+pub use external_memory::{ExternalMemory, MappedArrayBuffer};
+pub use frame_queue::{FrameQueue, FrameSender, FrameSlot, OverflowPolicy, QueuedFrame};
 pub use js_values::*;
+pub use memory::MemoryUsage;
+#[cfg(all(feature = "tokio_rt", feature = "napi4"))]
+pub use pool::{PerEnvPool, PoolGuard};
+pub use random::{RandomGenerator, RandomSource};
+pub use ring_buffer::{
+  ExchangedFrame, FrameWriter, RingBufferExchange, RingBufferProducer, SlotState,
+};
 pub use status::Status;
 pub use task::Task;
+#[cfg(all(feature = "napi3", not(target_family = "wasm")))]
+pub use uv::UvAsyncSender;
 pub use value_type::*;
 pub use version::NodeVersion;
+pub use yielder::Yielder;
+/// A 128-bit tag telling which native type an object holds, see [`Env::type_tag_object`]
+#[cfg(feature = "napi8")]
+pub type TypeTag = sys::napi_type_tag;
 #[cfg(feature = "serde-json")]
 #[macro_use]
 extern crate serde;
@@ -139,6 +310,58 @@ macro_rules! assert_type_of {
   };
 }
 
+/// Export the module init function under another symbol than `napi_register_module_v1`.
+///
+/// Useful when the addon is linked into a custom Node.js build, or when several addons share one
+/// shared library, and the embedder calls `node::AddLinkedBinding(env, "name", init, NAPI_VERSION)`
+/// with the exported symbol. The default symbol can be renamed with the `NAPI_RS_INIT_SYMBOL`
+/// environment variable at build time.
+///
+/// With `module = "name"` only the items marked with `#[napi(module = "name")]` are exported, so a
+/// plugin host can load subsets of the functionality from one library. `napi_register_module_v1`
+/// keeps exporting every item.
+///
+/// With `linked = "name"` the module is also registered with `napi_module_register` when the
+/// library is loaded, so a statically linked addon is available as `process._linkedBinding("name")`.
+///
+/// ```ignore
+/// napi::module!(napi_register_my_addon_v1);
+/// napi::module!(napi_register_my_addon_linked_v1, linked = "my_addon");
+/// napi::module!(napi_register_my_addon_core_v1, module = "core");
+/// ```
+#[cfg(not(feature = "noop"))]
+#[macro_export]
+macro_rules! module {
+  ($init:ident $(, module = $module:literal)? $(, linked = $name:literal)? $(,)?) => {
+    #[no_mangle]
+    pub unsafe extern "C" fn $init(
+      env: $crate::sys::napi_env,
+      exports: $crate::sys::napi_value,
+    ) -> $crate::sys::napi_value {
+      let module: Option<&'static str> = None $(.or(Some($module)))?;
+      match module {
+        Some(module) => unsafe {
+          $crate::bindgen_prelude::register_named_module(env, exports, module)
+        },
+        None => unsafe { $crate::bindgen_prelude::register_module(env, exports) },
+      }
+    }
+
+    $(
+      // test executables have no Node.js to register with
+      #[cfg(not(test))]
+      const _: () = {
+        #[$crate::bindgen_prelude::ctor]
+        fn register_linked_module() {
+          unsafe {
+            $crate::bindgen_prelude::register_linked_module(concat!($name, "\0"), Some($init))
+          }
+        }
+      };
+    )?
+  };
+}
+
 pub use crate::bindgen_runtime::ctor as module_init;
 
 pub mod bindgen_prelude {
@@ -168,6 +391,7 @@ pub mod __private {
   pub use crate::bindgen_runtime::{
     get_class_constructor, iterator::create_iterator, register_class, ___CALL_FROM_FACTORY,
   };
+  pub use crate::call_context::CallFrame;
 
   use crate::sys;
 
@@ -0,0 +1,119 @@
+use std::future::Future;
+use std::ptr;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Wake, Waker};
+
+use crate::bindgen_runtime::{PromiseRaw, ToNapiValue};
+use crate::{check_status, sys, Env, JsError, Result, Status, UvAsyncSender};
+
+/// Wakes a task of [`Env::spawn_local`] by waking the libuv async handle polling it, from any
+/// thread.
+///
+/// The sender is dropped once the task is done, which closes the handle, wakers kept after that do
+/// nothing.
+struct LocalWaker(Mutex<Option<UvAsyncSender>>);
+
+impl LocalWaker {
+  fn take_sender(&self) -> Option<UvAsyncSender> {
+    self.0.lock().unwrap_or_else(|e| e.into_inner()).take()
+  }
+}
+
+impl Wake for LocalWaker {
+  fn wake(self: Arc<Self>) {
+    self.wake_by_ref();
+  }
+
+  fn wake_by_ref(self: &Arc<Self>) {
+    if let Some(sender) = self.0.lock().unwrap_or_else(|e| e.into_inner()).as_ref() {
+      // the env is torn down, the task is dropped with it
+      let _ = sender.send();
+    }
+  }
+}
+
+impl Env {
+  /// Drive `future` on the JavaScript thread, returning a Promise settled with its output.
+  ///
+  /// Unlike `Env::spawn_future`, the future doesn't need to be `Send`: it's polled
+  /// on the libuv loop of this env, first on the next iteration and then each time it's woken,
+  /// so it can hold an `Env`, `Rc`s or references to JavaScript values across `.await`s. Each poll
+  /// runs in its own handle scope, followed by a microtask checkpoint like a libuv callback of
+  /// Node.js, so values created during a poll must not be kept for the next ones.
+  ///
+  /// ```rust,ignore
+  /// #[napi]
+  /// fn sum_all(env: Env, values: Vec<Promise<u32>>, on_value: FunctionRef<u32, ()>) -> Result<PromiseRaw<u32>> {
+  ///   env.spawn_local(async move {
+  ///     let mut sum = 0;
+  ///     for value in values {
+  ///       sum += value.await?;
+  ///       on_value.borrow_back(&env)?.call(sum)?;
+  ///     }
+  ///     Ok(sum)
+  ///   })
+  /// }
+  /// ```
+  ///
+  /// The future may be woken from any thread, but it's always polled on the JavaScript thread, so
+  /// blocking in it blocks JavaScript. The pending future keeps the process alive, it's dropped
+  /// without being completed if the env is torn down first.
+  pub fn spawn_local<T, F>(&self, future: F) -> Result<PromiseRaw<T>>
+  where
+    T: 'static + ToNapiValue,
+    F: 'static + Future<Output = Result<T>>,
+  {
+    let mut deferred = ptr::null_mut();
+    let mut promise = ptr::null_mut();
+    check_status!(
+      unsafe { sys::napi_create_promise(self.0, &mut deferred, &mut promise) },
+      "Failed to create the Promise of the local future"
+    )?;
+    let local_waker = Arc::new(LocalWaker(Mutex::new(None)));
+    let waker = Waker::from(local_waker.clone());
+    let task_waker = local_waker.clone();
+    let mut future = Some(Box::pin(future));
+    let sender = self.uv_async(move |env| {
+      let Some(pending) = future.as_mut() else {
+        return Ok(());
+      };
+      let Poll::Ready(output) = pending.as_mut().poll(&mut Context::from_waker(&waker)) else {
+        return Ok(());
+      };
+      future = None;
+      // closes the handle after this callback
+      drop(task_waker.take_sender());
+      unsafe { settle(env.raw(), deferred, output) }
+    })?;
+    let first_poll = sender.send();
+    *local_waker.0.lock().unwrap_or_else(|e| e.into_inner()) = Some(sender);
+    first_poll?;
+    Ok(PromiseRaw::new(self.0, promise))
+  }
+}
+
+unsafe fn settle<T: ToNapiValue>(
+  env: sys::napi_env,
+  deferred: sys::napi_deferred,
+  output: Result<T>,
+) -> Result<()> {
+  match output.and_then(|value| unsafe { T::to_napi_value(env, value) }) {
+    Ok(value) => check_status!(
+      unsafe { sys::napi_resolve_deferred(env, deferred, value) },
+      "Failed to resolve the Promise of the local future"
+    ),
+    Err(err) => {
+      let reason = if err.status == Status::PendingException {
+        let mut exception = ptr::null_mut();
+        check_status!(unsafe { sys::napi_get_and_clear_last_exception(env, &mut exception) })?;
+        exception
+      } else {
+        unsafe { JsError::from(err).into_value(env) }
+      };
+      check_status!(
+        unsafe { sys::napi_reject_deferred(env, deferred, reason) },
+        "Failed to reject the Promise of the local future"
+      )
+    }
+  }
+}
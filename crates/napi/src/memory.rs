@@ -0,0 +1,72 @@
+use std::sync::atomic::{AtomicI64, Ordering};
+
+use crate::{bindgen_runtime::Function, sys, Env, JsObject, Result};
+
+/// The external memory registered by this addon with `napi_adjust_external_memory`, in all its envs
+static ADDON_EXTERNAL_MEMORY: AtomicI64 = AtomicI64::new(0);
+
+/// The memory of the process and the external memory of the addon, see [`Env::memory_usage`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MemoryUsage {
+  /// The bytes registered by this addon with [`Env::adjust_external_memory`], by `External` size
+  /// hints and the like, summed over all the envs of the addon
+  pub addon_external: i64,
+  /// The external memory V8 knows about in the isolate of the env, registered by every addon and
+  /// by Node.js itself. It's what makes the garbage collections more frequent
+  pub isolate_external: i64,
+  /// The fields of `process.memoryUsage()`, `0` for the ones the runtime doesn't report
+  pub rss: u64,
+  pub heap_total: u64,
+  pub heap_used: u64,
+  pub external: u64,
+  pub array_buffers: u64,
+}
+
+impl Env {
+  /// The memory usage of the process, with the external memory registered by this addon.
+  ///
+  /// `process.memoryUsage()` is read through the global `process`, it fails in runtimes without
+  /// it.
+  pub fn memory_usage(&self) -> Result<MemoryUsage> {
+    let process: JsObject = self.get_global()?.get_named_property("process")?;
+    let memory_usage: Function<(), JsObject> = process.get_named_property("memoryUsage")?;
+    let usage = memory_usage.apply(&process, ())?;
+    let field = |name: &str| -> Result<u64> {
+      Ok(
+        usage
+          .get::<f64>(name)?
+          .filter(|bytes| bytes.is_finite() && *bytes > 0.0)
+          .map_or(0, |bytes| bytes as u64),
+      )
+    };
+    Ok(MemoryUsage {
+      addon_external: ADDON_EXTERNAL_MEMORY.load(Ordering::Relaxed),
+      isolate_external: unsafe { adjust_external_memory(self.0, 0)? },
+      rss: field("rss")?,
+      heap_total: field("heapTotal")?,
+      heap_used: field("heapUsed")?,
+      external: field("external")?,
+      array_buffers: field("arrayBuffers")?,
+    })
+  }
+}
+
+/// `napi_adjust_external_memory`, counted in [`MemoryUsage::addon_external`]. Returns the
+/// external memory of the isolate after the change, always `0` on WebAssembly
+pub(crate) unsafe fn adjust_external_memory(env: sys::napi_env, change: i64) -> Result<i64> {
+  ADDON_EXTERNAL_MEMORY.fetch_add(change, Ordering::Relaxed);
+  #[cfg(not(target_family = "wasm"))]
+  {
+    let mut adjusted = 0i64;
+    crate::check_status!(
+      unsafe { sys::napi_adjust_external_memory(env, change, &mut adjusted) },
+      "Adjust external memory failed"
+    )?;
+    Ok(adjusted)
+  }
+  #[cfg(target_family = "wasm")]
+  {
+    let _ = env;
+    Ok(0)
+  }
+}
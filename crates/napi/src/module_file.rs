@@ -0,0 +1,121 @@
+//! The file of the addon, when `node_api_get_module_file_name` is not available.
+//!
+//! The `module` object the addon is loaded for can't be reached from native code, but the file
+//! it's loaded from is the shared library holding this code, which the OS knows about.
+
+use std::ffi::c_void;
+use std::path::PathBuf;
+
+/// The `file://` URL of the addon, like `node_api_get_module_file_name` and `url.pathToFileURL`
+pub(crate) fn addon_file_url() -> Option<String> {
+  addon_path().map(|path| path_to_file_url(&path.to_string_lossy()))
+}
+
+/// Any address inside the addon
+fn address_in_addon() -> *const c_void {
+  addon_file_url as *const c_void
+}
+
+#[cfg(all(unix, not(target_family = "wasm")))]
+fn addon_path() -> Option<PathBuf> {
+  use std::ffi::{c_char, c_int, CStr};
+  use std::os::unix::ffi::OsStrExt;
+
+  #[repr(C)]
+  struct DlInfo {
+    dli_fname: *const c_char,
+    dli_fbase: *mut c_void,
+    dli_sname: *const c_char,
+    dli_saddr: *mut c_void,
+  }
+
+  extern "C" {
+    fn dladdr(addr: *const c_void, info: *mut DlInfo) -> c_int;
+  }
+
+  let mut info = DlInfo {
+    dli_fname: std::ptr::null(),
+    dli_fbase: std::ptr::null_mut(),
+    dli_sname: std::ptr::null(),
+    dli_saddr: std::ptr::null_mut(),
+  };
+  if unsafe { dladdr(address_in_addon(), &mut info) } == 0 || info.dli_fname.is_null() {
+    return None;
+  }
+  let file_name = unsafe { CStr::from_ptr(info.dli_fname) };
+  let path = PathBuf::from(std::ffi::OsStr::from_bytes(file_name.to_bytes()));
+  // the name is the one given to `dlopen`, usually absolute already
+  std::fs::canonicalize(&path).ok().or(Some(path))
+}
+
+#[cfg(windows)]
+fn addon_path() -> Option<PathBuf> {
+  use std::os::windows::ffi::OsStringExt;
+
+  const GET_MODULE_HANDLE_EX_FLAG_FROM_ADDRESS: u32 = 0x4;
+  const GET_MODULE_HANDLE_EX_FLAG_UNCHANGED_REFCOUNT: u32 = 0x2;
+
+  #[link(name = "kernel32")]
+  extern "system" {
+    fn GetModuleHandleExW(flags: u32, module_name: *const u16, module: *mut *mut c_void) -> i32;
+    fn GetModuleFileNameW(module: *mut c_void, file_name: *mut u16, size: u32) -> u32;
+  }
+
+  let mut module = std::ptr::null_mut();
+  if unsafe {
+    GetModuleHandleExW(
+      GET_MODULE_HANDLE_EX_FLAG_FROM_ADDRESS | GET_MODULE_HANDLE_EX_FLAG_UNCHANGED_REFCOUNT,
+      address_in_addon().cast(),
+      &mut module,
+    )
+  } == 0
+  {
+    return None;
+  }
+  let mut buffer = vec![0u16; 260];
+  loop {
+    let len = unsafe { GetModuleFileNameW(module, buffer.as_mut_ptr(), buffer.len() as u32) };
+    match len as usize {
+      0 => return None,
+      // truncated
+      len if len == buffer.len() => buffer.resize(buffer.len() * 2, 0),
+      len => return Some(PathBuf::from(std::ffi::OsString::from_wide(&buffer[..len]))),
+    }
+  }
+}
+
+#[cfg(not(any(all(unix, not(target_family = "wasm")), windows)))]
+fn addon_path() -> Option<PathBuf> {
+  let _ = address_in_addon;
+  None
+}
+
+/// The `file://` URL of an absolute path, percent-encoded like `url.pathToFileURL`
+fn path_to_file_url(path: &str) -> String {
+  let mut url = String::from("file://");
+  let path = if cfg!(windows) {
+    let path = path.strip_prefix(r"\\?\").unwrap_or(path);
+    match path.strip_prefix(r"\\") {
+      // `\\server\share\file` is `file://server/share/file`
+      Some(unc) => unc.replace('\\', "/"),
+      None => format!("/{}", path.replace('\\', "/")),
+    }
+  } else {
+    path.to_owned()
+  };
+  for byte in path.bytes() {
+    let encode = byte <= b' '
+      || byte >= 0x7f
+      || matches!(
+        byte,
+        b'"' | b'#' | b'%' | b'<' | b'>' | b'?' | b'`' | b'{' | b'}'
+      )
+      || (!cfg!(windows) && byte == b'\\');
+    if encode {
+      url.push_str(&format!("%{:02X}", byte));
+    } else {
+      url.push(byte as char);
+    }
+  }
+  url
+}
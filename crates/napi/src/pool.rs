@@ -0,0 +1,214 @@
+use std::future::Future;
+use std::ops::{Deref, DerefMut};
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+use crate::{Env, Error, Result, Status};
+
+type CreateFn<T> = Box<dyn Fn() -> Pin<Box<dyn Future<Output = Result<T>> + Send>> + Send + Sync>;
+
+struct PoolInner<T> {
+  idle: Mutex<Vec<T>>,
+  permits: Arc<Semaphore>,
+  max_size: usize,
+  create: CreateFn<T>,
+  drained: AtomicBool,
+}
+
+impl<T> PoolInner<T> {
+  fn close(&self) {
+    self.drained.store(true, Ordering::Release);
+    self.permits.close();
+    self.idle.lock().unwrap().clear();
+  }
+}
+
+/// A pool of `T`, like database connections or compiled templates, shared by the calls of an env.
+///
+/// There is one pool per `T` in each env, created by the first [`PerEnvPool::get_or_init`]. The
+/// items are created on demand up to `max_size`, [`PerEnvPool::checkout`] waits for one to be
+/// returned beyond that. The pool is `Send` and `Sync`, clone it into the futures of async
+/// functions:
+///
+/// ```rust
+/// #[napi]
+/// pub fn query(env: Env, sql: String) -> Result<PromiseRaw<Vec<Row>>> {
+///   let pool = PerEnvPool::get_or_init(&env, 8, || async { Connection::open(DATABASE_URL).await })?;
+///   env.spawn_future(async move {
+///     let conn = pool.checkout().await?;
+///     conn.query(&sql).await
+///   })
+/// }
+/// ```
+///
+/// When the env is torn down, the pool is drained: pending and new checkouts fail, the idle items
+/// are dropped and the items checked out are dropped when their guards are.
+pub struct PerEnvPool<T: 'static> {
+  inner: Arc<PoolInner<T>>,
+}
+
+impl<T: 'static> Clone for PerEnvPool<T> {
+  fn clone(&self) -> Self {
+    Self {
+      inner: self.inner.clone(),
+    }
+  }
+}
+
+/// The pool of `T` kept in the instance data of an env, drained when the env is torn down
+struct EnvPool<T: 'static>(PerEnvPool<T>);
+
+impl<T: 'static> Drop for EnvPool<T> {
+  fn drop(&mut self) {
+    self.0.inner.close();
+  }
+}
+
+impl<T: Send + 'static> PerEnvPool<T> {
+  /// Get the pool of `T` of `env`, creating it with up to `max_size` items made by `create` if
+  /// there is none.
+  pub fn get_or_init<F, Fut>(env: &Env, max_size: usize, create: F) -> Result<Self>
+  where
+    F: Fn() -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = Result<T>> + Send + 'static,
+  {
+    if let Some(pool) = Self::get(env) {
+      return Ok(pool);
+    }
+    if max_size == 0 {
+      return Err(Error::new(
+        Status::InvalidArg,
+        "The max size of a pool must be greater than 0",
+      ));
+    }
+    let pool = Self {
+      inner: Arc::new(PoolInner {
+        idle: Mutex::new(Vec::with_capacity(max_size)),
+        permits: Arc::new(Semaphore::new(max_size)),
+        max_size,
+        create: Box::new(move || Box::pin(create())),
+        drained: AtomicBool::new(false),
+      }),
+    };
+    env.set_instance_data_typed(EnvPool(pool.clone()))?;
+    Ok(pool)
+  }
+
+  /// Get the pool of `T` of `env`, if it's created
+  pub fn get(env: &Env) -> Option<Self> {
+    env
+      .get_instance_data_typed::<EnvPool<T>>()
+      .map(|pool| pool.0.clone())
+  }
+
+  /// Check out an idle item, or create one, waiting for an item to be returned if `max_size` items
+  /// are checked out.
+  pub async fn checkout(&self) -> Result<PoolGuard<T>> {
+    if self.is_drained() {
+      return Err(drained_error());
+    }
+    let permit = self
+      .inner
+      .permits
+      .clone()
+      .acquire_owned()
+      .await
+      .map_err(|_| drained_error())?;
+    if self.is_drained() {
+      return Err(drained_error());
+    }
+    let idle = self.inner.idle.lock().unwrap().pop();
+    let item = match idle {
+      Some(item) => item,
+      None => (self.inner.create)().await?,
+    };
+    Ok(PoolGuard {
+      item: Some(item),
+      pool: self.inner.clone(),
+      _permit: permit,
+    })
+  }
+
+  /// Wait for the checked out items to be returned and drop all the items, the checkouts fail once
+  /// the drain starts.
+  pub async fn drain(&self) {
+    if self.inner.drained.swap(true, Ordering::AcqRel) {
+      return;
+    }
+    // The semaphore is fair, this waits for the checkouts acquired before the drain only
+    let _all = self
+      .inner
+      .permits
+      .acquire_many(self.inner.max_size as u32)
+      .await;
+    self.inner.close();
+  }
+
+  /// Whether the pool is drained, by [`PerEnvPool::drain`] or the teardown of the env
+  pub fn is_drained(&self) -> bool {
+    self.inner.drained.load(Ordering::Acquire)
+  }
+
+  /// The number of items waiting in the pool
+  pub fn idle_count(&self) -> usize {
+    self.inner.idle.lock().unwrap().len()
+  }
+
+  /// The number of items checked out
+  pub fn in_use_count(&self) -> usize {
+    if self.inner.permits.is_closed() {
+      return 0;
+    }
+    self.inner.max_size - self.inner.permits.available_permits()
+  }
+
+  pub fn max_size(&self) -> usize {
+    self.inner.max_size
+  }
+}
+
+/// An item checked out of a [`PerEnvPool`], returned to the pool when dropped
+pub struct PoolGuard<T> {
+  item: Option<T>,
+  pool: Arc<PoolInner<T>>,
+  // Released after the item is returned in `drop`
+  _permit: OwnedSemaphorePermit,
+}
+
+impl<T> PoolGuard<T> {
+  /// Drop the item instead of returning it to the pool, like a broken connection
+  pub fn discard(mut self) {
+    self.item.take();
+  }
+}
+
+impl<T> Deref for PoolGuard<T> {
+  type Target = T;
+
+  fn deref(&self) -> &T {
+    self.item.as_ref().unwrap()
+  }
+}
+
+impl<T> DerefMut for PoolGuard<T> {
+  fn deref_mut(&mut self) -> &mut T {
+    self.item.as_mut().unwrap()
+  }
+}
+
+impl<T> Drop for PoolGuard<T> {
+  fn drop(&mut self) {
+    if let Some(item) = self.item.take() {
+      if !self.pool.drained.load(Ordering::Acquire) {
+        self.pool.idle.lock().unwrap().push(item);
+      }
+    }
+  }
+}
+
+fn drained_error() -> Error {
+  Error::new(Status::Closing, "The pool is drained")
+}
@@ -0,0 +1,122 @@
+use std::cell::RefCell;
+
+use crate::{
+  bindgen_runtime::{Buffer, FromNapiValue, Function, ToNapiValue, Unknown},
+  Env, Error, JsObject, NapiRaw, NapiValue, Result, Status, ValueType,
+};
+
+/// `crypto.getRandomValues` throws a `QuotaExceededError` above this length
+const GET_RANDOM_VALUES_MAX_LENGTH: usize = 65536;
+
+thread_local! {
+  static RANDOM_SOURCE: RefCell<RandomSource> = const { RefCell::new(RandomSource::Crypto) };
+}
+
+pub type RandomGenerator = Box<dyn FnMut(&mut [u8]) -> Result<()>>;
+
+/// Where [`Env::crypto_random_bytes`] gets its bytes from.
+///
+/// The source is set per JavaScript thread, so it's scoped to the `Env` of the main thread or
+/// of a worker.
+pub enum RandomSource {
+  /// `crypto.randomFillSync` of Node.js, or `crypto.getRandomValues` in other runtimes
+  Crypto,
+  /// A deterministic sequence generated from the seed, for tests.
+  ///
+  /// It's **not** cryptographically secure.
+  Seeded(u64),
+  /// Any other generator, called with the buffer to fill
+  Custom(RandomGenerator),
+}
+
+impl Env {
+  /// Get `len` random bytes from the current [`RandomSource`], they are cryptographically secure
+  /// unless the source was replaced.
+  pub fn crypto_random_bytes(&self, len: usize) -> Result<Vec<u8>> {
+    let mut bytes = vec![0; len];
+    self.fill_random_bytes(&mut bytes)?;
+    Ok(bytes)
+  }
+
+  /// Fill `dest` with random bytes from the current [`RandomSource`].
+  pub fn fill_random_bytes(&self, dest: &mut [u8]) -> Result<()> {
+    // don't hold the `RefCell` while calling into JavaScript
+    if RANDOM_SOURCE.with(|source| matches!(*source.borrow(), RandomSource::Crypto)) {
+      return fill_from_crypto(self, dest);
+    }
+    RANDOM_SOURCE.with(|source| match &mut *source.borrow_mut() {
+      RandomSource::Crypto => unreachable!(),
+      RandomSource::Seeded(state) => {
+        for chunk in dest.chunks_mut(8) {
+          let next = split_mix_64(state).to_le_bytes();
+          chunk.copy_from_slice(&next[..chunk.len()]);
+        }
+        Ok(())
+      }
+      RandomSource::Custom(generator) => generator(dest),
+    })
+  }
+
+  /// Replace the [`RandomSource`] of the current thread, e.g. with [`RandomSource::Seeded`]
+  /// when an addon is under test.
+  pub fn set_random_source(&self, source: RandomSource) {
+    RANDOM_SOURCE.with(|current| *current.borrow_mut() = source);
+  }
+}
+
+fn fill_from_crypto(env: &Env, dest: &mut [u8]) -> Result<()> {
+  if dest.is_empty() {
+    return Ok(());
+  }
+  let global = env.get_global()?;
+  let process: Unknown = global.get_named_property_unchecked("process")?;
+  if process.get_type()? == ValueType::Object {
+    let process = unsafe { JsObject::from_napi_value(env.raw(), process.raw())? };
+    let get_builtin_module: Unknown = process.get_named_property_unchecked("getBuiltinModule")?;
+    if get_builtin_module.get_type()? == ValueType::Function {
+      let get_builtin_module: Function<&str, JsObject> =
+        unsafe { Function::from_napi_value(env.raw(), get_builtin_module.raw())? };
+      let crypto = get_builtin_module.apply(&process, "node:crypto")?;
+      let random_fill_sync: Function<Unknown, Unknown> =
+        crypto.get_named_property_unchecked("randomFillSync")?;
+      return fill_with(env, dest, |buffer| random_fill_sync.apply(&crypto, buffer));
+    }
+  }
+  let crypto: Unknown = global.get_named_property_unchecked("crypto")?;
+  if crypto.get_type()? != ValueType::Object {
+    return Err(Error::new(
+      Status::GenericFailure,
+      "Neither `crypto.randomFillSync` nor `crypto.getRandomValues` is available",
+    ));
+  }
+  let crypto = unsafe { JsObject::from_napi_value(env.raw(), crypto.raw())? };
+  let get_random_values: Function<Unknown, Unknown> =
+    crypto.get_named_property_unchecked("getRandomValues")?;
+  for chunk in dest.chunks_mut(GET_RANDOM_VALUES_MAX_LENGTH) {
+    fill_with(env, chunk, |buffer| {
+      get_random_values.apply(&crypto, buffer)
+    })?;
+  }
+  Ok(())
+}
+
+/// Let `fill` fill a JavaScript `Buffer`, then copy it into `dest`
+fn fill_with<F: FnOnce(Unknown) -> Result<Unknown>>(
+  env: &Env,
+  dest: &mut [u8],
+  fill: F,
+) -> Result<()> {
+  let buffer = unsafe { Buffer::to_napi_value(env.raw(), vec![0; dest.len()].into())? };
+  fill(unsafe { Unknown::from_raw_unchecked(env.raw(), buffer) })?;
+  dest.copy_from_slice(unsafe { <&[u8]>::from_napi_value(env.raw(), buffer)? });
+  Ok(())
+}
+
+/// <https://prng.di.unimi.it/splitmix64.c>
+fn split_mix_64(state: &mut u64) -> u64 {
+  *state = state.wrapping_add(0x9e3779b97f4a7c15);
+  let mut z = *state;
+  z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+  z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+  z ^ (z >> 31)
+}
@@ -0,0 +1,216 @@
+//! Record the arguments and return values of `#[napi(record)]` functions, then replay them in
+//! pure Rust tests without a JavaScript runtime.
+//!
+//! Recording is off until [`start_recording`] is called, or until the addon is loaded with the
+//! `NAPI_RS_RECORD` environment variable set to a file path, the calls are appended to that file.
+//! Each call is written as one JSON line:
+//!
+//! ```json
+//! {"args":[1,2],"function":"add","ret":{"Ok":3}}
+//! {"args":["http"],"function":"parsePort","ret":{"Err":"InvalidArg, invalid digit found in string"}}
+//! ```
+//!
+//! The arguments converted from JavaScript values and the return value must implement
+//! `serde::Serialize`. `Env`, `this` and callback arguments are not recorded. Methods are recorded
+//! as `Class.method`.
+//!
+//! The recording stops at the first call which can't be written, the error is kept for
+//! [`take_error`].
+//!
+//! ```ignore
+//! #[napi(record)]
+//! pub fn add(a: u32, b: u32) -> u32 {
+//!   a + b
+//! }
+//!
+//! #[test]
+//! fn replay_recorded_calls() {
+//!   let file = std::io::BufReader::new(std::fs::File::open("calls.jsonl").unwrap());
+//!   for call in napi::record::replay(file) {
+//!     let call = call.unwrap();
+//!     if call.function == "add" {
+//!       let (a, b) = call.args().unwrap();
+//!       assert_eq!(call.ret::<u32>().unwrap(), Ok(add(a, b)));
+//!     }
+//!   }
+//! }
+//! ```
+
+use std::fmt::Display;
+use std::fs::OpenOptions;
+use std::io::{BufRead, LineWriter, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::{json, Value};
+
+use crate::{Error, Result, Status};
+
+static RECORDING: AtomicBool = AtomicBool::new(false);
+static RECORDER: Mutex<Option<Box<dyn Write + Send>>> = Mutex::new(None);
+/// Why the recording stopped on its own, if it did
+static RECORDING_ERROR: Mutex<Option<Error>> = Mutex::new(None);
+
+/// Write the calls of `#[napi(record)]` functions to `writer` from now on, replacing the
+/// previous writer if any.
+///
+/// Every call is flushed, wrap the writer in a `BufWriter` if that's too slow.
+pub fn start_recording<W: Write + Send + 'static>(writer: W) {
+  let mut recorder = RECORDER.lock().unwrap_or_else(|e| e.into_inner());
+  *recorder = Some(Box::new(writer));
+  RECORDING.store(true, Ordering::Release);
+}
+
+/// Stop recording and give back the writer
+pub fn stop_recording() -> Option<Box<dyn Write + Send>> {
+  let mut recorder = RECORDER.lock().unwrap_or_else(|e| e.into_inner());
+  RECORDING.store(false, Ordering::Release);
+  recorder.take()
+}
+
+pub fn is_recording() -> bool {
+  RECORDING.load(Ordering::Acquire)
+}
+
+/// Take the error which stopped the recording: the file of `NAPI_RS_RECORD` couldn't be opened, or
+/// a call couldn't be written.
+///
+/// The recorded functions never fail because of the recording, this is the only way to know.
+pub fn take_error() -> Option<Error> {
+  RECORDING_ERROR
+    .lock()
+    .unwrap_or_else(|e| e.into_inner())
+    .take()
+}
+
+fn set_error(error: Error) {
+  *RECORDING_ERROR.lock().unwrap_or_else(|e| e.into_inner()) = Some(error);
+}
+
+/// Called once from `napi_register_module_v1`
+pub(crate) fn start_recording_from_env() {
+  let Some(path) = std::env::var_os("NAPI_RS_RECORD") else {
+    return;
+  };
+  match OpenOptions::new().create(true).append(true).open(&path) {
+    Ok(file) => start_recording(LineWriter::new(file)),
+    Err(e) => set_error(Error::new(
+      Status::GenericFailure,
+      format!("Failed to open {path:?} to record calls: {e}"),
+    )),
+  }
+}
+
+// only serialize the arguments when recording, they are moved into the function afterwards
+#[doc(hidden)]
+pub fn capture_args<F: FnOnce() -> Vec<Value>>(capture: F) -> Option<Vec<Value>> {
+  is_recording().then(capture)
+}
+
+#[doc(hidden)]
+pub fn to_value<T: Serialize + ?Sized>(value: &T) -> Value {
+  serde_json::to_value(value)
+    .unwrap_or_else(|e| Value::String(format!("<failed to serialize: {e}>")))
+}
+
+#[doc(hidden)]
+pub fn record_return<T: Serialize>(function: &str, args: Option<Vec<Value>>, ret: &T) {
+  if let Some(args) = args {
+    write_call(function, args, Ok(to_value(ret)));
+  }
+}
+
+#[doc(hidden)]
+pub fn record_result<T: Serialize, E: Display>(
+  function: &str,
+  args: Option<Vec<Value>>,
+  ret: &std::result::Result<T, E>,
+) {
+  if let Some(args) = args {
+    let ret = match ret {
+      Ok(value) => Ok(to_value(value)),
+      Err(e) => Err(e.to_string()),
+    };
+    write_call(function, args, ret);
+  }
+}
+
+fn write_call(function: &str, args: Vec<Value>, ret: std::result::Result<Value, String>) {
+  let ret = match ret {
+    Ok(value) => json!({ "Ok": value }),
+    Err(message) => json!({ "Err": message }),
+  };
+  let mut line = json!({ "function": function, "args": args, "ret": ret }).to_string();
+  line.push('\n');
+  let mut recorder = RECORDER.lock().unwrap_or_else(|e| e.into_inner());
+  if let Some(writer) = recorder.as_mut() {
+    // the call itself succeeded, a broken recording must not turn it into an error
+    if let Err(e) = writer
+      .write_all(line.as_bytes())
+      .and_then(|_| writer.flush())
+    {
+      RECORDING.store(false, Ordering::Release);
+      recorder.take();
+      set_error(Error::new(
+        Status::GenericFailure,
+        format!("Failed to record the call of `{function}`: {e}"),
+      ));
+    }
+  }
+}
+
+/// A call read back by [`replay`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecordedCall {
+  /// The JavaScript name of the function
+  pub function: String,
+  pub args: Vec<Value>,
+  /// The returned value, or the message of the returned error
+  pub ret: std::result::Result<Value, String>,
+}
+
+impl RecordedCall {
+  /// Deserialize the arguments into a tuple, e.g. `(u32, String)`, or `(u32,)` for a single one
+  pub fn args<T: DeserializeOwned>(&self) -> Result<T> {
+    Ok(serde_json::from_value(Value::Array(self.args.clone()))?)
+  }
+
+  pub fn ret<T: DeserializeOwned>(&self) -> Result<std::result::Result<T, String>> {
+    match &self.ret {
+      Ok(value) => Ok(Ok(serde_json::from_value(value.clone())?)),
+      Err(message) => Ok(Err(message.clone())),
+    }
+  }
+}
+
+/// Read the calls written by a recording, in call order
+pub fn replay<R: BufRead>(reader: R) -> impl Iterator<Item = Result<RecordedCall>> {
+  reader
+    .lines()
+    .filter(|line| !matches!(line, Ok(line) if line.trim().is_empty()))
+    .map(|line| parse_call(&line?))
+}
+
+fn parse_call(line: &str) -> Result<RecordedCall> {
+  let invalid = || Error::new(Status::InvalidArg, format!("Invalid recorded call: {line}"));
+  let mut call: Value = serde_json::from_str(line)?;
+  let function = call["function"].as_str().ok_or_else(invalid)?.to_owned();
+  let args = match call["args"].take() {
+    Value::Array(args) => args,
+    _ => return Err(invalid()),
+  };
+  let ret = match call["ret"].take() {
+    Value::Object(mut ret) => match (ret.remove("Ok"), ret.remove("Err")) {
+      (Some(value), None) => Ok(value),
+      (None, Some(Value::String(message))) => Err(message),
+      _ => return Err(invalid()),
+    },
+    _ => return Err(invalid()),
+  };
+  Ok(RecordedCall {
+    function,
+    args,
+    ret,
+  })
+}
@@ -0,0 +1,386 @@
+use std::cell::Cell;
+use std::ffi::c_void;
+use std::ops::{Deref, DerefMut};
+use std::ptr;
+use std::slice;
+use std::sync::atomic::{AtomicU8, AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use crate::{check_status, sys, Env, Error, JsArrayBuffer, NapiValue, Result, Status};
+
+const FREE: u8 = 0;
+const WRITING: u8 = 1;
+const READY: u8 = 2;
+const HELD: u8 = 3;
+
+/// Who owns a slot of a [`RingBufferExchange`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SlotState {
+  /// Waiting for the producer
+  Free,
+  /// Being filled by the producer, see [`RingBufferProducer::try_acquire`]
+  Writing,
+  /// Filled, waiting for [`RingBufferExchange::take`]
+  Ready,
+  /// Read by JavaScript until [`RingBufferExchange::release`]
+  Held,
+}
+
+/// A frame handed to JavaScript by [`RingBufferExchange::take`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExchangedFrame {
+  /// The slot of the frame, its data is at the start of `exchange.buffer(index)`
+  pub index: usize,
+  /// The number of bytes written by the producer
+  pub len: usize,
+}
+
+/// The memory of the slots, freed once its owners and every ArrayBuffer over it are gone
+pub(crate) struct SlotMemory {
+  data: *mut u64,
+  words: usize,
+  slot_size: usize,
+  /// The distance between two slots, `slot_size` rounded up to 8 bytes
+  stride: usize,
+}
+
+unsafe impl Send for SlotMemory {}
+unsafe impl Sync for SlotMemory {}
+
+impl SlotMemory {
+  /// `slot_count` zeroed slots of `slot_size` bytes
+  pub(crate) fn new(slot_count: usize, slot_size: usize) -> Result<Arc<Self>> {
+    if slot_count == 0 || slot_size == 0 {
+      return Err(Error::new(
+        Status::InvalidArg,
+        "A ring buffer needs at least one slot of at least one byte",
+      ));
+    }
+    let stride = slot_size.div_ceil(8) * 8;
+    let words = stride / 8 * slot_count;
+    let data = Box::into_raw(vec![0u64; words].into_boxed_slice()).cast::<u64>();
+    Ok(Arc::new(SlotMemory {
+      data,
+      words,
+      slot_size,
+      stride,
+    }))
+  }
+
+  pub(crate) fn slot_size(&self) -> usize {
+    self.slot_size
+  }
+
+  fn slot_ptr(&self, index: usize) -> *mut u8 {
+    unsafe { self.data.cast::<u8>().add(index * self.stride) }
+  }
+
+  /// # Safety
+  ///
+  /// Nothing may write to the slot while the slice is alive
+  pub(crate) unsafe fn slot(&self, index: usize) -> &[u8] {
+    unsafe { slice::from_raw_parts(self.slot_ptr(index), self.slot_size) }
+  }
+
+  /// # Safety
+  ///
+  /// Nothing else may access the slot while the slice is alive
+  #[allow(clippy::mut_from_ref)]
+  pub(crate) unsafe fn slot_mut(&self, index: usize) -> &mut [u8] {
+    unsafe { slice::from_raw_parts_mut(self.slot_ptr(index), self.slot_size) }
+  }
+
+  /// An external ArrayBuffer over each slot, kept alive by a reference
+  pub(crate) fn create_buffers(
+    self: &Arc<Self>,
+    env: sys::napi_env,
+    slot_count: usize,
+  ) -> Result<Vec<sys::napi_ref>> {
+    let mut buffers = Vec::with_capacity(slot_count);
+    for index in 0..slot_count {
+      match self.create_buffer(env, index) {
+        Ok(buffer) => buffers.push(buffer),
+        Err(err) => {
+          for reference in buffers {
+            unsafe { sys::napi_delete_reference(env, reference) };
+          }
+          return Err(err);
+        }
+      }
+    }
+    Ok(buffers)
+  }
+
+  fn create_buffer(self: &Arc<Self>, env: sys::napi_env, index: usize) -> Result<sys::napi_ref> {
+    let hint = Arc::into_raw(self.clone());
+    let mut buffer = ptr::null_mut();
+    let status = unsafe {
+      sys::napi_create_external_arraybuffer(
+        env,
+        self.slot_ptr(index).cast(),
+        self.slot_size,
+        Some(release_memory),
+        hint.cast_mut().cast(),
+        &mut buffer,
+      )
+    };
+    if status != sys::Status::napi_ok {
+      drop(unsafe { Arc::from_raw(hint) });
+      if status == sys::Status::napi_no_external_buffers_allowed {
+        return Err(Error::new(
+          Status::GenericFailure,
+          "External ArrayBuffers are not allowed in this runtime, the slots can't be shared",
+        ));
+      }
+      check_status!(status, "Failed to create the ArrayBuffer of slot {}", index)?;
+    }
+    let mut reference = ptr::null_mut();
+    check_status!(
+      unsafe { sys::napi_create_reference(env, buffer, 1, &mut reference) },
+      "Failed to create a reference to the ArrayBuffer of slot {}",
+      index
+    )?;
+    Ok(reference)
+  }
+}
+
+impl Drop for SlotMemory {
+  fn drop(&mut self) {
+    drop(unsafe { Box::from_raw(ptr::slice_from_raw_parts_mut(self.data, self.words)) });
+  }
+}
+
+/// The ArrayBuffer of the slot `index`, among the ones of [`SlotMemory::create_buffers`]
+pub(crate) fn slot_buffer(
+  env: sys::napi_env,
+  buffers: &[sys::napi_ref],
+  index: usize,
+) -> Result<JsArrayBuffer> {
+  let reference = buffers.get(index).ok_or_else(|| out_of_range(index))?;
+  let mut buffer = ptr::null_mut();
+  check_status!(unsafe { sys::napi_get_reference_value(env, *reference, &mut buffer) })?;
+  Ok(unsafe { JsArrayBuffer::from_raw_unchecked(env, buffer) })
+}
+
+struct RingShared {
+  memory: Arc<SlotMemory>,
+  states: Box<[AtomicU8]>,
+  lens: Box<[AtomicUsize]>,
+}
+
+/// A fixed set of ArrayBuffers rotated between a Rust producer and a JavaScript consumer, for
+/// frame pipelines which can't allocate a new buffer per frame.
+///
+/// Each slot is handed off explicitly: the producer fills a free slot and commits it, JavaScript
+/// takes the committed slots in order, reads them through the same ArrayBuffer every time and
+/// releases them to the producer. The producer waits, or drops frames, while the slot after the
+/// last one it committed is not released yet.
+///
+/// ```rust,ignore
+/// let (exchange, mut producer) = RingBufferExchange::new(&env, 4, 1920 * 1080 * 4)?;
+/// std::thread::spawn(move || loop {
+///   if let Some(mut frame) = producer.try_acquire() {
+///     let len = decode_next_frame(&mut frame);
+///     frame.commit(len);
+///   }
+/// });
+/// // on the JavaScript thread, like in a method polled by JavaScript
+/// if let Some(frame) = exchange.take() {
+///   // hand `exchange.buffer(frame.index)?` and `frame.len` to JavaScript,
+///   // which calls `exchange.release(frame.index)` when it's done with it
+/// }
+/// ```
+///
+/// JavaScript must only touch the slots it holds, the others may be written by the producer at
+/// any time.
+///
+/// The exchange lives on the JavaScript thread and must be dropped before its env is torn down,
+/// like by keeping it in a `#[napi]` class. The ArrayBuffers are external, runtimes not allowing
+/// external ArrayBuffers, like Electron, are not supported.
+pub struct RingBufferExchange {
+  env: sys::napi_env,
+  shared: Arc<RingShared>,
+  buffers: Vec<sys::napi_ref>,
+  cursor: Cell<usize>,
+}
+
+/// The Rust side of a [`RingBufferExchange`], it can be sent to another thread
+pub struct RingBufferProducer {
+  shared: Arc<RingShared>,
+  cursor: usize,
+}
+
+impl RingBufferExchange {
+  /// Create `slot_count` ArrayBuffers of `slot_size` bytes, and the producer filling them
+  pub fn new(
+    env: &Env,
+    slot_count: usize,
+    slot_size: usize,
+  ) -> Result<(RingBufferExchange, RingBufferProducer)> {
+    let memory = SlotMemory::new(slot_count, slot_size)?;
+    let buffers = memory.create_buffers(env.0, slot_count)?;
+    let shared = Arc::new(RingShared {
+      memory,
+      states: (0..slot_count).map(|_| AtomicU8::new(FREE)).collect(),
+      lens: (0..slot_count).map(|_| AtomicUsize::new(0)).collect(),
+    });
+    Ok((
+      RingBufferExchange {
+        env: env.0,
+        shared: shared.clone(),
+        buffers,
+        cursor: Cell::new(0),
+      },
+      RingBufferProducer { shared, cursor: 0 },
+    ))
+  }
+
+  pub fn slot_count(&self) -> usize {
+    self.buffers.len()
+  }
+
+  pub fn slot_size(&self) -> usize {
+    self.shared.memory.slot_size
+  }
+
+  /// The ArrayBuffer of the slot `index`, the same one on every call
+  pub fn buffer(&self, index: usize) -> Result<JsArrayBuffer> {
+    slot_buffer(self.env, &self.buffers, index)
+  }
+
+  /// Take the next committed frame in order, `None` if the producer has not committed it yet
+  pub fn take(&self) -> Option<ExchangedFrame> {
+    let index = self.cursor.get();
+    self.shared.states[index]
+      .compare_exchange(READY, HELD, Ordering::Acquire, Ordering::Relaxed)
+      .ok()?;
+    self.cursor.set((index + 1) % self.slot_count());
+    Some(ExchangedFrame {
+      index,
+      len: self.shared.lens[index].load(Ordering::Relaxed),
+    })
+  }
+
+  /// Hand the slot `index` taken by [`RingBufferExchange::take`] back to the producer, an
+  /// `InvalidArg` error if it's not held by JavaScript
+  pub fn release(&self, index: usize) -> Result<()> {
+    let state = self
+      .shared
+      .states
+      .get(index)
+      .ok_or_else(|| out_of_range(index))?;
+    state
+      .compare_exchange(HELD, FREE, Ordering::Release, Ordering::Relaxed)
+      .map(|_| ())
+      .map_err(|_| {
+        Error::new(
+          Status::InvalidArg,
+          format!("The slot {index} is not held by JavaScript"),
+        )
+      })
+  }
+
+  /// Who owns the slot `index` right now
+  pub fn state(&self, index: usize) -> Result<SlotState> {
+    let state = self
+      .shared
+      .states
+      .get(index)
+      .ok_or_else(|| out_of_range(index))?;
+    Ok(slot_state(state.load(Ordering::Acquire)))
+  }
+}
+
+impl Drop for RingBufferExchange {
+  fn drop(&mut self) {
+    for reference in self.buffers.drain(..) {
+      unsafe { sys::napi_delete_reference(self.env, reference) };
+    }
+  }
+}
+
+impl RingBufferProducer {
+  /// The next slot in order to fill, `None` while JavaScript has not released it
+  pub fn try_acquire(&mut self) -> Option<FrameWriter<'_>> {
+    let index = self.cursor;
+    self.shared.states[index]
+      .compare_exchange(FREE, WRITING, Ordering::Acquire, Ordering::Relaxed)
+      .ok()?;
+    Some(FrameWriter {
+      producer: self,
+      index,
+    })
+  }
+
+  pub fn slot_size(&self) -> usize {
+    self.shared.memory.slot_size
+  }
+}
+
+/// A slot being filled, see [`RingBufferProducer::try_acquire`].
+///
+/// Dropped without [`FrameWriter::commit`], the slot is filled again by the next `try_acquire`.
+pub struct FrameWriter<'a> {
+  producer: &'a mut RingBufferProducer,
+  index: usize,
+}
+
+impl FrameWriter<'_> {
+  pub fn index(&self) -> usize {
+    self.index
+  }
+
+  /// Hand the first `len` bytes of the slot to JavaScript
+  pub fn commit(self, len: usize) {
+    let shared = &self.producer.shared;
+    shared.lens[self.index].store(len.min(shared.memory.slot_size), Ordering::Relaxed);
+    shared.states[self.index].store(READY, Ordering::Release);
+    self.producer.cursor = (self.index + 1) % shared.states.len();
+    // the state is set already
+    std::mem::forget(self);
+  }
+}
+
+impl Deref for FrameWriter<'_> {
+  type Target = [u8];
+
+  fn deref(&self) -> &[u8] {
+    unsafe { self.producer.shared.memory.slot(self.index) }
+  }
+}
+
+impl DerefMut for FrameWriter<'_> {
+  fn deref_mut(&mut self) -> &mut [u8] {
+    unsafe { self.producer.shared.memory.slot_mut(self.index) }
+  }
+}
+
+impl Drop for FrameWriter<'_> {
+  fn drop(&mut self) {
+    self.producer.shared.states[self.index].store(FREE, Ordering::Release);
+  }
+}
+
+fn slot_state(state: u8) -> SlotState {
+  match state {
+    FREE => SlotState::Free,
+    WRITING => SlotState::Writing,
+    READY => SlotState::Ready,
+    _ => SlotState::Held,
+  }
+}
+
+pub(crate) fn out_of_range(index: usize) -> Error {
+  Error::new(
+    Status::InvalidArg,
+    format!("The slot {index} is out of range"),
+  )
+}
+
+unsafe extern "C" fn release_memory(
+  _env: sys::napi_env,
+  _finalize_data: *mut c_void,
+  finalize_hint: *mut c_void,
+) {
+  drop(unsafe { Arc::<SlotMemory>::from_raw(finalize_hint.cast()) });
+}
@@ -0,0 +1,78 @@
+use std::cell::Cell;
+use std::ptr;
+
+use crate::{
+  bindgen_runtime::{FunctionCallContext, Unknown},
+  check_status, sys, Env, Error, NapiRaw, Result, Status, ValueType,
+};
+
+impl Env {
+  /// Queue `callback` to be called in a microtask, like `queueMicrotask(callback)`.
+  ///
+  /// `callback` is any JavaScript function, a `Function` or a `JsFunction`, it's called without
+  /// arguments once the current JavaScript code and the microtasks queued before it are done, so
+  /// before any Promise reaction queued after it.
+  ///
+  /// An exception thrown by `callback` is reported like any other uncaught exception.
+  pub fn queue_microtask<F: NapiRaw>(&self, callback: &F) -> Result<()> {
+    let callback = unsafe { callback.raw() };
+    let mut value_type = 0;
+    check_status!(unsafe { sys::napi_typeof(self.0, callback, &mut value_type) })?;
+    if ValueType::from(value_type) != ValueType::Function {
+      return Err(Error::new(
+        Status::FunctionExpected,
+        "The callback of `queueMicrotask` must be a function",
+      ));
+    }
+    self.call_global_function("queueMicrotask", callback)
+  }
+
+  /// Call `callback` on the next iteration of the event loop, after the pending I/O callbacks, like
+  /// `setImmediate(callback)`.
+  ///
+  /// `callback` is kept in a JavaScript function until it's called, an error returned by it is
+  /// thrown to JavaScript and reported like any other uncaught exception.
+  ///
+  /// The global `setImmediate` of Node.js is needed, a `GenericFailure` is returned in the runtimes
+  /// without it.
+  pub fn set_immediate<F>(&self, callback: F) -> Result<()>
+  where
+    F: 'static + FnOnce(Env) -> Result<()>,
+  {
+    let callback = Cell::new(Some(callback));
+    let immediate = self.create_function_from_closure::<(), (), _>(
+      "immediate",
+      move |ctx: FunctionCallContext| match callback.take() {
+        Some(callback) => callback(*ctx.env),
+        None => Ok(()),
+      },
+    )?;
+    self.call_global_function("setImmediate", unsafe { immediate.raw() })
+  }
+
+  fn call_global_function(&self, name: &str, argument: sys::napi_value) -> Result<()> {
+    let global = self.get_global()?;
+    let function: Unknown = global.get_named_property_unchecked(name)?;
+    if function.get_type()? != ValueType::Function {
+      return Err(Error::new(
+        Status::GenericFailure,
+        format!("`{name}` is not available"),
+      ));
+    }
+    let mut result = ptr::null_mut();
+    check_status!(
+      unsafe {
+        sys::napi_call_function(
+          self.0,
+          global.raw(),
+          function.raw(),
+          1,
+          &argument,
+          &mut result,
+        )
+      },
+      "Failed to call `{}`",
+      name
+    )
+  }
+}
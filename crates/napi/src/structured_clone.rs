@@ -0,0 +1,51 @@
+use crate::{
+  bindgen_runtime::{FromNapiValue, Function, Unknown},
+  Env, Error, JsObject, NapiRaw, NapiValue, Result, Status, ValueType,
+};
+
+impl Env {
+  /// Deep-clone `value` with the structured clone algorithm, like `structuredClone(value)`.
+  ///
+  /// Unlike a serde round trip, `Map`s, `Set`s, `Date`s, `RegExp`s, typed arrays and cycles are
+  /// kept. Values which can't be cloned, like functions and symbols, fail with the
+  /// `DataCloneError` thrown by the runtime.
+  ///
+  /// The global `structuredClone` is used, it falls back to `v8.serialize` and `v8.deserialize`
+  /// from `process.getBuiltinModule` in the runtimes without it.
+  pub fn structured_clone<V: NapiRaw>(&self, value: &V) -> Result<Unknown> {
+    let value = unsafe { Unknown::from_raw_unchecked(self.0, value.raw()) };
+    let global = self.get_global()?;
+    let structured_clone: Unknown = global.get_named_property_unchecked("structuredClone")?;
+    if structured_clone.get_type()? == ValueType::Function {
+      let structured_clone: Function<Unknown, Unknown> =
+        unsafe { Function::from_napi_value(self.0, structured_clone.raw())? };
+      return structured_clone.call(value);
+    }
+    let v8 = self.v8_module()?.ok_or_else(|| {
+      Error::new(
+        Status::GenericFailure,
+        "Neither `structuredClone` nor `v8.serialize` is available",
+      )
+    })?;
+    let serialize: Function<Unknown, Unknown> = v8.get_named_property_unchecked("serialize")?;
+    let deserialize: Function<Unknown, Unknown> = v8.get_named_property_unchecked("deserialize")?;
+    let serialized = serialize.apply(&v8, value)?;
+    deserialize.apply(&v8, serialized)
+  }
+
+  /// `process.getBuiltinModule("node:v8")`, `None` if it's not available
+  fn v8_module(&self) -> Result<Option<JsObject>> {
+    let process: Unknown = self.get_global()?.get_named_property_unchecked("process")?;
+    if process.get_type()? != ValueType::Object {
+      return Ok(None);
+    }
+    let process = unsafe { JsObject::from_napi_value(self.0, process.raw())? };
+    let get_builtin_module: Unknown = process.get_named_property_unchecked("getBuiltinModule")?;
+    if get_builtin_module.get_type()? != ValueType::Function {
+      return Ok(None);
+    }
+    let get_builtin_module: Function<&str, JsObject> =
+      unsafe { Function::from_napi_value(self.0, get_builtin_module.raw())? };
+    get_builtin_module.apply(&process, "node:v8").map(Some)
+  }
+}
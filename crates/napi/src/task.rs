@@ -25,3 +25,36 @@ pub trait Task: Send + Sized {
     Ok(())
   }
 }
+
+/// The [`Task`] running the closures of [`Env::spawn_blocking`](crate::Env::spawn_blocking)
+pub(crate) struct BlockingTask<F, R> {
+  pub(crate) compute: Option<F>,
+  pub(crate) resolver: Option<R>,
+}
+
+impl<T, V, F, R> Task for BlockingTask<F, R>
+where
+  T: 'static + Send,
+  V: ToNapiValue + TypeName,
+  F: Send + FnOnce() -> Result<T>,
+  R: Send + FnOnce(Env, T) -> Result<V>,
+{
+  type Output = T;
+  type JsValue = V;
+
+  fn compute(&mut self) -> Result<T> {
+    let compute = self
+      .compute
+      .take()
+      .expect("The closure of spawn_blocking is called once");
+    compute()
+  }
+
+  fn resolve(&mut self, env: Env, output: T) -> Result<V> {
+    let resolver = self
+      .resolver
+      .take()
+      .expect("The resolver of spawn_blocking is called once");
+    resolver(env, output)
+  }
+}
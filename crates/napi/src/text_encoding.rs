@@ -0,0 +1,275 @@
+//! Conversions between strings and the legacy encodings of the
+//! [Encoding Standard](https://encoding.spec.whatwg.org/) (Shift_JIS, GBK, windows-1251, ...),
+//! backed by [encoding_rs](https://docs.rs/encoding_rs).
+//!
+//! `Buffer` only handles a handful of encodings, and `TextDecoder` only knows the legacy ones when
+//! Node.js is built with full ICU, these helpers cover the rest without pulling `iconv-lite` in.
+//!
+//! The `text_encoding` feature makes them available to JS on the `textEncoding` object of the
+//! addon:
+//!
+//! ```js
+//! const { textEncoding } = require('./index.node')
+//! const bytes = textEncoding.encode('こんにちは', 'shift_jis') // <Buffer 82 b1 82 f1 82 c9 82 bf 82 cd>
+//! textEncoding.decode(bytes, 'shift_jis') // 'こんにちは'
+//!
+//! // same options and `decode` signature as `TextDecoder`
+//! const decoder = textEncoding.createDecoder('gbk', { fatal: true })
+//! let text = ''
+//! for await (const chunk of stream) {
+//!   text += decoder.decode(chunk, { stream: true })
+//! }
+//! text += decoder.decode()
+//! ```
+
+use std::borrow::Cow;
+use std::ptr;
+
+use encoding_rs::{CoderResult, Decoder, DecoderResult, Encoding, UTF_16BE, UTF_16LE};
+
+use crate::{
+  bindgen_prelude::{
+    register_module_export, Buffer, CallbackInfo, FromNapiValue, Object, ToNapiValue,
+  },
+  check_status, sys, Env, Error, JsObject, NapiRaw, Result, Status,
+};
+
+/// Decode bytes in the encoding named by `label` (e.g. `"sjis"`, `"gbk"`, `"cp1251"`).
+///
+/// Same as `new TextDecoder(label).decode(input)`: malformed sequences are replaced with
+/// `U+FFFD` and a leading BOM of the encoding is skipped.
+pub fn decode(input: &[u8], label: &str) -> Result<String> {
+  TextDecoder::new(label, false, false)?.decode(input, false)
+}
+
+/// Encode `input` in the encoding named by `label`.
+///
+/// Characters that can't be represented in the target encoding are written as HTML decimal
+/// character references (`&#12345;`), like browsers do when submitting forms. Unlike the Encoding
+/// Standard encoders, `utf-16le` and `utf-16be` produce UTF-16 rather than UTF-8.
+pub fn encode<'a>(input: &'a str, label: &str) -> Result<Cow<'a, [u8]>> {
+  let encoding = encoding_for_label(label)?;
+  if encoding == UTF_16LE {
+    return Ok(Cow::Owned(
+      input.encode_utf16().flat_map(u16::to_le_bytes).collect(),
+    ));
+  }
+  if encoding == UTF_16BE {
+    return Ok(Cow::Owned(
+      input.encode_utf16().flat_map(u16::to_be_bytes).collect(),
+    ));
+  }
+  let (output, _, _) = encoding.encode(input);
+  Ok(output)
+}
+
+/// A streaming decoder with the semantics of the WHATWG `TextDecoder`.
+///
+/// Multi-byte sequences split across chunks are kept in the decoder state as long as `stream` is
+/// `true`, the state is reset after the last chunk.
+pub struct TextDecoder {
+  encoding: &'static Encoding,
+  decoder: Decoder,
+  fatal: bool,
+  ignore_bom: bool,
+}
+
+impl TextDecoder {
+  /// With `fatal`, malformed input is an error instead of being replaced with `U+FFFD`. With
+  /// `ignore_bom`, a leading BOM is kept in the output.
+  pub fn new(label: &str, fatal: bool, ignore_bom: bool) -> Result<Self> {
+    let encoding = encoding_for_label(label)?;
+    Ok(Self {
+      encoding,
+      decoder: new_decoder(encoding, ignore_bom),
+      fatal,
+      ignore_bom,
+    })
+  }
+
+  /// The name of the encoding, lowercased like `TextDecoder.prototype.encoding`.
+  pub fn encoding(&self) -> String {
+    self.encoding.name().to_ascii_lowercase()
+  }
+
+  pub fn fatal(&self) -> bool {
+    self.fatal
+  }
+
+  pub fn ignore_bom(&self) -> bool {
+    self.ignore_bom
+  }
+
+  pub fn decode(&mut self, input: &[u8], stream: bool) -> Result<String> {
+    let last = !stream;
+    let max_length = if self.fatal {
+      self
+        .decoder
+        .max_utf8_buffer_length_without_replacement(input.len())
+    } else {
+      self.decoder.max_utf8_buffer_length(input.len())
+    }
+    .ok_or_else(|| Error::new(Status::InvalidArg, "Input is too large to decode"))?;
+    let mut output = String::with_capacity(max_length);
+    let malformed = if self.fatal {
+      let (result, _) = self
+        .decoder
+        .decode_to_string_without_replacement(input, &mut output, last);
+      debug_assert!(!matches!(result, DecoderResult::OutputFull));
+      matches!(result, DecoderResult::Malformed(..))
+    } else {
+      let (result, _, _) = self.decoder.decode_to_string(input, &mut output, last);
+      debug_assert!(matches!(result, CoderResult::InputEmpty));
+      false
+    };
+    if last || malformed {
+      self.decoder = new_decoder(self.encoding, self.ignore_bom);
+    }
+    if malformed {
+      return Err(Error::new(
+        Status::InvalidArg,
+        format!(
+          "The encoded data was not valid for encoding {}",
+          self.encoding()
+        ),
+      ));
+    }
+    Ok(output)
+  }
+}
+
+fn encoding_for_label(label: &str) -> Result<&'static Encoding> {
+  Encoding::for_label(label.as_bytes()).ok_or_else(|| {
+    Error::new(
+      Status::InvalidArg,
+      format!("The \"{label}\" encoding is not supported"),
+    )
+  })
+}
+
+fn new_decoder(encoding: &'static Encoding, ignore_bom: bool) -> Decoder {
+  if ignore_bom {
+    encoding.new_decoder_without_bom_handling()
+  } else {
+    encoding.new_decoder_with_bom_removal()
+  }
+}
+
+unsafe fn label_arg(env: sys::napi_env, value: sys::napi_value) -> Result<String> {
+  Ok(
+    unsafe { Option::<String>::from_napi_value(env, value)? }.unwrap_or_else(|| "utf-8".to_owned()),
+  )
+}
+
+fn option_flag(options: Option<&Object>, name: &str) -> Result<bool> {
+  Ok(
+    options
+      .map(|options| options.get::<bool>(name))
+      .transpose()?
+      .flatten()
+      .unwrap_or(false),
+  )
+}
+
+unsafe fn decode_js(env: sys::napi_env, cb: sys::napi_callback_info) -> Result<sys::napi_value> {
+  let cb = CallbackInfo::<3>::new(env, cb, Some(1), false)?;
+  let input = unsafe { <&[u8]>::from_napi_value(env, cb.get_arg(0))? };
+  let label = unsafe { label_arg(env, cb.get_arg(1))? };
+  let options = unsafe { Option::<Object>::from_napi_value(env, cb.get_arg(2))? };
+  let fatal = option_flag(options.as_ref(), "fatal")?;
+  let ignore_bom = option_flag(options.as_ref(), "ignoreBOM")?;
+  let output = TextDecoder::new(&label, fatal, ignore_bom)?.decode(input, false)?;
+  unsafe { String::to_napi_value(env, output) }
+}
+
+unsafe fn encode_js(env: sys::napi_env, cb: sys::napi_callback_info) -> Result<sys::napi_value> {
+  let cb = CallbackInfo::<2>::new(env, cb, Some(1), false)?;
+  let input = unsafe { String::from_napi_value(env, cb.get_arg(0))? };
+  let label = unsafe { label_arg(env, cb.get_arg(1))? };
+  let output = encode(&input, &label)?.into_owned();
+  unsafe { Buffer::to_napi_value(env, output.into()) }
+}
+
+unsafe fn create_decoder_js(
+  env: sys::napi_env,
+  cb: sys::napi_callback_info,
+) -> Result<sys::napi_value> {
+  let cb = CallbackInfo::<2>::new(env, cb, None, false)?;
+  let label = unsafe { label_arg(env, cb.get_arg(0))? };
+  let options = unsafe { Option::<Object>::from_napi_value(env, cb.get_arg(1))? };
+  let fatal = option_flag(options.as_ref(), "fatal")?;
+  let ignore_bom = option_flag(options.as_ref(), "ignoreBOM")?;
+  let decoder = TextDecoder::new(&label, fatal, ignore_bom)?;
+
+  let env = Env::from_raw(env);
+  let mut object = env.create_object()?;
+  object.set("encoding", decoder.encoding())?;
+  object.set("fatal", fatal)?;
+  object.set("ignoreBOM", ignore_bom)?;
+  let mut decode_fn = ptr::null_mut();
+  check_status!(
+    unsafe {
+      sys::napi_create_function(
+        env.raw(),
+        c"decode".as_ptr(),
+        6,
+        Some(decoder_decode_callback),
+        ptr::null_mut(),
+        &mut decode_fn,
+      )
+    },
+    "Failed to create `decode` function of the decoder"
+  )?;
+  check_status!(unsafe {
+    sys::napi_set_named_property(env.raw(), object.raw(), c"decode".as_ptr(), decode_fn)
+  })?;
+  env.wrap(&mut object, decoder, None)?;
+  unsafe { JsObject::to_napi_value(env.raw(), object) }
+}
+
+unsafe fn decoder_decode_js(
+  env: sys::napi_env,
+  cb: sys::napi_callback_info,
+) -> Result<sys::napi_value> {
+  let cb = CallbackInfo::<2>::new(env, cb, None, false)?;
+  let this = unsafe { JsObject::from_napi_value(env, cb.this())? };
+  let js_env = Env::from_raw(env);
+  let decoder = js_env.unwrap::<TextDecoder>(&this).map_err(|_| {
+    Error::new(
+      Status::InvalidArg,
+      "Illegal invocation, `decode` must be called on a decoder created by `createDecoder`",
+    )
+  })?;
+  let input = unsafe { Option::<&[u8]>::from_napi_value(env, cb.get_arg(0))? };
+  let options = unsafe { Option::<Object>::from_napi_value(env, cb.get_arg(1))? };
+  let stream = option_flag(options.as_ref(), "stream")?;
+  let output = decoder.decode(input.unwrap_or_default(), stream)?;
+  unsafe { String::to_napi_value(env, output) }
+}
+
+unsafe extern "C" fn decoder_decode_callback(
+  env: sys::napi_env,
+  cb: sys::napi_callback_info,
+) -> sys::napi_value {
+  unsafe { decoder_decode_js(env, cb) }.unwrap_or_else(|e| {
+    unsafe { crate::JsError::from(e).throw_into(env) };
+    ptr::null_mut()
+  })
+}
+
+js_function!("decode", decode_js, decode_callback, register_decode);
+js_function!("encode", encode_js, encode_callback, register_encode);
+js_function!(
+  "createDecoder",
+  create_decoder_js,
+  create_decoder_callback,
+  register_create_decoder
+);
+
+/// Export `decode`, `encode` and `createDecoder` on the `textEncoding` object of the addon
+pub(crate) fn register_exports() {
+  const JS_MOD: Option<&str> = Some("textEncoding\0");
+  register_module_export(JS_MOD, "decode\0", register_decode);
+  register_module_export(JS_MOD, "encode\0", register_encode);
+  register_module_export(JS_MOD, "createDecoder\0", register_create_decoder);
+}
@@ -10,10 +10,14 @@ use std::sync::{
 };
 
 use crate::bindgen_runtime::{
-  FromNapiValue, JsValuesTupleIntoVec, TypeName, Unknown, ValidateNapiValue,
+  ArgsBuffer, FromNapiValue, JsValuesTupleIntoVec, TypeName, Unknown, ValidateNapiValue,
 };
 use crate::{check_status, sys, Env, Error, JsError, Result, Status};
 
+mod error_reporter;
+
+pub use error_reporter::{ErrorChannel, ThreadsafeErrorReporter};
+
 #[deprecated(since = "2.17.0", note = "Please use `ThreadsafeFunction` instead")]
 pub type ThreadSafeCallContext<T> = ThreadsafeCallContext<T>;
 
@@ -652,21 +656,23 @@ unsafe extern "C" fn call_js_cb<
       env: Env::from_raw(raw_env),
       value: v.data,
     })
-    .and_then(|ret| Ok((ret.into_vec(raw_env)?, v.call_variant, v.callback)))
+    .and_then(|ret| {
+      let mut args = ArgsBuffer::new();
+      if CalleeHandled {
+        let mut js_null = ptr::null_mut();
+        unsafe { sys::napi_get_null(raw_env, &mut js_null) };
+        args.push(js_null);
+      }
+      ret.push_into(raw_env, &mut args)?;
+      Ok((args, v.call_variant, v.callback))
+    })
   });
 
   // Follow async callback conventions: https://nodejs.org/en/knowledge/errors/what-are-the-error-conventions/
   // Check if the Result is okay, if so, pass a null as the first (error) argument automatically.
   // If the Result is an error, pass that as the first argument.
   let status = match ret {
-    Ok((values, call_variant, callback)) => {
-      let args: Vec<sys::napi_value> = if CalleeHandled {
-        let mut js_null = ptr::null_mut();
-        unsafe { sys::napi_get_null(raw_env, &mut js_null) };
-        core::iter::once(js_null).chain(values).collect()
-      } else {
-        values
-      };
+    Ok((args, call_variant, callback)) => {
       let mut return_value = ptr::null_mut();
       let mut status = sys::napi_call_function(
         raw_env,
@@ -687,6 +693,10 @@ unsafe extern "C" fn call_js_cb<
             maybe_raw: error_reference,
             status: Status::from(status),
             reason: "".to_owned(),
+            napi_call: None,
+            js_error_value: None,
+            #[cfg(feature = "error-backtrace")]
+            backtrace: std::sync::Arc::new(std::backtrace::Backtrace::capture()),
           })
         } else {
           unsafe { Return::from_napi_value(raw_env, return_value) }
@@ -0,0 +1,262 @@
+use std::os::raw::c_void;
+use std::ptr;
+use std::sync::{self, Arc};
+
+use super::ThreadsafeFunctionHandle;
+use crate::bindgen_runtime::{Function, Object, Unknown};
+use crate::{check_status, sys, Env, Error, JsError, NapiRaw, Result, Status};
+
+/// Where a [`ThreadsafeErrorReporter`] surfaces the errors on the JavaScript thread
+pub enum ErrorChannel<'env> {
+  /// Throw the error as an uncaught exception, like `process.nextTick(() => { throw error })`
+  Throw,
+  /// `emitter.emit('error', error)`, like on an `EventEmitter` or a stream
+  Emit(Object),
+  /// `callback(error)`
+  Callback(Function<'env, Unknown, Unknown>),
+}
+
+/// The JavaScript side of the channel, kept alive by references until the reporter is finalized
+enum ErrorTarget {
+  Throw,
+  Emit(sys::napi_ref),
+  Callback(sys::napi_ref),
+}
+
+/// Report errors from any thread to the JavaScript thread, instead of creating a
+/// `ThreadsafeFunction` only for the error paths.
+///
+/// ```rust,ignore
+/// #[napi]
+/// pub fn watch(env: &Env, on_error: Function<Unknown, Unknown>) -> Result<()> {
+///   let reporter = ThreadsafeErrorReporter::new(env, ErrorChannel::Callback(on_error))?;
+///   std::thread::spawn(move || {
+///     if let Err(err) = poll_forever() {
+///       reporter.report(err);
+///     }
+///   });
+///   Ok(())
+/// }
+/// ```
+///
+/// The reporter is cheap to clone, and doesn't keep the event loop alive, the errors reported while
+/// the process is exiting may be dropped.
+///
+/// An exception thrown while surfacing an error, like by an `EventEmitter` without an `'error'`
+/// listener, is reported like any other uncaught exception.
+#[derive(Clone)]
+pub struct ThreadsafeErrorReporter {
+  handle: Arc<ThreadsafeFunctionHandle>,
+}
+
+unsafe impl Send for ThreadsafeErrorReporter {}
+unsafe impl Sync for ThreadsafeErrorReporter {}
+
+impl ThreadsafeErrorReporter {
+  pub fn new(env: &Env, channel: ErrorChannel) -> Result<Self> {
+    let target = match channel {
+      ErrorChannel::Throw => ErrorTarget::Throw,
+      ErrorChannel::Emit(emitter) => ErrorTarget::Emit(create_reference(env, &emitter)?),
+      ErrorChannel::Callback(callback) => ErrorTarget::Callback(create_reference(env, &callback)?),
+    };
+    let target = Box::into_raw(Box::new(target));
+
+    let mut async_resource_name = ptr::null_mut();
+    static ASYNC_RESOURCE_NAME: &str = "napi_rs_error_reporter";
+    let handle = ThreadsafeFunctionHandle::null();
+    let mut raw_tsfn = ptr::null_mut();
+    let created = check_status!(unsafe {
+      sys::napi_create_string_utf8(
+        env.0,
+        ASYNC_RESOURCE_NAME.as_ptr().cast(),
+        ASYNC_RESOURCE_NAME.len(),
+        &mut async_resource_name,
+      )
+    })
+    .and_then(|_| {
+      check_status!(
+        unsafe {
+          sys::napi_create_threadsafe_function(
+            env.0,
+            ptr::null_mut(),
+            ptr::null_mut(),
+            async_resource_name,
+            0,
+            1,
+            sync::Arc::downgrade(&handle).into_raw().cast_mut().cast(),
+            Some(finalize_reporter),
+            target.cast(),
+            Some(report_error),
+            &mut raw_tsfn,
+          )
+        },
+        "Failed to create the threadsafe function of the error reporter"
+      )
+    });
+    if let Err(err) = created {
+      let target = unsafe { Box::from_raw(target) };
+      unsafe { delete_target(env.0, *target) };
+      // nothing to release
+      handle.with_write_aborted(|mut aborted| *aborted = true);
+      return Err(err);
+    }
+    handle.set_raw(raw_tsfn);
+
+    check_status!(
+      unsafe { sys::napi_unref_threadsafe_function(env.0, raw_tsfn) },
+      "Failed to unref the threadsafe function of the error reporter"
+    )?;
+    Ok(Self { handle })
+  }
+
+  /// Queue `error` to be surfaced on the JavaScript thread, never blocks.
+  ///
+  /// `Status::Closing` is returned once the env is torn down.
+  pub fn report(&self, error: Error) -> Status {
+    self.handle.with_read_aborted(|aborted| {
+      if aborted {
+        return Status::Closing;
+      }
+      let error = Box::into_raw(Box::new(error));
+      let status = Status::from(unsafe {
+        sys::napi_call_threadsafe_function(
+          self.handle.get_raw(),
+          error.cast(),
+          sys::ThreadsafeFunctionCallMode::nonblocking,
+        )
+      });
+      if status != Status::Ok {
+        drop(unsafe { Box::from_raw(error) });
+      }
+      status
+    })
+  }
+
+  /// Whether the env of the reporter is torn down, the errors reported from now on are dropped
+  pub fn closed(&self) -> bool {
+    self.handle.with_read_aborted(|aborted| aborted)
+  }
+}
+
+fn create_reference<V: NapiRaw>(env: &Env, value: &V) -> Result<sys::napi_ref> {
+  let mut reference = ptr::null_mut();
+  check_status!(
+    unsafe { sys::napi_create_reference(env.0, value.raw(), 1, &mut reference) },
+    "Failed to create a reference to the channel of the error reporter"
+  )?;
+  Ok(reference)
+}
+
+unsafe fn delete_target(env: sys::napi_env, target: ErrorTarget) {
+  match target {
+    ErrorTarget::Throw => {}
+    ErrorTarget::Emit(reference) | ErrorTarget::Callback(reference) => {
+      unsafe { sys::napi_delete_reference(env, reference) };
+    }
+  }
+}
+
+unsafe extern "C" fn finalize_reporter(
+  env: sys::napi_env,
+  finalize_data: *mut c_void,
+  finalize_hint: *mut c_void,
+) {
+  let handle: Option<Arc<ThreadsafeFunctionHandle>> =
+    unsafe { sync::Weak::from_raw(finalize_data.cast()).upgrade() };
+  if let Some(handle) = handle {
+    handle.with_write_aborted(|mut aborted| *aborted = true);
+  }
+  let target = unsafe { Box::<ErrorTarget>::from_raw(finalize_hint.cast()) };
+  unsafe { delete_target(env, *target) };
+}
+
+unsafe extern "C" fn report_error(
+  env: sys::napi_env,
+  _js_callback: sys::napi_value,
+  context: *mut c_void,
+  data: *mut c_void,
+) {
+  let error = unsafe { Box::<Error>::from_raw(data.cast()) };
+  // the queue is drained without an env when the reporter is torn down
+  if env.is_null() {
+    return;
+  }
+  let target = unsafe { &*context.cast::<ErrorTarget>() };
+  let error = unsafe { JsError::from(*error).into_value(env) };
+  let delivered = match target {
+    ErrorTarget::Throw => Err(error),
+    ErrorTarget::Emit(emitter) => unsafe { emit_error(env, *emitter, error) },
+    ErrorTarget::Callback(callback) => unsafe { call_with_error(env, *callback, error) },
+  };
+  if let Err(error) = delivered {
+    unsafe { sys::napi_fatal_exception(env, error) };
+  }
+}
+
+/// `emitter.emit('error', error)`, the exception thrown by it if any
+unsafe fn emit_error(
+  env: sys::napi_env,
+  emitter: sys::napi_ref,
+  error: sys::napi_value,
+) -> std::result::Result<(), sys::napi_value> {
+  let mut emitter_value = ptr::null_mut();
+  let mut emit = ptr::null_mut();
+  let mut event = ptr::null_mut();
+  let mut result = ptr::null_mut();
+  let status = unsafe {
+    let mut status = sys::napi_get_reference_value(env, emitter, &mut emitter_value);
+    if status == sys::Status::napi_ok {
+      status = sys::napi_get_named_property(env, emitter_value, c"emit".as_ptr(), &mut emit);
+    }
+    if status == sys::Status::napi_ok {
+      status = sys::napi_create_string_utf8(env, c"error".as_ptr(), 5, &mut event);
+    }
+    if status == sys::Status::napi_ok {
+      let args = [event, error];
+      status = sys::napi_call_function(env, emitter_value, emit, 2, args.as_ptr(), &mut result);
+    }
+    status
+  };
+  unsafe { take_exception(env, status, error) }
+}
+
+/// `callback(error)`, the exception thrown by it if any
+unsafe fn call_with_error(
+  env: sys::napi_env,
+  callback: sys::napi_ref,
+  error: sys::napi_value,
+) -> std::result::Result<(), sys::napi_value> {
+  let mut callback_value = ptr::null_mut();
+  let mut this = ptr::null_mut();
+  let mut result = ptr::null_mut();
+  let status = unsafe {
+    let mut status = sys::napi_get_reference_value(env, callback, &mut callback_value);
+    if status == sys::Status::napi_ok {
+      status = sys::napi_get_undefined(env, &mut this);
+    }
+    if status == sys::Status::napi_ok {
+      status = sys::napi_call_function(env, this, callback_value, 1, &error, &mut result);
+    }
+    status
+  };
+  unsafe { take_exception(env, status, error) }
+}
+
+/// The pending exception after a failed call, or `error` itself if there is none
+unsafe fn take_exception(
+  env: sys::napi_env,
+  status: sys::napi_status,
+  error: sys::napi_value,
+) -> std::result::Result<(), sys::napi_value> {
+  if status == sys::Status::napi_ok {
+    return Ok(());
+  }
+  let mut is_pending = false;
+  unsafe { sys::napi_is_exception_pending(env, &mut is_pending) };
+  if !is_pending {
+    return Err(error);
+  }
+  let mut exception = ptr::null_mut();
+  unsafe { sys::napi_get_and_clear_last_exception(env, &mut exception) };
+  Err(exception)
+}
@@ -6,7 +6,8 @@ use std::{
 
 use tokio::runtime::Runtime;
 
-use crate::{sys, Error, JsDeferred, JsUnknown, NapiValue, Result};
+use crate::async_hook::{AsyncWorkKind, AsyncWorkTracker};
+use crate::{sys, Env, Error, JsDeferred, JsUnknown, NapiValue, Result};
 
 fn create_runtime() -> Option<Runtime> {
   #[cfg(not(target_family = "wasm"))]
@@ -181,15 +182,31 @@ pub fn execute_tokio_future<
   #[cfg(not(target_family = "wasm"))]
   let deferred_for_panic = deferred.clone();
   let sendable_resolver = SendableResolver::new(resolver);
+  // dropped with the future if it panics, which reports it
+  let mut tracker = AsyncWorkTracker::queue::<Fut>(&Env::from_raw(env), AsyncWorkKind::Future);
 
   let inner = async move {
+    if let Some(tracker) = tracker.as_mut() {
+      tracker.start();
+    }
     match fut.await {
-      Ok(v) => deferred.resolve(move |env| {
-        sendable_resolver
-          .resolve(env.raw(), v)
-          .map(|v| unsafe { JsUnknown::from_raw_unchecked(env.raw(), v) })
-      }),
-      Err(e) => deferred.reject(e.into()),
+      Ok(v) => {
+        if let Some(tracker) = tracker.as_mut() {
+          tracker.finish(Ok(()));
+        }
+        deferred.resolve(move |env| {
+          sendable_resolver
+            .resolve(env.raw(), v)
+            .map(|v| unsafe { JsUnknown::from_raw_unchecked(env.raw(), v) })
+        })
+      }
+      Err(e) => {
+        let e = e.into();
+        if let Some(tracker) = tracker.as_mut() {
+          tracker.finish::<()>(Err(&e));
+        }
+        deferred.reject(e)
+      }
     }
   };
 
@@ -0,0 +1,86 @@
+//! Log every failed Node-API call, to diagnose the status errors reported by users without
+//! rebuilding the addon.
+//!
+//! Tracing is off until [`enable`] is called, or until the addon is loaded with the
+//! `NAPI_RS_TRACE` environment variable set to anything but `0` or `false`. Each failure checked by
+//! [`check_status!`](crate::check_status) is written to stderr as one line:
+//!
+//! ```text
+//! [napi-rs] napi_get_value_string_utf8 failed with StringExpected in napi::bindgen_runtime::js_values::string::<impl napi::bindgen_runtime::js_values::FromNapiValue for alloc::string::String>::from_napi_value (crates/napi/src/bindgen_runtime/js_values/string.rs:52)
+//! ```
+//!
+//! The sink can be replaced with [`set_writer`], to forward the lines to a logger for example.
+
+use std::io::Write;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+
+use crate::Status;
+
+static TRACING: AtomicBool = AtomicBool::new(false);
+static WRITER: Mutex<Option<Box<dyn Write + Send>>> = Mutex::new(None);
+
+/// Start logging the failed Node-API calls
+pub fn enable() {
+  TRACING.store(true, Ordering::Release);
+}
+
+pub fn disable() {
+  TRACING.store(false, Ordering::Release);
+}
+
+pub fn is_enabled() -> bool {
+  TRACING.load(Ordering::Acquire)
+}
+
+/// Write the trace to `writer` instead of stderr, `None` restores stderr
+pub fn set_writer(writer: Option<Box<dyn Write + Send>>) {
+  *WRITER.lock().unwrap_or_else(|e| e.into_inner()) = writer;
+}
+
+/// Called once from `napi_register_module_v1`
+pub(crate) fn enable_from_env() {
+  if let Some(value) = std::env::var_os("NAPI_RS_TRACE") {
+    if !matches!(value.to_str(), Some("" | "0" | "false")) {
+      enable();
+    }
+  }
+}
+
+#[doc(hidden)]
+pub fn status_failure(
+  status: crate::sys::napi_status,
+  code: &str,
+  function: &str,
+  file: &str,
+  line: u32,
+) {
+  if !is_enabled() {
+    return;
+  }
+  let line = format!(
+    "[napi-rs] {} failed with {} in {} ({file}:{line})",
+    sys_call(code).unwrap_or(code),
+    Status::from(status),
+    function.trim_end_matches("::__napi_trace_fn"),
+  );
+  let mut writer = WRITER.lock().unwrap_or_else(|e| e.into_inner());
+  let _ = match writer.as_mut() {
+    Some(writer) => writeln!(writer, "{line}"),
+    None => writeln!(std::io::stderr(), "{line}"),
+  };
+}
+
+/// The first `napi_*` function called in the checked expression
+pub(crate) fn sys_call(code: &str) -> Option<&str> {
+  code
+    .match_indices("napi_")
+    .map(|(start, _)| &code[start..])
+    .find_map(|call| {
+      let end = call.find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))?;
+      call[end..]
+        .trim_start()
+        .starts_with('(')
+        .then(|| &call[..end])
+    })
+}
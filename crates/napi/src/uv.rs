@@ -0,0 +1,355 @@
+use std::alloc::{self, Layout};
+use std::cell::Cell;
+use std::os::raw::c_void;
+use std::ptr;
+use std::rc::Rc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use crate::{check_status, sys, AsyncContext, Env, Error, JsError, Result, Status};
+
+/// The loop side state of a libuv handle, dropped on the JavaScript thread when the handle is
+/// closed, or when the env is torn down before that
+trait HandleState: 'static {
+  const TYPE: sys::uv_handle_type;
+
+  fn on_close(&mut self) {}
+}
+
+struct UvHandle<S: HandleState> {
+  raw: *mut sys::uv_handle_s,
+  env: sys::napi_env,
+  context: AsyncContext,
+  state: S,
+}
+
+fn handle_layout<S: HandleState>() -> Layout {
+  Layout::from_size_align(unsafe { sys::uv_handle_size(S::TYPE) }, 16)
+    .expect("Invalid libuv handle size")
+}
+
+fn uv_error(code: i32, msg: &str) -> Error {
+  Error::new(Status::GenericFailure, format!("{msg}, libuv error {code}"))
+}
+
+impl<S: HandleState> UvHandle<S> {
+  /// Allocate and initialize a handle on the loop of `env`, it's closed with the env if it's
+  /// still open by then
+  fn open(
+    env: &Env,
+    name: &str,
+    state: S,
+    init: impl FnOnce(*mut sys::uv_loop_s, *mut sys::uv_handle_s) -> i32,
+  ) -> Result<*mut Self> {
+    let uv_loop = env.get_uv_event_loop()?;
+    let context = env.async_init(None, name)?;
+    let layout = handle_layout::<S>();
+    let raw = unsafe { alloc::alloc(layout) }.cast::<sys::uv_handle_s>();
+    if raw.is_null() {
+      alloc::handle_alloc_error(layout);
+    }
+    let code = init(uv_loop, raw);
+    if code != 0 {
+      unsafe { alloc::dealloc(raw.cast(), layout) };
+      return Err(uv_error(code, "Failed to init the libuv handle"));
+    }
+    let handle = Box::into_raw(Box::new(Self {
+      raw,
+      env: env.0,
+      context,
+      state,
+    }));
+    unsafe { sys::uv_handle_set_data(raw, handle.cast()) };
+    if let Err(err) = check_status!(
+      unsafe { sys::napi_add_env_cleanup_hook(env.0, Some(cleanup_handle::<S>), handle.cast()) },
+      "Failed to add the cleanup hook of the libuv handle"
+    ) {
+      unsafe { Self::close(handle, false) };
+      return Err(err);
+    }
+    Ok(handle)
+  }
+
+  /// # Safety
+  ///
+  /// `raw` must be a handle opened by [`UvHandle::open`] and not closed yet
+  unsafe fn from_raw(raw: *mut sys::uv_handle_s) -> *mut Self {
+    unsafe { sys::uv_handle_get_data(raw) }.cast()
+  }
+
+  /// Drop the state and close the handle, its memory is freed by libuv after that.
+  ///
+  /// # Safety
+  ///
+  /// `handle` must not be used after it
+  unsafe fn close(handle: *mut Self, remove_cleanup_hook: bool) {
+    let mut handle = unsafe { Box::from_raw(handle) };
+    if remove_cleanup_hook {
+      unsafe {
+        sys::napi_remove_env_cleanup_hook(
+          handle.env,
+          Some(cleanup_handle::<S>),
+          (&mut *handle as *mut Self).cast(),
+        )
+      };
+    }
+    handle.state.on_close();
+    let raw = handle.raw;
+    drop(handle);
+    unsafe { sys::uv_close(raw, Some(free_handle::<S>)) };
+  }
+}
+
+unsafe extern "C" fn cleanup_handle<S: HandleState>(handle: *mut c_void) {
+  unsafe { UvHandle::<S>::close(handle.cast(), false) };
+}
+
+unsafe extern "C" fn free_handle<S: HandleState>(raw: *mut sys::uv_handle_s) {
+  unsafe { alloc::dealloc(raw.cast(), handle_layout::<S>()) };
+}
+
+/// Run `f` in a callback scope of `context`, its error is thrown as an uncaught exception
+fn call_in_scope(env: sys::napi_env, context: &AsyncContext, f: impl FnOnce(Env) -> Result<()>) {
+  let mut handle_scope = ptr::null_mut();
+  if unsafe { sys::napi_open_handle_scope(env, &mut handle_scope) } != sys::Status::napi_ok {
+    return;
+  }
+  let _ = Env::from_raw(env).run_in_callback_scope(context, || {
+    if let Err(err) = f(Env::from_raw(env)) {
+      unsafe { throw_uncaught(env, err) };
+    }
+    Ok(())
+  });
+  unsafe { sys::napi_close_handle_scope(env, handle_scope) };
+}
+
+unsafe fn throw_uncaught(env: sys::napi_env, err: Error) {
+  let mut is_pending = false;
+  unsafe { sys::napi_is_exception_pending(env, &mut is_pending) };
+  let error = if is_pending {
+    let mut error = ptr::null_mut();
+    unsafe { sys::napi_get_and_clear_last_exception(env, &mut error) };
+    error
+  } else {
+    unsafe { JsError::from(err).into_value(env) }
+  };
+  unsafe { sys::napi_fatal_exception(env, error) };
+}
+
+struct IdleOnce<F: FnOnce(Env) -> Result<()>>(Option<F>);
+
+impl<F: 'static + FnOnce(Env) -> Result<()>> HandleState for IdleOnce<F> {
+  const TYPE: sys::uv_handle_type = sys::UV_IDLE;
+}
+
+unsafe extern "C" fn on_idle<F: 'static + FnOnce(Env) -> Result<()>>(raw: *mut sys::uv_handle_s) {
+  unsafe { sys::uv_idle_stop(raw) };
+  let handle = unsafe { UvHandle::<IdleOnce<F>>::from_raw(raw) };
+  let handle_ref = unsafe { &mut *handle };
+  if let Some(f) = handle_ref.state.0.take() {
+    call_in_scope(handle_ref.env, &handle_ref.context, f);
+  }
+  unsafe { UvHandle::close(handle, true) };
+}
+
+/// The state of a timer of [`uv_timer_once`], the cell shared with the [`UvTimer`] is cleared once
+/// the handle is closed
+struct TimerOnce {
+  callback: Option<Box<dyn FnOnce(Env) -> Result<()>>>,
+  handle: Rc<Cell<*mut UvHandle<TimerOnce>>>,
+}
+
+impl HandleState for TimerOnce {
+  const TYPE: sys::uv_handle_type = sys::UV_TIMER;
+
+  fn on_close(&mut self) {
+    self.handle.set(ptr::null_mut());
+  }
+}
+
+unsafe extern "C" fn on_timer(raw: *mut sys::uv_handle_s) {
+  let handle = unsafe { UvHandle::<TimerOnce>::from_raw(raw) };
+  let handle_ref = unsafe { &mut *handle };
+  // cancelling the timer from the callback does nothing
+  handle_ref.state.handle.set(ptr::null_mut());
+  if let Some(f) = handle_ref.state.callback.take() {
+    call_in_scope(handle_ref.env, &handle_ref.context, f);
+  }
+  unsafe { UvHandle::close(handle, true) };
+}
+
+/// Cancels the timer of [`uv_timer_once`], it must be used on the JavaScript thread
+pub(crate) struct UvTimer(Rc<Cell<*mut UvHandle<TimerOnce>>>);
+
+impl UvTimer {
+  /// Stop the timer and drop its callback, if it's not called yet
+  pub(crate) fn cancel(&self) {
+    let handle = self.0.replace(ptr::null_mut());
+    if !handle.is_null() {
+      unsafe { UvHandle::close(handle, true) };
+    }
+  }
+}
+
+/// Call `f` once on the JavaScript thread after `timeout`, unless the returned timer is cancelled
+/// first. Like `setTimeout`, the pending timer keeps the process alive
+pub(crate) fn uv_timer_once<F>(env: &Env, timeout: Duration, f: F) -> Result<UvTimer>
+where
+  F: 'static + FnOnce(Env) -> Result<()>,
+{
+  let cell = Rc::new(Cell::new(ptr::null_mut()));
+  let state = TimerOnce {
+    callback: Some(Box::new(f)),
+    handle: cell.clone(),
+  };
+  let handle = UvHandle::open(env, "napi_rs_uv_timer", state, |uv_loop, raw| unsafe {
+    sys::uv_timer_init(uv_loop, raw)
+  })?;
+  let timeout = u64::try_from(timeout.as_millis()).unwrap_or(u64::MAX);
+  let code = unsafe { sys::uv_timer_start((*handle).raw, Some(on_timer), timeout, 0) };
+  if code != 0 {
+    unsafe { UvHandle::close(handle, true) };
+    return Err(uv_error(code, "Failed to start the libuv timer"));
+  }
+  cell.set(handle);
+  Ok(UvTimer(cell))
+}
+
+struct RawHandle(*mut sys::uv_handle_s);
+
+unsafe impl Send for RawHandle {}
+
+struct AsyncShared {
+  /// `None` once the handle is closed
+  handle: Mutex<Option<RawHandle>>,
+  pending: AtomicBool,
+  closing: AtomicBool,
+}
+
+impl AsyncShared {
+  fn wake(&self) -> Result<()> {
+    let handle = self.handle.lock().unwrap_or_else(|e| e.into_inner());
+    match handle.as_ref() {
+      Some(handle) => match unsafe { sys::uv_async_send(handle.0) } {
+        0 => Ok(()),
+        code => Err(uv_error(code, "Failed to send to the libuv async handle")),
+      },
+      None => Err(Error::new(
+        Status::Closing,
+        "The env of the libuv async handle is torn down",
+      )),
+    }
+  }
+}
+
+struct AsyncState<F> {
+  shared: Arc<AsyncShared>,
+  callback: F,
+}
+
+impl<F: 'static + FnMut(Env) -> Result<()>> HandleState for AsyncState<F> {
+  const TYPE: sys::uv_handle_type = sys::UV_ASYNC;
+
+  fn on_close(&mut self) {
+    self
+      .shared
+      .handle
+      .lock()
+      .unwrap_or_else(|e| e.into_inner())
+      .take();
+  }
+}
+
+unsafe extern "C" fn on_async<F: 'static + FnMut(Env) -> Result<()>>(raw: *mut sys::uv_handle_s) {
+  let handle = unsafe { UvHandle::<AsyncState<F>>::from_raw(raw) };
+  let handle_ref = unsafe { &mut *handle };
+  // the last sender is dropped after all the sends, so no send is missed once it's observed
+  let closing = handle_ref.state.shared.closing.load(Ordering::Acquire);
+  if handle_ref
+    .state
+    .shared
+    .pending
+    .swap(false, Ordering::AcqRel)
+  {
+    let callback = &mut handle_ref.state.callback;
+    call_in_scope(handle_ref.env, &handle_ref.context, callback);
+  }
+  if closing {
+    unsafe { UvHandle::close(handle, true) };
+  }
+}
+
+struct SenderInner(Arc<AsyncShared>);
+
+impl Drop for SenderInner {
+  fn drop(&mut self) {
+    self.0.closing.store(true, Ordering::Release);
+    let _ = self.0.wake();
+  }
+}
+
+/// Wakes the callback of [`Env::uv_async`] on the JavaScript thread, from any thread.
+///
+/// The wakeups are coalesced, the callback runs at least once after each [`UvAsyncSender::send`].
+/// The libuv handle is closed when all the senders are dropped, or when the env is torn down.
+#[derive(Clone)]
+pub struct UvAsyncSender(Arc<SenderInner>);
+
+impl UvAsyncSender {
+  pub fn send(&self) -> Result<()> {
+    self.0 .0.pending.store(true, Ordering::Release);
+    self.0 .0.wake()
+  }
+}
+
+impl Env {
+  /// Run `f` once on the next iteration of the libuv loop of this env, after the current
+  /// JavaScript finished and before the loop polls for I/O.
+  ///
+  /// An error returned by `f` is thrown as an uncaught exception. `f` is dropped without being
+  /// called if the env is torn down first.
+  pub fn uv_idle_once<F>(&self, f: F) -> Result<()>
+  where
+    F: 'static + FnOnce(Env) -> Result<()>,
+  {
+    let handle = UvHandle::open(
+      self,
+      "napi_rs_uv_idle",
+      IdleOnce(Some(f)),
+      |uv_loop, raw| unsafe { sys::uv_idle_init(uv_loop, raw) },
+    )?;
+    let code = unsafe { sys::uv_idle_start((*handle).raw, Some(on_idle::<F>)) };
+    if code != 0 {
+      unsafe { UvHandle::close(handle, true) };
+      return Err(uv_error(code, "Failed to start the libuv idle handle"));
+    }
+    Ok(())
+  }
+
+  /// Create a libuv async handle calling `callback` on the JavaScript thread each time it's woken
+  /// by the returned [`UvAsyncSender`].
+  ///
+  /// An error returned by `callback` is thrown as an uncaught exception. The pending handle keeps
+  /// the process alive until all the senders are dropped.
+  pub fn uv_async<F>(&self, callback: F) -> Result<UvAsyncSender>
+  where
+    F: 'static + FnMut(Env) -> Result<()>,
+  {
+    let shared = Arc::new(AsyncShared {
+      handle: Mutex::new(None),
+      pending: AtomicBool::new(false),
+      closing: AtomicBool::new(false),
+    });
+    let state = AsyncState {
+      shared: shared.clone(),
+      callback,
+    };
+    let handle = UvHandle::open(self, "napi_rs_uv_async", state, |uv_loop, raw| unsafe {
+      sys::uv_async_init(uv_loop, raw, Some(on_async::<F>))
+    })?;
+    *shared.handle.lock().unwrap_or_else(|e| e.into_inner()) =
+      Some(RawHandle(unsafe { (*handle).raw }));
+    Ok(UvAsyncSender(Arc::new(SenderInner(shared))))
+  }
+}
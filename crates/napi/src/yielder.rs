@@ -0,0 +1,78 @@
+use std::time::{Duration, Instant};
+
+use crate::bindgen_runtime::CancelFlag;
+use crate::{Env, Error, Result, Status};
+
+/// Checks a [`CancelFlag`] and a deadline every few iterations of a long synchronous loop, see
+/// [`Env::yield_every`].
+pub struct Yielder<'a> {
+  every: u32,
+  remaining: u32,
+  flag: Option<&'a CancelFlag>,
+  deadline: Option<Instant>,
+}
+
+impl<'a> Yielder<'a> {
+  /// Stop with an error once `timeout` elapsed from now
+  pub fn with_timeout(mut self, timeout: Duration) -> Self {
+    self.deadline = Instant::now().checked_add(timeout);
+    self
+  }
+
+  /// Count an iteration, every `n` of them the flag and the deadline are checked and a
+  /// `Cancelled` error is returned if the flag is set or the deadline passed.
+  pub fn tick(&mut self) -> Result<()> {
+    self.remaining -= 1;
+    if self.remaining > 0 {
+      return Ok(());
+    }
+    self.remaining = self.every;
+    self.check()
+  }
+
+  /// Check the flag and the deadline now
+  pub fn check(&self) -> Result<()> {
+    if self.flag.is_some_and(CancelFlag::is_cancelled) {
+      return Err(Error::new(Status::Cancelled, "The operation was cancelled"));
+    }
+    if self
+      .deadline
+      .is_some_and(|deadline| Instant::now() >= deadline)
+    {
+      return Err(Error::new(Status::Cancelled, "The operation timed out"));
+    }
+    Ok(())
+  }
+}
+
+impl Env {
+  /// Check `flag` every `n` calls of [`Yielder::tick`], to let JavaScript cancel a long
+  /// synchronous loop:
+  ///
+  /// ```rust,ignore
+  /// #[napi]
+  /// fn hash_all(env: Env, inputs: Vec<String>, flag: Option<CancelFlag>) -> Result<Vec<u32>> {
+  ///   let mut yielder = env.yield_every(1000, flag.as_ref()).with_timeout(Duration::from_secs(1));
+  ///   let mut hashes = Vec::with_capacity(inputs.len());
+  ///   for input in &inputs {
+  ///     yielder.tick()?;
+  ///     hashes.push(hash(input));
+  ///   }
+  ///   Ok(hashes)
+  /// }
+  /// ```
+  ///
+  /// The event loop stays blocked until the call returns: Node-API has no way to run the
+  /// microtasks or the loop from inside a call, so timers, promises and messages wait for it, and
+  /// the flag can only be set from another thread. Move the work to an `AsyncTask` or an `async`
+  /// function to keep the loop running.
+  pub fn yield_every<'a>(&self, n: u32, flag: Option<&'a CancelFlag>) -> Yielder<'a> {
+    let every = n.max(1);
+    Yielder {
+      every,
+      remaining: every,
+      flag,
+      deadline: None,
+    }
+  }
+}
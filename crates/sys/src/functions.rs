@@ -487,13 +487,35 @@ mod napi1 {
 #[cfg(feature = "napi2")]
 mod napi2 {
   use super::super::types::*;
-  use std::os::raw::c_int;
+  use std::os::raw::{c_int, c_void};
 
   generate!(
     extern "C" {
       fn napi_get_uv_event_loop(env: napi_env, loop_: *mut *mut uv_loop_s) -> napi_status;
 
       fn uv_run(loop_: *mut uv_loop_s, mode: uv_run_mode) -> c_int;
+
+      fn uv_handle_size(type_: uv_handle_type) -> usize;
+      fn uv_handle_get_data(handle: *const uv_handle_s) -> *mut c_void;
+      fn uv_handle_set_data(handle: *mut uv_handle_s, data: *mut c_void);
+      fn uv_close(handle: *mut uv_handle_s, close_cb: uv_close_cb);
+      fn uv_async_init(
+        loop_: *mut uv_loop_s,
+        async_: *mut uv_handle_s,
+        async_cb: uv_async_cb,
+      ) -> c_int;
+      fn uv_async_send(async_: *mut uv_handle_s) -> c_int;
+      fn uv_idle_init(loop_: *mut uv_loop_s, idle: *mut uv_handle_s) -> c_int;
+      fn uv_idle_start(idle: *mut uv_handle_s, cb: uv_idle_cb) -> c_int;
+      fn uv_idle_stop(idle: *mut uv_handle_s) -> c_int;
+      fn uv_timer_init(loop_: *mut uv_loop_s, timer: *mut uv_handle_s) -> c_int;
+      fn uv_timer_start(
+        timer: *mut uv_handle_s,
+        cb: uv_timer_cb,
+        timeout: u64,
+        repeat: u64,
+      ) -> c_int;
+      fn uv_timer_stop(timer: *mut uv_handle_s) -> c_int;
     }
   );
 }
@@ -694,6 +716,19 @@ mod napi8 {
       fn napi_object_freeze(env: napi_env, object: napi_value) -> napi_status;
 
       fn napi_object_seal(env: napi_env, object: napi_value) -> napi_status;
+
+      fn napi_type_tag_object(
+        env: napi_env,
+        value: napi_value,
+        type_tag: *const napi_type_tag,
+      ) -> napi_status;
+
+      fn napi_check_object_type_tag(
+        env: napi_env,
+        value: napi_value,
+        type_tag: *const napi_type_tag,
+        result: *mut bool,
+      ) -> napi_status;
     }
   );
 }
@@ -780,73 +815,32 @@ pub use napi8::*;
 #[cfg(feature = "napi9")]
 pub use napi9::*;
 
-#[cfg(all(windows, not(target_env = "msvc"), feature = "dyn-symbols"))]
-fn test_library(
-  lib_result: Result<libloading::os::windows::Library, libloading::Error>,
-) -> Result<libloading::Library, libloading::Error> {
-  unsafe {
-    match lib_result {
-      Ok(lib) => {
-        let symbol: Result<
-          libloading::os::windows::Symbol<unsafe extern "C" fn()>,
-          libloading::Error,
-        > = lib.get(b"napi_create_int32\0");
-        match symbol {
-          Ok(_) => Ok(lib.into()),
-          Err(err) => Err(err),
-        }
-      }
-      Err(err) => Err(err),
-    }
-  }
-}
-
-#[cfg(all(windows, not(target_env = "msvc"), feature = "dyn-symbols"))]
-fn find_node_library() -> Result<libloading::Library, libloading::Error> {
-  return unsafe {
-    test_library(libloading::os::windows::Library::this())
-      .or(test_library(
-        libloading::os::windows::Library::open_already_loaded("libnode"),
-      ))
-      .or(test_library(
-        libloading::os::windows::Library::open_already_loaded("node"),
-      ))
-      .or(test_library(libloading::os::windows::Library::new("node")))
-      .or(test_library(libloading::os::windows::Library::new(
-        "libnode",
-      )))
-  };
-}
-
-#[cfg(any(target_env = "msvc", feature = "dyn-symbols"))]
+#[cfg(any(target_env = "msvc", target_os = "android", feature = "dyn-symbols"))]
 pub(super) unsafe fn load_all() -> Result<libloading::Library, libloading::Error> {
-  #[cfg(all(windows, target_env = "msvc"))]
-  let host = libloading::os::windows::Library::this()?.into();
-
-  #[cfg(all(windows, not(target_env = "msvc")))]
-  let host = find_node_library()?.into();
+  let host = crate::host::find_host_library()?;
 
-  #[cfg(unix)]
-  let host = libloading::os::unix::Library::this().into();
-
-  napi1::load(&host)?;
+  let mut missing = Vec::new();
+  napi1::load(&host, &mut missing)?;
   #[cfg(feature = "napi2")]
-  napi2::load(&host)?;
+  napi2::load(&host, &mut missing)?;
   #[cfg(feature = "napi3")]
-  napi3::load(&host)?;
+  napi3::load(&host, &mut missing)?;
   #[cfg(feature = "napi4")]
-  napi4::load(&host)?;
+  napi4::load(&host, &mut missing)?;
   #[cfg(feature = "napi5")]
-  napi5::load(&host)?;
+  napi5::load(&host, &mut missing)?;
   #[cfg(feature = "napi6")]
-  napi6::load(&host)?;
+  napi6::load(&host, &mut missing)?;
   #[cfg(feature = "napi7")]
-  napi7::load(&host)?;
+  napi7::load(&host, &mut missing)?;
   #[cfg(feature = "napi8")]
-  napi8::load(&host)?;
+  napi8::load(&host, &mut missing)?;
   #[cfg(feature = "napi9")]
-  napi9::load(&host)?;
+  napi9::load(&host, &mut missing)?;
   #[cfg(feature = "experimental")]
-  experimental::load(&host)?;
+  experimental::load(&host, &mut missing)?;
+  if let Ok(mut missing_symbols) = crate::MISSING_SYMBOLS.lock() {
+    *missing_symbols = missing;
+  }
   Ok(host)
 }
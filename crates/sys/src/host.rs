@@ -0,0 +1,172 @@
+//! Locating the module exporting Node-API when its functions are resolved at runtime.
+//!
+//! On Windows the Node-API functions are not resolved from the process like on unix, the addon
+//! has to find the module exporting them: `node.exe`, `electron.exe`, `libnode.dll` or a renamed
+//! copy of one of them. On unix they are usually exported by the executable, but embedders like
+//! nodejs-mobile on Android load them from a `libnode.so` which doesn't share its symbols with the
+//! process. Embedders can change the lookup with [`set_host_modules`] from a
+//! `#[napi::module_init]` function, or with the `NAPI_RS_HOST_MODULE` environment variable.
+
+use std::sync::Mutex;
+
+#[cfg(unix)]
+use libloading::os::unix::Library;
+#[cfg(windows)]
+use libloading::os::windows::Library;
+
+/// `RTLD_NOLOAD` of the platform, which libloading doesn't export. Without it, like on the unix
+/// systems not listed, a [`HostModule::Loaded`] module is loaded if it isn't yet.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+const RTLD_NOLOAD: std::os::raw::c_int = 0x4;
+#[cfg(target_vendor = "apple")]
+const RTLD_NOLOAD: std::os::raw::c_int = 0x10;
+#[cfg(target_os = "freebsd")]
+const RTLD_NOLOAD: std::os::raw::c_int = 0x2000;
+#[cfg(all(
+  unix,
+  not(any(
+    target_os = "linux",
+    target_os = "android",
+    target_vendor = "apple",
+    target_os = "freebsd"
+  ))
+))]
+const RTLD_NOLOAD: std::os::raw::c_int = 0;
+
+/// A candidate for the module exporting Node-API, tried in the order of [`host_modules`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HostModule {
+  /// The executable of the current process, whatever its file name. On unix, all the symbols
+  /// shared with the process are searched, not only the ones of the executable.
+  CurrentExecutable,
+  /// A module already loaded in the process, by name like `"libnode.dll"`, `"electron.exe"` or
+  /// `"libnode.so"`.
+  Loaded(String),
+  /// A module loaded with `LoadLibrary` or `dlopen` by name or path, if it isn't loaded yet.
+  Load(String),
+}
+
+impl HostModule {
+  #[cfg(windows)]
+  fn open(&self) -> Result<Library, libloading::Error> {
+    match self {
+      HostModule::CurrentExecutable => Library::this(),
+      HostModule::Loaded(name) => Library::open_already_loaded(name),
+      HostModule::Load(name) => unsafe { Library::new(name) },
+    }
+  }
+
+  #[cfg(unix)]
+  fn open(&self) -> Result<Library, libloading::Error> {
+    use libloading::os::unix::RTLD_LAZY;
+
+    match self {
+      HostModule::CurrentExecutable => Ok(Library::this()),
+      HostModule::Loaded(name) => unsafe { Library::open(Some(name), RTLD_LAZY | RTLD_NOLOAD) },
+      HostModule::Load(name) => unsafe { Library::new(name) },
+    }
+  }
+}
+
+impl std::fmt::Display for HostModule {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      HostModule::CurrentExecutable => write!(f, "current executable"),
+      HostModule::Loaded(name) => write!(f, "loaded `{}`", name),
+      HostModule::Load(name) => write!(f, "load `{}`", name),
+    }
+  }
+}
+
+static HOST_MODULES: Mutex<Option<Vec<HostModule>>> = Mutex::new(None);
+
+static FOUND_HOST_MODULE: Mutex<Option<HostModule>> = Mutex::new(None);
+
+/// The lookup used when [`set_host_modules`] was not called: the current executable, which is
+/// `node.exe` or `electron.exe` in most cases, then the usual names of a shared Node.js library.
+#[cfg(windows)]
+pub fn default_host_modules() -> Vec<HostModule> {
+  vec![
+    HostModule::CurrentExecutable,
+    HostModule::Loaded("libnode".to_owned()),
+    HostModule::Loaded("node".to_owned()),
+    HostModule::Loaded("electron".to_owned()),
+    HostModule::Load("node".to_owned()),
+    HostModule::Load("libnode".to_owned()),
+  ]
+}
+
+/// The lookup used when [`set_host_modules`] was not called: the symbols of the process, which
+/// has them with the `node` executable, then an already loaded shared Node.js library like the one
+/// of nodejs-mobile.
+#[cfg(unix)]
+pub fn default_host_modules() -> Vec<HostModule> {
+  vec![
+    HostModule::CurrentExecutable,
+    #[cfg(target_vendor = "apple")]
+    HostModule::Loaded("libnode.dylib".to_owned()),
+    #[cfg(not(target_vendor = "apple"))]
+    HostModule::Loaded("libnode.so".to_owned()),
+  ]
+}
+
+/// Replace the candidates for the module exporting Node-API.
+///
+/// It must be called before the module is registered, for example in a `#[napi::module_init]`
+/// function, the lookup runs once per `napi_register_module_v1`.
+pub fn set_host_modules<I: IntoIterator<Item = HostModule>>(modules: I) {
+  if let Ok(mut host_modules) = HOST_MODULES.lock() {
+    *host_modules = Some(modules.into_iter().collect());
+  }
+}
+
+/// The candidates in lookup order.
+///
+/// Names in the `NAPI_RS_HOST_MODULE` environment variable, separated by `;`, come first, each
+/// tried as an already loaded module then loaded by name, followed by the ones from
+/// [`set_host_modules`] or [`default_host_modules`].
+pub fn host_modules() -> Vec<HostModule> {
+  let mut modules = Vec::new();
+  if let Ok(names) = std::env::var("NAPI_RS_HOST_MODULE") {
+    for name in names.split(';').map(str::trim).filter(|n| !n.is_empty()) {
+      modules.push(HostModule::Loaded(name.to_owned()));
+      modules.push(HostModule::Load(name.to_owned()));
+    }
+  }
+  match HOST_MODULES.lock().ok().and_then(|m| m.clone()) {
+    Some(configured) => modules.extend(configured),
+    None => modules.extend(default_host_modules()),
+  }
+  modules
+}
+
+/// The candidate the Node-API functions were loaded from by the last [`setup`](crate::setup)
+pub fn host_module() -> Option<HostModule> {
+  FOUND_HOST_MODULE.lock().ok().and_then(|m| m.clone())
+}
+
+pub(crate) fn find_host_library() -> Result<libloading::Library, libloading::Error> {
+  let mut last_error = None;
+  for module in host_modules() {
+    let lib = module.open().and_then(|lib| {
+      unsafe { lib.get::<unsafe extern "C" fn()>(b"napi_create_int32\0") }?;
+      Ok(lib)
+    });
+    match lib {
+      Ok(lib) => {
+        if let Ok(mut found) = FOUND_HOST_MODULE.lock() {
+          *found = Some(module);
+        }
+        return Ok(lib.into());
+      }
+      Err(err) => {
+        last_error = Some(err);
+      }
+    }
+  }
+  match last_error {
+    Some(err) => Err(err),
+    // nothing configured, the error of the current executable is the most useful one
+    None => HostModule::CurrentExecutable.open().map(Into::into),
+  }
+}
@@ -2,7 +2,7 @@
 
 #![allow(ambiguous_glob_reexports)]
 
-#[cfg(any(target_env = "msvc", feature = "dyn-symbols"))]
+#[cfg(any(target_env = "msvc", target_os = "android", feature = "dyn-symbols"))]
 macro_rules! generate {
   (extern "C" {
     $(fn $name:ident($($param:ident: $ptype:ty$(,)?)*)$( -> $rtype:ty)?;)+
@@ -16,14 +16,17 @@ macro_rules! generate {
     }
 
     #[inline(never)]
-    fn panic_load<T>() -> T {
-      panic!("Node-API symbol has not been loaded")
+    fn panic_load<T>(name: &str) -> T {
+      panic!(
+        "Node-API function `{}` is not available in the host runtime, see `napi_sys::missing_symbols`",
+        name
+      )
     }
 
     static mut NAPI: Napi = {
       $(
         unsafe extern "C" fn $name($(_: $ptype,)*)$( -> $rtype)* {
-          panic_load()
+          panic_load(stringify!($name))
         }
       )*
 
@@ -37,6 +40,7 @@ macro_rules! generate {
     #[allow(clippy::missing_safety_doc)]
     pub unsafe fn load(
       host: &libloading::Library,
+      missing: &mut Vec<$crate::MissingSymbol>,
     ) -> Result<(), libloading::Error> {
       NAPI = Napi {
         $(
@@ -44,10 +48,11 @@ macro_rules! generate {
             let symbol: Result<libloading::Symbol<unsafe extern "C" fn ($(_: $ptype,)*)$( -> $rtype)*>, libloading::Error> = host.get(stringify!($name).as_bytes());
             match symbol {
               Ok(f) => *f,
-              Err(e) => {
-                #[cfg(debug_assertions)] {
-                  eprintln!("Load Node-API [{}] from host runtime failed: {}", stringify!($name), e);
-                }
+              Err(_) => {
+                missing.push($crate::MissingSymbol {
+                  name: stringify!($name),
+                  version: module_path!().rsplit("::").next().unwrap_or_default(),
+                });
                 NAPI.$name
               }
             }
@@ -68,7 +73,7 @@ macro_rules! generate {
   };
 }
 
-#[cfg(not(any(target_env = "msvc", feature = "dyn-symbols")))]
+#[cfg(not(any(target_env = "msvc", target_os = "android", feature = "dyn-symbols")))]
 macro_rules! generate {
   (extern "C" {
     $(fn $name:ident($($param:ident: $ptype:ty$(,)?)*)$( -> $rtype:ty)?;)+
@@ -82,16 +87,20 @@ macro_rules! generate {
 }
 
 mod functions;
+#[cfg(any(target_env = "msvc", target_os = "android", feature = "dyn-symbols"))]
+mod host;
 mod types;
 
 pub use functions::*;
+#[cfg(any(target_env = "msvc", target_os = "android", feature = "dyn-symbols"))]
+pub use host::*;
 pub use types::*;
 
 /// Loads N-API symbols from host process.
 /// Must be called at least once before using any functions in bindings or
 /// they will panic.
 /// Safety: `env` must be a valid `napi_env` for the current thread
-#[cfg(any(target_env = "msvc", feature = "dyn-symbols"))]
+#[cfg(any(target_env = "msvc", target_os = "android", feature = "dyn-symbols"))]
 #[allow(clippy::missing_safety_doc)]
 pub unsafe fn setup() -> libloading::Library {
   match load_all() {
@@ -99,3 +108,62 @@ pub unsafe fn setup() -> libloading::Library {
     Ok(l) => l,
   }
 }
+
+/// A Node-API function the host runtime doesn't export.
+///
+/// Runtimes like Bun or older Electron versions only implement a subset of Node-API, the addon
+/// still loads on them and calling a missing function panics with its name.
+#[cfg(any(target_env = "msvc", target_os = "android", feature = "dyn-symbols"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MissingSymbol {
+  pub name: &'static str,
+  /// The group of the function, `"napi1"` ~ `"napi9"` or `"experimental"`
+  pub version: &'static str,
+}
+
+#[cfg(any(target_env = "msvc", target_os = "android", feature = "dyn-symbols"))]
+impl std::fmt::Display for MissingSymbol {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{} ({})", self.name, self.version)
+  }
+}
+
+#[cfg(any(target_env = "msvc", target_os = "android", feature = "dyn-symbols"))]
+static MISSING_SYMBOLS: std::sync::Mutex<Vec<MissingSymbol>> = std::sync::Mutex::new(Vec::new());
+
+/// The functions of the enabled Node-API versions that were not found by the last [`setup`]
+#[cfg(any(target_env = "msvc", target_os = "android", feature = "dyn-symbols"))]
+pub fn missing_symbols() -> Vec<MissingSymbol> {
+  MISSING_SYMBOLS
+    .lock()
+    .map(|missing| missing.clone())
+    .unwrap_or_default()
+}
+
+/// Whether the Node-API functions are resolved from the host at runtime instead of being linked
+/// against, which is the case on Windows with MSVC, on Android, and with the `dyn-symbols` feature.
+pub const DYNAMIC_SYMBOLS: bool = cfg!(any(
+  target_env = "msvc",
+  target_os = "android",
+  feature = "dyn-symbols"
+));
+
+/// Whether the Node-API function `name` of the enabled versions can be called.
+///
+/// With [`DYNAMIC_SYMBOLS`] it's `false` for the functions the host runtime doesn't export, so an
+/// addon can fall back to another way instead of panicking. Otherwise the addon only loads if the
+/// host exports all of them, so it's always `true`.
+pub fn is_available(name: &str) -> bool {
+  #[cfg(any(target_env = "msvc", target_os = "android", feature = "dyn-symbols"))]
+  {
+    MISSING_SYMBOLS
+      .lock()
+      .map(|missing| !missing.iter().any(|symbol| symbol.name == name))
+      .unwrap_or(true)
+  }
+  #[cfg(not(any(target_env = "msvc", target_os = "android", feature = "dyn-symbols")))]
+  {
+    let _ = name;
+    true
+  }
+}
@@ -62,6 +62,20 @@ pub enum uv_run_mode {
   UV_RUN_ONCE = 1,
   UV_RUN_NOWAIT = 2,
 }
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct uv_handle_s {
+  _unused: [u8; 0],
+}
+/// The size of the handles depends on the platform, allocate them with `uv_handle_size`
+pub type uv_handle_type = ::std::os::raw::c_int;
+pub const UV_ASYNC: uv_handle_type = 1;
+pub const UV_IDLE: uv_handle_type = 6;
+pub const UV_TIMER: uv_handle_type = 13;
+pub type uv_close_cb = Option<unsafe extern "C" fn(handle: *mut uv_handle_s)>;
+pub type uv_async_cb = Option<unsafe extern "C" fn(handle: *mut uv_handle_s)>;
+pub type uv_idle_cb = Option<unsafe extern "C" fn(handle: *mut uv_handle_s)>;
+pub type uv_timer_cb = Option<unsafe extern "C" fn(handle: *mut uv_handle_s)>;
 pub type napi_deferred = *mut napi_deferred__;
 
 pub type napi_property_attributes = i32;
@@ -205,6 +219,13 @@ pub mod KeyConversion {
 }
 #[cfg(feature = "napi8")]
 #[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct napi_type_tag {
+  pub lower: u64,
+  pub upper: u64,
+}
+#[cfg(feature = "napi8")]
+#[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct napi_async_cleanup_hook_handle__ {
   _unused: [u8; 0],
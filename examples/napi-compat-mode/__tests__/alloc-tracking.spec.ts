@@ -0,0 +1,20 @@
+import test from 'ava'
+
+const bindings = require('../index.node')
+
+test('should attribute native allocations to their category', (t) => {
+  const { allocTracking } = bindings
+  t.true(allocTracking.snapshot().uncategorized.allocations > 0)
+  bindings.retainNativeMemory(1 << 20)
+  bindings.retainNativeMemory(1000)
+  const { retained } = allocTracking.snapshot()
+  t.is(retained.allocated, (1 << 20) + 1000)
+  t.is(retained.allocations, 2)
+  const { totalAllocated } = retained
+  bindings.releaseNativeMemory()
+  t.deepEqual(allocTracking.snapshot().retained, {
+    allocated: 0,
+    allocations: 0,
+    totalAllocated,
+  })
+})
@@ -1,4 +1,5 @@
 import test from 'ava'
+import { pathToFileURL } from 'node:url'
 
 const bindings = require('../index.node')
 
@@ -27,3 +28,8 @@ test('should be able to coerceToBool', (t) => {
   t.false(bindings.coerceToBool(null))
   t.false(bindings.coerceToBool(NaN))
 })
+
+test('should get the file name of the module', (t) => {
+  const fileName = bindings.getModuleFileName()
+  t.is(fileName, pathToFileURL(require.resolve('../index.node')).href)
+})
@@ -0,0 +1,79 @@
+import test from 'ava'
+
+const { formatting } = require('../index.node')
+
+test('should format fixed decimals like Number.prototype.toFixed', (t) => {
+  const fixtures: [number, number][] = [
+    [1.005, 2],
+    [0.5, 0],
+    [2.5, 0],
+    [-2.5, 0],
+    [-0.0001, 2],
+    [-0, 2],
+    [999.995, 2],
+    [0.1, 20],
+    [5e-324, 3],
+    [1e21, 2],
+    [NaN, 2],
+    [-Infinity, 1],
+  ]
+  for (const [value, digits] of fixtures) {
+    t.is(formatting.toFixed(value, digits), value.toFixed(digits))
+  }
+  t.is(formatting.toFixed(12.5), '13')
+  t.throws(() => formatting.toFixed(1, 101), {
+    message: 'The fraction digits must be between 0 and 100, got 101',
+  })
+})
+
+test('should format numbers with explicit separators', (t) => {
+  t.is(
+    formatting.formatNumber(1234567.891, { fractionDigits: 2 }),
+    '1,234,567.89',
+  )
+  t.is(
+    formatting.formatNumber(1234567.891, {
+      groupSeparator: '.',
+      decimalSeparator: ',',
+    }),
+    '1.234.568',
+  )
+  t.is(formatting.formatNumber(-999.5), '-1,000')
+  t.is(formatting.formatNumber(123), '123')
+  t.is(
+    formatting.formatNumber(1e21, { groupSeparator: '' }),
+    '1000000000000000000000',
+  )
+})
+
+test('should format numbers with the separators of a locale', (t) => {
+  t.is(
+    formatting.formatNumber(1234567.891, { locale: 'de', fractionDigits: 2 }),
+    '1.234.567,89',
+  )
+  t.is(formatting.formatNumber(1234567.891, { locale: 'en-IN' }), '12,34,568')
+  t.is(formatting.formatNumber(-999.5, { locale: 'en' }), '-1,000')
+  t.is(
+    formatting.formatNumber(1234.5, { locale: 'de', groupSeparator: '' }),
+    '1235',
+  )
+  // the rounding is the same as without a locale
+  t.is(
+    formatting.formatNumber(1.005, { locale: 'fr', fractionDigits: 2 }),
+    '1,00',
+  )
+  t.throws(() => formatting.formatNumber(1, { locale: '!!' }), {
+    code: 'InvalidArg',
+  })
+})
+
+test('should format dates like Date.prototype.toISOString', (t) => {
+  for (const time of [0, -1, 1709294400123, -62198755200000, 8.64e15]) {
+    const date = new Date(time)
+    t.is(formatting.formatRfc3339(date), date.toISOString())
+  }
+  t.is(formatting.formatRfc3339(1500), '1970-01-01T00:00:01.500Z')
+  t.throws(() => formatting.formatRfc3339(new Date(NaN)), {
+    message: 'Invalid time value',
+  })
+})
@@ -42,3 +42,48 @@ test('should be able to create function from closure', (t) => {
     )
   }
 })
+
+test('should share the call storage with nested conversions', (t) => {
+  t.deepEqual(bindings.splitItems('a;b,c', ';'), ['a', 'b,c'])
+  t.deepEqual(bindings.splitItems('a;b,c'), ['a;b', 'c'])
+  let nested: string[] = []
+  t.deepEqual(
+    bindings.splitItems('a;b,c', ';', () => {
+      nested = bindings.splitItems('x;y,z')
+    }),
+    ['a', 'b,c'],
+  )
+  t.deepEqual(nested, ['x;y', 'z'])
+  t.deepEqual(
+    bindings.splitItems('a;b,c', undefined, () => {
+      nested = bindings.splitItems('x;y,z', ';')
+    }),
+    ['a;b', 'c'],
+  )
+  t.deepEqual(nested, ['x', 'y,z'])
+})
+
+test('should see all the arguments and forward the call', (t) => {
+  const wrapper = {
+    target(this: unknown, ...args: unknown[]) {
+      return [this === wrapper, args.length]
+    },
+    forward: bindings.forwardToTarget,
+  }
+  t.deepEqual(wrapper.forward(1, 'x', null, 4), {
+    arity: 1,
+    length: 4,
+    arguments: [1, 'x', null, 4],
+    returned: [true, 4],
+  })
+  t.deepEqual(wrapper.forward(), {
+    arity: 1,
+    length: 0,
+    arguments: [],
+    returned: [true, 0],
+  })
+  wrapper.target = () => {
+    throw new Error('boom')
+  }
+  t.throws(() => wrapper.forward(1), { message: 'boom' })
+})
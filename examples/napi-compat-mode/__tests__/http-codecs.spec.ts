@@ -0,0 +1,60 @@
+import test from 'ava'
+
+const { httpCodecs } = require('../index.node')
+
+test('should parse query string', (t) => {
+  t.deepEqual(httpCodecs.parseQueryString('?a=1&a=2&b=%20x+y&c&d=%zz'), {
+    a: ['1', '2'],
+    b: ' x y',
+    c: '',
+    d: '%zz',
+  })
+})
+
+test('should stringify query string', (t) => {
+  t.is(
+    httpCodecs.stringifyQueryString({ a: ['1', '2'], b: ' &=', c: 1, d: null }),
+    'a=1&a=2&b=%20%26%3D&c=1&d=',
+  )
+})
+
+test('should parse cookie', (t) => {
+  t.deepEqual(httpCodecs.parseCookie('foo=bar; baz="qux"; foo=x; enc=a%20b'), {
+    foo: 'bar',
+    baz: 'qux',
+    enc: 'a b',
+  })
+})
+
+test('should parse raw headers', (t) => {
+  t.deepEqual(
+    httpCodecs.parseRawHeaders([
+      'Accept',
+      'a',
+      'accept',
+      'b',
+      'Set-Cookie',
+      'x',
+      'set-cookie',
+      'y',
+      'Cookie',
+      'c=1',
+      'cookie',
+      'd=2',
+    ]),
+    { accept: 'a, b', 'set-cookie': ['x', 'y'], cookie: 'c=1; d=2' },
+  )
+})
+
+test('should reject raw headers without a value for the last name', (t) => {
+  t.throws(() => httpCodecs.parseRawHeaders(['Accept', 'a', 'Cookie']), {
+    code: 'InvalidArg',
+    message: 'The raw headers must be name and value pairs, got 3 elements',
+  })
+})
+
+test('should not pollute prototype', (t) => {
+  const parsed = httpCodecs.parseQueryString('__proto__=1&a=2')
+  t.is(Object.getPrototypeOf(parsed), Object.prototype)
+  t.deepEqual(parsed, { a: '2' })
+})
@@ -0,0 +1,26 @@
+const path = require('path')
+
+const addon = path.join(__dirname, '..', 'index.node')
+
+const failures = []
+for (const failure of ['error', 'panic', 'exports']) {
+  process.env.NAPI_RS_INIT_FAILURE = failure
+  try {
+    require(addon)
+    failures.push('loaded')
+  } catch (e) {
+    failures.push(e.message)
+  }
+}
+
+// a later `require` registers the module again
+delete process.env.NAPI_RS_INIT_FAILURE
+const bindings = require(addon)
+
+console.info(
+  JSON.stringify({
+    failures,
+    version: typeof bindings.getNapiVersion(),
+    probes: [bindings.initProbeA, bindings.initProbeB],
+  }),
+)
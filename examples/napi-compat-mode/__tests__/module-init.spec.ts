@@ -0,0 +1,31 @@
+import { execSync } from 'child_process'
+import { join } from 'path'
+
+import test from 'ava'
+
+test('should surface the init failures and register again', (t) => {
+  const output = execSync(
+    `node ${join(__dirname, 'module-init-sub-process.js')}`,
+  ).toString()
+  t.deepEqual(JSON.parse(output.trim().split('\n').pop()!), {
+    failures: [
+      'Failed to initialize the module: init failure requested',
+      'Failed to initialize the module: panicked: init panic requested',
+      'Failed to initialize the module: 2 exports failed: Failed to create export `initProbeA`: export failure requested; Failed to create export `initProbeB`: export failure requested',
+    ],
+    version: 'number',
+    probes: [true, true],
+  })
+})
+
+test('should report the names exported more than once', (t) => {
+  const addon = JSON.stringify(join(__dirname, '..', 'index.node'))
+  const output = execSync(
+    `node -e 'try { require(${addon}) } catch (e) { console.info(e.message) }'`,
+    { env: { ...process.env, NAPI_RS_INIT_FAILURE: 'duplicate' } },
+  ).toString()
+  t.is(
+    output.trim().split('\n').pop(),
+    'Failed to initialize the module: `initProbeA` is exported 2 times by the first probe and the second probe',
+  )
+})
@@ -0,0 +1,23 @@
+import test from 'ava'
+
+import { napiVersion } from '../napi-version'
+
+const bindings = require('../../index.node')
+
+test('should get own enumerable keys with filters', (t) => {
+  if (napiVersion >= 6) {
+    const meta = Symbol('meta')
+    const hidden = Symbol('hidden')
+    const obj = { b: 1, [meta]: 2, 1: 3 }
+    Object.defineProperty(obj, 'internal', { value: 4, enumerable: false })
+    Object.defineProperty(obj, hidden, { value: 5, enumerable: false })
+    t.deepEqual(bindings.testGetOwnKeys(obj, false), [1, 'b'])
+    t.deepEqual(bindings.testGetOwnKeys(obj, true), [1, 'b', meta])
+    t.deepEqual(
+      bindings.testGetOwnKeys(Object.create({ inherited: true }), true),
+      [],
+    )
+  } else {
+    t.is(bindings.testGetOwnKeys, undefined)
+  }
+})
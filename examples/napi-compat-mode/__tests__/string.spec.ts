@@ -26,3 +26,27 @@ test('should be able to concat latin1 string', (t) => {
 test('should be able to crate latin1 string', (t) => {
   t.snapshot(bindings.createLatin1())
 })
+
+test('should measure and iterate the code points of a string', (t) => {
+  t.deepEqual(bindings.textStats('JavaScript 🌳 你好 \0 napi'), {
+    utf8Length: 29,
+    utf16Length: 23,
+    highSurrogates: 1,
+    codePoints: 22,
+    lossy: 'JavaScript 🌳 你好 \0 napi',
+  })
+  t.deepEqual(bindings.textStats('a\ud800b'), {
+    utf8Length: 5,
+    utf16Length: 3,
+    highSurrogates: 1,
+    codePoints: 3,
+    lossy: 'a�b',
+  })
+  t.deepEqual(bindings.textStats(''), {
+    utf8Length: 0,
+    utf16Length: 0,
+    highSurrogates: 0,
+    codePoints: 0,
+    lossy: '',
+  })
+})
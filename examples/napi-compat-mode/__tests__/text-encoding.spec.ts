@@ -0,0 +1,51 @@
+import test from 'ava'
+
+const { textEncoding } = require('../index.node')
+
+test('should encode and decode legacy encodings', (t) => {
+  const sjis = textEncoding.encode('こんにちは', 'shift_jis')
+  t.deepEqual(
+    sjis,
+    Buffer.from([0x82, 0xb1, 0x82, 0xf1, 0x82, 0xc9, 0x82, 0xbf, 0x82, 0xcd]),
+  )
+  t.is(textEncoding.decode(sjis, 'sjis'), 'こんにちは')
+  const cp1251 = textEncoding.encode('Привет', 'windows-1251')
+  t.is(textEncoding.decode(cp1251, 'cp1251'), 'Привет')
+  t.deepEqual(
+    textEncoding.encode('hi', 'utf-16le'),
+    Buffer.from([0x68, 0, 0x69, 0]),
+  )
+})
+
+test('should handle BOM like TextDecoder', (t) => {
+  const input = Buffer.from([0xef, 0xbb, 0xbf, 0x61])
+  t.is(textEncoding.decode(input), 'a')
+  t.is(textEncoding.decode(input, 'utf-8', { ignoreBOM: true }), '\uFEFFa')
+})
+
+test('should throw on unknown encoding', (t) => {
+  t.throws(() => textEncoding.decode(Buffer.from('a'), 'nope'), {
+    message: 'The "nope" encoding is not supported',
+  })
+})
+
+test('should decode streaming chunks', (t) => {
+  const decoder = textEncoding.createDecoder('gbk', { fatal: true })
+  t.is(decoder.encoding, 'gbk')
+  t.true(decoder.fatal)
+  t.false(decoder.ignoreBOM)
+  const gbk = textEncoding.encode('中文', 'gbk')
+  t.is(decoder.decode(gbk.subarray(0, 1), { stream: true }), '')
+  t.is(decoder.decode(gbk.subarray(1), { stream: true }), '中文')
+  t.is(decoder.decode(), '')
+  t.throws(() => decoder.decode(Buffer.from([0x81])), {
+    message: 'The encoded data was not valid for encoding gbk',
+  })
+  // the state is reset after an error
+  t.is(decoder.decode(gbk), '中文')
+})
+
+test('should replace malformed input when not fatal', (t) => {
+  const decoder = textEncoding.createDecoder('shift_jis')
+  t.is(decoder.decode(Buffer.from([0x82])), '\uFFFD')
+})
@@ -0,0 +1,31 @@
+use napi::alloc_tracking::{AllocCategory, TrackingAllocator};
+use napi::{CallContext, JsNumber, JsObject, JsUndefined, Result};
+
+#[global_allocator]
+static GLOBAL: TrackingAllocator = TrackingAllocator::new();
+
+static RETAINED: AllocCategory = AllocCategory::new("retained");
+
+thread_local! {
+  static RETAINED_BUFFERS: std::cell::RefCell<Vec<Vec<u8>>> = Default::default();
+}
+
+#[js_function(1)]
+pub fn retain_native_memory(ctx: CallContext) -> Result<JsUndefined> {
+  let size = ctx.get::<JsNumber>(0)?.get_uint32()? as usize;
+  let buffer = RETAINED.scope(|| vec![1u8; size]);
+  RETAINED_BUFFERS.with(|buffers| buffers.borrow_mut().push(buffer));
+  ctx.env.get_undefined()
+}
+
+#[js_function]
+pub fn release_native_memory(ctx: CallContext) -> Result<JsUndefined> {
+  RETAINED_BUFFERS.with(|buffers| buffers.borrow_mut().clear());
+  ctx.env.get_undefined()
+}
+
+pub fn register_js(exports: &mut JsObject) -> Result<()> {
+  exports.create_named_method("retainNativeMemory", retain_native_memory)?;
+  exports.create_named_method("releaseNativeMemory", release_native_memory)?;
+  Ok(())
+}
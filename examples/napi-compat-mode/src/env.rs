@@ -59,6 +59,13 @@ fn coerce_to_bool(ctx: CallContext) -> Result<JsBoolean> {
   arg.coerce_to_bool()
 }
 
+#[contextless_function]
+fn get_module_file_name(env: Env) -> ContextlessResult<JsString> {
+  env
+    .create_string_from_std(env.get_module_file_name()?)
+    .map(Some)
+}
+
 pub fn register_js(exports: &mut JsObject) -> Result<()> {
   exports.create_named_method("instanceof", instanceof)?;
   exports.create_named_method("isTypedarray", is_typedarray)?;
@@ -68,5 +75,6 @@ pub fn register_js(exports: &mut JsObject) -> Result<()> {
   exports.create_named_method("getEnvVariable", get_env_variable)?;
   exports.create_named_method("throwSyntaxError", throw_syntax_error)?;
   exports.create_named_method("coerceToBool", coerce_to_bool)?;
+  exports.create_named_method("getModuleFileName", get_module_file_name)?;
   Ok(())
 }
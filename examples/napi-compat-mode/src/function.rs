@@ -1,5 +1,6 @@
 use napi::{
-  bindgen_prelude::Function, CallContext, JsError, JsNull, JsObject, JsString, JsUnknown, Result,
+  bindgen_prelude::{FromNapiValue, Function},
+  sys, CallContext, Env, JsError, JsNull, JsObject, JsString, JsUnknown, Result,
 };
 
 #[js_function(1)]
@@ -59,6 +60,49 @@ pub fn test_create_function_from_closure(ctx: CallContext) -> Result<Function<u3
     })
 }
 
+struct SplitOptions {
+  separator: String,
+}
+
+/// Split with the separator of the current call, `,` by default
+struct SplitItems(Vec<String>);
+
+impl FromNapiValue for SplitItems {
+  unsafe fn from_napi_value(env: sys::napi_env, value: sys::napi_value) -> Result<Self> {
+    let input = unsafe { String::from_napi_value(env, value)? };
+    let options = Env::from_raw(env)
+      .call_storage()
+      .and_then(|storage| storage.get::<SplitOptions>());
+    let separator = options.as_ref().map_or(",", |options| &options.separator);
+    Ok(Self(input.split(separator).map(str::to_owned).collect()))
+  }
+}
+
+#[js_function(3)]
+pub fn split_items(ctx: CallContext) -> Result<Vec<String>> {
+  if let Some(separator) = ctx.get::<Option<String>>(1)? {
+    ctx.storage().insert(SplitOptions { separator });
+  }
+  if let Some(callback) = ctx.get::<Option<Function<(), JsUnknown>>>(2)? {
+    callback.call(())?;
+  }
+  let SplitItems(items) = ctx.get(0)?;
+  Ok(items)
+}
+
+/// Describe the call, then pass it through to `this.target`
+#[js_function(1)]
+pub fn forward_to_target(ctx: CallContext) -> Result<JsObject> {
+  let this: JsObject = ctx.this()?;
+  let target: Function = this.get_named_property("target")?;
+  let mut result = ctx.env.create_object()?;
+  result.set_named_property("arity", ctx.arity() as u32)?;
+  result.set_named_property("length", ctx.length as u32)?;
+  result.set_named_property("arguments", ctx.arguments()?)?;
+  result.set_named_property("returned", ctx.forward_call(&target)?)?;
+  Ok(result)
+}
+
 pub fn register_js(exports: &mut JsObject) -> Result<()> {
   exports.create_named_method("testCallFunction", call_function)?;
   exports.create_named_method(
@@ -71,5 +115,7 @@ pub fn register_js(exports: &mut JsObject) -> Result<()> {
     "testCreateFunctionFromClosure",
     test_create_function_from_closure,
   )?;
+  exports.create_named_method("splitItems", split_items)?;
+  exports.create_named_method("forwardToTarget", forward_to_target)?;
   Ok(())
 }
@@ -8,7 +8,7 @@ extern crate napi_derive;
 #[macro_use]
 extern crate serde_derive;
 
-use napi::{Env, JsObject, Result};
+use napi::{Env, Error, JsObject, Result, Status};
 
 mod cleanup_env;
 #[cfg(feature = "latest")]
@@ -24,6 +24,7 @@ mod napi8;
 #[cfg(feature = "latest")]
 mod tokio_rt;
 
+mod alloc_tracking;
 mod array;
 mod arraybuffer;
 mod buffer;
@@ -43,9 +44,39 @@ mod task;
 
 use napi_version::get_napi_version;
 
+/// Fails when `NAPI_RS_INIT_FAILURE` is `exports`, the other exports are still registered
+unsafe fn create_init_probe(env: napi::sys::napi_env) -> Result<napi::sys::napi_value> {
+  if std::env::var("NAPI_RS_INIT_FAILURE").as_deref() == Ok("exports") {
+    return Err(Error::new(
+      Status::GenericFailure,
+      "export failure requested",
+    ));
+  }
+  unsafe { napi::bindgen_prelude::ToNapiValue::to_napi_value(env, true) }
+}
+
+#[napi::module_init]
+fn register_init_probes() {
+  napi::bindgen_prelude::register_module_export(None, "initProbeA\0", create_init_probe);
+  napi::bindgen_prelude::register_module_export(None, "initProbeB\0", create_init_probe);
+  // reported when the module is registered
+  if std::env::var("NAPI_RS_INIT_FAILURE").as_deref() == Ok("duplicate") {
+    napi::bindgen_prelude::register_export_origin(None, "initProbeA\0", "the first probe");
+    napi::bindgen_prelude::register_module_export(None, "initProbeA\0", create_init_probe);
+    napi::bindgen_prelude::register_export_origin(None, "initProbeA\0", "the second probe");
+  }
+}
+
 #[module_exports]
 fn init(mut exports: JsObject, env: Env) -> Result<()> {
+  // the failures of the init surfaced to `require`, see `__tests__/module-init.spec.ts`
+  match std::env::var("NAPI_RS_INIT_FAILURE").as_deref() {
+    Ok("error") => return Err(Error::new(Status::GenericFailure, "init failure requested")),
+    Ok("panic") => panic!("init panic requested"),
+    _ => {}
+  }
   exports.create_named_method("getNapiVersion", get_napi_version)?;
+  alloc_tracking::register_js(&mut exports)?;
   array::register_js(&mut exports)?;
   error::register_js(&mut exports)?;
   string::register_js(&mut exports)?;
@@ -44,7 +44,7 @@ pub fn test_get_bigint_u64(ctx: CallContext) -> Result<JsNumber> {
 
 #[js_function(0)]
 pub fn test_get_bigint_words(ctx: CallContext) -> Result<JsObject> {
-  let mut js_bigint = ctx
+  let js_bigint = ctx
     .env
     .create_bigint_from_words(true, vec![i64::max_value() as u64, i64::max_value() as u64])?;
   let mut js_arr = ctx.env.create_array_with_length(2)?;
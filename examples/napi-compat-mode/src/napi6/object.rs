@@ -0,0 +1,26 @@
+use napi::*;
+
+#[js_function(2)]
+pub fn test_get_own_keys(ctx: CallContext) -> Result<JsObject> {
+  let obj = ctx.get::<JsObject>(0)?;
+  let with_symbols = ctx.get::<JsBoolean>(1)?.get_value()?;
+  let filter = if with_symbols {
+    KeyFilter::Enumerable
+  } else {
+    KeyFilter::Enumerable | KeyFilter::SkipSymbols
+  };
+  let keys = obj.get_property_keys(
+    KeyCollectionMode::OwnOnly,
+    filter,
+    KeyConversion::KeepNumbers,
+  )?;
+  let mut result = ctx.env.create_array_with_length(keys.len())?;
+  for (index, key) in keys.into_iter().enumerate() {
+    match key {
+      PropertyKey::String(key) => result.set_element(index as u32, key)?,
+      PropertyKey::Number(key) => result.set_element(index as u32, ctx.env.create_uint32(key)?)?,
+      PropertyKey::Symbol(key) => result.set_element(index as u32, key)?,
+    }
+  }
+  Ok(result)
+}
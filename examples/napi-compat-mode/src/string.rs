@@ -30,10 +30,31 @@ fn create_latin1(ctx: CallContext) -> Result<JsString> {
   ctx.env.create_string_latin1(bytes.as_slice())
 }
 
+/// The lengths of the string, and its code points with the lone surrogates replaced
+#[js_function(1)]
+fn text_stats(ctx: CallContext) -> Result<JsObject> {
+  let text = ctx.get::<JsString>(0)?;
+  let high_surrogates = text.with_utf16(|units| {
+    units
+      .iter()
+      .filter(|unit| (0xD800..0xDC00).contains(*unit))
+      .count()
+  })?;
+  let code_points = text.chars()?.collect::<String>();
+  let mut stats = ctx.env.create_object()?;
+  stats.set_named_property("utf8Length", text.utf8_len()? as u32)?;
+  stats.set_named_property("utf16Length", text.utf16_len()? as u32)?;
+  stats.set_named_property("highSurrogates", high_surrogates as u32)?;
+  stats.set_named_property("codePoints", code_points.chars().count() as u32)?;
+  stats.set_named_property("lossy", code_points)?;
+  Ok(stats)
+}
+
 pub fn register_js(exports: &mut JsObject) -> Result<()> {
   exports.create_named_method("concatString", concat_string)?;
   exports.create_named_method("concatUTF16String", concat_utf16_string)?;
   exports.create_named_method("concatLatin1String", concat_latin1_string)?;
   exports.create_named_method("createLatin1", create_latin1)?;
+  exports.create_named_method("textStats", text_stats)?;
   Ok(())
 }
@@ -1,7 +1,20 @@
+import { AsyncLocalStorage } from 'node:async_hooks'
 import { Buffer } from 'node:buffer'
 import { exec } from 'node:child_process'
+import { EventEmitter, once } from 'node:events'
+import { endianness } from 'node:os'
 import { join } from 'node:path'
+import { Readable, Transform, Writable } from 'node:stream'
+import { pipeline } from 'node:stream/promises'
 import { fileURLToPath } from 'node:url'
+import { Worker } from 'node:worker_threads'
+import {
+  brotliCompressSync,
+  brotliDecompressSync,
+  gunzipSync,
+  gzipSync,
+  inflateSync,
+} from 'node:zlib'
 
 import { Subject, take } from 'rxjs'
 import Sinon, { spy } from 'sinon'
@@ -10,6 +23,8 @@ import {
   DEFAULT_COST,
   add,
   fibonacci,
+  scale,
+  negate,
   call0,
   call1,
   call2,
@@ -17,9 +32,15 @@ import {
   apply1,
   callFunction,
   callFunctionWithArg,
+  callFunctionWithManyArgs,
+  callClosureWithManyArgs,
+  createCounter,
   callFunctionWithArgAndCtx,
   createReferenceOnFunction,
   referenceAsCallback,
+  RowCursor,
+  WeakObjectCache,
+  sumPreparedCalls,
   contains,
   concatLatin1,
   concatStr,
@@ -35,25 +56,49 @@ import {
   getIndexMapping,
   sumIndexMapping,
   indexmapPassthrough,
+  createEsMap,
+  sumEsMap,
+  esMapToRecord,
+  countInEsMap,
+  createEsSet,
+  esSetToSortedVec,
+  countDistinctInEsSet,
+  toggleInEsSet,
   getCwd,
   Animal,
   Kind,
   NinjaTurtle,
   ClassWithFactory,
   CustomNumEnum,
+  LogLevel,
+  Transport,
   Context,
   GetterSetterWithClosures,
   enumToI32,
   listObjKeys,
+  sumObjValues,
+  formatObjEntries,
   createObj,
   mapOption,
   readFile,
   throwError,
   customStatusCode,
+  validateUsername,
+  reserveQuota,
+  ValidationError,
+  QuotaExceededError,
   panic,
   readPackageJson,
   getPackageJsonName,
   getBuffer,
+  getStaticBuffer,
+  createStaticBuffer,
+  createExternalFloat32Array,
+  createVec3Buffer,
+  sumVec3Buffer,
+  createExternalFloat64Array,
+  createExternalUint16Array,
+  mutateStaticBuffer,
   getEmptyBuffer,
   getEmptyTypedArray,
   asyncBufferToArray,
@@ -63,8 +108,13 @@ import {
   either3,
   either4,
   withoutAbortController,
+  sumInThreadPool,
   withAbortController,
   asyncMultiTwo,
+  fibonacciBlocking,
+  queryPooled,
+  connectionPoolStatus,
+  parseNumberBlocking,
   bigintAdd,
   createBigInt,
   createBigIntI64,
@@ -82,6 +132,13 @@ import {
   setSymbolInObj,
   createSymbol,
   createSymbolFor,
+  setRegisteredSymbolInObj,
+  tagConfigObject,
+  isConfigObject,
+  createFrozenConfig,
+  createSealedConfig,
+  sealObject,
+  getIntegrityLevel,
   threadsafeFunctionFatalMode,
   createExternal,
   getExternal,
@@ -89,6 +146,32 @@ import {
   createExternalString,
   xxh2,
   xxh3,
+  getAnswer42,
+  toMilliseconds,
+  describeValue,
+  openDatabase,
+  databaseName,
+  getToStringTag,
+  setSymbolMetadata,
+  readSymbolMetadata,
+  DeviceReadback,
+  deviceAllocations,
+  defineTemperature,
+  CaptureQueue,
+  FramePipeline,
+  reportErrorsToCallback,
+  reportErrorsToEmitter,
+  withDefaultOptions,
+  utf8Buffer,
+  splitWords,
+  WordCounter,
+  shapeArea,
+  shapeCenter,
+  outlineStyle,
+  isFilled,
+  Fill,
+  ANSWER_42,
+  napi_examples_version,
   xxh64Alias,
   tsRename,
   acceptArraybuffer,
@@ -101,9 +184,17 @@ import {
   i32ArrayToArray,
   u64ArrayToArray,
   i64ArrayToArray,
+  createAlignedArraybuffer,
+  isArraybufferAligned,
+  viewSharedRegion,
+  sharedRegionViews,
+  sumI64Vec,
+  reverseU64Vec,
   f32ArrayToArray,
   f64ArrayToArray,
   acceptUint8ClampedSlice,
+  offsetI64Slice,
+  createIdColumn,
   acceptUint8ClampedSliceAndBufferSlice,
   convertU32Array,
   createExternalTypedArray,
@@ -126,15 +217,26 @@ import {
   testSerdeRoundtrip,
   testSerdeBigNumberPrecision,
   testSerdeBufferBytes,
+  GeoPoint,
   createObjWithProperty,
   receiveObjectOnlyFromJs,
   dateToNumber,
+  dateFromSystemTime,
+  dateToSystemTimeSecs,
+  dateFromChrono,
+  dateToChrono,
   chronoUtcDateToMillis,
   chronoLocalDateToMillis,
   chronoDateWithTimezoneToMillis,
   chronoDateFixtureReturn1,
   chronoDateFixtureReturn2,
   derefUint8Array,
+  scaleFloat64Array,
+  readPacketHeader,
+  writePacketHeader,
+  scalePacketPayload,
+  sumInt32Array,
+  toUint8ClampedArray,
   chronoDateAdd1Minute,
   bufferPassThrough,
   arrayBufferPassThrough,
@@ -156,17 +258,29 @@ import {
   AnotherClassForEither,
   receiveDifferentClass,
   getNumArr,
+  sumNumsInRange,
+  numsBounds,
+  sliceNums,
   getNestedNumArr,
+  blendColors,
+  transposeMatrix,
   CustomFinalize,
   plusOne,
   Width,
   captureErrorInCallback,
+  NativeEmitter,
   bigintFromI128,
   bigintFromI64,
+  bigintCheckedAdd,
+  bigintI128ToString,
+  bigintFactorial,
+  bigintNegate,
+  bigintBitLength,
   acceptThreadsafeFunction,
   acceptThreadsafeFunctionFatal,
   acceptThreadsafeFunctionTupleArgs,
   promiseInEither,
+  sumOnThread,
   runScript,
   tsfnReturnPromise,
   tsfnReturnPromiseTimeout,
@@ -176,6 +290,45 @@ import {
   throwAsyncError,
   getModuleFileName,
   throwSyntaxError,
+  throwTypeError,
+  throwCustomError,
+  rewrapString,
+  createLabels,
+  countRequest,
+  setAppName,
+  getAppName,
+  callOnIdle,
+  sendFromThread,
+  sumPromisesLocally,
+  chainAsyncSteps,
+  spinUntilCancelled,
+  looseSum,
+  looseDescribe,
+  memoryUsage,
+  addonExternalMemoryGrowth,
+  snapshotConfig,
+  isNapiFunctionAvailable,
+  scheduleFollowUps,
+  Ticker,
+  AsyncWorkObserver,
+  droppedDelays,
+  randomBytes,
+  setRandomSeed,
+  translatePoint,
+  parsePort,
+  startRecordingCalls,
+  traceFailedCalls,
+  replayRecordedCalls,
+  startRecordingToBrokenLog,
+  takeRecordingError,
+  extraAdd,
+  loadExtraModule,
+  counterVtable,
+  pluginAdd,
+  pluginGet,
+  countUpAsync,
+  countdownAsync,
+  failAsyncAfter,
   type AliasedStruct,
   returnObjectOnlyToJs,
   buildThreadsafeFunctionFromFunction,
@@ -189,6 +342,9 @@ import {
   callThenOnPromise,
   callCatchOnPromise,
   callFinallyOnPromise,
+  promiseWithTimeout,
+  racePromises,
+  allPromises,
   StructuredKind,
   validateStructuredEnum,
   createArraybuffer,
@@ -198,6 +354,37 @@ import {
   Reader,
   withinAsyncRuntimeIfAvailable,
   errorMessageContainsNullByte,
+  collectThrownErrors,
+  createTypedError,
+  getUint32Unchecked,
+  napiCallOfError,
+  createBlobFromChunks,
+  createFile,
+  getBlobSize,
+  getFileName,
+  readBlobText,
+  readBlobChunkSizes,
+  readFirstBlobChunk,
+  echoRequest,
+  readRequestBody,
+  createStreamingResponse,
+  getResponseStatus,
+  createHexEncoderStream,
+  createCompressStream,
+  createDecompressStream,
+  createRegExp,
+  getRegExpSourceAndFlags,
+  regExpTest,
+  regExpExec,
+  regExpMatchAll,
+  isRegExp,
+  sortByLocale,
+  localeEqualsIgnoreAccents,
+  toLocaleUpperCase,
+  lookupLocale,
+  localeLookupCount,
+  ObservedModel,
+  Document,
 } from '../index.cjs'
 
 import { test } from './test.framework.js'
@@ -222,6 +409,19 @@ test('number', (t) => {
   )
 })
 
+test('number edge cases', (t) => {
+  t.is(negate(0), -0)
+  t.is(negate(-0), 0)
+  t.true(Number.isNaN(negate(NaN)))
+  t.is(negate(Infinity), -Infinity)
+  t.is(scale(0.5, 3), 1.5)
+  t.is(scale(2, 1.9), 2)
+  t.throws(() => scale(NaN, 1), { message: 'Expected a finite number, got NaN' })
+  t.throws(() => scale(1, -Infinity), {
+    message: 'Expected a finite number, got -Infinity',
+  })
+})
+
 test('string', (t) => {
   t.true(contains('hello', 'ell'))
   t.false(contains('John', 'jn'))
@@ -247,6 +447,55 @@ test('array', (t) => {
   t.deepEqual(getNestedNumArr(), [[[1]], [[1]]])
 })
 
+test('range', (t) => {
+  t.is(sumNumsInRange([1, 2, 3, 4, 5], { start: 1, end: 3 }), 5)
+  t.is(sumNumsInRange([1, 2, 3], { start: 2, end: 10 }), 0)
+  t.deepEqual(numsBounds([3, 1, 4, 1, 5]), { start: 1, end: 5 })
+  t.is(numsBounds([]), null)
+  t.throws(() => sumNumsInRange([1, 2, 3], { start: 2, end: 1 }), {
+    code: 'InvalidArg',
+    message: 'Invalid range, `start` is greater than `end`',
+  })
+  t.throws(
+    // @ts-expect-error
+    () => sumNumsInRange([1, 2, 3], { start: 1 }),
+    {
+      code: 'InvalidArg',
+      message: 'Missing `end` of the range',
+    },
+  )
+  t.deepEqual(sliceNums([1, 2, 3, 4, 5]), [1, 2, 3, 4, 5])
+  t.deepEqual(sliceNums([1, 2, 3, 4, 5], 1, 3), [2, 3])
+  t.deepEqual(sliceNums([1, 2, 3, 4, 5], -2), [4, 5])
+  t.deepEqual(sliceNums([1, 2, 3, 4, 5], 1, -1), [2, 3, 4])
+  t.deepEqual(sliceNums([1, 2, 3, 4, 5], 4, 2), [])
+  t.deepEqual(sliceNums([1, 2, 3], -10, 10), [1, 2, 3])
+})
+
+test('fixed size array', (t) => {
+  t.deepEqual(blendColors([255, 0, 0, 255], [0, 0, 255, 255]), [127, 0, 127, 255])
+  t.deepEqual(
+    transposeMatrix([
+      [1, 2],
+      [3, 4],
+    ]),
+    [
+      [1, 3],
+      [2, 4],
+    ],
+  )
+  t.throws(
+    // @ts-expect-error
+    () => blendColors([255, 0, 0], [0, 0, 255, 255]),
+    {
+      code: 'InvalidArg',
+      message: 'Expected an array of length 4, got 3',
+    },
+  )
+  // @ts-expect-error
+  t.throws(() => transposeMatrix([[1, 2], [3]]), { code: 'InvalidArg' })
+})
+
 test('map', (t) => {
   t.deepEqual(getMapping(), { a: 101, b: 102 })
   t.is(sumMapping({ a: 101, b: 102 }), 203)
@@ -257,11 +506,72 @@ test('map', (t) => {
   t.deepEqual(indexmapPassthrough({ a: 101, b: 102 }), { a: 101, b: 102 })
 })
 
+test('ES Map', (t) => {
+  const map = createEsMap({ b: 2, a: 1 })
+  t.true(map instanceof Map)
+  t.deepEqual(
+    [...map],
+    [
+      ['a', 1],
+      ['b', 2],
+    ],
+  )
+  t.is(
+    sumEsMap(
+      new Map<unknown, number>([
+        [{}, 3],
+        [1, 4],
+      ]),
+    ),
+    7,
+  )
+  t.throws(() => sumEsMap(new Map([['a', 'x']])), { code: 'NumberExpected' })
+  t.deepEqual(
+    esMapToRecord(
+      new Map([
+        ['x', 1],
+        ['y', 2],
+      ]),
+    ),
+    { x: 1, y: 2 },
+  )
+  const counts = new Map<unknown, unknown>()
+  t.is(countInEsMap(counts, 'a', false), 1)
+  t.is(countInEsMap(counts, 'a', false), 1)
+  t.is(countInEsMap(counts, 'b', false), 2)
+  t.is(countInEsMap(counts, 'a', true), 1)
+  t.deepEqual([...counts], [['b', 1]])
+})
+
+test('ES Set', (t) => {
+  const set = createEsSet(['b', 'a', 'b'])
+  t.true(set instanceof Set)
+  t.deepEqual([...set], ['b', 'a'])
+  t.deepEqual(esSetToSortedVec(new Set([3, 1, 2])), [1, 2, 3])
+  t.throws(() => esSetToSortedVec(new Set(['a'])), { code: 'NumberExpected' })
+  t.is(countDistinctInEsSet(new Set(['a', 'b', 'c'])), 3)
+  const toggled = new Set<unknown>(['x'])
+  t.false(toggleInEsSet(toggled, 'x'))
+  t.true(toggleInEsSet(toggled, 'y'))
+  t.deepEqual([...toggled], ['y'])
+})
+
 test('enum', (t) => {
   t.deepEqual([Kind.Dog, Kind.Cat, Kind.Duck], [0, 1, 2])
   t.is(enumToI32(CustomNumEnum.Eight), 8)
 })
 
+test('enum reflection', (t) => {
+  t.deepEqual(LogLevel.values(), [10, 20, 30])
+  t.deepEqual(Object.keys(LogLevel), ['Debug', 'Info', 'Warn'])
+  t.is(LogLevel.nameOf(LogLevel.Info), 'Info')
+  t.is(LogLevel.nameOf(25), undefined)
+  t.is(LogLevel.nameOf('Info'), undefined)
+  t.deepEqual(Transport.values(), ['http', 'websocket'])
+  t.is(Transport.nameOf('websocket'), 'WebSocket')
+  t.is(Transport.nameOf(0), undefined)
+})
+
 test('structured enum', (t) => {
   const hello: StructuredKind = {
     type2: 'Hello',
@@ -323,6 +633,16 @@ test('function call', async (t) => {
     callFunctionWithArg((a, b) => a + b, 42, 10),
     52,
   )
+  t.is(
+    callFunctionWithManyArgs((...args) => args.reduce((a, b) => a + b, 0)),
+    55,
+  )
+  t.is(callClosureWithManyArgs(), 55)
+  const counter = createCounter(10)
+  t.is(counter.name, 'counter')
+  t.is(counter(), 11)
+  t.is(counter(), 12)
+  t.is(createCounter(0)(), 1)
   const ctx3 = new Animal(Kind.Dog, '旺财')
   callFunctionWithArgAndCtx(
     ctx3,
@@ -339,6 +659,26 @@ test('function call', async (t) => {
     referenceAsCallback((a, b) => a + b, 42, 10),
     52,
   )
+  const scale = function (this: { offset: number }, factor: number, i: number) {
+    return this.offset + factor * i
+  }
+  t.is(sumPreparedCalls({ offset: 1 }, scale, 2, 4), 16)
+  t.is(sumPreparedCalls({ offset: 1 }, scale, 2, 4, true), 16)
+  t.throws(
+    () =>
+      sumPreparedCalls(
+        {},
+        (_, i) => {
+          if (i === 2) {
+            throw new Error('third call failed')
+          }
+          return i
+        },
+        1,
+        4,
+      ),
+    { message: 'third call failed' },
+  )
 })
 
 test('class', (t) => {
@@ -485,6 +825,39 @@ test('should be able to into_reference', (t) => {
   t.is(anotherStyleSheet.rules, sheet.rules)
 })
 
+test('recycle class instances in a pool', (t) => {
+  const cursor = new RowCursor(1)
+  const first = cursor.nextRow('first')
+  t.is(first.id, 0)
+  t.is(first.name, 'first')
+  cursor.release(first)
+  t.is(cursor.pooled, 1)
+  t.throws(() => cursor.release(first), {
+    message: /already released/,
+  })
+  const second = cursor.nextRow('second')
+  t.is(second, first)
+  t.is(second.id, 1)
+  t.is(second.name, 'second')
+  t.is(cursor.pooled, 0)
+  const third = cursor.nextRow('third')
+  t.not(third, second)
+  cursor.release(second)
+  cursor.release(third)
+  t.is(cursor.pooled, 1)
+})
+
+test('weak references', (t) => {
+  const cache = new WeakObjectCache()
+  const value = { name: 'cached' }
+  t.is(cache.get('value'), null)
+  cache.set('value', value)
+  t.is(cache.get('value'), value)
+  const replaced = { name: 'replaced' }
+  cache.set('value', replaced)
+  t.is(cache.get('value'), replaced)
+})
+
 test('callback', (t) => {
   if (!process.env.WASI_TEST) {
     getCwd((cwd) => {
@@ -514,6 +887,31 @@ test('callback', (t) => {
   )
 })
 
+test('callbacks in the async context of native resources', (t) => {
+  const storage = new AsyncLocalStorage<string>()
+  const received: [string | undefined, number][] = []
+  const emitter = storage.run(
+    'created',
+    () =>
+      new NativeEmitter((value) => {
+        received.push([storage.getStore(), value])
+      }),
+  )
+  storage.run('emitted', () => {
+    emitter.emit(1)
+    emitter.emitBatch([2, 3])
+  })
+  t.deepEqual(received, [
+    ['created', 1],
+    ['created', 2],
+    ['created', 3],
+  ])
+  const failing = new NativeEmitter(() => {
+    throw new Error('Listener failed')
+  })
+  t.throws(() => failing.emit(1), { message: 'Listener failed' })
+})
+
 Napi4Test('callback function return Promise', async (t) => {
   const cbSpy = spy()
   await callbackReturnPromise<string>(() => '1', spy)
@@ -546,8 +944,72 @@ test('promise', async (t) => {
   t.true(spy.calledOnce)
 })
 
+test('promise combinators', async (t) => {
+  const delay = (ms: number, value: number) =>
+    new Promise<number>((resolve) => setTimeout(resolve, ms, value))
+  const fail = (ms: number, message: string) =>
+    new Promise<number>((_, reject) =>
+      setTimeout(() => reject(new Error(message)), ms),
+    )
+  t.is(await promiseWithTimeout(delay(5, 1), 1000), 1)
+  await t.throwsAsync(() => promiseWithTimeout(delay(500, 1), 20), {
+    code: 'Cancelled',
+    message: 'The Promise timed out after 20ms',
+  })
+  await t.throwsAsync(() => promiseWithTimeout(fail(5, 'boom'), 1000), {
+    message: 'boom',
+  })
+  t.is(await racePromises([delay(50, 1), delay(5, 2), delay(20, 3)]), 2)
+  await t.throwsAsync(() => racePromises([delay(50, 1), fail(5, 'first')]), {
+    message: 'first',
+  })
+  t.deepEqual(
+    await allPromises([delay(20, 1), delay(5, 2), Promise.resolve(3)]),
+    [1, 2, 3],
+  )
+  t.deepEqual(await allPromises([]), [])
+  await t.throwsAsync(() => allPromises([delay(50, 1), fail(5, 'bad')]), {
+    message: 'bad',
+  })
+})
+
+Napi4Test('blob and file', async (t) => {
+  const blob = createBlobFromChunks(['hello ', 'world'], 'text/plain')
+  t.true(blob instanceof Blob)
+  t.is(blob.type, 'text/plain')
+  t.is(await blob.text(), 'hello world')
+  t.is(getBlobSize(new Blob(['napi-rs'])), 7)
+  t.is(await readBlobText(new Blob(['napi-rs'])), 'napi-rs')
+  t.deepEqual(
+    await readBlobChunkSizes(new Blob(['0123456789']), 4),
+    [4, 4, 2],
+  )
+  const blob = new Blob(['0123456789'])
+  const slices: number[][] = []
+  const slice = blob.slice.bind(blob)
+  blob.slice = (start, end, contentType) => {
+    slices.push([start!, end!])
+    return slice(start, end, contentType)
+  }
+  t.is(await readFirstBlobChunk(blob, 4), 4)
+  t.deepEqual(slices, [[0, 4]])
+  const file = createFile('napi.txt', 'content', 1000)
+  t.true(file instanceof File)
+  t.is(file.name, 'napi.txt')
+  t.is(file.lastModified, 1000)
+  t.is(getFileName(file), 'napi.txt')
+})
+
 test('object', (t) => {
   t.deepEqual(listObjKeys({ name: 'John Doe', age: 20 }), ['name', 'age'])
+  t.is(sumObjValues({ a: 1, b: 2.5, 3: 4 }), 7.5)
+  t.deepEqual(formatObjEntries({ width: 800, height: 600 }), [
+    'width=800',
+    'height=600',
+  ])
+  t.throws(() => formatObjEntries({ width: 800, height: 'auto' }), {
+    message: /^Invalid value of property `height`/,
+  })
   t.deepEqual(createObj(), { test: 1 })
   t.throws(
     () =>
@@ -645,6 +1107,49 @@ test('create symbol', (t) => {
   t.is(createSymbol().toString(), 'Symbol(a symbol)')
 })
 
+test('create registered symbol', (t) => {
+  const obj = setRegisteredSymbolInObj('napi-rs.tag')
+  // @ts-expect-error
+  t.is(obj[Symbol.for('napi-rs.tag')], 'a registered symbol')
+  t.is(createSymbolFor('napi-rs.tag'), Symbol.for('napi-rs.tag'))
+})
+
+test('type tag object', (t) => {
+  const config = {}
+  t.false(isConfigObject(config))
+  tagConfigObject(config)
+  t.true(isConfigObject(config))
+  t.false(isConfigObject({}))
+  t.throws(() => tagConfigObject(config))
+})
+
+test('freeze and seal object', (t) => {
+  const frozen = createFrozenConfig()
+  t.true(Object.isFrozen(frozen))
+  t.is(getIntegrityLevel(frozen), 'frozen')
+  t.throws(() => {
+    frozen.name = 'changed'
+  })
+  const sealed = createSealedConfig()
+  t.false(Object.isFrozen(sealed))
+  t.true(Object.isSealed(sealed))
+  t.is(getIntegrityLevel(sealed), 'sealed')
+  sealed.name = 'changed'
+  t.is(sealed.name, 'changed')
+  const obj = { name: 'napi-rs' }
+  t.is(getIntegrityLevel(obj), 'none')
+  sealObject(obj)
+  t.is(getIntegrityLevel(obj), 'sealed')
+})
+
+test('class instances of another class are rejected', (t) => {
+  // @ts-expect-error
+  t.throws(() => apply0(new Dog('Doge'), () => {}), {
+    code: 'InvalidArg',
+    message: /is not an instance of `.*Animal` created by this addon/,
+  })
+})
+
 test('Option', (t) => {
   t.is(mapOption(null), null)
   t.is(mapOption(3), 4)
@@ -658,6 +1163,55 @@ test('Result', (t) => {
   t.throws(() => errorMessageContainsNullByte('\u001a\u0000'))
 })
 
+test('recover from pending exceptions', (t) => {
+  const thrown = collectThrownErrors(
+    (item) => {
+      if (item % 2 === 0) {
+        throw new Error(`even ${item}`)
+      }
+      if (item === 3) {
+        throw 'three'
+      }
+    },
+    [1, 2, 3, 4, 5],
+  )
+  t.is(thrown.length, 3)
+  t.is((thrown[0] as Error).message, 'even 2')
+  t.is(thrown[1], 'three')
+  t.is((thrown[2] as Error).message, 'even 4')
+})
+
+test('errors tell the failed Node-API call', (t) => {
+  t.throws(() => getUint32Unchecked('x'), {
+    code: 'NumberExpected',
+    message: '`napi_get_value_uint32` failed: A number was expected',
+  })
+  t.deepEqual(napiCallOfError('x'), {
+    function: 'napi_get_value_uint32',
+    message: 'A number was expected',
+  })
+  t.is(napiCallOfError(1), null)
+})
+
+test('create typed errors', async (t) => {
+  const typeError = createTypedError('TypeError', 'Expected a string', 'ERR_INVALID_ARG_TYPE')
+  t.true(typeError instanceof TypeError)
+  t.is(typeError.message, 'Expected a string')
+  t.is((typeError as Error & { code?: string }).code, 'ERR_INVALID_ARG_TYPE')
+  const rangeError = createTypedError('RangeError', 'Out of range')
+  t.true(rangeError instanceof RangeError)
+  t.false('code' in rangeError)
+  t.true(createTypedError('SyntaxError', 'Unexpected token') instanceof SyntaxError)
+  await t.throwsAsync(() => Promise.reject(typeError), {
+    instanceOf: TypeError,
+    code: 'ERR_INVALID_ARG_TYPE',
+  })
+  t.throws(() => createTypedError('EvalError', 'eval'), {
+    code: 'InvalidArg',
+    message: 'Unknown error kind `EvalError`',
+  })
+})
+
 test('Async error with stack trace', async (t) => {
   const err = await t.throwsAsync(() => throwAsyncError())
   t.not(err?.stack, undefined)
@@ -679,6 +1233,40 @@ test('custom status code in Error', (t) => {
   })
 })
 
+test('custom error class per function', async (t) => {
+  t.is(validateUsername('NapiRs'), 'napirs')
+  const err = t.throws<ValidationError>(() => validateUsername('napi rs'), {
+    instanceOf: ValidationError,
+    message: 'Invalid `username`',
+  })
+  t.true(err instanceof Error)
+  t.is(err!.name, 'ValidationError')
+  t.is(err!.field, 'username')
+  t.is(err!.code, 42)
+  t.is(err!.hint, 'Use letters and digits only')
+  t.false('hint' in t.throws(() => validateUsername(''))!)
+  if (!process.env.WASI_TEST) {
+    t.regex(err!.stack!, /^ValidationError: Invalid `username`\n\s+at /)
+  }
+
+  await t.throwsAsync(() => reserveQuota(500), {
+    instanceOf: QuotaExceededError,
+    message: 'Requested 500, the limit is 100',
+  })
+  t.is(await reserveQuota(20), 80)
+  const rejected = await reserveQuota(101).catch((e) => e)
+  t.is(rejected.limit, 100)
+
+  const manual = new ValidationError('manual')
+  t.true(manual instanceof ValidationError)
+  t.true(manual instanceof Error)
+  t.is(manual.message, 'manual')
+  // @ts-expect-error
+  t.throws(() => ValidationError('called'))
+  class SubError extends ValidationError {}
+  t.true(new SubError() instanceof ValidationError)
+})
+
 test('function ts type override', (t) => {
   // @ts-expect-error
   t.deepEqual(tsRename({ foo: 1, bar: 2, baz: 2 }), ['foo', 'bar', 'baz'])
@@ -749,6 +1337,9 @@ test('serde-roundtrip', (t) => {
     c: -1,
   })
   t.throws(() => testSerdeRoundtrip(NaN))
+  t.is(testSerdeRoundtrip(-0), -0)
+  t.is(testSerdeRoundtrip(1e300), 1e300)
+  t.deepEqual(testSerdeRoundtrip([-0, 2 ** 53]), [-0, 2 ** 53])
 
   t.is(testSerdeRoundtrip(null), null)
 
@@ -792,6 +1383,23 @@ test('serde-buffer-bytes', (t) => {
   t.is(testSerdeBufferBytes({ code: new ArrayBuffer(0) }), 0n)
 })
 
+test('serde-json class', (t) => {
+  const point = new GeoPoint(48.85, 2.35, 'Paris')
+  t.deepEqual(point.toJSON(), {
+    latitude: 48.85,
+    longitude: 2.35,
+    label: 'Paris',
+  })
+  const copy = GeoPoint.fromJSON(JSON.parse(JSON.stringify(point)))
+  t.true(copy instanceof GeoPoint)
+  t.is(copy.latitude, 48.85)
+  t.is(copy.label, 'Paris')
+  t.throws(() => GeoPoint.fromJSON({ latitude: 'north' }))
+  const edge = GeoPoint.fromJSON({ latitude: -0, longitude: 1e300 })
+  t.is(edge.latitude, -0)
+  t.is(edge.longitude, 1e300)
+})
+
 test('buffer', (t) => {
   let buf = getBuffer()
   t.is(buf.toString('utf-8'), 'Hello world')
@@ -817,6 +1425,30 @@ test('Return BufferSlice with lifetime', (t) => {
   t.deepEqual(reader2.read(), Buffer.from('Hello world'))
 })
 
+test('aligned ArrayBuffer', (t) => {
+  for (const align of [32, 64, 4096]) {
+    const buf = createAlignedArraybuffer(100, align)
+    t.is(buf.byteLength, 100)
+    t.deepEqual(new Uint8Array(buf), new Uint8Array(100).fill(1))
+    t.true(isArraybufferAligned(buf, align))
+  }
+  t.is(createAlignedArraybuffer(0, 64).byteLength, 0)
+  t.throws(() => createAlignedArraybuffer(8, 24))
+})
+
+test('ArrayBuffer kept alive by a guard', (t) => {
+  const viewsBefore = sharedRegionViews()
+  const first = viewSharedRegion()
+  const second = viewSharedRegion()
+  t.is(sharedRegionViews(), viewsBefore + 2)
+  t.is(first.byteLength, 256)
+  t.deepEqual(
+    new Uint8Array(first),
+    Uint8Array.from({ length: 256 }, (_, i) => i),
+  )
+  t.deepEqual(new Uint8Array(second), new Uint8Array(first))
+})
+
 test('TypedArray', (t) => {
   t.is(acceptSlice(new Uint8Array([1, 2, 3])), 3n)
   t.deepEqual(u8ArrayToArray(new Uint8Array([1, 2, 3])), [1, 2, 3])
@@ -827,6 +1459,17 @@ test('TypedArray', (t) => {
   t.deepEqual(i32ArrayToArray(new Int32Array([1, 2, 3])), [1, 2, 3])
   t.deepEqual(u64ArrayToArray(new BigUint64Array([1n, 2n, 3n])), [1n, 2n, 3n])
   t.deepEqual(i64ArrayToArray(new BigInt64Array([1n, 2n, 3n])), [1, 2, 3])
+  t.is(sumI64Vec([1, 2, 3]), 6)
+  t.is(sumI64Vec(new BigInt64Array([1n, -2n, 3n])), 2)
+  t.throws(() => sumI64Vec(new Float64Array([1, 2, 3])), {
+    message: 'Expected an Array or a BigInt64Array, got Float64Array',
+  })
+  t.deepEqual(
+    reverseU64Vec(new BigUint64Array([1n, 2n, 18446744073709551615n])),
+    new BigUint64Array([18446744073709551615n, 2n, 1n]),
+  )
+  t.deepEqual(reverseU64Vec([1n, 2n]), new BigUint64Array([2n, 1n]))
+  t.throws(() => reverseU64Vec([-1n]))
   t.deepEqual(f32ArrayToArray(new Float32Array([1, 2, 3])), [1, 2, 3])
   t.deepEqual(f64ArrayToArray(new Float64Array([1, 2, 3])), [1, 2, 3])
 
@@ -844,6 +1487,23 @@ test('TypedArray', (t) => {
   )
 })
 
+test('BigInt64Array and BigUint64Array views', (t) => {
+  const ids = new BigInt64Array([1n, -2n, 9223372036854775806n])
+  t.is(offsetI64Slice(ids, 1), ids)
+  t.deepEqual(Array.from(ids), [2n, -1n, 9223372036854775807n])
+  t.throws(() => offsetI64Slice(new BigUint64Array([1n]) as any, 1), {
+    message: 'Expected BigInt64Array, got BigUint64Array',
+  })
+  const column = createIdColumn(18446744073709551614n, 3)
+  t.true(column instanceof BigUint64Array)
+  t.deepEqual(Array.from(column), [
+    18446744073709551614n,
+    18446744073709551615n,
+    0n,
+  ])
+  t.is(createIdColumn(1n, 0).length, 0)
+})
+
 test('emptybuffer', (t) => {
   let buf = new ArrayBuffer(0)
   t.is(acceptArraybuffer(buf), 0n)
@@ -891,6 +1551,44 @@ test('deref uint8 array', (t) => {
   )
 })
 
+test('DataView accessors', (t) => {
+  const buffer = new ArrayBuffer(32)
+  const view = new DataView(buffer, 4)
+  const header = { version: 1, flags: 2, length: 16, checksum: 0xdeadbeef }
+  writePacketHeader(view, header)
+  t.deepEqual(readPacketHeader(view), header)
+  t.is(view.getUint16(2), 16)
+  t.is(view.getUint32(4, true), 0xdeadbeef)
+  view.setFloat64(8, 1.5, true)
+  view.setFloat64(16, -2, true)
+  const payload = scalePacketPayload(buffer, 4, 2)
+  t.is(payload.buffer, buffer)
+  t.is(payload.byteOffset, 12)
+  t.is(payload.byteLength, 16)
+  t.is(view.getFloat64(8, true), 3)
+  t.is(view.getFloat64(16, true), -4)
+  t.throws(() => readPacketHeader(new DataView(buffer, 28)), {
+    message: 'Offset 4 is outside the bounds of the DataView of 4 bytes',
+  })
+  t.throws(() => scalePacketPayload(buffer, 28, 1), {
+    message: 'The range 28..36 is outside the ArrayBuffer of 32 bytes',
+  })
+})
+
+test('concrete typed arrays', (t) => {
+  const input = new Float64Array([1, 2.5, -3])
+  const scaled = scaleFloat64Array(input, 2)
+  t.is(scaled, input)
+  t.deepEqual(Array.from(input), [2, 5, -6])
+  t.is(
+    sumInt32Array(new Int32Array([2 ** 31 - 1, 2 ** 31 - 1, -5])),
+    2 ** 32 - 7,
+  )
+  const clamped = toUint8ClampedArray(new Float32Array([-1, 1.4, 127.5, 300]))
+  t.true(clamped instanceof Uint8ClampedArray)
+  t.deepEqual(Array.from(clamped), [0, 1, 128, 255])
+})
+
 test('async', async (t) => {
   if (process.env.WASI_TEST) {
     t.pass()
@@ -923,12 +1621,72 @@ test('async move', async (t) => {
   t.is(await asyncMultiTwo(2), 4)
 })
 
+test('blocking thread functions', async (t) => {
+  const fib = fibonacciBlocking(20)
+  t.true(fib instanceof Promise)
+  t.is(await fib, 6765)
+  t.is(await parseNumberBlocking(' 42 '), 42)
+  await t.throwsAsync(() => parseNumberBlocking('forty-two'), {
+    code: 'InvalidArg',
+    message: /failed to parse `forty-two`/,
+  })
+})
+
+Napi4Test('per env pool', async (t) => {
+  const results = await Promise.all(
+    Array.from({ length: 5 }, (_, i) => queryPooled(`query ${i}`)),
+  )
+  t.is(results.length, 5)
+  for (const [i, result] of results.entries()) {
+    t.regex(result, new RegExp(`^query ${i} on connection [12]$`))
+  }
+  t.deepEqual(connectionPoolStatus(), { opened: 2, idle: 2, inUse: 0 })
+  t.regex(await queryPooled('query again'), /^query again on connection [12]$/)
+  t.is(connectionPoolStatus().opened, 2)
+})
+
 test('buffer passthrough', async (t) => {
   const fixture = Buffer.from('hello world')
   const ret = await bufferPassThrough(fixture)
   t.deepEqual(ret, fixture)
 })
 
+test('static buffer', (t) => {
+  const fixture = Buffer.from('Embedded static asset')
+  t.deepEqual(getStaticBuffer(), fixture)
+  t.deepEqual(getStaticBuffer(), fixture)
+  t.deepEqual(createStaticBuffer(), fixture)
+  t.deepEqual(mutateStaticBuffer(), Buffer.from('embedded static asset'))
+  t.deepEqual(getStaticBuffer(), fixture)
+})
+
+test('external typed arrays', (t) => {
+  t.deepEqual(
+    createExternalFloat32Array(4),
+    new Float32Array([0, 0.5, 1, 1.5]),
+  )
+  t.deepEqual(createExternalFloat64Array(3), new Float64Array([0, 1, 2]))
+  t.deepEqual(createExternalUint16Array(3), new Uint16Array([0, 1, 2]))
+  t.deepEqual(createExternalFloat32Array(0), new Float32Array())
+})
+
+test('pod buffer slices', (t) => {
+  const buffer = createVec3Buffer(2)
+  t.true(buffer instanceof Buffer)
+  t.deepEqual(
+    new Float32Array(buffer.buffer, buffer.byteOffset, 6),
+    new Float32Array([0, 0, 0, 1, 2, 3]),
+  )
+  t.deepEqual(sumVec3Buffer(buffer), [1, 2, 3])
+  t.deepEqual(sumVec3Buffer(createVec3Buffer(0)), [0, 0, 0])
+  t.throws(() => sumVec3Buffer(Buffer.alloc(10)), {
+    message: /not a multiple of the size/,
+  })
+  t.throws(() => sumVec3Buffer(Buffer.alloc(16).subarray(1, 13)), {
+    message: /not aligned/,
+  })
+})
+
 test('arraybuffer passthrough', async (t) => {
   const fixture = new Uint8Array([1, 2, 3, 4, 5])
   const ret = await arrayBufferPassThrough(fixture)
@@ -1064,6 +1822,13 @@ test('should be able to return object from shared crate', (t) => {
   })
 })
 
+test('spawn blocking closure', async (t) => {
+  t.is(await sumInThreadPool([1, 2, 3, 4294967295]), 'sum: 4294967301')
+  await t.throwsAsync(() => sumInThreadPool([]), {
+    message: 'Nothing to sum',
+  })
+})
+
 const AbortSignalTest =
   typeof AbortController !== 'undefined' ? test : test.skip
 
@@ -1107,6 +1872,43 @@ BigIntTest('BigInt get_u64', (t) => {
   t.is(bigintGetU64AsString(BigInt(0)), '0')
 })
 
+BigIntTest('lossless BigInt conversions', (t) => {
+  t.is(bigintCheckedAdd(1n, 2n ** 127n), 2n ** 127n + 1n)
+  t.throws(() => bigintCheckedAdd(2n ** 128n - 1n, 1n), {
+    code: 'InvalidArg',
+    message: "The sum doesn't fit in u128",
+  })
+  t.throws(() => bigintCheckedAdd(-1n, 1n), {
+    message: "The BigInt doesn't fit in u128",
+  })
+  t.throws(() => bigintCheckedAdd(0n, 2n ** 128n), {
+    message: "The BigInt doesn't fit in u128",
+  })
+  t.is(bigintI128ToString(-(2n ** 127n)), (-(2n ** 127n)).toString())
+  t.is(bigintI128ToString(2n ** 127n - 1n), (2n ** 127n - 1n).toString())
+  t.throws(() => bigintI128ToString(2n ** 127n), {
+    message: "The BigInt doesn't fit in i128",
+  })
+  t.throws(() => bigintI128ToString(-(2n ** 127n) - 1n), {
+    message: "The BigInt doesn't fit in i128",
+  })
+})
+
+BigIntTest('num-bigint conversions', (t) => {
+  t.is(bigintFactorial(0), 1n)
+  t.is(bigintFactorial(30), 265252859812191058636308480000000n)
+  t.is(bigintNegate(-(2n ** 100n)), 2n ** 100n)
+  t.is(bigintNegate(5n), -5n)
+  t.is(bigintNegate(0n), 0n)
+  t.is(bigintBitLength(0n), 0)
+  t.is(bigintBitLength(2n ** 200n), 201)
+  t.is(bigintBitLength(-0n), 0)
+  t.throws(() => bigintBitLength(-1n), {
+    code: 'InvalidArg',
+    message: "The BigInt doesn't fit in BigUint",
+  })
+})
+
 BigIntTest('js mod test', (t) => {
   t.is(xxh64Alias(Buffer.from('hello world')), BigInt('1116'))
   t.is(xxh3.xxh3_64(Buffer.from('hello world')), BigInt('1116'))
@@ -1117,6 +1919,68 @@ BigIntTest('js mod test', (t) => {
   const xx3 = new xxh3.Xxh3()
   xx3.update(Buffer.from('hello world'))
   t.is(xx3.digest(), BigInt('1116'))
+  t.is(xx3.endianness, endianness() === 'LE' ? 'little' : 'big')
+  t.true([4, 8].includes(xxh3.WORD_SIZE))
+})
+
+test('js_name computed by macros', (t) => {
+  t.is(getAnswer42(), 42)
+  t.is(ANSWER_42, 42)
+  // the version of `examples/napi/Cargo.toml`
+  t.is(napi_examples_version(), '0.1.0')
+})
+
+test('auto converted types', (t) => {
+  const square = {
+    type: 'Polygon' as const,
+    vertices: [
+      { x: 0, y: 0 },
+      { x: 2, y: 0 },
+      { x: 2, y: 2 },
+      { x: 0, y: 2 },
+    ],
+  }
+  t.is(shapeArea(square), 4)
+  t.is(shapeArea({ type: 'Circle', center: { x: 1, y: 1 }, radius: 1 }), Math.PI)
+  t.deepEqual(shapeCenter(square), { x: 1, y: 1 })
+  t.deepEqual(outlineStyle(2), { fill: Fill.Outline, strokeWidth: 2 })
+  t.true(isFilled({ fill: Fill.Solid, strokeWidth: 1 }))
+  t.throws(
+    // @ts-expect-error
+    () => isFilled({ fill: 'Dotted', strokeWidth: 1 }),
+    {
+      message:
+        'value `"Dotted"` does not match any variant of enum `Fill` on Style.fill',
+    },
+  )
+})
+
+test('function overloads', (t) => {
+  t.is(toMilliseconds('1.5s'), 1500)
+  t.is(toMilliseconds(2, 'm'), 120000)
+  t.is(toMilliseconds(5), 5)
+  t.is(toMilliseconds([1, 500000000]), 1500)
+  t.is(toMilliseconds.name, 'toMilliseconds')
+  t.throws(() => toMilliseconds('1x'), { message: 'Unknown unit `x`' })
+  t.throws(
+    // @ts-expect-error
+    () => toMilliseconds(true),
+    {
+      instanceOf: TypeError,
+      code: 'InvalidArg',
+      message: 'No overload of `toMilliseconds` matches the arguments (Boolean)',
+    },
+  )
+  t.throws(
+    // @ts-expect-error
+    () => toMilliseconds(1, 's', 3),
+    {
+      message:
+        'No overload of `toMilliseconds` matches the arguments (Number, String, Number)',
+    },
+  )
+  t.is(describeValue(1), 'number 1')
+  t.is(describeValue('1'), 'String')
 })
 
 BigIntTest('from i128 i64', (t) => {
@@ -1206,6 +2070,25 @@ Napi4Test('Promise should reject raw error in rust', async (t) => {
   t.is(err, fxError)
 })
 
+Napi4Test('await thenable in rust', async (t) => {
+  // @ts-expect-error
+  t.is(await asyncPlus100({ then: (resolve) => resolve(1) }), 101)
+  await t.throwsAsync(
+    // @ts-expect-error
+    () => asyncPlus100({ then: (_, reject) => reject(new Error('nope')) }),
+    { message: 'nope' },
+  )
+  t.throws(
+    // @ts-expect-error
+    () => asyncPlus100(5),
+    { message: 'Expected a Promise or a thenable, got Number' },
+  )
+  await t.throwsAsync(() => asyncPlus100(Promise.reject(3)), {
+    instanceOf: Error,
+    message: '3',
+  })
+})
+
 Napi4Test('call ThreadsafeFunction with callback', async (t) => {
   await t.notThrowsAsync(
     () =>
@@ -1336,6 +2219,13 @@ Napi4Test('build ThreadsafeFunction from Function', (t) => {
   return subject.pipe(take(3))
 })
 
+Napi4Test('deferred resolved from another thread', async (t) => {
+  t.is(await sumOnThread([1, 2, 3]), 6)
+  await t.throwsAsync(() => sumOnThread([0xffffffff, 1]), {
+    message: 'Sum overflowed',
+  })
+})
+
 Napi4Test('promise in either', async (t) => {
   t.is(await promiseInEither(1), false)
   t.is(await promiseInEither(20), true)
@@ -1347,6 +2237,194 @@ Napi4Test('promise in either', async (t) => {
 
 const Napi5Test = Number(process.versions.napi) >= 5 ? test : test.skip
 
+Napi5Test('fetch Request and Response', async (t) => {
+  const response = echoRequest(
+    new Request('http://localhost/napi?rs=1', {
+      method: 'POST',
+      headers: { 'user-agent': 'ava' },
+    }),
+  )
+  t.true(response instanceof Response)
+  t.is(response.status, 201)
+  t.is(response.statusText, 'Created')
+  t.is(response.headers.get('content-type'), 'text/plain')
+  t.is(response.headers.get('x-header-count'), '1')
+  t.is(await response.text(), 'POST http://localhost/napi?rs=1 ava')
+  t.is(
+    await readRequestBody(
+      new Request('http://localhost', { method: 'PUT', body: 'payload' }),
+    ),
+    'payload',
+  )
+  t.is(await createStreamingResponse(['a', 'b', 'c']).text(), 'abc')
+  t.is(getResponseStatus(new Response(null, { status: 404 })), 404)
+})
+
+const TransformStreamTest =
+  typeof process.getBuiltinModule === 'function' ? Napi5Test : test.skip
+
+TransformStreamTest('StreamCodec backed Transform stream', async (t) => {
+  const stream = createHexEncoderStream()
+  t.true(stream instanceof Transform)
+  stream.write('ab')
+  stream.end(Buffer.from('c'))
+  const chunks: Buffer[] = []
+  for await (const chunk of stream) {
+    chunks.push(chunk)
+  }
+  t.is(Buffer.concat(chunks).toString(), '616263\n3 bytes\n')
+})
+
+async function pipeThrough(stream: Transform, chunks: Buffer[]) {
+  const output: Buffer[] = []
+  await pipeline(
+    Readable.from(chunks),
+    stream,
+    new Writable({
+      write(chunk, _encoding, callback) {
+        output.push(chunk)
+        callback()
+      },
+    }),
+  )
+  return Buffer.concat(output)
+}
+
+TransformStreamTest('compression codecs', async (t) => {
+  const input = Buffer.from('napi-rs '.repeat(100_000))
+  const chunks = [
+    input.subarray(0, 1000),
+    input.subarray(1000, 500_000),
+    input.subarray(500_000),
+  ]
+  const gzip = await pipeThrough(createCompressStream('gzip'), chunks)
+  t.deepEqual(gunzipSync(gzip), input)
+  const deflate = await pipeThrough(createCompressStream('deflate', 9), chunks)
+  t.deepEqual(inflateSync(deflate), input)
+  const brotli = await pipeThrough(createCompressStream('brotli', 5), chunks)
+  t.deepEqual(brotliDecompressSync(brotli), input)
+  t.deepEqual(
+    await pipeThrough(createDecompressStream('gzip'), [gzipSync(input)]),
+    input,
+  )
+  t.deepEqual(
+    await pipeThrough(createDecompressStream('brotli'), [
+      brotliCompressSync(input),
+    ]),
+    input,
+  )
+  if (!process.env.WASI_TEST) {
+    const zstd = await pipeThrough(createCompressStream('zstd'), chunks)
+    t.deepEqual(
+      await pipeThrough(createDecompressStream('zstd'), [
+        zstd.subarray(0, 10),
+        zstd.subarray(10),
+      ]),
+      input,
+    )
+  }
+  t.throws(() => createCompressStream('gzip', 10), {
+    message: 'The compression level must be between 0 and 9, got 10',
+  })
+})
+
+TransformStreamTest('compression codecs flush', async (t) => {
+  const input = Buffer.from('napi-rs '.repeat(1000))
+  for (const format of ['gzip', 'deflate', 'brotli']) {
+    // the streams cut before the trailer written on flush are rejected
+    const compressed = await pipeThrough(createCompressStream(format), [input])
+    await t.throwsAsync(
+      pipeThrough(createDecompressStream(format), [
+        compressed.subarray(0, compressed.length - 5),
+      ]),
+    )
+  }
+  const compressed = await pipeThrough(createCompressStream('deflate'), [input])
+  await t.throwsAsync(
+    pipeThrough(createDecompressStream('deflate'), [
+      compressed.subarray(0, compressed.length - 5),
+    ]),
+    { message: 'inflate failed: the deflate stream is incomplete' },
+  )
+})
+
+TransformStreamTest('compression codecs backpressure', async (t) => {
+  const stream = createCompressStream('gzip')
+  t.false(stream.write(Buffer.alloc(64 * 1024)))
+  const drained = once(stream, 'drain')
+  stream.resume()
+  await drained
+  stream.end()
+  await once(stream, 'end')
+  t.pass()
+})
+
+test('RegExp interop', (t) => {
+  const re = createRegExp('(?<year>\\d{4})-(\\d{2})(x)?', 'g')
+  t.true(re instanceof RegExp)
+  t.is(re.source, '(?<year>\\d{4})-(\\d{2})(x)?')
+  t.throws(() => createRegExp('(', ''), { instanceOf: SyntaxError })
+  t.deepEqual(getRegExpSourceAndFlags(/a+b/iu), ['a+b', 'iu'])
+  t.true(regExpTest(/ab/, 'xaby'))
+  t.false(regExpTest(/ab/, 'ba'))
+  t.deepEqual(regExpExec(/(?<y>\d{4})-(\d{2})(x)?/, 'on 2024-05'), {
+    matched: '2024-05',
+    index: 3,
+    captures: ['2024', '05', null],
+    groups: { y: '2024' },
+  })
+  t.is(regExpExec(/z/, 'abc'), null)
+  t.deepEqual(
+    regExpMatchAll(re, '2020-01 2021-02').map((m) => [m.matched, m.index]),
+    [
+      ['2020-01', 0],
+      ['2021-02', 8],
+    ],
+  )
+  t.throws(() => regExpMatchAll(/a/, 'a'), { instanceOf: TypeError })
+  t.true(isRegExp(/a/))
+  t.true(isRegExp(createRegExp('a', 'g')))
+  t.false(isRegExp('/a/'))
+  t.false(isRegExp({ source: 'a', flags: '' }))
+  t.false(isRegExp(null))
+})
+
+test('Intl collation and case mapping', (t) => {
+  const items = ['z', 'ä', 'a', '10', '9', 'b']
+  t.deepEqual(
+    sortByLocale(items, 'de'),
+    [...items].sort(new Intl.Collator('de', { numeric: true }).compare),
+  )
+  t.deepEqual(sortByLocale(['z', 'ä', 'a'], 'sv'), ['a', 'z', 'ä'])
+  t.true(localeEqualsIgnoreAccents('resume', 'Résumé'))
+  t.false(localeEqualsIgnoreAccents('a', 'b'))
+  t.is(toLocaleUpperCase('istanbul', 'tr'), 'İSTANBUL')
+  t.is(toLocaleUpperCase('istanbul', 'en'), 'ISTANBUL')
+})
+
+test('memoize', (t) => {
+  const lookups = localeLookupCount()
+  const zhCN = lookupLocale('zh-cn')
+  t.deepEqual(zhCN, { language: 'zh', region: 'CN' })
+  t.is(lookupLocale('zh-cn'), zhCN)
+  t.is(localeLookupCount(), lookups + 1)
+  t.deepEqual(lookupLocale('en'), { language: 'en' })
+  t.is(localeLookupCount(), lookups + 2)
+  // errors are not memoized
+  t.throws(() => lookupLocale(''), { code: 'InvalidArg' })
+  t.throws(() => lookupLocale(''), { code: 'InvalidArg' })
+  t.is(localeLookupCount(), lookups + 4)
+  // the least recently used results are released beyond 256 per function
+  lookupLocale('zh-cn')
+  for (let i = 0; i < 256; i++) {
+    lookupLocale(`x${i}`)
+  }
+  t.is(localeLookupCount(), lookups + 260)
+  t.is(lookupLocale('x255').language, 'x255')
+  t.not(lookupLocale('zh-cn'), zhCN)
+  t.is(localeLookupCount(), lookups + 261)
+})
+
 Napi5Test('Date test', (t) => {
   const fixture = new Date('2016-12-24')
   t.is(dateToNumber(fixture), fixture.valueOf())
@@ -1363,6 +2441,29 @@ Napi5Test('Date to chrono test', (t) => {
   )
 })
 
+Napi5Test('Date from and to SystemTime and chrono', (t) => {
+  t.is(dateFromSystemTime(1, 1_500_000).getTime(), 1001)
+  // rounded down before the epoch too
+  t.is(dateFromSystemTime(-1, 999_999).getTime(), -1000)
+  t.is(dateFromSystemTime(8.64e12, 0).getTime(), 8.64e15)
+  t.throws(() => dateFromSystemTime(8.64e12 + 1, 0), {
+    message: 'The SystemTime is out of the range of Date',
+  })
+  t.is(dateToSystemTimeSecs(new Date(-1500)), -1.5)
+  t.is(dateToSystemTimeSecs(new Date(1500)), 1.5)
+  t.throws(() => dateToSystemTimeSecs(new Date(NaN)), {
+    message: 'Expected a valid Date, got Invalid Date',
+  })
+  t.deepEqual(
+    dateFromChrono('2024-02-07T18:28:18.123-08:00'),
+    new Date('2024-02-08T02:28:18.123Z'),
+  )
+  t.is(
+    dateToChrono(new Date('2024-02-07T18:28:18.123Z')),
+    '2024-02-07T18:28:18.123+00:00',
+  )
+})
+
 Napi5Test('Get date', (t) => {
   const fixture1 = new Date('2024-02-07T18:28:18-0800')
   t.deepEqual(chronoDateFixtureReturn1(), fixture1)
@@ -1422,3 +2523,686 @@ test('throw syntax error', (t) => {
     message,
   )
 })
+
+test('throw errors with NUL characters', (t) => {
+  t.throws(() => throwTypeError('Invalid \0 byte', 'E_\0'), {
+    instanceOf: TypeError,
+    message: 'Invalid \uFFFD byte',
+    code: 'E_\uFFFD',
+  })
+  t.throws(() => throwTypeError('Ungültige Eingabe 🚫'), {
+    instanceOf: TypeError,
+    message: 'Ungültige Eingabe 🚫',
+  })
+  t.throws(() => throwSyntaxError('Unexpected \0'), {
+    instanceOf: SyntaxError,
+    message: 'Unexpected \uFFFD',
+  })
+})
+
+test('throw custom error', (t) => {
+  class ValidationError extends Error {
+    name = 'ValidationError'
+  }
+  const error = t.throws(() => throwCustomError(ValidationError, 'Invalid'), {
+    instanceOf: ValidationError,
+    message: 'Invalid',
+  })
+  t.is(error.name, 'ValidationError')
+})
+
+test('remove wrap and wrap again', (t) => {
+  const obj = {}
+  t.is(rewrapString(obj, 'first'), null)
+  t.is(rewrapString(obj, 'second'), 'first')
+  t.is(rewrapString(obj, 'third'), 'second')
+})
+
+test('type tag a wrapped object', (t) => {
+  const obj = {}
+  t.is(rewrapString(obj, 'first'), null)
+  tagConfigObject(obj)
+  t.true(isConfigObject(obj))
+  t.is(rewrapString(obj, 'second'), 'first')
+})
+
+test('run in escapable scope', (t) => {
+  t.deepEqual(createLabels(2), { item0: 'Item 0', item1: 'Item 1' })
+  t.deepEqual(createLabels(0), {})
+})
+
+test('typed instance data', (t) => {
+  t.is(getAppName(), null)
+  t.is(setAppName('first'), null)
+  const count = countRequest()
+  t.is(countRequest(), count + 1)
+  t.is(setAppName('second'), 'first')
+  t.is(getAppName(), 'second')
+  t.is(countRequest(), count + 2)
+})
+
+test('loose coercion', (t) => {
+  t.is(looseSum(['1', 2, true, null, ' 3 ', [4]]), 11)
+  t.true(Number.isNaN(looseSum(['abc'])))
+  t.throws(() => looseSum([Symbol('x')]), {
+    instanceOf: TypeError,
+    message: 'Cannot convert a Symbol value to a number',
+  })
+  t.is(looseDescribe(0), '0 is falsy')
+  t.is(looseDescribe('0'), '0 is truthy')
+  t.is(looseDescribe({}), '[object Object] is truthy')
+  t.is(looseDescribe(null), 'null is falsy')
+})
+
+test('structured clone', (t) => {
+  const check = () => {
+    const config: any = {
+      name: 'app',
+      created: new Date(0),
+      tags: new Set(['a']),
+      limits: new Map([['rps', 10]]),
+      nested: { list: [1, 2] },
+    }
+    config.self = config
+    const copy: any = snapshotConfig(config)
+    config.nested.list.push(3)
+    config.limits.set('rps', 99)
+    t.not(copy, config)
+    t.is(copy.self, copy)
+    t.deepEqual(copy.created, new Date(0))
+    t.deepEqual(copy.tags, new Set(['a']))
+    t.is(copy.limits.get('rps'), 10)
+    t.deepEqual(copy.nested.list, [1, 2])
+    t.throws(() => snapshotConfig({ run() {} }), {
+      message: /could not be cloned/,
+    })
+  }
+  check()
+  // falls back to `v8.serialize` without the global
+  const { structuredClone } = globalThis
+  // @ts-expect-error
+  delete globalThis.structuredClone
+  try {
+    check()
+  } finally {
+    globalThis.structuredClone = structuredClone
+  }
+})
+
+test('async scope cancels its pending work', async (t) => {
+  const ticker = new Ticker()
+  t.is(await ticker.delay(1, 1), 1)
+  t.is(ticker.pending, 0)
+  const dropped = droppedDelays()
+  const delays = [ticker.delay(10_000, 2), ticker.delay(10_000, 3)]
+  t.is(ticker.pending, 2)
+  await ticker.close()
+  t.is(ticker.pending, 0)
+  t.is(droppedDelays(), dropped + 2)
+  for (const delay of delays) {
+    await t.throwsAsync(delay, {
+      code: 'Cancelled',
+      message: 'The async scope is closed',
+    })
+  }
+  await t.throwsAsync(ticker.delay(1, 4), { code: 'Cancelled' })
+})
+
+test('queue microtasks and immediates', async (t) => {
+  const order: string[] = []
+  await new Promise<void>((resolve) => {
+    scheduleFollowUps(
+      () => {
+        order.push('microtask')
+      },
+      () => {
+        order.push('immediate')
+        resolve()
+      },
+    )
+    Promise.resolve().then(() => order.push('then'))
+    order.push('sync')
+  })
+  t.deepEqual(order, ['sync', 'microtask', 'then', 'immediate'])
+})
+
+test('observe the lifecycle of async works', async (t) => {
+  const observer = new AsyncWorkObserver()
+  t.is(await withoutAbortController(1, 2), 3)
+  await t.throwsAsync(readFileAsync('__not_exist_file__'))
+  t.true(observer.stop())
+  t.false(observer.stop())
+  const events = observer.takeEvents()
+  const task = events.filter(({ typeName }) => typeName.endsWith('::DelaySum'))
+  t.deepEqual(
+    task.slice(0, 3).map(({ step, kind }) => [step, kind]),
+    [
+      ['queue', 'Task'],
+      ['start', 'Task'],
+      ['complete', 'Task'],
+    ],
+  )
+  t.true(task[2].runningMs! >= 90)
+  const future = events.filter(({ typeName }) =>
+    typeName.includes('read_file_async'),
+  )
+  t.deepEqual(
+    future.map(({ step, kind }) => [step, kind]),
+    [
+      ['queue', 'Future'],
+      ['start', 'Future'],
+      ['error', 'Future'],
+    ],
+  )
+})
+
+test('query the available Node-API functions', (t) => {
+  t.true(isNapiFunctionAvailable('napi_create_object'))
+  t.true(isNapiFunctionAvailable('napi_get_uv_event_loop'))
+})
+
+test('memory usage', (t) => {
+  const usage = memoryUsage()
+  t.true(usage.rss > 0)
+  t.true(usage.heapUsed > 0)
+  t.true(usage.heapUsed <= usage.heapTotal)
+  t.is(typeof usage.addonExternal, 'number')
+  if (!process.env.WASI_TEST) {
+    t.true(usage.isolateExternal > 0)
+  }
+  t.is(addonExternalMemoryGrowth(1024 * 1024), 1024 * 1024)
+})
+
+test('cancel a synchronous loop', async (t) => {
+  t.throws(() => spinUntilCancelled(null, 10), {
+    message: 'The operation timed out',
+  })
+  t.throws(() => spinUntilCancelled(new Int32Array(1), 10), {
+    message: 'Expected an Int32Array on a SharedArrayBuffer as the cancel flag',
+  })
+  if (process.env.WASI_TEST) {
+    t.pass()
+    return
+  }
+  const flag = new Int32Array(new SharedArrayBuffer(4))
+  const worker = new Worker(
+    `const { workerData } = require('node:worker_threads')
+    Atomics.wait(workerData, 0, 0, 50)
+    Atomics.store(workerData, 0, 1)`,
+    { eval: true, workerData: flag },
+  )
+  await new Promise((resolve) => worker.once('online', resolve))
+  t.throws(() => spinUntilCancelled(flag, 10_000), {
+    message: 'The operation was cancelled',
+  })
+  await worker.terminate()
+})
+
+test('schedule on the libuv loop', async (t) => {
+  const order: string[] = []
+  await new Promise<void>((resolve) => {
+    callOnIdle(() => {
+      order.push('idle')
+      resolve()
+    })
+    Promise.resolve().then(() => order.push('microtask'))
+    order.push('sync')
+  })
+  t.deepEqual(order, ['sync', 'microtask', 'idle'])
+  const received = await new Promise<number[]>((resolve) => {
+    const values: number[] = []
+    sendFromThread(100, (value) => {
+      values.push(value)
+      if (values.length === 100) {
+        resolve(values)
+      }
+    })
+  })
+  t.deepEqual(
+    received,
+    Array.from({ length: 100 }, (_, i) => i),
+  )
+})
+
+test('drive a local future on the JS thread', async (t) => {
+  const sums: number[] = []
+  const result = sumPromisesLocally(
+    [
+      Promise.resolve(1),
+      new Promise((resolve) => setTimeout(resolve, 10, 2)),
+      Promise.resolve(3),
+    ],
+    (sum) => {
+      sums.push(sum)
+    },
+  )
+  t.deepEqual(sums, [])
+  t.is(await result, 6)
+  t.deepEqual(sums, [1, 3, 6])
+  await t.throwsAsync(
+    () => sumPromisesLocally([Promise.reject(new Error('rejected'))], () => {}),
+    { message: 'rejected' },
+  )
+  await t.throwsAsync(
+    () =>
+      sumPromisesLocally([Promise.resolve(1)], () => {
+        throw new TypeError('callback failed')
+      }),
+    { instanceOf: TypeError, message: 'callback failed' },
+  )
+})
+
+test('await JS promises and thenables in a local future', async (t) => {
+  const seen: number[] = []
+  t.is(
+    await chainAsyncSteps(1, 4, (value) => {
+      seen.push(value)
+      return new Promise((resolve) => setTimeout(resolve, 5, value * 2))
+    }),
+    16,
+  )
+  t.deepEqual(seen, [1, 2, 4, 8])
+  t.is(await chainAsyncSteps(3, 2, (value) => value + 1), 5)
+  t.is(
+    await chainAsyncSteps(3, 2, (value) => ({
+      then(resolve: (value: number) => void) {
+        resolve(value * 10)
+      },
+    })),
+    300,
+  )
+  await t.throwsAsync(
+    () =>
+      chainAsyncSteps(1, 3, (value) =>
+        value === 2 ? Promise.reject(new RangeError('too big')) : value + 1,
+      ),
+    { instanceOf: RangeError, message: 'too big' },
+  )
+})
+
+test('crypto random bytes', (t) => {
+  const bytes = randomBytes(100_000)
+  t.true(bytes instanceof Buffer)
+  t.is(bytes.length, 100_000)
+  t.is(randomBytes(0).length, 0)
+  t.notDeepEqual(randomBytes(16), randomBytes(16))
+  setRandomSeed(42)
+  const seeded = randomBytes(13)
+  setRandomSeed(42)
+  t.deepEqual(randomBytes(13), seeded)
+  setRandomSeed(null)
+  t.notDeepEqual(randomBytes(13), seeded)
+})
+
+test('record and replay calls', (t) => {
+  t.deepEqual(replayRecordedCalls(), [])
+  startRecordingCalls()
+  t.deepEqual(translatePoint({ x: 1, y: 2 }, 3, 4), { x: 4, y: 6 })
+  t.is(parsePort('8080'), 8080)
+  t.throws(() => parsePort('http'), {
+    message: 'invalid digit found in string',
+  })
+  t.deepEqual(replayRecordedCalls(), [
+    'translatePoint',
+    'parsePort',
+    'parsePort',
+  ])
+  // recording is stopped
+  translatePoint({ x: 0, y: 0 }, 1, 1)
+  t.deepEqual(replayRecordedCalls(), [])
+})
+
+test('recording stops at the first call it can not write', (t) => {
+  t.is(takeRecordingError(), null)
+  startRecordingToBrokenLog()
+  t.is(parsePort('8080'), 8080)
+  t.is(
+    takeRecordingError(),
+    'Failed to record the call of `parsePort`: disk full',
+  )
+  t.is(parsePort('8081'), 8081)
+  t.is(takeRecordingError(), null)
+})
+
+test('trace failed calls', (t) => {
+  const lines = traceFailedCalls(() => {
+    // @ts-expect-error
+    t.throws(() => add(1, 'x'))
+  })
+  t.is(lines.length, 1)
+  t.regex(
+    lines[0],
+    /^\[napi-rs\] napi_get_value_uint32 failed with NumberExpected in .*from_napi_value \(.*number\.rs:\d+\)$/,
+  )
+  t.deepEqual(
+    traceFailedCalls(() => {}),
+    [],
+  )
+})
+
+test('register named module', (t) => {
+  t.is(extraAdd(1, 2), 3)
+  const extra = loadExtraModule()
+  t.deepEqual(Object.keys(extra).sort(), [
+    'EXTRA_VERSION',
+    'ExtraCounter',
+    'extraAdd',
+  ])
+  t.is(extra.extraAdd(2, 3), 5)
+  t.is(extra.EXTRA_VERSION, 1)
+  t.is(new extra.ExtraCounter().increment(), 1)
+})
+
+test('plugin vtable', (t) => {
+  const host = counterVtable()
+  t.is(pluginAdd(host, 2), 2)
+  t.is(pluginAdd(counterVtable(), 3), 5)
+  t.is(pluginGet(host), 5)
+  t.throws(() => pluginGet(createExternal(1) as any))
+  t.throws(() => pluginGet({} as any))
+})
+
+test('async generator', async (t) => {
+  const values: number[] = []
+  for await (const n of countUpAsync(3)) {
+    values.push(n)
+  }
+  t.deepEqual(values, [1, 2, 3])
+  const countdown: number[] = []
+  for await (const n of countdownAsync(3)) {
+    countdown.push(n)
+  }
+  t.deepEqual(countdown, [2, 1, 0])
+  const generator = countUpAsync(5)
+  t.deepEqual(
+    await Promise.all([generator.next(), generator.next()]),
+    [
+      { value: 1, done: false },
+      { value: 2, done: false },
+    ],
+  )
+  t.deepEqual(await generator.return(), { value: undefined, done: true })
+  t.deepEqual(await generator.next(), { value: undefined, done: true })
+  const items: string[] = []
+  await t.throwsAsync(
+    async () => {
+      for await (const item of failAsyncAfter(2)) {
+        items.push(item)
+      }
+    },
+    { message: 'generator failed' },
+  )
+  t.deepEqual(items, ['item 0', 'item 1'])
+})
+
+test('class set hook', (t) => {
+  const model = new ObservedModel('Ada', 36)
+  model.name = 'Grace'
+  model.age = 37
+  model.nickname = 'Amazing'
+  t.deepEqual(model.takeChanges(), ['name', 'age', 'nickname'])
+  t.throws(
+    () => {
+      model.age = 200
+    },
+    { message: '200 is not a valid age' },
+  )
+  t.is(model.age, 37)
+  t.deepEqual(model.takeChanges(), [])
+})
+
+test('borrowed getters', (t) => {
+  const document = new Document('notes', Buffer.from('first\nsecond'))
+  t.is(document.title, 'notes')
+  const content = document.content
+  t.true(Buffer.isBuffer(content))
+  t.is(content.toString(), 'first\nsecond')
+  // bytes are copied, writes don't reach the shared data
+  content[0] = 0x46
+  t.is(document.content.toString(), 'first\nsecond')
+  t.is(document.previousContent.length, 0)
+  document.replaceContent(Buffer.from('third'))
+  t.is(document.content.toString(), 'third')
+  t.is(document.previousContent.toString(), 'first\nsecond')
+  document.replaceContent(Buffer.from('fourth'))
+  t.is(document.previousContent.toString(), 'third')
+})
+
+test('unstable exports warn on first use', async (t) => {
+  const warnings: string[] = []
+  const onWarning = (warning: Error & { code?: string }) => {
+    if (warning.code === 'NAPI_RS_UNSTABLE') {
+      t.is(warning.name, 'ExperimentalWarning')
+      warnings.push(warning.message)
+    }
+  }
+  process.on('warning', onWarning)
+  t.deepEqual(splitWords('a b  c'), ['a', 'b', 'c'])
+  t.deepEqual(splitWords('d'), ['d'])
+  const counter = new WordCounter()
+  counter.feed('a b')
+  t.is(counter.feed('c'), 3)
+  // the warnings are emitted on the next tick
+  await new Promise((resolve) => setImmediate(resolve))
+  process.off('warning', onWarning)
+  t.deepEqual(warnings, [
+    '`splitWords` is an experimental API and might change at any time',
+    '`WordCounter.feed` is an experimental API and might change at any time',
+  ])
+})
+
+test('cached built-ins', (t) => {
+  t.deepEqual(withDefaultOptions({ retries: 1 }), [{ retries: 1, verbose: false }])
+  t.deepEqual(withDefaultOptions([{ verbose: true }, {}]), [
+    { retries: 3, verbose: true },
+    { retries: 3, verbose: false },
+  ])
+  t.deepEqual(utf8Buffer('héllo'), Buffer.from('héllo'))
+})
+
+Napi4Test('report errors from other threads', async (t) => {
+  const errors = await new Promise<Error[]>((resolve) => {
+    const received: Error[] = []
+    reportErrorsToCallback(
+      (error) => {
+        received.push(error as Error)
+        if (received.length === 2) {
+          resolve(received)
+        }
+      },
+      ['disk full', 'timed out'],
+    )
+  })
+  t.true(errors.every((error) => error instanceof Error))
+  t.deepEqual(errors.map((error) => error.message).sort(), [
+    'disk full',
+    'timed out',
+  ])
+  const emitter = new EventEmitter()
+  const emitted = once(emitter, 'error')
+  reportErrorsToEmitter(emitter, ['connection reset'])
+  const [error] = await emitted
+  t.is(error.message, 'connection reset')
+  t.is(error.code, 'GenericFailure')
+})
+
+test('ring buffer exchange', async (t) => {
+  const pipeline = new FramePipeline(3, 4)
+  const buffers = pipeline.buffers
+  t.is(buffers.length, 3)
+  t.is(pipeline.buffers[1], buffers[1])
+  t.throws(() => pipeline.release(0), {
+    code: 'InvalidArg',
+    message: 'The slot 0 is not held by JavaScript',
+  })
+  pipeline.start(8)
+  const frames: string[] = []
+  const held: number[] = []
+  while (frames.length < 8) {
+    const frame = pipeline.nextFrame()
+    if (!frame) {
+      // the producer waits for a released slot
+      t.true(held.length <= 3)
+      if (held.length) {
+        pipeline.release(held.shift()!)
+      }
+      await new Promise((resolve) => setTimeout(resolve, 2))
+      continue
+    }
+    frames.push(
+      Array.from(new Uint8Array(buffers[frame.index], 0, frame.length)).join(''),
+    )
+    held.push(frame.index)
+    if (held.length === 3) {
+      t.is(pipeline.nextFrame(), null)
+    }
+  }
+  t.deepEqual(frames, ['0', '11', '222', '3333', '4', '55', '666', '7777'])
+})
+
+test('timestamped frame queue', async (t) => {
+  const sleep = (ms: number) => new Promise((resolve) => setTimeout(resolve, ms))
+
+  const queue = new CaptureQueue(2, 4, false)
+  const buffers = queue.buffers
+  queue.start(6)
+  const frames: [string, number][] = []
+  while (frames.length < 6) {
+    const frame = queue.poll()
+    if (!frame) {
+      await sleep(2)
+      continue
+    }
+    frames.push([
+      Array.from(new Uint8Array(buffers[frame.index], 0, frame.length)).join(''),
+      frame.timestamp,
+    ])
+    queue.release(frame.index)
+  }
+  t.deepEqual(frames, [
+    ['0', 0],
+    ['11', 1000],
+    ['222', 2000],
+    ['3333', 3000],
+    ['4', 4000],
+    ['55', 5000],
+  ])
+  t.is(queue.dropped, 0)
+  t.throws(() => queue.release(0), {
+    code: 'InvalidArg',
+    message: 'The slot 0 is not held by JavaScript',
+  })
+
+  // the producer never waits, only the newest frames are kept
+  const live = new CaptureQueue(3, 2, true)
+  live.start(10)
+  while (!live.finished && live.dropped < 7) {
+    await sleep(5)
+  }
+  const timestamps: number[] = []
+  let frame
+  while ((frame = live.poll())) {
+    timestamps.push(frame.timestamp)
+    live.release(frame.index)
+  }
+  t.deepEqual(timestamps, [7000, 8000, 9000])
+  t.is(live.dropped, 7)
+  t.true(live.finished)
+
+  const subscribed = new CaptureQueue(2, 4, false)
+  const seen: [number, string][] = []
+  subscribed.onFrame((frame, buffer) => {
+    seen.push([frame.timestamp, new Uint8Array(buffer, 0, frame.length).join('')])
+  })
+  subscribed.start(5)
+  while (!subscribed.finished || seen.length < 5) {
+    await sleep(5)
+  }
+  t.deepEqual(seen, [
+    [0, '0'],
+    [1000, '11'],
+    [2000, '222'],
+    [3000, '3333'],
+    [4000, '4'],
+  ])
+})
+
+test('accessors implemented by Rust closures', (t) => {
+  const temperature: { celsius: number; readonly fahrenheit: number } = {} as any
+  defineTemperature(temperature, 100)
+  t.deepEqual(Object.keys(temperature), ['celsius', 'fahrenheit'])
+  t.is(temperature.fahrenheit, 212)
+  temperature.celsius = 0
+  t.is(temperature.celsius, 0)
+  t.is(temperature.fahrenheit, 32)
+  t.throws(
+    () => {
+      temperature.celsius = -300
+    },
+    { code: 'InvalidArg', message: 'The temperature is below absolute zero' },
+  )
+  t.throws(
+    () => {
+      ;(temperature as any).fahrenheit = 1
+    },
+    { instanceOf: TypeError },
+  )
+  t.is(temperature.celsius, 0)
+})
+
+test('external memory mapped as ArrayBuffer', (t) => {
+  const allocations = deviceAllocations()
+  const readback = new DeviceReadback([1, 2, 3])
+  const data = readback.data!
+  t.deepEqual(Array.from(new Uint8Array(data)), [2, 4, 6])
+  t.is(readback.data, data)
+  t.true(readback.mapped)
+  t.is(deviceAllocations(), allocations + 1)
+  t.true(readback.unmap())
+  // detached before the memory is released
+  t.is(data.byteLength, 0)
+  t.is(readback.data, null)
+  t.false(readback.mapped)
+  t.is(deviceAllocations(), allocations)
+  t.false(readback.unmap())
+})
+
+test('symbol-keyed properties', (t) => {
+  class Custom {
+    get [Symbol.toStringTag]() {
+      return 'Custom'
+    }
+  }
+  t.is(getToStringTag(new Map()), 'Map')
+  t.is(getToStringTag(new Custom()), 'Custom')
+  t.is(getToStringTag({}), null)
+
+  const meta = Symbol('meta')
+  const target: Record<symbol, unknown> = {}
+  setSymbolMetadata(target, meta, 'v1')
+  t.is(target[meta], 'v1')
+  t.is(readSymbolMetadata(target, meta), 'v1')
+  t.is(readSymbolMetadata(Object.create(target), meta), null)
+  target[meta] = 1
+  t.throws(() => readSymbolMetadata(target, meta), {
+    code: 'InvalidArg',
+    message:
+      'Symbol-keyed property type mismatch. Expect value to be String, but received Number',
+  })
+})
+
+test('branded externals', (t) => {
+  const database = openDatabase('main')
+  t.is(databaseName(database), 'main')
+  // @ts-expect-error
+  t.throws(() => getExternal(database), {
+    code: 'InvalidArg',
+    message: '<u32> on `External` is not the type of wrapped object',
+  })
+  // @ts-expect-error
+  t.throws(() => databaseName(createExternal(1)), {
+    code: 'InvalidArg',
+    message:
+      '<napi_examples::external::Database> on `External` is not the type of wrapped object',
+  })
+})
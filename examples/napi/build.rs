@@ -1,5 +1,6 @@
 fn main() {
-  use napi_build::setup;
+  use napi_build::{branded_externals, setup};
 
   setup();
+  branded_externals();
 }
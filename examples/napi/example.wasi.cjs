@@ -91,316 +91,523 @@ function __napi_rs_initialize_modules(__napiInstance) {
   __napiInstance.exports['__napi_register__to_js_obj_5']?.()
   __napiInstance.exports['__napi_register__get_num_arr_6']?.()
   __napiInstance.exports['__napi_register__get_nested_num_arr_7']?.()
-  __napiInstance.exports['__napi_register__read_file_async_8']?.()
-  __napiInstance.exports['__napi_register__async_multi_two_9']?.()
-  __napiInstance.exports['__napi_register__panic_in_async_10']?.()
-  __napiInstance.exports['__napi_register__within_async_runtime_if_available_11']?.()
-  __napiInstance.exports['__napi_register__bigint_add_12']?.()
-  __napiInstance.exports['__napi_register__create_big_int_13']?.()
-  __napiInstance.exports['__napi_register__create_big_int_i64_14']?.()
-  __napiInstance.exports['__napi_register__bigint_get_u64_as_string_15']?.()
-  __napiInstance.exports['__napi_register__bigint_from_i64_16']?.()
-  __napiInstance.exports['__napi_register__bigint_from_i128_17']?.()
-  __napiInstance.exports['__napi_register__get_cwd_18']?.()
-  __napiInstance.exports['__napi_register__option_end_19']?.()
-  __napiInstance.exports['__napi_register__option_start_20']?.()
-  __napiInstance.exports['__napi_register__option_start_end_21']?.()
-  __napiInstance.exports['__napi_register__option_only_22']?.()
-  __napiInstance.exports['__napi_register__read_file_23']?.()
-  __napiInstance.exports['__napi_register__callback_return_promise_24']?.()
-  __napiInstance.exports['__napi_register__callback_return_promise_and_spawn_25']?.()
-  __napiInstance.exports['__napi_register__capture_error_in_callback_26']?.()
-  __napiInstance.exports['__napi_register__Animal_struct_27']?.()
-  __napiInstance.exports['__napi_register__Animal_impl_39']?.()
-  __napiInstance.exports['__napi_register__Dog_struct_40']?.()
-  __napiInstance.exports['__napi_register__Bird_struct_41']?.()
-  __napiInstance.exports['__napi_register__Bird_impl_46']?.()
-  __napiInstance.exports['__napi_register__Blake2bHasher_struct_47']?.()
-  __napiInstance.exports['__napi_register__Blake2bHasher_impl_49']?.()
-  __napiInstance.exports['__napi_register__Blake2bHasher_impl_51']?.()
-  __napiInstance.exports['__napi_register__Blake2bKey_struct_52']?.()
-  __napiInstance.exports['__napi_register__Context_struct_53']?.()
-  __napiInstance.exports['__napi_register__Context_impl_58']?.()
-  __napiInstance.exports['__napi_register__AnimalWithDefaultConstructor_struct_59']?.()
-  __napiInstance.exports['__napi_register__NinjaTurtle_struct_60']?.()
-  __napiInstance.exports['__napi_register__NinjaTurtle_impl_67']?.()
-  __napiInstance.exports['__napi_register__JsAssets_struct_68']?.()
-  __napiInstance.exports['__napi_register__JsAssets_impl_71']?.()
-  __napiInstance.exports['__napi_register__JsAsset_struct_72']?.()
-  __napiInstance.exports['__napi_register__JsAsset_impl_75']?.()
-  __napiInstance.exports['__napi_register__Optional_struct_76']?.()
-  __napiInstance.exports['__napi_register__Optional_impl_81']?.()
-  __napiInstance.exports['__napi_register__ObjectFieldClassInstance_struct_82']?.()
-  __napiInstance.exports['__napi_register__create_object_with_class_field_83']?.()
-  __napiInstance.exports['__napi_register__receive_object_with_class_field_84']?.()
-  __napiInstance.exports['__napi_register__NotWritableClass_struct_85']?.()
-  __napiInstance.exports['__napi_register__NotWritableClass_impl_87']?.()
-  __napiInstance.exports['__napi_register__CustomFinalize_struct_88']?.()
-  __napiInstance.exports['__napi_register__CustomFinalize_impl_90']?.()
-  __napiInstance.exports['__napi_register__Width_struct_91']?.()
-  __napiInstance.exports['__napi_register__plus_one_92']?.()
-  __napiInstance.exports['__napi_register__GetterSetterWithClosures_struct_93']?.()
-  __napiInstance.exports['__napi_register__GetterSetterWithClosures_impl_95']?.()
-  __napiInstance.exports['__napi_register__CatchOnConstructor_struct_96']?.()
-  __napiInstance.exports['__napi_register__CatchOnConstructor_impl_98']?.()
-  __napiInstance.exports['__napi_register__CatchOnConstructor2_struct_99']?.()
-  __napiInstance.exports['__napi_register__CatchOnConstructor2_impl_101']?.()
-  __napiInstance.exports['__napi_register__ClassWithLifetime_struct_102']?.()
-  __napiInstance.exports['__napi_register__ClassWithLifetime_impl_105']?.()
-  __napiInstance.exports['__napi_register__ClassWithFactory_struct_106']?.()
-  __napiInstance.exports['__napi_register__ClassWithFactory_impl_111']?.()
-  __napiInstance.exports['__napi_register__Selector_struct_112']?.()
-  __napiInstance.exports['__napi_register__date_to_number_113']?.()
-  __napiInstance.exports['__napi_register__chrono_utc_date_to_millis_114']?.()
-  __napiInstance.exports['__napi_register__chrono_local_date_to_millis_115']?.()
-  __napiInstance.exports['__napi_register__chrono_date_with_timezone_to_millis_116']?.()
-  __napiInstance.exports['__napi_register__chrono_date_add_1_minute_117']?.()
-  __napiInstance.exports['__napi_register__UtcDates_struct_118']?.()
-  __napiInstance.exports['__napi_register__LocalDates_struct_119']?.()
-  __napiInstance.exports['__napi_register__DatesWithTimeZone_struct_120']?.()
-  __napiInstance.exports['__napi_register__chrono_native_date_time_121']?.()
-  __napiInstance.exports['__napi_register__chrono_native_date_time_return_122']?.()
-  __napiInstance.exports['__napi_register__chrono_utc_date_return_123']?.()
-  __napiInstance.exports['__napi_register__chrono_local_date_return_124']?.()
-  __napiInstance.exports['__napi_register__chrono_date_with_timezone_return_125']?.()
-  __napiInstance.exports['__napi_register__chrono_date_fixture_return1_126']?.()
-  __napiInstance.exports['__napi_register__chrono_date_fixture_return2_127']?.()
-  __napiInstance.exports['__napi_register__either_string_or_number_128']?.()
-  __napiInstance.exports['__napi_register__return_either_129']?.()
-  __napiInstance.exports['__napi_register__either3_130']?.()
-  __napiInstance.exports['__napi_register__Obj_struct_131']?.()
-  __napiInstance.exports['__napi_register__either4_132']?.()
-  __napiInstance.exports['__napi_register__JsClassForEither_struct_133']?.()
-  __napiInstance.exports['__napi_register__JsClassForEither_impl_135']?.()
-  __napiInstance.exports['__napi_register__AnotherClassForEither_struct_136']?.()
-  __napiInstance.exports['__napi_register__AnotherClassForEither_impl_138']?.()
-  __napiInstance.exports['__napi_register__receive_class_or_number_139']?.()
-  __napiInstance.exports['__napi_register__receive_mut_class_or_number_140']?.()
-  __napiInstance.exports['__napi_register__receive_different_class_141']?.()
-  __napiInstance.exports['__napi_register__return_either_class_142']?.()
-  __napiInstance.exports['__napi_register__either_from_option_143']?.()
-  __napiInstance.exports['__napi_register__A_struct_144']?.()
-  __napiInstance.exports['__napi_register__B_struct_145']?.()
-  __napiInstance.exports['__napi_register__C_struct_146']?.()
-  __napiInstance.exports['__napi_register__either_from_objects_147']?.()
-  __napiInstance.exports['__napi_register__either_bool_or_function_148']?.()
-  __napiInstance.exports['__napi_register__promise_in_either_149']?.()
-  __napiInstance.exports['__napi_register__either_bool_or_tuple_150']?.()
-  __napiInstance.exports['__napi_register__Kind_151']?.()
-  __napiInstance.exports['__napi_register__Empty_152']?.()
-  __napiInstance.exports['__napi_register__Status_153']?.()
-  __napiInstance.exports['__napi_register__StringEnum_154']?.()
-  __napiInstance.exports['__napi_register__CustomNumEnum_155']?.()
-  __napiInstance.exports['__napi_register__enum_to_i32_156']?.()
-  __napiInstance.exports['__napi_register__SkippedEnums_157']?.()
-  __napiInstance.exports['__napi_register__CustomStringEnum_158']?.()
-  __napiInstance.exports['__napi_register__StructuredKind_struct_159']?.()
-  __napiInstance.exports['__napi_register__validate_structured_enum_160']?.()
-  __napiInstance.exports['__napi_register__run_script_161']?.()
-  __napiInstance.exports['__napi_register__get_module_file_name_162']?.()
-  __napiInstance.exports['__napi_register__throw_syntax_error_163']?.()
-  __napiInstance.exports['__napi_register__throw_error_164']?.()
-  __napiInstance.exports['__napi_register__panic_165']?.()
-  __napiInstance.exports['__napi_register__receive_string_166']?.()
-  __napiInstance.exports['__napi_register__custom_status_code_167']?.()
-  __napiInstance.exports['__napi_register__error_message_contains_null_byte_168']?.()
-  __napiInstance.exports['__napi_register__throw_async_error_169']?.()
-  __napiInstance.exports['__napi_register__CustomStruct_struct_170']?.()
-  __napiInstance.exports['__napi_register__CustomStruct_impl_173']?.()
-  __napiInstance.exports['__napi_register__create_external_174']?.()
-  __napiInstance.exports['__napi_register__create_external_string_175']?.()
-  __napiInstance.exports['__napi_register__get_external_176']?.()
-  __napiInstance.exports['__napi_register__mutate_external_177']?.()
-  __napiInstance.exports['__napi_register__create_optional_external_178']?.()
-  __napiInstance.exports['__napi_register__get_optional_external_179']?.()
-  __napiInstance.exports['__napi_register__mutate_optional_external_180']?.()
-  __napiInstance.exports['__napi_register__validate_array_181']?.()
-  __napiInstance.exports['__napi_register__validate_buffer_182']?.()
-  __napiInstance.exports['__napi_register__validate_typed_array_183']?.()
-  __napiInstance.exports['__napi_register__validate_typed_array_slice_184']?.()
-  __napiInstance.exports['__napi_register__validate_uint8_clamped_slice_185']?.()
-  __napiInstance.exports['__napi_register__validate_buffer_slice_186']?.()
-  __napiInstance.exports['__napi_register__validate_bigint_187']?.()
-  __napiInstance.exports['__napi_register__validate_boolean_188']?.()
-  __napiInstance.exports['__napi_register__validate_date_189']?.()
-  __napiInstance.exports['__napi_register__validate_date_time_190']?.()
-  __napiInstance.exports['__napi_register__validate_external_191']?.()
-  __napiInstance.exports['__napi_register__validate_function_192']?.()
-  __napiInstance.exports['__napi_register__validate_hash_map_193']?.()
-  __napiInstance.exports['__napi_register__validate_null_194']?.()
-  __napiInstance.exports['__napi_register__validate_undefined_195']?.()
-  __napiInstance.exports['__napi_register__validate_number_196']?.()
-  __napiInstance.exports['__napi_register__validate_promise_197']?.()
-  __napiInstance.exports['__napi_register__validate_string_198']?.()
-  __napiInstance.exports['__napi_register__validate_symbol_199']?.()
-  __napiInstance.exports['__napi_register__validate_optional_200']?.()
-  __napiInstance.exports['__napi_register__KindInValidate_201']?.()
-  __napiInstance.exports['__napi_register__validate_enum_202']?.()
-  __napiInstance.exports['__napi_register__StatusInValidate_203']?.()
-  __napiInstance.exports['__napi_register__validate_string_enum_204']?.()
-  __napiInstance.exports['__napi_register__return_undefined_if_invalid_205']?.()
-  __napiInstance.exports['__napi_register__return_undefined_if_invalid_promise_206']?.()
-  __napiInstance.exports['__napi_register__ts_rename_207']?.()
-  __napiInstance.exports['__napi_register__override_individual_arg_on_function_208']?.()
-  __napiInstance.exports['__napi_register__override_individual_arg_on_function_with_cb_arg_209']?.()
-  __napiInstance.exports['__napi_register__call0_210']?.()
-  __napiInstance.exports['__napi_register__call1_211']?.()
-  __napiInstance.exports['__napi_register__call2_212']?.()
-  __napiInstance.exports['__napi_register__apply0_213']?.()
-  __napiInstance.exports['__napi_register__apply1_214']?.()
-  __napiInstance.exports['__napi_register__call_function_215']?.()
-  __napiInstance.exports['__napi_register__call_function_with_arg_216']?.()
-  __napiInstance.exports['__napi_register__create_reference_on_function_217']?.()
-  __napiInstance.exports['__napi_register__call_function_with_arg_and_ctx_218']?.()
-  __napiInstance.exports['__napi_register__reference_as_callback_219']?.()
-  __napiInstance.exports['__napi_register__build_threadsafe_function_from_function_220']?.()
-  __napiInstance.exports['__napi_register__build_threadsafe_function_from_function_callee_handle_221']?.()
-  __napiInstance.exports['__napi_register__Fib_struct_222']?.()
-  __napiInstance.exports['__napi_register__Fib_impl_223']?.()
-  __napiInstance.exports['__napi_register__Fib_impl_225']?.()
-  __napiInstance.exports['__napi_register__Fib2_struct_226']?.()
-  __napiInstance.exports['__napi_register__Fib2_impl_227']?.()
-  __napiInstance.exports['__napi_register__Fib2_impl_229']?.()
-  __napiInstance.exports['__napi_register__Fib3_struct_230']?.()
-  __napiInstance.exports['__napi_register__Fib3_impl_231']?.()
-  __napiInstance.exports['__napi_register__ALIGNMENT_232']?.()
-  __napiInstance.exports['__napi_register__xxh64_233']?.()
-  __napiInstance.exports['__napi_register__xxh128_234']?.()
-  __napiInstance.exports['__napi_register__Xxh3_struct_235']?.()
-  __napiInstance.exports['__napi_register__Xxh3_impl_239']?.()
-  __napiInstance.exports['__napi_register__xxh2_plus_240']?.()
-  __napiInstance.exports['__napi_register__xxh3_xxh64_alias_241']?.()
-  __napiInstance.exports['__napi_register__xxh64_alias_242']?.()
-  __napiInstance.exports['__napi_register__get_mapping_243']?.()
-  __napiInstance.exports['__napi_register__sum_mapping_244']?.()
-  __napiInstance.exports['__napi_register__get_btree_mapping_245']?.()
-  __napiInstance.exports['__napi_register__sum_btree_mapping_246']?.()
-  __napiInstance.exports['__napi_register__get_index_mapping_247']?.()
-  __napiInstance.exports['__napi_register__sum_index_mapping_248']?.()
-  __napiInstance.exports['__napi_register__indexmap_passthrough_249']?.()
-  __napiInstance.exports['__napi_register__map_option_250']?.()
-  __napiInstance.exports['__napi_register__return_null_251']?.()
-  __napiInstance.exports['__napi_register__return_undefined_252']?.()
-  __napiInstance.exports['__napi_register__UseNullableStruct_struct_253']?.()
-  __napiInstance.exports['__napi_register__NotUseNullableStruct_struct_254']?.()
-  __napiInstance.exports['__napi_register__DefaultUseNullableStruct_struct_255']?.()
-  __napiInstance.exports['__napi_register__UseNullableClass_struct_256']?.()
-  __napiInstance.exports['__napi_register__NotUseNullableClass_struct_257']?.()
-  __napiInstance.exports['__napi_register__DefaultUseNullableClass_struct_258']?.()
-  __napiInstance.exports['__napi_register__add_259']?.()
-  __napiInstance.exports['__napi_register__fibonacci_260']?.()
-  __napiInstance.exports['__napi_register__list_obj_keys_261']?.()
-  __napiInstance.exports['__napi_register__create_obj_262']?.()
-  __napiInstance.exports['__napi_register__get_global_263']?.()
-  __napiInstance.exports['__napi_register__get_undefined_264']?.()
-  __napiInstance.exports['__napi_register__get_null_265']?.()
-  __napiInstance.exports['__napi_register__AllOptionalObject_struct_266']?.()
-  __napiInstance.exports['__napi_register__receive_all_optional_object_267']?.()
-  __napiInstance.exports['__napi_register__AliasedEnum_268']?.()
-  __napiInstance.exports['__napi_register__StructContainsAliasedEnum_struct_269']?.()
-  __napiInstance.exports['__napi_register__fn_received_aliased_270']?.()
-  __napiInstance.exports['__napi_register__StrictObject_struct_271']?.()
-  __napiInstance.exports['__napi_register__receive_strict_object_272']?.()
-  __napiInstance.exports['__napi_register__get_str_from_object_273']?.()
-  __napiInstance.exports['__napi_register__TsTypeChanged_struct_274']?.()
-  __napiInstance.exports['__napi_register__create_obj_with_property_275']?.()
-  __napiInstance.exports['__napi_register__getter_from_obj_276']?.()
-  __napiInstance.exports['__napi_register__ObjectOnlyFromJs_struct_277']?.()
-  __napiInstance.exports['__napi_register__receive_object_only_from_js_278']?.()
-  __napiInstance.exports['__napi_register__object_get_named_property_should_perform_typecheck_279']?.()
-  __napiInstance.exports['__napi_register__ObjectOnlyToJs_struct_280']?.()
-  __napiInstance.exports['__napi_register__return_object_only_to_js_281']?.()
-  __napiInstance.exports['__napi_register__TupleObject_struct_282']?.()
-  __napiInstance.exports['__napi_register__async_plus_100_283']?.()
-  __napiInstance.exports['__napi_register__call_then_on_promise_284']?.()
-  __napiInstance.exports['__napi_register__call_catch_on_promise_285']?.()
-  __napiInstance.exports['__napi_register__call_finally_on_promise_286']?.()
-  __napiInstance.exports['__napi_register__JsRepo_struct_287']?.()
-  __napiInstance.exports['__napi_register__JsRepo_impl_290']?.()
-  __napiInstance.exports['__napi_register__JsRemote_struct_291']?.()
-  __napiInstance.exports['__napi_register__JsRemote_impl_294']?.()
-  __napiInstance.exports['__napi_register__CSSRuleList_struct_295']?.()
-  __napiInstance.exports['__napi_register__CSSRuleList_impl_299']?.()
-  __napiInstance.exports['__napi_register__CSSStyleSheet_struct_300']?.()
-  __napiInstance.exports['__napi_register__AnotherCSSStyleSheet_struct_301']?.()
-  __napiInstance.exports['__napi_register__AnotherCSSStyleSheet_impl_303']?.()
-  __napiInstance.exports['__napi_register__CSSStyleSheet_impl_307']?.()
-  __napiInstance.exports['__napi_register__PackageJson_struct_308']?.()
-  __napiInstance.exports['__napi_register__read_package_json_309']?.()
-  __napiInstance.exports['__napi_register__get_package_json_name_310']?.()
-  __napiInstance.exports['__napi_register__test_serde_roundtrip_311']?.()
-  __napiInstance.exports['__napi_register__test_serde_big_number_precision_312']?.()
-  __napiInstance.exports['__napi_register__test_serde_buffer_bytes_313']?.()
-  __napiInstance.exports['__napi_register__return_from_shared_crate_314']?.()
-  __napiInstance.exports['__napi_register__contains_315']?.()
-  __napiInstance.exports['__napi_register__concat_str_316']?.()
-  __napiInstance.exports['__napi_register__concat_utf16_317']?.()
-  __napiInstance.exports['__napi_register__concat_latin1_318']?.()
-  __napiInstance.exports['__napi_register__roundtrip_str_319']?.()
-  __napiInstance.exports['__napi_register__set_symbol_in_obj_320']?.()
-  __napiInstance.exports['__napi_register__create_symbol_321']?.()
-  __napiInstance.exports['__napi_register__create_symbol_for_322']?.()
-  __napiInstance.exports['__napi_register__DelaySum_impl_323']?.()
-  __napiInstance.exports['__napi_register__without_abort_controller_324']?.()
-  __napiInstance.exports['__napi_register__with_abort_controller_325']?.()
-  __napiInstance.exports['__napi_register__AsyncTaskVoidReturn_impl_326']?.()
-  __napiInstance.exports['__napi_register__async_task_void_return_327']?.()
-  __napiInstance.exports['__napi_register__AsyncTaskOptionalReturn_impl_328']?.()
-  __napiInstance.exports['__napi_register__async_task_optional_return_329']?.()
-  __napiInstance.exports['__napi_register__AsyncTaskReadFile_impl_330']?.()
-  __napiInstance.exports['__napi_register__async_task_read_file_331']?.()
-  __napiInstance.exports['__napi_register__call_threadsafe_function_332']?.()
-  __napiInstance.exports['__napi_register__call_long_threadsafe_function_333']?.()
-  __napiInstance.exports['__napi_register__threadsafe_function_throw_error_334']?.()
-  __napiInstance.exports['__napi_register__threadsafe_function_fatal_mode_335']?.()
-  __napiInstance.exports['__napi_register__threadsafe_function_fatal_mode_error_336']?.()
-  __napiInstance.exports['__napi_register__threadsafe_function_closure_capture_337']?.()
-  __napiInstance.exports['__napi_register__tsfn_call_with_callback_338']?.()
-  __napiInstance.exports['__napi_register__tsfn_async_call_339']?.()
-  __napiInstance.exports['__napi_register__accept_threadsafe_function_340']?.()
-  __napiInstance.exports['__napi_register__accept_threadsafe_function_fatal_341']?.()
-  __napiInstance.exports['__napi_register__accept_threadsafe_function_tuple_args_342']?.()
-  __napiInstance.exports['__napi_register__tsfn_return_promise_343']?.()
-  __napiInstance.exports['__napi_register__tsfn_return_promise_timeout_344']?.()
-  __napiInstance.exports['__napi_register__tsfn_throw_from_js_345']?.()
-  __napiInstance.exports['__napi_register__spawn_thread_in_thread_346']?.()
-  __napiInstance.exports['__napi_register__Pet_struct_347']?.()
-  __napiInstance.exports['__napi_register__tsfn_in_either_348']?.()
-  __napiInstance.exports['__napi_register__get_buffer_349']?.()
-  __napiInstance.exports['__napi_register__get_buffer_slice_350']?.()
-  __napiInstance.exports['__napi_register__append_buffer_351']?.()
-  __napiInstance.exports['__napi_register__get_empty_buffer_352']?.()
-  __napiInstance.exports['__napi_register__create_external_buffer_slice_353']?.()
-  __napiInstance.exports['__napi_register__create_buffer_slice_from_copied_data_354']?.()
-  __napiInstance.exports['__napi_register__get_empty_typed_array_355']?.()
-  __napiInstance.exports['__napi_register__convert_u32_array_356']?.()
-  __napiInstance.exports['__napi_register__create_external_typed_array_357']?.()
-  __napiInstance.exports['__napi_register__mutate_typed_array_358']?.()
-  __napiInstance.exports['__napi_register__deref_uint8_array_359']?.()
-  __napiInstance.exports['__napi_register__buffer_pass_through_360']?.()
-  __napiInstance.exports['__napi_register__array_buffer_pass_through_361']?.()
-  __napiInstance.exports['__napi_register__accept_slice_362']?.()
-  __napiInstance.exports['__napi_register__accept_arraybuffer_363']?.()
-  __napiInstance.exports['__napi_register__create_arraybuffer_364']?.()
-  __napiInstance.exports['__napi_register__u8_array_to_array_365']?.()
-  __napiInstance.exports['__napi_register__i8_array_to_array_366']?.()
-  __napiInstance.exports['__napi_register__u16_array_to_array_367']?.()
-  __napiInstance.exports['__napi_register__i16_array_to_array_368']?.()
-  __napiInstance.exports['__napi_register__u32_array_to_array_369']?.()
-  __napiInstance.exports['__napi_register__i32_array_to_array_370']?.()
-  __napiInstance.exports['__napi_register__f32_array_to_array_371']?.()
-  __napiInstance.exports['__napi_register__f64_array_to_array_372']?.()
-  __napiInstance.exports['__napi_register__u64_array_to_array_373']?.()
-  __napiInstance.exports['__napi_register__i64_array_to_array_374']?.()
-  __napiInstance.exports['__napi_register__accept_uint8_clamped_slice_375']?.()
-  __napiInstance.exports['__napi_register__accept_uint8_clamped_slice_and_buffer_slice_376']?.()
-  __napiInstance.exports['__napi_register__AsyncBuffer_impl_377']?.()
-  __napiInstance.exports['__napi_register__async_reduce_buffer_378']?.()
-  __napiInstance.exports['__napi_register__async_buffer_to_array_379']?.()
-  __napiInstance.exports['__napi_register__u_init8_array_from_string_380']?.()
-  __napiInstance.exports['__napi_register__AsyncReader_impl_381']?.()
-  __napiInstance.exports['__napi_register__Reader_struct_382']?.()
-  __napiInstance.exports['__napi_register__Reader_impl_384']?.()
+  __napiInstance.exports['__napi_register__blend_colors_8']?.()
+  __napiInstance.exports['__napi_register__transpose_matrix_9']?.()
+  __napiInstance.exports['__napi_register__sum_nums_in_range_10']?.()
+  __napiInstance.exports['__napi_register__nums_bounds_11']?.()
+  __napiInstance.exports['__napi_register__slice_nums_12']?.()
+  __napiInstance.exports['__napi_register__read_file_async_13']?.()
+  __napiInstance.exports['__napi_register__async_multi_two_14']?.()
+  __napiInstance.exports['__napi_register__panic_in_async_15']?.()
+  __napiInstance.exports['__napi_register__within_async_runtime_if_available_16']?.()
+  __napiInstance.exports['__napi_register__fibonacci_blocking_17']?.()
+  __napiInstance.exports['__napi_register__parse_number_blocking_18']?.()
+  __napiInstance.exports['__napi_register__query_pooled_19']?.()
+  __napiInstance.exports['__napi_register__ConnectionPoolStatus_struct_20']?.()
+  __napiInstance.exports['__napi_register__connection_pool_status_21']?.()
+  __napiInstance.exports['__napi_register__Ticker_struct_22']?.()
+  __napiInstance.exports['__napi_register__Ticker_impl_27']?.()
+  __napiInstance.exports['__napi_register__dropped_delays_28']?.()
+  __napiInstance.exports['__napi_register__count_up_async_29']?.()
+  __napiInstance.exports['__napi_register__countdown_async_30']?.()
+  __napiInstance.exports['__napi_register__fail_async_after_31']?.()
+  __napiInstance.exports['__napi_register__Vertex_struct_32']?.()
+  __napiInstance.exports['__napi_register__Fill_33']?.()
+  __napiInstance.exports['__napi_register__Shape_struct_34']?.()
+  __napiInstance.exports['__napi_register__Style_struct_35']?.()
+  __napiInstance.exports['__napi_register__shape_area_36']?.()
+  __napiInstance.exports['__napi_register__shape_center_37']?.()
+  __napiInstance.exports['__napi_register__outline_style_38']?.()
+  __napiInstance.exports['__napi_register__is_filled_39']?.()
+  __napiInstance.exports['__napi_register__bigint_add_40']?.()
+  __napiInstance.exports['__napi_register__create_big_int_41']?.()
+  __napiInstance.exports['__napi_register__create_big_int_i64_42']?.()
+  __napiInstance.exports['__napi_register__bigint_get_u64_as_string_43']?.()
+  __napiInstance.exports['__napi_register__bigint_from_i64_44']?.()
+  __napiInstance.exports['__napi_register__bigint_from_i128_45']?.()
+  __napiInstance.exports['__napi_register__bigint_checked_add_46']?.()
+  __napiInstance.exports['__napi_register__bigint_i128_to_string_47']?.()
+  __napiInstance.exports['__napi_register__bigint_factorial_48']?.()
+  __napiInstance.exports['__napi_register__bigint_negate_49']?.()
+  __napiInstance.exports['__napi_register__bigint_bit_length_50']?.()
+  __napiInstance.exports['__napi_register__create_blob_from_chunks_51']?.()
+  __napiInstance.exports['__napi_register__create_file_52']?.()
+  __napiInstance.exports['__napi_register__get_blob_size_53']?.()
+  __napiInstance.exports['__napi_register__get_file_name_54']?.()
+  __napiInstance.exports['__napi_register__read_blob_text_55']?.()
+  __napiInstance.exports['__napi_register__read_blob_chunk_sizes_56']?.()
+  __napiInstance.exports['__napi_register__read_first_blob_chunk_57']?.()
+  __napiInstance.exports['__napi_register__get_cwd_58']?.()
+  __napiInstance.exports['__napi_register__option_end_59']?.()
+  __napiInstance.exports['__napi_register__option_start_60']?.()
+  __napiInstance.exports['__napi_register__option_start_end_61']?.()
+  __napiInstance.exports['__napi_register__option_only_62']?.()
+  __napiInstance.exports['__napi_register__read_file_63']?.()
+  __napiInstance.exports['__napi_register__callback_return_promise_64']?.()
+  __napiInstance.exports['__napi_register__callback_return_promise_and_spawn_65']?.()
+  __napiInstance.exports['__napi_register__capture_error_in_callback_66']?.()
+  __napiInstance.exports['__napi_register__NativeEmitter_struct_67']?.()
+  __napiInstance.exports['__napi_register__NativeEmitter_impl_71']?.()
+  __napiInstance.exports['__napi_register__Animal_struct_72']?.()
+  __napiInstance.exports['__napi_register__Animal_impl_84']?.()
+  __napiInstance.exports['__napi_register__Dog_struct_85']?.()
+  __napiInstance.exports['__napi_register__Bird_struct_86']?.()
+  __napiInstance.exports['__napi_register__Bird_impl_91']?.()
+  __napiInstance.exports['__napi_register__Blake2bHasher_struct_92']?.()
+  __napiInstance.exports['__napi_register__Blake2bHasher_impl_94']?.()
+  __napiInstance.exports['__napi_register__Blake2bHasher_impl_96']?.()
+  __napiInstance.exports['__napi_register__Blake2bKey_struct_97']?.()
+  __napiInstance.exports['__napi_register__Context_struct_98']?.()
+  __napiInstance.exports['__napi_register__Context_impl_103']?.()
+  __napiInstance.exports['__napi_register__AnimalWithDefaultConstructor_struct_104']?.()
+  __napiInstance.exports['__napi_register__NinjaTurtle_struct_105']?.()
+  __napiInstance.exports['__napi_register__NinjaTurtle_impl_112']?.()
+  __napiInstance.exports['__napi_register__JsAssets_struct_113']?.()
+  __napiInstance.exports['__napi_register__JsAssets_impl_116']?.()
+  __napiInstance.exports['__napi_register__JsAsset_struct_117']?.()
+  __napiInstance.exports['__napi_register__JsAsset_impl_120']?.()
+  __napiInstance.exports['__napi_register__Optional_struct_121']?.()
+  __napiInstance.exports['__napi_register__Optional_impl_126']?.()
+  __napiInstance.exports['__napi_register__ObjectFieldClassInstance_struct_127']?.()
+  __napiInstance.exports['__napi_register__create_object_with_class_field_128']?.()
+  __napiInstance.exports['__napi_register__receive_object_with_class_field_129']?.()
+  __napiInstance.exports['__napi_register__NotWritableClass_struct_130']?.()
+  __napiInstance.exports['__napi_register__NotWritableClass_impl_132']?.()
+  __napiInstance.exports['__napi_register__CustomFinalize_struct_133']?.()
+  __napiInstance.exports['__napi_register__CustomFinalize_impl_135']?.()
+  __napiInstance.exports['__napi_register__Width_struct_136']?.()
+  __napiInstance.exports['__napi_register__plus_one_137']?.()
+  __napiInstance.exports['__napi_register__GetterSetterWithClosures_struct_138']?.()
+  __napiInstance.exports['__napi_register__GetterSetterWithClosures_impl_140']?.()
+  __napiInstance.exports['__napi_register__CatchOnConstructor_struct_141']?.()
+  __napiInstance.exports['__napi_register__CatchOnConstructor_impl_143']?.()
+  __napiInstance.exports['__napi_register__CatchOnConstructor2_struct_144']?.()
+  __napiInstance.exports['__napi_register__CatchOnConstructor2_impl_146']?.()
+  __napiInstance.exports['__napi_register__ClassWithLifetime_struct_147']?.()
+  __napiInstance.exports['__napi_register__ClassWithLifetime_impl_150']?.()
+  __napiInstance.exports['__napi_register__ObservedModel_struct_151']?.()
+  __napiInstance.exports['__napi_register__ObservedModel_impl_156']?.()
+  __napiInstance.exports['__napi_register__Document_struct_157']?.()
+  __napiInstance.exports['__napi_register__Document_impl_161']?.()
+  __napiInstance.exports['__napi_register__ClassWithFactory_struct_162']?.()
+  __napiInstance.exports['__napi_register__ClassWithFactory_impl_167']?.()
+  __napiInstance.exports['__napi_register__Selector_struct_168']?.()
+  __napiInstance.exports['__napi_register__date_to_number_169']?.()
+  __napiInstance.exports['__napi_register__chrono_utc_date_to_millis_170']?.()
+  __napiInstance.exports['__napi_register__chrono_local_date_to_millis_171']?.()
+  __napiInstance.exports['__napi_register__chrono_date_with_timezone_to_millis_172']?.()
+  __napiInstance.exports['__napi_register__chrono_date_add_1_minute_173']?.()
+  __napiInstance.exports['__napi_register__UtcDates_struct_174']?.()
+  __napiInstance.exports['__napi_register__LocalDates_struct_175']?.()
+  __napiInstance.exports['__napi_register__DatesWithTimeZone_struct_176']?.()
+  __napiInstance.exports['__napi_register__chrono_native_date_time_177']?.()
+  __napiInstance.exports['__napi_register__chrono_native_date_time_return_178']?.()
+  __napiInstance.exports['__napi_register__chrono_utc_date_return_179']?.()
+  __napiInstance.exports['__napi_register__chrono_local_date_return_180']?.()
+  __napiInstance.exports['__napi_register__chrono_date_with_timezone_return_181']?.()
+  __napiInstance.exports['__napi_register__chrono_date_fixture_return1_182']?.()
+  __napiInstance.exports['__napi_register__chrono_date_fixture_return2_183']?.()
+  __napiInstance.exports['__napi_register__date_from_system_time_184']?.()
+  __napiInstance.exports['__napi_register__date_to_system_time_secs_185']?.()
+  __napiInstance.exports['__napi_register__date_from_chrono_186']?.()
+  __napiInstance.exports['__napi_register__date_to_chrono_187']?.()
+  __napiInstance.exports['__napi_register__either_string_or_number_188']?.()
+  __napiInstance.exports['__napi_register__return_either_189']?.()
+  __napiInstance.exports['__napi_register__either3_190']?.()
+  __napiInstance.exports['__napi_register__Obj_struct_191']?.()
+  __napiInstance.exports['__napi_register__either4_192']?.()
+  __napiInstance.exports['__napi_register__JsClassForEither_struct_193']?.()
+  __napiInstance.exports['__napi_register__JsClassForEither_impl_195']?.()
+  __napiInstance.exports['__napi_register__AnotherClassForEither_struct_196']?.()
+  __napiInstance.exports['__napi_register__AnotherClassForEither_impl_198']?.()
+  __napiInstance.exports['__napi_register__receive_class_or_number_199']?.()
+  __napiInstance.exports['__napi_register__receive_mut_class_or_number_200']?.()
+  __napiInstance.exports['__napi_register__receive_different_class_201']?.()
+  __napiInstance.exports['__napi_register__return_either_class_202']?.()
+  __napiInstance.exports['__napi_register__either_from_option_203']?.()
+  __napiInstance.exports['__napi_register__A_struct_204']?.()
+  __napiInstance.exports['__napi_register__B_struct_205']?.()
+  __napiInstance.exports['__napi_register__C_struct_206']?.()
+  __napiInstance.exports['__napi_register__either_from_objects_207']?.()
+  __napiInstance.exports['__napi_register__either_bool_or_function_208']?.()
+  __napiInstance.exports['__napi_register__promise_in_either_209']?.()
+  __napiInstance.exports['__napi_register__either_bool_or_tuple_210']?.()
+  __napiInstance.exports['__napi_register__Kind_211']?.()
+  __napiInstance.exports['__napi_register__Empty_212']?.()
+  __napiInstance.exports['__napi_register__Status_213']?.()
+  __napiInstance.exports['__napi_register__StringEnum_214']?.()
+  __napiInstance.exports['__napi_register__CustomNumEnum_215']?.()
+  __napiInstance.exports['__napi_register__LogLevel_216']?.()
+  __napiInstance.exports['__napi_register__Transport_217']?.()
+  __napiInstance.exports['__napi_register__enum_to_i32_218']?.()
+  __napiInstance.exports['__napi_register__SkippedEnums_219']?.()
+  __napiInstance.exports['__napi_register__CustomStringEnum_220']?.()
+  __napiInstance.exports['__napi_register__StructuredKind_struct_221']?.()
+  __napiInstance.exports['__napi_register__validate_structured_enum_222']?.()
+  __napiInstance.exports['__napi_register__run_script_223']?.()
+  __napiInstance.exports['__napi_register__get_module_file_name_224']?.()
+  __napiInstance.exports['__napi_register__is_napi_function_available_225']?.()
+  __napiInstance.exports['__napi_register__throw_syntax_error_226']?.()
+  __napiInstance.exports['__napi_register__throw_type_error_227']?.()
+  __napiInstance.exports['__napi_register__throw_custom_error_228']?.()
+  __napiInstance.exports['__napi_register__rewrap_string_229']?.()
+  __napiInstance.exports['__napi_register__create_labels_230']?.()
+  __napiInstance.exports['__napi_register__random_bytes_231']?.()
+  __napiInstance.exports['__napi_register__set_random_seed_232']?.()
+  __napiInstance.exports['__napi_register__call_on_idle_233']?.()
+  __napiInstance.exports['__napi_register__send_from_thread_234']?.()
+  __napiInstance.exports['__napi_register__sum_promises_locally_235']?.()
+  __napiInstance.exports['__napi_register__chain_async_steps_236']?.()
+  __napiInstance.exports['__napi_register__snapshot_config_237']?.()
+  __napiInstance.exports['__napi_register__schedule_follow_ups_238']?.()
+  __napiInstance.exports['__napi_register__count_request_239']?.()
+  __napiInstance.exports['__napi_register__set_app_name_240']?.()
+  __napiInstance.exports['__napi_register__get_app_name_241']?.()
+  __napiInstance.exports['__napi_register__spin_until_cancelled_242']?.()
+  __napiInstance.exports['__napi_register__loose_sum_243']?.()
+  __napiInstance.exports['__napi_register__loose_describe_244']?.()
+  __napiInstance.exports['__napi_register__MemoryUsageReport_struct_245']?.()
+  __napiInstance.exports['__napi_register__memory_usage_246']?.()
+  __napiInstance.exports['__napi_register__addon_external_memory_growth_247']?.()
+  __napiInstance.exports['__napi_register__with_default_options_248']?.()
+  __napiInstance.exports['__napi_register__utf8_buffer_249']?.()
+  __napiInstance.exports['__napi_register__throw_error_250']?.()
+  __napiInstance.exports['__napi_register__panic_251']?.()
+  __napiInstance.exports['__napi_register__receive_string_252']?.()
+  __napiInstance.exports['__napi_register__custom_status_code_253']?.()
+  __napiInstance.exports['__napi_register__error_message_contains_null_byte_254']?.()
+  __napiInstance.exports['__napi_register__collect_thrown_errors_255']?.()
+  __napiInstance.exports['__napi_register__create_typed_error_256']?.()
+  __napiInstance.exports['__napi_register__throw_async_error_257']?.()
+  __napiInstance.exports['__napi_register__CustomStruct_struct_258']?.()
+  __napiInstance.exports['__napi_register__CustomStruct_impl_261']?.()
+  __napiInstance.exports['__napi_register__get_uint32_unchecked_262']?.()
+  __napiInstance.exports['__napi_register__NapiCallOfError_struct_263']?.()
+  __napiInstance.exports['__napi_register__napi_call_of_error_264']?.()
+  __napiInstance.exports['__napi_register__ValidationError_struct_265']?.()
+  __napiInstance.exports['__napi_register__QuotaExceededError_struct_266']?.()
+  __napiInstance.exports['__napi_register__validate_username_267']?.()
+  __napiInstance.exports['__napi_register__reserve_quota_268']?.()
+  __napiInstance.exports['__napi_register__create_external_269']?.()
+  __napiInstance.exports['__napi_register__create_external_string_270']?.()
+  __napiInstance.exports['__napi_register__get_external_271']?.()
+  __napiInstance.exports['__napi_register__mutate_external_272']?.()
+  __napiInstance.exports['__napi_register__create_optional_external_273']?.()
+  __napiInstance.exports['__napi_register__get_optional_external_274']?.()
+  __napiInstance.exports['__napi_register__mutate_optional_external_275']?.()
+  __napiInstance.exports['__napi_register__open_database_276']?.()
+  __napiInstance.exports['__napi_register__database_name_277']?.()
+  __napiInstance.exports['__napi_register__device_allocations_278']?.()
+  __napiInstance.exports['__napi_register__DeviceReadback_struct_279']?.()
+  __napiInstance.exports['__napi_register__DeviceReadback_impl_284']?.()
+  __napiInstance.exports['__napi_register__echo_request_285']?.()
+  __napiInstance.exports['__napi_register__read_request_body_286']?.()
+  __napiInstance.exports['__napi_register__create_streaming_response_287']?.()
+  __napiInstance.exports['__napi_register__get_response_status_288']?.()
+  __napiInstance.exports['__napi_register__validate_array_289']?.()
+  __napiInstance.exports['__napi_register__validate_buffer_290']?.()
+  __napiInstance.exports['__napi_register__validate_typed_array_291']?.()
+  __napiInstance.exports['__napi_register__validate_typed_array_slice_292']?.()
+  __napiInstance.exports['__napi_register__validate_uint8_clamped_slice_293']?.()
+  __napiInstance.exports['__napi_register__validate_buffer_slice_294']?.()
+  __napiInstance.exports['__napi_register__validate_bigint_295']?.()
+  __napiInstance.exports['__napi_register__validate_boolean_296']?.()
+  __napiInstance.exports['__napi_register__validate_date_297']?.()
+  __napiInstance.exports['__napi_register__validate_date_time_298']?.()
+  __napiInstance.exports['__napi_register__validate_external_299']?.()
+  __napiInstance.exports['__napi_register__validate_function_300']?.()
+  __napiInstance.exports['__napi_register__validate_hash_map_301']?.()
+  __napiInstance.exports['__napi_register__validate_null_302']?.()
+  __napiInstance.exports['__napi_register__validate_undefined_303']?.()
+  __napiInstance.exports['__napi_register__validate_number_304']?.()
+  __napiInstance.exports['__napi_register__validate_promise_305']?.()
+  __napiInstance.exports['__napi_register__validate_string_306']?.()
+  __napiInstance.exports['__napi_register__validate_symbol_307']?.()
+  __napiInstance.exports['__napi_register__validate_optional_308']?.()
+  __napiInstance.exports['__napi_register__KindInValidate_309']?.()
+  __napiInstance.exports['__napi_register__validate_enum_310']?.()
+  __napiInstance.exports['__napi_register__StatusInValidate_311']?.()
+  __napiInstance.exports['__napi_register__validate_string_enum_312']?.()
+  __napiInstance.exports['__napi_register__return_undefined_if_invalid_313']?.()
+  __napiInstance.exports['__napi_register__return_undefined_if_invalid_promise_314']?.()
+  __napiInstance.exports['__napi_register__ts_rename_315']?.()
+  __napiInstance.exports['__napi_register__override_individual_arg_on_function_316']?.()
+  __napiInstance.exports['__napi_register__override_individual_arg_on_function_with_cb_arg_317']?.()
+  __napiInstance.exports['__napi_register__call0_318']?.()
+  __napiInstance.exports['__napi_register__call1_319']?.()
+  __napiInstance.exports['__napi_register__call2_320']?.()
+  __napiInstance.exports['__napi_register__apply0_321']?.()
+  __napiInstance.exports['__napi_register__apply1_322']?.()
+  __napiInstance.exports['__napi_register__call_function_323']?.()
+  __napiInstance.exports['__napi_register__call_function_with_arg_324']?.()
+  __napiInstance.exports['__napi_register__create_reference_on_function_325']?.()
+  __napiInstance.exports['__napi_register__call_function_with_arg_and_ctx_326']?.()
+  __napiInstance.exports['__napi_register__reference_as_callback_327']?.()
+  __napiInstance.exports['__napi_register__sum_prepared_calls_328']?.()
+  __napiInstance.exports['__napi_register__build_threadsafe_function_from_function_329']?.()
+  __napiInstance.exports['__napi_register__build_threadsafe_function_from_function_callee_handle_330']?.()
+  __napiInstance.exports['__napi_register__call_function_with_many_args_331']?.()
+  __napiInstance.exports['__napi_register__create_counter_332']?.()
+  __napiInstance.exports['__napi_register__call_closure_with_many_args_333']?.()
+  __napiInstance.exports['__napi_register__Fib_struct_334']?.()
+  __napiInstance.exports['__napi_register__Fib_impl_335']?.()
+  __napiInstance.exports['__napi_register__Fib_impl_337']?.()
+  __napiInstance.exports['__napi_register__Fib2_struct_338']?.()
+  __napiInstance.exports['__napi_register__Fib2_impl_339']?.()
+  __napiInstance.exports['__napi_register__Fib2_impl_341']?.()
+  __napiInstance.exports['__napi_register__Fib3_struct_342']?.()
+  __napiInstance.exports['__napi_register__Fib3_impl_343']?.()
+  __napiInstance.exports['__napi_register__sort_by_locale_344']?.()
+  __napiInstance.exports['__napi_register__locale_equals_ignore_accents_345']?.()
+  __napiInstance.exports['__napi_register__to_locale_upper_case_346']?.()
+  __napiInstance.exports['__napi_register__LocaleInfo_struct_347']?.()
+  __napiInstance.exports['__napi_register__lookup_locale_348']?.()
+  __napiInstance.exports['__napi_register__locale_lookup_count_349']?.()
+  __napiInstance.exports['__napi_register__ALIGNMENT_350']?.()
+  __napiInstance.exports['__napi_register__xxh64_351']?.()
+  __napiInstance.exports['__napi_register__xxh128_352']?.()
+  __napiInstance.exports['__napi_register__Xxh3_struct_353']?.()
+  __napiInstance.exports['__napi_register__Xxh3_impl_359']?.()
+  __napiInstance.exports['__napi_register__WORD_SIZE_360']?.()
+  __napiInstance.exports['__napi_register__xxh2_plus_362']?.()
+  __napiInstance.exports['__napi_register__xxh3_xxh64_alias_363']?.()
+  __napiInstance.exports['__napi_register__xxh64_alias_364']?.()
+  __napiInstance.exports['__napi_register__ANSWER_365']?.()
+  __napiInstance.exports['__napi_register__answer_366']?.()
+  __napiInstance.exports['__napi_register__crate_version_367']?.()
+  __napiInstance.exports['__napi_register__get_mapping_368']?.()
+  __napiInstance.exports['__napi_register__sum_mapping_369']?.()
+  __napiInstance.exports['__napi_register__get_btree_mapping_370']?.()
+  __napiInstance.exports['__napi_register__sum_btree_mapping_371']?.()
+  __napiInstance.exports['__napi_register__get_index_mapping_372']?.()
+  __napiInstance.exports['__napi_register__sum_index_mapping_373']?.()
+  __napiInstance.exports['__napi_register__indexmap_passthrough_374']?.()
+  __napiInstance.exports['__napi_register__create_es_map_375']?.()
+  __napiInstance.exports['__napi_register__sum_es_map_376']?.()
+  __napiInstance.exports['__napi_register__es_map_to_record_377']?.()
+  __napiInstance.exports['__napi_register__count_in_es_map_378']?.()
+  __napiInstance.exports['__napi_register__map_option_379']?.()
+  __napiInstance.exports['__napi_register__return_null_380']?.()
+  __napiInstance.exports['__napi_register__return_undefined_381']?.()
+  __napiInstance.exports['__napi_register__UseNullableStruct_struct_382']?.()
+  __napiInstance.exports['__napi_register__NotUseNullableStruct_struct_383']?.()
+  __napiInstance.exports['__napi_register__DefaultUseNullableStruct_struct_384']?.()
+  __napiInstance.exports['__napi_register__UseNullableClass_struct_385']?.()
+  __napiInstance.exports['__napi_register__NotUseNullableClass_struct_386']?.()
+  __napiInstance.exports['__napi_register__DefaultUseNullableClass_struct_387']?.()
+  __napiInstance.exports['__napi_register__add_388']?.()
+  __napiInstance.exports['__napi_register__fibonacci_389']?.()
+  __napiInstance.exports['__napi_register__scale_390']?.()
+  __napiInstance.exports['__napi_register__negate_391']?.()
+  __napiInstance.exports['__napi_register__list_obj_keys_392']?.()
+  __napiInstance.exports['__napi_register__sum_obj_values_393']?.()
+  __napiInstance.exports['__napi_register__format_obj_entries_394']?.()
+  __napiInstance.exports['__napi_register__create_obj_395']?.()
+  __napiInstance.exports['__napi_register__get_global_396']?.()
+  __napiInstance.exports['__napi_register__get_undefined_397']?.()
+  __napiInstance.exports['__napi_register__get_null_398']?.()
+  __napiInstance.exports['__napi_register__AllOptionalObject_struct_399']?.()
+  __napiInstance.exports['__napi_register__receive_all_optional_object_400']?.()
+  __napiInstance.exports['__napi_register__AliasedEnum_401']?.()
+  __napiInstance.exports['__napi_register__StructContainsAliasedEnum_struct_402']?.()
+  __napiInstance.exports['__napi_register__fn_received_aliased_403']?.()
+  __napiInstance.exports['__napi_register__StrictObject_struct_404']?.()
+  __napiInstance.exports['__napi_register__receive_strict_object_405']?.()
+  __napiInstance.exports['__napi_register__get_str_from_object_406']?.()
+  __napiInstance.exports['__napi_register__TsTypeChanged_struct_407']?.()
+  __napiInstance.exports['__napi_register__create_obj_with_property_408']?.()
+  __napiInstance.exports['__napi_register__getter_from_obj_409']?.()
+  __napiInstance.exports['__napi_register__ObjectOnlyFromJs_struct_410']?.()
+  __napiInstance.exports['__napi_register__receive_object_only_from_js_411']?.()
+  __napiInstance.exports['__napi_register__object_get_named_property_should_perform_typecheck_412']?.()
+  __napiInstance.exports['__napi_register__ObjectOnlyToJs_struct_413']?.()
+  __napiInstance.exports['__napi_register__return_object_only_to_js_414']?.()
+  __napiInstance.exports['__napi_register__TupleObject_struct_415']?.()
+  __napiInstance.exports['__napi_register__tag_config_object_416']?.()
+  __napiInstance.exports['__napi_register__is_config_object_417']?.()
+  __napiInstance.exports['__napi_register__create_frozen_config_418']?.()
+  __napiInstance.exports['__napi_register__create_sealed_config_419']?.()
+  __napiInstance.exports['__napi_register__seal_object_420']?.()
+  __napiInstance.exports['__napi_register__get_integrity_level_421']?.()
+  __napiInstance.exports['__napi_register__define_temperature_422']?.()
+  __napiInstance.exports['__napi_register__to_milliseconds_from_str_424']?.()
+  __napiInstance.exports['__napi_register__to_milliseconds_with_unit_426']?.()
+  __napiInstance.exports['__napi_register__to_milliseconds_from_hrtime_428']?.()
+  __napiInstance.exports['__napi_register__describe_number_430']?.()
+  __napiInstance.exports['__napi_register__describe_unknown_432']?.()
+  __napiInstance.exports['__napi_register__EXTRA_VERSION_433']?.()
+  __napiInstance.exports['__napi_register__extra_add_434']?.()
+  __napiInstance.exports['__napi_register__ExtraCounter_struct_435']?.()
+  __napiInstance.exports['__napi_register__ExtraCounter_impl_438']?.()
+  __napiInstance.exports['__napi_register__load_extra_module_439']?.()
+  __napiInstance.exports['__napi_register__counter_vtable_440']?.()
+  __napiInstance.exports['__napi_register__plugin_add_441']?.()
+  __napiInstance.exports['__napi_register__plugin_get_442']?.()
+  __napiInstance.exports['__napi_register__async_plus_100_443']?.()
+  __napiInstance.exports['__napi_register__call_then_on_promise_444']?.()
+  __napiInstance.exports['__napi_register__call_catch_on_promise_445']?.()
+  __napiInstance.exports['__napi_register__call_finally_on_promise_446']?.()
+  __napiInstance.exports['__napi_register__sum_on_thread_447']?.()
+  __napiInstance.exports['__napi_register__promise_with_timeout_448']?.()
+  __napiInstance.exports['__napi_register__race_promises_449']?.()
+  __napiInstance.exports['__napi_register__all_promises_450']?.()
+  __napiInstance.exports['__napi_register__RecordedPoint_struct_451']?.()
+  __napiInstance.exports['__napi_register__translate_point_452']?.()
+  __napiInstance.exports['__napi_register__parse_port_453']?.()
+  __napiInstance.exports['__napi_register__start_recording_calls_454']?.()
+  __napiInstance.exports['__napi_register__start_recording_to_broken_log_455']?.()
+  __napiInstance.exports['__napi_register__take_recording_error_456']?.()
+  __napiInstance.exports['__napi_register__replay_recorded_calls_457']?.()
+  __napiInstance.exports['__napi_register__JsRepo_struct_458']?.()
+  __napiInstance.exports['__napi_register__JsRepo_impl_461']?.()
+  __napiInstance.exports['__napi_register__JsRemote_struct_462']?.()
+  __napiInstance.exports['__napi_register__JsRemote_impl_465']?.()
+  __napiInstance.exports['__napi_register__CSSRuleList_struct_466']?.()
+  __napiInstance.exports['__napi_register__CSSRuleList_impl_470']?.()
+  __napiInstance.exports['__napi_register__CSSStyleSheet_struct_471']?.()
+  __napiInstance.exports['__napi_register__AnotherCSSStyleSheet_struct_472']?.()
+  __napiInstance.exports['__napi_register__AnotherCSSStyleSheet_impl_474']?.()
+  __napiInstance.exports['__napi_register__CSSStyleSheet_impl_478']?.()
+  __napiInstance.exports['__napi_register__Row_struct_479']?.()
+  __napiInstance.exports['__napi_register__RowCursor_struct_480']?.()
+  __napiInstance.exports['__napi_register__RowCursor_impl_485']?.()
+  __napiInstance.exports['__napi_register__WeakObjectCache_struct_486']?.()
+  __napiInstance.exports['__napi_register__WeakObjectCache_impl_490']?.()
+  __napiInstance.exports['__napi_register__RegExpExecResult_struct_491']?.()
+  __napiInstance.exports['__napi_register__create_reg_exp_492']?.()
+  __napiInstance.exports['__napi_register__get_reg_exp_source_and_flags_493']?.()
+  __napiInstance.exports['__napi_register__reg_exp_test_494']?.()
+  __napiInstance.exports['__napi_register__reg_exp_exec_495']?.()
+  __napiInstance.exports['__napi_register__reg_exp_match_all_496']?.()
+  __napiInstance.exports['__napi_register__is_reg_exp_497']?.()
+  __napiInstance.exports['__napi_register__PipelineFrame_struct_498']?.()
+  __napiInstance.exports['__napi_register__FramePipeline_struct_499']?.()
+  __napiInstance.exports['__napi_register__FramePipeline_impl_505']?.()
+  __napiInstance.exports['__napi_register__CapturedFrame_struct_506']?.()
+  __napiInstance.exports['__napi_register__CaptureQueue_struct_507']?.()
+  __napiInstance.exports['__napi_register__CaptureQueue_impl_516']?.()
+  __napiInstance.exports['__napi_register__PackageJson_struct_517']?.()
+  __napiInstance.exports['__napi_register__read_package_json_518']?.()
+  __napiInstance.exports['__napi_register__get_package_json_name_519']?.()
+  __napiInstance.exports['__napi_register__test_serde_roundtrip_520']?.()
+  __napiInstance.exports['__napi_register__test_serde_big_number_precision_521']?.()
+  __napiInstance.exports['__napi_register__test_serde_buffer_bytes_522']?.()
+  __napiInstance.exports['__napi_register__GeoPoint_struct_523']?.()
+  __napiInstance.exports['__napi_register__create_es_set_524']?.()
+  __napiInstance.exports['__napi_register__es_set_to_sorted_vec_525']?.()
+  __napiInstance.exports['__napi_register__count_distinct_in_es_set_526']?.()
+  __napiInstance.exports['__napi_register__toggle_in_es_set_527']?.()
+  __napiInstance.exports['__napi_register__return_from_shared_crate_528']?.()
+  __napiInstance.exports['__napi_register__contains_529']?.()
+  __napiInstance.exports['__napi_register__concat_str_530']?.()
+  __napiInstance.exports['__napi_register__concat_utf16_531']?.()
+  __napiInstance.exports['__napi_register__concat_latin1_532']?.()
+  __napiInstance.exports['__napi_register__roundtrip_str_533']?.()
+  __napiInstance.exports['__napi_register__set_symbol_in_obj_534']?.()
+  __napiInstance.exports['__napi_register__create_symbol_535']?.()
+  __napiInstance.exports['__napi_register__create_symbol_for_536']?.()
+  __napiInstance.exports['__napi_register__set_registered_symbol_in_obj_537']?.()
+  __napiInstance.exports['__napi_register__get_to_string_tag_538']?.()
+  __napiInstance.exports['__napi_register__set_symbol_metadata_539']?.()
+  __napiInstance.exports['__napi_register__read_symbol_metadata_540']?.()
+  __napiInstance.exports['__napi_register__DelaySum_impl_541']?.()
+  __napiInstance.exports['__napi_register__without_abort_controller_542']?.()
+  __napiInstance.exports['__napi_register__with_abort_controller_543']?.()
+  __napiInstance.exports['__napi_register__AsyncTaskVoidReturn_impl_544']?.()
+  __napiInstance.exports['__napi_register__async_task_void_return_545']?.()
+  __napiInstance.exports['__napi_register__AsyncTaskOptionalReturn_impl_546']?.()
+  __napiInstance.exports['__napi_register__async_task_optional_return_547']?.()
+  __napiInstance.exports['__napi_register__AsyncTaskReadFile_impl_548']?.()
+  __napiInstance.exports['__napi_register__async_task_read_file_549']?.()
+  __napiInstance.exports['__napi_register__sum_in_thread_pool_550']?.()
+  __napiInstance.exports['__napi_register__AsyncWorkEvent_struct_551']?.()
+  __napiInstance.exports['__napi_register__AsyncWorkObserver_struct_552']?.()
+  __napiInstance.exports['__napi_register__AsyncWorkObserver_impl_556']?.()
+  __napiInstance.exports['__napi_register__call_threadsafe_function_557']?.()
+  __napiInstance.exports['__napi_register__call_long_threadsafe_function_558']?.()
+  __napiInstance.exports['__napi_register__threadsafe_function_throw_error_559']?.()
+  __napiInstance.exports['__napi_register__threadsafe_function_fatal_mode_560']?.()
+  __napiInstance.exports['__napi_register__threadsafe_function_fatal_mode_error_561']?.()
+  __napiInstance.exports['__napi_register__threadsafe_function_closure_capture_562']?.()
+  __napiInstance.exports['__napi_register__tsfn_call_with_callback_563']?.()
+  __napiInstance.exports['__napi_register__tsfn_async_call_564']?.()
+  __napiInstance.exports['__napi_register__accept_threadsafe_function_565']?.()
+  __napiInstance.exports['__napi_register__accept_threadsafe_function_fatal_566']?.()
+  __napiInstance.exports['__napi_register__accept_threadsafe_function_tuple_args_567']?.()
+  __napiInstance.exports['__napi_register__tsfn_return_promise_568']?.()
+  __napiInstance.exports['__napi_register__tsfn_return_promise_timeout_569']?.()
+  __napiInstance.exports['__napi_register__tsfn_throw_from_js_570']?.()
+  __napiInstance.exports['__napi_register__spawn_thread_in_thread_571']?.()
+  __napiInstance.exports['__napi_register__Pet_struct_572']?.()
+  __napiInstance.exports['__napi_register__tsfn_in_either_573']?.()
+  __napiInstance.exports['__napi_register__report_errors_to_callback_574']?.()
+  __napiInstance.exports['__napi_register__report_errors_to_emitter_575']?.()
+  __napiInstance.exports['__napi_register__report_uncaught_error_576']?.()
+  __napiInstance.exports['__napi_register__trace_failed_calls_577']?.()
+  __napiInstance.exports['__napi_register__create_hex_encoder_stream_578']?.()
+  __napiInstance.exports['__napi_register__create_compress_stream_579']?.()
+  __napiInstance.exports['__napi_register__create_decompress_stream_580']?.()
+  __napiInstance.exports['__napi_register__get_buffer_581']?.()
+  __napiInstance.exports['__napi_register__get_static_buffer_582']?.()
+  __napiInstance.exports['__napi_register__create_static_buffer_583']?.()
+  __napiInstance.exports['__napi_register__mutate_static_buffer_584']?.()
+  __napiInstance.exports['__napi_register__create_external_float32_array_585']?.()
+  __napiInstance.exports['__napi_register__create_external_float64_array_586']?.()
+  __napiInstance.exports['__napi_register__create_external_uint16_array_587']?.()
+  __napiInstance.exports['__napi_register__create_vec3_buffer_588']?.()
+  __napiInstance.exports['__napi_register__sum_vec3_buffer_589']?.()
+  __napiInstance.exports['__napi_register__get_buffer_slice_590']?.()
+  __napiInstance.exports['__napi_register__append_buffer_591']?.()
+  __napiInstance.exports['__napi_register__get_empty_buffer_592']?.()
+  __napiInstance.exports['__napi_register__create_external_buffer_slice_593']?.()
+  __napiInstance.exports['__napi_register__create_buffer_slice_from_copied_data_594']?.()
+  __napiInstance.exports['__napi_register__get_empty_typed_array_595']?.()
+  __napiInstance.exports['__napi_register__convert_u32_array_596']?.()
+  __napiInstance.exports['__napi_register__create_external_typed_array_597']?.()
+  __napiInstance.exports['__napi_register__mutate_typed_array_598']?.()
+  __napiInstance.exports['__napi_register__deref_uint8_array_599']?.()
+  __napiInstance.exports['__napi_register__scale_float64_array_600']?.()
+  __napiInstance.exports['__napi_register__sum_int32_array_601']?.()
+  __napiInstance.exports['__napi_register__to_uint8_clamped_array_602']?.()
+  __napiInstance.exports['__napi_register__buffer_pass_through_603']?.()
+  __napiInstance.exports['__napi_register__array_buffer_pass_through_604']?.()
+  __napiInstance.exports['__napi_register__accept_slice_605']?.()
+  __napiInstance.exports['__napi_register__accept_arraybuffer_606']?.()
+  __napiInstance.exports['__napi_register__create_arraybuffer_607']?.()
+  __napiInstance.exports['__napi_register__create_aligned_arraybuffer_608']?.()
+  __napiInstance.exports['__napi_register__view_shared_region_609']?.()
+  __napiInstance.exports['__napi_register__shared_region_views_610']?.()
+  __napiInstance.exports['__napi_register__is_arraybuffer_aligned_611']?.()
+  __napiInstance.exports['__napi_register__u8_array_to_array_612']?.()
+  __napiInstance.exports['__napi_register__i8_array_to_array_613']?.()
+  __napiInstance.exports['__napi_register__u16_array_to_array_614']?.()
+  __napiInstance.exports['__napi_register__i16_array_to_array_615']?.()
+  __napiInstance.exports['__napi_register__u32_array_to_array_616']?.()
+  __napiInstance.exports['__napi_register__i32_array_to_array_617']?.()
+  __napiInstance.exports['__napi_register__f32_array_to_array_618']?.()
+  __napiInstance.exports['__napi_register__f64_array_to_array_619']?.()
+  __napiInstance.exports['__napi_register__u64_array_to_array_620']?.()
+  __napiInstance.exports['__napi_register__i64_array_to_array_621']?.()
+  __napiInstance.exports['__napi_register__sum_i64_vec_622']?.()
+  __napiInstance.exports['__napi_register__reverse_u64_vec_623']?.()
+  __napiInstance.exports['__napi_register__offset_i64_slice_624']?.()
+  __napiInstance.exports['__napi_register__create_id_column_625']?.()
+  __napiInstance.exports['__napi_register__accept_uint8_clamped_slice_626']?.()
+  __napiInstance.exports['__napi_register__accept_uint8_clamped_slice_and_buffer_slice_627']?.()
+  __napiInstance.exports['__napi_register__AsyncBuffer_impl_628']?.()
+  __napiInstance.exports['__napi_register__async_reduce_buffer_629']?.()
+  __napiInstance.exports['__napi_register__async_buffer_to_array_630']?.()
+  __napiInstance.exports['__napi_register__u_init8_array_from_string_631']?.()
+  __napiInstance.exports['__napi_register__AsyncReader_impl_632']?.()
+  __napiInstance.exports['__napi_register__Reader_struct_633']?.()
+  __napiInstance.exports['__napi_register__Reader_impl_635']?.()
+  __napiInstance.exports['__napi_register__PacketHeader_struct_636']?.()
+  __napiInstance.exports['__napi_register__read_packet_header_637']?.()
+  __napiInstance.exports['__napi_register__write_packet_header_638']?.()
+  __napiInstance.exports['__napi_register__scale_packet_payload_639']?.()
+  __napiInstance.exports['__napi_register__split_words_640']?.()
+  __napiInstance.exports['__napi_register__WordCounter_struct_641']?.()
+  __napiInstance.exports['__napi_register__WordCounter_impl_644']?.()
 }
 module.exports.Animal = __napiModule.exports.Animal
 module.exports.AnimalWithDefaultConstructor = __napiModule.exports.AnimalWithDefaultConstructor
@@ -411,11 +618,13 @@ module.exports.Asset = __napiModule.exports.Asset
 module.exports.JsAsset = __napiModule.exports.JsAsset
 module.exports.Assets = __napiModule.exports.Assets
 module.exports.JsAssets = __napiModule.exports.JsAssets
+module.exports.AsyncWorkObserver = __napiModule.exports.AsyncWorkObserver
 module.exports.Bird = __napiModule.exports.Bird
 module.exports.Blake2BHasher = __napiModule.exports.Blake2BHasher
 module.exports.Blake2bHasher = __napiModule.exports.Blake2bHasher
 module.exports.Blake2BKey = __napiModule.exports.Blake2BKey
 module.exports.Blake2bKey = __napiModule.exports.Blake2bKey
+module.exports.CaptureQueue = __napiModule.exports.CaptureQueue
 module.exports.CatchOnConstructor = __napiModule.exports.CatchOnConstructor
 module.exports.CatchOnConstructor2 = __napiModule.exports.CatchOnConstructor2
 module.exports.ClassWithFactory = __napiModule.exports.ClassWithFactory
@@ -428,22 +637,34 @@ module.exports.CSSStyleSheet = __napiModule.exports.CSSStyleSheet
 module.exports.CustomFinalize = __napiModule.exports.CustomFinalize
 module.exports.CustomStruct = __napiModule.exports.CustomStruct
 module.exports.DefaultUseNullableClass = __napiModule.exports.DefaultUseNullableClass
+module.exports.DeviceReadback = __napiModule.exports.DeviceReadback
+module.exports.Document = __napiModule.exports.Document
 module.exports.Dog = __napiModule.exports.Dog
+module.exports.ExtraCounter = __napiModule.exports.ExtraCounter
 module.exports.Fib = __napiModule.exports.Fib
 module.exports.Fib2 = __napiModule.exports.Fib2
 module.exports.Fib3 = __napiModule.exports.Fib3
+module.exports.FramePipeline = __napiModule.exports.FramePipeline
+module.exports.GeoPoint = __napiModule.exports.GeoPoint
 module.exports.GetterSetterWithClosures = __napiModule.exports.GetterSetterWithClosures
 module.exports.JsClassForEither = __napiModule.exports.JsClassForEither
 module.exports.JsRemote = __napiModule.exports.JsRemote
 module.exports.JsRepo = __napiModule.exports.JsRepo
+module.exports.NativeEmitter = __napiModule.exports.NativeEmitter
 module.exports.NinjaTurtle = __napiModule.exports.NinjaTurtle
 module.exports.NotUseNullableClass = __napiModule.exports.NotUseNullableClass
 module.exports.NotWritableClass = __napiModule.exports.NotWritableClass
+module.exports.ObservedModel = __napiModule.exports.ObservedModel
 module.exports.Optional = __napiModule.exports.Optional
 module.exports.Reader = __napiModule.exports.Reader
+module.exports.Row = __napiModule.exports.Row
+module.exports.RowCursor = __napiModule.exports.RowCursor
 module.exports.Selector = __napiModule.exports.Selector
+module.exports.Ticker = __napiModule.exports.Ticker
 module.exports.UseNullableClass = __napiModule.exports.UseNullableClass
+module.exports.WeakObjectCache = __napiModule.exports.WeakObjectCache
 module.exports.Width = __napiModule.exports.Width
+module.exports.WordCounter = __napiModule.exports.WordCounter
 module.exports.acceptArraybuffer = __napiModule.exports.acceptArraybuffer
 module.exports.acceptSlice = __napiModule.exports.acceptSlice
 module.exports.acceptThreadsafeFunction = __napiModule.exports.acceptThreadsafeFunction
@@ -452,8 +673,12 @@ module.exports.acceptThreadsafeFunctionTupleArgs = __napiModule.exports.acceptTh
 module.exports.acceptUint8ClampedSlice = __napiModule.exports.acceptUint8ClampedSlice
 module.exports.acceptUint8ClampedSliceAndBufferSlice = __napiModule.exports.acceptUint8ClampedSliceAndBufferSlice
 module.exports.add = __napiModule.exports.add
+module.exports.addonExternalMemoryGrowth = __napiModule.exports.addonExternalMemoryGrowth
 module.exports.ALIAS = __napiModule.exports.ALIAS
 module.exports.AliasedEnum = __napiModule.exports.AliasedEnum
+module.exports.allPromises = __napiModule.exports.allPromises
+module.exports.ANSWER_42 = __napiModule.exports.ANSWER_42
+module.exports.ANSWER = __napiModule.exports.ANSWER
 module.exports.appendBuffer = __napiModule.exports.appendBuffer
 module.exports.apply0 = __napiModule.exports.apply0
 module.exports.apply1 = __napiModule.exports.apply1
@@ -466,9 +691,15 @@ module.exports.asyncTaskOptionalReturn = __napiModule.exports.asyncTaskOptionalR
 module.exports.asyncTaskReadFile = __napiModule.exports.asyncTaskReadFile
 module.exports.asyncTaskVoidReturn = __napiModule.exports.asyncTaskVoidReturn
 module.exports.bigintAdd = __napiModule.exports.bigintAdd
+module.exports.bigintBitLength = __napiModule.exports.bigintBitLength
+module.exports.bigintCheckedAdd = __napiModule.exports.bigintCheckedAdd
+module.exports.bigintFactorial = __napiModule.exports.bigintFactorial
 module.exports.bigintFromI128 = __napiModule.exports.bigintFromI128
 module.exports.bigintFromI64 = __napiModule.exports.bigintFromI64
 module.exports.bigintGetU64AsString = __napiModule.exports.bigintGetU64AsString
+module.exports.bigintI128ToString = __napiModule.exports.bigintI128ToString
+module.exports.bigintNegate = __napiModule.exports.bigintNegate
+module.exports.blendColors = __napiModule.exports.blendColors
 module.exports.bufferPassThrough = __napiModule.exports.bufferPassThrough
 module.exports.buildThreadsafeFunctionFromFunction = __napiModule.exports.buildThreadsafeFunctionFromFunction
 module.exports.buildThreadsafeFunctionFromFunctionCalleeHandle = __napiModule.exports.buildThreadsafeFunctionFromFunctionCalleeHandle
@@ -478,14 +709,18 @@ module.exports.call2 = __napiModule.exports.call2
 module.exports.callbackReturnPromise = __napiModule.exports.callbackReturnPromise
 module.exports.callbackReturnPromiseAndSpawn = __napiModule.exports.callbackReturnPromiseAndSpawn
 module.exports.callCatchOnPromise = __napiModule.exports.callCatchOnPromise
+module.exports.callClosureWithManyArgs = __napiModule.exports.callClosureWithManyArgs
 module.exports.callFinallyOnPromise = __napiModule.exports.callFinallyOnPromise
 module.exports.callFunction = __napiModule.exports.callFunction
 module.exports.callFunctionWithArg = __napiModule.exports.callFunctionWithArg
 module.exports.callFunctionWithArgAndCtx = __napiModule.exports.callFunctionWithArgAndCtx
+module.exports.callFunctionWithManyArgs = __napiModule.exports.callFunctionWithManyArgs
 module.exports.callLongThreadsafeFunction = __napiModule.exports.callLongThreadsafeFunction
+module.exports.callOnIdle = __napiModule.exports.callOnIdle
 module.exports.callThenOnPromise = __napiModule.exports.callThenOnPromise
 module.exports.callThreadsafeFunction = __napiModule.exports.callThreadsafeFunction
 module.exports.captureErrorInCallback = __napiModule.exports.captureErrorInCallback
+module.exports.chainAsyncSteps = __napiModule.exports.chainAsyncSteps
 module.exports.chronoDateAdd1Minute = __napiModule.exports.chronoDateAdd1Minute
 module.exports.chronoDateFixtureReturn1 = __napiModule.exports.chronoDateFixtureReturn1
 module.exports.chronoDateFixtureReturn2 = __napiModule.exports.chronoDateFixtureReturn2
@@ -497,32 +732,71 @@ module.exports.chronoNativeDateTime = __napiModule.exports.chronoNativeDateTime
 module.exports.chronoNativeDateTimeReturn = __napiModule.exports.chronoNativeDateTimeReturn
 module.exports.chronoUtcDateReturn = __napiModule.exports.chronoUtcDateReturn
 module.exports.chronoUtcDateToMillis = __napiModule.exports.chronoUtcDateToMillis
+module.exports.collectThrownErrors = __napiModule.exports.collectThrownErrors
 module.exports.concatLatin1 = __napiModule.exports.concatLatin1
 module.exports.concatStr = __napiModule.exports.concatStr
 module.exports.concatUtf16 = __napiModule.exports.concatUtf16
+module.exports.connectionPoolStatus = __napiModule.exports.connectionPoolStatus
 module.exports.contains = __napiModule.exports.contains
 module.exports.convertU32Array = __napiModule.exports.convertU32Array
+module.exports.countDistinctInEsSet = __napiModule.exports.countDistinctInEsSet
+module.exports.countdownAsync = __napiModule.exports.countdownAsync
+module.exports.counterVtable = __napiModule.exports.counterVtable
+module.exports.countInEsMap = __napiModule.exports.countInEsMap
+module.exports.countRequest = __napiModule.exports.countRequest
+module.exports.countUpAsync = __napiModule.exports.countUpAsync
+module.exports.createAlignedArraybuffer = __napiModule.exports.createAlignedArraybuffer
 module.exports.createArraybuffer = __napiModule.exports.createArraybuffer
 module.exports.createBigInt = __napiModule.exports.createBigInt
 module.exports.createBigIntI64 = __napiModule.exports.createBigIntI64
+module.exports.createBlobFromChunks = __napiModule.exports.createBlobFromChunks
 module.exports.createBufferSliceFromCopiedData = __napiModule.exports.createBufferSliceFromCopiedData
+module.exports.createCompressStream = __napiModule.exports.createCompressStream
+module.exports.createCounter = __napiModule.exports.createCounter
+module.exports.createDecompressStream = __napiModule.exports.createDecompressStream
+module.exports.createEsMap = __napiModule.exports.createEsMap
+module.exports.createEsSet = __napiModule.exports.createEsSet
 module.exports.createExternal = __napiModule.exports.createExternal
 module.exports.createExternalBufferSlice = __napiModule.exports.createExternalBufferSlice
+module.exports.createExternalFloat32Array = __napiModule.exports.createExternalFloat32Array
+module.exports.createExternalFloat64Array = __napiModule.exports.createExternalFloat64Array
 module.exports.createExternalString = __napiModule.exports.createExternalString
 module.exports.createExternalTypedArray = __napiModule.exports.createExternalTypedArray
+module.exports.createExternalUint16Array = __napiModule.exports.createExternalUint16Array
+module.exports.createFile = __napiModule.exports.createFile
+module.exports.createFrozenConfig = __napiModule.exports.createFrozenConfig
+module.exports.createHexEncoderStream = __napiModule.exports.createHexEncoderStream
+module.exports.createIdColumn = __napiModule.exports.createIdColumn
+module.exports.createLabels = __napiModule.exports.createLabels
 module.exports.createObj = __napiModule.exports.createObj
 module.exports.createObjectWithClassField = __napiModule.exports.createObjectWithClassField
 module.exports.createObjWithProperty = __napiModule.exports.createObjWithProperty
 module.exports.createOptionalExternal = __napiModule.exports.createOptionalExternal
 module.exports.createReferenceOnFunction = __napiModule.exports.createReferenceOnFunction
+module.exports.createRegExp = __napiModule.exports.createRegExp
+module.exports.createSealedConfig = __napiModule.exports.createSealedConfig
+module.exports.createStaticBuffer = __napiModule.exports.createStaticBuffer
+module.exports.createStreamingResponse = __napiModule.exports.createStreamingResponse
 module.exports.createSymbol = __napiModule.exports.createSymbol
 module.exports.createSymbolFor = __napiModule.exports.createSymbolFor
+module.exports.createTypedError = __napiModule.exports.createTypedError
+module.exports.createVec3Buffer = __napiModule.exports.createVec3Buffer
 module.exports.CustomNumEnum = __napiModule.exports.CustomNumEnum
 module.exports.customStatusCode = __napiModule.exports.customStatusCode
 module.exports.CustomStringEnum = __napiModule.exports.CustomStringEnum
+module.exports.databaseName = __napiModule.exports.databaseName
+module.exports.dateFromChrono = __napiModule.exports.dateFromChrono
+module.exports.dateFromSystemTime = __napiModule.exports.dateFromSystemTime
+module.exports.dateToChrono = __napiModule.exports.dateToChrono
 module.exports.dateToNumber = __napiModule.exports.dateToNumber
+module.exports.dateToSystemTimeSecs = __napiModule.exports.dateToSystemTimeSecs
 module.exports.DEFAULT_COST = __napiModule.exports.DEFAULT_COST
+module.exports.defineTemperature = __napiModule.exports.defineTemperature
 module.exports.derefUint8Array = __napiModule.exports.derefUint8Array
+module.exports.describeValue = __napiModule.exports.describeValue
+module.exports.deviceAllocations = __napiModule.exports.deviceAllocations
+module.exports.droppedDelays = __napiModule.exports.droppedDelays
+module.exports.echoRequest = __napiModule.exports.echoRequest
 module.exports.either3 = __napiModule.exports.either3
 module.exports.either4 = __napiModule.exports.either4
 module.exports.eitherBoolOrFunction = __napiModule.exports.eitherBoolOrFunction
@@ -533,10 +807,21 @@ module.exports.eitherStringOrNumber = __napiModule.exports.eitherStringOrNumber
 module.exports.Empty = __napiModule.exports.Empty
 module.exports.enumToI32 = __napiModule.exports.enumToI32
 module.exports.errorMessageContainsNullByte = __napiModule.exports.errorMessageContainsNullByte
+module.exports.esMapToRecord = __napiModule.exports.esMapToRecord
+module.exports.esSetToSortedVec = __napiModule.exports.esSetToSortedVec
+module.exports.EXTRA_VERSION = __napiModule.exports.EXTRA_VERSION
+module.exports.extraAdd = __napiModule.exports.extraAdd
 module.exports.f32ArrayToArray = __napiModule.exports.f32ArrayToArray
 module.exports.f64ArrayToArray = __napiModule.exports.f64ArrayToArray
+module.exports.failAsyncAfter = __napiModule.exports.failAsyncAfter
 module.exports.fibonacci = __napiModule.exports.fibonacci
+module.exports.fibonacciBlocking = __napiModule.exports.fibonacciBlocking
+module.exports.Fill = __napiModule.exports.Fill
 module.exports.fnReceivedAliased = __napiModule.exports.fnReceivedAliased
+module.exports.formatObjEntries = __napiModule.exports.formatObjEntries
+module.exports.getAnswer42 = __napiModule.exports.getAnswer42
+module.exports.getAppName = __napiModule.exports.getAppName
+module.exports.getBlobSize = __napiModule.exports.getBlobSize
 module.exports.getBtreeMapping = __napiModule.exports.getBtreeMapping
 module.exports.getBuffer = __napiModule.exports.getBuffer
 module.exports.getBufferSlice = __napiModule.exports.getBufferSlice
@@ -544,8 +829,10 @@ module.exports.getCwd = __napiModule.exports.getCwd
 module.exports.getEmptyBuffer = __napiModule.exports.getEmptyBuffer
 module.exports.getEmptyTypedArray = __napiModule.exports.getEmptyTypedArray
 module.exports.getExternal = __napiModule.exports.getExternal
+module.exports.getFileName = __napiModule.exports.getFileName
 module.exports.getGlobal = __napiModule.exports.getGlobal
 module.exports.getIndexMapping = __napiModule.exports.getIndexMapping
+module.exports.getIntegrityLevel = __napiModule.exports.getIntegrityLevel
 module.exports.getMapping = __napiModule.exports.getMapping
 module.exports.getModuleFileName = __napiModule.exports.getModuleFileName
 module.exports.getNestedNumArr = __napiModule.exports.getNestedNumArr
@@ -554,8 +841,13 @@ module.exports.getNumArr = __napiModule.exports.getNumArr
 module.exports.getNums = __napiModule.exports.getNums
 module.exports.getOptionalExternal = __napiModule.exports.getOptionalExternal
 module.exports.getPackageJsonName = __napiModule.exports.getPackageJsonName
+module.exports.getRegExpSourceAndFlags = __napiModule.exports.getRegExpSourceAndFlags
+module.exports.getResponseStatus = __napiModule.exports.getResponseStatus
+module.exports.getStaticBuffer = __napiModule.exports.getStaticBuffer
 module.exports.getStrFromObject = __napiModule.exports.getStrFromObject
 module.exports.getterFromObj = __napiModule.exports.getterFromObj
+module.exports.getToStringTag = __napiModule.exports.getToStringTag
+module.exports.getUint32Unchecked = __napiModule.exports.getUint32Unchecked
 module.exports.getUndefined = __napiModule.exports.getUndefined
 module.exports.getWords = __napiModule.exports.getWords
 module.exports.i16ArrayToArray = __napiModule.exports.i16ArrayToArray
@@ -563,27 +855,63 @@ module.exports.i32ArrayToArray = __napiModule.exports.i32ArrayToArray
 module.exports.i64ArrayToArray = __napiModule.exports.i64ArrayToArray
 module.exports.i8ArrayToArray = __napiModule.exports.i8ArrayToArray
 module.exports.indexmapPassthrough = __napiModule.exports.indexmapPassthrough
+module.exports.isArraybufferAligned = __napiModule.exports.isArraybufferAligned
+module.exports.isConfigObject = __napiModule.exports.isConfigObject
+module.exports.isFilled = __napiModule.exports.isFilled
+module.exports.isNapiFunctionAvailable = __napiModule.exports.isNapiFunctionAvailable
+module.exports.isRegExp = __napiModule.exports.isRegExp
 module.exports.Kind = __napiModule.exports.Kind
 module.exports.KindInValidate = __napiModule.exports.KindInValidate
 module.exports.listObjKeys = __napiModule.exports.listObjKeys
+module.exports.loadExtraModule = __napiModule.exports.loadExtraModule
+module.exports.localeEqualsIgnoreAccents = __napiModule.exports.localeEqualsIgnoreAccents
+module.exports.localeLookupCount = __napiModule.exports.localeLookupCount
+module.exports.LogLevel = __napiModule.exports.LogLevel
+module.exports.lookupLocale = __napiModule.exports.lookupLocale
+module.exports.looseDescribe = __napiModule.exports.looseDescribe
+module.exports.looseSum = __napiModule.exports.looseSum
 module.exports.mapOption = __napiModule.exports.mapOption
+module.exports.memoryUsage = __napiModule.exports.memoryUsage
 module.exports.mutateExternal = __napiModule.exports.mutateExternal
 module.exports.mutateOptionalExternal = __napiModule.exports.mutateOptionalExternal
+module.exports.mutateStaticBuffer = __napiModule.exports.mutateStaticBuffer
 module.exports.mutateTypedArray = __napiModule.exports.mutateTypedArray
+module.exports.napi_examples_version = __napiModule.exports.napi_examples_version
+module.exports.napiCallOfError = __napiModule.exports.napiCallOfError
+module.exports.negate = __napiModule.exports.negate
+module.exports.numsBounds = __napiModule.exports.numsBounds
 module.exports.objectGetNamedPropertyShouldPerformTypecheck = __napiModule.exports.objectGetNamedPropertyShouldPerformTypecheck
+module.exports.offsetI64Slice = __napiModule.exports.offsetI64Slice
+module.exports.openDatabase = __napiModule.exports.openDatabase
 module.exports.optionEnd = __napiModule.exports.optionEnd
 module.exports.optionOnly = __napiModule.exports.optionOnly
 module.exports.optionStart = __napiModule.exports.optionStart
 module.exports.optionStartEnd = __napiModule.exports.optionStartEnd
+module.exports.outlineStyle = __napiModule.exports.outlineStyle
 module.exports.overrideIndividualArgOnFunction = __napiModule.exports.overrideIndividualArgOnFunction
 module.exports.overrideIndividualArgOnFunctionWithCbArg = __napiModule.exports.overrideIndividualArgOnFunctionWithCbArg
 module.exports.panic = __napiModule.exports.panic
 module.exports.panicInAsync = __napiModule.exports.panicInAsync
+module.exports.parseNumberBlocking = __napiModule.exports.parseNumberBlocking
+module.exports.parsePort = __napiModule.exports.parsePort
+module.exports.pluginAdd = __napiModule.exports.pluginAdd
+module.exports.pluginGet = __napiModule.exports.pluginGet
 module.exports.plusOne = __napiModule.exports.plusOne
 module.exports.promiseInEither = __napiModule.exports.promiseInEither
+module.exports.promiseWithTimeout = __napiModule.exports.promiseWithTimeout
+module.exports.queryPooled = __napiModule.exports.queryPooled
+module.exports.QuotaExceededError = __napiModule.exports.QuotaExceededError
+module.exports.racePromises = __napiModule.exports.racePromises
+module.exports.randomBytes = __napiModule.exports.randomBytes
+module.exports.readBlobChunkSizes = __napiModule.exports.readBlobChunkSizes
+module.exports.readBlobText = __napiModule.exports.readBlobText
 module.exports.readFile = __napiModule.exports.readFile
 module.exports.readFileAsync = __napiModule.exports.readFileAsync
+module.exports.readFirstBlobChunk = __napiModule.exports.readFirstBlobChunk
 module.exports.readPackageJson = __napiModule.exports.readPackageJson
+module.exports.readPacketHeader = __napiModule.exports.readPacketHeader
+module.exports.readRequestBody = __napiModule.exports.readRequestBody
+module.exports.readSymbolMetadata = __napiModule.exports.readSymbolMetadata
 module.exports.receiveAllOptionalObject = __napiModule.exports.receiveAllOptionalObject
 module.exports.receiveClassOrNumber = __napiModule.exports.receiveClassOrNumber
 module.exports.receiveDifferentClass = __napiModule.exports.receiveDifferentClass
@@ -593,6 +921,14 @@ module.exports.receiveObjectWithClassField = __napiModule.exports.receiveObjectW
 module.exports.receiveStrictObject = __napiModule.exports.receiveStrictObject
 module.exports.receiveString = __napiModule.exports.receiveString
 module.exports.referenceAsCallback = __napiModule.exports.referenceAsCallback
+module.exports.regExpExec = __napiModule.exports.regExpExec
+module.exports.regExpMatchAll = __napiModule.exports.regExpMatchAll
+module.exports.regExpTest = __napiModule.exports.regExpTest
+module.exports.replayRecordedCalls = __napiModule.exports.replayRecordedCalls
+module.exports.reportErrorsToCallback = __napiModule.exports.reportErrorsToCallback
+module.exports.reportErrorsToEmitter = __napiModule.exports.reportErrorsToEmitter
+module.exports.reportUncaughtError = __napiModule.exports.reportUncaughtError
+module.exports.reserveQuota = __napiModule.exports.reserveQuota
 module.exports.returnEither = __napiModule.exports.returnEither
 module.exports.returnEitherClass = __napiModule.exports.returnEitherClass
 module.exports.returnFromSharedCrate = __napiModule.exports.returnFromSharedCrate
@@ -601,17 +937,51 @@ module.exports.returnObjectOnlyToJs = __napiModule.exports.returnObjectOnlyToJs
 module.exports.returnUndefined = __napiModule.exports.returnUndefined
 module.exports.returnUndefinedIfInvalid = __napiModule.exports.returnUndefinedIfInvalid
 module.exports.returnUndefinedIfInvalidPromise = __napiModule.exports.returnUndefinedIfInvalidPromise
+module.exports.reverseU64Vec = __napiModule.exports.reverseU64Vec
+module.exports.rewrapString = __napiModule.exports.rewrapString
 module.exports.roundtripStr = __napiModule.exports.roundtripStr
 module.exports.runScript = __napiModule.exports.runScript
+module.exports.scale = __napiModule.exports.scale
+module.exports.scaleFloat64Array = __napiModule.exports.scaleFloat64Array
+module.exports.scalePacketPayload = __napiModule.exports.scalePacketPayload
+module.exports.scheduleFollowUps = __napiModule.exports.scheduleFollowUps
+module.exports.sealObject = __napiModule.exports.sealObject
+module.exports.sendFromThread = __napiModule.exports.sendFromThread
+module.exports.setAppName = __napiModule.exports.setAppName
+module.exports.setRandomSeed = __napiModule.exports.setRandomSeed
+module.exports.setRegisteredSymbolInObj = __napiModule.exports.setRegisteredSymbolInObj
 module.exports.setSymbolInObj = __napiModule.exports.setSymbolInObj
+module.exports.setSymbolMetadata = __napiModule.exports.setSymbolMetadata
+module.exports.shapeArea = __napiModule.exports.shapeArea
+module.exports.shapeCenter = __napiModule.exports.shapeCenter
+module.exports.sharedRegionViews = __napiModule.exports.sharedRegionViews
+module.exports.sliceNums = __napiModule.exports.sliceNums
+module.exports.snapshotConfig = __napiModule.exports.snapshotConfig
+module.exports.sortByLocale = __napiModule.exports.sortByLocale
 module.exports.spawnThreadInThread = __napiModule.exports.spawnThreadInThread
+module.exports.spinUntilCancelled = __napiModule.exports.spinUntilCancelled
+module.exports.splitWords = __napiModule.exports.splitWords
+module.exports.startRecordingCalls = __napiModule.exports.startRecordingCalls
+module.exports.startRecordingToBrokenLog = __napiModule.exports.startRecordingToBrokenLog
 module.exports.Status = __napiModule.exports.Status
 module.exports.StatusInValidate = __napiModule.exports.StatusInValidate
 module.exports.StringEnum = __napiModule.exports.StringEnum
 module.exports.sumBtreeMapping = __napiModule.exports.sumBtreeMapping
+module.exports.sumEsMap = __napiModule.exports.sumEsMap
+module.exports.sumI64Vec = __napiModule.exports.sumI64Vec
 module.exports.sumIndexMapping = __napiModule.exports.sumIndexMapping
+module.exports.sumInt32Array = __napiModule.exports.sumInt32Array
+module.exports.sumInThreadPool = __napiModule.exports.sumInThreadPool
 module.exports.sumMapping = __napiModule.exports.sumMapping
 module.exports.sumNums = __napiModule.exports.sumNums
+module.exports.sumNumsInRange = __napiModule.exports.sumNumsInRange
+module.exports.sumObjValues = __napiModule.exports.sumObjValues
+module.exports.sumOnThread = __napiModule.exports.sumOnThread
+module.exports.sumPreparedCalls = __napiModule.exports.sumPreparedCalls
+module.exports.sumPromisesLocally = __napiModule.exports.sumPromisesLocally
+module.exports.sumVec3Buffer = __napiModule.exports.sumVec3Buffer
+module.exports.tagConfigObject = __napiModule.exports.tagConfigObject
+module.exports.takeRecordingError = __napiModule.exports.takeRecordingError
 module.exports.testSerdeBigNumberPrecision = __napiModule.exports.testSerdeBigNumberPrecision
 module.exports.testSerdeBufferBytes = __napiModule.exports.testSerdeBufferBytes
 module.exports.testSerdeRoundtrip = __napiModule.exports.testSerdeRoundtrip
@@ -620,9 +990,19 @@ module.exports.threadsafeFunctionFatalMode = __napiModule.exports.threadsafeFunc
 module.exports.threadsafeFunctionFatalModeError = __napiModule.exports.threadsafeFunctionFatalModeError
 module.exports.threadsafeFunctionThrowError = __napiModule.exports.threadsafeFunctionThrowError
 module.exports.throwAsyncError = __napiModule.exports.throwAsyncError
+module.exports.throwCustomError = __napiModule.exports.throwCustomError
 module.exports.throwError = __napiModule.exports.throwError
 module.exports.throwSyntaxError = __napiModule.exports.throwSyntaxError
+module.exports.throwTypeError = __napiModule.exports.throwTypeError
+module.exports.toggleInEsSet = __napiModule.exports.toggleInEsSet
 module.exports.toJsObj = __napiModule.exports.toJsObj
+module.exports.toLocaleUpperCase = __napiModule.exports.toLocaleUpperCase
+module.exports.toMilliseconds = __napiModule.exports.toMilliseconds
+module.exports.toUint8ClampedArray = __napiModule.exports.toUint8ClampedArray
+module.exports.traceFailedCalls = __napiModule.exports.traceFailedCalls
+module.exports.translatePoint = __napiModule.exports.translatePoint
+module.exports.Transport = __napiModule.exports.Transport
+module.exports.transposeMatrix = __napiModule.exports.transposeMatrix
 module.exports.tsfnAsyncCall = __napiModule.exports.tsfnAsyncCall
 module.exports.tsfnCallWithCallback = __napiModule.exports.tsfnCallWithCallback
 module.exports.tsfnInEither = __napiModule.exports.tsfnInEither
@@ -635,6 +1015,7 @@ module.exports.u32ArrayToArray = __napiModule.exports.u32ArrayToArray
 module.exports.u64ArrayToArray = __napiModule.exports.u64ArrayToArray
 module.exports.u8ArrayToArray = __napiModule.exports.u8ArrayToArray
 module.exports.uInit8ArrayFromString = __napiModule.exports.uInit8ArrayFromString
+module.exports.utf8Buffer = __napiModule.exports.utf8Buffer
 module.exports.validateArray = __napiModule.exports.validateArray
 module.exports.validateBigint = __napiModule.exports.validateBigint
 module.exports.validateBoolean = __napiModule.exports.validateBoolean
@@ -658,9 +1039,14 @@ module.exports.validateTypedArray = __napiModule.exports.validateTypedArray
 module.exports.validateTypedArraySlice = __napiModule.exports.validateTypedArraySlice
 module.exports.validateUint8ClampedSlice = __napiModule.exports.validateUint8ClampedSlice
 module.exports.validateUndefined = __napiModule.exports.validateUndefined
+module.exports.validateUsername = __napiModule.exports.validateUsername
+module.exports.ValidationError = __napiModule.exports.ValidationError
+module.exports.viewSharedRegion = __napiModule.exports.viewSharedRegion
 module.exports.withAbortController = __napiModule.exports.withAbortController
+module.exports.withDefaultOptions = __napiModule.exports.withDefaultOptions
 module.exports.withinAsyncRuntimeIfAvailable = __napiModule.exports.withinAsyncRuntimeIfAvailable
 module.exports.withoutAbortController = __napiModule.exports.withoutAbortController
+module.exports.writePacketHeader = __napiModule.exports.writePacketHeader
 module.exports.xxh64Alias = __napiModule.exports.xxh64Alias
 module.exports.xxh2 = __napiModule.exports.xxh2
 module.exports.xxh3 = __napiModule.exports.xxh3
@@ -373,11 +373,13 @@ module.exports.Asset = nativeBinding.Asset
 module.exports.JsAsset = nativeBinding.JsAsset
 module.exports.Assets = nativeBinding.Assets
 module.exports.JsAssets = nativeBinding.JsAssets
+module.exports.AsyncWorkObserver = nativeBinding.AsyncWorkObserver
 module.exports.Bird = nativeBinding.Bird
 module.exports.Blake2BHasher = nativeBinding.Blake2BHasher
 module.exports.Blake2bHasher = nativeBinding.Blake2bHasher
 module.exports.Blake2BKey = nativeBinding.Blake2BKey
 module.exports.Blake2bKey = nativeBinding.Blake2bKey
+module.exports.CaptureQueue = nativeBinding.CaptureQueue
 module.exports.CatchOnConstructor = nativeBinding.CatchOnConstructor
 module.exports.CatchOnConstructor2 = nativeBinding.CatchOnConstructor2
 module.exports.ClassWithFactory = nativeBinding.ClassWithFactory
@@ -390,22 +392,34 @@ module.exports.CSSStyleSheet = nativeBinding.CSSStyleSheet
 module.exports.CustomFinalize = nativeBinding.CustomFinalize
 module.exports.CustomStruct = nativeBinding.CustomStruct
 module.exports.DefaultUseNullableClass = nativeBinding.DefaultUseNullableClass
+module.exports.DeviceReadback = nativeBinding.DeviceReadback
+module.exports.Document = nativeBinding.Document
 module.exports.Dog = nativeBinding.Dog
+module.exports.ExtraCounter = nativeBinding.ExtraCounter
 module.exports.Fib = nativeBinding.Fib
 module.exports.Fib2 = nativeBinding.Fib2
 module.exports.Fib3 = nativeBinding.Fib3
+module.exports.FramePipeline = nativeBinding.FramePipeline
+module.exports.GeoPoint = nativeBinding.GeoPoint
 module.exports.GetterSetterWithClosures = nativeBinding.GetterSetterWithClosures
 module.exports.JsClassForEither = nativeBinding.JsClassForEither
 module.exports.JsRemote = nativeBinding.JsRemote
 module.exports.JsRepo = nativeBinding.JsRepo
+module.exports.NativeEmitter = nativeBinding.NativeEmitter
 module.exports.NinjaTurtle = nativeBinding.NinjaTurtle
 module.exports.NotUseNullableClass = nativeBinding.NotUseNullableClass
 module.exports.NotWritableClass = nativeBinding.NotWritableClass
+module.exports.ObservedModel = nativeBinding.ObservedModel
 module.exports.Optional = nativeBinding.Optional
 module.exports.Reader = nativeBinding.Reader
+module.exports.Row = nativeBinding.Row
+module.exports.RowCursor = nativeBinding.RowCursor
 module.exports.Selector = nativeBinding.Selector
+module.exports.Ticker = nativeBinding.Ticker
 module.exports.UseNullableClass = nativeBinding.UseNullableClass
+module.exports.WeakObjectCache = nativeBinding.WeakObjectCache
 module.exports.Width = nativeBinding.Width
+module.exports.WordCounter = nativeBinding.WordCounter
 module.exports.acceptArraybuffer = nativeBinding.acceptArraybuffer
 module.exports.acceptSlice = nativeBinding.acceptSlice
 module.exports.acceptThreadsafeFunction = nativeBinding.acceptThreadsafeFunction
@@ -414,8 +428,12 @@ module.exports.acceptThreadsafeFunctionTupleArgs = nativeBinding.acceptThreadsaf
 module.exports.acceptUint8ClampedSlice = nativeBinding.acceptUint8ClampedSlice
 module.exports.acceptUint8ClampedSliceAndBufferSlice = nativeBinding.acceptUint8ClampedSliceAndBufferSlice
 module.exports.add = nativeBinding.add
+module.exports.addonExternalMemoryGrowth = nativeBinding.addonExternalMemoryGrowth
 module.exports.ALIAS = nativeBinding.ALIAS
 module.exports.AliasedEnum = nativeBinding.AliasedEnum
+module.exports.allPromises = nativeBinding.allPromises
+module.exports.ANSWER_42 = nativeBinding.ANSWER_42
+module.exports.ANSWER = nativeBinding.ANSWER
 module.exports.appendBuffer = nativeBinding.appendBuffer
 module.exports.apply0 = nativeBinding.apply0
 module.exports.apply1 = nativeBinding.apply1
@@ -428,9 +446,15 @@ module.exports.asyncTaskOptionalReturn = nativeBinding.asyncTaskOptionalReturn
 module.exports.asyncTaskReadFile = nativeBinding.asyncTaskReadFile
 module.exports.asyncTaskVoidReturn = nativeBinding.asyncTaskVoidReturn
 module.exports.bigintAdd = nativeBinding.bigintAdd
+module.exports.bigintBitLength = nativeBinding.bigintBitLength
+module.exports.bigintCheckedAdd = nativeBinding.bigintCheckedAdd
+module.exports.bigintFactorial = nativeBinding.bigintFactorial
 module.exports.bigintFromI128 = nativeBinding.bigintFromI128
 module.exports.bigintFromI64 = nativeBinding.bigintFromI64
 module.exports.bigintGetU64AsString = nativeBinding.bigintGetU64AsString
+module.exports.bigintI128ToString = nativeBinding.bigintI128ToString
+module.exports.bigintNegate = nativeBinding.bigintNegate
+module.exports.blendColors = nativeBinding.blendColors
 module.exports.bufferPassThrough = nativeBinding.bufferPassThrough
 module.exports.buildThreadsafeFunctionFromFunction = nativeBinding.buildThreadsafeFunctionFromFunction
 module.exports.buildThreadsafeFunctionFromFunctionCalleeHandle = nativeBinding.buildThreadsafeFunctionFromFunctionCalleeHandle
@@ -440,14 +464,18 @@ module.exports.call2 = nativeBinding.call2
 module.exports.callbackReturnPromise = nativeBinding.callbackReturnPromise
 module.exports.callbackReturnPromiseAndSpawn = nativeBinding.callbackReturnPromiseAndSpawn
 module.exports.callCatchOnPromise = nativeBinding.callCatchOnPromise
+module.exports.callClosureWithManyArgs = nativeBinding.callClosureWithManyArgs
 module.exports.callFinallyOnPromise = nativeBinding.callFinallyOnPromise
 module.exports.callFunction = nativeBinding.callFunction
 module.exports.callFunctionWithArg = nativeBinding.callFunctionWithArg
 module.exports.callFunctionWithArgAndCtx = nativeBinding.callFunctionWithArgAndCtx
+module.exports.callFunctionWithManyArgs = nativeBinding.callFunctionWithManyArgs
 module.exports.callLongThreadsafeFunction = nativeBinding.callLongThreadsafeFunction
+module.exports.callOnIdle = nativeBinding.callOnIdle
 module.exports.callThenOnPromise = nativeBinding.callThenOnPromise
 module.exports.callThreadsafeFunction = nativeBinding.callThreadsafeFunction
 module.exports.captureErrorInCallback = nativeBinding.captureErrorInCallback
+module.exports.chainAsyncSteps = nativeBinding.chainAsyncSteps
 module.exports.chronoDateAdd1Minute = nativeBinding.chronoDateAdd1Minute
 module.exports.chronoDateFixtureReturn1 = nativeBinding.chronoDateFixtureReturn1
 module.exports.chronoDateFixtureReturn2 = nativeBinding.chronoDateFixtureReturn2
@@ -459,32 +487,71 @@ module.exports.chronoNativeDateTime = nativeBinding.chronoNativeDateTime
 module.exports.chronoNativeDateTimeReturn = nativeBinding.chronoNativeDateTimeReturn
 module.exports.chronoUtcDateReturn = nativeBinding.chronoUtcDateReturn
 module.exports.chronoUtcDateToMillis = nativeBinding.chronoUtcDateToMillis
+module.exports.collectThrownErrors = nativeBinding.collectThrownErrors
 module.exports.concatLatin1 = nativeBinding.concatLatin1
 module.exports.concatStr = nativeBinding.concatStr
 module.exports.concatUtf16 = nativeBinding.concatUtf16
+module.exports.connectionPoolStatus = nativeBinding.connectionPoolStatus
 module.exports.contains = nativeBinding.contains
 module.exports.convertU32Array = nativeBinding.convertU32Array
+module.exports.countDistinctInEsSet = nativeBinding.countDistinctInEsSet
+module.exports.countdownAsync = nativeBinding.countdownAsync
+module.exports.counterVtable = nativeBinding.counterVtable
+module.exports.countInEsMap = nativeBinding.countInEsMap
+module.exports.countRequest = nativeBinding.countRequest
+module.exports.countUpAsync = nativeBinding.countUpAsync
+module.exports.createAlignedArraybuffer = nativeBinding.createAlignedArraybuffer
 module.exports.createArraybuffer = nativeBinding.createArraybuffer
 module.exports.createBigInt = nativeBinding.createBigInt
 module.exports.createBigIntI64 = nativeBinding.createBigIntI64
+module.exports.createBlobFromChunks = nativeBinding.createBlobFromChunks
 module.exports.createBufferSliceFromCopiedData = nativeBinding.createBufferSliceFromCopiedData
+module.exports.createCompressStream = nativeBinding.createCompressStream
+module.exports.createCounter = nativeBinding.createCounter
+module.exports.createDecompressStream = nativeBinding.createDecompressStream
+module.exports.createEsMap = nativeBinding.createEsMap
+module.exports.createEsSet = nativeBinding.createEsSet
 module.exports.createExternal = nativeBinding.createExternal
 module.exports.createExternalBufferSlice = nativeBinding.createExternalBufferSlice
+module.exports.createExternalFloat32Array = nativeBinding.createExternalFloat32Array
+module.exports.createExternalFloat64Array = nativeBinding.createExternalFloat64Array
 module.exports.createExternalString = nativeBinding.createExternalString
 module.exports.createExternalTypedArray = nativeBinding.createExternalTypedArray
+module.exports.createExternalUint16Array = nativeBinding.createExternalUint16Array
+module.exports.createFile = nativeBinding.createFile
+module.exports.createFrozenConfig = nativeBinding.createFrozenConfig
+module.exports.createHexEncoderStream = nativeBinding.createHexEncoderStream
+module.exports.createIdColumn = nativeBinding.createIdColumn
+module.exports.createLabels = nativeBinding.createLabels
 module.exports.createObj = nativeBinding.createObj
 module.exports.createObjectWithClassField = nativeBinding.createObjectWithClassField
 module.exports.createObjWithProperty = nativeBinding.createObjWithProperty
 module.exports.createOptionalExternal = nativeBinding.createOptionalExternal
 module.exports.createReferenceOnFunction = nativeBinding.createReferenceOnFunction
+module.exports.createRegExp = nativeBinding.createRegExp
+module.exports.createSealedConfig = nativeBinding.createSealedConfig
+module.exports.createStaticBuffer = nativeBinding.createStaticBuffer
+module.exports.createStreamingResponse = nativeBinding.createStreamingResponse
 module.exports.createSymbol = nativeBinding.createSymbol
 module.exports.createSymbolFor = nativeBinding.createSymbolFor
+module.exports.createTypedError = nativeBinding.createTypedError
+module.exports.createVec3Buffer = nativeBinding.createVec3Buffer
 module.exports.CustomNumEnum = nativeBinding.CustomNumEnum
 module.exports.customStatusCode = nativeBinding.customStatusCode
 module.exports.CustomStringEnum = nativeBinding.CustomStringEnum
+module.exports.databaseName = nativeBinding.databaseName
+module.exports.dateFromChrono = nativeBinding.dateFromChrono
+module.exports.dateFromSystemTime = nativeBinding.dateFromSystemTime
+module.exports.dateToChrono = nativeBinding.dateToChrono
 module.exports.dateToNumber = nativeBinding.dateToNumber
+module.exports.dateToSystemTimeSecs = nativeBinding.dateToSystemTimeSecs
 module.exports.DEFAULT_COST = nativeBinding.DEFAULT_COST
+module.exports.defineTemperature = nativeBinding.defineTemperature
 module.exports.derefUint8Array = nativeBinding.derefUint8Array
+module.exports.describeValue = nativeBinding.describeValue
+module.exports.deviceAllocations = nativeBinding.deviceAllocations
+module.exports.droppedDelays = nativeBinding.droppedDelays
+module.exports.echoRequest = nativeBinding.echoRequest
 module.exports.either3 = nativeBinding.either3
 module.exports.either4 = nativeBinding.either4
 module.exports.eitherBoolOrFunction = nativeBinding.eitherBoolOrFunction
@@ -495,10 +562,21 @@ module.exports.eitherStringOrNumber = nativeBinding.eitherStringOrNumber
 module.exports.Empty = nativeBinding.Empty
 module.exports.enumToI32 = nativeBinding.enumToI32
 module.exports.errorMessageContainsNullByte = nativeBinding.errorMessageContainsNullByte
+module.exports.esMapToRecord = nativeBinding.esMapToRecord
+module.exports.esSetToSortedVec = nativeBinding.esSetToSortedVec
+module.exports.EXTRA_VERSION = nativeBinding.EXTRA_VERSION
+module.exports.extraAdd = nativeBinding.extraAdd
 module.exports.f32ArrayToArray = nativeBinding.f32ArrayToArray
 module.exports.f64ArrayToArray = nativeBinding.f64ArrayToArray
+module.exports.failAsyncAfter = nativeBinding.failAsyncAfter
 module.exports.fibonacci = nativeBinding.fibonacci
+module.exports.fibonacciBlocking = nativeBinding.fibonacciBlocking
+module.exports.Fill = nativeBinding.Fill
 module.exports.fnReceivedAliased = nativeBinding.fnReceivedAliased
+module.exports.formatObjEntries = nativeBinding.formatObjEntries
+module.exports.getAnswer42 = nativeBinding.getAnswer42
+module.exports.getAppName = nativeBinding.getAppName
+module.exports.getBlobSize = nativeBinding.getBlobSize
 module.exports.getBtreeMapping = nativeBinding.getBtreeMapping
 module.exports.getBuffer = nativeBinding.getBuffer
 module.exports.getBufferSlice = nativeBinding.getBufferSlice
@@ -506,8 +584,10 @@ module.exports.getCwd = nativeBinding.getCwd
 module.exports.getEmptyBuffer = nativeBinding.getEmptyBuffer
 module.exports.getEmptyTypedArray = nativeBinding.getEmptyTypedArray
 module.exports.getExternal = nativeBinding.getExternal
+module.exports.getFileName = nativeBinding.getFileName
 module.exports.getGlobal = nativeBinding.getGlobal
 module.exports.getIndexMapping = nativeBinding.getIndexMapping
+module.exports.getIntegrityLevel = nativeBinding.getIntegrityLevel
 module.exports.getMapping = nativeBinding.getMapping
 module.exports.getModuleFileName = nativeBinding.getModuleFileName
 module.exports.getNestedNumArr = nativeBinding.getNestedNumArr
@@ -516,8 +596,13 @@ module.exports.getNumArr = nativeBinding.getNumArr
 module.exports.getNums = nativeBinding.getNums
 module.exports.getOptionalExternal = nativeBinding.getOptionalExternal
 module.exports.getPackageJsonName = nativeBinding.getPackageJsonName
+module.exports.getRegExpSourceAndFlags = nativeBinding.getRegExpSourceAndFlags
+module.exports.getResponseStatus = nativeBinding.getResponseStatus
+module.exports.getStaticBuffer = nativeBinding.getStaticBuffer
 module.exports.getStrFromObject = nativeBinding.getStrFromObject
 module.exports.getterFromObj = nativeBinding.getterFromObj
+module.exports.getToStringTag = nativeBinding.getToStringTag
+module.exports.getUint32Unchecked = nativeBinding.getUint32Unchecked
 module.exports.getUndefined = nativeBinding.getUndefined
 module.exports.getWords = nativeBinding.getWords
 module.exports.i16ArrayToArray = nativeBinding.i16ArrayToArray
@@ -525,27 +610,63 @@ module.exports.i32ArrayToArray = nativeBinding.i32ArrayToArray
 module.exports.i64ArrayToArray = nativeBinding.i64ArrayToArray
 module.exports.i8ArrayToArray = nativeBinding.i8ArrayToArray
 module.exports.indexmapPassthrough = nativeBinding.indexmapPassthrough
+module.exports.isArraybufferAligned = nativeBinding.isArraybufferAligned
+module.exports.isConfigObject = nativeBinding.isConfigObject
+module.exports.isFilled = nativeBinding.isFilled
+module.exports.isNapiFunctionAvailable = nativeBinding.isNapiFunctionAvailable
+module.exports.isRegExp = nativeBinding.isRegExp
 module.exports.Kind = nativeBinding.Kind
 module.exports.KindInValidate = nativeBinding.KindInValidate
 module.exports.listObjKeys = nativeBinding.listObjKeys
+module.exports.loadExtraModule = nativeBinding.loadExtraModule
+module.exports.localeEqualsIgnoreAccents = nativeBinding.localeEqualsIgnoreAccents
+module.exports.localeLookupCount = nativeBinding.localeLookupCount
+module.exports.LogLevel = nativeBinding.LogLevel
+module.exports.lookupLocale = nativeBinding.lookupLocale
+module.exports.looseDescribe = nativeBinding.looseDescribe
+module.exports.looseSum = nativeBinding.looseSum
 module.exports.mapOption = nativeBinding.mapOption
+module.exports.memoryUsage = nativeBinding.memoryUsage
 module.exports.mutateExternal = nativeBinding.mutateExternal
 module.exports.mutateOptionalExternal = nativeBinding.mutateOptionalExternal
+module.exports.mutateStaticBuffer = nativeBinding.mutateStaticBuffer
 module.exports.mutateTypedArray = nativeBinding.mutateTypedArray
+module.exports.napi_examples_version = nativeBinding.napi_examples_version
+module.exports.napiCallOfError = nativeBinding.napiCallOfError
+module.exports.negate = nativeBinding.negate
+module.exports.numsBounds = nativeBinding.numsBounds
 module.exports.objectGetNamedPropertyShouldPerformTypecheck = nativeBinding.objectGetNamedPropertyShouldPerformTypecheck
+module.exports.offsetI64Slice = nativeBinding.offsetI64Slice
+module.exports.openDatabase = nativeBinding.openDatabase
 module.exports.optionEnd = nativeBinding.optionEnd
 module.exports.optionOnly = nativeBinding.optionOnly
 module.exports.optionStart = nativeBinding.optionStart
 module.exports.optionStartEnd = nativeBinding.optionStartEnd
+module.exports.outlineStyle = nativeBinding.outlineStyle
 module.exports.overrideIndividualArgOnFunction = nativeBinding.overrideIndividualArgOnFunction
 module.exports.overrideIndividualArgOnFunctionWithCbArg = nativeBinding.overrideIndividualArgOnFunctionWithCbArg
 module.exports.panic = nativeBinding.panic
 module.exports.panicInAsync = nativeBinding.panicInAsync
+module.exports.parseNumberBlocking = nativeBinding.parseNumberBlocking
+module.exports.parsePort = nativeBinding.parsePort
+module.exports.pluginAdd = nativeBinding.pluginAdd
+module.exports.pluginGet = nativeBinding.pluginGet
 module.exports.plusOne = nativeBinding.plusOne
 module.exports.promiseInEither = nativeBinding.promiseInEither
+module.exports.promiseWithTimeout = nativeBinding.promiseWithTimeout
+module.exports.queryPooled = nativeBinding.queryPooled
+module.exports.QuotaExceededError = nativeBinding.QuotaExceededError
+module.exports.racePromises = nativeBinding.racePromises
+module.exports.randomBytes = nativeBinding.randomBytes
+module.exports.readBlobChunkSizes = nativeBinding.readBlobChunkSizes
+module.exports.readBlobText = nativeBinding.readBlobText
 module.exports.readFile = nativeBinding.readFile
 module.exports.readFileAsync = nativeBinding.readFileAsync
+module.exports.readFirstBlobChunk = nativeBinding.readFirstBlobChunk
 module.exports.readPackageJson = nativeBinding.readPackageJson
+module.exports.readPacketHeader = nativeBinding.readPacketHeader
+module.exports.readRequestBody = nativeBinding.readRequestBody
+module.exports.readSymbolMetadata = nativeBinding.readSymbolMetadata
 module.exports.receiveAllOptionalObject = nativeBinding.receiveAllOptionalObject
 module.exports.receiveClassOrNumber = nativeBinding.receiveClassOrNumber
 module.exports.receiveDifferentClass = nativeBinding.receiveDifferentClass
@@ -555,6 +676,14 @@ module.exports.receiveObjectWithClassField = nativeBinding.receiveObjectWithClas
 module.exports.receiveStrictObject = nativeBinding.receiveStrictObject
 module.exports.receiveString = nativeBinding.receiveString
 module.exports.referenceAsCallback = nativeBinding.referenceAsCallback
+module.exports.regExpExec = nativeBinding.regExpExec
+module.exports.regExpMatchAll = nativeBinding.regExpMatchAll
+module.exports.regExpTest = nativeBinding.regExpTest
+module.exports.replayRecordedCalls = nativeBinding.replayRecordedCalls
+module.exports.reportErrorsToCallback = nativeBinding.reportErrorsToCallback
+module.exports.reportErrorsToEmitter = nativeBinding.reportErrorsToEmitter
+module.exports.reportUncaughtError = nativeBinding.reportUncaughtError
+module.exports.reserveQuota = nativeBinding.reserveQuota
 module.exports.returnEither = nativeBinding.returnEither
 module.exports.returnEitherClass = nativeBinding.returnEitherClass
 module.exports.returnFromSharedCrate = nativeBinding.returnFromSharedCrate
@@ -563,17 +692,51 @@ module.exports.returnObjectOnlyToJs = nativeBinding.returnObjectOnlyToJs
 module.exports.returnUndefined = nativeBinding.returnUndefined
 module.exports.returnUndefinedIfInvalid = nativeBinding.returnUndefinedIfInvalid
 module.exports.returnUndefinedIfInvalidPromise = nativeBinding.returnUndefinedIfInvalidPromise
+module.exports.reverseU64Vec = nativeBinding.reverseU64Vec
+module.exports.rewrapString = nativeBinding.rewrapString
 module.exports.roundtripStr = nativeBinding.roundtripStr
 module.exports.runScript = nativeBinding.runScript
+module.exports.scale = nativeBinding.scale
+module.exports.scaleFloat64Array = nativeBinding.scaleFloat64Array
+module.exports.scalePacketPayload = nativeBinding.scalePacketPayload
+module.exports.scheduleFollowUps = nativeBinding.scheduleFollowUps
+module.exports.sealObject = nativeBinding.sealObject
+module.exports.sendFromThread = nativeBinding.sendFromThread
+module.exports.setAppName = nativeBinding.setAppName
+module.exports.setRandomSeed = nativeBinding.setRandomSeed
+module.exports.setRegisteredSymbolInObj = nativeBinding.setRegisteredSymbolInObj
 module.exports.setSymbolInObj = nativeBinding.setSymbolInObj
+module.exports.setSymbolMetadata = nativeBinding.setSymbolMetadata
+module.exports.shapeArea = nativeBinding.shapeArea
+module.exports.shapeCenter = nativeBinding.shapeCenter
+module.exports.sharedRegionViews = nativeBinding.sharedRegionViews
+module.exports.sliceNums = nativeBinding.sliceNums
+module.exports.snapshotConfig = nativeBinding.snapshotConfig
+module.exports.sortByLocale = nativeBinding.sortByLocale
 module.exports.spawnThreadInThread = nativeBinding.spawnThreadInThread
+module.exports.spinUntilCancelled = nativeBinding.spinUntilCancelled
+module.exports.splitWords = nativeBinding.splitWords
+module.exports.startRecordingCalls = nativeBinding.startRecordingCalls
+module.exports.startRecordingToBrokenLog = nativeBinding.startRecordingToBrokenLog
 module.exports.Status = nativeBinding.Status
 module.exports.StatusInValidate = nativeBinding.StatusInValidate
 module.exports.StringEnum = nativeBinding.StringEnum
 module.exports.sumBtreeMapping = nativeBinding.sumBtreeMapping
+module.exports.sumEsMap = nativeBinding.sumEsMap
+module.exports.sumI64Vec = nativeBinding.sumI64Vec
 module.exports.sumIndexMapping = nativeBinding.sumIndexMapping
+module.exports.sumInt32Array = nativeBinding.sumInt32Array
+module.exports.sumInThreadPool = nativeBinding.sumInThreadPool
 module.exports.sumMapping = nativeBinding.sumMapping
 module.exports.sumNums = nativeBinding.sumNums
+module.exports.sumNumsInRange = nativeBinding.sumNumsInRange
+module.exports.sumObjValues = nativeBinding.sumObjValues
+module.exports.sumOnThread = nativeBinding.sumOnThread
+module.exports.sumPreparedCalls = nativeBinding.sumPreparedCalls
+module.exports.sumPromisesLocally = nativeBinding.sumPromisesLocally
+module.exports.sumVec3Buffer = nativeBinding.sumVec3Buffer
+module.exports.tagConfigObject = nativeBinding.tagConfigObject
+module.exports.takeRecordingError = nativeBinding.takeRecordingError
 module.exports.testSerdeBigNumberPrecision = nativeBinding.testSerdeBigNumberPrecision
 module.exports.testSerdeBufferBytes = nativeBinding.testSerdeBufferBytes
 module.exports.testSerdeRoundtrip = nativeBinding.testSerdeRoundtrip
@@ -582,9 +745,19 @@ module.exports.threadsafeFunctionFatalMode = nativeBinding.threadsafeFunctionFat
 module.exports.threadsafeFunctionFatalModeError = nativeBinding.threadsafeFunctionFatalModeError
 module.exports.threadsafeFunctionThrowError = nativeBinding.threadsafeFunctionThrowError
 module.exports.throwAsyncError = nativeBinding.throwAsyncError
+module.exports.throwCustomError = nativeBinding.throwCustomError
 module.exports.throwError = nativeBinding.throwError
 module.exports.throwSyntaxError = nativeBinding.throwSyntaxError
+module.exports.throwTypeError = nativeBinding.throwTypeError
+module.exports.toggleInEsSet = nativeBinding.toggleInEsSet
 module.exports.toJsObj = nativeBinding.toJsObj
+module.exports.toLocaleUpperCase = nativeBinding.toLocaleUpperCase
+module.exports.toMilliseconds = nativeBinding.toMilliseconds
+module.exports.toUint8ClampedArray = nativeBinding.toUint8ClampedArray
+module.exports.traceFailedCalls = nativeBinding.traceFailedCalls
+module.exports.translatePoint = nativeBinding.translatePoint
+module.exports.Transport = nativeBinding.Transport
+module.exports.transposeMatrix = nativeBinding.transposeMatrix
 module.exports.tsfnAsyncCall = nativeBinding.tsfnAsyncCall
 module.exports.tsfnCallWithCallback = nativeBinding.tsfnCallWithCallback
 module.exports.tsfnInEither = nativeBinding.tsfnInEither
@@ -597,6 +770,7 @@ module.exports.u32ArrayToArray = nativeBinding.u32ArrayToArray
 module.exports.u64ArrayToArray = nativeBinding.u64ArrayToArray
 module.exports.u8ArrayToArray = nativeBinding.u8ArrayToArray
 module.exports.uInit8ArrayFromString = nativeBinding.uInit8ArrayFromString
+module.exports.utf8Buffer = nativeBinding.utf8Buffer
 module.exports.validateArray = nativeBinding.validateArray
 module.exports.validateBigint = nativeBinding.validateBigint
 module.exports.validateBoolean = nativeBinding.validateBoolean
@@ -620,9 +794,14 @@ module.exports.validateTypedArray = nativeBinding.validateTypedArray
 module.exports.validateTypedArraySlice = nativeBinding.validateTypedArraySlice
 module.exports.validateUint8ClampedSlice = nativeBinding.validateUint8ClampedSlice
 module.exports.validateUndefined = nativeBinding.validateUndefined
+module.exports.validateUsername = nativeBinding.validateUsername
+module.exports.ValidationError = nativeBinding.ValidationError
+module.exports.viewSharedRegion = nativeBinding.viewSharedRegion
 module.exports.withAbortController = nativeBinding.withAbortController
+module.exports.withDefaultOptions = nativeBinding.withDefaultOptions
 module.exports.withinAsyncRuntimeIfAvailable = nativeBinding.withinAsyncRuntimeIfAvailable
 module.exports.withoutAbortController = nativeBinding.withoutAbortController
+module.exports.writePacketHeader = nativeBinding.writePacketHeader
 module.exports.xxh64Alias = nativeBinding.xxh64Alias
 module.exports.xxh2 = nativeBinding.xxh2
 module.exports.xxh3 = nativeBinding.xxh3
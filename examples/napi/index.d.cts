@@ -71,6 +71,12 @@ export declare class Assets {
 }
 export type JsAssets = Assets
 
+export declare class AsyncWorkObserver {
+  constructor()
+  takeEvents(): Array<AsyncWorkEvent>
+  stop(): boolean
+}
+
 export declare class Bird {
   name: string
   constructor(name: string)
@@ -91,6 +97,25 @@ export declare class Blake2BKey {
 }
 export type Blake2bKey = Blake2BKey
 
+/** Timestamped frames captured on another thread */
+export declare class CaptureQueue {
+  constructor(capacity: number, frameSize: number, dropOldest: boolean)
+  /** The ArrayBuffer of each slot */
+  get buffers(): Array<ArrayBuffer>
+  /**
+   * Capture `count` frames on another thread, frame `n` is filled with `n`, is `n % size + 1`
+   * bytes long and is at `n * 1000`
+   */
+  start(count: number): void
+  poll(): CapturedFrame | null
+  release(index: number): void
+  onFrame(callback: (frame: CapturedFrame, buffer: ArrayBuffer) => void): void
+  /** The frames dropped to make room for newer ones */
+  get dropped(): number
+  /** Whether the capture is over and all its frames are received */
+  get finished(): boolean
+}
+
 export declare class CatchOnConstructor {
   constructor()
 }
@@ -152,11 +177,38 @@ export declare class DefaultUseNullableClass {
   constructor(requiredNumberField: number, requiredStringField: string, optionalNumberField?: number, optionalStringField?: string)
 }
 
+/** The doubled `values`, computed into device memory and read from JavaScript without a copy */
+export declare class DeviceReadback {
+  constructor(values: Array<number>)
+  get data(): ArrayBuffer | null
+  get mapped(): boolean
+  /**
+   * Detach the data from JavaScript and release the device memory, `false` if it's released
+   * already
+   */
+  unmap(): boolean
+}
+
+export declare class Document {
+  readonly title: string
+  readonly content: Buffer
+  constructor(title: string, content: Buffer)
+  /** The content before the last `replaceContent`, without cloning the field */
+  get previousContent(): Buffer
+  replaceContent(content: Buffer): void
+}
+
 export declare class Dog {
   name: string
   constructor(name: string)
 }
 
+export declare class ExtraCounter {
+  count: number
+  constructor()
+  increment(): number
+}
+
 export declare class Fib {
   [Symbol.iterator](): Iterator<number, void, number>
   constructor()
@@ -174,6 +226,29 @@ export declare class Fib3 {
   [Symbol.iterator](): Iterator<number, void, number>
 }
 
+/** Frames produced on another thread into a fixed set of ArrayBuffers */
+export declare class FramePipeline {
+  constructor(slots: number, frameSize: number)
+  /** The ArrayBuffer of each slot */
+  get buffers(): Array<ArrayBuffer>
+  /**
+   * Produce `count` frames on another thread, frame `n` is filled with `n` and is `n % size + 1`
+   * bytes long
+   */
+  start(count: number): void
+  nextFrame(): PipelineFrame | null
+  release(index: number): void
+}
+
+export declare class GeoPoint {
+  latitude: number
+  longitude: number
+  label?: string
+  constructor(latitude: number, longitude: number, label?: string)
+  static fromJSON(json: unknown): GeoPoint
+  toJSON(): unknown
+}
+
 export declare class GetterSetterWithClosures {
   constructor()
 }
@@ -192,6 +267,13 @@ export declare class JsRepo {
   remote(): JsRemote
 }
 
+/** Calls the listener like a native event source, in the async context it was created in */
+export declare class NativeEmitter {
+  constructor(listener: (arg: number) => void)
+  emit(value: number): void
+  emitBatch(values: Array<number>): void
+}
+
 export declare class NinjaTurtle {
   name: string
   static isInstanceOf(value: unknown): boolean
@@ -216,6 +298,16 @@ export declare class NotWritableClass {
   setName(name: string): void
 }
 
+export declare class ObservedModel {
+  name: string
+  age: number
+  constructor(name: string, age: number)
+  get nickname(): string
+  set nickname(nickname: string)
+  /** The properties set from JavaScript since the last call */
+  takeChanges(): Array<string>
+}
+
 export declare class Optional {
   static optionEnd(required: string, optional?: string | undefined | null): string
   static optionStart(optional: string | undefined | null, required: string): string
@@ -229,6 +321,18 @@ export declare class Reader {
   read(): Buffer
 }
 
+export declare class Row {
+  id: number
+  name: string
+}
+
+export declare class RowCursor {
+  constructor(poolSize: number)
+  nextRow(name: string): Row
+  release(row: Row): void
+  get pooled(): number
+}
+
 export declare class Selector {
   orderBy: Array<string>
   select: Array<string>
@@ -237,6 +341,13 @@ export declare class Selector {
   constructor(orderBy: Array<string>, select: Array<string>, struct: string, where?: string)
 }
 
+export declare class Ticker {
+  constructor()
+  delay(ms: number, value: number): Promise<number>
+  get pending(): number
+  close(): Promise<undefined>
+}
+
 export declare class UseNullableClass {
   requiredNumberField: number
   requiredStringField: string
@@ -245,11 +356,28 @@ export declare class UseNullableClass {
   constructor(requiredNumberField: number, requiredStringField: string, nullableNumberField: number | null, nullableStringField: string | null)
 }
 
+/** Hold objects without keeping them alive */
+export declare class WeakObjectCache {
+  constructor()
+  set(key: string, value: object): void
+  /** The object of `key`, unless it's garbage collected */
+  get(key: string): object | null
+}
+
 export declare class Width {
   value: number
   constructor(value: number)
 }
 
+export declare class WordCounter {
+  constructor()
+  /**
+   * Count the words of `input`
+   * @experimental
+   */
+  feed(input: string): number
+}
+
 export interface A {
   foo: number
 }
@@ -270,6 +398,9 @@ export declare function acceptUint8ClampedSliceAndBufferSlice(a: Buffer, b: Uint
 
 export declare function add(a: number, b: number): number
 
+/** How much the external memory of the addon grows while `bytes` are registered */
+export declare function addonExternalMemoryGrowth(bytes: number): number
+
 export declare const enum ALIAS {
   A = 0,
   B = 1
@@ -285,6 +416,10 @@ export interface AllOptionalObject {
   age?: number
 }
 
+export declare function allPromises(inputs: Array<Promise<number>>): Promise<Array<number>>
+
+export const ANSWER_42: number
+
 export declare function appendBuffer(buf: Buffer): Buffer
 
 export declare function apply0(ctx: Animal, callback: () => void): void
@@ -307,18 +442,38 @@ export declare function asyncTaskReadFile(path: string): Promise<Buffer>
 
 export declare function asyncTaskVoidReturn(): Promise<void>
 
+export interface AsyncWorkEvent {
+  step: string
+  kind: string
+  typeName: string
+  /** The time spent running the work, for the `complete` and `error` steps */
+  runningMs?: number
+}
+
 export interface B {
   bar: number
 }
 
 export declare function bigintAdd(a: bigint, b: bigint): bigint
 
+export declare function bigintBitLength(value: bigint): number
+
+export declare function bigintCheckedAdd(a: bigint, b: bigint): bigint
+
+export declare function bigintFactorial(n: number): bigint
+
 export declare function bigintFromI128(): bigint
 
 export declare function bigintFromI64(): bigint
 
 export declare function bigintGetU64AsString(bi: bigint): string
 
+export declare function bigintI128ToString(bi: bigint): string
+
+export declare function bigintNegate(value: bigint): bigint
+
+export declare function blendColors(a: [number, number, number, number], b: [number, number, number, number]): [number, number, number, number]
+
 export declare function bufferPassThrough(buf: Buffer): Promise<Buffer>
 
 export declare function buildThreadsafeFunctionFromFunction(callback: (arg0: number, arg1: number) => number): void
@@ -341,6 +496,8 @@ export declare function callbackReturnPromiseAndSpawn(jsFunc: (arg0: string) =>
 
 export declare function callCatchOnPromise(input: Promise<number>): Promise<string>
 
+export declare function callClosureWithManyArgs(): number
+
 export declare function callFinallyOnPromise(input: Promise<number>, onFinally: () => void): Promise<number>
 
 export declare function callFunction(cb: () => number): number
@@ -349,14 +506,27 @@ export declare function callFunctionWithArg(cb: (arg0: number, arg1: number) =>
 
 export declare function callFunctionWithArgAndCtx(ctx: Animal, cb: (arg: string) => void, name: string): void
 
+export declare function callFunctionWithManyArgs(callback: (...args: number[]) => number): number
+
 export declare function callLongThreadsafeFunction(tsfn: ((err: Error | null, arg: number) => unknown)): void
 
+export declare function callOnIdle(callback: () => void): void
+
 export declare function callThenOnPromise(input: Promise<number>): Promise<string>
 
 export declare function callThreadsafeFunction(tsfn: ((err: Error | null, arg: number) => unknown)): void
 
+export interface CapturedFrame {
+  index: number
+  length: number
+  timestamp: number
+}
+
 export declare function captureErrorInCallback(cb1: () => void, cb2: (arg0: Error) => void): void
 
+/** Call `step` `steps` times with the output of the previous call, awaiting what it returns */
+export declare function chainAsyncSteps(initial: number, steps: number, step: (value: number) => PromiseLike<number> | number): Promise<number>
+
 export declare function chronoDateAdd1Minute(input: Date): Date
 
 export declare function chronoDateFixtureReturn1(): Date
@@ -379,46 +549,113 @@ export declare function chronoUtcDateReturn(): Date | null
 
 export declare function chronoUtcDateToMillis(input: Date): number
 
+/** Calls `callback` with each item, collecting what it throws instead of stopping at the first error */
+export declare function collectThrownErrors(callback: (arg: number) => void, items: Array<number>): Array<unknown>
+
 export declare function concatLatin1(s: string): string
 
 export declare function concatStr(s: string): string
 
 export declare function concatUtf16(s: string): string
 
+export declare function connectionPoolStatus(): ConnectionPoolStatus
+
+export interface ConnectionPoolStatus {
+  opened: number
+  idle: number
+  inUse: number
+}
+
 export declare function contains(source: string, target: string): boolean
 
 export declare function convertU32Array(input: Uint32Array): Array<number>
 
+export declare function countDistinctInEsSet(set: Set<unknown>): number
+
+export declare function countdownAsync(from: number): AsyncGenerator<number, void, unknown>
+
+/** The host side, exporting its shared counter to the plugins */
+export declare function counterVtable(): ExternalObject<'CounterVTable'>
+
+/** Increment the count of `key`, returns the new size of the map */
+export declare function countInEsMap(map: Map<unknown, unknown>, key: string, remove: boolean): number
+
+export declare function countRequest(): number
+
+export declare function countUpAsync(to: number): AsyncGenerator<number, void, unknown>
+
+export declare function createAlignedArraybuffer(length: number, align: number): ArrayBuffer
+
 export declare function createArraybuffer(): ArrayBuffer
 
 export declare function createBigInt(): bigint
 
 export declare function createBigIntI64(): bigint
 
+export declare function createBlobFromChunks(chunks: Array<string>, mimeType?: string | undefined | null): Blob
+
 export declare function createBufferSliceFromCopiedData(): Buffer
 
-export declare function createExternal(size: number): ExternalObject<number>
+export declare function createCompressStream(format: string, level?: number | undefined | null): import('node:stream').Transform
+
+export declare function createCounter(start: number): () => number
+
+export declare function createDecompressStream(format: string): import('node:stream').Transform
+
+export declare function createEsMap(fixture: Record<string, number>): Map<unknown, unknown>
+
+export declare function createEsSet(values: Array<string>): Set<unknown>
+
+export declare function createExternal(size: number): ExternalObject<'u32'>
 
 export declare function createExternalBufferSlice(): Buffer
 
-export declare function createExternalString(content: string): ExternalObject<string>
+export declare function createExternalFloat32Array(length: number): Float32Array
+
+export declare function createExternalFloat64Array(length: number): Float64Array
+
+export declare function createExternalString(content: string): ExternalObject<'String'>
 
 export declare function createExternalTypedArray(): Uint32Array
 
+export declare function createExternalUint16Array(length: number): Uint16Array
+
+export declare function createFile(name: string, content: string, lastModified: number): File
+
+export declare function createFrozenConfig(): { name: string }
+
+export declare function createHexEncoderStream(): import('node:stream').Transform
+
+export declare function createIdColumn(first: bigint, count: number): BigUint64Array
+
+export declare function createLabels(count: number): object
+
 export declare function createObj(): object
 
 export declare function createObjectWithClassField(): ObjectFieldClassInstance
 
 export declare function createObjWithProperty(): { value: ArrayBuffer, get getter(): number }
 
-export declare function createOptionalExternal(size?: number | undefined | null): ExternalObject<number> | null
+export declare function createOptionalExternal(size?: number | undefined | null): ExternalObject<'u32'> | null
 
 export declare function createReferenceOnFunction(cb: () => void): Promise<void>
 
+export declare function createRegExp(pattern: string, flags: string): RegExp
+
+export declare function createSealedConfig(): { name: string }
+
+export declare function createStaticBuffer(): Buffer
+
+export declare function createStreamingResponse(chunks: Array<string>): Response
+
 export declare function createSymbol(): symbol
 
 export declare function createSymbolFor(desc: string): symbol
 
+export declare function createTypedError(kind: string, message: string, code?: string | undefined | null): Error
+
+export declare function createVec3Buffer(count: number): Buffer
+
 /** You could break the step and for an new continuous value. */
 export declare const enum CustomNumEnum {
   One = 1,
@@ -439,13 +676,23 @@ export declare const enum CustomStringEnum {
   Baz = 'Baz'
 }
 
+export declare function databaseName(database: ExternalObject<'Database'>): string
+
+export declare function dateFromChrono(rfc3339: string): Date
+
+export declare function dateFromSystemTime(secs: number, nanos: number): Date
+
 export interface DatesWithTimeZone {
   start: Date
   end?: Date
 }
 
+export declare function dateToChrono(date: Date): string
+
 export declare function dateToNumber(input: Date): number
 
+export declare function dateToSystemTimeSecs(date: Date): number
+
 /** This is a const */
 export const DEFAULT_COST: number
 
@@ -456,8 +703,23 @@ export interface DefaultUseNullableStruct {
   optionalStringField?: string
 }
 
+/** Define the `celsius` and the read-only `fahrenheit` accessors on `target`, backed by Rust state */
+export declare function defineTemperature(target: object, celsius: number): void
+
 export declare function derefUint8Array(a: Uint8Array, b: Uint8ClampedArray): number
 
+/** The overloads taking as many arguments are tried in declaration order */
+export declare function describeValue(value: number): string
+
+export declare function describeValue(value: unknown): string
+
+/** The number of device allocations not released yet */
+export declare function deviceAllocations(): number
+
+export declare function droppedDelays(): number
+
+export declare function echoRequest(request: Request): Response
+
 export declare function either3(input: string | number | boolean): number
 
 export declare function either4(input: string | number | boolean | Obj): number
@@ -480,14 +742,40 @@ export declare function enumToI32(e: CustomNumEnum): number
 
 export declare function errorMessageContainsNullByte(msg: string): void
 
+export declare function esMapToRecord(map: Map<unknown, unknown>): Record<string, number>
+
+export declare function esSetToSortedVec(set: Set<unknown>): Array<number>
+
+export const EXTRA_VERSION: number
+
+export declare function extraAdd(a: number, b: number): number
+
 export declare function f32ArrayToArray(input: Float32Array): Array<number>
 
 export declare function f64ArrayToArray(input: Float64Array): Array<number>
 
+export declare function failAsyncAfter(n: number): AsyncGenerator<string, void, unknown>
+
 export declare function fibonacci(n: number): number
 
+export declare function fibonacciBlocking(n: number): Promise<number>
+
+export declare const enum Fill {
+  Solid = 'Solid',
+  Hatched = 'Hatched',
+  Outline = 'Outline'
+}
+
 export declare function fnReceivedAliased(s: AliasedStruct, e: ALIAS): void
 
+export declare function formatObjEntries(obj: object): Array<string>
+
+export declare function getAnswer42(): number
+
+export declare function getAppName(): string | null
+
+export declare function getBlobSize(blob: Blob): number
+
 export declare function getBtreeMapping(): Record<string, number>
 
 export declare function getBuffer(): Buffer
@@ -500,33 +788,49 @@ export declare function getEmptyBuffer(): Buffer
 
 export declare function getEmptyTypedArray(): Uint8Array
 
-export declare function getExternal(external: ExternalObject<number>): number
+export declare function getExternal(external: ExternalObject<'u32'>): number
+
+export declare function getFileName(file: File): string
 
 export declare function getGlobal(): typeof global
 
 export declare function getIndexMapping(): Record<string, number>
 
+export declare function getIntegrityLevel(obj: object): 'frozen' | 'sealed' | 'none'
+
 export declare function getMapping(): Record<string, number>
 
 export declare function getModuleFileName(): string
 
-export declare function getNestedNumArr(): number[][][]
+export declare function getNestedNumArr(): [[[number]], [[number]]]
 
 export declare function getNull(): null
 
-export declare function getNumArr(): number[]
+export declare function getNumArr(): [number, number]
 
 /** Gets some numbers */
 export declare function getNums(): Array<number>
 
-export declare function getOptionalExternal(external?: ExternalObject<number> | undefined | null): number | null
+export declare function getOptionalExternal(external?: ExternalObject<'u32'> | undefined | null): number | null
 
 export declare function getPackageJsonName(packageJson: PackageJson): string
 
+export declare function getRegExpSourceAndFlags(regexp: RegExp): Array<string>
+
+export declare function getResponseStatus(response: Response): number
+
+export declare function getStaticBuffer(): Buffer
+
 export declare function getStrFromObject(): void
 
 export declare function getterFromObj(): number
 
+/** `value[Symbol.toStringTag]`, like `Map` for a `Map` */
+export declare function getToStringTag(value: object): string | null
+
+/** Read `value` as a `u32` without checking its type first, the error tells which Node-API call failed */
+export declare function getUint32Unchecked(value: unknown): number
+
 export declare function getUndefined(): void
 
 export declare function getWords(): Array<string>
@@ -541,6 +845,16 @@ export declare function i8ArrayToArray(input: Int8Array): Array<number>
 
 export declare function indexmapPassthrough(fixture: Record<string, number>): Record<string, number>
 
+export declare function isArraybufferAligned(buf: ArrayBuffer, align: number): boolean
+
+export declare function isConfigObject(obj: object): boolean
+
+export declare function isFilled(style: Style): boolean
+
+export declare function isNapiFunctionAvailable(name: string): boolean
+
+export declare function isRegExp(value: unknown): boolean
+
 /** default enum values are continuos i32s start from 0 */
 export declare const enum Kind {
   /** Barks */
@@ -563,19 +877,74 @@ export declare const enum KindInValidate {
 
 export declare function listObjKeys(obj: object): Array<string>
 
+/** Call the init symbol of the `extra` module like a plugin host would, on a fresh exports object */
+export declare function loadExtraModule(): object
+
 export interface LocalDates {
   start: Date
   end?: Date
 }
 
+export declare function localeEqualsIgnoreAccents(a: string, b: string): boolean
+
+export interface LocaleInfo {
+  language: string
+  region?: string
+}
+
+export declare function localeLookupCount(): number
+
+/** Listed with `LogLevel.values()` */
+export declare enum LogLevel {
+  Debug = 10,
+  Info = 20,
+  Warn = 30
+}
+export declare namespace LogLevel {
+  function values(): Array<LogLevel>
+  function nameOf(value: unknown): string | undefined
+}
+
+/** Memoized, the calls with the same tag get the same object */
+export declare function lookupLocale(tag: string): LocaleInfo
+
+export declare function looseDescribe(value: unknown): string
+
+export declare function looseSum(values: Array<unknown>): number
+
 export declare function mapOption(val?: number | undefined | null): number | null
 
-export declare function mutateExternal(external: ExternalObject<number>, newVal: number): void
+export declare function memoryUsage(): MemoryUsageReport
+
+export interface MemoryUsageReport {
+  addonExternal: number
+  isolateExternal: number
+  rss: number
+  heapTotal: number
+  heapUsed: number
+  external: number
+  arrayBuffers: number
+}
 
-export declare function mutateOptionalExternal(external: ExternalObject<number> | undefined | null, newVal: number): void
+export declare function mutateExternal(external: ExternalObject<'u32'>, newVal: number): void
+
+export declare function mutateOptionalExternal(external: ExternalObject<'u32'> | undefined | null, newVal: number): void
+
+export declare function mutateStaticBuffer(): Buffer
 
 export declare function mutateTypedArray(input: Float32Array): void
 
+export declare function napi_examples_version(): string
+
+export declare function napiCallOfError(value: unknown): NapiCallOfError | null
+
+export interface NapiCallOfError {
+  function: string
+  message?: string
+}
+
+export declare function negate(value: number): number
+
 export interface NotUseNullableStruct {
   requiredNumberField: number
   requiredStringField: string
@@ -583,6 +952,8 @@ export interface NotUseNullableStruct {
   optionalStringField?: string
 }
 
+export declare function numsBounds(nums: Array<number>): { start: number; end: number } | null
+
 export interface Obj {
   v: string | number
 }
@@ -603,6 +974,11 @@ export interface ObjectOnlyToJs {
   dependencies: any
 }
 
+export declare function offsetI64Slice(input: BigInt64Array, offset: number): BigInt64Array
+
+/** Typed as `ExternalObject<'Database'>`, it can't be passed where another external is expected */
+export declare function openDatabase(name: string): ExternalObject<'Database'>
+
 export declare function optionEnd(callback: (arg0: string, arg1?: string | undefined | null) => void): void
 
 export declare function optionOnly(callback: (arg0?: string | undefined | null) => void): void
@@ -611,6 +987,8 @@ export declare function optionStart(callback: (arg0: string | undefined | null,
 
 export declare function optionStartEnd(callback: (arg0: string | undefined | null, arg1: string, arg2?: string | undefined | null) => void): void
 
+export declare function outlineStyle(strokeWidth: number): Style
+
 export declare function overrideIndividualArgOnFunction(notOverridden: string, f: () => string, notOverridden2: number): string
 
 export declare function overrideIndividualArgOnFunctionWithCbArg(callback: (town: string, name?: string | undefined | null) => string, notOverridden: number): object
@@ -624,27 +1002,74 @@ export interface PackageJson {
   devDependencies?: Record<string, any>
 }
 
+export interface PacketHeader {
+  version: number
+  flags: number
+  length: number
+  checksum: number
+}
+
 export declare function panic(): void
 
 export declare function panicInAsync(): Promise<void>
 
+export declare function parseNumberBlocking(input: string): Promise<number>
+
+export declare function parsePort(input: string): number
+
 export interface Pet {
   name: string
   kind: number
   eitherTsfn: string | ((err: Error | null, arg: number) => number)
 }
 
+export interface PipelineFrame {
+  index: number
+  length: number
+}
+
+/** The plugin side, calling into the host through its vtable */
+export declare function pluginAdd(host: ExternalObject<'CounterVTable'>, by: number): number
+
+export declare function pluginGet(host: ExternalObject<'CounterVTable'>): number
+
 export declare function plusOne(this: Width): number
 
 export declare function promiseInEither(input: number | Promise<number>): Promise<boolean>
 
+export declare function promiseWithTimeout(input: Promise<number>, ms: number): Promise<number>
+
+export declare function queryPooled(sql: string): Promise<string>
+
+export declare class QuotaExceededError extends Error {
+  limit: number
+}
+
+export declare function racePromises(inputs: Array<Promise<number>>): Promise<number>
+
+export declare function randomBytes(len: number): Buffer
+
+export declare function readBlobChunkSizes(blob: Blob, chunkSize: number): Promise<number[]>
+
+export declare function readBlobText(blob: Blob): Promise<string>
+
 /** napi = { version = 2, features = ["serde-json"] } */
 export declare function readFile(callback: (arg0: Error | undefined, arg1?: string | undefined | null) => void): void
 
 export declare function readFileAsync(path: string): Promise<Buffer>
 
+export declare function readFirstBlobChunk(blob: Blob, chunkSize: number): Promise<number>
+
 export declare function readPackageJson(): PackageJson
 
+/** The header is `version: u8, flags: u8, length: u16` big endian, then a little endian `checksum: u32` */
+export declare function readPacketHeader(view: DataView): PacketHeader
+
+export declare function readRequestBody(request: Request): Promise<string>
+
+/** The metadata under `key` owned by `target`, the inherited one is ignored */
+export declare function readSymbolMetadata(target: object, key: symbol): string | null
+
 export declare function receiveAllOptionalObject(obj?: AllOptionalObject | undefined | null): void
 
 export declare function receiveClassOrNumber(either: number | JsClassForEither): number
@@ -661,8 +1086,40 @@ export declare function receiveStrictObject(strictObject: StrictObject): void
 
 export declare function receiveString(s: string): string
 
+export interface RecordedPoint {
+  x: number
+  y: number
+}
+
 export declare function referenceAsCallback(callback: (arg0: number, arg1: number) => number, arg0: number, arg1: number): number
 
+export declare function regExpExec(regexp: RegExp, input: string): RegExpExecResult | null
+
+export interface RegExpExecResult {
+  matched: string
+  index: number
+  captures: Array<string | undefined | null>
+  groups: Record<string, string | undefined | null>
+}
+
+export declare function regExpMatchAll(regexp: RegExp, input: string): Array<RegExpExecResult>
+
+export declare function regExpTest(regexp: RegExp, input: string): boolean
+
+/** Stop recording and run the recorded calls again in Rust, returns the names of the replayed functions */
+export declare function replayRecordedCalls(): Array<string>
+
+/** Report each of `failures` from its own thread to `on_error` */
+export declare function reportErrorsToCallback(onError: (arg: unknown) => unknown, failures: Array<string>): void
+
+/** Report each of `failures` from its own thread as an `'error'` event of `emitter` */
+export declare function reportErrorsToEmitter(emitter: object, failures: Array<string>): void
+
+/** Report `failure` from another thread as an uncaught exception */
+export declare function reportUncaughtError(failure: string): void
+
+export declare function reserveQuota(requested: number): Promise<number>
+
 export declare function returnEither(input: number): string | number
 
 export declare function returnEitherClass(input: number): number | JsClassForEither
@@ -679,18 +1136,80 @@ export declare function returnUndefinedIfInvalid(input: boolean): boolean
 
 export declare function returnUndefinedIfInvalidPromise(input: Promise<boolean>): Promise<boolean>
 
+export declare function reverseU64Vec(input: Array<bigint> | BigUint64Array): BigUint64Array
+
+/** Wrap `value` in `obj`, returning the value wrapped before if any */
+export declare function rewrapString(obj: object, value: string): string | null
+
 export declare function roundtripStr(s: string): string
 
 export declare function runScript(script: string): unknown
 
+export declare function scale(factor: number, value: number): number
+
+export declare function scaleFloat64Array(input: Float64Array, factor: number): Float64Array
+
+/**
+ * The `length` bytes after the header of the packet in `buffer` at `byte_offset`, with its
+ * `f64` samples scaled by `factor`
+ */
+export declare function scalePacketPayload(buffer: ArrayBuffer, byteOffset: number, factor: number): DataView
+
+/** Call `on_microtask` after the current job and `on_immediate` on the next loop iteration */
+export declare function scheduleFollowUps(onMicrotask: () => void, onImmediate: () => void): void
+
+export declare function sealObject(obj: object): void
+
+/** Send `0..count` from another thread, the callback is called for each of them on the JS thread */
+export declare function sendFromThread(count: number, callback: (arg: number) => void): void
+
+export declare function setAppName(name: string): string | null
+
+export declare function setRandomSeed(seed?: number | undefined | null): void
+
+export declare function setRegisteredSymbolInObj(key: string): object
+
 export declare function setSymbolInObj(symbol: symbol): object
 
+export declare function setSymbolMetadata(target: object, key: symbol, value: string): void
+
+export type Shape =
+  | { type: 'Circle', center: Vertex, radius: number }
+  | { type: 'Polygon', vertices: Array<Vertex> }
+
+export declare function shapeArea(shape: Shape): number
+
+export declare function shapeCenter(shape: Shape): Vertex
+
 export interface Shared {
   value: number
 }
 
+/** The number of ArrayBuffers viewing the shared region which are not garbage collected yet */
+export declare function sharedRegionViews(): number
+
+export declare function sliceNums(nums: Array<number>, start?: number | undefined | null, end?: number | undefined | null): Array<number>
+
+/** A deep copy of `config` which the caller can't mutate anymore */
+export declare function snapshotConfig(config: unknown): unknown
+
+export declare function sortByLocale(items: Array<string>, locale: string): Array<string>
+
 export declare function spawnThreadInThread(tsfn: ((err: Error | null, arg: number) => number)): void
 
+/** Spin until `flag` is set from a worker or `timeout_ms` elapsed */
+export declare function spinUntilCancelled(flag: Int32Array | undefined | null, timeoutMs: number): void
+
+/**
+ * Split `input` into words, the rules might still change
+ * @experimental
+ */
+export declare function splitWords(input: string): Array<string>
+
+export declare function startRecordingCalls(): void
+
+export declare function startRecordingToBrokenLog(): void
+
 export declare const enum Status {
   Pristine = 'Pristine',
   Loading = 'Loading',
@@ -719,14 +1238,46 @@ export type StructuredKind =
   | { type2: 'Birthday', name: string, age: number }
   | { type2: 'Tuple', field0: number, field1: number }
 
+/** The private fields are converted too */
+export interface Style {
+  fill: Fill
+  strokeWidth: number
+}
+
 export declare function sumBtreeMapping(nums: Record<string, number>): number
 
+export declare function sumEsMap(map: Map<unknown, unknown>): number
+
+export declare function sumI64Vec(input: Array<number> | BigInt64Array): number
+
 export declare function sumIndexMapping(nums: Record<string, number>): number
 
+export declare function sumInt32Array(input: Int32Array): number
+
+export declare function sumInThreadPool(numbers: Array<number>): Promise<string>
+
 export declare function sumMapping(nums: Record<string, number>): number
 
 export declare function sumNums(nums: Array<number>): number
 
+export declare function sumNumsInRange(nums: Array<number>, range: { start: number; end: number }): number
+
+export declare function sumObjValues(obj: object): number
+
+export declare function sumOnThread(nums: Array<number>): Promise<number>
+
+export declare function sumPreparedCalls(ctx: object, callback: (arg0: number, arg1: number) => number, factor: number, times: number, unchecked?: boolean | undefined | null): number
+
+/** Await `values` one after the other on the JS thread, calling `on_sum` with the running sum */
+export declare function sumPromisesLocally(values: Array<Promise<number>>, onSum: (arg: number) => void): Promise<number>
+
+/** The sums of the coordinates of the `Vec3`s in `buffer` */
+export declare function sumVec3Buffer(buffer: Buffer): Array<number>
+
+export declare function tagConfigObject(obj: object): void
+
+export declare function takeRecordingError(): string | null
+
 export declare function testSerdeBigNumberPrecision(number: string): any
 
 export declare function testSerdeBufferBytes(obj: object): bigint
@@ -743,12 +1294,48 @@ export declare function threadsafeFunctionThrowError(cb: ((err: Error | null, ar
 
 export declare function throwAsyncError(): Promise<void>
 
+export declare function throwCustomError(errorClass: new (message: string) => Error, message: string): void
+
 export declare function throwError(): void
 
 export declare function throwSyntaxError(error: string, code?: string | undefined | null): void
 
+export declare function throwTypeError(message: string, code?: string | undefined | null): void
+
+/** Toggle `value` in the set, returns whether it's in the set now */
+export declare function toggleInEsSet(set: Set<unknown>, value: string): boolean
+
 export declare function toJsObj(): object
 
+export declare function toLocaleUpperCase(input: string, locale: string): string
+
+/** Milliseconds of a duration like `"1.5s"` or `"250ms"` */
+export declare function toMilliseconds(input: string): number
+
+/** Milliseconds of `value` in `unit`, which is milliseconds by default */
+export declare function toMilliseconds(value: number, unit?: string | undefined | null): number
+
+/** Milliseconds of `[seconds, nanoseconds]`, like from `process.hrtime()` */
+export declare function toMilliseconds(time: Array<number>): number
+
+export declare function toUint8ClampedArray(input: Float32Array): Uint8ClampedArray
+
+/** Run `callback` with the failed Node-API calls traced, returns the trace lines */
+export declare function traceFailedCalls(callback: () => void): Array<string>
+
+export declare function translatePoint(point: RecordedPoint, dx: number, dy: number): RecordedPoint
+
+export declare enum Transport {
+  Http = 'http',
+  WebSocket = 'websocket'
+}
+export declare namespace Transport {
+  function values(): Array<Transport>
+  function nameOf(value: unknown): string | undefined
+}
+
+export declare function transposeMatrix(matrix: [[number, number], [number, number]]): [[number, number], [number, number]]
+
 export declare function tsfnAsyncCall(func: (arg0: number, arg1: number, arg2: number) => string): Promise<void>
 
 export declare function tsfnCallWithCallback(tsfn: ((err: Error | null, ) => string)): void
@@ -795,6 +1382,8 @@ export interface UtcDates {
   end?: Date
 }
 
+export declare function utf8Buffer(text: string): Buffer
+
 export declare function validateArray(arr: Array<number>): number
 
 export declare function validateBigint(input: bigint): bigint
@@ -811,7 +1400,7 @@ export declare function validateDateTime(d: Date): number
 
 export declare function validateEnum(input: KindInValidate): number
 
-export declare function validateExternal(e: ExternalObject<number>): number
+export declare function validateExternal(e: ExternalObject<'u32'>): number
 
 export declare function validateFunction(cb: () => number): number
 
@@ -841,12 +1430,33 @@ export declare function validateUint8ClampedSlice(input: Uint8ClampedArray): num
 
 export declare function validateUndefined(i: undefined): boolean
 
+export declare function validateUsername(username: string): string
+
+/** Thrown when an input doesn't pass the validation, `e instanceof ValidationError` in JavaScript */
+export declare class ValidationError extends Error {
+  field: string
+  code: number
+  hint?: string
+}
+
+export interface Vertex {
+  x: number
+  y: number
+}
+
+export declare function viewSharedRegion(): ArrayBuffer
+
 export declare function withAbortController(a: number, b: number, signal: AbortSignal): Promise<number>
 
+/** `options` over `{ retries: 3, verbose: false }`, or over each of them if it's an array */
+export declare function withDefaultOptions(options: unknown): Array<object>
+
 export declare function withinAsyncRuntimeIfAvailable(): void
 
 export declare function withoutAbortController(a: number, b: number): Promise<number>
 
+export declare function writePacketHeader(view: DataView, header: PacketHeader): void
+
 export declare function xxh64Alias(input: Buffer): bigint
 
 export declare namespace xxh2 {
@@ -861,8 +1471,10 @@ export declare namespace xxh3 {
     /** update */
     update(input: Buffer): void
     digest(): bigint
+    get endianness(): string
   }
   export const ALIGNMENT: number
+  export const WORD_SIZE: number
   /** xxh128 function */
   export function xxh128(input: Buffer): bigint
   export function xxh3_64(input: Buffer): bigint
@@ -1,4 +1,6 @@
-use napi::{Env, JsObject};
+use std::ops::{Range, RangeInclusive};
+
+use napi::{bindgen_prelude::slice_range, Env, JsObject};
 
 #[napi]
 pub fn get_words() -> Vec<&'static str> {
@@ -33,3 +35,37 @@ fn get_num_arr() -> [u32; 2] {
 fn get_nested_num_arr() -> [[[u32; 1]; 1]; 2] {
   [[[1]], [[1]]]
 }
+
+#[napi]
+fn blend_colors(a: [u8; 4], b: [u8; 4]) -> [u8; 4] {
+  let mut blended = [0; 4];
+  for (channel, (a, b)) in blended.iter_mut().zip(a.iter().zip(b.iter())) {
+    *channel = ((*a as u16 + *b as u16) / 2) as u8;
+  }
+  blended
+}
+
+#[napi]
+fn transpose_matrix(matrix: [[f64; 2]; 2]) -> [[f64; 2]; 2] {
+  [[matrix[0][0], matrix[1][0]], [matrix[0][1], matrix[1][1]]]
+}
+
+#[napi]
+fn sum_nums_in_range(nums: Vec<u32>, range: Range<u32>) -> u32 {
+  nums
+    .get(range.start as usize..range.end as usize)
+    .map(|nums| nums.iter().sum())
+    .unwrap_or(0)
+}
+
+#[napi]
+fn nums_bounds(nums: Vec<u32>) -> Option<RangeInclusive<u32>> {
+  let min = nums.iter().min()?;
+  let max = nums.iter().max()?;
+  Some(*min..=*max)
+}
+
+#[napi]
+fn slice_nums(nums: Vec<u32>, start: Option<i64>, end: Option<i64>) -> Vec<u32> {
+  nums[slice_range(nums.len(), start, end)].to_vec()
+}
@@ -1,8 +1,10 @@
+use std::sync::atomic::{AtomicU32, Ordering};
+
 #[cfg(not(target_family = "wasm"))]
 use futures::prelude::*;
-use napi::bindgen_prelude::*;
 #[cfg(not(target_family = "wasm"))]
 use napi::tokio::fs;
+use napi::{bindgen_prelude::*, AsyncScope, PerEnvPool};
 
 #[napi]
 async fn read_file_async(path: String) -> Result<Buffer> {
@@ -43,3 +45,117 @@ pub fn within_async_runtime_if_available() {
     println!("within_runtime_if_available");
   });
 }
+
+#[napi(thread = "blocking")]
+pub fn fibonacci_blocking(n: u32) -> u32 {
+  match n {
+    0 | 1 => n,
+    _ => fibonacci_blocking(n - 1) + fibonacci_blocking(n - 2),
+  }
+}
+
+#[napi(thread = "blocking")]
+pub fn parse_number_blocking(input: String) -> Result<u32> {
+  input.trim().parse().map_err(|e| {
+    Error::new(
+      Status::InvalidArg,
+      format!("failed to parse `{}`, {}", input, e),
+    )
+  })
+}
+
+static OPENED_CONNECTIONS: AtomicU32 = AtomicU32::new(0);
+
+struct Connection {
+  id: u32,
+}
+
+impl Connection {
+  async fn open() -> Result<Self> {
+    Ok(Self {
+      id: OPENED_CONNECTIONS.fetch_add(1, Ordering::Relaxed) + 1,
+    })
+  }
+
+  async fn query(&self, sql: &str) -> String {
+    tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+    format!("{sql} on connection {}", self.id)
+  }
+}
+
+#[napi]
+pub fn query_pooled(env: Env, sql: String) -> Result<PromiseRaw<String>> {
+  let pool = PerEnvPool::get_or_init(&env, 2, Connection::open)?;
+  env.spawn_future(async move {
+    let conn = pool.checkout().await?;
+    Ok(conn.query(&sql).await)
+  })
+}
+
+#[napi(object)]
+pub struct ConnectionPoolStatus {
+  pub opened: u32,
+  pub idle: u32,
+  pub in_use: u32,
+}
+
+#[napi]
+pub fn connection_pool_status(env: Env) -> ConnectionPoolStatus {
+  let pool = PerEnvPool::<Connection>::get(&env);
+  ConnectionPoolStatus {
+    opened: OPENED_CONNECTIONS.load(Ordering::Relaxed),
+    idle: pool.as_ref().map_or(0, |pool| pool.idle_count() as u32),
+    in_use: pool.as_ref().map_or(0, |pool| pool.in_use_count() as u32),
+  }
+}
+
+static DROPPED_DELAYS: AtomicU32 = AtomicU32::new(0);
+
+/// Counts the delays dropped, completed or not
+struct DelayDropCounter;
+
+impl Drop for DelayDropCounter {
+  fn drop(&mut self) {
+    DROPPED_DELAYS.fetch_add(1, Ordering::Relaxed);
+  }
+}
+
+#[napi]
+pub struct Ticker {
+  scope: AsyncScope,
+}
+
+#[napi]
+impl Ticker {
+  #[napi(constructor)]
+  pub fn new() -> Self {
+    Self {
+      scope: AsyncScope::new(),
+    }
+  }
+
+  #[napi]
+  pub fn delay(&self, env: Env, ms: u32, value: u32) -> Result<PromiseRaw<u32>> {
+    let counter = DelayDropCounter;
+    self.scope.spawn(&env, async move {
+      let _counter = counter;
+      tokio::time::sleep(std::time::Duration::from_millis(ms as u64)).await;
+      Ok(value)
+    })
+  }
+
+  #[napi(getter)]
+  pub fn pending(&self) -> u32 {
+    self.scope.pending() as u32
+  }
+
+  #[napi]
+  pub fn close(&self, env: Env) -> Result<PromiseRaw<()>> {
+    self.scope.close(&env)
+  }
+}
+
+#[napi]
+pub fn dropped_delays() -> u32 {
+  DROPPED_DELAYS.load(Ordering::Relaxed)
+}
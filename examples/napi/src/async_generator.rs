@@ -0,0 +1,48 @@
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures::Stream;
+use napi::bindgen_prelude::*;
+
+#[napi]
+pub fn count_up_async(to: u32) -> AsyncGenerator<u32> {
+  AsyncGenerator::new(futures::stream::iter((1..=to).map(Ok)))
+}
+
+struct Countdown {
+  remaining: u32,
+}
+
+impl Stream for Countdown {
+  type Item = Result<u32>;
+
+  fn poll_next(mut self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+    if self.remaining == 0 {
+      return Poll::Ready(None);
+    }
+    self.remaining -= 1;
+    Poll::Ready(Some(Ok(self.remaining)))
+  }
+}
+
+#[napi]
+pub fn countdown_async(from: u32) -> AsyncGenerator<u32> {
+  AsyncGenerator::new(Countdown { remaining: from })
+}
+
+#[napi]
+pub fn fail_async_after(n: u32) -> AsyncGenerator<String> {
+  AsyncGenerator::new(futures::stream::unfold(0, move |i| async move {
+    tokio::time::sleep(std::time::Duration::from_millis(1)).await;
+    if i < n {
+      Some((Ok(format!("item {i}")), i + 1))
+    } else if i == n {
+      Some((
+        Err(Error::new(Status::GenericFailure, "generator failed")),
+        i + 1,
+      ))
+    } else {
+      None
+    }
+  }))
+}
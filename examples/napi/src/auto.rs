@@ -0,0 +1,76 @@
+use self::shapes::{Fill, Shape, Style, Vertex};
+
+#[napi(auto)]
+mod shapes {
+  pub struct Vertex {
+    pub x: f64,
+    pub y: f64,
+  }
+
+  pub enum Fill {
+    Solid,
+    Hatched,
+    Outline,
+  }
+
+  pub enum Shape {
+    Circle { center: Vertex, radius: f64 },
+    Polygon { vertices: Vec<Vertex> },
+  }
+
+  /// The private fields are converted too
+  pub struct Style {
+    fill: Fill,
+    stroke_width: f64,
+  }
+
+  impl Style {
+    pub fn new(fill: Fill, stroke_width: f64) -> Self {
+      Self { fill, stroke_width }
+    }
+
+    pub fn is_filled(&self) -> bool {
+      !matches!(self.fill, Fill::Outline)
+    }
+  }
+}
+
+#[napi]
+pub fn shape_area(shape: Shape) -> f64 {
+  match shape {
+    Shape::Circle { radius, .. } => std::f64::consts::PI * radius * radius,
+    // the shoelace formula
+    Shape::Polygon { vertices } => {
+      let doubled: f64 = vertices
+        .iter()
+        .zip(vertices.iter().cycle().skip(1))
+        .map(|(a, b)| a.x * b.y - b.x * a.y)
+        .sum();
+      doubled.abs() / 2.0
+    }
+  }
+}
+
+#[napi]
+pub fn shape_center(shape: Shape) -> Vertex {
+  match shape {
+    Shape::Circle { center, .. } => center,
+    Shape::Polygon { vertices } => {
+      let count = vertices.len().max(1) as f64;
+      Vertex {
+        x: vertices.iter().map(|v| v.x).sum::<f64>() / count,
+        y: vertices.iter().map(|v| v.y).sum::<f64>() / count,
+      }
+    }
+  }
+}
+
+#[napi]
+pub fn outline_style(stroke_width: f64) -> Style {
+  Style::new(Fill::Outline, stroke_width)
+}
+
+#[napi]
+pub fn is_filled(style: Style) -> bool {
+  style.is_filled()
+}
@@ -32,3 +32,30 @@ pub fn bigint_from_i64() -> BigInt {
 pub fn bigint_from_i128() -> BigInt {
   BigInt::from(-100i128)
 }
+
+#[napi]
+pub fn bigint_checked_add(a: BigInt, b: BigInt) -> Result<u128> {
+  a.try_into_u128()?
+    .checked_add(b.try_into_u128()?)
+    .ok_or_else(|| Error::new(Status::InvalidArg, "The sum doesn't fit in u128"))
+}
+
+#[napi]
+pub fn bigint_i128_to_string(bi: BigInt) -> Result<String> {
+  Ok(i128::try_from(bi)?.to_string())
+}
+
+#[napi]
+pub fn bigint_factorial(n: u32) -> num_bigint::BigUint {
+  (1..=n).map(num_bigint::BigUint::from).product()
+}
+
+#[napi]
+pub fn bigint_negate(value: num_bigint::BigInt) -> num_bigint::BigInt {
+  -value
+}
+
+#[napi]
+pub fn bigint_bit_length(value: num_bigint::BigUint) -> u32 {
+  value.bits() as u32
+}
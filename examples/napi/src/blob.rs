@@ -0,0 +1,60 @@
+use napi::bindgen_prelude::*;
+
+#[napi]
+pub fn create_blob_from_chunks(chunks: Vec<String>, mime_type: Option<String>) -> BlobBuilder {
+  let mut builder = BlobBuilder::new();
+  if let Some(mime_type) = mime_type {
+    builder = builder.with_type(mime_type);
+  }
+  builder.extend(chunks);
+  builder
+}
+
+#[napi]
+pub fn create_file(env: Env, name: String, content: String, last_modified: i64) -> Result<File> {
+  let mut builder = BlobBuilder::new().with_type("text/plain");
+  builder.append(content);
+  builder.build_file(&env, &name, Some(last_modified))
+}
+
+#[napi]
+pub fn get_blob_size(blob: Blob) -> Result<u32> {
+  Ok(blob.size()? as u32)
+}
+
+#[napi]
+pub fn get_file_name(file: File) -> Result<String> {
+  file.name()
+}
+
+#[napi]
+pub fn read_blob_text(blob: Blob) -> Result<PromiseRaw<String>> {
+  blob.text()
+}
+
+#[napi(ts_return_type = "Promise<number[]>")]
+pub fn read_blob_chunk_sizes(
+  env: Env,
+  blob: Blob,
+  chunk_size: u32,
+) -> Result<PromiseRaw<Vec<u32>>> {
+  let mut chunks = blob.chunks(chunk_size as usize)?;
+  env.spawn_future(async move {
+    let mut sizes = Vec::with_capacity(chunks.remaining());
+    while let Some(chunk) = chunks.next_chunk().await {
+      sizes.push(chunk?.len() as u32);
+    }
+    Ok(sizes)
+  })
+}
+
+#[napi]
+pub fn read_first_blob_chunk(env: Env, blob: Blob, chunk_size: u32) -> Result<PromiseRaw<u32>> {
+  let mut chunks = blob.chunks(chunk_size as usize)?;
+  env.spawn_future(async move {
+    match chunks.next_chunk().await {
+      Some(chunk) => Ok(chunk?.len() as u32),
+      None => Ok(0),
+    }
+  })
+}
@@ -1,6 +1,8 @@
 use std::{env, format};
 
-use napi::{bindgen_prelude::*, threadsafe_function::ThreadsafeFunctionCallMode, JsUnknown};
+use napi::{
+  bindgen_prelude::*, threadsafe_function::ThreadsafeFunctionCallMode, AsyncContext, JsUnknown,
+};
 
 #[napi]
 fn get_cwd<T: Fn(String) -> Result<()>>(callback: T) {
@@ -97,3 +99,36 @@ pub fn capture_error_in_callback<C: Fn() -> Result<()>, E: Fn(Error) -> Result<(
     Ok(())
   }
 }
+
+/// Calls the listener like a native event source, in the async context it was created in
+#[napi]
+pub struct NativeEmitter {
+  context: AsyncContext,
+  listener: FunctionRef<u32, ()>,
+}
+
+#[napi]
+impl NativeEmitter {
+  #[napi(constructor)]
+  pub fn new(env: Env, listener: FunctionRef<u32, ()>) -> Result<Self> {
+    Ok(Self {
+      context: env.async_init(None, "NativeEmitter")?,
+      listener,
+    })
+  }
+
+  #[napi]
+  pub fn emit(&self, env: Env, value: u32) -> Result<()> {
+    env.make_callback(&self.context, &self.listener.borrow_back(&env)?, value)
+  }
+
+  #[napi]
+  pub fn emit_batch(&self, env: Env, values: Vec<u32>) -> Result<()> {
+    let listener = self.listener.borrow_back(&env)?;
+    env.run_in_callback_scope(&self.context, || {
+      values
+        .into_iter()
+        .try_for_each(|value| listener.call(value))
+    })
+  }
+}
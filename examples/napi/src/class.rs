@@ -1,8 +1,11 @@
+use std::sync::Arc;
+
 use napi::{
   bindgen_prelude::{
-    Buffer, ClassInstance, JavaScriptClassExt, ObjectFinalize, This, Uint8Array, Unknown,
+    Buffer, ClassInstance, JavaScriptClassExt, ObjectFinalize, ObjectSetHook, This, Uint8Array,
+    Unknown,
   },
-  Env, Property, PropertyAttributes, Result,
+  Env, Error, Property, PropertyAttributes, Result, Status,
 };
 
 use crate::r#enum::Kind;
@@ -488,3 +491,88 @@ impl<'scope> ClassWithLifetime<'scope> {
     self.inner.get_name()
   }
 }
+
+#[napi(set_hook)]
+pub struct ObservedModel {
+  pub name: String,
+  pub age: u32,
+  nickname: String,
+  changes: Vec<String>,
+}
+
+#[napi]
+impl ObservedModel {
+  #[napi(constructor)]
+  pub fn new(name: String, age: u32) -> Self {
+    ObservedModel {
+      name,
+      age,
+      nickname: String::new(),
+      changes: vec![],
+    }
+  }
+
+  #[napi(getter)]
+  pub fn nickname(&self) -> &str {
+    &self.nickname
+  }
+
+  #[napi(setter)]
+  pub fn set_nickname(&mut self, nickname: String) {
+    self.nickname = nickname;
+  }
+
+  /// The properties set from JavaScript since the last call
+  #[napi]
+  pub fn take_changes(&mut self) -> Vec<String> {
+    std::mem::take(&mut self.changes)
+  }
+}
+
+impl ObjectSetHook for ObservedModel {
+  fn before_set(&mut self, property: &'static str, value: &dyn std::any::Any) -> Result<()> {
+    match value.downcast_ref::<u32>() {
+      Some(age) if property == "age" && *age > 150 => Err(Error::new(
+        Status::InvalidArg,
+        format!("{age} is not a valid age"),
+      )),
+      _ => Ok(()),
+    }
+  }
+
+  fn after_set(&mut self, property: &'static str) {
+    self.changes.push(property.to_owned());
+  }
+}
+
+#[napi]
+pub struct Document {
+  #[napi(readonly, borrow)]
+  pub title: Arc<str>,
+  #[napi(readonly, borrow)]
+  pub content: Arc<[u8]>,
+  previous_content: Arc<[u8]>,
+}
+
+#[napi]
+impl Document {
+  #[napi(constructor)]
+  pub fn new(title: String, content: Buffer) -> Self {
+    Document {
+      title: title.into(),
+      content: content.to_vec().into(),
+      previous_content: Arc::from([]),
+    }
+  }
+
+  /// The content before the last `replaceContent`, without cloning the field
+  #[napi(getter, borrow)]
+  pub fn previous_content(&self) -> &Arc<[u8]> {
+    &self.previous_content
+  }
+
+  #[napi]
+  pub fn replace_content(&mut self, content: Buffer) {
+    self.previous_content = std::mem::replace(&mut self.content, content.to_vec().into());
+  }
+}
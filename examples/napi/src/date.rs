@@ -90,3 +90,35 @@ pub fn chrono_date_fixture_return2() -> chrono::DateTime<FixedOffset> {
     .single()
     .unwrap()
 }
+
+#[napi]
+pub fn date_from_system_time(env: &Env, secs: i64, nanos: u32) -> Result<Date> {
+  let since_epoch = std::time::Duration::new(secs.unsigned_abs(), 0);
+  let time = if secs < 0 {
+    std::time::UNIX_EPOCH - since_epoch
+  } else {
+    std::time::UNIX_EPOCH + since_epoch
+  };
+  env.create_date_from_system_time(time + std::time::Duration::from_nanos(nanos.into()))
+}
+
+#[napi]
+pub fn date_to_system_time_secs(date: Date) -> Result<f64> {
+  let time = date.to_system_time()?;
+  Ok(match time.duration_since(std::time::UNIX_EPOCH) {
+    Ok(after) => after.as_secs_f64(),
+    Err(before) => -before.duration().as_secs_f64(),
+  })
+}
+
+#[napi]
+pub fn date_from_chrono(env: &Env, rfc3339: String) -> Result<Date> {
+  let time = chrono::DateTime::parse_from_rfc3339(&rfc3339)
+    .map_err(|err| Error::new(Status::InvalidArg, err.to_string()))?;
+  env.create_date_from_chrono(&time)
+}
+
+#[napi]
+pub fn date_to_chrono(date: Date) -> Result<String> {
+  Ok(date.to_chrono()?.to_rfc3339())
+}
@@ -42,6 +42,20 @@ pub enum CustomNumEnum {
   Ten,  // 10
 }
 
+/// Listed with `LogLevel.values()`
+#[napi(reflect)]
+pub enum LogLevel {
+  Debug = 10,
+  Info = 20,
+  Warn = 30,
+}
+
+#[napi(string_enum = "lowercase", reflect)]
+pub enum Transport {
+  Http,
+  WebSocket,
+}
+
 #[napi]
 fn enum_to_i32(e: CustomNumEnum) -> i32 {
   e as i32
@@ -1,4 +1,8 @@
-use napi::bindgen_prelude::*;
+use std::cell::Cell;
+use std::rc::Rc;
+use std::sync::{Arc, Mutex};
+
+use napi::{bindgen_prelude::*, NapiRaw, RandomSource};
 
 #[napi]
 pub fn run_script(env: Env, script: String) -> Result<Unknown> {
@@ -10,7 +14,261 @@ pub fn get_module_file_name(env: Env) -> Result<String> {
   env.get_module_file_name()
 }
 
+#[napi]
+pub fn is_napi_function_available(name: String) -> bool {
+  napi::sys::is_available(&name)
+}
+
 #[napi]
 pub fn throw_syntax_error(env: Env, error: String, code: Option<String>) {
   env.throw_syntax_error(error, code);
 }
+
+#[napi]
+pub fn throw_type_error(env: Env, message: String, code: Option<String>) -> Result<()> {
+  env.throw_type_error(&message, code.as_deref())
+}
+
+#[napi(ts_args_type = "errorClass: new (message: string) => Error, message: string")]
+pub fn throw_custom_error(
+  env: Env,
+  error_class: Function<String, Unknown>,
+  message: String,
+) -> Result<()> {
+  env.throw(error_class.new_instance(message)?)?;
+  Err(Error::from_status(Status::PendingException))
+}
+
+/// Wrap `value` in `obj`, returning the value wrapped before if any
+#[napi]
+pub fn rewrap_string(env: Env, mut obj: Object, value: String) -> Result<Option<String>> {
+  let previous = env.remove_wrap::<String>(&obj).ok();
+  env.wrap(&mut obj, value, None)?;
+  Ok(previous)
+}
+
+#[napi]
+pub fn create_labels(env: Env, count: u32) -> Result<Object> {
+  env.run_in_escapable_scope(|| {
+    let mut labels = env.create_object()?;
+    for i in 0..count {
+      labels.set(format!("item{i}"), env.create_string(&format!("Item {i}"))?)?;
+    }
+    Ok(labels)
+  })
+}
+
+#[napi]
+pub fn random_bytes(env: Env, len: u32) -> Result<Buffer> {
+  Ok(env.crypto_random_bytes(len as usize)?.into())
+}
+
+#[napi]
+pub fn set_random_seed(env: Env, seed: Option<u32>) {
+  env.set_random_source(match seed {
+    Some(seed) => RandomSource::Seeded(seed.into()),
+    None => RandomSource::Crypto,
+  });
+}
+
+#[napi]
+pub fn call_on_idle(env: Env, callback: FunctionRef<(), ()>) -> Result<()> {
+  env.uv_idle_once(move |env| callback.borrow_back(&env)?.call(()))
+}
+
+/// Send `0..count` from another thread, the callback is called for each of them on the JS thread
+#[napi]
+pub fn send_from_thread(env: Env, count: u32, callback: FunctionRef<u32, ()>) -> Result<()> {
+  let queue = Arc::new(Mutex::new(Vec::new()));
+  let received = queue.clone();
+  let sender = env.uv_async(move |env| {
+    let callback = callback.borrow_back(&env)?;
+    let values = std::mem::take(&mut *received.lock().unwrap());
+    values
+      .into_iter()
+      .try_for_each(|value| callback.call(value))
+  })?;
+  std::thread::spawn(move || {
+    for i in 0..count {
+      queue.lock().unwrap().push(i);
+      // the env is torn down
+      if sender.send().is_err() {
+        break;
+      }
+    }
+  });
+  Ok(())
+}
+
+/// Await `values` one after the other on the JS thread, calling `on_sum` with the running sum
+#[napi]
+pub fn sum_promises_locally(
+  env: Env,
+  values: Vec<Promise<u32>>,
+  on_sum: FunctionRef<u32, ()>,
+) -> Result<PromiseRaw<u32>> {
+  // not `Send`, it can't cross an `.await` of `spawn_future`
+  let sum = Rc::new(Cell::new(0));
+  env.spawn_local(async move {
+    for value in values {
+      sum.set(sum.get() + value.await?);
+      on_sum.borrow_back(&env)?.call(sum.get())?;
+    }
+    Ok(sum.get())
+  })
+}
+
+/// Call `step` `steps` times with the output of the previous call, awaiting what it returns
+#[napi(
+  ts_args_type = "initial: number, steps: number, step: (value: number) => PromiseLike<number> | number"
+)]
+pub fn chain_async_steps(
+  env: Env,
+  initial: u32,
+  steps: u32,
+  step: FunctionRef<u32, Unknown>,
+) -> Result<PromiseRaw<u32>> {
+  env.spawn_local(async move {
+    let mut value = initial;
+    for _ in 0..steps {
+      let output = step.borrow_back(&env)?.call(value)?;
+      value = JsPromiseFuture::new(&env, output)?.await?;
+    }
+    Ok(value)
+  })
+}
+
+/// A deep copy of `config` which the caller can't mutate anymore
+#[napi(ts_args_type = "config: unknown", ts_return_type = "unknown")]
+pub fn snapshot_config(env: Env, config: Unknown) -> Result<Unknown> {
+  env.structured_clone(&config)
+}
+
+/// Call `on_microtask` after the current job and `on_immediate` on the next loop iteration
+#[napi]
+pub fn schedule_follow_ups(
+  env: Env,
+  on_microtask: Function<(), ()>,
+  on_immediate: FunctionRef<(), ()>,
+) -> Result<()> {
+  env.queue_microtask(&on_microtask)?;
+  env.set_immediate(move |env| on_immediate.borrow_back(&env)?.call(()))
+}
+
+struct RequestCounter(Cell<u32>);
+
+struct AppName(String);
+
+#[napi]
+pub fn count_request(env: Env) -> Result<u32> {
+  let counter = env.get_or_init_instance_data_typed(|| Ok(RequestCounter(Cell::new(0))))?;
+  counter.0.set(counter.0.get() + 1);
+  Ok(counter.0.get())
+}
+
+#[napi]
+pub fn set_app_name(env: Env, name: String) -> Result<Option<String>> {
+  Ok(
+    env
+      .set_instance_data_typed(AppName(name))?
+      .map(|previous| previous.0.clone()),
+  )
+}
+
+#[napi]
+pub fn get_app_name(env: Env) -> Option<String> {
+  env
+    .get_instance_data_typed::<AppName>()
+    .map(|name| name.0.clone())
+}
+
+/// Spin until `flag` is set from a worker or `timeout_ms` elapsed
+#[napi]
+pub fn spin_until_cancelled(env: Env, flag: Option<CancelFlag>, timeout_ms: u32) -> Result<()> {
+  let mut yielder = env
+    .yield_every(1000, flag.as_ref())
+    .with_timeout(std::time::Duration::from_millis(timeout_ms.into()));
+  loop {
+    yielder.tick()?;
+    std::hint::spin_loop();
+  }
+}
+
+#[napi]
+pub fn loose_sum(env: &Env, values: Vec<Unknown>) -> Result<f64> {
+  values
+    .into_iter()
+    .map(|value| env.coerce_to_number(&value)?.get_double())
+    .sum()
+}
+
+#[napi]
+pub fn loose_describe(env: &Env, value: Unknown) -> Result<String> {
+  let truthy = env.coerce_to_bool(&value)?.get_value()?;
+  let text = env.coerce_to_string(&value)?.into_utf8()?.into_owned()?;
+  Ok(format!(
+    "{text} is {}",
+    if truthy { "truthy" } else { "falsy" }
+  ))
+}
+
+#[napi(object)]
+pub struct MemoryUsageReport {
+  pub addon_external: i64,
+  pub isolate_external: i64,
+  pub rss: f64,
+  pub heap_total: f64,
+  pub heap_used: f64,
+  pub external: f64,
+  pub array_buffers: f64,
+}
+
+#[napi]
+pub fn memory_usage(env: &Env) -> Result<MemoryUsageReport> {
+  let usage = env.memory_usage()?;
+  Ok(MemoryUsageReport {
+    addon_external: usage.addon_external,
+    isolate_external: usage.isolate_external,
+    rss: usage.rss as f64,
+    heap_total: usage.heap_total as f64,
+    heap_used: usage.heap_used as f64,
+    external: usage.external as f64,
+    array_buffers: usage.array_buffers as f64,
+  })
+}
+
+/// How much the external memory of the addon grows while `bytes` are registered
+#[napi]
+pub fn addon_external_memory_growth(env: &Env, bytes: u32) -> Result<i64> {
+  let before = env.memory_usage()?.addon_external;
+  env.adjust_external_memory(bytes.into())?;
+  let during = env.memory_usage()?.addon_external;
+  env.adjust_external_memory(-i64::from(bytes))?;
+  Ok(during - before)
+}
+
+/// `options` over `{ retries: 3, verbose: false }`, or over each of them if it's an array
+#[napi]
+pub fn with_default_options(env: &Env, options: Unknown) -> Result<Vec<Object>> {
+  let builtins = env.builtins()?;
+  let options: Vec<Unknown> = if builtins.is_array(&options)? {
+    unsafe { Vec::from_napi_value(env.raw(), options.raw())? }
+  } else {
+    vec![options]
+  };
+  options
+    .into_iter()
+    .map(|options| {
+      let mut target = env.create_object()?;
+      target.set_named_property("retries", 3)?;
+      target.set_named_property("verbose", false)?;
+      builtins.object_assign(&target, options)?;
+      Ok(target)
+    })
+    .collect()
+}
+
+#[napi]
+pub fn utf8_buffer(env: &Env, text: String) -> Result<Buffer> {
+  env.builtins()?.buffer_from(text)
+}
@@ -1,4 +1,4 @@
-use napi::bindgen_prelude::*;
+use napi::{bindgen_prelude::*, JsNumber, JsObject, JsUnknown};
 
 #[napi]
 pub fn throw_error() -> Result<()> {
@@ -39,6 +39,44 @@ pub fn error_message_contains_null_byte(msg: Utf16String) -> Result<()> {
   Err(Error::new(Status::InvalidArg, msg))
 }
 
+/// Calls `callback` with each item, collecting what it throws instead of stopping at the first error
+#[napi]
+pub fn collect_thrown_errors(
+  env: Env,
+  callback: Function<u32, ()>,
+  items: Vec<u32>,
+) -> Result<Vec<JsUnknown>> {
+  let mut thrown = Vec::new();
+  for item in items {
+    if let Err(err) = callback.apply((), item) {
+      match env.get_and_clear_last_exception()? {
+        Some(exception) => thrown.push(exception),
+        None => return Err(err),
+      }
+    }
+  }
+  Ok(thrown)
+}
+
+#[napi(ts_return_type = "Error")]
+pub fn create_typed_error(
+  env: Env,
+  kind: String,
+  message: String,
+  code: Option<String>,
+) -> Result<JsObject> {
+  let code = code.as_deref();
+  match kind.as_str() {
+    "TypeError" => env.create_type_error(&message, code),
+    "RangeError" => env.create_range_error(&message, code),
+    "SyntaxError" => env.create_syntax_error(&message, code),
+    _ => Err(Error::new(
+      Status::InvalidArg,
+      format!("Unknown error kind `{kind}`"),
+    )),
+  }
+}
+
 #[napi]
 pub async fn throw_async_error() -> Result<()> {
   Err(Error::new(Status::InvalidArg, "Async Error".to_owned()))
@@ -59,3 +97,68 @@ impl CustomStruct {
     Err(Error::new(CustomError::Panic, "don't panic"))
   }
 }
+
+/// Read `value` as a `u32` without checking its type first, the error tells which Node-API call failed
+#[napi]
+pub fn get_uint32_unchecked(value: JsUnknown) -> Result<u32> {
+  unsafe { value.cast::<JsNumber>() }.get_uint32()
+}
+
+#[napi(object)]
+pub struct NapiCallOfError {
+  pub function: String,
+  pub message: Option<String>,
+}
+
+#[napi]
+pub fn napi_call_of_error(value: JsUnknown) -> Option<NapiCallOfError> {
+  let err = get_uint32_unchecked(value).err()?;
+  err.napi_call().map(|call| NapiCallOfError {
+    function: call.function.to_owned(),
+    message: call.message.clone(),
+  })
+}
+
+/// Thrown when an input doesn't pass the validation, `e instanceof ValidationError` in JavaScript
+#[napi(error)]
+pub struct ValidationError {
+  pub field: String,
+  pub code: u32,
+  pub hint: Option<String>,
+}
+
+impl std::fmt::Display for ValidationError {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "Invalid `{}`", self.field)
+  }
+}
+
+#[napi(error)]
+pub struct QuotaExceededError {
+  pub message: String,
+  pub limit: u32,
+}
+
+#[napi(error = ValidationError)]
+pub fn validate_username(username: String) -> std::result::Result<String, ValidationError> {
+  if username.is_empty() || !username.chars().all(|c| c.is_ascii_alphanumeric()) {
+    return Err(ValidationError {
+      field: "username".to_owned(),
+      code: 42,
+      hint: (!username.is_empty()).then(|| "Use letters and digits only".to_owned()),
+    });
+  }
+  Ok(username.to_lowercase())
+}
+
+#[napi(error = QuotaExceededError)]
+pub async fn reserve_quota(requested: u32) -> std::result::Result<u32, QuotaExceededError> {
+  const LIMIT: u32 = 100;
+  if requested > LIMIT {
+    return Err(QuotaExceededError {
+      message: format!("Requested {requested}, the limit is {LIMIT}"),
+      limit: LIMIT,
+    });
+  }
+  Ok(LIMIT - requested)
+}
@@ -36,3 +36,18 @@ pub fn mutate_optional_external(external: Option<&mut External<u32>>, new_val: u
     **external = new_val;
   }
 }
+
+pub struct Database {
+  name: String,
+}
+
+/// Typed as `ExternalObject<'Database'>`, it can't be passed where another external is expected
+#[napi]
+pub fn open_database(name: String) -> External<Database> {
+  External::new(Database { name })
+}
+
+#[napi]
+pub fn database_name(database: &External<Database>) -> String {
+  database.name.clone()
+}
@@ -0,0 +1,77 @@
+use std::sync::atomic::{AtomicU32, Ordering};
+
+use napi::{bindgen_prelude::*, ExternalMemory, JsArrayBuffer, MappedArrayBuffer};
+
+static DEVICE_ALLOCATIONS: AtomicU32 = AtomicU32::new(0);
+
+/// Memory of a simulated device, counted until it's released
+struct DeviceMemory {
+  data: Box<[u8]>,
+}
+
+impl DeviceMemory {
+  fn alloc(data: Box<[u8]>) -> Self {
+    DEVICE_ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    DeviceMemory { data }
+  }
+}
+
+unsafe impl ExternalMemory for DeviceMemory {
+  fn as_mut_ptr(&mut self) -> *mut u8 {
+    self.data.as_mut_ptr()
+  }
+
+  fn len(&self) -> usize {
+    self.data.len()
+  }
+
+  fn release(self, _env: Env) {
+    DEVICE_ALLOCATIONS.fetch_sub(1, Ordering::Relaxed);
+  }
+}
+
+/// The number of device allocations not released yet
+#[napi]
+pub fn device_allocations() -> u32 {
+  DEVICE_ALLOCATIONS.load(Ordering::Relaxed)
+}
+
+/// The doubled `values`, computed into device memory and read from JavaScript without a copy
+#[napi]
+pub struct DeviceReadback {
+  mapped: MappedArrayBuffer<DeviceMemory>,
+}
+
+#[napi]
+impl DeviceReadback {
+  #[napi(constructor)]
+  pub fn new(env: Env, values: Vec<u8>) -> Result<Self> {
+    let result = values.iter().map(|value| value.wrapping_mul(2)).collect();
+    Ok(DeviceReadback {
+      mapped: MappedArrayBuffer::new(&env, DeviceMemory::alloc(result))?,
+    })
+  }
+
+  #[napi(getter)]
+  pub fn data(&self) -> Result<Option<JsArrayBuffer>> {
+    self.mapped.array_buffer()
+  }
+
+  #[napi(getter)]
+  pub fn mapped(&self) -> bool {
+    self.mapped.is_mapped()
+  }
+
+  /// Detach the data from JavaScript and release the device memory, `false` if it's released
+  /// already
+  #[napi]
+  pub fn unmap(&self, env: Env) -> Result<bool> {
+    Ok(match self.mapped.unmap()? {
+      Some(memory) => {
+        memory.release(env);
+        true
+      }
+      None => false,
+    })
+  }
+}
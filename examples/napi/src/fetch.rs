@@ -0,0 +1,42 @@
+use napi::bindgen_prelude::*;
+
+#[napi]
+pub fn echo_request(request: Request) -> Result<ResponseBuilder> {
+  let user_agent = request.header("user-agent")?.unwrap_or_default();
+  Ok(
+    ResponseBuilder::new()
+      .status(201)
+      .status_text("Created")
+      .header("content-type", "text/plain")
+      .header("x-header-count", request.headers()?.len().to_string())
+      .body(format!(
+        "{} {} {}",
+        request.method()?,
+        request.url()?,
+        user_agent
+      )),
+  )
+}
+
+#[napi]
+pub fn read_request_body(request: Request) -> Result<PromiseRaw<String>> {
+  request.text()
+}
+
+#[napi]
+pub fn create_streaming_response(chunks: Vec<String>) -> ResponseBuilder {
+  let (tx, rx) = tokio::sync::mpsc::channel(1);
+  napi::bindgen_prelude::spawn(async move {
+    for chunk in chunks {
+      if tx.send(Ok(chunk.into_bytes())).await.is_err() {
+        break;
+      }
+    }
+  });
+  ResponseBuilder::new().body(rx)
+}
+
+#[napi]
+pub fn get_response_status(response: Response) -> Result<u32> {
+  Ok(response.status()? as u32)
+}
@@ -25,3 +25,38 @@ fn override_individual_arg_on_function_with_cb_arg<
 ) -> Result<Object> {
   callback(format!("World({})", not_overridden), None)
 }
+
+#[cfg(test)]
+mod type_def_tests {
+  #[test]
+  fn fn_type_def() {
+    assert_eq!(
+      type_def!(super::ts_rename),
+      "export declare function tsRename(a: { foo: number }): string[]"
+    );
+    assert_eq!(
+      type_def!(super::override_individual_arg_on_function),
+      "export declare function overrideIndividualArgOnFunction(notOverridden: string, f: () => string, notOverridden2: number): string"
+    );
+  }
+
+  #[test]
+  fn class_type_def() {
+    assert_eq!(
+      type_def!(crate::class::AnimalWithDefaultConstructor),
+      "export declare class AnimalWithDefaultConstructor {\nname: string\nkind: number\nconstructor(name: string, kind: number)\n}"
+    );
+  }
+
+  #[test]
+  fn method_type_def() {
+    assert_eq!(
+      type_def!(crate::class::Animal::whoami),
+      "/**\n * This is a\n * multi-line comment\n * with an emoji 🚀\n */\nwhoami(): string"
+    );
+    assert_eq!(
+      type_def!(crate::class::Animal::return_other_class),
+      "/**\n * Here are some characters and character sequences\n * that should be escaped correctly:\n * \\[]{}/\\:\"\"{\n * }\n */\nreturnOtherClass(): Dog"
+    );
+  }
+}
@@ -1,7 +1,7 @@
 #![allow(deprecated)]
 
 use napi::{
-  bindgen_prelude::{ClassInstance, Function, FunctionRef, PromiseRaw},
+  bindgen_prelude::{ClassInstance, Function, FunctionRef, Object, PreparedCall, PromiseRaw},
   threadsafe_function::ThreadsafeFunctionCallMode,
   Env, Error, Result, Status,
 };
@@ -82,6 +82,26 @@ pub fn reference_as_callback(
   callback.borrow_back(&env)?.call((arg0, arg1))
 }
 
+#[napi]
+pub fn sum_prepared_calls(
+  ctx: Object,
+  callback: Function<(u32, u32), u32>,
+  factor: u32,
+  times: u32,
+  unchecked: Option<bool>,
+) -> Result<u32> {
+  let call: PreparedCall<u32, u32> = callback.prepare_call(ctx, factor)?;
+  (0..times).try_fold(0, |sum, i| {
+    let value = if unchecked.unwrap_or(false) {
+      // SAFETY: only called with callbacks which never throw
+      unsafe { call.call_unchecked(i)? }
+    } else {
+      call.call(i)?
+    };
+    Ok(sum + value)
+  })
+}
+
 #[napi]
 pub fn build_threadsafe_function_from_function(callback: Function<(u32, u32), u32>) -> Result<()> {
   let tsfn = callback.build_threadsafe_function().build()?;
@@ -127,3 +147,28 @@ pub fn build_threadsafe_function_from_function_callee_handle(
 
   Ok(())
 }
+
+type TenArgs = (u32, u32, u32, u32, u32, u32, u32, u32, u32, u32);
+
+#[napi(ts_args_type = "callback: (...args: number[]) => number")]
+pub fn call_function_with_many_args(callback: Function<TenArgs, u32>) -> Result<u32> {
+  callback.call((1, 2, 3, 4, 5, 6, 7, 8, 9, 10))
+}
+
+#[napi(ts_return_type = "() => number")]
+pub fn create_counter(env: &Env, start: u32) -> Result<Function<'_, (), u32>> {
+  let count = std::cell::Cell::new(start);
+  env.create_function_from_closure("counter", move |_| {
+    count.set(count.get() + 1);
+    Ok(count.get())
+  })
+}
+
+#[napi]
+pub fn call_closure_with_many_args(env: Env) -> Result<u32> {
+  let sum = env.create_function_from_closure::<TenArgs, u32, _>("sum", |ctx| {
+    let (a, b, c, d, e, f, g, h, i, j) = ctx.args::<TenArgs>()?;
+    Ok(a + b + c + d + e + f + g + h + i + j)
+  })?;
+  sum.call((1, 2, 3, 4, 5, 6, 7, 8, 9, 10))
+}
@@ -0,0 +1,60 @@
+use napi::bindgen_prelude::*;
+
+#[napi]
+pub fn sort_by_locale(env: Env, items: Vec<String>, locale: String) -> Result<Vec<String>> {
+  let collator = Collator::new(
+    &env,
+    &[&locale],
+    CollatorOptions {
+      numeric: Some(true),
+      ..Default::default()
+    },
+  )?;
+  collator.sort(&env, items)
+}
+
+#[napi]
+pub fn locale_equals_ignore_accents(env: Env, a: String, b: String) -> Result<bool> {
+  let collator = Collator::new(
+    &env,
+    &[],
+    CollatorOptions {
+      sensitivity: Some(CollatorSensitivity::Base),
+      ..Default::default()
+    },
+  )?;
+  Ok(collator.compare(&env, &a, &b)?.is_eq())
+}
+
+#[napi]
+pub fn to_locale_upper_case(env: Env, input: String, locale: String) -> Result<String> {
+  LocaleCaseMapper::new(&env, Some(&locale))?.to_upper_case(&env, &input)
+}
+
+static LOCALE_LOOKUPS: std::sync::atomic::AtomicU32 = std::sync::atomic::AtomicU32::new(0);
+
+#[napi(object)]
+pub struct LocaleInfo {
+  pub language: String,
+  pub region: Option<String>,
+}
+
+/// Memoized, the calls with the same tag get the same object
+#[napi(memoize)]
+pub fn lookup_locale(tag: String) -> Result<LocaleInfo> {
+  LOCALE_LOOKUPS.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+  let mut parts = tag.split(['-', '_']);
+  let language = parts
+    .next()
+    .filter(|language| !language.is_empty())
+    .ok_or_else(|| Error::new(Status::InvalidArg, format!("Invalid locale `{}`", tag)))?;
+  Ok(LocaleInfo {
+    language: language.to_lowercase(),
+    region: parts.next().map(|region| region.to_uppercase()),
+  })
+}
+
+#[napi]
+pub fn locale_lookup_count() -> u32 {
+  LOCALE_LOOKUPS.load(std::sync::atomic::Ordering::Relaxed)
+}
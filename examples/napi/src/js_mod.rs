@@ -57,7 +57,27 @@ mod xxh3 {
     pub fn digest(&self) -> BigInt {
       self.inner.clone()
     }
+
+    #[cfg(target_endian = "little")]
+    #[napi(getter)]
+    pub fn endianness(&self) -> &'static str {
+      "little"
+    }
+
+    #[cfg(target_endian = "big")]
+    #[napi(getter)]
+    pub fn endianness(&self) -> &'static str {
+      "big"
+    }
   }
+
+  #[napi]
+  #[cfg(target_pointer_width = "64")]
+  pub const WORD_SIZE: u32 = 8;
+
+  #[napi]
+  #[cfg(not(target_pointer_width = "64"))]
+  pub const WORD_SIZE: u32 = 4;
 }
 
 #[napi]
@@ -81,3 +101,16 @@ use napi::bindgen_prelude::Buffer;
 pub fn xxh64_alias(input: Buffer) -> u64 {
   xxh3::xxh64(input)
 }
+
+#[napi(js_name = concat!("ANSWER_", 42))]
+pub const ANSWER: u32 = 42;
+
+#[napi(js_name = concat!("get", stringify!(Answer), 42))]
+pub fn answer() -> u32 {
+  42
+}
+
+#[napi(js_name = concat!(env!("CARGO_CRATE_NAME"), "_version"))]
+pub fn crate_version() -> String {
+  env!("CARGO_PKG_VERSION").to_owned()
+}
@@ -30,6 +30,10 @@ fn init() {
   create_custom_tokio_runtime(rt);
 }
 
+// for embedders registering the examples with `node::AddLinkedBinding`
+#[cfg(not(target_family = "wasm"))]
+napi::module!(napi_register_examples_v1, linked = "napi_examples");
+
 #[napi]
 /// This is a const
 pub const DEFAULT_COST: u32 = 12;
@@ -39,7 +43,10 @@ pub const TYPE_SKIPPED_CONST: u32 = 12;
 
 mod array;
 mod r#async;
+mod async_generator;
+mod auto;
 mod bigint;
+mod blob;
 mod callback;
 mod class;
 mod class_factory;
@@ -50,21 +57,33 @@ mod r#enum;
 mod env;
 mod error;
 mod external;
+mod external_memory;
+mod fetch;
 mod fn_strict;
 mod fn_ts_override;
 mod function;
 mod generator;
+mod intl;
 mod js_mod;
 mod map;
 mod nullable;
 mod number;
 mod object;
+mod overload;
+mod plugin;
 mod promise;
+mod record;
 mod reference;
+mod regexp;
+mod ring_buffer;
 mod serde;
+mod set;
 mod shared;
 mod string;
 mod symbol;
 mod task;
 mod threadsafe_function;
+mod trace;
+mod transform;
 mod typed_array;
+mod unstable;
@@ -1,6 +1,7 @@
 use std::collections::{BTreeMap, HashMap};
 
 use indexmap::IndexMap;
+use napi::{bindgen_prelude::*, JsMap};
 
 #[napi]
 fn get_mapping() -> HashMap<String, u32> {
@@ -45,3 +46,32 @@ fn sum_index_mapping(nums: IndexMap<String, u32>) -> u32 {
 fn indexmap_passthrough(fixture: IndexMap<String, u32>) -> IndexMap<String, u32> {
   fixture
 }
+
+#[napi]
+fn create_es_map(env: Env, fixture: BTreeMap<String, u32>) -> Result<JsMap> {
+  env.create_map_from(fixture)
+}
+
+#[napi]
+fn sum_es_map(map: JsMap) -> Result<u32> {
+  map
+    .entries::<Unknown, u32>()?
+    .try_fold(0, |sum, entry| Ok(sum + entry?.1))
+}
+
+#[napi]
+fn es_map_to_record(map: JsMap) -> Result<HashMap<String, u32>> {
+  map.try_into()
+}
+
+/// Increment the count of `key`, returns the new size of the map
+#[napi]
+fn count_in_es_map(mut map: JsMap, key: String, remove: bool) -> Result<u32> {
+  if remove {
+    map.delete(key.as_str())?;
+  } else {
+    let count = map.get::<_, u32>(key.as_str())?.unwrap_or(0);
+    map.set(key.as_str(), count + 1)?;
+  }
+  map.size()
+}
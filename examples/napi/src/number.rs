@@ -1,3 +1,5 @@
+use napi::bindgen_prelude::Finite;
+
 #[napi]
 fn add(a: u32, b: u32) -> u32 {
   a + b
@@ -10,3 +12,13 @@ fn fibonacci(n: u32) -> u32 {
     _ => fibonacci(n - 1) + fibonacci(n - 2),
   }
 }
+
+#[napi]
+fn scale(factor: Finite<f64>, value: Finite<i32>) -> f64 {
+  *factor * f64::from(*value)
+}
+
+#[napi]
+fn negate(value: f64) -> f64 {
+  -value
+}
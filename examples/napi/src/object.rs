@@ -1,6 +1,9 @@
+use std::cell::Cell;
+use std::rc::Rc;
+
 use napi::{
-  bindgen_prelude::*, threadsafe_function::ThreadsafeFunction, JsGlobal, JsNull, JsObject,
-  JsUndefined,
+  bindgen_prelude::*, threadsafe_function::ThreadsafeFunction, Accessor, JsGlobal, JsNull,
+  JsObject, JsUndefined, TypeTag,
 };
 
 #[napi]
@@ -8,6 +11,22 @@ fn list_obj_keys(obj: Object) -> Vec<String> {
   Object::keys(&obj).unwrap()
 }
 
+#[napi]
+fn sum_obj_values(obj: Object) -> Result<f64> {
+  Ok(obj.values::<f64>()?.into_iter().sum())
+}
+
+#[napi]
+fn format_obj_entries(obj: Object) -> Result<Vec<String>> {
+  Ok(
+    obj
+      .entries::<u32>()?
+      .into_iter()
+      .map(|(key, value)| format!("{key}={value}"))
+      .collect(),
+  )
+}
+
 #[napi]
 fn create_obj(env: Env) -> Object {
   let mut obj = env.create_object().unwrap();
@@ -148,3 +167,76 @@ fn return_object_only_to_js() -> ObjectOnlyToJs {
 
 #[napi(object)]
 pub struct TupleObject(pub u32, pub u32);
+
+const CONFIG_TYPE_TAG: TypeTag = TypeTag {
+  lower: 0x8c3e_6f3d_2a4b_1e07,
+  upper: 0x5d1f_0b7a_94c2_e6a3,
+};
+
+#[napi]
+fn tag_config_object(env: Env, obj: Object) -> Result<()> {
+  env.type_tag_object(&obj, &CONFIG_TYPE_TAG)
+}
+
+#[napi]
+fn is_config_object(env: Env, obj: Object) -> Result<bool> {
+  env.check_object_type_tag(&obj, &CONFIG_TYPE_TAG)
+}
+
+#[napi(ts_return_type = "{ name: string }")]
+fn create_frozen_config(env: Env) -> Result<Object> {
+  let mut config = env.create_object()?;
+  config.set("name", "napi-rs")?;
+  env.object_freeze(&config)?;
+  Ok(config)
+}
+
+#[napi(ts_return_type = "{ name: string }")]
+fn create_sealed_config(env: Env) -> Result<Object> {
+  let mut config = env.create_object()?;
+  config.set("name", "napi-rs")?;
+  env.object_seal(&config)?;
+  Ok(config)
+}
+
+#[napi]
+fn seal_object(mut obj: Object) -> Result<()> {
+  obj.seal()
+}
+
+#[napi(ts_return_type = "'frozen' | 'sealed' | 'none'")]
+fn get_integrity_level(obj: Object) -> Result<&'static str> {
+  Ok(if obj.is_frozen()? {
+    "frozen"
+  } else if obj.is_sealed()? {
+    "sealed"
+  } else {
+    "none"
+  })
+}
+
+/// Define the `celsius` and the read-only `fahrenheit` accessors on `target`, backed by Rust state
+#[napi]
+pub fn define_temperature(mut target: Object, celsius: f64) -> Result<()> {
+  let value = Rc::new(Cell::new(celsius));
+  let (get, set) = (value.clone(), value.clone());
+  target.define_property(
+    "celsius",
+    Accessor::new()
+      .with_getter(move |_env, _this| Ok(get.get()))
+      .with_setter(move |_env, _this, celsius: f64| {
+        if celsius < -273.15 {
+          return Err(Error::new(
+            Status::InvalidArg,
+            "The temperature is below absolute zero",
+          ));
+        }
+        set.set(celsius);
+        Ok(())
+      }),
+  )?;
+  target.define_property(
+    "fahrenheit",
+    Accessor::new().with_getter(move |_env, _this| Ok(value.get() * 9.0 / 5.0 + 32.0)),
+  )
+}
@@ -0,0 +1,48 @@
+use napi::bindgen_prelude::*;
+
+/// Milliseconds of a duration like `"1.5s"` or `"250ms"`
+#[napi(overload = "toMilliseconds")]
+pub fn to_milliseconds_from_str(input: String) -> Result<f64> {
+  let (value, unit) = input
+    .find(|c: char| c.is_ascii_alphabetic())
+    .map(|i| input.split_at(i))
+    .ok_or_else(|| Error::from_reason(format!("`{input}` has no unit")))?;
+  let value = value
+    .trim()
+    .parse()
+    .map_err(|_| Error::from_reason(format!("`{input}` is not a duration")))?;
+  to_milliseconds_with_unit(value, Some(unit.to_owned()))
+}
+
+/// Milliseconds of `value` in `unit`, which is milliseconds by default
+#[napi(overload = "toMilliseconds")]
+pub fn to_milliseconds_with_unit(value: f64, unit: Option<String>) -> Result<f64> {
+  let scale = match unit.as_deref().unwrap_or("ms") {
+    "ms" => 1.0,
+    "s" => 1000.0,
+    "m" => 60_000.0,
+    "h" => 3_600_000.0,
+    unit => return Err(Error::from_reason(format!("Unknown unit `{unit}`"))),
+  };
+  Ok(value * scale)
+}
+
+/// Milliseconds of `[seconds, nanoseconds]`, like from `process.hrtime()`
+#[napi(overload = "toMilliseconds")]
+pub fn to_milliseconds_from_hrtime(time: Vec<u32>) -> Result<f64> {
+  match time[..] {
+    [seconds, nanoseconds] => Ok(seconds as f64 * 1000.0 + nanoseconds as f64 / 1_000_000.0),
+    _ => Err(Error::from_reason("Expected `[seconds, nanoseconds]`")),
+  }
+}
+
+/// The overloads taking as many arguments are tried in declaration order
+#[napi(overload = "describeValue")]
+pub fn describe_number(value: f64) -> String {
+  format!("number {value}")
+}
+
+#[napi(overload = "describeValue")]
+pub fn describe_unknown(value: Unknown) -> Result<String> {
+  Ok(format!("{}", value.get_type()?))
+}
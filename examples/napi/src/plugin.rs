@@ -0,0 +1,82 @@
+use std::sync::atomic::{AtomicU32, Ordering};
+
+use napi::{bindgen_prelude::*, NapiRaw};
+
+#[napi(module = "extra")]
+pub const EXTRA_VERSION: u32 = 1;
+
+#[napi(module = "extra")]
+pub fn extra_add(a: u32, b: u32) -> u32 {
+  a + b
+}
+
+#[napi(module = "extra")]
+pub struct ExtraCounter {
+  pub count: u32,
+}
+
+#[napi]
+impl ExtraCounter {
+  #[napi(constructor)]
+  pub fn new() -> Self {
+    ExtraCounter { count: 0 }
+  }
+
+  #[napi]
+  pub fn increment(&mut self) -> u32 {
+    self.count += 1;
+    self.count
+  }
+}
+
+napi::module!(napi_register_extra_v1, module = "extra");
+
+/// Call the init symbol of the `extra` module like a plugin host would, on a fresh exports object
+#[napi]
+pub fn load_extra_module(env: Env) -> Result<Object> {
+  let exports = env.create_object()?;
+  unsafe { napi_register_extra_v1(env.raw(), exports.raw()) };
+  Ok(exports)
+}
+
+#[repr(C)]
+pub struct CounterVTable {
+  pub add: extern "C" fn(by: u32) -> u32,
+  pub get: extern "C" fn() -> u32,
+}
+
+unsafe impl PluginVTable for CounterVTable {
+  const TYPE_TAG: u128 = 0x8f6c_1d02_4b7e_4a39_b3c5_90e1_27d4_6a18;
+}
+
+static SHARED_COUNT: AtomicU32 = AtomicU32::new(0);
+
+extern "C" fn shared_add(by: u32) -> u32 {
+  SHARED_COUNT.fetch_add(by, Ordering::Relaxed) + by
+}
+
+extern "C" fn shared_get() -> u32 {
+  SHARED_COUNT.load(Ordering::Relaxed)
+}
+
+static COUNTER_VTABLE: CounterVTable = CounterVTable {
+  add: shared_add,
+  get: shared_get,
+};
+
+/// The host side, exporting its shared counter to the plugins
+#[napi]
+pub fn counter_vtable() -> VTable<CounterVTable> {
+  VTable::new(&COUNTER_VTABLE)
+}
+
+/// The plugin side, calling into the host through its vtable
+#[napi]
+pub fn plugin_add(host: VTable<CounterVTable>, by: u32) -> u32 {
+  (host.add)(by)
+}
+
+#[napi]
+pub fn plugin_get(host: VTable<CounterVTable>) -> u32 {
+  (host.get)()
+}
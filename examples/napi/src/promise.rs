@@ -1,4 +1,4 @@
-use napi::bindgen_prelude::*;
+use napi::{bindgen_prelude::*, JsDeferred, JsObject};
 
 #[napi]
 pub async fn async_plus_100(p: Promise<u32>) -> Result<u32> {
@@ -26,3 +26,30 @@ pub fn call_finally_on_promise(
     Ok(())
   })
 }
+
+#[napi(ts_return_type = "Promise<number>")]
+pub fn sum_on_thread(env: Env, nums: Vec<u32>) -> Result<JsObject> {
+  let (deferred, promise): (JsDeferred<u32>, _) = env.create_deferred()?;
+  std::thread::spawn(
+    move || match nums.iter().try_fold(0u32, |sum, n| sum.checked_add(*n)) {
+      Some(sum) => deferred.resolve_value(sum),
+      None => deferred.reject(Error::new(Status::GenericFailure, "Sum overflowed")),
+    },
+  );
+  Ok(promise)
+}
+
+#[napi]
+pub fn promise_with_timeout(input: PromiseRaw<u32>, ms: u32) -> Result<PromiseRaw<u32>> {
+  input.timeout(std::time::Duration::from_millis(ms.into()))
+}
+
+#[napi]
+pub fn race_promises(env: Env, inputs: Vec<PromiseRaw<u32>>) -> Result<PromiseRaw<u32>> {
+  PromiseRaw::race(&env, inputs)
+}
+
+#[napi]
+pub fn all_promises(env: Env, inputs: Vec<PromiseRaw<u32>>) -> Result<PromiseRaw<Vec<u32>>> {
+  PromiseRaw::all(&env, inputs)
+}
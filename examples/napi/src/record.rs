@@ -0,0 +1,99 @@
+use std::io::{Cursor, Write};
+use std::sync::Mutex;
+
+use napi::{bindgen_prelude::*, record};
+
+static CALL_LOG: Mutex<Vec<u8>> = Mutex::new(Vec::new());
+
+struct CallLog;
+
+impl Write for CallLog {
+  fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+    CALL_LOG.lock().unwrap().extend_from_slice(buf);
+    Ok(buf.len())
+  }
+
+  fn flush(&mut self) -> std::io::Result<()> {
+    Ok(())
+  }
+}
+
+struct BrokenLog;
+
+impl Write for BrokenLog {
+  fn write(&mut self, _buf: &[u8]) -> std::io::Result<usize> {
+    Err(std::io::Error::other("disk full"))
+  }
+
+  fn flush(&mut self) -> std::io::Result<()> {
+    Ok(())
+  }
+}
+
+#[napi(object)]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct RecordedPoint {
+  pub x: f64,
+  pub y: f64,
+}
+
+#[napi(record)]
+pub fn translate_point(point: RecordedPoint, dx: f64, dy: f64) -> RecordedPoint {
+  RecordedPoint {
+    x: point.x + dx,
+    y: point.y + dy,
+  }
+}
+
+#[napi(record)]
+pub fn parse_port(input: String) -> Result<u32> {
+  input
+    .parse()
+    .map_err(|e| Error::new(Status::InvalidArg, format!("{e}")))
+}
+
+#[napi]
+pub fn start_recording_calls() {
+  CALL_LOG.lock().unwrap().clear();
+  record::start_recording(CallLog);
+}
+
+#[napi]
+pub fn start_recording_to_broken_log() {
+  record::start_recording(BrokenLog);
+}
+
+#[napi]
+pub fn take_recording_error() -> Option<String> {
+  record::take_error().map(|e| e.reason.clone())
+}
+
+/// Stop recording and run the recorded calls again in Rust, returns the names of the replayed functions
+#[napi]
+pub fn replay_recorded_calls() -> Result<Vec<String>> {
+  record::stop_recording();
+  let log = std::mem::take(&mut *CALL_LOG.lock().unwrap());
+  let mut replayed = Vec::new();
+  for call in record::replay(Cursor::new(log)) {
+    let call = call?;
+    let matches = match call.function.as_str() {
+      "translatePoint" => {
+        let (point, dx, dy) = call.args()?;
+        call.ret()? == Ok(translate_point(point, dx, dy))
+      }
+      "parsePort" => {
+        let (input,) = call.args()?;
+        call.ret::<u32>()? == parse_port(input).map_err(|e| e.to_string())
+      }
+      _ => false,
+    };
+    if !matches {
+      return Err(Error::new(
+        Status::GenericFailure,
+        format!("Replaying `{}` gave a different result", call.function),
+      ));
+    }
+    replayed.push(call.function);
+  }
+  Ok(replayed)
+}
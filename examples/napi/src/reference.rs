@@ -1,6 +1,6 @@
-use std::{cell::RefCell, rc::Rc};
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
 
-use napi::bindgen_prelude::*;
+use napi::{bindgen_prelude::*, Ref};
 
 pub struct Repository {
   dir: String,
@@ -160,3 +160,88 @@ impl CSSStyleSheet {
     })
   }
 }
+
+#[napi]
+pub struct Row {
+  pub id: u32,
+  pub name: String,
+}
+
+#[napi]
+pub struct RowCursor {
+  next_id: u32,
+  pool: ClassInstancePool<Row>,
+}
+
+#[napi]
+impl RowCursor {
+  #[napi(constructor)]
+  pub fn new(pool_size: u32) -> Self {
+    RowCursor {
+      next_id: 0,
+      pool: ClassInstancePool::new(pool_size as usize),
+    }
+  }
+
+  #[napi]
+  pub fn next_row(&mut self, env: Env, name: String) -> Result<PooledInstance<Row>> {
+    let id = self.next_id;
+    self.next_id += 1;
+    let row = self.pool.acquire(env, Row { id, name })?;
+    debug_assert_eq!(row.get()?.id, id);
+    Ok(row)
+  }
+
+  #[napi]
+  pub fn release(&mut self, row: Reference<Row>) -> Result<()> {
+    self.pool.release(row)
+  }
+
+  #[napi(getter)]
+  pub fn pooled(&self) -> u32 {
+    self.pool.len() as u32
+  }
+}
+
+/// Hold objects without keeping them alive
+#[napi(custom_finalize)]
+pub struct WeakObjectCache {
+  entries: HashMap<String, Ref<Object>>,
+}
+
+#[napi]
+impl WeakObjectCache {
+  #[napi(constructor)]
+  pub fn new() -> Self {
+    WeakObjectCache {
+      entries: HashMap::new(),
+    }
+  }
+
+  #[napi]
+  pub fn set(&mut self, env: Env, key: String, value: Object) -> Result<()> {
+    let reference = env.create_weak_reference(&value)?;
+    if let Some(mut previous) = self.entries.insert(key, reference) {
+      previous.unref(&env)?;
+    }
+    Ok(())
+  }
+
+  /// The object of `key`, unless it's garbage collected
+  #[napi]
+  pub fn get(&self, env: Env, key: String) -> Result<Option<Object>> {
+    match self.entries.get(&key) {
+      Some(reference) => reference.upgrade(&env),
+      None => Ok(None),
+    }
+  }
+}
+
+impl ObjectFinalize for WeakObjectCache {
+  fn finalize(self, env: Env) -> Result<()> {
+    for (_, mut reference) in self.entries {
+      reference.unref(&env)?;
+    }
+    Ok(())
+  }
+}
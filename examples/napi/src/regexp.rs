@@ -0,0 +1,58 @@
+use std::collections::HashMap;
+
+use napi::bindgen_prelude::*;
+
+#[napi(object)]
+pub struct RegExpExecResult {
+  pub matched: String,
+  pub index: u32,
+  pub captures: Vec<Option<String>>,
+  pub groups: HashMap<String, Option<String>>,
+}
+
+impl From<RegExpMatch> for RegExpExecResult {
+  fn from(m: RegExpMatch) -> Self {
+    Self {
+      matched: m.matched,
+      index: m.index,
+      captures: m.captures,
+      groups: m.groups,
+    }
+  }
+}
+
+#[napi]
+pub fn create_reg_exp(env: Env, pattern: String, flags: String) -> Result<RegExp> {
+  env.create_regexp(&pattern, &flags)
+}
+
+#[napi]
+pub fn get_reg_exp_source_and_flags(regexp: RegExp) -> Result<Vec<String>> {
+  Ok(vec![regexp.source()?, regexp.flags()?])
+}
+
+#[napi]
+pub fn reg_exp_test(regexp: RegExp, input: String) -> Result<bool> {
+  regexp.test(&input)
+}
+
+#[napi]
+pub fn reg_exp_exec(regexp: RegExp, input: String) -> Result<Option<RegExpExecResult>> {
+  Ok(regexp.exec(&input)?.map(RegExpExecResult::from))
+}
+
+#[napi]
+pub fn reg_exp_match_all(regexp: RegExp, input: String) -> Result<Vec<RegExpExecResult>> {
+  Ok(
+    regexp
+      .match_all(&input)?
+      .into_iter()
+      .map(RegExpExecResult::from)
+      .collect(),
+  )
+}
+
+#[napi(ts_args_type = "value: unknown")]
+pub fn is_reg_exp(value: Unknown) -> Result<bool> {
+  value.is_regexp()
+}
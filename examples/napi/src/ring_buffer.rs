@@ -0,0 +1,179 @@
+use std::thread;
+use std::time::Duration;
+
+use napi::{
+  bindgen_prelude::*, FrameQueue, FrameSender, JsArrayBuffer, OverflowPolicy, RingBufferExchange,
+  RingBufferProducer,
+};
+
+#[napi(object)]
+pub struct PipelineFrame {
+  pub index: u32,
+  pub length: u32,
+}
+
+/// Frames produced on another thread into a fixed set of ArrayBuffers
+#[napi]
+pub struct FramePipeline {
+  exchange: RingBufferExchange,
+  producer: Option<RingBufferProducer>,
+}
+
+#[napi]
+impl FramePipeline {
+  #[napi(constructor)]
+  pub fn new(env: Env, slots: u32, frame_size: u32) -> Result<Self> {
+    let (exchange, producer) = RingBufferExchange::new(&env, slots as usize, frame_size as usize)?;
+    Ok(FramePipeline {
+      exchange,
+      producer: Some(producer),
+    })
+  }
+
+  /// The ArrayBuffer of each slot
+  #[napi(getter)]
+  pub fn buffers(&self) -> Result<Vec<JsArrayBuffer>> {
+    (0..self.exchange.slot_count())
+      .map(|index| self.exchange.buffer(index))
+      .collect()
+  }
+
+  /// Produce `count` frames on another thread, frame `n` is filled with `n` and is `n % size + 1`
+  /// bytes long
+  #[napi]
+  pub fn start(&mut self, count: u32) -> Result<()> {
+    let mut producer = self
+      .producer
+      .take()
+      .ok_or_else(|| Error::from_reason("The pipeline is started already"))?;
+    thread::spawn(move || {
+      for n in 0..count {
+        loop {
+          let size = producer.slot_size();
+          if let Some(mut frame) = producer.try_acquire() {
+            frame.fill(n as u8);
+            frame.commit(n as usize % size + 1);
+            break;
+          }
+          thread::sleep(Duration::from_millis(1));
+        }
+      }
+    });
+    Ok(())
+  }
+
+  #[napi]
+  pub fn next_frame(&self) -> Option<PipelineFrame> {
+    self.exchange.take().map(|frame| PipelineFrame {
+      index: frame.index as u32,
+      length: frame.len as u32,
+    })
+  }
+
+  #[napi]
+  pub fn release(&self, index: u32) -> Result<()> {
+    self.exchange.release(index as usize)
+  }
+}
+
+#[napi(object)]
+pub struct CapturedFrame {
+  pub index: u32,
+  pub length: u32,
+  pub timestamp: i64,
+}
+
+impl From<napi::QueuedFrame> for CapturedFrame {
+  fn from(frame: napi::QueuedFrame) -> Self {
+    CapturedFrame {
+      index: frame.index as u32,
+      length: frame.len as u32,
+      timestamp: frame.timestamp,
+    }
+  }
+}
+
+/// Timestamped frames captured on another thread
+#[napi]
+pub struct CaptureQueue {
+  queue: FrameQueue,
+  sender: Option<FrameSender>,
+}
+
+#[napi]
+impl CaptureQueue {
+  #[napi(constructor)]
+  pub fn new(env: Env, capacity: u32, frame_size: u32, drop_oldest: bool) -> Result<Self> {
+    let policy = if drop_oldest {
+      OverflowPolicy::DropOldest
+    } else {
+      OverflowPolicy::Backpressure
+    };
+    let (queue, sender) = FrameQueue::new(&env, capacity as usize, frame_size as usize, policy)?;
+    Ok(CaptureQueue {
+      queue,
+      sender: Some(sender),
+    })
+  }
+
+  /// The ArrayBuffer of each slot
+  #[napi(getter)]
+  pub fn buffers(&self) -> Result<Vec<JsArrayBuffer>> {
+    (0..self.queue.capacity())
+      .map(|index| self.queue.buffer(index))
+      .collect()
+  }
+
+  /// Capture `count` frames on another thread, frame `n` is filled with `n`, is `n % size + 1`
+  /// bytes long and is at `n * 1000`
+  #[napi]
+  pub fn start(&mut self, count: u32) -> Result<()> {
+    let mut sender = self
+      .sender
+      .take()
+      .ok_or_else(|| Error::from_reason("The capture is started already"))?;
+    thread::spawn(move || {
+      for n in 0..count {
+        let size = sender.frame_size();
+        let Some(mut slot) = sender.acquire(Duration::from_secs(5)) else {
+          return;
+        };
+        slot.fill(n as u8);
+        slot.commit(n as usize % size + 1, n as i64 * 1000);
+      }
+    });
+    Ok(())
+  }
+
+  #[napi]
+  pub fn poll(&self) -> Option<CapturedFrame> {
+    self.queue.poll().map(CapturedFrame::from)
+  }
+
+  #[napi]
+  pub fn release(&self, index: u32) -> Result<()> {
+    self.queue.release(index as usize)
+  }
+
+  #[napi(ts_args_type = "callback: (frame: CapturedFrame, buffer: ArrayBuffer) => void")]
+  pub fn on_frame(&self, callback: Function<(CapturedFrame, JsArrayBuffer), ()>) -> Result<()> {
+    let callback = callback.create_ref()?;
+    self.queue.on_frame(move |env, frame, buffer| {
+      callback
+        .borrow_back(&env)?
+        .call((CapturedFrame::from(frame), buffer))
+    })
+  }
+
+  /// The frames dropped to make room for newer ones
+  #[napi(getter)]
+  pub fn dropped(&self) -> i64 {
+    self.queue.dropped() as i64
+  }
+
+  /// Whether the capture is over and all its frames are received
+  #[napi(getter)]
+  pub fn finished(&self) -> bool {
+    self.queue.is_finished()
+  }
+}
@@ -48,3 +48,11 @@ fn test_serde_buffer_bytes(obj: Object, env: Env) -> napi::Result<usize> {
   let obj: BytesObject = env.from_js_value(obj)?;
   Ok(obj.code.len())
 }
+
+#[napi(constructor, json)]
+#[derive(Serialize, Deserialize)]
+pub struct GeoPoint {
+  pub latitude: f64,
+  pub longitude: f64,
+  pub label: Option<String>,
+}
@@ -0,0 +1,33 @@
+use std::collections::HashSet;
+
+use napi::{bindgen_prelude::*, JsSet};
+
+#[napi]
+pub fn create_es_set(env: Env, values: Vec<String>) -> Result<JsSet> {
+  env.create_set_from(values)
+}
+
+#[napi]
+pub fn es_set_to_sorted_vec(set: JsSet) -> Result<Vec<u32>> {
+  let mut values: Vec<u32> = set.try_into()?;
+  values.sort_unstable();
+  Ok(values)
+}
+
+#[napi]
+pub fn count_distinct_in_es_set(set: JsSet) -> Result<u32> {
+  let distinct: HashSet<String> = set.to_hash_set()?;
+  Ok(distinct.len() as u32)
+}
+
+/// Toggle `value` in the set, returns whether it's in the set now
+#[napi]
+pub fn toggle_in_es_set(mut set: JsSet, value: String) -> Result<bool> {
+  if set.has(value.as_str())? {
+    set.delete(value.as_str())?;
+    Ok(false)
+  } else {
+    set.add(value.as_str())?;
+    Ok(true)
+  }
+}
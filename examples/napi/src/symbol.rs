@@ -1,4 +1,4 @@
-use napi::{bindgen_prelude::*, JsObject, JsSymbol};
+use napi::{bindgen_prelude::*, JsObject, JsSymbol, WellKnownSymbol};
 
 #[napi]
 pub fn set_symbol_in_obj(env: Env, symbol: JsSymbol) -> Result<JsObject> {
@@ -16,3 +16,34 @@ pub fn create_symbol() -> Symbol {
 pub fn create_symbol_for(desc: String) -> Symbol {
   Symbol::for_desc(desc)
 }
+
+#[napi]
+pub fn set_registered_symbol_in_obj(env: Env, key: String) -> Result<JsObject> {
+  let mut obj = env.create_object()?;
+  obj.set_property(
+    env.create_symbol_for(&key)?,
+    env.create_string("a registered symbol")?,
+  )?;
+  Ok(obj)
+}
+
+/// `value[Symbol.toStringTag]`, like `Map` for a `Map`
+#[napi]
+pub fn get_to_string_tag(env: Env, value: Object) -> Result<Option<String>> {
+  let to_string_tag = env.get_well_known_symbol(WellKnownSymbol::ToStringTag)?;
+  value.get_symbol_property(&to_string_tag)
+}
+
+#[napi]
+pub fn set_symbol_metadata(mut target: Object, key: JsSymbol, value: String) -> Result<()> {
+  target.set_symbol_property(&key, value)
+}
+
+/// The metadata under `key` owned by `target`, the inherited one is ignored
+#[napi]
+pub fn read_symbol_metadata(target: Object, key: JsSymbol) -> Result<Option<String>> {
+  if !target.has_own_symbol_property(&key)? {
+    return Ok(None);
+  }
+  target.get_symbol_property(&key).map(Some)
+}
@@ -1,6 +1,8 @@
+use std::sync::{Arc, Mutex};
 use std::thread::sleep;
+use std::time::Duration;
 
-use napi::bindgen_prelude::*;
+use napi::{bindgen_prelude::*, AsyncHook, AsyncHookId, AsyncWorkInfo, AsyncWorkTimings};
 
 struct DelaySum(u32, u32);
 
@@ -93,3 +95,84 @@ impl Task for AsyncTaskReadFile {
 pub fn async_task_read_file(path: String) -> AsyncTask<AsyncTaskReadFile> {
   AsyncTask::new(AsyncTaskReadFile { path })
 }
+
+#[napi]
+fn sum_in_thread_pool(env: Env, numbers: Vec<u32>) -> Result<PromiseRaw<String>> {
+  let work = env.spawn_blocking(
+    move || {
+      if numbers.is_empty() {
+        return Err(Error::new(Status::InvalidArg, "Nothing to sum"));
+      }
+      Ok(numbers.iter().map(|n| u64::from(*n)).sum::<u64>())
+    },
+    |_env, sum| Ok(format!("sum: {sum}")),
+  )?;
+  Ok(work.promise_object())
+}
+
+#[napi(object)]
+pub struct AsyncWorkEvent {
+  pub step: String,
+  pub kind: String,
+  pub type_name: String,
+  /// The time spent running the work, for the `complete` and `error` steps
+  pub running_ms: Option<f64>,
+}
+
+/// Records the lifecycle of the async works
+struct RecordingHook(Arc<Mutex<Vec<AsyncWorkEvent>>>);
+
+impl RecordingHook {
+  fn record(&self, step: &str, work: &AsyncWorkInfo, running: Option<Duration>) {
+    self.0.lock().unwrap().push(AsyncWorkEvent {
+      step: step.to_owned(),
+      kind: format!("{:?}", work.kind),
+      type_name: work.type_name.to_owned(),
+      running_ms: running.map(|running| running.as_secs_f64() * 1000.0),
+    });
+  }
+}
+
+impl AsyncHook for RecordingHook {
+  fn on_queue(&self, work: &AsyncWorkInfo) {
+    self.record("queue", work, None);
+  }
+
+  fn on_start(&self, work: &AsyncWorkInfo, _queued: Duration) {
+    self.record("start", work, None);
+  }
+
+  fn on_complete(&self, work: &AsyncWorkInfo, timings: &AsyncWorkTimings) {
+    self.record("complete", work, Some(timings.running));
+  }
+
+  fn on_error(&self, work: &AsyncWorkInfo, _error: &Error, timings: &AsyncWorkTimings) {
+    self.record("error", work, Some(timings.running));
+  }
+}
+
+#[napi]
+pub struct AsyncWorkObserver {
+  id: AsyncHookId,
+  events: Arc<Mutex<Vec<AsyncWorkEvent>>>,
+}
+
+#[napi]
+impl AsyncWorkObserver {
+  #[napi(constructor)]
+  pub fn new(env: Env) -> Result<Self> {
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let id = env.add_async_hook(RecordingHook(events.clone()))?;
+    Ok(Self { id, events })
+  }
+
+  #[napi]
+  pub fn take_events(&self) -> Vec<AsyncWorkEvent> {
+    std::mem::take(&mut *self.events.lock().unwrap())
+  }
+
+  #[napi]
+  pub fn stop(&self, env: Env) -> bool {
+    env.remove_async_hook(self.id)
+  }
+}
@@ -2,7 +2,10 @@ use std::{sync::Arc, thread, time::Duration};
 
 use napi::{
   bindgen_prelude::*,
-  threadsafe_function::{ThreadsafeFunction, ThreadsafeFunctionCallMode, UnknownReturnValue},
+  threadsafe_function::{
+    ErrorChannel, ThreadsafeErrorReporter, ThreadsafeFunction, ThreadsafeFunctionCallMode,
+    UnknownReturnValue,
+  },
 };
 
 #[napi]
@@ -189,3 +192,40 @@ pub fn tsfn_in_either(pet: Pet) {
     });
   }
 }
+
+fn report_from_threads(reporter: ThreadsafeErrorReporter, failures: Vec<String>) {
+  for reason in failures {
+    let reporter = reporter.clone();
+    thread::spawn(move || {
+      reporter.report(Error::new(Status::GenericFailure, reason));
+    });
+  }
+}
+
+/// Report each of `failures` from its own thread to `on_error`
+#[napi]
+pub fn report_errors_to_callback(
+  env: &Env,
+  on_error: Function<Unknown, Unknown>,
+  failures: Vec<String>,
+) -> Result<()> {
+  let reporter = ThreadsafeErrorReporter::new(env, ErrorChannel::Callback(on_error))?;
+  report_from_threads(reporter, failures);
+  Ok(())
+}
+
+/// Report each of `failures` from its own thread as an `'error'` event of `emitter`
+#[napi]
+pub fn report_errors_to_emitter(env: &Env, emitter: Object, failures: Vec<String>) -> Result<()> {
+  let reporter = ThreadsafeErrorReporter::new(env, ErrorChannel::Emit(emitter))?;
+  report_from_threads(reporter, failures);
+  Ok(())
+}
+
+/// Report `failure` from another thread as an uncaught exception
+#[napi]
+pub fn report_uncaught_error(env: &Env, failure: String) -> Result<()> {
+  let reporter = ThreadsafeErrorReporter::new(env, ErrorChannel::Throw)?;
+  report_from_threads(reporter, vec![failure]);
+  Ok(())
+}
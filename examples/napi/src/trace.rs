@@ -0,0 +1,38 @@
+use std::io::Write;
+use std::sync::Mutex;
+
+use napi::{bindgen_prelude::*, trace};
+
+static TRACE_LOG: Mutex<Vec<u8>> = Mutex::new(Vec::new());
+
+struct TraceLog;
+
+impl Write for TraceLog {
+  fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+    TRACE_LOG.lock().unwrap().extend_from_slice(buf);
+    Ok(buf.len())
+  }
+
+  fn flush(&mut self) -> std::io::Result<()> {
+    Ok(())
+  }
+}
+
+/// Run `callback` with the failed Node-API calls traced, returns the trace lines
+#[napi]
+pub fn trace_failed_calls(callback: Function<(), ()>) -> Result<Vec<String>> {
+  TRACE_LOG.lock().unwrap().clear();
+  trace::set_writer(Some(Box::new(TraceLog)));
+  trace::enable();
+  let result = callback.call(());
+  trace::disable();
+  trace::set_writer(None);
+  result?;
+  let log = std::mem::take(&mut *TRACE_LOG.lock().unwrap());
+  Ok(
+    String::from_utf8_lossy(&log)
+      .lines()
+      .map(ToOwned::to_owned)
+      .collect(),
+  )
+}
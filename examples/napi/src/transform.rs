@@ -0,0 +1,61 @@
+use napi::bindgen_prelude::*;
+
+struct HexEncoder {
+  written: usize,
+}
+
+impl StreamCodec for HexEncoder {
+  fn transform(&mut self, input: &[u8], output: &mut Vec<u8>) -> Result<()> {
+    self.written += input.len();
+    for byte in input {
+      output.extend_from_slice(format!("{byte:02x}").as_bytes());
+    }
+    Ok(())
+  }
+
+  fn flush(&mut self, output: &mut Vec<u8>) -> Result<()> {
+    output.extend_from_slice(format!("\n{} bytes\n", self.written).as_bytes());
+    Ok(())
+  }
+}
+
+#[napi]
+pub fn create_hex_encoder_stream(env: Env) -> Result<Transform> {
+  Transform::new(&env, HexEncoder { written: 0 })
+}
+
+#[napi]
+pub fn create_compress_stream(env: Env, format: String, level: Option<u32>) -> Result<Transform> {
+  let codec = match format.as_str() {
+    "gzip" => CompressionCodec::gzip(level.unwrap_or(6))?,
+    "deflate" => CompressionCodec::deflate(level.unwrap_or(6))?,
+    "brotli" => CompressionCodec::brotli_compress(level.unwrap_or(11))?,
+    #[cfg(not(target_family = "wasm"))]
+    "zstd" => CompressionCodec::zstd_compress(level.unwrap_or(3) as i32)?,
+    _ => {
+      return Err(Error::new(
+        Status::InvalidArg,
+        format!("Unsupported format {format}"),
+      ))
+    }
+  };
+  Transform::new(&env, codec)
+}
+
+#[napi]
+pub fn create_decompress_stream(env: Env, format: String) -> Result<Transform> {
+  let codec = match format.as_str() {
+    "gzip" => CompressionCodec::gunzip(),
+    "deflate" => CompressionCodec::inflate(),
+    "brotli" => CompressionCodec::brotli_decompress(),
+    #[cfg(not(target_family = "wasm"))]
+    "zstd" => CompressionCodec::zstd_decompress()?,
+    _ => {
+      return Err(Error::new(
+        Status::InvalidArg,
+        format!("Unsupported format {format}"),
+      ))
+    }
+  };
+  Transform::new(&env, codec)
+}
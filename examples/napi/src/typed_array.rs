@@ -1,10 +1,82 @@
-use napi::{bindgen_prelude::*, JsArrayBuffer};
+use std::sync::{Arc, OnceLock};
+
+use napi::{bindgen_prelude::*, JsArrayBuffer, JsBuffer, JsDataView, JsTypedArray};
 
 #[napi]
 fn get_buffer() -> Buffer {
   String::from("Hello world").as_bytes().into()
 }
 
+static STATIC_ASSET: &[u8] = b"Embedded static asset";
+
+#[napi]
+fn get_static_buffer() -> Buffer {
+  Buffer::from_static(STATIC_ASSET)
+}
+
+#[napi]
+fn create_static_buffer(env: Env) -> Result<JsBuffer> {
+  // the buffer is only read by the tests
+  unsafe { env.create_buffer_with_static_data(STATIC_ASSET) }
+}
+
+#[napi]
+fn mutate_static_buffer() -> Buffer {
+  let mut buffer = Buffer::from_static(STATIC_ASSET);
+  buffer[0] = b'e';
+  buffer
+}
+
+#[napi(ts_return_type = "Float32Array")]
+fn create_external_float32_array(env: Env, length: u32) -> Result<JsTypedArray> {
+  env.create_external_typed_array((0..length).map(|i| i as f32 / 2.0).collect::<Vec<_>>())
+}
+
+#[napi(ts_return_type = "Float64Array")]
+fn create_external_float64_array(env: Env, length: u32) -> Result<JsTypedArray> {
+  env.create_external_typed_array((0..length).map(f64::from).collect::<Vec<_>>())
+}
+
+#[napi(ts_return_type = "Uint16Array")]
+fn create_external_uint16_array(env: Env, length: u32) -> Result<JsTypedArray> {
+  env.create_external_typed_array((0..length as u16).collect::<Vec<_>>())
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct Vec3 {
+  x: f32,
+  y: f32,
+  z: f32,
+}
+
+unsafe impl Pod for Vec3 {}
+
+#[napi]
+fn create_vec3_buffer(env: &Env, count: u32) -> Result<BufferSlice<'_>> {
+  env.create_buffer_slice_of(
+    (0..count)
+      .map(|i| Vec3 {
+        x: i as f32,
+        y: i as f32 * 2.0,
+        z: i as f32 * 3.0,
+      })
+      .collect(),
+  )
+}
+
+/// The sums of the coordinates of the `Vec3`s in `buffer`
+#[napi]
+fn sum_vec3_buffer(buffer: BufferSlice) -> Result<Vec<f64>> {
+  let mut sum = vec![0.0; 3];
+  for point in buffer.as_slice_of::<Vec3>()? {
+    sum[0] += f64::from(point.x);
+    sum[1] += f64::from(point.y);
+    sum[2] += f64::from(point.z);
+  }
+  Ok(sum)
+}
+
 #[napi]
 fn get_buffer_slice(env: &Env) -> Result<BufferSlice> {
   BufferSlice::from_data(env, String::from("Hello world").as_bytes().to_vec())
@@ -68,6 +140,31 @@ fn deref_uint8_array(a: Uint8Array, b: Uint8ClampedArray) -> u32 {
   (a.len() + b.len()) as u32
 }
 
+#[napi]
+fn scale_float64_array(mut input: Float64Array, factor: f64) -> Float64Array {
+  input
+    .as_mut_slice()
+    .iter_mut()
+    .for_each(|item| *item *= factor);
+  input
+}
+
+#[napi]
+fn sum_int32_array(input: Int32Array) -> i64 {
+  input.as_slice().iter().map(|&item| i64::from(item)).sum()
+}
+
+#[napi]
+fn to_uint8_clamped_array(input: Float32Array) -> Uint8ClampedArray {
+  Uint8ClampedArray::new(
+    input
+      .as_slice()
+      .iter()
+      .map(|&item| item.round().clamp(0.0, 255.0) as u8)
+      .collect(),
+  )
+}
+
 #[napi]
 async fn buffer_pass_through(buf: Buffer) -> Result<Buffer> {
   Ok(buf)
@@ -94,6 +191,45 @@ fn create_arraybuffer(env: Env) -> Result<JsArrayBuffer> {
   Ok(buf.value)
 }
 
+#[napi]
+fn create_aligned_arraybuffer(env: Env, length: u32, align: u32) -> Result<JsArrayBuffer> {
+  let mut buf = env.create_arraybuffer_aligned(length as usize, align as usize)?;
+  buf.fill(1);
+  Ok(buf.value)
+}
+
+/// A region shared by the ArrayBuffers viewing it, like a memory-mapped file
+struct SharedRegion(Vec<u8>);
+
+impl AsRef<[u8]> for SharedRegion {
+  fn as_ref(&self) -> &[u8] {
+    &self.0
+  }
+}
+
+static SHARED_REGION: OnceLock<Arc<SharedRegion>> = OnceLock::new();
+
+#[napi]
+fn view_shared_region(env: Env) -> Result<JsArrayBuffer> {
+  let region = SHARED_REGION.get_or_init(|| Arc::new(SharedRegion((0..=255).collect())));
+  env
+    .create_arraybuffer_with_guard(region.clone())
+    .map(|buf| buf.into_raw())
+}
+
+/// The number of ArrayBuffers viewing the shared region which are not garbage collected yet
+#[napi]
+fn shared_region_views() -> u32 {
+  SHARED_REGION
+    .get()
+    .map_or(0, |region| Arc::strong_count(region) as u32 - 1)
+}
+
+#[napi]
+fn is_arraybuffer_aligned(buf: JsArrayBuffer, align: u32) -> Result<bool> {
+  Ok(buf.into_value()?.as_ptr() as usize % align as usize == 0)
+}
+
 #[napi]
 fn u8_array_to_array(input: &[u8]) -> Vec<u8> {
   input.to_vec()
@@ -144,6 +280,34 @@ fn i64_array_to_array(input: &[i64]) -> Vec<i64> {
   input.to_vec()
 }
 
+#[napi]
+fn sum_i64_vec(input: Vec<i64>) -> i64 {
+  input.iter().sum()
+}
+
+#[napi]
+fn reverse_u64_vec(mut input: Vec<u64>) -> BigUint64Array {
+  input.reverse();
+  input.into()
+}
+
+#[napi]
+fn offset_i64_slice(mut input: BigInt64Slice, offset: i64) -> BigInt64Slice {
+  input
+    .iter_mut()
+    .for_each(|item| *item = item.wrapping_add(offset));
+  input
+}
+
+#[napi]
+fn create_id_column(env: &Env, first: BigInt, count: u32) -> Result<BigUint64Slice<'_>> {
+  let (_, first, _) = first.get_u64();
+  let ids = (0..u64::from(count))
+    .map(|i| first.wrapping_add(i))
+    .collect::<Vec<_>>();
+  BigUint64Slice::copy_from(env, ids)
+}
+
 #[napi]
 fn accept_uint8_clamped_slice(input: Uint8ClampedSlice) -> usize {
   input.len()
@@ -224,3 +388,49 @@ impl Reader {
     output.into_buffer_slice(env)
   }
 }
+
+#[napi(object)]
+pub struct PacketHeader {
+  pub version: u8,
+  pub flags: u8,
+  pub length: u16,
+  pub checksum: u32,
+}
+
+/// The header is `version: u8, flags: u8, length: u16` big endian, then a little endian `checksum: u32`
+#[napi]
+pub fn read_packet_header(view: JsDataView) -> Result<PacketHeader> {
+  Ok(PacketHeader {
+    version: view.get_u8(0)?,
+    flags: view.get_u8(1)?,
+    length: view.get_u16(2, false)?,
+    checksum: view.get_u32(4, true)?,
+  })
+}
+
+#[napi]
+pub fn write_packet_header(mut view: JsDataView, header: PacketHeader) -> Result<()> {
+  view.set_u8(0, header.version)?;
+  view.set_u8(1, header.flags)?;
+  view.set_u16(2, header.length, false)?;
+  view.set_u32(4, header.checksum, true)
+}
+
+/// The `length` bytes after the header of the packet in `buffer` at `byte_offset`, with its
+/// `f64` samples scaled by `factor`
+#[napi]
+pub fn scale_packet_payload(
+  env: &Env,
+  buffer: JsArrayBuffer,
+  byte_offset: u32,
+  factor: f64,
+) -> Result<JsDataView> {
+  let header = env.create_dataview(&buffer, byte_offset as usize, 8)?;
+  let length = header.get_u16(2, false)? as usize;
+  let mut payload = env.create_dataview(&buffer, byte_offset as usize + 8, length)?;
+  for offset in (0..length / 8).map(|i| i * 8) {
+    let sample = payload.get_f64(offset, true)?;
+    payload.set_f64(offset, sample * factor, true)?;
+  }
+  Ok(payload)
+}
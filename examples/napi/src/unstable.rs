@@ -0,0 +1,25 @@
+/// Split `input` into words, the rules might still change
+#[napi(unstable)]
+pub fn split_words(input: String) -> Vec<String> {
+  input.split_whitespace().map(ToOwned::to_owned).collect()
+}
+
+#[napi]
+pub struct WordCounter {
+  count: u32,
+}
+
+#[napi]
+impl WordCounter {
+  #[napi(constructor)]
+  pub fn new() -> Self {
+    WordCounter { count: 0 }
+  }
+
+  /// Count the words of `input`
+  #[napi(unstable)]
+  pub fn feed(&mut self, input: String) -> u32 {
+    self.count += input.split_whitespace().count() as u32;
+    self.count
+  }
+}
@@ -0,0 +1,13 @@
+//! This is testing that only reference counted data can be borrowed by JavaScript
+
+use napi_derive::napi;
+
+#[napi]
+pub struct Document {
+  #[napi(readonly, borrow)]
+  pub content: Vec<u8>,
+}
+
+// Needed for the trybuild tests.
+#[allow(unused)]
+fn main() {}
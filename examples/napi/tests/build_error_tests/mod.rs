@@ -1,5 +1,6 @@
 //! Include the test files here so they can be formatted properly with `cargo fmt`
 
+pub mod borrow_vec_field;
 pub mod fn_outside_impl_factory;
 pub mod ts_arg_type_1;
 pub mod ts_arg_type_2;
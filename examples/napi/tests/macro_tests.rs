@@ -9,4 +9,5 @@ fn run_build_error_tests() {
   let t = trybuild::TestCases::new();
   t.compile_fail("tests/build_error_tests/ts_arg_type_*.rs");
   t.compile_fail("tests/build_error_tests/fn_outside_impl_factory.rs");
+  t.compile_fail("tests/build_error_tests/borrow_vec_field.rs");
 }